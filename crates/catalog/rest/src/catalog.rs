@@ -41,9 +41,9 @@ use crate::client::{
     HttpClient, deserialize_catalog_response, deserialize_unexpected_catalog_error,
 };
 use crate::types::{
-    CatalogConfig, CommitTableRequest, CommitTableResponse, CreateNamespaceRequest,
-    CreateTableRequest, ListNamespaceResponse, ListTablesResponse, LoadTableResult,
-    NamespaceResponse, RegisterTableRequest, RenameTableRequest,
+    CatalogConfig, CommitTableRequest, CommitTableResponse, CommitTransactionRequest,
+    CreateNamespaceRequest, CreateTableRequest, ListNamespaceResponse, ListTablesResponse,
+    LoadTableResult, NamespaceResponse, RegisterTableRequest, RenameTableRequest,
 };
 
 /// REST catalog URI
@@ -55,6 +55,20 @@ const ICEBERG_REST_SPEC_VERSION: &str = "0.14.1";
 const CARGO_PKG_VERSION: &str = env!("CARGO_PKG_VERSION");
 const PATH_V1: &str = "v1";
 
+/// Header asking the server to delegate access to the data of loaded tables.
+const ACCESS_DELEGATION_HEADER: &str = "x-iceberg-access-delegation";
+/// Asks the server to vend temporary storage credentials scoped to a table.
+const VENDED_CREDENTIALS: &str = "vended-credentials";
+/// Properties holding the expiry in milliseconds since the epoch of vended credentials.
+const CREDENTIAL_EXPIRY_PROPS: [&str; 2] = [
+    "s3.session-token-expires-at-ms",
+    "gcs.oauth2.token-expires-at",
+];
+/// Prefix of the properties holding the expiry of vended ADLS SAS tokens, per account.
+const ADLS_CREDENTIAL_EXPIRY_PROP_PREFIX: &str = "adls.sas-token-expires-at-ms.";
+/// How long before their expiry vended credentials are refreshed.
+const CREDENTIAL_REFRESH_MARGIN_MS: i64 = 5 * 60 * 1000;
+
 /// Builder for [`RestCatalog`].
 #[derive(Debug)]
 pub struct RestCatalogBuilder(RestCatalogConfig);
@@ -185,6 +199,10 @@ impl RestCatalogConfig {
         self.url_prefixed(&["namespaces", &ns.to_url_string(), "register"])
     }
 
+    fn commit_transaction_endpoint(&self) -> String {
+        self.url_prefixed(&["transactions", "commit"])
+    }
+
     fn table_endpoint(&self, table: &TableIdent) -> String {
         self.url_prefixed(&[
             "namespaces",
@@ -416,6 +434,56 @@ impl RestCatalog {
         Ok(file_io)
     }
 
+    /// Returns the FileIO config of a table loaded from the server.
+    ///
+    /// The table config returned by the server is overridden by the local config, and both are
+    /// overridden by the vended credentials with the longest prefix of the table location.
+    fn table_file_io_config(&self, response: &LoadTableResult) -> HashMap<String, String> {
+        let location = response.metadata.location();
+        let credentials = response
+            .storage_credentials
+            .iter()
+            .flatten()
+            .filter(|credential| location.starts_with(&credential.prefix))
+            .max_by_key(|credential| credential.prefix.len());
+
+        response
+            .config
+            .clone()
+            .into_iter()
+            .chain(self.user_config.props.clone())
+            .chain(credentials.map(|c| c.config.clone()).into_iter().flatten())
+            .collect()
+    }
+
+    /// Reloads `table` when the storage credentials vended for it expire within five minutes,
+    /// and returns it unchanged otherwise.
+    ///
+    /// The expiry of the credentials is read from the `s3.session-token-expires-at-ms`,
+    /// `gcs.oauth2.token-expires-at` and `adls.sas-token-expires-at-ms.<account>` properties
+    /// of the FileIO of the table.
+    pub async fn refresh_expiring_credentials(&self, table: Table) -> Result<Table> {
+        let (_, props, _) = table.file_io().clone().into_builder().into_parts();
+        let expires_at_ms = props
+            .iter()
+            .filter(|(key, _)| {
+                CREDENTIAL_EXPIRY_PROPS.contains(&key.as_str())
+                    || key.starts_with(ADLS_CREDENTIAL_EXPIRY_PROP_PREFIX)
+            })
+            .filter_map(|(_, value)| value.parse::<i64>().ok())
+            .min();
+
+        match expires_at_ms {
+            Some(expires_at_ms)
+                if expires_at_ms - CREDENTIAL_REFRESH_MARGIN_MS
+                    <= chrono::Utc::now().timestamp_millis() =>
+            {
+                self.load_table(table.identifier()).await
+            }
+            _ => Ok(table),
+        }
+    }
+
     /// Invalidate the current token without generating a new one. On the next request, the client
     /// will attempt to generate a new token.
     pub async fn invalidate_token(&self) -> Result<()> {
@@ -431,6 +499,65 @@ impl RestCatalog {
     pub async fn regenerate_token(&self) -> Result<()> {
         self.context().await?.client.regenerate_token().await
     }
+
+    /// Commits changes to several tables atomically: either all of them are applied or
+    /// none of them.
+    ///
+    /// The commits can be created with [`Transaction::table_commit`](iceberg::transaction::Transaction::table_commit).
+    /// When the requirements of any table are not met, a retryable
+    /// [`ErrorKind::CatalogCommitConflicts`] error is returned with the server message
+    /// naming the conflicting table, and no table is changed.
+    pub async fn commit_tables(&self, commits: Vec<TableCommit>) -> Result<()> {
+        let context = self.context().await?;
+
+        let identifiers = commits
+            .iter()
+            .map(|commit| commit.identifier().to_string())
+            .join(", ");
+        let table_changes = commits
+            .into_iter()
+            .map(|mut commit| CommitTableRequest {
+                identifier: Some(commit.identifier().clone()),
+                requirements: commit.take_requirements(),
+                updates: commit.take_updates(),
+            })
+            .collect();
+
+        let request = context
+            .client
+            .request(Method::POST, context.config.commit_transaction_endpoint())
+            .json(&CommitTransactionRequest { table_changes })
+            .build()?;
+
+        let http_response = context.client.query_catalog(request).await?;
+
+        match http_response.status() {
+            StatusCode::NO_CONTENT | StatusCode::OK => Ok(()),
+            StatusCode::NOT_FOUND => Err(Error::new(
+                ErrorKind::TableNotFound,
+                "Tried to update a table that does not exist",
+            )
+            .with_context("tables", identifiers)),
+            StatusCode::CONFLICT => {
+                let error = Error::new(
+                    ErrorKind::CatalogCommitConflicts,
+                    "CatalogCommitConflicts, one or more requirements failed. No table was updated and the client may retry.",
+                )
+                .with_context("tables", identifiers)
+                .with_retryable(true);
+                let server_error = deserialize_unexpected_catalog_error(http_response).await;
+                Err(error.with_source(server_error))
+            }
+            StatusCode::INTERNAL_SERVER_ERROR
+            | StatusCode::BAD_GATEWAY
+            | StatusCode::GATEWAY_TIMEOUT => Err(Error::new(
+                ErrorKind::Unexpected,
+                "A server-side problem occurred; the commit state is unknown.",
+            )
+            .with_context("tables", identifiers)),
+            _ => Err(deserialize_unexpected_catalog_error(http_response).await),
+        }
+    }
 }
 
 /// All requests and expected responses are derived from the REST catalog API spec:
@@ -648,6 +775,7 @@ impl Catalog for RestCatalog {
         let request = context
             .client
             .request(Method::POST, context.config.tables_endpoint(namespace))
+            .header(ACCESS_DELEGATION_HEADER, VENDED_CREDENTIALS)
             .json(&CreateTableRequest {
                 name: creation.name,
                 location: creation.location,
@@ -685,11 +813,7 @@ impl Catalog for RestCatalog {
             "Metadata location missing in `create_table` response!",
         ))?;
 
-        let config = response
-            .config
-            .into_iter()
-            .chain(self.user_config.props.clone())
-            .collect();
+        let config = self.table_file_io_config(&response);
 
         let file_io = self
             .load_file_io(Some(metadata_location), Some(config))
@@ -712,12 +836,17 @@ impl Catalog for RestCatalog {
     /// If there are any config properties that are present in both the response from the REST
     /// server and the config provided when creating this `RestCatalog` instance, then the value
     /// provided locally to the `RestCatalog` will take precedence.
+    ///
+    /// The server is asked for vended credentials, which take precedence over both when
+    /// building the FileIO of the table. They can be refreshed with
+    /// [`RestCatalog::refresh_expiring_credentials`].
     async fn load_table(&self, table_ident: &TableIdent) -> Result<Table> {
         let context = self.context().await?;
 
         let request = context
             .client
             .request(Method::GET, context.config.table_endpoint(table_ident))
+            .header(ACCESS_DELEGATION_HEADER, VENDED_CREDENTIALS)
             .build()?;
 
         let http_response = context.client.query_catalog(request).await?;
@@ -735,11 +864,7 @@ impl Catalog for RestCatalog {
             _ => return Err(deserialize_unexpected_catalog_error(http_response).await),
         };
 
-        let config = response
-            .config
-            .into_iter()
-            .chain(self.user_config.props.clone())
-            .collect();
+        let config = self.table_file_io_config(&response);
 
         let file_io = self
             .load_file_io(response.metadata_location.as_deref(), Some(config))
@@ -1679,6 +1804,50 @@ mod tests {
         get_ns_mock.assert_async().await;
     }
 
+    #[tokio::test]
+    async fn check_namespace_exists_by_status() {
+        let mut server = Server::new_async().await;
+
+        let config_mock = create_config_mock(&mut server).await;
+
+        let mut head_ns_mocks = vec![];
+        for (namespace, status) in [("ns1", 200), ("ns2", 404), ("ns3", 500)] {
+            head_ns_mocks.push(
+                server
+                    .mock("HEAD", format!("/v1/namespaces/{namespace}").as_str())
+                    .with_status(status)
+                    .create_async()
+                    .await,
+            );
+        }
+
+        let catalog = RestCatalog::new(RestCatalogConfig::builder().uri(server.url()).build());
+
+        assert!(
+            catalog
+                .namespace_exists(&NamespaceIdent::new("ns1".to_string()))
+                .await
+                .unwrap()
+        );
+        assert!(
+            !catalog
+                .namespace_exists(&NamespaceIdent::new("ns2".to_string()))
+                .await
+                .unwrap()
+        );
+        assert!(
+            catalog
+                .namespace_exists(&NamespaceIdent::new("ns3".to_string()))
+                .await
+                .is_err()
+        );
+
+        config_mock.assert_async().await;
+        for head_ns_mock in head_ns_mocks {
+            head_ns_mock.assert_async().await;
+        }
+    }
+
     #[tokio::test]
     async fn test_drop_namespace() {
         let mut server = Server::new_async().await;
@@ -2004,6 +2173,40 @@ mod tests {
         check_table_exists_mock.assert_async().await;
     }
 
+    #[tokio::test]
+    async fn test_check_table_exists_by_status() {
+        let mut server = Server::new_async().await;
+
+        let config_mock = create_config_mock(&mut server).await;
+
+        let mut head_table_mocks = vec![];
+        for (table, status) in [("table1", 200), ("table2", 404), ("table3", 500)] {
+            head_table_mocks.push(
+                server
+                    .mock(
+                        "HEAD",
+                        format!("/v1/namespaces/ns1/tables/{table}").as_str(),
+                    )
+                    .with_status(status)
+                    .create_async()
+                    .await,
+            );
+        }
+
+        let catalog = RestCatalog::new(RestCatalogConfig::builder().uri(server.url()).build());
+        let table_ident =
+            |name: &str| TableIdent::new(NamespaceIdent::new("ns1".to_string()), name.to_string());
+
+        assert!(catalog.table_exists(&table_ident("table1")).await.unwrap());
+        assert!(!catalog.table_exists(&table_ident("table2")).await.unwrap());
+        assert!(catalog.table_exists(&table_ident("table3")).await.is_err());
+
+        config_mock.assert_async().await;
+        for head_table_mock in head_table_mocks {
+            head_table_mock.assert_async().await;
+        }
+    }
+
     #[tokio::test]
     async fn test_rename_table() {
         let mut server = Server::new_async().await;
@@ -2140,6 +2343,102 @@ mod tests {
         rename_table_mock.assert_async().await;
     }
 
+    async fn create_load_table_with_credentials_mock(
+        server: &mut ServerGuard,
+        expires_at_ms: i64,
+        hits: usize,
+    ) -> Mock {
+        let mut body: serde_json::Value = serde_json::from_reader(BufReader::new(
+            File::open(format!(
+                "{}/testdata/{}",
+                env!("CARGO_MANIFEST_DIR"),
+                "load_table_response.json"
+            ))
+            .unwrap(),
+        ))
+        .unwrap();
+        body["storage-credentials"] = json!([
+            {
+                "prefix": "s3://warehouse/",
+                "config": {"s3.access-key-id": "warehouse-key"}
+            },
+            {
+                "prefix": "s3://warehouse/database/table",
+                "config": {
+                    "s3.access-key-id": "table-key",
+                    "s3.secret-access-key": "table-secret",
+                    "s3.session-token": "table-token",
+                    "s3.session-token-expires-at-ms": expires_at_ms.to_string()
+                }
+            }
+        ]);
+
+        server
+            .mock("GET", "/v1/namespaces/ns1/tables/test1")
+            .match_header("x-iceberg-access-delegation", "vended-credentials")
+            .with_status(200)
+            .with_body(body.to_string())
+            .expect(hits)
+            .create_async()
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_load_table_with_vended_credentials() {
+        let mut server = Server::new_async().await;
+
+        let config_mock = create_config_mock(&mut server).await;
+        let expires_at_ms = Utc::now().timestamp_millis() + 60 * 60 * 1000;
+        let load_table_mock =
+            create_load_table_with_credentials_mock(&mut server, expires_at_ms, 2).await;
+
+        let catalog = RestCatalog::new(
+            RestCatalogConfig::builder()
+                .uri(server.url())
+                .props(HashMap::from([(
+                    "s3.access-key-id".to_string(),
+                    "ambient-key".to_string(),
+                )]))
+                .build(),
+        );
+        let table = catalog
+            .load_table(&TableIdent::from_strs(["ns1", "test1"]).unwrap())
+            .await
+            .unwrap();
+
+        let (_, props, _) = table.file_io().clone().into_builder().into_parts();
+        assert_eq!(props.get("s3.access-key-id").unwrap(), "table-key");
+        assert_eq!(props.get("s3.secret-access-key").unwrap(), "table-secret");
+        assert_eq!(props.get("s3.session-token").unwrap(), "table-token");
+
+        // The credentials are valid for another hour, so the table is not reloaded.
+        let table = catalog.refresh_expiring_credentials(table).await.unwrap();
+
+        // Credentials expiring within five minutes are refreshed by reloading the table.
+        let expires_at_ms = Utc::now().timestamp_millis() + 60 * 1000;
+        let (scheme, mut props, extensions) = table.file_io().clone().into_builder().into_parts();
+        props.insert(
+            "s3.session-token-expires-at-ms".to_string(),
+            expires_at_ms.to_string(),
+        );
+        let table = Table::builder()
+            .identifier(table.identifier().clone())
+            .metadata(table.metadata_ref())
+            .file_io(
+                io::FileIOBuilder::new(scheme)
+                    .with_props(props)
+                    .with_extensions(extensions)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        catalog.refresh_expiring_credentials(table).await.unwrap();
+        load_table_mock.assert_async().await;
+
+        config_mock.assert_async().await;
+    }
+
     #[tokio::test]
     async fn test_load_table_404() {
         let mut server = Server::new_async().await;
@@ -2378,6 +2677,117 @@ mod tests {
         create_table_mock.assert_async().await;
     }
 
+    fn table_from_create_response(name: &str) -> Table {
+        let file = File::open(format!(
+            "{}/testdata/{}",
+            env!("CARGO_MANIFEST_DIR"),
+            "create_table_response.json"
+        ))
+        .unwrap();
+        let reader = BufReader::new(file);
+        let resp = serde_json::from_reader::<_, LoadTableResult>(reader).unwrap();
+
+        Table::builder()
+            .metadata(resp.metadata)
+            .metadata_location(resp.metadata_location.unwrap())
+            .identifier(TableIdent::from_strs(["ns1", name]).unwrap())
+            .file_io(FileIO::from_path("/tmp").unwrap().build().unwrap())
+            .build()
+            .unwrap()
+    }
+
+    async fn set_property_commit(table: &Table, key: &str, value: &str) -> TableCommit {
+        let tx = Transaction::new(table);
+        tx.update_table_properties()
+            .set(key.to_string(), value.to_string())
+            .apply(tx)
+            .unwrap()
+            .table_commit()
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_commit_tables() {
+        let mut server = Server::new_async().await;
+
+        let config_mock = create_config_mock(&mut server).await;
+
+        let commit_mock = server
+            .mock("POST", "/v1/transactions/commit")
+            .match_body(mockito::Matcher::Json(json!({
+                "table-changes": [
+                    {
+                        "identifier": {"namespace": ["ns1"], "name": "t1"},
+                        "requirements": [],
+                        "updates": [
+                            {"action": "set-properties", "updates": {"a": "1"}},
+                            {"action": "remove-properties", "removals": []}
+                        ]
+                    },
+                    {
+                        "identifier": {"namespace": ["ns1"], "name": "t2"},
+                        "requirements": [],
+                        "updates": [
+                            {"action": "set-properties", "updates": {"b": "2"}},
+                            {"action": "remove-properties", "removals": []}
+                        ]
+                    }
+                ]
+            })))
+            .with_status(204)
+            .create_async()
+            .await;
+
+        let catalog = RestCatalog::new(RestCatalogConfig::builder().uri(server.url()).build());
+
+        let commits = vec![
+            set_property_commit(&table_from_create_response("t1"), "a", "1").await,
+            set_property_commit(&table_from_create_response("t2"), "b", "2").await,
+        ];
+        catalog.commit_tables(commits).await.unwrap();
+
+        config_mock.assert_async().await;
+        commit_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_commit_tables_conflict() {
+        let mut server = Server::new_async().await;
+
+        let config_mock = create_config_mock(&mut server).await;
+
+        let commit_mock = server
+            .mock("POST", "/v1/transactions/commit")
+            .with_status(409)
+            .with_body(
+                json!({
+                    "error": {
+                        "message": "Requirement failed: current schema changed for table ns1.t2",
+                        "type": "CommitFailedException",
+                        "code": 409
+                    }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let catalog = RestCatalog::new(RestCatalogConfig::builder().uri(server.url()).build());
+
+        let commits = vec![
+            set_property_commit(&table_from_create_response("t1"), "a", "1").await,
+            set_property_commit(&table_from_create_response("t2"), "b", "2").await,
+        ];
+        let err = catalog.commit_tables(commits).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::CatalogCommitConflicts);
+        assert!(err.retryable());
+        assert!(format!("{err:?}").contains("current schema changed for table ns1.t2"));
+
+        config_mock.assert_async().await;
+        commit_mock.assert_async().await;
+    }
+
     #[tokio::test]
     async fn test_update_table() {
         let mut server = Server::new_async().await;
@@ -2390,7 +2800,7 @@ mod tests {
             .with_body_from_file(format!(
                 "{}/testdata/{}",
                 env!("CARGO_MANIFEST_DIR"),
-                "load_table_response.json"
+                "create_table_response.json"
             ))
             .create_async()
             .await;
@@ -2524,7 +2934,7 @@ mod tests {
             .with_body_from_file(format!(
                 "{}/testdata/{}",
                 env!("CARGO_MANIFEST_DIR"),
-                "load_table_response.json"
+                "create_table_response.json"
             ))
             .create_async()
             .await;
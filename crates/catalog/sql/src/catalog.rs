@@ -16,22 +16,28 @@
 // under the License.
 
 use std::collections::{HashMap, HashSet};
+use std::future::Future;
 use std::str::FromStr;
+use std::sync::Arc;
 use std::time::Duration;
 
 use async_trait::async_trait;
 use iceberg::io::FileIO;
-use iceberg::spec::{TableMetadata, TableMetadataBuilder};
+use iceberg::spec::{
+    ListType, MAIN_BRANCH, MapType, NestedField, NestedFieldRef, Schema, SortField, SortOrder,
+    StructType, TableMetadataBuilder, Type, UnboundPartitionField, UnboundPartitionSpec,
+};
 use iceberg::table::Table;
 use iceberg::{
-    Catalog, CatalogBuilder, Error, ErrorKind, MetadataLocation, Namespace, NamespaceIdent, Result,
-    TableCommit, TableCreation, TableIdent,
+    Catalog, CatalogBuilder, Error, ErrorKind, FileIOMetadataStore, MetadataLocation,
+    MetadataStore, Namespace, NamespaceIdent, Result, TableCommit, TableCreation, TableIdent,
 };
 use sqlx::any::{AnyPoolOptions, AnyQueryResult, AnyRow, install_default_drivers};
 use sqlx::{Any, AnyPool, Row, Transaction};
 
 use crate::error::{
-    from_sqlx_error, no_such_namespace_err, no_such_table_err, table_already_exists_err,
+    commit_timeout_err, from_sqlx_error, no_such_namespace_err, no_such_table_err,
+    operation_timeout_err, table_already_exists_err,
 };
 
 /// catalog URI
@@ -40,6 +46,8 @@ pub const SQL_CATALOG_PROP_URI: &str = "uri";
 pub const SQL_CATALOG_PROP_WAREHOUSE: &str = "warehouse";
 /// catalog sql bind style
 pub const SQL_CATALOG_PROP_BIND_STYLE: &str = "sql_bind_style";
+/// catalog operation timeout, in milliseconds
+pub const SQL_CATALOG_PROP_OPERATION_TIMEOUT_MS: &str = "operation_timeout_ms";
 
 static CATALOG_TABLE_NAME: &str = "iceberg_tables";
 static CATALOG_FIELD_CATALOG_NAME: &str = "catalog_name";
@@ -72,7 +80,9 @@ impl Default for SqlCatalogBuilder {
             name: "".to_string(),
             warehouse_location: "".to_string(),
             sql_bind_style: SqlBindStyle::DollarNumeric,
+            operation_timeout: None,
             props: HashMap::new(),
+            metadata_store: None,
         })
     }
 }
@@ -105,6 +115,28 @@ impl SqlCatalogBuilder {
         self
     }
 
+    /// Configure the timeout of a single catalog operation
+    ///
+    /// Loading, creating, dropping and updating a table each fail with an error once they
+    /// take longer than `timeout`. Operations are not bounded by default.
+    ///
+    /// If `SQL_CATALOG_PROP_OPERATION_TIMEOUT_MS` has a value set in `props` during `SqlCatalogBuilder::load`,
+    /// that value takes precedence, and the value specified by this method will not be used.
+    pub fn operation_timeout(mut self, timeout: Duration) -> Self {
+        self.0.operation_timeout = Some(timeout);
+        self
+    }
+
+    /// Keep the table metadata in `metadata_store` instead of metadata files in the
+    /// warehouse.
+    ///
+    /// The catalog database only stores the location of the current metadata of each table,
+    /// which is read from and written to `metadata_store`.
+    pub fn with_metadata_store(mut self, metadata_store: Arc<dyn MetadataStore>) -> Self {
+        self.0.metadata_store = Some(metadata_store);
+        self
+    }
+
     /// Configure the any properties
     ///
     /// If the same key has values set in `props` during `SqlCatalogBuilder::load`,
@@ -158,6 +190,15 @@ impl CatalogBuilder for SqlCatalogBuilder {
             }
         }
 
+        let mut valid_operation_timeout = true;
+        if let Some(timeout_ms) = self.0.props.remove(SQL_CATALOG_PROP_OPERATION_TIMEOUT_MS) {
+            if let Ok(timeout_ms) = timeout_ms.parse::<u64>() {
+                self.0.operation_timeout = Some(Duration::from_millis(timeout_ms));
+            } else {
+                valid_operation_timeout = false;
+            }
+        }
+
         let valid_name = !name.trim().is_empty();
 
         async move {
@@ -176,6 +217,13 @@ impl CatalogBuilder for SqlCatalogBuilder {
                         SqlBindStyle::QMark
                     ),
                 ))
+            } else if !valid_operation_timeout {
+                Err(Error::new(
+                    ErrorKind::DataInvalid,
+                    format!(
+                        "`{SQL_CATALOG_PROP_OPERATION_TIMEOUT_MS}` must be a number of milliseconds"
+                    ),
+                ))
             } else {
                 self.0.name = name;
                 SqlCatalog::new(self.0).await
@@ -198,17 +246,25 @@ struct SqlCatalogConfig {
     name: String,
     warehouse_location: String,
     sql_bind_style: SqlBindStyle,
+    operation_timeout: Option<Duration>,
     props: HashMap<String, String>,
+    metadata_store: Option<Arc<dyn MetadataStore>>,
 }
 
 #[derive(Debug)]
 /// Sql catalog implementation.
+///
+/// The database keeps the pointer from each table to its current metadata location, while
+/// the table metadata is written to metadata files in the warehouse, or to the
+/// [`MetadataStore`] set with [`SqlCatalogBuilder::with_metadata_store`].
 pub struct SqlCatalog {
     name: String,
     connection: AnyPool,
     warehouse_location: String,
     fileio: FileIO,
+    metadata_store: Arc<dyn MetadataStore>,
     sql_bind_style: SqlBindStyle,
+    operation_timeout: Option<Duration>,
 }
 
 #[derive(Debug, PartialEq, strum::EnumString, strum::Display)]
@@ -224,6 +280,9 @@ impl SqlCatalog {
     /// Create new sql catalog instance
     async fn new(config: SqlCatalogConfig) -> Result<Self> {
         let fileio = FileIO::from_path(&config.warehouse_location)?.build()?;
+        let metadata_store = config
+            .metadata_store
+            .unwrap_or_else(|| Arc::new(FileIOMetadataStore::new(fileio.clone())));
         install_default_drivers();
         let max_connections: u32 = config
             .props
@@ -280,7 +339,9 @@ impl SqlCatalog {
             connection: pool,
             warehouse_location: config.warehouse_location,
             fileio,
+            metadata_store,
             sql_bind_style: config.sql_bind_style,
+            operation_timeout: config.operation_timeout,
         })
     }
 
@@ -321,6 +382,26 @@ impl SqlCatalog {
             .map_err(from_sqlx_error)
     }
 
+    /// Fetch rows from a query within the transaction `transaction`
+    async fn fetch_rows_in_transaction(
+        &self,
+        query: &str,
+        args: Vec<Option<&str>>,
+        transaction: &mut Transaction<'_, Any>,
+    ) -> Result<Vec<AnyRow>> {
+        let query_with_placeholders = self.replace_placeholders(query);
+
+        let mut sqlx_query = sqlx::query(&query_with_placeholders);
+        for arg in args {
+            sqlx_query = sqlx_query.bind(arg);
+        }
+
+        sqlx_query
+            .fetch_all(&mut **transaction)
+            .await
+            .map_err(from_sqlx_error)
+    }
+
     /// Execute statements in a transaction, provided or not
     async fn execute(
         &self,
@@ -345,445 +426,362 @@ impl SqlCatalog {
             }
         }
     }
-}
 
-#[async_trait]
-impl Catalog for SqlCatalog {
-    async fn list_namespaces(
+    /// Run a catalog operation, failing it once the configured operation timeout elapses.
+    async fn with_timeout<T>(
         &self,
-        parent: Option<&NamespaceIdent>,
-    ) -> Result<Vec<NamespaceIdent>> {
-        // UNION will remove duplicates.
-        let all_namespaces_stmt = format!(
-            "SELECT {CATALOG_FIELD_TABLE_NAMESPACE}
-             FROM {CATALOG_TABLE_NAME}
-             WHERE {CATALOG_FIELD_CATALOG_NAME} = ?
-             UNION
-             SELECT {NAMESPACE_FIELD_NAME}
-             FROM {NAMESPACE_TABLE_NAME}
-             WHERE {CATALOG_FIELD_CATALOG_NAME} = ?"
-        );
-
-        let namespace_rows = self
-            .fetch_rows(&all_namespaces_stmt, vec![
-                Some(&self.name),
-                Some(&self.name),
-            ])
-            .await?;
-
-        let mut namespaces = HashSet::<NamespaceIdent>::with_capacity(namespace_rows.len());
+        operation: &str,
+        future: impl Future<Output = Result<T>>,
+    ) -> Result<T> {
+        match self.operation_timeout {
+            Some(timeout) => tokio::time::timeout(timeout, future)
+                .await
+                .unwrap_or_else(|_| operation_timeout_err(operation, timeout)),
+            None => future.await,
+        }
+    }
 
-        if let Some(parent) = parent {
-            if self.namespace_exists(parent).await? {
-                let parent_str = parent.join(".");
+    /// Run a catalog operation that writes a new metadata file, removing the file if the
+    /// operation fails or times out before the table points at it. A timeout is reported as
+    /// a non-retryable error, since the commit may still have been applied.
+    ///
+    /// `operation` is given the slot to record the metadata location in before it starts
+    /// writing, so the file can be found even when the operation is aborted midway.
+    async fn with_metadata_cleanup<T>(
+        &self,
+        name: &str,
+        table_ident: &TableIdent,
+        operation: impl AsyncFnOnce(&mut Option<String>) -> Result<T>,
+    ) -> Result<T> {
+        let mut metadata_location = None;
+        let future = operation(&mut metadata_location);
+        let result = match self.operation_timeout {
+            Some(timeout) => tokio::time::timeout(timeout, future)
+                .await
+                .unwrap_or_else(|_| commit_timeout_err(name, timeout)),
+            None => future.await,
+        };
+        if result.is_err()
+            && let Some(metadata_location) = metadata_location
+        {
+            self.remove_uncommitted_metadata(table_ident, &metadata_location)
+                .await;
+        }
+        result
+    }
 
-                for row in namespace_rows.iter() {
-                    let nsp = row.try_get::<String, _>(0).map_err(from_sqlx_error)?;
-                    // if parent = a, then we only want to see a.b, a.c returned.
-                    if nsp != parent_str && nsp.starts_with(&parent_str) {
-                        namespaces.insert(NamespaceIdent::from_strs(nsp.split("."))?);
-                    }
-                }
+    /// Remove a metadata file written by a failed operation.
+    ///
+    /// An aborted SQL statement may still have been applied by the database, so the file
+    /// is only removed once the catalog is known not to reference it. Otherwise, or if the
+    /// removal fails, the file is left behind.
+    async fn remove_uncommitted_metadata(&self, table_ident: &TableIdent, metadata_location: &str) {
+        let current_metadata_location = self
+            .with_timeout(
+                "remove_uncommitted_metadata",
+                self.fetch_metadata_location(table_ident),
+            )
+            .await;
+        if let Ok(current_metadata_location) = current_metadata_location
+            && current_metadata_location.as_deref() != Some(metadata_location)
+        {
+            let _ = self
+                .with_timeout(
+                    "remove_uncommitted_metadata",
+                    self.metadata_store.delete(metadata_location),
+                )
+                .await;
+        }
+    }
 
-                Ok(namespaces.into_iter().collect::<Vec<NamespaceIdent>>())
-            } else {
-                no_such_namespace_err(parent)
-            }
-        } else {
-            for row in namespace_rows.iter() {
-                let nsp = row.try_get::<String, _>(0).map_err(from_sqlx_error)?;
-                let mut levels = nsp.split(".").collect::<Vec<&str>>();
-                if !levels.is_empty() {
-                    let first_level = levels.drain(..1).collect::<Vec<&str>>();
-                    namespaces.insert(NamespaceIdent::from_strs(first_level)?);
-                }
-            }
+    /// Fetch the metadata location the table points at, if the table exists.
+    async fn fetch_metadata_location(&self, identifier: &TableIdent) -> Result<Option<String>> {
+        let rows = self
+            .fetch_rows(
+                &format!(
+                    "SELECT {CATALOG_FIELD_METADATA_LOCATION_PROP}
+                     FROM {CATALOG_TABLE_NAME}
+                     WHERE {CATALOG_FIELD_CATALOG_NAME} = ?
+                      AND {CATALOG_FIELD_TABLE_NAME} = ?
+                      AND {CATALOG_FIELD_TABLE_NAMESPACE} = ?
+                      AND (
+                        {CATALOG_FIELD_RECORD_TYPE} = '{CATALOG_FIELD_TABLE_RECORD_TYPE}' 
+                        OR {CATALOG_FIELD_RECORD_TYPE} IS NULL
+                      )"
+                ),
+                vec![
+                    Some(&self.name),
+                    Some(identifier.name()),
+                    Some(&identifier.namespace().join(".")),
+                ],
+            )
+            .await?;
 
-            Ok(namespaces.into_iter().collect::<Vec<NamespaceIdent>>())
-        }
+        rows.first()
+            .map(|row| {
+                row.try_get::<String, _>(CATALOG_FIELD_METADATA_LOCATION_PROP)
+                    .map_err(from_sqlx_error)
+            })
+            .transpose()
     }
 
-    async fn create_namespace(
+    /// Lists a page of at most `page_size` tables of `namespace`, ordered by name.
+    ///
+    /// The listing starts after the table named by `page_token`, or at the first table when
+    /// no token is given. The returned token continues the listing on the next call and is
+    /// `None` once the last page has been returned. Views are not listed.
+    pub async fn list_tables_page(
         &self,
         namespace: &NamespaceIdent,
-        properties: HashMap<String, String>,
-    ) -> Result<Namespace> {
-        let exists = self.namespace_exists(namespace).await?;
-
-        if exists {
+        page_size: usize,
+        page_token: Option<&str>,
+    ) -> Result<(Vec<TableIdent>, Option<String>)> {
+        if page_size == 0 {
             return Err(Error::new(
-                iceberg::ErrorKind::Unexpected,
-                format!("Namespace {namespace:?} already exists"),
+                ErrorKind::DataInvalid,
+                "Page size of a table listing must be positive",
             ));
         }
+        if !self.namespace_exists(namespace).await? {
+            return no_such_namespace_err(namespace);
+        }
 
-        let namespace_str = namespace.join(".");
-        let insert = format!(
-            "INSERT INTO {NAMESPACE_TABLE_NAME} ({CATALOG_FIELD_CATALOG_NAME}, {NAMESPACE_FIELD_NAME}, {NAMESPACE_FIELD_PROPERTY_KEY}, {NAMESPACE_FIELD_PROPERTY_VALUE})
-             VALUES (?, ?, ?, ?)");
-        if !properties.is_empty() {
-            let mut insert_properties = properties.clone();
-            insert_properties.insert("exists".to_string(), "true".to_string());
-
-            let mut query_args = Vec::with_capacity(insert_properties.len() * 4);
-            let mut insert_stmt = insert.clone();
-            for (index, (key, value)) in insert_properties.iter().enumerate() {
-                query_args.extend_from_slice(&[
-                    Some(self.name.as_str()),
-                    Some(namespace_str.as_str()),
-                    Some(key.as_str()),
-                    Some(value.as_str()),
-                ]);
-                if index > 0 {
-                    insert_stmt.push_str(", (?, ?, ?, ?)");
-                }
-            }
+        // Fetch one more table than requested to know whether another page follows.
+        let mut tables = self
+            .fetch_table_idents(namespace, Some(page_size + 1), page_token)
+            .await?;
+        let next_page_token = if tables.len() > page_size {
+            tables.truncate(page_size);
+            tables.last().map(|table| table.name().to_string())
+        } else {
+            None
+        };
 
-            self.execute(&insert_stmt, query_args, None).await?;
+        Ok((tables, next_page_token))
+    }
 
-            Ok(Namespace::with_properties(
-                namespace.clone(),
-                insert_properties,
-            ))
-        } else {
-            // set a default property of exists = true
-            self.execute(
-                &insert,
-                vec![
-                    Some(&self.name),
-                    Some(&namespace_str),
-                    Some("exists"),
-                    Some("true"),
-                ],
-                None,
-            )
-            .await?;
-            Ok(Namespace::with_properties(namespace.clone(), properties))
+    /// Fetch the identifiers of the tables in `namespace` ordered by name, skipping views.
+    ///
+    /// Only the tables named after `after` are fetched, at most `limit` of them.
+    async fn fetch_table_idents(
+        &self,
+        namespace: &NamespaceIdent,
+        limit: Option<usize>,
+        after: Option<&str>,
+    ) -> Result<Vec<TableIdent>> {
+        let namespace_str = namespace.join(".");
+        let mut args = vec![Some(namespace_str.as_str()), Some(self.name.as_str())];
+        let mut query = format!(
+            "SELECT {CATALOG_FIELD_TABLE_NAME}
+             FROM {CATALOG_TABLE_NAME}
+             WHERE {CATALOG_FIELD_TABLE_NAMESPACE} = ?
+              AND {CATALOG_FIELD_CATALOG_NAME} = ?
+              AND (
+                    {CATALOG_FIELD_RECORD_TYPE} = '{CATALOG_FIELD_TABLE_RECORD_TYPE}'
+                    OR {CATALOG_FIELD_RECORD_TYPE} IS NULL
+              )"
+        );
+        if let Some(after) = after {
+            query.push_str(&format!(" AND {CATALOG_FIELD_TABLE_NAME} > ?"));
+            args.push(Some(after));
+        }
+        query.push_str(&format!(" ORDER BY {CATALOG_FIELD_TABLE_NAME}"));
+        if let Some(limit) = limit {
+            query.push_str(&format!(" LIMIT {limit}"));
         }
+
+        let rows = self.fetch_rows(&query, args).await?;
+        rows.iter()
+            .map(|row| {
+                let name = row
+                    .try_get::<String, _>(CATALOG_FIELD_TABLE_NAME)
+                    .map_err(from_sqlx_error)?;
+                Ok(TableIdent::new(namespace.clone(), name))
+            })
+            .collect()
     }
 
-    async fn get_namespace(&self, namespace: &NamespaceIdent) -> Result<Namespace> {
-        let exists = self.namespace_exists(namespace).await?;
-        if exists {
-            let namespace_props = self
-                .fetch_rows(
+    /// Drop `namespace`, and when `cascade` is set, everything it contains.
+    ///
+    /// Without `cascade`, dropping a namespace that contains tables, views or nested
+    /// namespaces fails. With `cascade`, the namespaces nested under
+    /// `namespace` are dropped as well, along with the tables and views of all of them. As
+    /// with [`Catalog::drop_table`], dropped tables and views are only removed from the
+    /// catalog, their metadata and data files are kept.
+    pub async fn drop_namespace_with_cascade(
+        &self,
+        namespace: &NamespaceIdent,
+        cascade: bool,
+    ) -> Result<()> {
+        self.with_timeout("drop_namespace", async {
+            if !self.namespace_exists(namespace).await? {
+                return no_such_namespace_err(namespace);
+            }
+
+            // The namespace is checked for entries in the transaction deleting it, so that
+            // entries created concurrently are either seen or not dropped.
+            let namespace_str = namespace.join(".");
+            let mut tx = self.connection.begin().await.map_err(from_sqlx_error)?;
+            let dropped_namespaces = self
+                .fetch_nested_namespaces(&namespace_str, &mut tx)
+                .await?;
+            if !cascade {
+                let (tables, views) = self
+                    .fetch_namespace_entries(&namespace_str, &mut tx)
+                    .await?;
+                if !tables.is_empty() || !views.is_empty() {
+                    return Err(Error::new(
+                        ErrorKind::Unexpected,
+                        format!(
+                            "Namespace {namespace:?} is not empty. Tables: [{}]. Views: [{}].",
+                            tables.join(", "),
+                            views.join(", ")
+                        ),
+                    ));
+                }
+                let children: Vec<&str> = dropped_namespaces
+                    .iter()
+                    .map(String::as_str)
+                    .filter(|name| *name != namespace_str)
+                    .collect();
+                if !children.is_empty() {
+                    return Err(Error::new(
+                        ErrorKind::Unexpected,
+                        format!(
+                            "Namespace {namespace:?} is not empty. Namespaces: [{}].",
+                            children.join(", ")
+                        ),
+                    ));
+                }
+            }
+
+            for dropped_namespace in &dropped_namespaces {
+                self.execute(
                     &format!(
-                        "SELECT
-                            {NAMESPACE_FIELD_NAME},
-                            {NAMESPACE_FIELD_PROPERTY_KEY},
-                            {NAMESPACE_FIELD_PROPERTY_VALUE}
-                            FROM {NAMESPACE_TABLE_NAME}
-                            WHERE {CATALOG_FIELD_CATALOG_NAME} = ?
-                            AND {NAMESPACE_FIELD_NAME} = ?"
+                        "DELETE FROM {CATALOG_TABLE_NAME}
+                         WHERE {CATALOG_FIELD_TABLE_NAMESPACE} = ?
+                          AND {CATALOG_FIELD_CATALOG_NAME} = ?"
                     ),
-                    vec![Some(&self.name), Some(&namespace.join("."))],
+                    vec![Some(dropped_namespace), Some(&self.name)],
+                    Some(&mut tx),
+                )
+                .await?;
+                self.execute(
+                    &format!(
+                        "DELETE FROM {NAMESPACE_TABLE_NAME}
+                         WHERE {NAMESPACE_FIELD_NAME} = ?
+                          AND {CATALOG_FIELD_CATALOG_NAME} = ?"
+                    ),
+                    vec![Some(dropped_namespace), Some(&self.name)],
+                    Some(&mut tx),
                 )
                 .await?;
+            }
+            tx.commit().await.map_err(from_sqlx_error)?;
 
-            let mut properties = HashMap::with_capacity(namespace_props.len());
+            Ok(())
+        })
+        .await
+    }
 
-            for row in namespace_props {
-                let key = row
-                    .try_get::<String, _>(NAMESPACE_FIELD_PROPERTY_KEY)
-                    .map_err(from_sqlx_error)?;
-                let value = row
-                    .try_get::<String, _>(NAMESPACE_FIELD_PROPERTY_VALUE)
-                    .map_err(from_sqlx_error)?;
+    /// Fetch the names of the tables and of the views in the namespace `namespace_str`,
+    /// ordered by name.
+    async fn fetch_namespace_entries(
+        &self,
+        namespace_str: &str,
+        transaction: &mut Transaction<'_, Any>,
+    ) -> Result<(Vec<String>, Vec<String>)> {
+        let rows = self
+            .fetch_rows_in_transaction(
+                &format!(
+                    "SELECT {CATALOG_FIELD_TABLE_NAME}, {CATALOG_FIELD_RECORD_TYPE}
+                     FROM {CATALOG_TABLE_NAME}
+                     WHERE {CATALOG_FIELD_TABLE_NAMESPACE} = ?
+                      AND {CATALOG_FIELD_CATALOG_NAME} = ?
+                     ORDER BY {CATALOG_FIELD_TABLE_NAME}"
+                ),
+                vec![Some(namespace_str), Some(&self.name)],
+                transaction,
+            )
+            .await?;
 
-                properties.insert(key, value);
+        let mut tables = vec![];
+        let mut views = vec![];
+        for row in &rows {
+            let name = row
+                .try_get::<String, _>(CATALOG_FIELD_TABLE_NAME)
+                .map_err(from_sqlx_error)?;
+            let record_type = row
+                .try_get::<Option<String>, _>(CATALOG_FIELD_RECORD_TYPE)
+                .map_err(from_sqlx_error)?;
+            match record_type {
+                Some(record_type) if record_type != CATALOG_FIELD_TABLE_RECORD_TYPE => {
+                    views.push(name)
+                }
+                _ => tables.push(name),
             }
-
-            Ok(Namespace::with_properties(namespace.clone(), properties))
-        } else {
-            no_such_namespace_err(namespace)
         }
+        Ok((tables, views))
     }
 
-    async fn namespace_exists(&self, namespace: &NamespaceIdent) -> Result<bool> {
-        let namespace_str = namespace.join(".");
-
-        let table_namespaces = self
-            .fetch_rows(
-                &format!(
-                    "SELECT 1 FROM {CATALOG_TABLE_NAME}
-                     WHERE {CATALOG_FIELD_CATALOG_NAME} = ?
-                      AND {CATALOG_FIELD_TABLE_NAMESPACE} = ?
-                     LIMIT 1"
-                ),
-                vec![Some(&self.name), Some(&namespace_str)],
-            )
-            .await?;
-
-        if !table_namespaces.is_empty() {
-            Ok(true)
-        } else {
-            let namespaces = self
-                .fetch_rows(
-                    &format!(
-                        "SELECT 1 FROM {NAMESPACE_TABLE_NAME}
-                         WHERE {CATALOG_FIELD_CATALOG_NAME} = ?
-                          AND {NAMESPACE_FIELD_NAME} = ?
-                         LIMIT 1"
-                    ),
-                    vec![Some(&self.name), Some(&namespace_str)],
-                )
-                .await?;
-            if !namespaces.is_empty() {
-                Ok(true)
-            } else {
-                Ok(false)
-            }
-        }
-    }
-
-    async fn update_namespace(
+    /// Fetch the namespace `namespace_str` and the namespaces nested under it, ordered by name.
+    async fn fetch_nested_namespaces(
         &self,
-        namespace: &NamespaceIdent,
-        properties: HashMap<String, String>,
-    ) -> Result<()> {
-        let exists = self.namespace_exists(namespace).await?;
-        if exists {
-            let existing_properties = self.get_namespace(namespace).await?.properties().clone();
-            let namespace_str = namespace.join(".");
-
-            let mut updates = vec![];
-            let mut inserts = vec![];
-
-            for (key, value) in properties.iter() {
-                if existing_properties.contains_key(key) {
-                    if existing_properties.get(key) != Some(value) {
-                        updates.push((key, value));
-                    }
-                } else {
-                    inserts.push((key, value));
-                }
-            }
-
-            let mut tx = self.connection.begin().await.map_err(from_sqlx_error)?;
-            let update_stmt = format!(
-                "UPDATE {NAMESPACE_TABLE_NAME} SET {NAMESPACE_FIELD_PROPERTY_VALUE} = ?
-                 WHERE {CATALOG_FIELD_CATALOG_NAME} = ? 
-                 AND {NAMESPACE_FIELD_NAME} = ?
-                 AND {NAMESPACE_FIELD_PROPERTY_KEY} = ?"
-            );
-
-            let insert_stmt = format!(
-                "INSERT INTO {NAMESPACE_TABLE_NAME} ({CATALOG_FIELD_CATALOG_NAME}, {NAMESPACE_FIELD_NAME}, {NAMESPACE_FIELD_PROPERTY_KEY}, {NAMESPACE_FIELD_PROPERTY_VALUE})
-                 VALUES (?, ?, ?, ?)"
-            );
-
-            for (key, value) in updates {
-                self.execute(
-                    &update_stmt,
-                    vec![
-                        Some(value),
-                        Some(&self.name),
-                        Some(&namespace_str),
-                        Some(key),
-                    ],
-                    Some(&mut tx),
-                )
-                .await?;
-            }
-
-            for (key, value) in inserts {
-                self.execute(
-                    &insert_stmt,
-                    vec![
-                        Some(&self.name),
-                        Some(&namespace_str),
-                        Some(key),
-                        Some(value),
-                    ],
-                    Some(&mut tx),
-                )
-                .await?;
-            }
-
-            let _ = tx.commit().await.map_err(from_sqlx_error)?;
-
-            Ok(())
-        } else {
-            no_such_namespace_err(namespace)
-        }
-    }
-
-    async fn drop_namespace(&self, namespace: &NamespaceIdent) -> Result<()> {
-        let exists = self.namespace_exists(namespace).await?;
-        if exists {
-            // if there are tables in the namespace, don't allow drop.
-            let tables = self.list_tables(namespace).await?;
-            if !tables.is_empty() {
-                return Err(Error::new(
-                    iceberg::ErrorKind::Unexpected,
-                    format!(
-                        "Namespace {:?} is not empty. {} tables exist.",
-                        namespace,
-                        tables.len()
-                    ),
-                ));
-            }
-
-            self.execute(
-                &format!(
-                    "DELETE FROM {NAMESPACE_TABLE_NAME}
-                     WHERE {NAMESPACE_FIELD_NAME} = ?
-                      AND {CATALOG_FIELD_CATALOG_NAME} = ?"
-                ),
-                vec![Some(&namespace.join(".")), Some(&self.name)],
-                None,
-            )
-            .await?;
-
-            Ok(())
-        } else {
-            no_such_namespace_err(namespace)
-        }
-    }
-
-    async fn list_tables(&self, namespace: &NamespaceIdent) -> Result<Vec<TableIdent>> {
-        let exists = self.namespace_exists(namespace).await?;
-        if exists {
-            let rows = self
-                .fetch_rows(
-                    &format!(
-                        "SELECT {CATALOG_FIELD_TABLE_NAME},
-                                {CATALOG_FIELD_TABLE_NAMESPACE}
-                         FROM {CATALOG_TABLE_NAME}
-                         WHERE {CATALOG_FIELD_TABLE_NAMESPACE} = ?
-                          AND {CATALOG_FIELD_CATALOG_NAME} = ?
-                          AND (
-                                {CATALOG_FIELD_RECORD_TYPE} = '{CATALOG_FIELD_TABLE_RECORD_TYPE}' 
-                                OR {CATALOG_FIELD_RECORD_TYPE} IS NULL
-                          )",
-                    ),
-                    vec![Some(&namespace.join(".")), Some(&self.name)],
-                )
-                .await?;
-
-            let mut tables = HashSet::<TableIdent>::with_capacity(rows.len());
-
-            for row in rows.iter() {
-                let tbl = row
-                    .try_get::<String, _>(CATALOG_FIELD_TABLE_NAME)
-                    .map_err(from_sqlx_error)?;
-                let ns_strs = row
-                    .try_get::<String, _>(CATALOG_FIELD_TABLE_NAMESPACE)
-                    .map_err(from_sqlx_error)?;
-                let ns = NamespaceIdent::from_strs(ns_strs.split("."))?;
-                tables.insert(TableIdent::new(ns, tbl));
-            }
-
-            Ok(tables.into_iter().collect::<Vec<TableIdent>>())
-        } else {
-            no_such_namespace_err(namespace)
-        }
-    }
-
-    async fn table_exists(&self, identifier: &TableIdent) -> Result<bool> {
-        let namespace = identifier.namespace().join(".");
-        let table_name = identifier.name();
-        let table_counts = self
-            .fetch_rows(
-                &format!(
-                    "SELECT 1
-                     FROM {CATALOG_TABLE_NAME}
-                     WHERE {CATALOG_FIELD_TABLE_NAMESPACE} = ?
-                      AND {CATALOG_FIELD_CATALOG_NAME} = ?
-                      AND {CATALOG_FIELD_TABLE_NAME} = ?
-                      AND (
-                        {CATALOG_FIELD_RECORD_TYPE} = '{CATALOG_FIELD_TABLE_RECORD_TYPE}' 
-                        OR {CATALOG_FIELD_RECORD_TYPE} IS NULL
-                      )"
-                ),
-                vec![Some(&namespace), Some(&self.name), Some(table_name)],
-            )
-            .await?;
-
-        if !table_counts.is_empty() {
-            Ok(true)
-        } else {
-            Ok(false)
-        }
-    }
-
-    async fn drop_table(&self, identifier: &TableIdent) -> Result<()> {
-        if !self.table_exists(identifier).await? {
-            return no_such_table_err(identifier);
-        }
-
-        self.execute(
-            &format!(
-                "DELETE FROM {CATALOG_TABLE_NAME}
-                 WHERE {CATALOG_FIELD_CATALOG_NAME} = ?
-                  AND {CATALOG_FIELD_TABLE_NAME} = ?
-                  AND {CATALOG_FIELD_TABLE_NAMESPACE} = ?
-                  AND (
-                    {CATALOG_FIELD_RECORD_TYPE} = '{CATALOG_FIELD_TABLE_RECORD_TYPE}' 
-                    OR {CATALOG_FIELD_RECORD_TYPE} IS NULL
-                  )"
-            ),
-            vec![
-                Some(&self.name),
-                Some(identifier.name()),
-                Some(&identifier.namespace().join(".")),
-            ],
-            None,
-        )
-        .await?;
-
-        Ok(())
-    }
-
-    async fn load_table(&self, identifier: &TableIdent) -> Result<Table> {
-        if !self.table_exists(identifier).await? {
-            return no_such_table_err(identifier);
-        }
-
+        namespace_str: &str,
+        transaction: &mut Transaction<'_, Any>,
+    ) -> Result<Vec<String>> {
         let rows = self
-            .fetch_rows(
+            .fetch_rows_in_transaction(
                 &format!(
-                    "SELECT {CATALOG_FIELD_METADATA_LOCATION_PROP}
+                    "SELECT {CATALOG_FIELD_TABLE_NAMESPACE}
                      FROM {CATALOG_TABLE_NAME}
                      WHERE {CATALOG_FIELD_CATALOG_NAME} = ?
-                      AND {CATALOG_FIELD_TABLE_NAME} = ?
-                      AND {CATALOG_FIELD_TABLE_NAMESPACE} = ?
-                      AND (
-                        {CATALOG_FIELD_RECORD_TYPE} = '{CATALOG_FIELD_TABLE_RECORD_TYPE}' 
-                        OR {CATALOG_FIELD_RECORD_TYPE} IS NULL
-                      )"
+                     UNION
+                     SELECT {NAMESPACE_FIELD_NAME}
+                     FROM {NAMESPACE_TABLE_NAME}
+                     WHERE {CATALOG_FIELD_CATALOG_NAME} = ?"
                 ),
-                vec![
-                    Some(&self.name),
-                    Some(identifier.name()),
-                    Some(&identifier.namespace().join(".")),
-                ],
+                vec![Some(&self.name), Some(&self.name)],
+                transaction,
             )
             .await?;
 
-        if rows.is_empty() {
-            return no_such_table_err(identifier);
+        let nested_prefix = format!("{namespace_str}.");
+        let mut namespaces = vec![];
+        for row in &rows {
+            let name = row.try_get::<String, _>(0).map_err(from_sqlx_error)?;
+            if name == namespace_str || name.starts_with(&nested_prefix) {
+                namespaces.push(name);
+            }
         }
+        namespaces.sort();
+        Ok(namespaces)
+    }
+
+    /// The location of a table created without one: the table name under the `location`
+    /// property of its namespace, or when the namespace has none, under the namespace path
+    /// in the warehouse location.
+    async fn default_table_location(&self, tbl_ident: &TableIdent) -> Result<String> {
+        let namespace = tbl_ident.namespace();
+        let nsp_properties = self.get_namespace(namespace).await?.properties().clone();
+        let nsp_location = match nsp_properties.get(NAMESPACE_LOCATION_PROPERTY_KEY) {
+            Some(location) => location.trim_end_matches('/').to_string(),
+            None => format!(
+                "{}/{}",
+                self.warehouse_location.trim_end_matches('/'),
+                namespace.join("/")
+            ),
+        };
 
-        let row = &rows[0];
-        let tbl_metadata_location = row
-            .try_get::<String, _>(CATALOG_FIELD_METADATA_LOCATION_PROP)
-            .map_err(from_sqlx_error)?;
-
-        let metadata = TableMetadata::read_from(&self.fileio, &tbl_metadata_location).await?;
-
-        Ok(Table::builder()
-            .file_io(self.fileio.clone())
-            .identifier(identifier.clone())
-            .metadata_location(tbl_metadata_location)
-            .metadata(metadata)
-            .build()?)
+        Ok(format!("{}/{}", nsp_location, tbl_ident.name()))
     }
 
-    async fn create_table(
+    /// Write the metadata of a new table and insert the table into the catalog.
+    async fn insert_new_table(
         &self,
         namespace: &NamespaceIdent,
         creation: TableCreation,
+        written_metadata_location: &mut Option<String>,
     ) -> Result<Table> {
         if !self.namespace_exists(namespace).await? {
             return no_such_namespace_err(namespace);
@@ -799,21 +797,7 @@ impl Catalog for SqlCatalog {
         let (tbl_creation, location) = match creation.location.clone() {
             Some(location) => (creation, location),
             None => {
-                // fall back to namespace-specific location
-                // and then to warehouse location
-                let nsp_properties = self.get_namespace(namespace).await?.properties().clone();
-                let nsp_location = match nsp_properties.get(NAMESPACE_LOCATION_PROPERTY_KEY) {
-                    Some(location) => location.clone(),
-                    None => {
-                        format!(
-                            "{}/{}",
-                            self.warehouse_location.clone(),
-                            namespace.join("/")
-                        )
-                    }
-                };
-
-                let tbl_location = format!("{}/{}", nsp_location, tbl_ident.name());
+                let tbl_location = self.default_table_location(&tbl_ident).await?;
 
                 (
                     TableCreation {
@@ -831,8 +815,9 @@ impl Catalog for SqlCatalog {
         let tbl_metadata_location =
             MetadataLocation::new_with_table_location(location.clone()).to_string();
 
-        tbl_metadata
-            .write_to(&self.fileio, &tbl_metadata_location)
+        *written_metadata_location = Some(tbl_metadata_location.clone());
+        self.metadata_store
+            .write(&tbl_metadata_location, &tbl_metadata)
             .await?;
 
         self.execute(&format!(
@@ -841,99 +826,22 @@ impl Catalog for SqlCatalog {
              VALUES (?, ?, ?, ?, ?)
             "), vec![Some(&self.name), Some(&namespace.join(".")), Some(&tbl_name.clone()), Some(&tbl_metadata_location), Some(CATALOG_FIELD_TABLE_RECORD_TYPE)], None).await?;
 
-        Ok(Table::builder()
+        Table::builder()
             .file_io(self.fileio.clone())
             .metadata_location(tbl_metadata_location)
             .identifier(tbl_ident)
             .metadata(tbl_metadata)
-            .build()?)
+            .build()
     }
 
-    async fn rename_table(&self, src: &TableIdent, dest: &TableIdent) -> Result<()> {
-        if src == dest {
-            return Ok(());
-        }
-
-        if !self.table_exists(src).await? {
-            return no_such_table_err(src);
-        }
-
-        if !self.namespace_exists(dest.namespace()).await? {
-            return no_such_namespace_err(dest.namespace());
-        }
-
-        if self.table_exists(dest).await? {
-            return table_already_exists_err(dest);
-        }
-
-        self.execute(
-            &format!(
-                "UPDATE {CATALOG_TABLE_NAME}
-                 SET {CATALOG_FIELD_TABLE_NAME} = ?, {CATALOG_FIELD_TABLE_NAMESPACE} = ?
-                 WHERE {CATALOG_FIELD_CATALOG_NAME} = ?
-                  AND {CATALOG_FIELD_TABLE_NAME} = ?
-                  AND {CATALOG_FIELD_TABLE_NAMESPACE} = ?
-                  AND (
-                    {CATALOG_FIELD_RECORD_TYPE} = '{CATALOG_FIELD_TABLE_RECORD_TYPE}'
-                    OR {CATALOG_FIELD_RECORD_TYPE} IS NULL
-                )"
-            ),
-            vec![
-                Some(dest.name()),
-                Some(&dest.namespace().join(".")),
-                Some(&self.name),
-                Some(src.name()),
-                Some(&src.namespace().join(".")),
-            ],
-            None,
-        )
-        .await?;
-
-        Ok(())
-    }
-
-    async fn register_table(
+    /// Point the table at a new metadata file, provided it still points at
+    /// `current_metadata_location`.
+    async fn swap_metadata_location(
         &self,
         table_ident: &TableIdent,
-        metadata_location: String,
-    ) -> Result<Table> {
-        if self.table_exists(table_ident).await? {
-            return table_already_exists_err(table_ident);
-        }
-
-        let metadata = TableMetadata::read_from(&self.fileio, &metadata_location).await?;
-
-        let namespace = table_ident.namespace();
-        let tbl_name = table_ident.name().to_string();
-
-        self.execute(&format!(
-            "INSERT INTO {CATALOG_TABLE_NAME}
-             ({CATALOG_FIELD_CATALOG_NAME}, {CATALOG_FIELD_TABLE_NAMESPACE}, {CATALOG_FIELD_TABLE_NAME}, {CATALOG_FIELD_METADATA_LOCATION_PROP}, {CATALOG_FIELD_RECORD_TYPE})
-             VALUES (?, ?, ?, ?, ?)
-            "), vec![Some(&self.name), Some(&namespace.join(".")), Some(&tbl_name), Some(&metadata_location), Some(CATALOG_FIELD_TABLE_RECORD_TYPE)], None).await?;
-
-        Ok(Table::builder()
-            .identifier(table_ident.clone())
-            .metadata_location(metadata_location)
-            .metadata(metadata)
-            .file_io(self.fileio.clone())
-            .build()?)
-    }
-
-    /// Updates an existing table within the SQL catalog.
-    async fn update_table(&self, commit: TableCommit) -> Result<Table> {
-        let table_ident = commit.identifier().clone();
-        let current_table = self.load_table(&table_ident).await?;
-        let current_metadata_location = current_table.metadata_location_result()?.to_string();
-
-        let staged_table = commit.apply(current_table)?;
-        let staged_metadata_location = staged_table.metadata_location_result()?;
-
-        staged_table
-            .metadata()
-            .write_to(staged_table.file_io(), &staged_metadata_location)
-            .await?;
-
+        current_metadata_location: &str,
+        new_metadata_location: &str,
+    ) -> Result<()> {
         let update_result = self
             .execute(
                 &format!(
@@ -949,12 +857,12 @@ impl Catalog for SqlCatalog {
                       AND {CATALOG_FIELD_METADATA_LOCATION_PROP} = ?"
                 ),
                 vec![
-                    Some(staged_metadata_location),
-                    Some(current_metadata_location.as_str()),
+                    Some(new_metadata_location),
+                    Some(current_metadata_location),
                     Some(&self.name),
                     Some(table_ident.name()),
                     Some(&table_ident.namespace().join(".")),
-                    Some(current_metadata_location.as_str()),
+                    Some(current_metadata_location),
                 ],
                 None,
             )
@@ -968,779 +876,2180 @@ impl Catalog for SqlCatalog {
             .with_retryable(true));
         }
 
-        Ok(staged_table)
+        Ok(())
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use std::collections::{HashMap, HashSet};
-    use std::hash::Hash;
+    /// Create a table, or replace the definition of an existing one, as
+    /// `CREATE OR REPLACE TABLE` does.
+    ///
+    /// If the table doesn't exist this is the same as [`Catalog::create_table`].
+    /// Otherwise the schema, partition spec and sort order of the table are
+    /// replaced by the ones in `creation`, the properties in `creation` are set
+    /// on top of the existing ones, and the table is left without a current
+    /// snapshot, so it reads as empty. Fields of the new schema named as fields
+    /// of the current schema keep their ids, the other fields get fresh ids
+    /// above the last column id of the table, so that the ids of the old
+    /// snapshots still refer to the same columns. The table keeps its uuid, and
+    /// its location unless `creation` sets one. When
+    /// `preserve_history` is `false` the old snapshots and refs are dropped as
+    /// well, otherwise they remain available for time travel.
+    ///
+    /// The new metadata is swapped in atomically: if the table is changed
+    /// concurrently the replace fails with [`ErrorKind::CatalogCommitConflicts`].
+    pub async fn create_or_replace_table(
+        &self,
+        namespace: &NamespaceIdent,
+        creation: TableCreation,
+        preserve_history: bool,
+    ) -> Result<Table> {
+        if !self.namespace_exists(namespace).await? {
+            return no_such_namespace_err(namespace);
+        }
 
-    use iceberg::spec::{NestedField, PartitionSpec, PrimitiveType, Schema, SortOrder, Type};
-    use iceberg::table::Table;
-    use iceberg::transaction::{ApplyTransactionAction, Transaction};
-    use iceberg::{Catalog, CatalogBuilder, Namespace, NamespaceIdent, TableCreation, TableIdent};
-    use itertools::Itertools;
-    use regex::Regex;
-    use sqlx::migrate::MigrateDatabase;
-    use tempfile::TempDir;
+        let tbl_ident = TableIdent::new(namespace.clone(), creation.name.clone());
+        if !self.table_exists(&tbl_ident).await? {
+            return self.create_table(namespace, creation).await;
+        }
 
-    use crate::catalog::{
-        NAMESPACE_LOCATION_PROPERTY_KEY, SQL_CATALOG_PROP_BIND_STYLE, SQL_CATALOG_PROP_URI,
-        SQL_CATALOG_PROP_WAREHOUSE,
-    };
-    use crate::{SqlBindStyle, SqlCatalogBuilder};
+        self.with_metadata_cleanup(
+            "create_or_replace_table",
+            &tbl_ident,
+            async |written_metadata_location| {
+                self.replace_table(
+                    tbl_ident.clone(),
+                    creation,
+                    preserve_history,
+                    written_metadata_location,
+                )
+                .await
+            },
+        )
+        .await
+    }
 
-    const UUID_REGEX_STR: &str = "[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}";
+    async fn replace_table(
+        &self,
+        tbl_ident: TableIdent,
+        creation: TableCreation,
+        preserve_history: bool,
+        written_metadata_location: &mut Option<String>,
+    ) -> Result<Table> {
+        let current_table = self.load_table(&tbl_ident).await?;
+        let current_metadata_location = current_table.metadata_location_result()?.to_string();
+        let current_metadata = current_table.metadata();
+
+        let mut builder = current_metadata
+            .clone()
+            .into_builder(Some(current_metadata_location.clone()))
+            .remove_ref(MAIN_BRANCH);
+        if !preserve_history {
+            let snapshot_ids = current_metadata
+                .snapshots()
+                .map(|snapshot| snapshot.snapshot_id())
+                .collect::<Vec<_>>();
+            builder = builder.remove_snapshots(&snapshot_ids);
+        }
+        if creation.format_version > current_metadata.format_version() {
+            builder = builder.upgrade_format_version(creation.format_version)?;
+        }
+        if let Some(location) = creation.location {
+            builder = builder.set_location(location);
+        }
+        let (schema, fresh_ids) = assign_fresh_ids(
+            &creation.schema,
+            current_metadata.current_schema(),
+            current_metadata.last_column_id(),
+        )?;
+        let fresh_id = |id: i32| fresh_ids.get(&id).copied().unwrap_or(id);
+        let partition_spec = match creation.partition_spec {
+            Some(partition_spec) => UnboundPartitionSpec::builder()
+                .add_partition_fields(partition_spec.fields().iter().map(|field| {
+                    UnboundPartitionField {
+                        source_id: fresh_id(field.source_id),
+                        field_id: None,
+                        ..field.clone()
+                    }
+                }))?
+                .build(),
+            None => UnboundPartitionSpec::builder().build(),
+        };
+        let sort_order = match creation.sort_order {
+            Some(sort_order) => SortOrder {
+                order_id: sort_order.order_id,
+                fields: sort_order
+                    .fields
+                    .into_iter()
+                    .map(|field| SortField {
+                        source_id: fresh_id(field.source_id),
+                        ..field
+                    })
+                    .collect(),
+            },
+            None => SortOrder::unsorted_order(),
+        };
+        let tbl_metadata = builder
+            .add_current_schema(schema)?
+            .add_default_partition_spec(partition_spec)?
+            .add_sort_order(sort_order)?
+            .set_default_sort_order(TableMetadataBuilder::LAST_ADDED.into())?
+            .set_properties(creation.properties)?
+            .build()?
+            .metadata;
 
-    fn temp_path() -> String {
-        let temp_dir = TempDir::new().unwrap();
-        temp_dir.path().to_str().unwrap().to_string()
-    }
+        let tbl_metadata_location = MetadataLocation::from_str(&current_metadata_location)?
+            .with_next_version()
+            .to_string();
+        *written_metadata_location = Some(tbl_metadata_location.clone());
+        self.metadata_store
+            .write(&tbl_metadata_location, &tbl_metadata)
+            .await?;
 
-    fn to_set<T: std::cmp::Eq + Hash>(vec: Vec<T>) -> HashSet<T> {
-        HashSet::from_iter(vec)
-    }
+        self.swap_metadata_location(
+            &tbl_ident,
+            &current_metadata_location,
+            &tbl_metadata_location,
+        )
+        .await?;
 
-    fn default_properties() -> HashMap<String, String> {
-        HashMap::from([("exists".to_string(), "true".to_string())])
+        Table::builder()
+            .file_io(self.fileio.clone())
+            .identifier(tbl_ident)
+            .metadata_location(tbl_metadata_location)
+            .metadata(tbl_metadata)
+            .build()
     }
+}
 
-    /// Create a new SQLite catalog for testing. If name is not specified it defaults to "iceberg".
-    async fn new_sql_catalog(
-        warehouse_location: String,
-        name: Option<impl ToString>,
-    ) -> impl Catalog {
-        let name = if let Some(name) = name {
-            name.to_string()
-        } else {
-            "iceberg".to_string()
-        };
-        let sql_lite_uri = format!("sqlite:{}", temp_path());
-        sqlx::Sqlite::create_database(&sql_lite_uri).await.unwrap();
+#[async_trait]
+impl Catalog for SqlCatalog {
+    async fn list_namespaces(
+        &self,
+        parent: Option<&NamespaceIdent>,
+    ) -> Result<Vec<NamespaceIdent>> {
+        // UNION will remove duplicates.
+        let all_namespaces_stmt = format!(
+            "SELECT {CATALOG_FIELD_TABLE_NAMESPACE}
+             FROM {CATALOG_TABLE_NAME}
+             WHERE {CATALOG_FIELD_CATALOG_NAME} = ?
+             UNION
+             SELECT {NAMESPACE_FIELD_NAME}
+             FROM {NAMESPACE_TABLE_NAME}
+             WHERE {CATALOG_FIELD_CATALOG_NAME} = ?"
+        );
 
-        let props = HashMap::from_iter([
-            (SQL_CATALOG_PROP_URI.to_string(), sql_lite_uri.to_string()),
-            (SQL_CATALOG_PROP_WAREHOUSE.to_string(), warehouse_location),
-            (
-                SQL_CATALOG_PROP_BIND_STYLE.to_string(),
-                SqlBindStyle::DollarNumeric.to_string(),
-            ),
-        ]);
-        SqlCatalogBuilder::default()
-            .load(&name, props)
-            .await
-            .unwrap()
-    }
+        let namespace_rows = self
+            .fetch_rows(&all_namespaces_stmt, vec![
+                Some(&self.name),
+                Some(&self.name),
+            ])
+            .await?;
 
-    async fn create_namespace<C: Catalog>(catalog: &C, namespace_ident: &NamespaceIdent) {
-        let _ = catalog
-            .create_namespace(namespace_ident, HashMap::new())
-            .await
-            .unwrap();
-    }
+        let mut namespaces = HashSet::<NamespaceIdent>::with_capacity(namespace_rows.len());
 
-    async fn create_namespaces<C: Catalog>(catalog: &C, namespace_idents: &Vec<&NamespaceIdent>) {
-        for namespace_ident in namespace_idents {
-            let _ = create_namespace(catalog, namespace_ident).await;
-        }
-    }
+        if let Some(parent) = parent {
+            if self.namespace_exists(parent).await? {
+                let parent_str = parent.join(".");
 
-    fn simple_table_schema() -> Schema {
-        Schema::builder()
-            .with_fields(vec![
-                NestedField::required(1, "foo", Type::Primitive(PrimitiveType::Int)).into(),
-            ])
-            .build()
-            .unwrap()
-    }
+                for row in namespace_rows.iter() {
+                    let nsp = row.try_get::<String, _>(0).map_err(from_sqlx_error)?;
+                    // if parent = a, then we only want to see a.b, a.c returned.
+                    if nsp != parent_str && nsp.starts_with(&parent_str) {
+                        namespaces.insert(NamespaceIdent::from_strs(nsp.split("."))?);
+                    }
+                }
 
-    async fn create_table<C: Catalog>(catalog: &C, table_ident: &TableIdent) {
-        let _ = catalog
-            .create_table(
-                &table_ident.namespace,
-                TableCreation::builder()
-                    .name(table_ident.name().into())
-                    .schema(simple_table_schema())
-                    .location(temp_path())
-                    .build(),
-            )
-            .await
-            .unwrap();
-    }
+                Ok(namespaces.into_iter().collect::<Vec<NamespaceIdent>>())
+            } else {
+                no_such_namespace_err(parent)
+            }
+        } else {
+            for row in namespace_rows.iter() {
+                let nsp = row.try_get::<String, _>(0).map_err(from_sqlx_error)?;
+                let mut levels = nsp.split(".").collect::<Vec<&str>>();
+                if !levels.is_empty() {
+                    let first_level = levels.drain(..1).collect::<Vec<&str>>();
+                    namespaces.insert(NamespaceIdent::from_strs(first_level)?);
+                }
+            }
 
-    async fn create_tables<C: Catalog>(catalog: &C, table_idents: Vec<&TableIdent>) {
-        for table_ident in table_idents {
-            create_table(catalog, table_ident).await;
+            Ok(namespaces.into_iter().collect::<Vec<NamespaceIdent>>())
         }
     }
 
-    fn assert_table_eq(table: &Table, expected_table_ident: &TableIdent, expected_schema: &Schema) {
-        assert_eq!(table.identifier(), expected_table_ident);
-
-        let metadata = table.metadata();
-
-        assert_eq!(metadata.current_schema().as_ref(), expected_schema);
+    async fn create_namespace(
+        &self,
+        namespace: &NamespaceIdent,
+        properties: HashMap<String, String>,
+    ) -> Result<Namespace> {
+        let exists = self.namespace_exists(namespace).await?;
 
-        let expected_partition_spec = PartitionSpec::builder(expected_schema.clone())
-            .with_spec_id(0)
-            .build()
-            .unwrap();
+        if exists {
+            return Err(Error::new(
+                iceberg::ErrorKind::NamespaceAlreadyExists,
+                format!("Namespace {namespace:?} already exists"),
+            ));
+        }
 
-        assert_eq!(
-            metadata
-                .partition_specs_iter()
-                .map(|p| p.as_ref())
-                .collect_vec(),
-            vec![&expected_partition_spec]
-        );
+        let namespace_str = namespace.join(".");
+        let insert = format!(
+            "INSERT INTO {NAMESPACE_TABLE_NAME} ({CATALOG_FIELD_CATALOG_NAME}, {NAMESPACE_FIELD_NAME}, {NAMESPACE_FIELD_PROPERTY_KEY}, {NAMESPACE_FIELD_PROPERTY_VALUE})
+             VALUES (?, ?, ?, ?)");
+        if !properties.is_empty() {
+            let mut insert_properties = properties.clone();
+            insert_properties.insert("exists".to_string(), "true".to_string());
 
-        let expected_sorted_order = SortOrder::builder()
+            let mut query_args = Vec::with_capacity(insert_properties.len() * 4);
+            let mut insert_stmt = insert.clone();
+            for (index, (key, value)) in insert_properties.iter().enumerate() {
+                query_args.extend_from_slice(&[
+                    Some(self.name.as_str()),
+                    Some(namespace_str.as_str()),
+                    Some(key.as_str()),
+                    Some(value.as_str()),
+                ]);
+                if index > 0 {
+                    insert_stmt.push_str(", (?, ?, ?, ?)");
+                }
+            }
+
+            self.execute(&insert_stmt, query_args, None).await?;
+
+            Ok(Namespace::with_properties(
+                namespace.clone(),
+                insert_properties,
+            ))
+        } else {
+            // set a default property of exists = true
+            self.execute(
+                &insert,
+                vec![
+                    Some(&self.name),
+                    Some(&namespace_str),
+                    Some("exists"),
+                    Some("true"),
+                ],
+                None,
+            )
+            .await?;
+            Ok(Namespace::with_properties(namespace.clone(), properties))
+        }
+    }
+
+    async fn get_namespace(&self, namespace: &NamespaceIdent) -> Result<Namespace> {
+        let exists = self.namespace_exists(namespace).await?;
+        if exists {
+            let namespace_props = self
+                .fetch_rows(
+                    &format!(
+                        "SELECT
+                            {NAMESPACE_FIELD_NAME},
+                            {NAMESPACE_FIELD_PROPERTY_KEY},
+                            {NAMESPACE_FIELD_PROPERTY_VALUE}
+                            FROM {NAMESPACE_TABLE_NAME}
+                            WHERE {CATALOG_FIELD_CATALOG_NAME} = ?
+                            AND {NAMESPACE_FIELD_NAME} = ?"
+                    ),
+                    vec![Some(&self.name), Some(&namespace.join("."))],
+                )
+                .await?;
+
+            let mut properties = HashMap::with_capacity(namespace_props.len());
+
+            for row in namespace_props {
+                let key = row
+                    .try_get::<String, _>(NAMESPACE_FIELD_PROPERTY_KEY)
+                    .map_err(from_sqlx_error)?;
+                let value = row
+                    .try_get::<String, _>(NAMESPACE_FIELD_PROPERTY_VALUE)
+                    .map_err(from_sqlx_error)?;
+
+                properties.insert(key, value);
+            }
+
+            Ok(Namespace::with_properties(namespace.clone(), properties))
+        } else {
+            no_such_namespace_err(namespace)
+        }
+    }
+
+    async fn namespace_exists(&self, namespace: &NamespaceIdent) -> Result<bool> {
+        let namespace_str = namespace.join(".");
+
+        let table_namespaces = self
+            .fetch_rows(
+                &format!(
+                    "SELECT 1 FROM {CATALOG_TABLE_NAME}
+                     WHERE {CATALOG_FIELD_CATALOG_NAME} = ?
+                      AND {CATALOG_FIELD_TABLE_NAMESPACE} = ?
+                     LIMIT 1"
+                ),
+                vec![Some(&self.name), Some(&namespace_str)],
+            )
+            .await?;
+
+        if !table_namespaces.is_empty() {
+            Ok(true)
+        } else {
+            let namespaces = self
+                .fetch_rows(
+                    &format!(
+                        "SELECT 1 FROM {NAMESPACE_TABLE_NAME}
+                         WHERE {CATALOG_FIELD_CATALOG_NAME} = ?
+                          AND {NAMESPACE_FIELD_NAME} = ?
+                         LIMIT 1"
+                    ),
+                    vec![Some(&self.name), Some(&namespace_str)],
+                )
+                .await?;
+            if !namespaces.is_empty() {
+                Ok(true)
+            } else {
+                Ok(false)
+            }
+        }
+    }
+
+    async fn update_namespace(
+        &self,
+        namespace: &NamespaceIdent,
+        properties: HashMap<String, String>,
+    ) -> Result<()> {
+        let exists = self.namespace_exists(namespace).await?;
+        if exists {
+            let existing_properties = self.get_namespace(namespace).await?.properties().clone();
+            let namespace_str = namespace.join(".");
+
+            let mut updates = vec![];
+            let mut inserts = vec![];
+
+            for (key, value) in properties.iter() {
+                if existing_properties.contains_key(key) {
+                    if existing_properties.get(key) != Some(value) {
+                        updates.push((key, value));
+                    }
+                } else {
+                    inserts.push((key, value));
+                }
+            }
+
+            let mut tx = self.connection.begin().await.map_err(from_sqlx_error)?;
+            let update_stmt = format!(
+                "UPDATE {NAMESPACE_TABLE_NAME} SET {NAMESPACE_FIELD_PROPERTY_VALUE} = ?
+                 WHERE {CATALOG_FIELD_CATALOG_NAME} = ? 
+                 AND {NAMESPACE_FIELD_NAME} = ?
+                 AND {NAMESPACE_FIELD_PROPERTY_KEY} = ?"
+            );
+
+            let insert_stmt = format!(
+                "INSERT INTO {NAMESPACE_TABLE_NAME} ({CATALOG_FIELD_CATALOG_NAME}, {NAMESPACE_FIELD_NAME}, {NAMESPACE_FIELD_PROPERTY_KEY}, {NAMESPACE_FIELD_PROPERTY_VALUE})
+                 VALUES (?, ?, ?, ?)"
+            );
+
+            for (key, value) in updates {
+                self.execute(
+                    &update_stmt,
+                    vec![
+                        Some(value),
+                        Some(&self.name),
+                        Some(&namespace_str),
+                        Some(key),
+                    ],
+                    Some(&mut tx),
+                )
+                .await?;
+            }
+
+            for (key, value) in inserts {
+                self.execute(
+                    &insert_stmt,
+                    vec![
+                        Some(&self.name),
+                        Some(&namespace_str),
+                        Some(key),
+                        Some(value),
+                    ],
+                    Some(&mut tx),
+                )
+                .await?;
+            }
+
+            let _ = tx.commit().await.map_err(from_sqlx_error)?;
+
+            Ok(())
+        } else {
+            no_such_namespace_err(namespace)
+        }
+    }
+
+    async fn drop_namespace(&self, namespace: &NamespaceIdent) -> Result<()> {
+        self.drop_namespace_with_cascade(namespace, false).await
+    }
+
+    async fn list_tables(&self, namespace: &NamespaceIdent) -> Result<Vec<TableIdent>> {
+        if !self.namespace_exists(namespace).await? {
+            return no_such_namespace_err(namespace);
+        }
+
+        self.fetch_table_idents(namespace, None, None).await
+    }
+
+    async fn table_exists(&self, identifier: &TableIdent) -> Result<bool> {
+        let namespace = identifier.namespace().join(".");
+        let table_name = identifier.name();
+        let table_counts = self
+            .fetch_rows(
+                &format!(
+                    "SELECT 1
+                     FROM {CATALOG_TABLE_NAME}
+                     WHERE {CATALOG_FIELD_TABLE_NAMESPACE} = ?
+                      AND {CATALOG_FIELD_CATALOG_NAME} = ?
+                      AND {CATALOG_FIELD_TABLE_NAME} = ?
+                      AND (
+                        {CATALOG_FIELD_RECORD_TYPE} = '{CATALOG_FIELD_TABLE_RECORD_TYPE}' 
+                        OR {CATALOG_FIELD_RECORD_TYPE} IS NULL
+                      )"
+                ),
+                vec![Some(&namespace), Some(&self.name), Some(table_name)],
+            )
+            .await?;
+
+        if !table_counts.is_empty() {
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    async fn drop_table(&self, identifier: &TableIdent) -> Result<()> {
+        self.with_timeout("drop_table", async {
+            if !self.table_exists(identifier).await? {
+                return no_such_table_err(identifier);
+            }
+
+            self.execute(
+                &format!(
+                    "DELETE FROM {CATALOG_TABLE_NAME}
+                 WHERE {CATALOG_FIELD_CATALOG_NAME} = ?
+                  AND {CATALOG_FIELD_TABLE_NAME} = ?
+                  AND {CATALOG_FIELD_TABLE_NAMESPACE} = ?
+                  AND (
+                    {CATALOG_FIELD_RECORD_TYPE} = '{CATALOG_FIELD_TABLE_RECORD_TYPE}' 
+                    OR {CATALOG_FIELD_RECORD_TYPE} IS NULL
+                  )"
+                ),
+                vec![
+                    Some(&self.name),
+                    Some(identifier.name()),
+                    Some(&identifier.namespace().join(".")),
+                ],
+                None,
+            )
+            .await?;
+
+            Ok(())
+        })
+        .await
+    }
+
+    async fn load_table(&self, identifier: &TableIdent) -> Result<Table> {
+        self.with_timeout("load_table", async {
+            if !self.table_exists(identifier).await? {
+                return no_such_table_err(identifier);
+            }
+
+            let Some(tbl_metadata_location) = self.fetch_metadata_location(identifier).await?
+            else {
+                return no_such_table_err(identifier);
+            };
+
+            let metadata = self.metadata_store.read(&tbl_metadata_location).await?;
+
+            Table::builder()
+                .file_io(self.fileio.clone())
+                .identifier(identifier.clone())
+                .metadata_location(tbl_metadata_location)
+                .metadata(metadata)
+                .build()
+        })
+        .await
+    }
+
+    async fn create_table(
+        &self,
+        namespace: &NamespaceIdent,
+        creation: TableCreation,
+    ) -> Result<Table> {
+        let tbl_ident = TableIdent::new(namespace.clone(), creation.name.clone());
+        self.with_metadata_cleanup(
+            "create_table",
+            &tbl_ident,
+            async |written_metadata_location| {
+                self.insert_new_table(namespace, creation, written_metadata_location)
+                    .await
+            },
+        )
+        .await
+    }
+
+    async fn rename_table(&self, src: &TableIdent, dest: &TableIdent) -> Result<()> {
+        if src == dest {
+            return Ok(());
+        }
+
+        if !self.table_exists(src).await? {
+            return no_such_table_err(src);
+        }
+
+        if !self.namespace_exists(dest.namespace()).await? {
+            return no_such_namespace_err(dest.namespace());
+        }
+
+        if self.table_exists(dest).await? {
+            return table_already_exists_err(dest);
+        }
+
+        self.execute(
+            &format!(
+                "UPDATE {CATALOG_TABLE_NAME}
+                 SET {CATALOG_FIELD_TABLE_NAME} = ?, {CATALOG_FIELD_TABLE_NAMESPACE} = ?
+                 WHERE {CATALOG_FIELD_CATALOG_NAME} = ?
+                  AND {CATALOG_FIELD_TABLE_NAME} = ?
+                  AND {CATALOG_FIELD_TABLE_NAMESPACE} = ?
+                  AND (
+                    {CATALOG_FIELD_RECORD_TYPE} = '{CATALOG_FIELD_TABLE_RECORD_TYPE}'
+                    OR {CATALOG_FIELD_RECORD_TYPE} IS NULL
+                )"
+            ),
+            vec![
+                Some(dest.name()),
+                Some(&dest.namespace().join(".")),
+                Some(&self.name),
+                Some(src.name()),
+                Some(&src.namespace().join(".")),
+            ],
+            None,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn register_table(
+        &self,
+        table_ident: &TableIdent,
+        metadata_location: String,
+    ) -> Result<Table> {
+        if self.table_exists(table_ident).await? {
+            return table_already_exists_err(table_ident);
+        }
+
+        let metadata = self.metadata_store.read(&metadata_location).await?;
+
+        let namespace = table_ident.namespace();
+        let tbl_name = table_ident.name().to_string();
+
+        self.execute(&format!(
+            "INSERT INTO {CATALOG_TABLE_NAME}
+             ({CATALOG_FIELD_CATALOG_NAME}, {CATALOG_FIELD_TABLE_NAMESPACE}, {CATALOG_FIELD_TABLE_NAME}, {CATALOG_FIELD_METADATA_LOCATION_PROP}, {CATALOG_FIELD_RECORD_TYPE})
+             VALUES (?, ?, ?, ?, ?)
+            "), vec![Some(&self.name), Some(&namespace.join(".")), Some(&tbl_name), Some(&metadata_location), Some(CATALOG_FIELD_TABLE_RECORD_TYPE)], None).await?;
+
+        Ok(Table::builder()
+            .identifier(table_ident.clone())
+            .metadata_location(metadata_location)
+            .metadata(metadata)
+            .file_io(self.fileio.clone())
+            .build()?)
+    }
+
+    /// Updates an existing table within the SQL catalog.
+    async fn update_table(&self, commit: TableCommit) -> Result<Table> {
+        let table_ident = commit.identifier().clone();
+        self.with_metadata_cleanup(
+            "update_table",
+            &table_ident,
+            async |written_metadata_location| {
+                let current_table = self.load_table(&table_ident).await?;
+                let current_metadata_location =
+                    current_table.metadata_location_result()?.to_string();
+
+                // Fails unless all the requirements of the commit, such as the table UUID,
+                // hold for the current metadata of the table
+                let staged_table = commit.apply(current_table)?;
+                let staged_metadata_location = staged_table.metadata_location_result()?;
+
+                *written_metadata_location = Some(staged_metadata_location.to_string());
+                self.metadata_store
+                    .write(staged_metadata_location, staged_table.metadata())
+                    .await?;
+
+                self.swap_metadata_location(
+                    &table_ident,
+                    &current_metadata_location,
+                    staged_metadata_location,
+                )
+                .await?;
+
+                Ok(staged_table)
+            },
+        )
+        .await
+    }
+}
+
+/// Assign to the fields of `schema` the ids of the fields of `base_schema` with the same
+/// name, and fresh ids above `last_column_id` to the other fields, as Java's
+/// `TableMetadata.buildReplacement` does.
+///
+/// Returns the schema with the assigned ids, and the assigned id of each field id of
+/// `schema`.
+fn assign_fresh_ids(
+    schema: &Schema,
+    base_schema: &Schema,
+    last_column_id: i32,
+) -> Result<(Schema, HashMap<i32, i32>)> {
+    struct FreshIds<'a> {
+        schema: &'a Schema,
+        base_schema: &'a Schema,
+        last_column_id: i32,
+        assigned_ids: HashMap<i32, i32>,
+    }
+
+    impl FreshIds<'_> {
+        fn field(&mut self, field: &NestedFieldRef) -> Result<NestedFieldRef> {
+            let name = self.schema.name_by_field_id(field.id).ok_or_else(|| {
+                Error::new(
+                    ErrorKind::DataInvalid,
+                    format!("Field id {} not found in schema", field.id),
+                )
+            })?;
+            let id = match self.base_schema.field_by_name(name) {
+                Some(base_field) => base_field.id,
+                None => {
+                    self.last_column_id += 1;
+                    self.last_column_id
+                }
+            };
+            self.assigned_ids.insert(field.id, id);
+
+            let mut field = NestedField::clone(field);
+            field.id = id;
+            field.field_type = Box::new(self.r#type(&field.field_type)?);
+            Ok(Arc::new(field))
+        }
+
+        fn r#type(&mut self, r#type: &Type) -> Result<Type> {
+            Ok(match r#type {
+                Type::Primitive(_) => r#type.clone(),
+                Type::Struct(struct_type) => Type::Struct(StructType::new(
+                    struct_type
+                        .fields()
+                        .iter()
+                        .map(|field| self.field(field))
+                        .collect::<Result<_>>()?,
+                )),
+                Type::List(list_type) => Type::List(ListType {
+                    element_field: self.field(&list_type.element_field)?,
+                }),
+                Type::Map(map_type) => Type::Map(MapType {
+                    key_field: self.field(&map_type.key_field)?,
+                    value_field: self.field(&map_type.value_field)?,
+                }),
+            })
+        }
+    }
+
+    let mut fresh_ids = FreshIds {
+        schema,
+        base_schema,
+        last_column_id,
+        assigned_ids: HashMap::new(),
+    };
+    let fields = schema
+        .as_struct()
+        .fields()
+        .iter()
+        .map(|field| fresh_ids.field(field))
+        .collect::<Result<Vec<_>>>()?;
+    let assigned_ids = fresh_ids.assigned_ids;
+    let schema = Schema::builder()
+        .with_schema_id(schema.schema_id())
+        .with_fields(fields)
+        .with_identifier_field_ids(
+            schema
+                .identifier_field_ids()
+                .map(|id| assigned_ids.get(&id).copied().unwrap_or(id)),
+        )
+        .build()?;
+    Ok((schema, assigned_ids))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+    use std::hash::Hash;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use async_trait::async_trait;
+    use iceberg::spec::{
+        DataContentType, DataFileBuilder, DataFileFormat, NestedField, NullOrder, PartitionSpec,
+        PrimitiveType, Schema, SortDirection, SortField, SortOrder, Struct, TableMetadata,
+        Transform, Type,
+    };
+    use iceberg::table::Table;
+    use iceberg::transaction::{ApplyTransactionAction, Transaction};
+    use iceberg::{
+        Catalog, CatalogBuilder, Error, ErrorKind, MetadataStore, Namespace, NamespaceIdent,
+        Result, TableCreation, TableIdent,
+    };
+    use itertools::Itertools;
+    use regex::Regex;
+    use sqlx::migrate::MigrateDatabase;
+    use tempfile::TempDir;
+
+    use crate::catalog::{
+        CATALOG_FIELD_CATALOG_NAME, CATALOG_FIELD_METADATA_LOCATION_PROP,
+        CATALOG_FIELD_RECORD_TYPE, CATALOG_FIELD_TABLE_NAME, CATALOG_FIELD_TABLE_NAMESPACE,
+        CATALOG_TABLE_NAME, NAMESPACE_LOCATION_PROPERTY_KEY, SQL_CATALOG_PROP_BIND_STYLE,
+        SQL_CATALOG_PROP_OPERATION_TIMEOUT_MS, SQL_CATALOG_PROP_URI, SQL_CATALOG_PROP_WAREHOUSE,
+    };
+    use crate::{SqlBindStyle, SqlCatalog, SqlCatalogBuilder};
+
+    const UUID_REGEX_STR: &str = "[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}";
+
+    fn temp_path() -> String {
+        let temp_dir = TempDir::new().unwrap();
+        temp_dir.path().to_str().unwrap().to_string()
+    }
+
+    fn to_set<T: std::cmp::Eq + Hash>(vec: Vec<T>) -> HashSet<T> {
+        HashSet::from_iter(vec)
+    }
+
+    fn default_properties() -> HashMap<String, String> {
+        HashMap::from([("exists".to_string(), "true".to_string())])
+    }
+
+    /// Create a new SQLite catalog for testing. If name is not specified it defaults to "iceberg".
+    async fn new_sql_catalog(
+        warehouse_location: String,
+        name: Option<impl ToString>,
+    ) -> SqlCatalog {
+        let name = if let Some(name) = name {
+            name.to_string()
+        } else {
+            "iceberg".to_string()
+        };
+        let sql_lite_uri = format!("sqlite:{}", temp_path());
+        sqlx::Sqlite::create_database(&sql_lite_uri).await.unwrap();
+
+        let props = HashMap::from_iter([
+            (SQL_CATALOG_PROP_URI.to_string(), sql_lite_uri.to_string()),
+            (SQL_CATALOG_PROP_WAREHOUSE.to_string(), warehouse_location),
+            (
+                SQL_CATALOG_PROP_BIND_STYLE.to_string(),
+                SqlBindStyle::DollarNumeric.to_string(),
+            ),
+        ]);
+        SqlCatalogBuilder::default()
+            .load(&name, props)
+            .await
+            .unwrap()
+    }
+
+    async fn create_namespace<C: Catalog>(catalog: &C, namespace_ident: &NamespaceIdent) {
+        let _ = catalog
+            .create_namespace(namespace_ident, HashMap::new())
+            .await
+            .unwrap();
+    }
+
+    async fn create_namespaces<C: Catalog>(catalog: &C, namespace_idents: &Vec<&NamespaceIdent>) {
+        for namespace_ident in namespace_idents {
+            let _ = create_namespace(catalog, namespace_ident).await;
+        }
+    }
+
+    fn simple_table_schema() -> Schema {
+        Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "foo", Type::Primitive(PrimitiveType::Int)).into(),
+            ])
+            .build()
+            .unwrap()
+    }
+
+    async fn create_table<C: Catalog>(catalog: &C, table_ident: &TableIdent) {
+        let _ = catalog
+            .create_table(
+                &table_ident.namespace,
+                TableCreation::builder()
+                    .name(table_ident.name().into())
+                    .schema(simple_table_schema())
+                    .location(temp_path())
+                    .build(),
+            )
+            .await
+            .unwrap();
+    }
+
+    async fn create_tables<C: Catalog>(catalog: &C, table_idents: Vec<&TableIdent>) {
+        for table_ident in table_idents {
+            create_table(catalog, table_ident).await;
+        }
+    }
+
+    fn assert_table_eq(table: &Table, expected_table_ident: &TableIdent, expected_schema: &Schema) {
+        assert_eq!(table.identifier(), expected_table_ident);
+
+        let metadata = table.metadata();
+
+        assert_eq!(metadata.current_schema().as_ref(), expected_schema);
+
+        let expected_partition_spec = PartitionSpec::builder(expected_schema.clone())
+            .with_spec_id(0)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            metadata
+                .partition_specs_iter()
+                .map(|p| p.as_ref())
+                .collect_vec(),
+            vec![&expected_partition_spec]
+        );
+
+        let expected_sorted_order = SortOrder::builder()
             .with_order_id(0)
             .with_fields(vec![])
             .build(expected_schema)
             .unwrap();
 
         assert_eq!(
-            metadata
-                .sort_orders_iter()
-                .map(|s| s.as_ref())
-                .collect_vec(),
-            vec![&expected_sorted_order]
+            metadata
+                .sort_orders_iter()
+                .map(|s| s.as_ref())
+                .collect_vec(),
+            vec![&expected_sorted_order]
+        );
+
+        assert_eq!(metadata.properties(), &HashMap::new());
+
+        assert!(!table.readonly());
+    }
+
+    fn assert_table_metadata_location_matches(table: &Table, regex_str: &str) {
+        let actual = table.metadata_location().unwrap().to_string();
+        let regex = Regex::new(regex_str).unwrap();
+        assert!(regex.is_match(&actual))
+    }
+
+    #[tokio::test]
+    async fn test_initialized() {
+        let warehouse_loc = temp_path();
+        new_sql_catalog(warehouse_loc.clone(), Some("iceberg")).await;
+        // catalog instantiation should not fail even if tables exist
+        new_sql_catalog(warehouse_loc.clone(), Some("iceberg")).await;
+        new_sql_catalog(warehouse_loc.clone(), Some("iceberg")).await;
+    }
+
+    #[tokio::test]
+    async fn test_builder_method() {
+        let sql_lite_uri = format!("sqlite:{}", temp_path());
+        sqlx::Sqlite::create_database(&sql_lite_uri).await.unwrap();
+        let warehouse_location = temp_path();
+
+        let catalog = SqlCatalogBuilder::default()
+            .uri(sql_lite_uri.to_string())
+            .warehouse_location(warehouse_location.clone())
+            .sql_bind_style(SqlBindStyle::QMark)
+            .load("iceberg", HashMap::default())
+            .await;
+        assert!(catalog.is_ok());
+
+        let catalog = catalog.unwrap();
+        assert!(catalog.warehouse_location == warehouse_location);
+        assert!(catalog.sql_bind_style == SqlBindStyle::QMark);
+    }
+
+    /// Overwriting an sqlite database with a non-existent path causes
+    /// catalog generation to fail
+    #[tokio::test]
+    async fn test_builder_props_non_existent_path_fails() {
+        let sql_lite_uri = format!("sqlite:{}", temp_path());
+        let sql_lite_uri2 = format!("sqlite:{}", temp_path());
+        sqlx::Sqlite::create_database(&sql_lite_uri).await.unwrap();
+        let warehouse_location = temp_path();
+
+        let catalog = SqlCatalogBuilder::default()
+            .uri(sql_lite_uri)
+            .warehouse_location(warehouse_location)
+            .load(
+                "iceberg",
+                HashMap::from_iter([(SQL_CATALOG_PROP_URI.to_string(), sql_lite_uri2)]),
+            )
+            .await;
+        assert!(catalog.is_err());
+    }
+
+    /// Even when an invalid URI is specified in a builder method,
+    /// it can be successfully overridden with a valid URI in props
+    /// for catalog generation to succeed.
+    #[tokio::test]
+    async fn test_builder_props_set_valid_uri() {
+        let sql_lite_uri = format!("sqlite:{}", temp_path());
+        let sql_lite_uri2 = format!("sqlite:{}", temp_path());
+        sqlx::Sqlite::create_database(&sql_lite_uri).await.unwrap();
+        let warehouse_location = temp_path();
+
+        let catalog = SqlCatalogBuilder::default()
+            .uri(sql_lite_uri2)
+            .warehouse_location(warehouse_location)
+            .load(
+                "iceberg",
+                HashMap::from_iter([(SQL_CATALOG_PROP_URI.to_string(), sql_lite_uri.clone())]),
+            )
+            .await;
+        assert!(catalog.is_ok());
+    }
+
+    /// values assigned via props take precedence
+    #[tokio::test]
+    async fn test_builder_props_take_precedence() {
+        let sql_lite_uri = format!("sqlite:{}", temp_path());
+        sqlx::Sqlite::create_database(&sql_lite_uri).await.unwrap();
+        let warehouse_location = temp_path();
+        let warehouse_location2 = temp_path();
+
+        let catalog = SqlCatalogBuilder::default()
+            .warehouse_location(warehouse_location2)
+            .sql_bind_style(SqlBindStyle::DollarNumeric)
+            .load(
+                "iceberg",
+                HashMap::from_iter([
+                    (SQL_CATALOG_PROP_URI.to_string(), sql_lite_uri),
+                    (
+                        SQL_CATALOG_PROP_WAREHOUSE.to_string(),
+                        warehouse_location.clone(),
+                    ),
+                    (
+                        SQL_CATALOG_PROP_BIND_STYLE.to_string(),
+                        SqlBindStyle::QMark.to_string(),
+                    ),
+                ]),
+            )
+            .await;
+
+        assert!(catalog.is_ok());
+
+        let catalog = catalog.unwrap();
+        assert!(catalog.warehouse_location == warehouse_location);
+        assert!(catalog.sql_bind_style == SqlBindStyle::QMark);
+    }
+
+    /// values assigned via props take precedence
+    #[tokio::test]
+    async fn test_builder_props_take_precedence_props() {
+        let sql_lite_uri = format!("sqlite:{}", temp_path());
+        let sql_lite_uri2 = format!("sqlite:{}", temp_path());
+        sqlx::Sqlite::create_database(&sql_lite_uri).await.unwrap();
+        let warehouse_location = temp_path();
+        let warehouse_location2 = temp_path();
+
+        let props = HashMap::from_iter([
+            (SQL_CATALOG_PROP_URI.to_string(), sql_lite_uri.clone()),
+            (
+                SQL_CATALOG_PROP_WAREHOUSE.to_string(),
+                warehouse_location.clone(),
+            ),
+            (
+                SQL_CATALOG_PROP_BIND_STYLE.to_string(),
+                SqlBindStyle::QMark.to_string(),
+            ),
+        ]);
+        let props2 = HashMap::from_iter([
+            (SQL_CATALOG_PROP_URI.to_string(), sql_lite_uri2.clone()),
+            (
+                SQL_CATALOG_PROP_WAREHOUSE.to_string(),
+                warehouse_location2.clone(),
+            ),
+            (
+                SQL_CATALOG_PROP_BIND_STYLE.to_string(),
+                SqlBindStyle::DollarNumeric.to_string(),
+            ),
+        ]);
+
+        let catalog = SqlCatalogBuilder::default()
+            .props(props2)
+            .load("iceberg", props)
+            .await;
+
+        assert!(catalog.is_ok());
+
+        let catalog = catalog.unwrap();
+        assert!(catalog.warehouse_location == warehouse_location);
+        assert!(catalog.sql_bind_style == SqlBindStyle::QMark);
+    }
+
+    /// values assigned via props take precedence
+    #[tokio::test]
+    async fn test_builder_props_take_precedence_prop() {
+        let sql_lite_uri = format!("sqlite:{}", temp_path());
+        let sql_lite_uri2 = format!("sqlite:{}", temp_path());
+        sqlx::Sqlite::create_database(&sql_lite_uri).await.unwrap();
+        let warehouse_location = temp_path();
+        let warehouse_location2 = temp_path();
+
+        let props = HashMap::from_iter([
+            (SQL_CATALOG_PROP_URI.to_string(), sql_lite_uri.clone()),
+            (
+                SQL_CATALOG_PROP_WAREHOUSE.to_string(),
+                warehouse_location.clone(),
+            ),
+            (
+                SQL_CATALOG_PROP_BIND_STYLE.to_string(),
+                SqlBindStyle::QMark.to_string(),
+            ),
+        ]);
+
+        let catalog = SqlCatalogBuilder::default()
+            .prop(SQL_CATALOG_PROP_URI.to_string(), sql_lite_uri2)
+            .prop(SQL_CATALOG_PROP_WAREHOUSE.to_string(), warehouse_location2)
+            .prop(
+                SQL_CATALOG_PROP_BIND_STYLE.to_string(),
+                SqlBindStyle::DollarNumeric.to_string(),
+            )
+            .load("iceberg", props)
+            .await;
+
+        assert!(catalog.is_ok());
+
+        let catalog = catalog.unwrap();
+        assert!(catalog.warehouse_location == warehouse_location);
+        assert!(catalog.sql_bind_style == SqlBindStyle::QMark);
+    }
+
+    /// invalid value for `SqlBindStyle` causes catalog creation to fail
+    #[tokio::test]
+    async fn test_builder_props_invalid_bind_style_fails() {
+        let sql_lite_uri = format!("sqlite:{}", temp_path());
+        sqlx::Sqlite::create_database(&sql_lite_uri).await.unwrap();
+        let warehouse_location = temp_path();
+
+        let catalog = SqlCatalogBuilder::default()
+            .load(
+                "iceberg",
+                HashMap::from_iter([
+                    (SQL_CATALOG_PROP_URI.to_string(), sql_lite_uri),
+                    (SQL_CATALOG_PROP_WAREHOUSE.to_string(), warehouse_location),
+                    (SQL_CATALOG_PROP_BIND_STYLE.to_string(), "AAA".to_string()),
+                ]),
+            )
+            .await;
+
+        assert!(catalog.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_list_namespaces_returns_empty_vector() {
+        let warehouse_loc = temp_path();
+        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
+
+        assert_eq!(catalog.list_namespaces(None).await.unwrap(), vec![]);
+    }
+
+    #[tokio::test]
+    async fn test_list_namespaces_returns_empty_different_name() {
+        let warehouse_loc = temp_path();
+        let catalog = new_sql_catalog(warehouse_loc.clone(), Some("iceberg")).await;
+        let namespace_ident_1 = NamespaceIdent::new("a".into());
+        let namespace_ident_2 = NamespaceIdent::new("b".into());
+        create_namespaces(&catalog, &vec![&namespace_ident_1, &namespace_ident_2]).await;
+        assert_eq!(
+            to_set(catalog.list_namespaces(None).await.unwrap()),
+            to_set(vec![namespace_ident_1, namespace_ident_2])
+        );
+
+        let catalog2 = new_sql_catalog(warehouse_loc, Some("test")).await;
+        assert_eq!(catalog2.list_namespaces(None).await.unwrap(), vec![]);
+    }
+
+    #[tokio::test]
+    async fn test_list_namespaces_returns_multiple_namespaces() {
+        let warehouse_loc = temp_path();
+        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
+        let namespace_ident_1 = NamespaceIdent::new("a".into());
+        let namespace_ident_2 = NamespaceIdent::new("b".into());
+        create_namespaces(&catalog, &vec![&namespace_ident_1, &namespace_ident_2]).await;
+
+        assert_eq!(
+            to_set(catalog.list_namespaces(None).await.unwrap()),
+            to_set(vec![namespace_ident_1, namespace_ident_2])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_namespaces_returns_only_top_level_namespaces() {
+        let warehouse_loc = temp_path();
+        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
+        let namespace_ident_1 = NamespaceIdent::new("a".into());
+        let namespace_ident_2 = NamespaceIdent::from_strs(vec!["a", "b"]).unwrap();
+        let namespace_ident_3 = NamespaceIdent::new("b".into());
+        create_namespaces(&catalog, &vec![
+            &namespace_ident_1,
+            &namespace_ident_2,
+            &namespace_ident_3,
+        ])
+        .await;
+
+        assert_eq!(
+            to_set(catalog.list_namespaces(None).await.unwrap()),
+            to_set(vec![namespace_ident_1, namespace_ident_3])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_namespaces_returns_no_namespaces_under_parent() {
+        let warehouse_loc = temp_path();
+        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
+        let namespace_ident_1 = NamespaceIdent::new("a".into());
+        let namespace_ident_2 = NamespaceIdent::new("b".into());
+        create_namespaces(&catalog, &vec![&namespace_ident_1, &namespace_ident_2]).await;
+
+        assert_eq!(
+            catalog
+                .list_namespaces(Some(&namespace_ident_1))
+                .await
+                .unwrap(),
+            vec![]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_namespaces_returns_namespace_under_parent() {
+        let warehouse_loc = temp_path();
+        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
+        let namespace_ident_1 = NamespaceIdent::new("a".into());
+        let namespace_ident_2 = NamespaceIdent::from_strs(vec!["a", "b"]).unwrap();
+        let namespace_ident_3 = NamespaceIdent::new("c".into());
+        create_namespaces(&catalog, &vec![
+            &namespace_ident_1,
+            &namespace_ident_2,
+            &namespace_ident_3,
+        ])
+        .await;
+
+        assert_eq!(
+            to_set(catalog.list_namespaces(None).await.unwrap()),
+            to_set(vec![namespace_ident_1.clone(), namespace_ident_3])
+        );
+
+        assert_eq!(
+            catalog
+                .list_namespaces(Some(&namespace_ident_1))
+                .await
+                .unwrap(),
+            vec![NamespaceIdent::from_strs(vec!["a", "b"]).unwrap()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_namespaces_returns_multiple_namespaces_under_parent() {
+        let warehouse_loc = temp_path();
+        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
+        let namespace_ident_1 = NamespaceIdent::new("a".to_string());
+        let namespace_ident_2 = NamespaceIdent::from_strs(vec!["a", "a"]).unwrap();
+        let namespace_ident_3 = NamespaceIdent::from_strs(vec!["a", "b"]).unwrap();
+        let namespace_ident_4 = NamespaceIdent::from_strs(vec!["a", "c"]).unwrap();
+        let namespace_ident_5 = NamespaceIdent::new("b".into());
+        create_namespaces(&catalog, &vec![
+            &namespace_ident_1,
+            &namespace_ident_2,
+            &namespace_ident_3,
+            &namespace_ident_4,
+            &namespace_ident_5,
+        ])
+        .await;
+
+        assert_eq!(
+            to_set(
+                catalog
+                    .list_namespaces(Some(&namespace_ident_1))
+                    .await
+                    .unwrap()
+            ),
+            to_set(vec![
+                NamespaceIdent::from_strs(vec!["a", "a"]).unwrap(),
+                NamespaceIdent::from_strs(vec!["a", "b"]).unwrap(),
+                NamespaceIdent::from_strs(vec!["a", "c"]).unwrap(),
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_namespace_exists_returns_false() {
+        let warehouse_loc = temp_path();
+        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
+        let namespace_ident = NamespaceIdent::new("a".into());
+        create_namespace(&catalog, &namespace_ident).await;
+
+        assert!(
+            !catalog
+                .namespace_exists(&NamespaceIdent::new("b".into()))
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_namespace_exists_returns_true() {
+        let warehouse_loc = temp_path();
+        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
+        let namespace_ident = NamespaceIdent::new("a".into());
+        create_namespace(&catalog, &namespace_ident).await;
+
+        assert!(catalog.namespace_exists(&namespace_ident).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_create_namespace_with_properties() {
+        let warehouse_loc = temp_path();
+        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
+        let namespace_ident = NamespaceIdent::new("abc".into());
+
+        let mut properties = default_properties();
+        properties.insert("k".into(), "v".into());
+
+        assert_eq!(
+            catalog
+                .create_namespace(&namespace_ident, properties.clone())
+                .await
+                .unwrap(),
+            Namespace::with_properties(namespace_ident.clone(), properties.clone())
+        );
+
+        assert_eq!(
+            catalog.get_namespace(&namespace_ident).await.unwrap(),
+            Namespace::with_properties(namespace_ident, properties)
         );
+    }
 
-        assert_eq!(metadata.properties(), &HashMap::new());
+    #[tokio::test]
+    async fn test_create_namespace_throws_error_if_namespace_already_exists() {
+        let warehouse_loc = temp_path();
+        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
+        let namespace_ident = NamespaceIdent::new("a".into());
+        create_namespace(&catalog, &namespace_ident).await;
 
-        assert!(!table.readonly());
-    }
+        assert_eq!(
+            catalog
+                .create_namespace(&namespace_ident, HashMap::new())
+                .await
+                .unwrap_err()
+                .to_string(),
+            format!(
+                "NamespaceAlreadyExists => Namespace {:?} already exists",
+                &namespace_ident
+            )
+        );
 
-    fn assert_table_metadata_location_matches(table: &Table, regex_str: &str) {
-        let actual = table.metadata_location().unwrap().to_string();
-        let regex = Regex::new(regex_str).unwrap();
-        assert!(regex.is_match(&actual))
+        assert_eq!(
+            catalog.get_namespace(&namespace_ident).await.unwrap(),
+            Namespace::with_properties(namespace_ident, default_properties())
+        );
     }
 
     #[tokio::test]
-    async fn test_initialized() {
+    async fn test_create_nested_namespace() {
         let warehouse_loc = temp_path();
-        new_sql_catalog(warehouse_loc.clone(), Some("iceberg")).await;
-        // catalog instantiation should not fail even if tables exist
-        new_sql_catalog(warehouse_loc.clone(), Some("iceberg")).await;
-        new_sql_catalog(warehouse_loc.clone(), Some("iceberg")).await;
+        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
+        let parent_namespace_ident = NamespaceIdent::new("a".into());
+        create_namespace(&catalog, &parent_namespace_ident).await;
+
+        let child_namespace_ident = NamespaceIdent::from_strs(vec!["a", "b"]).unwrap();
+
+        assert_eq!(
+            catalog
+                .create_namespace(&child_namespace_ident, HashMap::new())
+                .await
+                .unwrap(),
+            Namespace::new(child_namespace_ident.clone())
+        );
+
+        assert_eq!(
+            catalog.get_namespace(&child_namespace_ident).await.unwrap(),
+            Namespace::with_properties(child_namespace_ident, default_properties())
+        );
     }
 
     #[tokio::test]
-    async fn test_builder_method() {
-        let sql_lite_uri = format!("sqlite:{}", temp_path());
-        sqlx::Sqlite::create_database(&sql_lite_uri).await.unwrap();
-        let warehouse_location = temp_path();
+    async fn test_create_deeply_nested_namespace() {
+        let warehouse_loc = temp_path();
+        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
+        let namespace_ident_a = NamespaceIdent::new("a".into());
+        let namespace_ident_a_b = NamespaceIdent::from_strs(vec!["a", "b"]).unwrap();
+        create_namespaces(&catalog, &vec![&namespace_ident_a, &namespace_ident_a_b]).await;
 
-        let catalog = SqlCatalogBuilder::default()
-            .uri(sql_lite_uri.to_string())
-            .warehouse_location(warehouse_location.clone())
-            .sql_bind_style(SqlBindStyle::QMark)
-            .load("iceberg", HashMap::default())
-            .await;
-        assert!(catalog.is_ok());
+        let namespace_ident_a_b_c = NamespaceIdent::from_strs(vec!["a", "b", "c"]).unwrap();
 
-        let catalog = catalog.unwrap();
-        assert!(catalog.warehouse_location == warehouse_location);
-        assert!(catalog.sql_bind_style == SqlBindStyle::QMark);
+        assert_eq!(
+            catalog
+                .create_namespace(&namespace_ident_a_b_c, HashMap::new())
+                .await
+                .unwrap(),
+            Namespace::new(namespace_ident_a_b_c.clone())
+        );
+
+        assert_eq!(
+            catalog.get_namespace(&namespace_ident_a_b_c).await.unwrap(),
+            Namespace::with_properties(namespace_ident_a_b_c, default_properties())
+        );
     }
 
-    /// Overwriting an sqlite database with a non-existent path causes
-    /// catalog generation to fail
     #[tokio::test]
-    async fn test_builder_props_non_existent_path_fails() {
-        let sql_lite_uri = format!("sqlite:{}", temp_path());
-        let sql_lite_uri2 = format!("sqlite:{}", temp_path());
-        sqlx::Sqlite::create_database(&sql_lite_uri).await.unwrap();
-        let warehouse_location = temp_path();
+    async fn test_update_namespace_noop() {
+        let warehouse_loc = temp_path();
+        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
+        let namespace_ident = NamespaceIdent::new("a".into());
+        create_namespace(&catalog, &namespace_ident).await;
 
-        let catalog = SqlCatalogBuilder::default()
-            .uri(sql_lite_uri)
-            .warehouse_location(warehouse_location)
-            .load(
-                "iceberg",
-                HashMap::from_iter([(SQL_CATALOG_PROP_URI.to_string(), sql_lite_uri2)]),
-            )
-            .await;
-        assert!(catalog.is_err());
+        catalog
+            .update_namespace(&namespace_ident, HashMap::new())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            *catalog
+                .get_namespace(&namespace_ident)
+                .await
+                .unwrap()
+                .properties(),
+            HashMap::from_iter([("exists".to_string(), "true".to_string())])
+        )
     }
 
-    /// Even when an invalid URI is specified in a builder method,
-    /// it can be successfully overridden with a valid URI in props
-    /// for catalog generation to succeed.
     #[tokio::test]
-    async fn test_builder_props_set_valid_uri() {
-        let sql_lite_uri = format!("sqlite:{}", temp_path());
-        let sql_lite_uri2 = format!("sqlite:{}", temp_path());
-        sqlx::Sqlite::create_database(&sql_lite_uri).await.unwrap();
-        let warehouse_location = temp_path();
+    async fn test_update_namespace() {
+        let warehouse_loc = temp_path();
+        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
+        let namespace_ident = NamespaceIdent::new("a".into());
+        create_namespace(&catalog, &namespace_ident).await;
 
-        let catalog = SqlCatalogBuilder::default()
-            .uri(sql_lite_uri2)
-            .warehouse_location(warehouse_location)
-            .load(
-                "iceberg",
-                HashMap::from_iter([(SQL_CATALOG_PROP_URI.to_string(), sql_lite_uri.clone())]),
-            )
-            .await;
-        assert!(catalog.is_ok());
+        let mut props = HashMap::from_iter([
+            ("prop1".to_string(), "val1".to_string()),
+            ("prop2".into(), "val2".into()),
+        ]);
+
+        catalog
+            .update_namespace(&namespace_ident, props.clone())
+            .await
+            .unwrap();
+
+        props.insert("exists".into(), "true".into());
+
+        assert_eq!(
+            *catalog
+                .get_namespace(&namespace_ident)
+                .await
+                .unwrap()
+                .properties(),
+            props
+        )
     }
 
-    /// values assigned via props take precedence
     #[tokio::test]
-    async fn test_builder_props_take_precedence() {
-        let sql_lite_uri = format!("sqlite:{}", temp_path());
-        sqlx::Sqlite::create_database(&sql_lite_uri).await.unwrap();
-        let warehouse_location = temp_path();
-        let warehouse_location2 = temp_path();
+    async fn test_update_nested_namespace() {
+        let warehouse_loc = temp_path();
+        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
+        let namespace_ident = NamespaceIdent::from_strs(["a", "b"]).unwrap();
+        create_namespace(&catalog, &namespace_ident).await;
 
-        let catalog = SqlCatalogBuilder::default()
-            .warehouse_location(warehouse_location2)
-            .sql_bind_style(SqlBindStyle::DollarNumeric)
-            .load(
-                "iceberg",
-                HashMap::from_iter([
-                    (SQL_CATALOG_PROP_URI.to_string(), sql_lite_uri),
-                    (
-                        SQL_CATALOG_PROP_WAREHOUSE.to_string(),
-                        warehouse_location.clone(),
-                    ),
-                    (
-                        SQL_CATALOG_PROP_BIND_STYLE.to_string(),
-                        SqlBindStyle::QMark.to_string(),
-                    ),
-                ]),
-            )
-            .await;
+        let mut props = HashMap::from_iter([
+            ("prop1".to_string(), "val1".to_string()),
+            ("prop2".into(), "val2".into()),
+        ]);
 
-        assert!(catalog.is_ok());
+        catalog
+            .update_namespace(&namespace_ident, props.clone())
+            .await
+            .unwrap();
 
-        let catalog = catalog.unwrap();
-        assert!(catalog.warehouse_location == warehouse_location);
-        assert!(catalog.sql_bind_style == SqlBindStyle::QMark);
+        props.insert("exists".into(), "true".into());
+
+        assert_eq!(
+            *catalog
+                .get_namespace(&namespace_ident)
+                .await
+                .unwrap()
+                .properties(),
+            props
+        )
     }
 
-    /// values assigned via props take precedence
     #[tokio::test]
-    async fn test_builder_props_take_precedence_props() {
-        let sql_lite_uri = format!("sqlite:{}", temp_path());
-        let sql_lite_uri2 = format!("sqlite:{}", temp_path());
-        sqlx::Sqlite::create_database(&sql_lite_uri).await.unwrap();
-        let warehouse_location = temp_path();
-        let warehouse_location2 = temp_path();
+    async fn test_update_namespace_errors_if_namespace_doesnt_exist() {
+        let warehouse_loc = temp_path();
+        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
+        let namespace_ident = NamespaceIdent::new("a".into());
 
         let props = HashMap::from_iter([
-            (SQL_CATALOG_PROP_URI.to_string(), sql_lite_uri.clone()),
-            (
-                SQL_CATALOG_PROP_WAREHOUSE.to_string(),
-                warehouse_location.clone(),
-            ),
-            (
-                SQL_CATALOG_PROP_BIND_STYLE.to_string(),
-                SqlBindStyle::QMark.to_string(),
-            ),
-        ]);
-        let props2 = HashMap::from_iter([
-            (SQL_CATALOG_PROP_URI.to_string(), sql_lite_uri2.clone()),
-            (
-                SQL_CATALOG_PROP_WAREHOUSE.to_string(),
-                warehouse_location2.clone(),
-            ),
-            (
-                SQL_CATALOG_PROP_BIND_STYLE.to_string(),
-                SqlBindStyle::DollarNumeric.to_string(),
-            ),
+            ("prop1".to_string(), "val1".to_string()),
+            ("prop2".into(), "val2".into()),
         ]);
 
-        let catalog = SqlCatalogBuilder::default()
-            .props(props2)
-            .load("iceberg", props)
-            .await;
-
-        assert!(catalog.is_ok());
+        let err = catalog
+            .update_namespace(&namespace_ident, props)
+            .await
+            .unwrap_err();
 
-        let catalog = catalog.unwrap();
-        assert!(catalog.warehouse_location == warehouse_location);
-        assert!(catalog.sql_bind_style == SqlBindStyle::QMark);
+        assert_eq!(
+            err.message(),
+            format!("No such namespace: {namespace_ident:?}")
+        );
     }
 
-    /// values assigned via props take precedence
     #[tokio::test]
-    async fn test_builder_props_take_precedence_prop() {
-        let sql_lite_uri = format!("sqlite:{}", temp_path());
-        let sql_lite_uri2 = format!("sqlite:{}", temp_path());
-        sqlx::Sqlite::create_database(&sql_lite_uri).await.unwrap();
-        let warehouse_location = temp_path();
-        let warehouse_location2 = temp_path();
+    async fn test_update_namespace_errors_if_nested_namespace_doesnt_exist() {
+        let warehouse_loc = temp_path();
+        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
+        let namespace_ident = NamespaceIdent::from_strs(["a", "b"]).unwrap();
 
         let props = HashMap::from_iter([
-            (SQL_CATALOG_PROP_URI.to_string(), sql_lite_uri.clone()),
-            (
-                SQL_CATALOG_PROP_WAREHOUSE.to_string(),
-                warehouse_location.clone(),
-            ),
-            (
-                SQL_CATALOG_PROP_BIND_STYLE.to_string(),
-                SqlBindStyle::QMark.to_string(),
-            ),
+            ("prop1".to_string(), "val1".to_string()),
+            ("prop2".into(), "val2".into()),
         ]);
 
-        let catalog = SqlCatalogBuilder::default()
-            .prop(SQL_CATALOG_PROP_URI.to_string(), sql_lite_uri2)
-            .prop(SQL_CATALOG_PROP_WAREHOUSE.to_string(), warehouse_location2)
-            .prop(
-                SQL_CATALOG_PROP_BIND_STYLE.to_string(),
-                SqlBindStyle::DollarNumeric.to_string(),
-            )
-            .load("iceberg", props)
-            .await;
-
-        assert!(catalog.is_ok());
+        let err = catalog
+            .update_namespace(&namespace_ident, props)
+            .await
+            .unwrap_err();
 
-        let catalog = catalog.unwrap();
-        assert!(catalog.warehouse_location == warehouse_location);
-        assert!(catalog.sql_bind_style == SqlBindStyle::QMark);
+        assert_eq!(
+            err.message(),
+            format!("No such namespace: {namespace_ident:?}")
+        );
     }
 
-    /// invalid value for `SqlBindStyle` causes catalog creation to fail
     #[tokio::test]
-    async fn test_builder_props_invalid_bind_style_fails() {
-        let sql_lite_uri = format!("sqlite:{}", temp_path());
-        sqlx::Sqlite::create_database(&sql_lite_uri).await.unwrap();
-        let warehouse_location = temp_path();
+    async fn test_drop_namespace() {
+        let warehouse_loc = temp_path();
+        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
+        let namespace_ident = NamespaceIdent::new("abc".into());
+        create_namespace(&catalog, &namespace_ident).await;
 
-        let catalog = SqlCatalogBuilder::default()
-            .load(
-                "iceberg",
-                HashMap::from_iter([
-                    (SQL_CATALOG_PROP_URI.to_string(), sql_lite_uri),
-                    (SQL_CATALOG_PROP_WAREHOUSE.to_string(), warehouse_location),
-                    (SQL_CATALOG_PROP_BIND_STYLE.to_string(), "AAA".to_string()),
-                ]),
-            )
-            .await;
+        catalog.drop_namespace(&namespace_ident).await.unwrap();
 
-        assert!(catalog.is_err());
+        assert!(!catalog.namespace_exists(&namespace_ident).await.unwrap())
     }
 
     #[tokio::test]
-    async fn test_list_namespaces_returns_empty_vector() {
+    async fn test_drop_nested_namespace() {
         let warehouse_loc = temp_path();
         let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
+        let namespace_ident_a = NamespaceIdent::new("a".into());
+        let namespace_ident_a_b = NamespaceIdent::from_strs(vec!["a", "b"]).unwrap();
+        create_namespaces(&catalog, &vec![&namespace_ident_a, &namespace_ident_a_b]).await;
 
-        assert_eq!(catalog.list_namespaces(None).await.unwrap(), vec![]);
-    }
+        catalog.drop_namespace(&namespace_ident_a_b).await.unwrap();
 
-    #[tokio::test]
-    async fn test_list_namespaces_returns_empty_different_name() {
-        let warehouse_loc = temp_path();
-        let catalog = new_sql_catalog(warehouse_loc.clone(), Some("iceberg")).await;
-        let namespace_ident_1 = NamespaceIdent::new("a".into());
-        let namespace_ident_2 = NamespaceIdent::new("b".into());
-        create_namespaces(&catalog, &vec![&namespace_ident_1, &namespace_ident_2]).await;
-        assert_eq!(
-            to_set(catalog.list_namespaces(None).await.unwrap()),
-            to_set(vec![namespace_ident_1, namespace_ident_2])
+        assert!(
+            !catalog
+                .namespace_exists(&namespace_ident_a_b)
+                .await
+                .unwrap()
         );
 
-        let catalog2 = new_sql_catalog(warehouse_loc, Some("test")).await;
-        assert_eq!(catalog2.list_namespaces(None).await.unwrap(), vec![]);
+        assert!(catalog.namespace_exists(&namespace_ident_a).await.unwrap());
     }
 
     #[tokio::test]
-    async fn test_list_namespaces_returns_multiple_namespaces() {
+    async fn test_drop_deeply_nested_namespace() {
         let warehouse_loc = temp_path();
         let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
-        let namespace_ident_1 = NamespaceIdent::new("a".into());
-        let namespace_ident_2 = NamespaceIdent::new("b".into());
-        create_namespaces(&catalog, &vec![&namespace_ident_1, &namespace_ident_2]).await;
+        let namespace_ident_a = NamespaceIdent::new("a".into());
+        let namespace_ident_a_b = NamespaceIdent::from_strs(vec!["a", "b"]).unwrap();
+        let namespace_ident_a_b_c = NamespaceIdent::from_strs(vec!["a", "b", "c"]).unwrap();
+        create_namespaces(&catalog, &vec![
+            &namespace_ident_a,
+            &namespace_ident_a_b,
+            &namespace_ident_a_b_c,
+        ])
+        .await;
 
-        assert_eq!(
-            to_set(catalog.list_namespaces(None).await.unwrap()),
-            to_set(vec![namespace_ident_1, namespace_ident_2])
+        catalog
+            .drop_namespace(&namespace_ident_a_b_c)
+            .await
+            .unwrap();
+
+        assert!(
+            !catalog
+                .namespace_exists(&namespace_ident_a_b_c)
+                .await
+                .unwrap()
+        );
+
+        assert!(
+            catalog
+                .namespace_exists(&namespace_ident_a_b)
+                .await
+                .unwrap()
         );
+
+        assert!(catalog.namespace_exists(&namespace_ident_a).await.unwrap());
     }
 
     #[tokio::test]
-    async fn test_list_namespaces_returns_only_top_level_namespaces() {
+    async fn test_drop_namespace_throws_error_if_namespace_doesnt_exist() {
         let warehouse_loc = temp_path();
         let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
-        let namespace_ident_1 = NamespaceIdent::new("a".into());
-        let namespace_ident_2 = NamespaceIdent::from_strs(vec!["a", "b"]).unwrap();
-        let namespace_ident_3 = NamespaceIdent::new("b".into());
-        create_namespaces(&catalog, &vec![
-            &namespace_ident_1,
-            &namespace_ident_2,
-            &namespace_ident_3,
-        ])
-        .await;
 
+        let non_existent_namespace_ident = NamespaceIdent::new("abc".into());
         assert_eq!(
-            to_set(catalog.list_namespaces(None).await.unwrap()),
-            to_set(vec![namespace_ident_1, namespace_ident_3])
-        );
+            catalog
+                .drop_namespace(&non_existent_namespace_ident)
+                .await
+                .unwrap_err()
+                .to_string(),
+            format!("NamespaceNotFound => No such namespace: {non_existent_namespace_ident:?}")
+        )
     }
 
     #[tokio::test]
-    async fn test_list_namespaces_returns_no_namespaces_under_parent() {
+    async fn test_drop_namespace_throws_error_if_nested_namespace_doesnt_exist() {
         let warehouse_loc = temp_path();
         let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
-        let namespace_ident_1 = NamespaceIdent::new("a".into());
-        let namespace_ident_2 = NamespaceIdent::new("b".into());
-        create_namespaces(&catalog, &vec![&namespace_ident_1, &namespace_ident_2]).await;
+        create_namespace(&catalog, &NamespaceIdent::new("a".into())).await;
 
+        let non_existent_namespace_ident =
+            NamespaceIdent::from_vec(vec!["a".into(), "b".into()]).unwrap();
         assert_eq!(
             catalog
-                .list_namespaces(Some(&namespace_ident_1))
+                .drop_namespace(&non_existent_namespace_ident)
                 .await
-                .unwrap(),
-            vec![]
-        );
+                .unwrap_err()
+                .to_string(),
+            format!("NamespaceNotFound => No such namespace: {non_existent_namespace_ident:?}")
+        )
     }
 
     #[tokio::test]
-    async fn test_list_namespaces_returns_namespace_under_parent() {
+    async fn test_drop_namespace_throws_error_if_namespace_has_nested_namespaces() {
         let warehouse_loc = temp_path();
         let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
-        let namespace_ident_1 = NamespaceIdent::new("a".into());
-        let namespace_ident_2 = NamespaceIdent::from_strs(vec!["a", "b"]).unwrap();
-        let namespace_ident_3 = NamespaceIdent::new("c".into());
+        let namespace_ident_a = NamespaceIdent::new("a".into());
+        let namespace_ident_a_b = NamespaceIdent::from_strs(vec!["a", "b"]).unwrap();
+        let namespace_ident_ab = NamespaceIdent::new("ab".into());
         create_namespaces(&catalog, &vec![
-            &namespace_ident_1,
-            &namespace_ident_2,
-            &namespace_ident_3,
+            &namespace_ident_a,
+            &namespace_ident_a_b,
+            &namespace_ident_ab,
         ])
         .await;
 
         assert_eq!(
-            to_set(catalog.list_namespaces(None).await.unwrap()),
-            to_set(vec![namespace_ident_1.clone(), namespace_ident_3])
+            catalog
+                .drop_namespace(&namespace_ident_a)
+                .await
+                .unwrap_err()
+                .message(),
+            format!("Namespace {namespace_ident_a:?} is not empty. Namespaces: [a.b].")
         );
 
-        assert_eq!(
+        assert!(catalog.namespace_exists(&namespace_ident_a).await.unwrap());
+        assert!(
             catalog
-                .list_namespaces(Some(&namespace_ident_1))
+                .namespace_exists(&namespace_ident_a_b)
                 .await
-                .unwrap(),
-            vec![NamespaceIdent::from_strs(vec!["a", "b"]).unwrap()]
+                .unwrap()
         );
     }
 
+    async fn create_view(catalog: &SqlCatalog, namespace: &str, name: &str) {
+        sqlx::query(&format!(
+            "INSERT INTO {CATALOG_TABLE_NAME}
+             ({CATALOG_FIELD_CATALOG_NAME}, {CATALOG_FIELD_TABLE_NAMESPACE}, {CATALOG_FIELD_TABLE_NAME}, {CATALOG_FIELD_METADATA_LOCATION_PROP}, {CATALOG_FIELD_RECORD_TYPE})
+             VALUES ('iceberg', '{namespace}', '{name}', '{name}.metadata.json', 'VIEW')"
+        ))
+        .execute(&catalog.connection)
+        .await
+        .unwrap();
+    }
+
     #[tokio::test]
-    async fn test_list_namespaces_returns_multiple_namespaces_under_parent() {
+    async fn test_drop_namespace_throws_error_if_namespace_is_not_empty() {
         let warehouse_loc = temp_path();
         let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
-        let namespace_ident_1 = NamespaceIdent::new("a".to_string());
-        let namespace_ident_2 = NamespaceIdent::from_strs(vec!["a", "a"]).unwrap();
-        let namespace_ident_3 = NamespaceIdent::from_strs(vec!["a", "b"]).unwrap();
-        let namespace_ident_4 = NamespaceIdent::from_strs(vec!["a", "c"]).unwrap();
-        let namespace_ident_5 = NamespaceIdent::new("b".into());
+        let namespace_ident = NamespaceIdent::new("a".into());
+        create_namespace(&catalog, &namespace_ident).await;
+        let tbl1 = TableIdent::new(namespace_ident.clone(), "tbl1".into());
+        let tbl2 = TableIdent::new(namespace_ident.clone(), "tbl2".into());
+        create_tables(&catalog, vec![&tbl2, &tbl1]).await;
+        create_view(&catalog, "a", "view1").await;
+
+        for result in [
+            catalog.drop_namespace(&namespace_ident).await,
+            catalog
+                .drop_namespace_with_cascade(&namespace_ident, false)
+                .await,
+        ] {
+            assert_eq!(
+                result.unwrap_err().message(),
+                format!(
+                    "Namespace {namespace_ident:?} is not empty. Tables: [tbl1, tbl2]. Views: [view1]."
+                )
+            );
+        }
+
+        assert!(catalog.namespace_exists(&namespace_ident).await.unwrap());
+        assert!(catalog.table_exists(&tbl1).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_drop_namespace_with_cascade() {
+        let warehouse_loc = temp_path();
+        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
+        let namespace_ident_a = NamespaceIdent::new("a".into());
+        let namespace_ident_a_b = NamespaceIdent::from_strs(vec!["a", "b"]).unwrap();
+        let namespace_ident_ab = NamespaceIdent::new("ab".into());
         create_namespaces(&catalog, &vec![
-            &namespace_ident_1,
-            &namespace_ident_2,
-            &namespace_ident_3,
-            &namespace_ident_4,
-            &namespace_ident_5,
+            &namespace_ident_a,
+            &namespace_ident_a_b,
+            &namespace_ident_ab,
         ])
         .await;
+        let tbl_a = TableIdent::new(namespace_ident_a.clone(), "tbl".into());
+        let tbl_a_b = TableIdent::new(namespace_ident_a_b.clone(), "tbl".into());
+        let tbl_ab = TableIdent::new(namespace_ident_ab.clone(), "tbl".into());
+        create_tables(&catalog, vec![&tbl_a, &tbl_a_b, &tbl_ab]).await;
+        create_view(&catalog, "a.b", "view1").await;
 
-        assert_eq!(
-            to_set(
-                catalog
-                    .list_namespaces(Some(&namespace_ident_1))
-                    .await
-                    .unwrap()
-            ),
-            to_set(vec![
-                NamespaceIdent::from_strs(vec!["a", "a"]).unwrap(),
-                NamespaceIdent::from_strs(vec!["a", "b"]).unwrap(),
-                NamespaceIdent::from_strs(vec!["a", "c"]).unwrap(),
-            ])
+        catalog
+            .drop_namespace_with_cascade(&namespace_ident_a, true)
+            .await
+            .unwrap();
+
+        assert!(!catalog.namespace_exists(&namespace_ident_a).await.unwrap());
+        assert!(
+            !catalog
+                .namespace_exists(&namespace_ident_a_b)
+                .await
+                .unwrap()
+        );
+        assert!(!catalog.table_exists(&tbl_a).await.unwrap());
+        assert!(!catalog.table_exists(&tbl_a_b).await.unwrap());
+        assert_eq!(catalog.list_namespaces(None).await.unwrap(), vec![
+            namespace_ident_ab.clone()
+        ]);
+        assert_eq!(
+            catalog.list_tables(&namespace_ident_ab).await.unwrap(),
+            vec![tbl_ab]
         );
+
+        // The dropped namespace and its views are gone, so it can be created again empty.
+        create_namespace(&catalog, &namespace_ident_a_b).await;
+        catalog.drop_namespace(&namespace_ident_a_b).await.unwrap();
     }
 
     #[tokio::test]
-    async fn test_namespace_exists_returns_false() {
+    async fn test_drop_empty_namespace_with_cascade() {
         let warehouse_loc = temp_path();
         let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
-        let namespace_ident = NamespaceIdent::new("a".into());
+        let namespace_ident = NamespaceIdent::new("abc".into());
         create_namespace(&catalog, &namespace_ident).await;
 
-        assert!(
-            !catalog
-                .namespace_exists(&NamespaceIdent::new("b".into()))
+        catalog
+            .drop_namespace_with_cascade(&namespace_ident, true)
+            .await
+            .unwrap();
+
+        assert!(!catalog.namespace_exists(&namespace_ident).await.unwrap());
+        assert_eq!(
+            catalog
+                .drop_namespace_with_cascade(&namespace_ident, true)
                 .await
-                .unwrap()
+                .unwrap_err()
+                .to_string(),
+            format!("NamespaceNotFound => No such namespace: {namespace_ident:?}")
         );
     }
 
     #[tokio::test]
-    async fn test_namespace_exists_returns_true() {
+    async fn test_list_tables_returns_empty_vector() {
         let warehouse_loc = temp_path();
         let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
         let namespace_ident = NamespaceIdent::new("a".into());
         create_namespace(&catalog, &namespace_ident).await;
 
-        assert!(catalog.namespace_exists(&namespace_ident).await.unwrap());
+        assert_eq!(catalog.list_tables(&namespace_ident).await.unwrap(), vec![]);
     }
 
     #[tokio::test]
-    async fn test_create_namespace_with_properties() {
+    async fn test_list_tables_throws_error_if_namespace_doesnt_exist() {
         let warehouse_loc = temp_path();
         let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
-        let namespace_ident = NamespaceIdent::new("abc".into());
 
-        let mut properties = default_properties();
-        properties.insert("k".into(), "v".into());
+        let non_existent_namespace_ident = NamespaceIdent::new("n1".into());
 
         assert_eq!(
             catalog
-                .create_namespace(&namespace_ident, properties.clone())
+                .list_tables(&non_existent_namespace_ident)
                 .await
-                .unwrap(),
-            Namespace::with_properties(namespace_ident.clone(), properties.clone())
+                .unwrap_err()
+                .to_string(),
+            format!("NamespaceNotFound => No such namespace: {non_existent_namespace_ident:?}"),
         );
+    }
+
+    #[tokio::test]
+    async fn test_list_tables_excludes_views() {
+        let warehouse_loc = temp_path();
+        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
+        let namespace_ident = NamespaceIdent::new("a".into());
+        create_namespace(&catalog, &namespace_ident).await;
+        let tbl2 = TableIdent::new(namespace_ident.clone(), "tbl2".into());
+        let tbl1 = TableIdent::new(namespace_ident.clone(), "tbl1".into());
+        create_tables(&catalog, vec![&tbl2, &tbl1]).await;
+
+        sqlx::query(&format!(
+            "INSERT INTO {CATALOG_TABLE_NAME}
+             ({CATALOG_FIELD_CATALOG_NAME}, {CATALOG_FIELD_TABLE_NAMESPACE}, {CATALOG_FIELD_TABLE_NAME}, {CATALOG_FIELD_METADATA_LOCATION_PROP}, {CATALOG_FIELD_RECORD_TYPE})
+             VALUES ('iceberg', 'a', 'view1', 'view1.metadata.json', 'VIEW')"
+        ))
+        .execute(&catalog.connection)
+        .await
+        .unwrap();
 
+        assert_eq!(catalog.list_tables(&namespace_ident).await.unwrap(), vec![
+            tbl1.clone(),
+            tbl2.clone()
+        ]);
         assert_eq!(
-            catalog.get_namespace(&namespace_ident).await.unwrap(),
-            Namespace::with_properties(namespace_ident, properties)
+            catalog
+                .list_tables_page(&namespace_ident, 10, None)
+                .await
+                .unwrap(),
+            (vec![tbl1, tbl2], None)
         );
     }
 
     #[tokio::test]
-    async fn test_create_namespace_throws_error_if_namespace_already_exists() {
+    async fn test_list_tables_page() {
         let warehouse_loc = temp_path();
         let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
         let namespace_ident = NamespaceIdent::new("a".into());
         create_namespace(&catalog, &namespace_ident).await;
+        let table_idents = (1..=5)
+            .map(|i| TableIdent::new(namespace_ident.clone(), format!("tbl{i}")))
+            .collect_vec();
+        create_tables(&catalog, table_idents.iter().rev().collect()).await;
+
+        let mut pages = vec![];
+        let mut page_token = None;
+        loop {
+            let (tables, next_page_token) = catalog
+                .list_tables_page(&namespace_ident, 2, page_token.as_deref())
+                .await
+                .unwrap();
+            pages.push(tables);
+            match next_page_token {
+                Some(token) => page_token = Some(token),
+                None => break,
+            }
+        }
+        assert_eq!(pages, vec![
+            table_idents[0..2].to_vec(),
+            table_idents[2..4].to_vec(),
+            table_idents[4..].to_vec(),
+        ]);
 
+        // A full last page has no next page.
         assert_eq!(
             catalog
-                .create_namespace(&namespace_ident, HashMap::new())
+                .list_tables_page(&namespace_ident, 5, None)
                 .await
-                .unwrap_err()
-                .to_string(),
-            format!(
-                "Unexpected => Namespace {:?} already exists",
-                &namespace_ident
-            )
+                .unwrap(),
+            (table_idents, None)
         );
 
+        let empty_namespace = NamespaceIdent::new("b".into());
+        create_namespace(&catalog, &empty_namespace).await;
         assert_eq!(
-            catalog.get_namespace(&namespace_ident).await.unwrap(),
-            Namespace::with_properties(namespace_ident, default_properties())
+            catalog
+                .list_tables_page(&empty_namespace, 2, None)
+                .await
+                .unwrap(),
+            (vec![], None)
+        );
+        assert_eq!(
+            catalog
+                .list_tables_page(&namespace_ident, 0, None)
+                .await
+                .unwrap_err()
+                .kind(),
+            ErrorKind::DataInvalid
         );
     }
 
     #[tokio::test]
-    async fn test_create_nested_namespace() {
+    async fn test_create_table_with_location() {
         let warehouse_loc = temp_path();
-        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
-        let parent_namespace_ident = NamespaceIdent::new("a".into());
-        create_namespace(&catalog, &parent_namespace_ident).await;
+        let catalog = new_sql_catalog(warehouse_loc.clone(), Some("iceberg")).await;
+        let namespace_ident = NamespaceIdent::new("a".into());
+        create_namespace(&catalog, &namespace_ident).await;
 
-        let child_namespace_ident = NamespaceIdent::from_strs(vec!["a", "b"]).unwrap();
+        let table_name = "abc";
+        let location = warehouse_loc.clone();
+        let table_creation = TableCreation::builder()
+            .name(table_name.into())
+            .location(location.clone())
+            .schema(simple_table_schema())
+            .build();
 
-        assert_eq!(
-            catalog
-                .create_namespace(&child_namespace_ident, HashMap::new())
+        let expected_table_ident = TableIdent::new(namespace_ident.clone(), table_name.into());
+
+        assert_table_eq(
+            &catalog
+                .create_table(&namespace_ident, table_creation)
                 .await
                 .unwrap(),
-            Namespace::new(child_namespace_ident.clone())
+            &expected_table_ident,
+            &simple_table_schema(),
         );
 
-        assert_eq!(
-            catalog.get_namespace(&child_namespace_ident).await.unwrap(),
-            Namespace::with_properties(child_namespace_ident, default_properties())
+        let table = catalog.load_table(&expected_table_ident).await.unwrap();
+
+        assert_table_eq(&table, &expected_table_ident, &simple_table_schema());
+
+        assert!(
+            table
+                .metadata_location()
+                .unwrap()
+                .to_string()
+                .starts_with(&location)
+        )
+    }
+
+    #[tokio::test]
+    async fn test_create_table_falls_back_to_namespace_location_if_table_location_is_missing() {
+        let warehouse_loc = temp_path();
+        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
+
+        let namespace_ident = NamespaceIdent::new("a".into());
+        let mut namespace_properties = HashMap::new();
+        let namespace_location = temp_path();
+        namespace_properties.insert(
+            NAMESPACE_LOCATION_PROPERTY_KEY.to_string(),
+            namespace_location.to_string(),
         );
+        catalog
+            .create_namespace(&namespace_ident, namespace_properties)
+            .await
+            .unwrap();
+
+        let table_name = "tbl1";
+        let expected_table_ident = TableIdent::new(namespace_ident.clone(), table_name.into());
+        let expected_table_metadata_location_regex =
+            format!("^{namespace_location}/tbl1/metadata/00000-{UUID_REGEX_STR}.metadata.json$",);
+
+        let table = catalog
+            .create_table(
+                &namespace_ident,
+                TableCreation::builder()
+                    .name(table_name.into())
+                    .schema(simple_table_schema())
+                    // no location specified for table
+                    .build(),
+            )
+            .await
+            .unwrap();
+        assert_table_eq(&table, &expected_table_ident, &simple_table_schema());
+        assert_table_metadata_location_matches(&table, &expected_table_metadata_location_regex);
+
+        let table = catalog.load_table(&expected_table_ident).await.unwrap();
+        assert_table_eq(&table, &expected_table_ident, &simple_table_schema());
+        assert_table_metadata_location_matches(&table, &expected_table_metadata_location_regex);
     }
 
     #[tokio::test]
-    async fn test_create_deeply_nested_namespace() {
+    async fn test_create_table_default_location_ignores_trailing_slashes() {
+        let warehouse_loc = temp_path();
+        let catalog = new_sql_catalog(format!("{warehouse_loc}/"), Some("iceberg")).await;
+
+        let namespace_location = temp_path();
+        let namespace_with_location = NamespaceIdent::new("a".into());
+        catalog
+            .create_namespace(
+                &namespace_with_location,
+                HashMap::from([(
+                    NAMESPACE_LOCATION_PROPERTY_KEY.to_string(),
+                    format!("{namespace_location}/"),
+                )]),
+            )
+            .await
+            .unwrap();
+        let namespace_without_location = NamespaceIdent::new("b".into());
+        catalog
+            .create_namespace(&namespace_without_location, HashMap::new())
+            .await
+            .unwrap();
+
+        for (namespace, expected_location) in [
+            (
+                &namespace_with_location,
+                format!("{namespace_location}/tbl1"),
+            ),
+            (
+                &namespace_without_location,
+                format!("{warehouse_loc}/b/tbl1"),
+            ),
+        ] {
+            let table = catalog
+                .create_table(
+                    namespace,
+                    TableCreation::builder()
+                        .name("tbl1".into())
+                        .schema(simple_table_schema())
+                        .build(),
+                )
+                .await
+                .unwrap();
+            assert_eq!(table.metadata().location(), expected_location);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_table_in_nested_namespace_falls_back_to_nested_namespace_location_if_table_location_is_missing()
+     {
         let warehouse_loc = temp_path();
         let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
-        let namespace_ident_a = NamespaceIdent::new("a".into());
-        let namespace_ident_a_b = NamespaceIdent::from_strs(vec!["a", "b"]).unwrap();
-        create_namespaces(&catalog, &vec![&namespace_ident_a, &namespace_ident_a_b]).await;
 
-        let namespace_ident_a_b_c = NamespaceIdent::from_strs(vec!["a", "b", "c"]).unwrap();
+        let namespace_ident = NamespaceIdent::new("a".into());
+        let mut namespace_properties = HashMap::new();
+        let namespace_location = temp_path();
+        namespace_properties.insert(
+            NAMESPACE_LOCATION_PROPERTY_KEY.to_string(),
+            namespace_location.to_string(),
+        );
+        catalog
+            .create_namespace(&namespace_ident, namespace_properties)
+            .await
+            .unwrap();
+
+        let nested_namespace_ident = NamespaceIdent::from_strs(vec!["a", "b"]).unwrap();
+        let mut nested_namespace_properties = HashMap::new();
+        let nested_namespace_location = temp_path();
+        nested_namespace_properties.insert(
+            NAMESPACE_LOCATION_PROPERTY_KEY.to_string(),
+            nested_namespace_location.to_string(),
+        );
+        catalog
+            .create_namespace(&nested_namespace_ident, nested_namespace_properties)
+            .await
+            .unwrap();
+
+        let table_name = "tbl1";
+        let expected_table_ident =
+            TableIdent::new(nested_namespace_ident.clone(), table_name.into());
+        let expected_table_metadata_location_regex = format!(
+            "^{nested_namespace_location}/tbl1/metadata/00000-{UUID_REGEX_STR}.metadata.json$",
+        );
+
+        let table = catalog
+            .create_table(
+                &nested_namespace_ident,
+                TableCreation::builder()
+                    .name(table_name.into())
+                    .schema(simple_table_schema())
+                    // no location specified for table
+                    .build(),
+            )
+            .await
+            .unwrap();
+        assert_table_eq(&table, &expected_table_ident, &simple_table_schema());
+        assert_table_metadata_location_matches(&table, &expected_table_metadata_location_regex);
+
+        let table = catalog.load_table(&expected_table_ident).await.unwrap();
+        assert_table_eq(&table, &expected_table_ident, &simple_table_schema());
+        assert_table_metadata_location_matches(&table, &expected_table_metadata_location_regex);
+    }
+
+    #[tokio::test]
+    async fn test_create_table_falls_back_to_warehouse_location_if_both_table_location_and_namespace_location_are_missing()
+     {
+        let warehouse_loc = temp_path();
+        let catalog = new_sql_catalog(warehouse_loc.clone(), Some("iceberg")).await;
+
+        let namespace_ident = NamespaceIdent::new("a".into());
+        // note: no location specified in namespace_properties
+        let namespace_properties = HashMap::new();
+        catalog
+            .create_namespace(&namespace_ident, namespace_properties)
+            .await
+            .unwrap();
+
+        let table_name = "tbl1";
+        let expected_table_ident = TableIdent::new(namespace_ident.clone(), table_name.into());
+        let expected_table_metadata_location_regex =
+            format!("^{warehouse_loc}/a/tbl1/metadata/00000-{UUID_REGEX_STR}.metadata.json$");
 
-        assert_eq!(
-            catalog
-                .create_namespace(&namespace_ident_a_b_c, HashMap::new())
-                .await
-                .unwrap(),
-            Namespace::new(namespace_ident_a_b_c.clone())
-        );
+        let table = catalog
+            .create_table(
+                &namespace_ident,
+                TableCreation::builder()
+                    .name(table_name.into())
+                    .schema(simple_table_schema())
+                    // no location specified for table
+                    .build(),
+            )
+            .await
+            .unwrap();
+        assert_table_eq(&table, &expected_table_ident, &simple_table_schema());
+        assert_table_metadata_location_matches(&table, &expected_table_metadata_location_regex);
 
-        assert_eq!(
-            catalog.get_namespace(&namespace_ident_a_b_c).await.unwrap(),
-            Namespace::with_properties(namespace_ident_a_b_c, default_properties())
-        );
+        let table = catalog.load_table(&expected_table_ident).await.unwrap();
+        assert_table_eq(&table, &expected_table_ident, &simple_table_schema());
+        assert_table_metadata_location_matches(&table, &expected_table_metadata_location_regex);
     }
 
     #[tokio::test]
-    async fn test_update_namespace_noop() {
+    async fn test_create_table_in_nested_namespace_falls_back_to_warehouse_location_if_both_table_location_and_namespace_location_are_missing()
+     {
         let warehouse_loc = temp_path();
-        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
+        let catalog = new_sql_catalog(warehouse_loc.clone(), Some("iceberg")).await;
+
         let namespace_ident = NamespaceIdent::new("a".into());
         create_namespace(&catalog, &namespace_ident).await;
 
-        catalog
-            .update_namespace(&namespace_ident, HashMap::new())
+        let nested_namespace_ident = NamespaceIdent::from_strs(vec!["a", "b"]).unwrap();
+        create_namespace(&catalog, &nested_namespace_ident).await;
+
+        let table_name = "tbl1";
+        let expected_table_ident =
+            TableIdent::new(nested_namespace_ident.clone(), table_name.into());
+        let expected_table_metadata_location_regex =
+            format!("^{warehouse_loc}/a/b/tbl1/metadata/00000-{UUID_REGEX_STR}.metadata.json$");
+
+        let table = catalog
+            .create_table(
+                &nested_namespace_ident,
+                TableCreation::builder()
+                    .name(table_name.into())
+                    .schema(simple_table_schema())
+                    // no location specified for table
+                    .build(),
+            )
             .await
             .unwrap();
+        assert_table_eq(&table, &expected_table_ident, &simple_table_schema());
+        assert_table_metadata_location_matches(&table, &expected_table_metadata_location_regex);
 
-        assert_eq!(
-            *catalog
-                .get_namespace(&namespace_ident)
-                .await
-                .unwrap()
-                .properties(),
-            HashMap::from_iter([("exists".to_string(), "true".to_string())])
-        )
+        let table = catalog.load_table(&expected_table_ident).await.unwrap();
+        assert_table_eq(&table, &expected_table_ident, &simple_table_schema());
+        assert_table_metadata_location_matches(&table, &expected_table_metadata_location_regex);
     }
 
     #[tokio::test]
-    async fn test_update_namespace() {
+    async fn test_create_table_throws_error_if_table_with_same_name_already_exists() {
         let warehouse_loc = temp_path();
-        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
+        let catalog = new_sql_catalog(warehouse_loc.clone(), Some("iceberg")).await;
         let namespace_ident = NamespaceIdent::new("a".into());
         create_namespace(&catalog, &namespace_ident).await;
+        let table_name = "tbl1";
+        let table_ident = TableIdent::new(namespace_ident.clone(), table_name.into());
+        create_table(&catalog, &table_ident).await;
 
-        let mut props = HashMap::from_iter([
-            ("prop1".to_string(), "val1".to_string()),
-            ("prop2".into(), "val2".into()),
-        ]);
-
-        catalog
-            .update_namespace(&namespace_ident, props.clone())
-            .await
-            .unwrap();
-
-        props.insert("exists".into(), "true".into());
+        let tmp_dir = TempDir::new().unwrap();
+        let location = tmp_dir.path().to_str().unwrap().to_string();
 
         assert_eq!(
-            *catalog
-                .get_namespace(&namespace_ident)
+            catalog
+                .create_table(
+                    &namespace_ident,
+                    TableCreation::builder()
+                        .name(table_name.into())
+                        .schema(simple_table_schema())
+                        .location(location)
+                        .build()
+                )
                 .await
-                .unwrap()
-                .properties(),
-            props
-        )
+                .unwrap_err()
+                .to_string(),
+            format!(
+                "TableAlreadyExists => Table {:?} already exists.",
+                &table_ident
+            )
+        );
     }
 
     #[tokio::test]
-    async fn test_update_nested_namespace() {
+    async fn test_rename_table_in_same_namespace() {
         let warehouse_loc = temp_path();
         let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
-        let namespace_ident = NamespaceIdent::from_strs(["a", "b"]).unwrap();
+        let namespace_ident = NamespaceIdent::new("n1".into());
         create_namespace(&catalog, &namespace_ident).await;
-
-        let mut props = HashMap::from_iter([
-            ("prop1".to_string(), "val1".to_string()),
-            ("prop2".into(), "val2".into()),
-        ]);
+        let src_table_ident = TableIdent::new(namespace_ident.clone(), "tbl1".into());
+        let dst_table_ident = TableIdent::new(namespace_ident.clone(), "tbl2".into());
+        create_table(&catalog, &src_table_ident).await;
 
         catalog
-            .update_namespace(&namespace_ident, props.clone())
+            .rename_table(&src_table_ident, &dst_table_ident)
             .await
             .unwrap();
 
-        props.insert("exists".into(), "true".into());
-
-        assert_eq!(
-            *catalog
-                .get_namespace(&namespace_ident)
-                .await
-                .unwrap()
-                .properties(),
-            props
-        )
+        assert_eq!(catalog.list_tables(&namespace_ident).await.unwrap(), vec![
+            dst_table_ident
+        ],);
     }
 
     #[tokio::test]
-    async fn test_update_namespace_errors_if_namespace_doesnt_exist() {
+    async fn test_rename_table_across_namespaces() {
         let warehouse_loc = temp_path();
         let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
-        let namespace_ident = NamespaceIdent::new("a".into());
-
-        let props = HashMap::from_iter([
-            ("prop1".to_string(), "val1".to_string()),
-            ("prop2".into(), "val2".into()),
-        ]);
+        let src_namespace_ident = NamespaceIdent::new("a".into());
+        let dst_namespace_ident = NamespaceIdent::new("b".into());
+        create_namespaces(&catalog, &vec![&src_namespace_ident, &dst_namespace_ident]).await;
+        let src_table_ident = TableIdent::new(src_namespace_ident.clone(), "tbl1".into());
+        let dst_table_ident = TableIdent::new(dst_namespace_ident.clone(), "tbl2".into());
+        create_table(&catalog, &src_table_ident).await;
 
-        let err = catalog
-            .update_namespace(&namespace_ident, props)
+        catalog
+            .rename_table(&src_table_ident, &dst_table_ident)
             .await
-            .unwrap_err();
+            .unwrap();
 
         assert_eq!(
-            err.message(),
-            format!("No such namespace: {namespace_ident:?}")
+            catalog.list_tables(&src_namespace_ident).await.unwrap(),
+            vec![],
         );
-    }
-
-    #[tokio::test]
-    async fn test_update_namespace_errors_if_nested_namespace_doesnt_exist() {
-        let warehouse_loc = temp_path();
-        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
-        let namespace_ident = NamespaceIdent::from_strs(["a", "b"]).unwrap();
-
-        let props = HashMap::from_iter([
-            ("prop1".to_string(), "val1".to_string()),
-            ("prop2".into(), "val2".into()),
-        ]);
-
-        let err = catalog
-            .update_namespace(&namespace_ident, props)
-            .await
-            .unwrap_err();
 
         assert_eq!(
-            err.message(),
-            format!("No such namespace: {namespace_ident:?}")
+            catalog.list_tables(&dst_namespace_ident).await.unwrap(),
+            vec![dst_table_ident],
         );
     }
 
     #[tokio::test]
-    async fn test_drop_namespace() {
+    async fn test_rename_table_src_table_is_same_as_dst_table() {
         let warehouse_loc = temp_path();
         let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
-        let namespace_ident = NamespaceIdent::new("abc".into());
+        let namespace_ident = NamespaceIdent::new("n1".into());
         create_namespace(&catalog, &namespace_ident).await;
+        let table_ident = TableIdent::new(namespace_ident.clone(), "tbl".into());
+        create_table(&catalog, &table_ident).await;
 
-        catalog.drop_namespace(&namespace_ident).await.unwrap();
-
-        assert!(!catalog.namespace_exists(&namespace_ident).await.unwrap())
-    }
-
-    #[tokio::test]
-    async fn test_drop_nested_namespace() {
-        let warehouse_loc = temp_path();
-        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
-        let namespace_ident_a = NamespaceIdent::new("a".into());
-        let namespace_ident_a_b = NamespaceIdent::from_strs(vec!["a", "b"]).unwrap();
-        create_namespaces(&catalog, &vec![&namespace_ident_a, &namespace_ident_a_b]).await;
-
-        catalog.drop_namespace(&namespace_ident_a_b).await.unwrap();
-
-        assert!(
-            !catalog
-                .namespace_exists(&namespace_ident_a_b)
-                .await
-                .unwrap()
-        );
+        catalog
+            .rename_table(&table_ident, &table_ident)
+            .await
+            .unwrap();
 
-        assert!(catalog.namespace_exists(&namespace_ident_a).await.unwrap());
+        assert_eq!(catalog.list_tables(&namespace_ident).await.unwrap(), vec![
+            table_ident
+        ],);
     }
 
     #[tokio::test]
-    async fn test_drop_deeply_nested_namespace() {
+    async fn test_rename_table_across_nested_namespaces() {
         let warehouse_loc = temp_path();
         let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
         let namespace_ident_a = NamespaceIdent::new("a".into());
@@ -1753,111 +3062,165 @@ mod tests {
         ])
         .await;
 
+        let src_table_ident = TableIdent::new(namespace_ident_a_b_c.clone(), "tbl1".into());
+        create_tables(&catalog, vec![&src_table_ident]).await;
+
+        let dst_table_ident = TableIdent::new(namespace_ident_a_b.clone(), "tbl1".into());
         catalog
-            .drop_namespace(&namespace_ident_a_b_c)
+            .rename_table(&src_table_ident, &dst_table_ident)
             .await
             .unwrap();
 
-        assert!(
-            !catalog
-                .namespace_exists(&namespace_ident_a_b_c)
-                .await
-                .unwrap()
-        );
+        assert!(!catalog.table_exists(&src_table_ident).await.unwrap());
 
-        assert!(
+        assert!(catalog.table_exists(&dst_table_ident).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_rename_table_throws_error_if_dst_namespace_doesnt_exist() {
+        let warehouse_loc = temp_path();
+        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
+        let src_namespace_ident = NamespaceIdent::new("n1".into());
+        let src_table_ident = TableIdent::new(src_namespace_ident.clone(), "tbl1".into());
+        create_namespace(&catalog, &src_namespace_ident).await;
+        create_table(&catalog, &src_table_ident).await;
+
+        let non_existent_dst_namespace_ident = NamespaceIdent::new("n2".into());
+        let dst_table_ident =
+            TableIdent::new(non_existent_dst_namespace_ident.clone(), "tbl1".into());
+        assert_eq!(
             catalog
-                .namespace_exists(&namespace_ident_a_b)
+                .rename_table(&src_table_ident, &dst_table_ident)
                 .await
-                .unwrap()
+                .unwrap_err()
+                .to_string(),
+            format!("NamespaceNotFound => No such namespace: {non_existent_dst_namespace_ident:?}"),
         );
-
-        assert!(catalog.namespace_exists(&namespace_ident_a).await.unwrap());
     }
 
     #[tokio::test]
-    async fn test_drop_namespace_throws_error_if_namespace_doesnt_exist() {
+    async fn test_rename_table_throws_error_if_src_table_doesnt_exist() {
         let warehouse_loc = temp_path();
         let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
+        let namespace_ident = NamespaceIdent::new("n1".into());
+        create_namespace(&catalog, &namespace_ident).await;
+        let src_table_ident = TableIdent::new(namespace_ident.clone(), "tbl1".into());
+        let dst_table_ident = TableIdent::new(namespace_ident.clone(), "tbl2".into());
 
-        let non_existent_namespace_ident = NamespaceIdent::new("abc".into());
         assert_eq!(
             catalog
-                .drop_namespace(&non_existent_namespace_ident)
+                .rename_table(&src_table_ident, &dst_table_ident)
                 .await
                 .unwrap_err()
                 .to_string(),
-            format!("Unexpected => No such namespace: {non_existent_namespace_ident:?}")
-        )
+            format!("TableNotFound => No such table: {src_table_ident:?}"),
+        );
     }
 
     #[tokio::test]
-    async fn test_drop_namespace_throws_error_if_nested_namespace_doesnt_exist() {
+    async fn test_rename_table_throws_error_if_dst_table_already_exists() {
         let warehouse_loc = temp_path();
         let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
-        create_namespace(&catalog, &NamespaceIdent::new("a".into())).await;
+        let namespace_ident = NamespaceIdent::new("n1".into());
+        create_namespace(&catalog, &namespace_ident).await;
+        let src_table_ident = TableIdent::new(namespace_ident.clone(), "tbl1".into());
+        let dst_table_ident = TableIdent::new(namespace_ident.clone(), "tbl2".into());
+        create_tables(&catalog, vec![&src_table_ident, &dst_table_ident]).await;
 
-        let non_existent_namespace_ident =
-            NamespaceIdent::from_vec(vec!["a".into(), "b".into()]).unwrap();
         assert_eq!(
             catalog
-                .drop_namespace(&non_existent_namespace_ident)
+                .rename_table(&src_table_ident, &dst_table_ident)
                 .await
                 .unwrap_err()
                 .to_string(),
-            format!("Unexpected => No such namespace: {non_existent_namespace_ident:?}")
-        )
+            format!(
+                "TableAlreadyExists => Table {:?} already exists.",
+                &dst_table_ident
+            ),
+        );
     }
 
     #[tokio::test]
-    async fn test_dropping_a_namespace_does_not_drop_namespaces_nested_under_that_one() {
+    async fn test_drop_table_throws_error_if_table_not_exist() {
         let warehouse_loc = temp_path();
-        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
-        let namespace_ident_a = NamespaceIdent::new("a".into());
-        let namespace_ident_a_b = NamespaceIdent::from_strs(vec!["a", "b"]).unwrap();
-        create_namespaces(&catalog, &vec![&namespace_ident_a, &namespace_ident_a_b]).await;
-
-        catalog.drop_namespace(&namespace_ident_a).await.unwrap();
-
-        assert!(!catalog.namespace_exists(&namespace_ident_a).await.unwrap());
+        let catalog = new_sql_catalog(warehouse_loc.clone(), Some("iceberg")).await;
+        let namespace_ident = NamespaceIdent::new("a".into());
+        let table_name = "tbl1";
+        let table_ident = TableIdent::new(namespace_ident.clone(), table_name.into());
+        create_namespace(&catalog, &namespace_ident).await;
 
-        assert!(
-            catalog
-                .namespace_exists(&namespace_ident_a_b)
-                .await
-                .unwrap()
+        let err = catalog
+            .drop_table(&table_ident)
+            .await
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            err,
+            "TableNotFound => No such table: TableIdent { namespace: NamespaceIdent([\"a\"]), name: \"tbl1\" }"
         );
     }
 
     #[tokio::test]
-    async fn test_list_tables_returns_empty_vector() {
+    async fn test_drop_table() {
         let warehouse_loc = temp_path();
-        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
+        let catalog = new_sql_catalog(warehouse_loc.clone(), Some("iceberg")).await;
         let namespace_ident = NamespaceIdent::new("a".into());
+        let table_name = "tbl1";
+        let table_ident = TableIdent::new(namespace_ident.clone(), table_name.into());
         create_namespace(&catalog, &namespace_ident).await;
 
-        assert_eq!(catalog.list_tables(&namespace_ident).await.unwrap(), vec![]);
+        let location = warehouse_loc.clone();
+        let table_creation = TableCreation::builder()
+            .name(table_name.into())
+            .location(location.clone())
+            .schema(simple_table_schema())
+            .build();
+
+        catalog
+            .create_table(&namespace_ident, table_creation)
+            .await
+            .unwrap();
+
+        let table = catalog.load_table(&table_ident).await.unwrap();
+        assert_table_eq(&table, &table_ident, &simple_table_schema());
+
+        catalog.drop_table(&table_ident).await.unwrap();
+        let err = catalog
+            .load_table(&table_ident)
+            .await
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            err,
+            "TableNotFound => No such table: TableIdent { namespace: NamespaceIdent([\"a\"]), name: \"tbl1\" }"
+        );
     }
 
     #[tokio::test]
-    async fn test_list_tables_throws_error_if_namespace_doesnt_exist() {
+    async fn test_register_table_throws_error_if_table_with_same_name_already_exists() {
         let warehouse_loc = temp_path();
-        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
-
-        let non_existent_namespace_ident = NamespaceIdent::new("n1".into());
+        let catalog = new_sql_catalog(warehouse_loc.clone(), Some("iceberg")).await;
+        let namespace_ident = NamespaceIdent::new("a".into());
+        create_namespace(&catalog, &namespace_ident).await;
+        let table_name = "tbl1";
+        let table_ident = TableIdent::new(namespace_ident.clone(), table_name.into());
+        create_table(&catalog, &table_ident).await;
 
         assert_eq!(
             catalog
-                .list_tables(&non_existent_namespace_ident)
+                .register_table(&table_ident, warehouse_loc)
                 .await
                 .unwrap_err()
                 .to_string(),
-            format!("Unexpected => No such namespace: {non_existent_namespace_ident:?}"),
+            format!(
+                "TableAlreadyExists => Table {:?} already exists.",
+                &table_ident
+            )
         );
     }
 
     #[tokio::test]
-    async fn test_create_table_with_location() {
+    async fn test_register_table() {
         let warehouse_loc = temp_path();
         let catalog = new_sql_catalog(warehouse_loc.clone(), Some("iceberg")).await;
         let namespace_ident = NamespaceIdent::new("a".into());
@@ -1871,551 +3234,623 @@ mod tests {
             .schema(simple_table_schema())
             .build();
 
-        let expected_table_ident = TableIdent::new(namespace_ident.clone(), table_name.into());
-
-        assert_table_eq(
-            &catalog
-                .create_table(&namespace_ident, table_creation)
-                .await
-                .unwrap(),
-            &expected_table_ident,
-            &simple_table_schema(),
-        );
-
-        let table = catalog.load_table(&expected_table_ident).await.unwrap();
-
-        assert_table_eq(&table, &expected_table_ident, &simple_table_schema());
-
-        assert!(
-            table
-                .metadata_location()
-                .unwrap()
-                .to_string()
-                .starts_with(&location)
-        )
-    }
-
-    #[tokio::test]
-    async fn test_create_table_falls_back_to_namespace_location_if_table_location_is_missing() {
-        let warehouse_loc = temp_path();
-        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
-
-        let namespace_ident = NamespaceIdent::new("a".into());
-        let mut namespace_properties = HashMap::new();
-        let namespace_location = temp_path();
-        namespace_properties.insert(
-            NAMESPACE_LOCATION_PROPERTY_KEY.to_string(),
-            namespace_location.to_string(),
-        );
-        catalog
-            .create_namespace(&namespace_ident, namespace_properties)
+        let table_ident = TableIdent::new(namespace_ident.clone(), table_name.into());
+        let expected_table = catalog
+            .create_table(&namespace_ident, table_creation)
             .await
             .unwrap();
 
-        let table_name = "tbl1";
-        let expected_table_ident = TableIdent::new(namespace_ident.clone(), table_name.into());
-        let expected_table_metadata_location_regex =
-            format!("^{namespace_location}/tbl1/metadata/00000-{UUID_REGEX_STR}.metadata.json$",);
+        let metadata_location = expected_table
+            .metadata_location()
+            .expect("Expected metadata location to be set")
+            .to_string();
+
+        assert_table_eq(&expected_table, &table_ident, &simple_table_schema());
+
+        let _ = catalog.drop_table(&table_ident).await;
 
         let table = catalog
-            .create_table(
-                &namespace_ident,
-                TableCreation::builder()
-                    .name(table_name.into())
-                    .schema(simple_table_schema())
-                    // no location specified for table
-                    .build(),
-            )
+            .register_table(&table_ident, metadata_location.clone())
             .await
             .unwrap();
-        assert_table_eq(&table, &expected_table_ident, &simple_table_schema());
-        assert_table_metadata_location_matches(&table, &expected_table_metadata_location_regex);
 
-        let table = catalog.load_table(&expected_table_ident).await.unwrap();
-        assert_table_eq(&table, &expected_table_ident, &simple_table_schema());
-        assert_table_metadata_location_matches(&table, &expected_table_metadata_location_regex);
+        assert_eq!(table.identifier(), expected_table.identifier());
+        assert_eq!(table.metadata_location(), Some(metadata_location.as_str()));
     }
 
-    #[tokio::test]
-    async fn test_create_table_in_nested_namespace_falls_back_to_nested_namespace_location_if_table_location_is_missing()
-     {
-        let warehouse_loc = temp_path();
-        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
-
-        let namespace_ident = NamespaceIdent::new("a".into());
-        let mut namespace_properties = HashMap::new();
-        let namespace_location = temp_path();
-        namespace_properties.insert(
-            NAMESPACE_LOCATION_PROPERTY_KEY.to_string(),
-            namespace_location.to_string(),
-        );
-        catalog
-            .create_namespace(&namespace_ident, namespace_properties)
-            .await
-            .unwrap();
-
-        let nested_namespace_ident = NamespaceIdent::from_strs(vec!["a", "b"]).unwrap();
-        let mut nested_namespace_properties = HashMap::new();
-        let nested_namespace_location = temp_path();
-        nested_namespace_properties.insert(
-            NAMESPACE_LOCATION_PROPERTY_KEY.to_string(),
-            nested_namespace_location.to_string(),
-        );
-        catalog
-            .create_namespace(&nested_namespace_ident, nested_namespace_properties)
-            .await
-            .unwrap();
+    /// A metadata store keeping the metadata JSON of each location in memory.
+    #[derive(Debug, Default)]
+    struct InMemoryMetadataStore {
+        blobs: Mutex<HashMap<String, Vec<u8>>>,
+    }
 
-        let table_name = "tbl1";
-        let expected_table_ident =
-            TableIdent::new(nested_namespace_ident.clone(), table_name.into());
-        let expected_table_metadata_location_regex = format!(
-            "^{nested_namespace_location}/tbl1/metadata/00000-{UUID_REGEX_STR}.metadata.json$",
-        );
+    #[async_trait]
+    impl MetadataStore for InMemoryMetadataStore {
+        async fn read(&self, metadata_location: &str) -> Result<TableMetadata> {
+            let blobs = self.blobs.lock().unwrap();
+            let blob = blobs.get(metadata_location).ok_or_else(|| {
+                Error::new(
+                    ErrorKind::DataInvalid,
+                    format!("No metadata stored at {metadata_location}"),
+                )
+            })?;
+            TableMetadata::from_json_bytes(blob)
+        }
 
-        let table = catalog
-            .create_table(
-                &nested_namespace_ident,
-                TableCreation::builder()
-                    .name(table_name.into())
-                    .schema(simple_table_schema())
-                    // no location specified for table
-                    .build(),
-            )
-            .await
-            .unwrap();
-        assert_table_eq(&table, &expected_table_ident, &simple_table_schema());
-        assert_table_metadata_location_matches(&table, &expected_table_metadata_location_regex);
+        async fn write(&self, metadata_location: &str, metadata: &TableMetadata) -> Result<()> {
+            let blob = metadata.to_json_bytes()?;
+            self.blobs
+                .lock()
+                .unwrap()
+                .insert(metadata_location.to_string(), blob);
+            Ok(())
+        }
 
-        let table = catalog.load_table(&expected_table_ident).await.unwrap();
-        assert_table_eq(&table, &expected_table_ident, &simple_table_schema());
-        assert_table_metadata_location_matches(&table, &expected_table_metadata_location_regex);
+        async fn delete(&self, metadata_location: &str) -> Result<()> {
+            self.blobs.lock().unwrap().remove(metadata_location);
+            Ok(())
+        }
     }
 
     #[tokio::test]
-    async fn test_create_table_falls_back_to_warehouse_location_if_both_table_location_and_namespace_location_are_missing()
-     {
-        let warehouse_loc = temp_path();
-        let catalog = new_sql_catalog(warehouse_loc.clone(), Some("iceberg")).await;
-
-        let namespace_ident = NamespaceIdent::new("a".into());
-        // note: no location specified in namespace_properties
-        let namespace_properties = HashMap::new();
-        catalog
-            .create_namespace(&namespace_ident, namespace_properties)
+    async fn test_metadata_store_keeps_metadata_out_of_warehouse() {
+        // The warehouse directory is never created, as nothing is written through the FileIO.
+        let warehouse_loc = format!("{}/warehouse", temp_path());
+        let sql_lite_uri = format!("sqlite:{}", temp_path());
+        sqlx::Sqlite::create_database(&sql_lite_uri).await.unwrap();
+        let metadata_store = Arc::new(InMemoryMetadataStore::default());
+        let catalog = SqlCatalogBuilder::default()
+            .with_metadata_store(metadata_store.clone())
+            .load(
+                "iceberg",
+                HashMap::from([
+                    (SQL_CATALOG_PROP_URI.to_string(), sql_lite_uri),
+                    (
+                        SQL_CATALOG_PROP_WAREHOUSE.to_string(),
+                        warehouse_loc.clone(),
+                    ),
+                    (
+                        SQL_CATALOG_PROP_BIND_STYLE.to_string(),
+                        SqlBindStyle::QMark.to_string(),
+                    ),
+                ]),
+            )
             .await
             .unwrap();
 
-        let table_name = "tbl1";
-        let expected_table_ident = TableIdent::new(namespace_ident.clone(), table_name.into());
-        let expected_table_metadata_location_regex =
-            format!("^{warehouse_loc}/a/tbl1/metadata/00000-{UUID_REGEX_STR}.metadata.json$");
-
+        let namespace_ident = NamespaceIdent::new("ns1".into());
+        create_namespace(&catalog, &namespace_ident).await;
+        let table_ident = TableIdent::new(namespace_ident.clone(), "tbl1".into());
         let table = catalog
             .create_table(
                 &namespace_ident,
                 TableCreation::builder()
-                    .name(table_name.into())
+                    .name(table_ident.name().into())
                     .schema(simple_table_schema())
-                    // no location specified for table
                     .build(),
             )
             .await
             .unwrap();
-        assert_table_eq(&table, &expected_table_ident, &simple_table_schema());
-        assert_table_metadata_location_matches(&table, &expected_table_metadata_location_regex);
-
-        let table = catalog.load_table(&expected_table_ident).await.unwrap();
-        assert_table_eq(&table, &expected_table_ident, &simple_table_schema());
-        assert_table_metadata_location_matches(&table, &expected_table_metadata_location_regex);
-    }
-
-    #[tokio::test]
-    async fn test_create_table_in_nested_namespace_falls_back_to_warehouse_location_if_both_table_location_and_namespace_location_are_missing()
-     {
-        let warehouse_loc = temp_path();
-        let catalog = new_sql_catalog(warehouse_loc.clone(), Some("iceberg")).await;
-
-        let namespace_ident = NamespaceIdent::new("a".into());
-        create_namespace(&catalog, &namespace_ident).await;
 
-        let nested_namespace_ident = NamespaceIdent::from_strs(vec!["a", "b"]).unwrap();
-        create_namespace(&catalog, &nested_namespace_ident).await;
+        let tx = Transaction::new(&table);
+        let table = tx
+            .update_table_properties()
+            .set("key".to_string(), "value".to_string())
+            .apply(tx)
+            .unwrap()
+            .commit(&catalog)
+            .await
+            .unwrap();
 
-        let table_name = "tbl1";
-        let expected_table_ident =
-            TableIdent::new(nested_namespace_ident.clone(), table_name.into());
-        let expected_table_metadata_location_regex =
-            format!("^{warehouse_loc}/a/b/tbl1/metadata/00000-{UUID_REGEX_STR}.metadata.json$");
+        let loaded = catalog.load_table(&table_ident).await.unwrap();
+        assert_eq!(loaded.metadata_location(), table.metadata_location());
+        assert_eq!(
+            loaded.metadata().properties().get("key"),
+            Some(&"value".to_string())
+        );
 
-        let table = catalog
-            .create_table(
-                &nested_namespace_ident,
-                TableCreation::builder()
-                    .name(table_name.into())
-                    .schema(simple_table_schema())
-                    // no location specified for table
-                    .build(),
+        let registered_ident = TableIdent::new(namespace_ident, "tbl2".into());
+        let registered = catalog
+            .register_table(
+                &registered_ident,
+                loaded.metadata_location().unwrap().to_string(),
             )
             .await
             .unwrap();
-        assert_table_eq(&table, &expected_table_ident, &simple_table_schema());
-        assert_table_metadata_location_matches(&table, &expected_table_metadata_location_regex);
+        assert_eq!(registered.metadata(), loaded.metadata());
 
-        let table = catalog.load_table(&expected_table_ident).await.unwrap();
-        assert_table_eq(&table, &expected_table_ident, &simple_table_schema());
-        assert_table_metadata_location_matches(&table, &expected_table_metadata_location_regex);
+        // Both metadata versions are in the store, and none was written to the warehouse.
+        assert_eq!(metadata_store.blobs.lock().unwrap().len(), 2);
+        assert!(
+            !std::path::Path::new(&warehouse_loc).exists(),
+            "the warehouse should not be written to"
+        );
     }
 
     #[tokio::test]
-    async fn test_create_table_throws_error_if_table_with_same_name_already_exists() {
+    async fn test_update_table() {
         let warehouse_loc = temp_path();
-        let catalog = new_sql_catalog(warehouse_loc.clone(), Some("iceberg")).await;
-        let namespace_ident = NamespaceIdent::new("a".into());
+        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
+
+        // Create a test namespace and table
+        let namespace_ident = NamespaceIdent::new("ns1".into());
         create_namespace(&catalog, &namespace_ident).await;
-        let table_name = "tbl1";
-        let table_ident = TableIdent::new(namespace_ident.clone(), table_name.into());
+        let table_ident = TableIdent::new(namespace_ident.clone(), "tbl1".into());
         create_table(&catalog, &table_ident).await;
 
-        let tmp_dir = TempDir::new().unwrap();
-        let location = tmp_dir.path().to_str().unwrap().to_string();
-
-        assert_eq!(
-            catalog
-                .create_table(
-                    &namespace_ident,
-                    TableCreation::builder()
-                        .name(table_name.into())
-                        .schema(simple_table_schema())
-                        .location(location)
-                        .build()
-                )
-                .await
-                .unwrap_err()
-                .to_string(),
-            format!("Unexpected => Table {:?} already exists.", &table_ident)
-        );
-    }
+        let table = catalog.load_table(&table_ident).await.unwrap();
 
-    #[tokio::test]
-    async fn test_rename_table_in_same_namespace() {
-        let warehouse_loc = temp_path();
-        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
-        let namespace_ident = NamespaceIdent::new("n1".into());
-        create_namespace(&catalog, &namespace_ident).await;
-        let src_table_ident = TableIdent::new(namespace_ident.clone(), "tbl1".into());
-        let dst_table_ident = TableIdent::new(namespace_ident.clone(), "tbl2".into());
-        create_table(&catalog, &src_table_ident).await;
+        // Store the original metadata location for comparison
+        let original_metadata_location = table.metadata_location().unwrap().to_string();
 
-        catalog
-            .rename_table(&src_table_ident, &dst_table_ident)
-            .await
+        // Create a transaction to update the table
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .update_table_properties()
+            .set("test_property".to_string(), "test_value".to_string())
+            .apply(tx)
             .unwrap();
 
-        assert_eq!(catalog.list_tables(&namespace_ident).await.unwrap(), vec![
-            dst_table_ident
-        ],);
-    }
+        // Commit the transaction to the catalog
+        let updated_table = tx.commit(&catalog).await.unwrap();
 
-    #[tokio::test]
-    async fn test_rename_table_across_namespaces() {
-        let warehouse_loc = temp_path();
-        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
-        let src_namespace_ident = NamespaceIdent::new("a".into());
-        let dst_namespace_ident = NamespaceIdent::new("b".into());
-        create_namespaces(&catalog, &vec![&src_namespace_ident, &dst_namespace_ident]).await;
-        let src_table_ident = TableIdent::new(src_namespace_ident.clone(), "tbl1".into());
-        let dst_table_ident = TableIdent::new(dst_namespace_ident.clone(), "tbl2".into());
-        create_table(&catalog, &src_table_ident).await;
+        // Verify the update was successful
+        assert_eq!(
+            updated_table.metadata().properties().get("test_property"),
+            Some(&"test_value".to_string())
+        );
+        // Verify the metadata location has been updated
+        assert_ne!(
+            updated_table.metadata_location().unwrap(),
+            original_metadata_location.as_str()
+        );
 
-        catalog
-            .rename_table(&src_table_ident, &dst_table_ident)
-            .await
-            .unwrap();
+        // Load the table again from the catalog to verify changes were persisted
+        let reloaded = catalog.load_table(&table_ident).await.unwrap();
 
+        // Verify the reloaded table matches the updated table
         assert_eq!(
-            catalog.list_tables(&src_namespace_ident).await.unwrap(),
-            vec![],
+            reloaded.metadata().properties().get("test_property"),
+            Some(&"test_value".to_string())
         );
-
         assert_eq!(
-            catalog.list_tables(&dst_namespace_ident).await.unwrap(),
-            vec![dst_table_ident],
+            reloaded.metadata_location(),
+            updated_table.metadata_location()
         );
     }
 
     #[tokio::test]
-    async fn test_rename_table_src_table_is_same_as_dst_table() {
+    async fn test_table_operations_report_not_found_kinds() {
         let warehouse_loc = temp_path();
         let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
-        let namespace_ident = NamespaceIdent::new("n1".into());
-        create_namespace(&catalog, &namespace_ident).await;
-        let table_ident = TableIdent::new(namespace_ident.clone(), "tbl".into());
-        create_table(&catalog, &table_ident).await;
+        let namespace_ident = NamespaceIdent::new("a".into());
+        let table_ident = TableIdent::new(namespace_ident.clone(), "tbl1".into());
 
-        catalog
-            .rename_table(&table_ident, &table_ident)
+        let err = catalog
+            .create_table(
+                &namespace_ident,
+                TableCreation::builder()
+                    .name(table_ident.name().into())
+                    .schema(simple_table_schema())
+                    .build(),
+            )
             .await
-            .unwrap();
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NamespaceNotFound);
 
-        assert_eq!(catalog.list_tables(&namespace_ident).await.unwrap(), vec![
-            table_ident
-        ],);
+        create_namespace(&catalog, &namespace_ident).await;
+
+        let err = catalog.load_table(&table_ident).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::TableNotFound);
+        let err = catalog.drop_table(&table_ident).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::TableNotFound);
+
+        create_table(&catalog, &table_ident).await;
+        let err = catalog
+            .create_table(
+                &namespace_ident,
+                TableCreation::builder()
+                    .name(table_ident.name().into())
+                    .schema(simple_table_schema())
+                    .build(),
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::TableAlreadyExists);
     }
 
     #[tokio::test]
-    async fn test_rename_table_across_nested_namespaces() {
+    async fn test_update_table_reports_commit_conflict() {
         let warehouse_loc = temp_path();
         let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
-        let namespace_ident_a = NamespaceIdent::new("a".into());
-        let namespace_ident_a_b = NamespaceIdent::from_strs(vec!["a", "b"]).unwrap();
-        let namespace_ident_a_b_c = NamespaceIdent::from_strs(vec!["a", "b", "c"]).unwrap();
-        create_namespaces(&catalog, &vec![
-            &namespace_ident_a,
-            &namespace_ident_a_b,
-            &namespace_ident_a_b_c,
-        ])
-        .await;
-
-        let src_table_ident = TableIdent::new(namespace_ident_a_b_c.clone(), "tbl1".into());
-        create_tables(&catalog, vec![&src_table_ident]).await;
-
-        let dst_table_ident = TableIdent::new(namespace_ident_a_b.clone(), "tbl1".into());
-        catalog
-            .rename_table(&src_table_ident, &dst_table_ident)
+        let namespace_ident = NamespaceIdent::new("ns1".into());
+        create_namespace(&catalog, &namespace_ident).await;
+        let table_ident = TableIdent::new(namespace_ident.clone(), "tbl1".into());
+        let table = catalog
+            .create_table(
+                &namespace_ident,
+                TableCreation::builder()
+                    .name(table_ident.name().into())
+                    .schema(simple_table_schema())
+                    .location(temp_path())
+                    .properties(HashMap::from([(
+                        "commit.retry.num-retries".to_string(),
+                        "0".to_string(),
+                    )]))
+                    .build(),
+            )
             .await
             .unwrap();
 
-        assert!(!catalog.table_exists(&src_table_ident).await.unwrap());
+        // Silently skip every update of the table pointer, as if another writer
+        // had moved it between the load and the compare-and-swap.
+        sqlx::query(&format!(
+            "CREATE TRIGGER skip_pointer_update BEFORE UPDATE ON {CATALOG_TABLE_NAME}
+             BEGIN SELECT RAISE(IGNORE); END"
+        ))
+        .execute(&catalog.connection)
+        .await
+        .unwrap();
 
-        assert!(catalog.table_exists(&dst_table_ident).await.unwrap());
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .update_table_properties()
+            .set("test_property".to_string(), "test_value".to_string())
+            .apply(tx)
+            .unwrap();
+        let err = tx.commit(&catalog).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::CatalogCommitConflicts);
+        assert!(err.retryable());
     }
 
     #[tokio::test]
-    async fn test_rename_table_throws_error_if_dst_namespace_doesnt_exist() {
+    async fn test_update_table_rejects_table_uuid_mismatch() {
         let warehouse_loc = temp_path();
         let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
-        let src_namespace_ident = NamespaceIdent::new("n1".into());
-        let src_table_ident = TableIdent::new(src_namespace_ident.clone(), "tbl1".into());
-        create_namespace(&catalog, &src_namespace_ident).await;
-        create_table(&catalog, &src_table_ident).await;
+        let namespace_ident = NamespaceIdent::new("ns1".into());
+        create_namespace(&catalog, &namespace_ident).await;
+        let table_ident = TableIdent::new(namespace_ident.clone(), "tbl1".into());
+        create_table(&catalog, &table_ident).await;
+        let table = catalog.load_table(&table_ident).await.unwrap();
 
-        let non_existent_dst_namespace_ident = NamespaceIdent::new("n2".into());
-        let dst_table_ident =
-            TableIdent::new(non_existent_dst_namespace_ident.clone(), "tbl1".into());
-        assert_eq!(
-            catalog
-                .rename_table(&src_table_ident, &dst_table_ident)
-                .await
-                .unwrap_err()
-                .to_string(),
-            format!("Unexpected => No such namespace: {non_existent_dst_namespace_ident:?}"),
-        );
-    }
+        // The commit requires the UUID of the table it was built for.
+        let data_file = DataFileBuilder::default()
+            .content(DataContentType::Data)
+            .file_path(format!("{}/data/1.parquet", table.metadata().location()))
+            .file_format(DataFileFormat::Parquet)
+            .file_size_in_bytes(100)
+            .record_count(1)
+            .partition_spec_id(table.metadata().default_partition_spec_id())
+            .partition(Struct::empty())
+            .build()
+            .unwrap();
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .fast_append()
+            .add_data_files(vec![data_file])
+            .apply(tx)
+            .unwrap();
+        let commit = tx.table_commit().await.unwrap();
 
-    #[tokio::test]
-    async fn test_rename_table_throws_error_if_src_table_doesnt_exist() {
-        let warehouse_loc = temp_path();
-        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
-        let namespace_ident = NamespaceIdent::new("n1".into());
-        create_namespace(&catalog, &namespace_ident).await;
-        let src_table_ident = TableIdent::new(namespace_ident.clone(), "tbl1".into());
-        let dst_table_ident = TableIdent::new(namespace_ident.clone(), "tbl2".into());
+        // Drop the table and create another one under the same name.
+        catalog.drop_table(&table_ident).await.unwrap();
+        create_table(&catalog, &table_ident).await;
+        let recreated = catalog.load_table(&table_ident).await.unwrap();
+        assert_ne!(recreated.metadata().uuid(), table.metadata().uuid());
 
-        assert_eq!(
-            catalog
-                .rename_table(&src_table_ident, &dst_table_ident)
-                .await
-                .unwrap_err()
-                .to_string(),
-            format!("Unexpected => No such table: {src_table_ident:?}"),
-        );
+        let err = catalog.update_table(commit).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::CatalogCommitConflicts);
+        let reloaded = catalog.load_table(&table_ident).await.unwrap();
+        assert_eq!(reloaded.metadata_location(), recreated.metadata_location());
+        assert!(reloaded.metadata().current_snapshot().is_none());
     }
 
     #[tokio::test]
-    async fn test_rename_table_throws_error_if_dst_table_already_exists() {
+    async fn test_table_operations_report_backend_unavailable() {
         let warehouse_loc = temp_path();
         let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
-        let namespace_ident = NamespaceIdent::new("n1".into());
+        let namespace_ident = NamespaceIdent::new("a".into());
+        let table_ident = TableIdent::new(namespace_ident.clone(), "tbl1".into());
         create_namespace(&catalog, &namespace_ident).await;
-        let src_table_ident = TableIdent::new(namespace_ident.clone(), "tbl1".into());
-        let dst_table_ident = TableIdent::new(namespace_ident.clone(), "tbl2".into());
-        create_tables(&catalog, vec![&src_table_ident, &dst_table_ident]).await;
+        create_table(&catalog, &table_ident).await;
 
-        assert_eq!(
-            catalog
-                .rename_table(&src_table_ident, &dst_table_ident)
-                .await
-                .unwrap_err()
-                .to_string(),
-            format!("Unexpected => Table {:?} already exists.", &dst_table_ident),
-        );
+        catalog.connection.close().await;
+
+        let err = catalog.load_table(&table_ident).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::BackendUnavailable);
+        assert!(err.retryable());
+        let err = catalog.drop_table(&table_ident).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::BackendUnavailable);
+        let err = catalog
+            .create_table(
+                &namespace_ident,
+                TableCreation::builder()
+                    .name("tbl2".into())
+                    .schema(simple_table_schema())
+                    .build(),
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::BackendUnavailable);
     }
 
     #[tokio::test]
-    async fn test_drop_table_throws_error_if_table_not_exist() {
+    async fn test_update_table_times_out_and_removes_metadata_file() {
         let warehouse_loc = temp_path();
-        let catalog = new_sql_catalog(warehouse_loc.clone(), Some("iceberg")).await;
-        let namespace_ident = NamespaceIdent::new("a".into());
-        let table_name = "tbl1";
-        let table_ident = TableIdent::new(namespace_ident.clone(), table_name.into());
+        let mut catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
+        let namespace_ident = NamespaceIdent::new("ns1".into());
         create_namespace(&catalog, &namespace_ident).await;
-
-        let err = catalog
-            .drop_table(&table_ident)
+        let table_ident = TableIdent::new(namespace_ident.clone(), "tbl1".into());
+        let table_location = temp_path();
+        let table = catalog
+            .create_table(
+                &namespace_ident,
+                TableCreation::builder()
+                    .name(table_ident.name().into())
+                    .schema(simple_table_schema())
+                    .location(table_location.clone())
+                    .build(),
+            )
             .await
-            .unwrap_err()
-            .to_string();
-        assert_eq!(
-            err,
-            "Unexpected => No such table: TableIdent { namespace: NamespaceIdent([\"a\"]), name: \"tbl1\" }"
+            .unwrap();
+        let metadata_files = || {
+            std::fs::read_dir(format!("{table_location}/metadata"))
+                .unwrap()
+                .map(|entry| entry.unwrap().path())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(metadata_files().len(), 1);
+
+        // Hold a write lock on the catalog table, so that the pointer update hangs
+        // after the new metadata file has been written.
+        let mut lock = catalog.connection.begin().await.unwrap();
+        sqlx::query(&format!(
+            "UPDATE {CATALOG_TABLE_NAME} SET {CATALOG_FIELD_RECORD_TYPE} = {CATALOG_FIELD_RECORD_TYPE}"
+        ))
+        .execute(&mut *lock)
+        .await
+        .unwrap();
+
+        catalog.operation_timeout = Some(Duration::from_millis(200));
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .update_table_properties()
+            .set("test_property".to_string(), "test_value".to_string())
+            .apply(tx)
+            .unwrap();
+        let err = tx.commit(&catalog).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Unexpected);
+        assert!(!err.retryable());
+        assert!(
+            err.message().contains("`update_table` timed out"),
+            "unexpected error: {err}"
         );
+        assert_eq!(metadata_files().len(), 1);
+
+        lock.rollback().await.unwrap();
+        catalog.operation_timeout = None;
+        let loaded_table = catalog.load_table(&table_ident).await.unwrap();
+        assert_eq!(loaded_table.metadata_location(), table.metadata_location());
     }
 
     #[tokio::test]
-    async fn test_drop_table() {
-        let warehouse_loc = temp_path();
-        let catalog = new_sql_catalog(warehouse_loc.clone(), Some("iceberg")).await;
-        let namespace_ident = NamespaceIdent::new("a".into());
-        let table_name = "tbl1";
-        let table_ident = TableIdent::new(namespace_ident.clone(), table_name.into());
-        create_namespace(&catalog, &namespace_ident).await;
+    async fn test_builder_props_invalid_operation_timeout_fails() {
+        let sql_lite_uri = format!("sqlite:{}", temp_path());
+        sqlx::Sqlite::create_database(&sql_lite_uri).await.unwrap();
 
-        let location = warehouse_loc.clone();
-        let table_creation = TableCreation::builder()
-            .name(table_name.into())
-            .location(location.clone())
-            .schema(simple_table_schema())
-            .build();
+        let catalog = SqlCatalogBuilder::default()
+            .load(
+                "iceberg",
+                HashMap::from_iter([
+                    (SQL_CATALOG_PROP_URI.to_string(), sql_lite_uri),
+                    (SQL_CATALOG_PROP_WAREHOUSE.to_string(), temp_path()),
+                    (
+                        SQL_CATALOG_PROP_OPERATION_TIMEOUT_MS.to_string(),
+                        "soon".to_string(),
+                    ),
+                ]),
+            )
+            .await;
 
-        catalog
-            .create_table(&namespace_ident, table_creation)
-            .await
-            .unwrap();
+        assert!(catalog.is_err());
+    }
 
-        let table = catalog.load_table(&table_ident).await.unwrap();
-        assert_table_eq(&table, &table_ident, &simple_table_schema());
+    async fn append_data_file(catalog: &SqlCatalog, table: &Table) -> Table {
+        let data_file = DataFileBuilder::default()
+            .content(DataContentType::Data)
+            .file_path(format!("{}/data/1.parquet", table.metadata().location()))
+            .file_format(DataFileFormat::Parquet)
+            .file_size_in_bytes(100)
+            .record_count(1)
+            .partition_spec_id(table.metadata().default_partition_spec_id())
+            .partition(Struct::empty())
+            .build()
+            .unwrap();
+        let tx = Transaction::new(table);
+        let tx = tx
+            .fast_append()
+            .add_data_files(vec![data_file])
+            .apply(tx)
+            .unwrap();
+        tx.commit(catalog).await.unwrap()
+    }
 
-        catalog.drop_table(&table_ident).await.unwrap();
-        let err = catalog
-            .load_table(&table_ident)
-            .await
-            .unwrap_err()
-            .to_string();
-        assert_eq!(
-            err,
-            "Unexpected => No such table: TableIdent { namespace: NamespaceIdent([\"a\"]), name: \"tbl1\" }"
-        );
+    fn replacement_schema() -> Schema {
+        Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+                NestedField::optional(2, "name", Type::Primitive(PrimitiveType::String)).into(),
+            ])
+            .build()
+            .unwrap()
     }
 
     #[tokio::test]
-    async fn test_register_table_throws_error_if_table_with_same_name_already_exists() {
+    async fn test_create_or_replace_table_replaces_existing_table() {
         let warehouse_loc = temp_path();
-        let catalog = new_sql_catalog(warehouse_loc.clone(), Some("iceberg")).await;
+        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
         let namespace_ident = NamespaceIdent::new("a".into());
         create_namespace(&catalog, &namespace_ident).await;
-        let table_name = "tbl1";
-        let table_ident = TableIdent::new(namespace_ident.clone(), table_name.into());
+        let table_ident = TableIdent::new(namespace_ident.clone(), "tbl1".into());
         create_table(&catalog, &table_ident).await;
+        let table = catalog.load_table(&table_ident).await.unwrap();
+        let table = append_data_file(&catalog, &table).await;
+        assert!(table.metadata().current_snapshot().is_some());
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .update_table_properties()
+            .set("existing".to_string(), "kept".to_string())
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+
+        let replaced = catalog
+            .create_or_replace_table(
+                &namespace_ident,
+                TableCreation::builder()
+                    .name(table_ident.name().into())
+                    .schema(replacement_schema())
+                    .properties(HashMap::from([("k".to_string(), "v".to_string())]))
+                    .build(),
+                false,
+            )
+            .await
+            .unwrap();
 
+        assert_eq!(replaced.metadata().uuid(), table.metadata().uuid());
+        assert_eq!(replaced.metadata().location(), table.metadata().location());
+        // The replacement fields get ids above the last column id of the table.
+        let fields = replaced.metadata().current_schema().as_struct().fields();
         assert_eq!(
-            catalog
-                .register_table(&table_ident, warehouse_loc)
-                .await
-                .unwrap_err()
-                .to_string(),
-            format!("Unexpected => Table {:?} already exists.", &table_ident)
+            fields
+                .iter()
+                .map(|field| (field.id, field.name.as_str()))
+                .collect::<Vec<_>>(),
+            vec![(2, "id"), (3, "name")]
+        );
+        assert_eq!(replaced.metadata().last_column_id(), 3);
+        assert!(replaced.metadata().current_snapshot().is_none());
+        assert_eq!(replaced.metadata().snapshots().len(), 0);
+        assert_eq!(
+            replaced.metadata().properties().get("k"),
+            Some(&"v".to_string())
         );
+        assert_eq!(
+            replaced.metadata().properties().get("existing"),
+            Some(&"kept".to_string())
+        );
+        assert_ne!(replaced.metadata_location(), table.metadata_location());
+
+        let reloaded = catalog.load_table(&table_ident).await.unwrap();
+        assert_eq!(reloaded.metadata_location(), replaced.metadata_location());
+        assert_eq!(reloaded.metadata(), replaced.metadata());
     }
 
     #[tokio::test]
-    async fn test_register_table() {
+    async fn test_create_or_replace_table_reuses_ids_of_existing_columns() {
         let warehouse_loc = temp_path();
-        let catalog = new_sql_catalog(warehouse_loc.clone(), Some("iceberg")).await;
+        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
         let namespace_ident = NamespaceIdent::new("a".into());
         create_namespace(&catalog, &namespace_ident).await;
+        let table_ident = TableIdent::new(namespace_ident.clone(), "tbl1".into());
+        create_table(&catalog, &table_ident).await;
 
-        let table_name = "abc";
-        let location = warehouse_loc.clone();
-        let table_creation = TableCreation::builder()
-            .name(table_name.into())
-            .location(location.clone())
-            .schema(simple_table_schema())
-            .build();
-
-        let table_ident = TableIdent::new(namespace_ident.clone(), table_name.into());
-        let expected_table = catalog
-            .create_table(&namespace_ident, table_creation)
-            .await
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::optional(1, "name", Type::Primitive(PrimitiveType::String)).into(),
+                NestedField::required(2, "foo", Type::Primitive(PrimitiveType::Int)).into(),
+            ])
+            .with_identifier_field_ids(vec![2])
+            .build()
             .unwrap();
-
-        let metadata_location = expected_table
-            .metadata_location()
-            .expect("Expected metadata location to be set")
-            .to_string();
-
-        assert_table_eq(&expected_table, &table_ident, &simple_table_schema());
-
-        let _ = catalog.drop_table(&table_ident).await;
-
-        let table = catalog
-            .register_table(&table_ident, metadata_location.clone())
+        let replaced = catalog
+            .create_or_replace_table(
+                &namespace_ident,
+                TableCreation::builder()
+                    .name(table_ident.name().into())
+                    .schema(schema)
+                    .sort_order(
+                        SortOrder::builder()
+                            .with_order_id(1)
+                            .with_sort_field(
+                                SortField::builder()
+                                    .source_id(2)
+                                    .direction(SortDirection::Ascending)
+                                    .null_order(NullOrder::First)
+                                    .transform(Transform::Identity)
+                                    .build(),
+                            )
+                            .build_unbound()
+                            .unwrap(),
+                    )
+                    .build(),
+                false,
+            )
             .await
             .unwrap();
 
-        assert_eq!(table.identifier(), expected_table.identifier());
-        assert_eq!(table.metadata_location(), Some(metadata_location.as_str()));
+        let schema = replaced.metadata().current_schema();
+        assert_eq!(schema.field_by_name("foo").unwrap().id, 1);
+        assert_eq!(schema.field_by_name("name").unwrap().id, 2);
+        assert_eq!(schema.identifier_field_ids().collect::<Vec<_>>(), vec![1]);
+        assert_eq!(
+            replaced.metadata().default_sort_order().fields[0].source_id,
+            1
+        );
     }
 
     #[tokio::test]
-    async fn test_update_table() {
+    async fn test_create_or_replace_table_preserves_history() {
         let warehouse_loc = temp_path();
         let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
-
-        // Create a test namespace and table
-        let namespace_ident = NamespaceIdent::new("ns1".into());
+        let namespace_ident = NamespaceIdent::new("a".into());
         create_namespace(&catalog, &namespace_ident).await;
         let table_ident = TableIdent::new(namespace_ident.clone(), "tbl1".into());
         create_table(&catalog, &table_ident).await;
-
         let table = catalog.load_table(&table_ident).await.unwrap();
+        let table = append_data_file(&catalog, &table).await;
+        let snapshot_id = table.metadata().current_snapshot_id().unwrap();
 
-        // Store the original metadata location for comparison
-        let original_metadata_location = table.metadata_location().unwrap().to_string();
-
-        // Create a transaction to update the table
-        let tx = Transaction::new(&table);
-        let tx = tx
-            .update_table_properties()
-            .set("test_property".to_string(), "test_value".to_string())
-            .apply(tx)
+        let replaced = catalog
+            .create_or_replace_table(
+                &namespace_ident,
+                TableCreation::builder()
+                    .name(table_ident.name().into())
+                    .schema(replacement_schema())
+                    .build(),
+                true,
+            )
+            .await
             .unwrap();
 
-        // Commit the transaction to the catalog
-        let updated_table = tx.commit(&catalog).await.unwrap();
+        assert!(replaced.metadata().current_snapshot().is_none());
+        assert!(replaced.metadata().snapshot_by_id(snapshot_id).is_some());
+        assert_eq!(replaced.metadata().schemas_iter().len(), 2);
+    }
 
-        // Verify the update was successful
-        assert_eq!(
-            updated_table.metadata().properties().get("test_property"),
-            Some(&"test_value".to_string())
-        );
-        // Verify the metadata location has been updated
-        assert_ne!(
-            updated_table.metadata_location().unwrap(),
-            original_metadata_location.as_str()
-        );
+    #[tokio::test]
+    async fn test_create_or_replace_table_creates_missing_table() {
+        let warehouse_loc = temp_path();
+        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
+        let namespace_ident = NamespaceIdent::new("a".into());
+        create_namespace(&catalog, &namespace_ident).await;
+        let table_ident = TableIdent::new(namespace_ident.clone(), "tbl1".into());
 
-        // Load the table again from the catalog to verify changes were persisted
-        let reloaded = catalog.load_table(&table_ident).await.unwrap();
+        let table = catalog
+            .create_or_replace_table(
+                &namespace_ident,
+                TableCreation::builder()
+                    .name(table_ident.name().into())
+                    .schema(replacement_schema())
+                    .build(),
+                false,
+            )
+            .await
+            .unwrap();
 
-        // Verify the reloaded table matches the updated table
-        assert_eq!(
-            reloaded.metadata().properties().get("test_property"),
-            Some(&"test_value".to_string())
-        );
-        assert_eq!(
-            reloaded.metadata_location(),
-            updated_table.metadata_location()
-        );
+        assert_table_eq(&table, &table_ident, &replacement_schema());
+        assert!(catalog.table_exists(&table_ident).await.unwrap());
+        let loaded = catalog.load_table(&table_ident).await.unwrap();
+        assert_eq!(loaded.metadata_location(), table.metadata_location());
     }
 }
@@ -15,10 +15,25 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::time::Duration;
+
 use iceberg::{Error, ErrorKind, NamespaceIdent, Result, TableIdent};
 
 /// Format an sqlx error into iceberg error.
+///
+/// Failures to reach the database (I/O, TLS, an exhausted or closed pool) are
+/// reported as [`ErrorKind::BackendUnavailable`] and marked retryable, everything
+/// else is [`ErrorKind::Unexpected`].
 pub fn from_sqlx_error(error: sqlx::Error) -> Error {
+    if is_backend_unavailable(&error) {
+        return Error::new(
+            ErrorKind::BackendUnavailable,
+            "sql catalog backend is unavailable".to_string(),
+        )
+        .with_retryable(true)
+        .with_source(error);
+    }
+
     Error::new(
         ErrorKind::Unexpected,
         "operation failed for hitting sqlx error".to_string(),
@@ -26,23 +41,77 @@ pub fn from_sqlx_error(error: sqlx::Error) -> Error {
     .with_source(error)
 }
 
+fn is_backend_unavailable(error: &sqlx::Error) -> bool {
+    matches!(
+        error,
+        sqlx::Error::Io(_)
+            | sqlx::Error::Tls(_)
+            | sqlx::Error::PoolTimedOut
+            | sqlx::Error::PoolClosed
+            | sqlx::Error::WorkerCrashed
+    )
+}
+
 pub fn no_such_namespace_err<T>(namespace: &NamespaceIdent) -> Result<T> {
     Err(Error::new(
-        ErrorKind::Unexpected,
+        ErrorKind::NamespaceNotFound,
         format!("No such namespace: {namespace:?}"),
     ))
 }
 
 pub fn no_such_table_err<T>(table_ident: &TableIdent) -> Result<T> {
     Err(Error::new(
-        ErrorKind::Unexpected,
+        ErrorKind::TableNotFound,
         format!("No such table: {table_ident:?}"),
     ))
 }
 
 pub fn table_already_exists_err<T>(table_ident: &TableIdent) -> Result<T> {
     Err(Error::new(
-        ErrorKind::Unexpected,
+        ErrorKind::TableAlreadyExists,
         format!("Table {table_ident:?} already exists."),
     ))
 }
+
+pub fn operation_timeout_err<T>(operation: &str, timeout: Duration) -> Result<T> {
+    Err(Error::new(
+        ErrorKind::Unexpected,
+        format!("Sql catalog operation `{operation}` timed out after {timeout:?}"),
+    ))
+}
+
+/// A commit timing out may still have been applied by the database, so its state is unknown
+/// and it must not be retried blindly.
+pub fn commit_timeout_err<T>(operation: &str, timeout: Duration) -> Result<T> {
+    Err(Error::new(
+        ErrorKind::Unexpected,
+        format!(
+            "Sql catalog operation `{operation}` timed out after {timeout:?}; the commit state is unknown."
+        ),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use iceberg::ErrorKind;
+
+    use super::from_sqlx_error;
+
+    #[test]
+    fn test_from_sqlx_error_kind() {
+        for error in [
+            sqlx::Error::PoolTimedOut,
+            sqlx::Error::PoolClosed,
+            sqlx::Error::WorkerCrashed,
+            sqlx::Error::Io(std::io::Error::from(std::io::ErrorKind::ConnectionRefused)),
+        ] {
+            let error = from_sqlx_error(error);
+            assert_eq!(error.kind(), ErrorKind::BackendUnavailable);
+            assert!(error.retryable());
+        }
+
+        let error = from_sqlx_error(sqlx::Error::RowNotFound);
+        assert_eq!(error.kind(), ErrorKind::Unexpected);
+        assert!(!error.retryable());
+    }
+}
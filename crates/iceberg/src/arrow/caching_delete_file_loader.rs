@@ -49,13 +49,17 @@ pub(crate) struct CachingDeleteFileLoader {
 
 // Intermediate context during processing of a delete file task.
 enum DeleteFileContext {
-    // TODO: Delete Vector loader from Puffin files
     ExistingEqDel,
     ExistingPosDel,
     PosDels {
         file_path: String,
         stream: ArrowRecordBatchStream,
     },
+    DelVec {
+        load_key: String,
+        data_file_path: String,
+        delete_vector: DeleteVector,
+    },
     FreshEqDel {
         batch_stream: ArrowRecordBatchStream,
         equality_ids: HashSet<i32>,
@@ -101,11 +105,12 @@ impl CachingDeleteFileLoader {
     ///    tasks from starting to load the same equality delete file. We spawn a task to load
     ///    the EQ delete's record batch stream, convert it to a predicate, update the delete filter,
     ///    and notify any task that was waiting for it.
-    ///  * When this gets updated to add support for delete vectors, the load phase will return
-    ///    a PuffinReader for them.
+    ///  * for delete vectors the load phase reads the blob of the delete vector from its
+    ///    Puffin file. Several delete vectors may be stored in the same Puffin file, so their
+    ///    loading is tracked by file path and blob offset.
     ///  * The parse phase parses each record batch stream according to its associated data type.
     ///    The result of this is a map of data file paths to delete vectors for the positional
-    ///    delete tasks (and in future for the delete vector tasks). For equality delete
+    ///    delete and delete vector tasks. For equality delete
     ///    file tasks, this results in an unbound Predicate.
     ///  * The unbound Predicates resulting from equality deletes are sent to their associated oneshot
     ///    channel to store them in the right place in the delete file managers state.
@@ -127,7 +132,7 @@ impl CachingDeleteFileLoader {
     ///                                                     |
     ///                                                     |
     ///                       +-----------------------------+--------------------------+
-    ///                     Pos Del           Del Vec (Not yet Implemented)         EQ Del
+    ///                     Pos Del                      Del Vec                        EQ Del
     ///                       |                             |                          |
     ///              [parse pos del stream]         [parse del vec puffin]       [parse eq del]
     ///          HashMap<String, RoaringTreeMap> HashMap<String, RoaringTreeMap>   (Predicate, Sender)
@@ -223,6 +228,19 @@ impl CachingDeleteFileLoader {
     ) -> Result<DeleteFileContext> {
         match task.file_type {
             DataContentType::PositionDeletes => {
+                if let (Some(offset), Some(length)) =
+                    (task.content_offset, task.content_size_in_bytes)
+                {
+                    return Self::load_delete_vector_for_task(
+                        task,
+                        offset,
+                        length,
+                        basic_delete_file_loader,
+                        del_filter,
+                    )
+                    .await;
+                }
+
                 match del_filter.try_start_pos_del_load(&task.file_path) {
                     PosDelLoadAction::AlreadyLoaded => Ok(DeleteFileContext::ExistingPosDel),
                     PosDelLoadAction::WaitFor(notify) => {
@@ -275,6 +293,40 @@ impl CachingDeleteFileLoader {
         }
     }
 
+    async fn load_delete_vector_for_task(
+        task: &FileScanTaskDeleteFile,
+        offset: i64,
+        length: i64,
+        basic_delete_file_loader: BasicDeleteFileLoader,
+        del_filter: DeleteFilter,
+    ) -> Result<DeleteFileContext> {
+        let Some(data_file_path) = &task.referenced_data_file else {
+            return Err(Error::new(
+                ErrorKind::DataInvalid,
+                format!(
+                    "Deletion vector in {} does not reference a data file",
+                    task.file_path
+                ),
+            ));
+        };
+
+        let load_key = format!("{}@{offset}", task.file_path);
+        match del_filter.try_start_pos_del_load(&load_key) {
+            PosDelLoadAction::AlreadyLoaded => Ok(DeleteFileContext::ExistingPosDel),
+            PosDelLoadAction::WaitFor(notify) => {
+                notify.notified().await;
+                Ok(DeleteFileContext::ExistingPosDel)
+            }
+            PosDelLoadAction::Load => Ok(DeleteFileContext::DelVec {
+                load_key,
+                data_file_path: data_file_path.clone(),
+                delete_vector: basic_delete_file_loader
+                    .read_deletion_vector(&task.file_path, offset, length)
+                    .await?,
+            }),
+        }
+    }
+
     async fn parse_file_content_for_task(
         ctx: DeleteFileContext,
     ) -> Result<ParsedDeleteFileContext> {
@@ -288,6 +340,14 @@ impl CachingDeleteFileLoader {
                     results: del_vecs,
                 })
             }
+            DeleteFileContext::DelVec {
+                load_key,
+                data_file_path,
+                delete_vector,
+            } => Ok(ParsedDeleteFileContext::DelVecs {
+                file_path: load_key,
+                results: HashMap::from([(data_file_path, delete_vector)]),
+            }),
             DeleteFileContext::FreshEqDel {
                 sender,
                 batch_stream,
@@ -917,6 +977,11 @@ mod tests {
             file_type: DataContentType::PositionDeletes,
             partition_spec_id: 0,
             equality_ids: None,
+            referenced_data_file: None,
+            content_offset: None,
+            content_size_in_bytes: None,
+            file_size_in_bytes: 0,
+            record_count: 0,
         };
 
         let eq_del = FileScanTaskDeleteFile {
@@ -924,6 +989,11 @@ mod tests {
             file_type: DataContentType::EqualityDeletes,
             partition_spec_id: 0,
             equality_ids: Some(vec![2, 3]), // Only use field IDs that exist in both schemas
+            referenced_data_file: None,
+            content_offset: None,
+            content_size_in_bytes: None,
+            file_size_in_bytes: 0,
+            record_count: 0,
         };
 
         let file_scan_task = FileScanTask {
@@ -940,6 +1010,10 @@ mod tests {
             partition_spec: None,
             name_mapping: None,
             case_sensitive: false,
+            sort_order_id: None,
+            column_sizes: HashMap::new(),
+            value_counts: HashMap::new(),
+            timestamp_zone: None,
         };
 
         // Load the deletes - should handle both types without error
@@ -20,7 +20,9 @@ use std::sync::Arc;
 use futures::{StreamExt, TryStreamExt};
 
 use crate::arrow::ArrowReader;
+use crate::arrow::reader::ParquetReadOptions;
 use crate::arrow::record_batch_transformer::RecordBatchTransformerBuilder;
+use crate::delete_vector::DeleteVector;
 use crate::io::FileIO;
 use crate::scan::{ArrowRecordBatchStream, FileScanTaskDeleteFile};
 use crate::spec::{Schema, SchemaRef};
@@ -64,6 +66,7 @@ impl BasicDeleteFileLoader {
             self.file_io.clone(),
             false,
             None,
+            ParquetReadOptions::default(),
         )
         .await?
         .build()?
@@ -72,6 +75,26 @@ impl BasicDeleteFileLoader {
         Ok(Box::pin(record_batch_stream) as ArrowRecordBatchStream)
     }
 
+    /// Loads the deletion vector stored in the Puffin file `file_path` as the blob of
+    /// `length` bytes at `offset`.
+    pub(crate) async fn read_deletion_vector(
+        &self,
+        file_path: &str,
+        offset: i64,
+        length: i64,
+    ) -> Result<DeleteVector> {
+        let start = u64::try_from(offset)?;
+        let end = start + u64::try_from(length)?;
+        let data = self
+            .file_io
+            .new_input(file_path)?
+            .reader()
+            .await?
+            .read(start..end)
+            .await?;
+        DeleteVector::from_puffin_blob_data(&data)
+    }
+
     /// Evolves the schema of the RecordBatches from an equality delete file.
     ///
     /// Per the [Iceberg spec](https://iceberg.apache.org/spec/#equality-delete-files),
@@ -381,6 +381,11 @@ pub(crate) mod tests {
             file_type: DataContentType::PositionDeletes,
             partition_spec_id: 0,
             equality_ids: None,
+            referenced_data_file: None,
+            content_offset: None,
+            content_size_in_bytes: None,
+            file_size_in_bytes: 0,
+            record_count: 0,
         };
 
         let pos_del_2 = FileScanTaskDeleteFile {
@@ -388,6 +393,11 @@ pub(crate) mod tests {
             file_type: DataContentType::PositionDeletes,
             partition_spec_id: 0,
             equality_ids: None,
+            referenced_data_file: None,
+            content_offset: None,
+            content_size_in_bytes: None,
+            file_size_in_bytes: 0,
+            record_count: 0,
         };
 
         let pos_del_3 = FileScanTaskDeleteFile {
@@ -395,6 +405,11 @@ pub(crate) mod tests {
             file_type: DataContentType::PositionDeletes,
             partition_spec_id: 0,
             equality_ids: None,
+            referenced_data_file: None,
+            content_offset: None,
+            content_size_in_bytes: None,
+            file_size_in_bytes: 0,
+            record_count: 0,
         };
 
         let file_scan_tasks = vec![
@@ -412,6 +427,10 @@ pub(crate) mod tests {
                 partition_spec: None,
                 name_mapping: None,
                 case_sensitive: false,
+                sort_order_id: None,
+                column_sizes: HashMap::new(),
+                value_counts: HashMap::new(),
+                timestamp_zone: None,
             },
             FileScanTask {
                 start: 0,
@@ -427,6 +446,10 @@ pub(crate) mod tests {
                 partition_spec: None,
                 name_mapping: None,
                 case_sensitive: false,
+                sort_order_id: None,
+                column_sizes: HashMap::new(),
+                value_counts: HashMap::new(),
+                timestamp_zone: None,
             },
         ];
 
@@ -477,11 +500,20 @@ pub(crate) mod tests {
                 file_type: DataContentType::EqualityDeletes,
                 partition_spec_id: 0,
                 equality_ids: None,
+                referenced_data_file: None,
+                content_offset: None,
+                content_size_in_bytes: None,
+                file_size_in_bytes: 0,
+                record_count: 0,
             }],
             partition: None,
             partition_spec: None,
             name_mapping: None,
             case_sensitive: true,
+            sort_order_id: None,
+            column_sizes: HashMap::new(),
+            value_counts: HashMap::new(),
+            timestamp_zone: None,
         };
 
         let filter = DeleteFilter::default();
@@ -27,6 +27,10 @@ pub(crate) mod caching_delete_file_loader;
 pub mod delete_file_loader;
 pub(crate) mod delete_filter;
 
+#[cfg(feature = "orc")]
+mod orc;
+mod partition_columns;
+pub(crate) use partition_columns::PartitionColumns;
 mod reader;
 /// RecordBatch projection utilities
 pub mod record_batch_projector;
@@ -0,0 +1,395 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! ORC file data reader
+
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+
+use arrow_array::{BooleanArray, RecordBatch};
+use arrow_schema::{Field, Schema as ArrowSchema, SchemaRef as ArrowSchemaRef};
+use arrow_select::filter::filter_record_batch;
+use futures::StreamExt;
+use orc_rust::ArrowReaderBuilder as OrcReaderBuilder;
+use orc_rust::projection::ProjectionMask as OrcProjectionMask;
+use orc_rust::schema::TimestampPrecision;
+use parquet::arrow::{ArrowSchemaConverter, PARQUET_FIELD_ID_META_KEY};
+
+use crate::arrow::delete_filter::DeleteFilter;
+use crate::arrow::reader::{
+    ArrowReader, CollectFieldIdVisitor, PredicateConverter, PredicateResult,
+    apply_name_mapping_to_arrow_schema,
+};
+use crate::delete_vector::DeleteVector;
+use crate::error::Result;
+use crate::expr::BoundPredicate;
+use crate::expr::visitors::bound_predicate_visitor::visit;
+use crate::io::FileIO;
+use crate::metadata_columns::is_metadata_field;
+use crate::scan::{ArrowRecordBatchStream, FileScanTask};
+use crate::spec::{PrimitiveType, Schema, Type};
+use crate::{Error, ErrorKind};
+
+/// Reads the ORC data file of `task` as a stream of record batches in the projected schema
+/// of the task.
+///
+/// orc-rust does not expose the `iceberg.id` attributes of ORC types, so the top-level columns
+/// of the file are matched to table fields through the name mapping of the table, or else by
+/// the names of the table schema.
+pub(crate) async fn read_orc_data_file(
+    task: FileScanTask,
+    batch_size: Option<usize>,
+    file_io: FileIO,
+    delete_filter: DeleteFilter,
+) -> Result<ArrowRecordBatchStream> {
+    let content = file_io.new_input(&task.data_file_path)?.read().await?;
+    let mut builder = OrcReaderBuilder::try_new(content)
+        .map_err(|err| orc_error(&task.data_file_path, err))?
+        .with_timestamp_precision(timestamp_precision(&task.schema));
+
+    let file_schema = match &task.name_mapping {
+        Some(name_mapping) => apply_name_mapping_to_arrow_schema(builder.schema(), name_mapping)?,
+        None => assign_field_ids_by_name(&builder.schema(), &task.schema),
+    };
+
+    let delete_predicate = delete_filter.build_equality_delete_predicate(&task).await?;
+    let predicate = match (&task.predicate, delete_predicate) {
+        (None, None) => None,
+        (Some(predicate), None) => Some(predicate.clone()),
+        (None, Some(predicate)) => Some(predicate),
+        (Some(filter_predicate), Some(delete_predicate)) => {
+            Some(filter_predicate.clone().and(delete_predicate))
+        }
+    };
+
+    // Columns referenced by the predicate are read as well, the record batch transformer
+    // drops them if they are not projected.
+    let mut read_field_ids: HashSet<i32> = task
+        .project_field_ids
+        .iter()
+        .filter(|&&id| !is_metadata_field(id))
+        .copied()
+        .collect();
+    if let Some(predicate) = &predicate {
+        let mut collector = CollectFieldIdVisitor {
+            field_ids: HashSet::default(),
+        };
+        visit(&mut collector, predicate)?;
+        read_field_ids.extend(collector.field_ids());
+    }
+
+    let root_data_type = builder.file_metadata().root_data_type();
+    let mut orc_column_indices = vec![];
+    let mut projected_fields = vec![];
+    for (column, field) in root_data_type.children().iter().zip(file_schema.fields()) {
+        if field_id(field).is_some_and(|id| read_field_ids.contains(&id)) {
+            orc_column_indices.push(column.data_type().column_index());
+            projected_fields.push(field.clone());
+        }
+    }
+    let projection = OrcProjectionMask::roots(root_data_type, orc_column_indices);
+    let schema = Arc::new(ArrowSchema::new_with_metadata(
+        projected_fields,
+        file_schema.metadata().clone(),
+    ));
+    builder = builder.with_projection(projection);
+
+    // Row positions of position deletes count from the start of the file, not from the start
+    // of the stripes selected by the byte range of the task.
+    let mut position = 0;
+    if task.start != 0 || task.length != 0 {
+        position = builder
+            .file_metadata()
+            .stripe_metadatas()
+            .iter()
+            .filter(|stripe| stripe.offset() < task.start)
+            .map(|stripe| stripe.number_of_rows())
+            .sum();
+        builder =
+            builder.with_file_byte_range(task.start as usize..(task.start + task.length) as usize);
+    }
+
+    if let Some(batch_size) = batch_size {
+        builder = builder.with_batch_size(batch_size);
+    }
+
+    let mut row_filter = predicate
+        .map(|predicate| OrcRowFilter::try_new(&predicate, &schema, &task.schema))
+        .transpose()?;
+    let delete_vector = delete_filter.get_delete_vector(&task);
+    let mut record_batch_transformer = ArrowReader::record_batch_transformer(&task)?;
+
+    let record_batch_stream = futures::stream::iter(builder.build()).map(move |batch| {
+        let batch = RecordBatch::try_new(schema.clone(), batch?.columns().to_vec())?;
+        let num_rows = batch.num_rows() as u64;
+        let batch = match &delete_vector {
+            Some(delete_vector) => remove_deleted_rows(&batch, delete_vector, position)?,
+            None => batch,
+        };
+        position += num_rows;
+        let batch = match row_filter.as_mut() {
+            Some(row_filter) => row_filter.filter(&batch)?,
+            None => batch,
+        };
+        record_batch_transformer.process_record_batch(batch)
+    });
+
+    Ok(Box::pin(record_batch_stream) as ArrowRecordBatchStream)
+}
+
+fn orc_error(data_file_path: &str, err: orc_rust::error::OrcError) -> Error {
+    Error::new(
+        ErrorKind::DataInvalid,
+        format!("Failed to read ORC data file {data_file_path}"),
+    )
+    .with_source(err)
+}
+
+fn field_id(field: &Field) -> Option<i32> {
+    field
+        .metadata()
+        .get(PARQUET_FIELD_ID_META_KEY)?
+        .parse()
+        .ok()
+}
+
+/// Returns the precision of the ORC timestamps read for `schema`, nanoseconds if any of its
+/// top-level columns is a nanosecond timestamp and microseconds otherwise.
+fn timestamp_precision(schema: &Schema) -> TimestampPrecision {
+    let has_nanosecond_timestamps = schema.as_struct().fields().iter().any(|field| {
+        matches!(
+            *field.field_type,
+            Type::Primitive(PrimitiveType::TimestampNs | PrimitiveType::TimestamptzNs)
+        )
+    });
+    if has_nanosecond_timestamps {
+        TimestampPrecision::Nanosecond
+    } else {
+        TimestampPrecision::Microsecond
+    }
+}
+
+/// Assigns the ids of the top-level fields of `table_schema` to the columns of
+/// `arrow_schema` of the same name. Columns without a matching field are left without an id
+/// and are not read.
+fn assign_field_ids_by_name(
+    arrow_schema: &ArrowSchemaRef,
+    table_schema: &Schema,
+) -> ArrowSchemaRef {
+    let fields: Vec<_> = arrow_schema
+        .fields()
+        .iter()
+        .map(|field| {
+            let mut metadata = field.metadata().clone();
+            if let Some(table_field) = table_schema
+                .as_struct()
+                .fields()
+                .iter()
+                .find(|table_field| &table_field.name == field.name())
+            {
+                metadata.insert(
+                    PARQUET_FIELD_ID_META_KEY.to_string(),
+                    table_field.id.to_string(),
+                );
+            }
+            field.as_ref().clone().with_metadata(metadata)
+        })
+        .collect();
+    Arc::new(ArrowSchema::new_with_metadata(
+        fields,
+        arrow_schema.metadata().clone(),
+    ))
+}
+
+/// Removes the rows of `batch` deleted by `delete_vector`, where `position` is the position of
+/// the first row of `batch` in the data file.
+fn remove_deleted_rows(
+    batch: &RecordBatch,
+    delete_vector: &Mutex<DeleteVector>,
+    position: u64,
+) -> Result<RecordBatch> {
+    let delete_vector = delete_vector.lock().unwrap();
+    let kept_rows: BooleanArray = (position..position + batch.num_rows() as u64)
+        .map(|pos| Some(!delete_vector.contains(pos)))
+        .collect();
+    Ok(filter_record_batch(batch, &kept_rows)?)
+}
+
+/// Filters record batches read from an ORC file by a predicate on their top-level primitive
+/// columns.
+struct OrcRowFilter {
+    /// Positions of the columns referenced by the predicate in the record batches.
+    columns: Vec<usize>,
+    predicate: Box<PredicateResult>,
+}
+
+impl OrcRowFilter {
+    fn try_new(
+        predicate: &BoundPredicate,
+        arrow_schema: &ArrowSchema,
+        table_schema: &Schema,
+    ) -> Result<Self> {
+        let mut collector = CollectFieldIdVisitor {
+            field_ids: HashSet::default(),
+        };
+        visit(&mut collector, predicate)?;
+        let mut field_ids: Vec<i32> = collector.field_ids().into_iter().collect();
+        field_ids.sort();
+
+        let mut columns = vec![];
+        let mut column_map = HashMap::new();
+        for field_id in field_ids {
+            if table_schema.as_struct().field_by_id(field_id).is_none() {
+                return Err(Error::new(
+                    ErrorKind::FeatureUnsupported,
+                    format!(
+                        "Filtering ORC data files by nested field {field_id} is not supported yet"
+                    ),
+                ));
+            }
+            // Columns missing from the file are handled by the predicate converter.
+            if let Some(column) = arrow_schema
+                .fields()
+                .iter()
+                .position(|field| self::field_id(field) == Some(field_id))
+            {
+                column_map.insert(field_id, columns.len());
+                columns.push(column);
+            }
+        }
+
+        // The predicate converter resolves the leaves of the record batches it is given from
+        // a Parquet schema, so the predicate columns are described as one.
+        let predicate_schema = arrow_schema.project(&columns)?;
+        let parquet_schema = ArrowSchemaConverter::new().convert(&predicate_schema)?;
+        let column_indices = (0..columns.len()).collect();
+        let mut converter = PredicateConverter {
+            parquet_schema: &parquet_schema,
+            column_map: &column_map,
+            column_indices: &column_indices,
+        };
+        let predicate = visit(&mut converter, predicate)?;
+
+        Ok(Self { columns, predicate })
+    }
+
+    fn filter(&mut self, batch: &RecordBatch) -> Result<RecordBatch> {
+        let matching_rows = (self.predicate)(batch.project(&self.columns)?)?;
+        Ok(filter_record_batch(batch, &matching_rows)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::fs::File;
+    use std::sync::Arc;
+
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int64Type;
+    use arrow_array::{ArrayRef, Int64Array, RecordBatch, StringArray};
+    use arrow_schema::{DataType, Field, Schema as ArrowSchema};
+    use futures::TryStreamExt;
+    use orc_rust::ArrowWriterBuilder as OrcWriterBuilder;
+    use tempfile::TempDir;
+
+    use crate::arrow::ArrowReaderBuilder;
+    use crate::expr::{Bind, Reference};
+    use crate::io::FileIO;
+    use crate::scan::{FileScanTask, FileScanTaskStream};
+    use crate::spec::{DataFileFormat, Datum, NestedField, PrimitiveType, Schema, Type};
+
+    #[tokio::test]
+    async fn test_read_orc_data_file() {
+        let schema = Arc::new(
+            Schema::builder()
+                .with_schema_id(1)
+                .with_fields(vec![
+                    NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+                    NestedField::optional(2, "name", Type::Primitive(PrimitiveType::String)).into(),
+                ])
+                .build()
+                .unwrap(),
+        );
+
+        // The columns of the file are in another order than the fields of the table.
+        let file_schema = Arc::new(ArrowSchema::new(vec![
+            Field::new("name", DataType::Utf8, true),
+            Field::new("id", DataType::Int64, true),
+        ]));
+        let batch = RecordBatch::try_new(file_schema.clone(), vec![
+            Arc::new(StringArray::from(vec![Some("a"), None, Some("c")])) as ArrayRef,
+            Arc::new(Int64Array::from(vec![1, 2, 3])) as ArrayRef,
+        ])
+        .unwrap();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let data_file_path = format!("{}/1.orc", tmp_dir.path().to_str().unwrap());
+        let mut writer = OrcWriterBuilder::new(File::create(&data_file_path).unwrap(), file_schema)
+            .try_build()
+            .unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let predicate = Reference::new("id")
+            .greater_than(Datum::long(1))
+            .bind(schema.clone(), true)
+            .unwrap();
+        let tasks = Box::pin(futures::stream::iter(vec![Ok(FileScanTask {
+            start: 0,
+            length: 0,
+            record_count: None,
+            data_file_path,
+            data_file_format: DataFileFormat::Orc,
+            schema: schema.clone(),
+            project_field_ids: vec![1, 2],
+            predicate: Some(predicate),
+            deletes: vec![],
+            partition: None,
+            partition_spec: None,
+            name_mapping: None,
+            case_sensitive: true,
+            sort_order_id: None,
+            column_sizes: HashMap::new(),
+            value_counts: HashMap::new(),
+            timestamp_zone: None,
+        })])) as FileScanTaskStream;
+
+        let reader = ArrowReaderBuilder::new(
+            FileIO::from_path(tmp_dir.path().to_str().unwrap())
+                .unwrap()
+                .build()
+                .unwrap(),
+        )
+        .build();
+        let batches: Vec<RecordBatch> = reader.read(tasks).unwrap().try_collect().await.unwrap();
+
+        assert_eq!(batches.len(), 1);
+        let batch = &batches[0];
+        assert_eq!(batch.schema().field(0).name(), "id");
+        assert_eq!(
+            batch
+                .column(0)
+                .as_primitive::<Int64Type>()
+                .values()
+                .to_vec(),
+            vec![2, 3]
+        );
+        let names: Vec<Option<&str>> = batch.column(1).as_string::<i32>().iter().collect();
+        assert_eq!(names, vec![None, Some("c")]);
+    }
+}
@@ -0,0 +1,217 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Projection of the partition tuple of data files as extra columns of the scanned rows.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, RecordBatch};
+use arrow_schema::{Field, Schema as ArrowSchema, SchemaRef as ArrowSchemaRef};
+
+use super::type_to_arrow_type;
+use super::value::create_primitive_array_repeated;
+use crate::metadata_columns::RESERVED_COL_NAME_PARTITION;
+use crate::scan::FileScanTask;
+use crate::spec::{PrimitiveLiteral, TableMetadata};
+use crate::{Error, ErrorKind, Result};
+
+/// The partition fields of all the partition specs of a table, appended to the scanned rows
+/// as `_partition.<field name>` columns.
+///
+/// The values of the columns are the partition values of the file of each row, as recorded in
+/// the manifests, so non-identity transforms show the transformed value. The columns of the
+/// partition fields missing from the spec of a file are null.
+///
+/// Partition fields of different specs may share a name, in which case the columns of the
+/// fields after the first are named `_partition.<field name>_<field id>`.
+#[derive(Debug)]
+pub(crate) struct PartitionColumns {
+    /// The partition field id of each column.
+    field_ids: Vec<i32>,
+    /// The columns appended to the batches.
+    fields: Vec<Arc<Field>>,
+}
+
+impl PartitionColumns {
+    /// Collects the partition fields of all the partition specs of `table_metadata`, ordered
+    /// by spec id and by position in the spec.
+    ///
+    /// Fails if the qualified name of a partition field is still taken by another field.
+    pub(crate) fn try_new(table_metadata: &TableMetadata) -> Result<Self> {
+        let mut specs: Vec<_> = table_metadata.partition_specs_iter().collect();
+        specs.sort_by_key(|spec| spec.spec_id());
+
+        let mut seen = HashSet::new();
+        let mut names = HashSet::new();
+        let mut field_ids = vec![];
+        let mut fields = vec![];
+        for spec in specs {
+            for partition_field in spec.fields() {
+                if !seen.insert(partition_field.field_id) {
+                    continue;
+                }
+                // The source column of the field of an old spec may have been dropped since.
+                let source_type = table_metadata
+                    .schemas_iter()
+                    .find_map(|schema| schema.field_by_id(partition_field.source_id))
+                    .map(|field| field.field_type.as_ref().clone())
+                    .ok_or_else(|| {
+                        Error::new(
+                            ErrorKind::DataInvalid,
+                            format!(
+                                "Source column {} of partition field {} not found in any schema of the table",
+                                partition_field.source_id, partition_field.name
+                            ),
+                        )
+                    })?;
+                let result_type = partition_field.transform.result_type(&source_type)?;
+                let mut name = format!("{RESERVED_COL_NAME_PARTITION}.{}", partition_field.name);
+                if names.contains(&name) {
+                    name = format!("{name}_{}", partition_field.field_id);
+                }
+                if !names.insert(name.clone()) {
+                    return Err(Error::new(
+                        ErrorKind::DataInvalid,
+                        format!(
+                            "Partition column {name} of partition field {} is already the column of another partition field",
+                            partition_field.field_id
+                        ),
+                    ));
+                }
+                field_ids.push(partition_field.field_id);
+                fields.push(Arc::new(Field::new(
+                    name,
+                    type_to_arrow_type(&result_type)?,
+                    true,
+                )));
+            }
+        }
+        Ok(Self { field_ids, fields })
+    }
+
+    /// Returns the partition values of the file of `task`, to append to its batches.
+    pub(crate) fn values_of(self: &Arc<Self>, task: &FileScanTask) -> PartitionColumnValues {
+        let values = self
+            .field_ids
+            .iter()
+            .map(|field_id| {
+                let (Some(spec), Some(partition)) = (&task.partition_spec, &task.partition) else {
+                    return None;
+                };
+                let position = spec
+                    .fields()
+                    .iter()
+                    .position(|field| field.field_id == *field_id)?;
+                partition[position]
+                    .as_ref()
+                    .and_then(|literal| literal.as_primitive_literal())
+            })
+            .collect();
+        PartitionColumnValues {
+            columns: Arc::clone(self),
+            values,
+        }
+    }
+}
+
+/// The partition values of a file, appended as columns to the batches read from it.
+pub(crate) struct PartitionColumnValues {
+    columns: Arc<PartitionColumns>,
+    values: Vec<Option<PrimitiveLiteral>>,
+}
+
+impl PartitionColumnValues {
+    /// Appends a column repeating each partition value to `batch`.
+    pub(crate) fn append_to(&self, batch: RecordBatch) -> Result<RecordBatch> {
+        let schema = batch.schema();
+        let mut fields: Vec<Arc<Field>> = schema.fields().iter().cloned().collect();
+        fields.extend(self.columns.fields.iter().cloned());
+        let schema: ArrowSchemaRef = Arc::new(ArrowSchema::new_with_metadata(
+            fields,
+            schema.metadata().clone(),
+        ));
+
+        let num_rows = batch.num_rows();
+        let mut columns: Vec<ArrayRef> = batch.columns().to_vec();
+        for (field, value) in self.columns.fields.iter().zip(&self.values) {
+            columns.push(create_primitive_array_repeated(
+                field.data_type(),
+                value,
+                num_rows,
+            )?);
+        }
+        Ok(RecordBatch::try_new(schema, columns)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::spec::{
+        FormatVersion, NestedField, PrimitiveType, Schema, SortOrder, TableMetadataBuilder,
+        Transform, Type, UnboundPartitionSpec,
+    };
+
+    #[test]
+    fn test_partition_columns_qualify_names_shared_across_specs() {
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+            ])
+            .build()
+            .unwrap();
+        let spec_with_name = |transform| {
+            UnboundPartitionSpec::builder()
+                .add_partition_field(1, "id_part", transform)
+                .unwrap()
+                .build()
+        };
+        let table_metadata = TableMetadataBuilder::new(
+            schema,
+            spec_with_name(Transform::Bucket(16)),
+            SortOrder::unsorted_order(),
+            "memory://warehouse/t".to_string(),
+            FormatVersion::V2,
+            HashMap::new(),
+        )
+        .unwrap()
+        .add_default_partition_spec(spec_with_name(Transform::Truncate(10)))
+        .unwrap()
+        .build()
+        .unwrap()
+        .metadata;
+        assert_ne!(
+            table_metadata.partition_spec_by_id(0).unwrap().fields()[0].field_id,
+            table_metadata.default_partition_spec().fields()[0].field_id
+        );
+
+        let partition_columns = PartitionColumns::try_new(&table_metadata).unwrap();
+        let field_id = table_metadata.default_partition_spec().fields()[0].field_id;
+        let names: Vec<&str> = partition_columns
+            .fields
+            .iter()
+            .map(|field| field.name().as_str())
+            .collect();
+        assert_eq!(names, vec![
+            "_partition.id_part".to_string(),
+            format!("_partition.id_part_{field_id}"),
+        ]);
+    }
+}
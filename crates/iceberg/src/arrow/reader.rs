@@ -23,12 +23,14 @@ use std::str::FromStr;
 use std::sync::Arc;
 
 use arrow_arith::boolean::{and, and_kleene, is_not_null, is_null, not, or, or_kleene};
+use arrow_array::cast::AsArray;
 use arrow_array::{Array, ArrayRef, BooleanArray, Datum as ArrowDatum, RecordBatch, Scalar};
 use arrow_cast::cast::cast;
 use arrow_ord::cmp::{eq, gt, gt_eq, lt, lt_eq, neq};
 use arrow_schema::{
-    ArrowError, DataType, FieldRef, Schema as ArrowSchema, SchemaRef as ArrowSchemaRef,
+    ArrowError, DataType, FieldRef, Schema as ArrowSchema, SchemaRef as ArrowSchemaRef, TimeUnit,
 };
+use arrow_select::coalesce::BatchCoalescer;
 use arrow_string::like::starts_with;
 use bytes::Bytes;
 use fnv::FnvHashSet;
@@ -39,24 +41,33 @@ use parquet::arrow::arrow_reader::{
 };
 use parquet::arrow::async_reader::AsyncFileReader;
 use parquet::arrow::{PARQUET_FIELD_ID_META_KEY, ParquetRecordBatchStreamBuilder, ProjectionMask};
+use parquet::basic::Type as PhysicalType;
 use parquet::file::metadata::{
     PageIndexPolicy, ParquetMetaData, ParquetMetaDataReader, RowGroupMetaData,
 };
 use parquet::schema::types::{SchemaDescriptor, Type as ParquetType};
 
 use crate::arrow::caching_delete_file_loader::CachingDeleteFileLoader;
-use crate::arrow::record_batch_transformer::RecordBatchTransformerBuilder;
-use crate::arrow::{arrow_schema_to_schema, get_arrow_datum};
+#[cfg(feature = "orc")]
+use crate::arrow::orc;
+use crate::arrow::partition_columns::{PartitionColumnValues, PartitionColumns};
+use crate::arrow::record_batch_transformer::{
+    RecordBatchTransformer, RecordBatchTransformerBuilder,
+};
+use crate::arrow::{UTC_TIME_ZONE, arrow_schema_to_schema, get_arrow_datum};
 use crate::delete_vector::DeleteVector;
+#[cfg(feature = "encryption")]
+use crate::encryption::{KeyRetriever, file_decryption_properties};
 use crate::error::Result;
 use crate::expr::visitors::bound_predicate_visitor::{BoundPredicateVisitor, visit};
 use crate::expr::visitors::page_index_evaluator::PageIndexEvaluator;
+use crate::expr::visitors::row_group_bloom_filter_evaluator::RowGroupBloomFilterEvaluator;
 use crate::expr::visitors::row_group_metrics_evaluator::RowGroupMetricsEvaluator;
 use crate::expr::{BoundPredicate, BoundReference};
-use crate::io::{FileIO, FileMetadata, FileRead};
+use crate::io::{FileIO, FileMetadata, FileRead, verify_checksum};
 use crate::metadata_columns::{RESERVED_FIELD_ID_FILE, is_metadata_field};
 use crate::scan::{ArrowRecordBatchStream, FileScanTask, FileScanTaskStream};
-use crate::spec::{Datum, NameMapping, NestedField, PrimitiveType, Schema, Type};
+use crate::spec::{DataFileFormat, Datum, NameMapping, NestedField, PrimitiveType, Schema, Type};
 use crate::utils::available_parallelism;
 use crate::{Error, ErrorKind};
 
@@ -67,6 +78,10 @@ pub struct ArrowReaderBuilder {
     concurrency_limit_data_files: usize,
     row_group_filtering_enabled: bool,
     row_selection_enabled: bool,
+    read_options: ParquetReadOptions,
+    partition_columns: Option<Arc<PartitionColumns>>,
+    #[cfg(feature = "encryption")]
+    key_retriever: Option<Arc<dyn KeyRetriever>>,
 }
 
 impl ArrowReaderBuilder {
@@ -80,6 +95,10 @@ impl ArrowReaderBuilder {
             concurrency_limit_data_files: num_cpus,
             row_group_filtering_enabled: true,
             row_selection_enabled: false,
+            read_options: ParquetReadOptions::default(),
+            partition_columns: None,
+            #[cfg(feature = "encryption")]
+            key_retriever: None,
         }
     }
 
@@ -89,8 +108,10 @@ impl ArrowReaderBuilder {
         self
     }
 
-    /// Sets the desired size of batches in the response
-    /// to something other than the default
+    /// Sets the number of rows of the batches in the response.
+    ///
+    /// Decoded rows are re-chunked so that every batch but the last one has exactly
+    /// `batch_size` rows, regardless of the row groups and deletes of the files read.
     pub fn with_batch_size(mut self, batch_size: usize) -> Self {
         self.batch_size = Some(batch_size);
         self
@@ -103,11 +124,62 @@ impl ArrowReaderBuilder {
     }
 
     /// Determines whether to enable row selection.
+    ///
+    /// When enabled, the page index of files read with a predicate is loaded, and the pages
+    /// whose column index shows that none of their rows can match the predicate are neither
+    /// read nor decoded.
     pub fn with_row_selection_enabled(mut self, row_selection_enabled: bool) -> Self {
         self.row_selection_enabled = row_selection_enabled;
         self
     }
 
+    /// Sets the number of bytes read at once from the end of the Parquet files to load
+    /// their footer. A hint large enough for the whole footer saves a second read.
+    pub fn with_metadata_size_hint(mut self, metadata_size_hint: usize) -> Self {
+        self.read_options.metadata_size_hint = Some(metadata_size_hint);
+        self
+    }
+
+    /// Merges the byte ranges of a Parquet file read together, such as the column chunks of
+    /// a row group, into a single read when they are at most `gap` bytes apart.
+    ///
+    /// Fewer, larger reads cut the latency of scans on high-latency object stores, at the cost
+    /// of also reading the bytes between the ranges.
+    pub fn with_range_coalescing_gap(mut self, gap: u64) -> Self {
+        self.read_options.range_coalescing_gap = Some(gap);
+        self
+    }
+
+    /// Appends the partition values of the file of each row to the batches read, as the
+    /// columns of `partition_columns`.
+    pub(crate) fn with_partition_columns(
+        mut self,
+        partition_columns: Arc<PartitionColumns>,
+    ) -> Self {
+        self.partition_columns = Some(partition_columns);
+        self
+    }
+
+    /// Decrypt Parquet files encrypted with Parquet modular encryption, looking up their keys
+    /// through `key_retriever`.
+    #[cfg(feature = "encryption")]
+    pub fn with_key_retriever(mut self, key_retriever: Arc<dyn KeyRetriever>) -> Self {
+        self.key_retriever = Some(key_retriever);
+        self
+    }
+
+    /// Determines whether to verify the data files against their checksums before decoding
+    /// them, failing the read of a data file that does not match.
+    ///
+    /// The checksums of a data file are read from the Hadoop checksum file `.<name>.crc` next
+    /// to it, as written by Hadoop's local file system. A data file without a checksum file is
+    /// read without verification and a warning is logged. Verification reads each data file
+    /// entirely before decoding it.
+    pub fn with_verify_checksums(mut self, verify_checksums: bool) -> Self {
+        self.read_options.verify_checksums = verify_checksums;
+        self
+    }
+
     /// Build the ArrowReader.
     pub fn build(self) -> ArrowReader {
         ArrowReader {
@@ -120,10 +192,22 @@ impl ArrowReaderBuilder {
             concurrency_limit_data_files: self.concurrency_limit_data_files,
             row_group_filtering_enabled: self.row_group_filtering_enabled,
             row_selection_enabled: self.row_selection_enabled,
+            read_options: self.read_options,
+            partition_columns: self.partition_columns,
+            #[cfg(feature = "encryption")]
+            key_retriever: self.key_retriever,
         }
     }
 }
 
+/// Tuning of the byte range reads of Parquet files.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct ParquetReadOptions {
+    metadata_size_hint: Option<usize>,
+    range_coalescing_gap: Option<u64>,
+    verify_checksums: bool,
+}
+
 /// Reads data from Parquet files
 #[derive(Clone)]
 pub struct ArrowReader {
@@ -136,6 +220,10 @@ pub struct ArrowReader {
 
     row_group_filtering_enabled: bool,
     row_selection_enabled: bool,
+    read_options: ParquetReadOptions,
+    partition_columns: Option<Arc<PartitionColumns>>,
+    #[cfg(feature = "encryption")]
+    key_retriever: Option<Arc<dyn KeyRetriever>>,
 }
 
 impl ArrowReader {
@@ -147,6 +235,9 @@ impl ArrowReader {
         let concurrency_limit_data_files = self.concurrency_limit_data_files;
         let row_group_filtering_enabled = self.row_group_filtering_enabled;
         let row_selection_enabled = self.row_selection_enabled;
+        let reader_options = self.reader_options()?;
+        let read_options = self.read_options;
+        let partition_columns = self.partition_columns.clone();
 
         // Fast-path for single concurrency to avoid overhead of try_flatten_unordered
         let stream: ArrowRecordBatchStream = if concurrency_limit_data_files == 1 {
@@ -154,6 +245,9 @@ impl ArrowReader {
                 tasks
                     .and_then(move |task| {
                         let file_io = file_io.clone();
+                        let partition_values = partition_columns
+                            .as_ref()
+                            .map(|partition_columns| partition_columns.values_of(&task));
 
                         Self::process_file_scan_task(
                             task,
@@ -162,7 +256,10 @@ impl ArrowReader {
                             self.delete_file_loader.clone(),
                             row_group_filtering_enabled,
                             row_selection_enabled,
+                            reader_options.clone(),
+                            read_options,
                         )
+                        .map_ok(|stream| Self::append_partition_values(stream, partition_values))
                     })
                     .map_err(|err| {
                         Error::new(ErrorKind::Unexpected, "file scan task generate failed")
@@ -175,6 +272,9 @@ impl ArrowReader {
                 tasks
                     .map_ok(move |task| {
                         let file_io = file_io.clone();
+                        let partition_values = partition_columns
+                            .as_ref()
+                            .map(|partition_columns| partition_columns.values_of(&task));
 
                         Self::process_file_scan_task(
                             task,
@@ -183,7 +283,10 @@ impl ArrowReader {
                             self.delete_file_loader.clone(),
                             row_group_filtering_enabled,
                             row_selection_enabled,
+                            reader_options.clone(),
+                            read_options,
                         )
+                        .map_ok(|stream| Self::append_partition_values(stream, partition_values))
                     })
                     .map_err(|err| {
                         Error::new(ErrorKind::Unexpected, "file scan task generate failed")
@@ -194,7 +297,79 @@ impl ArrowReader {
             )
         };
 
-        Ok(stream)
+        Ok(match batch_size {
+            Some(batch_size) => Self::coalesce_batches(stream, batch_size),
+            None => stream,
+        })
+    }
+
+    /// Returns the options of the Parquet readers of the data files.
+    fn reader_options(&self) -> Result<ArrowReaderOptions> {
+        let options = ArrowReaderOptions::new();
+        #[cfg(feature = "encryption")]
+        if let Some(key_retriever) = &self.key_retriever {
+            return Ok(
+                options.with_file_decryption_properties(file_decryption_properties(Arc::clone(
+                    key_retriever,
+                ))?),
+            );
+        }
+        Ok(options)
+    }
+
+    /// Appends the columns of `partition_values` to the batches of `stream`, if any.
+    fn append_partition_values(
+        stream: ArrowRecordBatchStream,
+        partition_values: Option<PartitionColumnValues>,
+    ) -> ArrowRecordBatchStream {
+        match partition_values {
+            Some(partition_values) => {
+                Box::pin(stream.and_then(move |batch| {
+                    futures::future::ready(partition_values.append_to(batch))
+                }))
+            }
+            None => stream,
+        }
+    }
+
+    /// Re-chunks `stream` into batches of exactly `batch_size` rows, except for the last one.
+    ///
+    /// The Parquet reader only caps the size of the batches it decodes: batches never span row
+    /// groups or files, and row filters and deletes shrink them further. Small batches are
+    /// concatenated and large ones are split so that consumers see a uniform batch size.
+    fn coalesce_batches(
+        stream: ArrowRecordBatchStream,
+        batch_size: usize,
+    ) -> ArrowRecordBatchStream {
+        let state = (stream, None::<BatchCoalescer>, false);
+        Box::pin(futures::stream::try_unfold(
+            state,
+            move |(mut stream, mut coalescer, mut finished)| async move {
+                loop {
+                    if let Some(batch) = coalescer
+                        .as_mut()
+                        .and_then(BatchCoalescer::next_completed_batch)
+                    {
+                        return Ok(Some((batch, (stream, coalescer, finished))));
+                    }
+                    if finished {
+                        return Ok(None);
+                    }
+
+                    match stream.try_next().await? {
+                        Some(batch) => coalescer
+                            .get_or_insert_with(|| BatchCoalescer::new(batch.schema(), batch_size))
+                            .push_batch(batch)?,
+                        None => {
+                            finished = true;
+                            if let Some(coalescer) = coalescer.as_mut() {
+                                coalescer.finish_buffered_batch()?;
+                            }
+                        }
+                    }
+                }
+            },
+        ))
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -205,20 +380,49 @@ impl ArrowReader {
         delete_file_loader: CachingDeleteFileLoader,
         row_group_filtering_enabled: bool,
         row_selection_enabled: bool,
+        reader_options: ArrowReaderOptions,
+        read_options: ParquetReadOptions,
     ) -> Result<ArrowRecordBatchStream> {
+        if read_options.verify_checksums && !verify_checksum(&file_io, &task.data_file_path).await?
+        {
+            tracing::warn!(
+                "Data file {} has no checksum file, reading it without verification",
+                task.data_file_path
+            );
+        }
+
         let should_load_page_index =
             (row_selection_enabled && task.predicate.is_some()) || !task.deletes.is_empty();
 
         let delete_filter_rx =
             delete_file_loader.load_deletes(&task.deletes, Arc::clone(&task.schema));
 
+        match task.data_file_format {
+            DataFileFormat::Parquet => {}
+            #[cfg(feature = "orc")]
+            DataFileFormat::Orc => {
+                let delete_filter = delete_filter_rx.await.unwrap()?;
+                return orc::read_orc_data_file(task, batch_size, file_io, delete_filter).await;
+            }
+            data_file_format => {
+                return Err(Error::new(
+                    ErrorKind::FeatureUnsupported,
+                    format!(
+                        "Reading {data_file_format} data files is not supported, file: {}",
+                        task.data_file_path
+                    ),
+                ));
+            }
+        }
+
         // Migrated tables lack field IDs, requiring us to inspect the schema to choose
         // between field-ID-based or position-based projection
         let initial_stream_builder = Self::create_parquet_record_batch_stream_builder(
             &task.data_file_path,
             file_io.clone(),
             should_load_page_index,
-            None,
+            Some(reader_options.clone()),
+            read_options,
         )
         .await?;
 
@@ -264,13 +468,14 @@ impl ArrowReader {
                 add_fallback_field_ids_to_arrow_schema(initial_stream_builder.schema())
             };
 
-            let options = ArrowReaderOptions::new().with_schema(arrow_schema);
+            let options = reader_options.clone().with_schema(arrow_schema);
 
             Self::create_parquet_record_batch_stream_builder(
                 &task.data_file_path,
                 file_io.clone(),
                 should_load_page_index,
                 Some(options),
+                read_options,
             )
             .await?
         } else {
@@ -278,6 +483,24 @@ impl ArrowReader {
             initial_stream_builder
         };
 
+        // Legacy writers such as Spark and Impala store timestamps as INT96, which is read as
+        // nanoseconds by default. Such columns are read in the unit of the table column instead,
+        // which also keeps instants outside of the nanosecond range intact.
+        if let Some(arrow_schema) = int96_timestamps_as_table_type(
+            record_batch_stream_builder.schema(),
+            record_batch_stream_builder.parquet_schema(),
+            &task.schema,
+        ) {
+            record_batch_stream_builder = Self::create_parquet_record_batch_stream_builder(
+                &task.data_file_path,
+                file_io.clone(),
+                should_load_page_index,
+                Some(reader_options.with_schema(arrow_schema)),
+                read_options,
+            )
+            .await?;
+        }
+
         // Filter out metadata fields for Parquet projection (they don't exist in files)
         let project_field_ids_without_metadata: Vec<i32> = task
             .project_field_ids
@@ -301,27 +524,7 @@ impl ArrowReader {
         record_batch_stream_builder =
             record_batch_stream_builder.with_projection(projection_mask.clone());
 
-        // RecordBatchTransformer performs any transformations required on the RecordBatches
-        // that come back from the file, such as type promotion, default column insertion,
-        // column re-ordering, partition constants, and virtual field addition (like _file)
-        let mut record_batch_transformer_builder =
-            RecordBatchTransformerBuilder::new(task.schema_ref(), task.project_field_ids());
-
-        // Add the _file metadata column if it's in the projected fields
-        if task.project_field_ids().contains(&RESERVED_FIELD_ID_FILE) {
-            let file_datum = Datum::string(task.data_file_path.clone());
-            record_batch_transformer_builder =
-                record_batch_transformer_builder.with_constant(RESERVED_FIELD_ID_FILE, file_datum);
-        }
-
-        if let (Some(partition_spec), Some(partition_data)) =
-            (task.partition_spec.clone(), task.partition.clone())
-        {
-            record_batch_transformer_builder =
-                record_batch_transformer_builder.with_partition(partition_spec, partition_data)?;
-        }
-
-        let mut record_batch_transformer = record_batch_transformer_builder.build();
+        let mut record_batch_transformer = Self::record_batch_transformer(&task)?;
 
         if let Some(batch_size) = batch_size {
             record_batch_stream_builder = record_batch_stream_builder.with_batch_size(batch_size);
@@ -407,6 +610,20 @@ impl ArrowReader {
                     }
                     None => Some(predicate_filtered_row_groups),
                 };
+
+                // Bloom filters have to be read from the file, so they are only consulted
+                // for the row groups left after pruning by the row group metadata.
+                if let Some(row_group_indices) = selected_row_group_indices.take() {
+                    selected_row_group_indices = Some(
+                        Self::filter_row_groups_by_bloom_filters(
+                            &mut record_batch_stream_builder,
+                            &predicate,
+                            row_group_indices,
+                            &field_id_map,
+                        )
+                        .await?,
+                    );
+                }
             }
 
             if row_selection_enabled {
@@ -469,21 +686,52 @@ impl ArrowReader {
         Ok(Box::pin(record_batch_stream) as ArrowRecordBatchStream)
     }
 
+    /// Creates the [`RecordBatchTransformer`] performing any transformations required on the
+    /// RecordBatches that come back from the data file of `task`, such as type promotion,
+    /// default column insertion, column re-ordering, partition constants, and virtual field
+    /// addition (like _file).
+    pub(crate) fn record_batch_transformer(task: &FileScanTask) -> Result<RecordBatchTransformer> {
+        let mut record_batch_transformer_builder =
+            RecordBatchTransformerBuilder::new(task.schema_ref(), task.project_field_ids())
+                .with_timestamp_zone(task.timestamp_zone.as_deref());
+
+        // Add the _file metadata column if it's in the projected fields
+        if task.project_field_ids().contains(&RESERVED_FIELD_ID_FILE) {
+            let file_datum = Datum::string(task.data_file_path.clone());
+            record_batch_transformer_builder =
+                record_batch_transformer_builder.with_constant(RESERVED_FIELD_ID_FILE, file_datum);
+        }
+
+        if let (Some(partition_spec), Some(partition_data)) =
+            (task.partition_spec.clone(), task.partition.clone())
+        {
+            record_batch_transformer_builder =
+                record_batch_transformer_builder.with_partition(partition_spec, partition_data)?;
+        }
+
+        Ok(record_batch_transformer_builder.build())
+    }
+
     pub(crate) async fn create_parquet_record_batch_stream_builder(
         data_file_path: &str,
         file_io: FileIO,
         should_load_page_index: bool,
         arrow_reader_options: Option<ArrowReaderOptions>,
+        read_options: ParquetReadOptions,
     ) -> Result<ParquetRecordBatchStreamBuilder<ArrowFileReader>> {
         // Get the metadata for the Parquet file we need to read and build
         // a reader for the data within
         let parquet_file = file_io.new_input(data_file_path)?;
         let (parquet_metadata, parquet_reader) =
             try_join!(parquet_file.metadata(), parquet_file.reader())?;
-        let parquet_file_reader = ArrowFileReader::new(parquet_metadata, parquet_reader)
+        let mut parquet_file_reader = ArrowFileReader::new(parquet_metadata, parquet_reader)
             .with_preload_column_index(true)
             .with_preload_offset_index(true)
-            .with_preload_page_index(should_load_page_index);
+            .with_preload_page_index(should_load_page_index)
+            .with_range_coalescing_gap(read_options.range_coalescing_gap);
+        if let Some(metadata_size_hint) = read_options.metadata_size_hint {
+            parquet_file_reader = parquet_file_reader.with_metadata_size_hint(metadata_size_hint);
+        }
 
         // Create the record batch stream builder, which wraps the parquet file reader
         let options = arrow_reader_options.unwrap_or_default();
@@ -681,6 +929,9 @@ impl ArrowReader {
                 ) if requested_precision >= file_precision && file_scale == requested_scale => true,
                 // Uuid will be store as Fixed(16) in parquet file, so the read back type will be Fixed(16).
                 (Some(PrimitiveType::Fixed(16)), Some(PrimitiveType::Uuid)) => true,
+                // Some writers store fixed as variable-length binary, the values are cast back to
+                // Fixed(L) when read.
+                (Some(PrimitiveType::Binary), Some(PrimitiveType::Fixed(_))) => true,
                 _ => false,
             }
         }
@@ -866,6 +1117,59 @@ impl ArrowReader {
         Ok(results)
     }
 
+    /// Drops the row groups whose bloom filters prove that no row matches the equality
+    /// predicates in `predicate`.
+    async fn filter_row_groups_by_bloom_filters(
+        record_batch_stream_builder: &mut ParquetRecordBatchStreamBuilder<ArrowFileReader>,
+        predicate: &BoundPredicate,
+        row_group_indices: Vec<usize>,
+        field_id_map: &HashMap<i32, usize>,
+    ) -> Result<Vec<usize>> {
+        let field_ids = RowGroupBloomFilterEvaluator::equality_field_ids(predicate);
+        if field_ids.is_empty() {
+            return Ok(row_group_indices);
+        }
+
+        let parquet_metadata = record_batch_stream_builder.metadata().clone();
+        let mut results = Vec::with_capacity(row_group_indices.len());
+        for idx in row_group_indices {
+            let row_group_metadata = parquet_metadata.row_group(idx);
+
+            let mut bloom_filters = HashMap::new();
+            for field_id in &field_ids {
+                let Some(&column_idx) = field_id_map.get(field_id) else {
+                    continue;
+                };
+                if row_group_metadata
+                    .column(column_idx)
+                    .bloom_filter_offset()
+                    .is_none()
+                {
+                    continue;
+                }
+                if let Some(bloom_filter) = record_batch_stream_builder
+                    .get_row_group_column_bloom_filter(idx, column_idx)
+                    .await?
+                {
+                    bloom_filters.insert(*field_id, bloom_filter);
+                }
+            }
+
+            if bloom_filters.is_empty()
+                || RowGroupBloomFilterEvaluator::eval(
+                    predicate,
+                    row_group_metadata,
+                    field_id_map,
+                    &bloom_filters,
+                )?
+            {
+                results.push(idx);
+            }
+        }
+
+        Ok(results)
+    }
+
     fn get_row_selection_for_filter_predicate(
         predicate: &BoundPredicate,
         parquet_metadata: &Arc<ParquetMetaData>,
@@ -1025,7 +1329,7 @@ fn build_fallback_field_id_map(parquet_schema: &SchemaDescriptor) -> HashMap<i32
 ///
 /// # Returns
 /// Arrow schema with field IDs assigned based on name mapping
-fn apply_name_mapping_to_arrow_schema(
+pub(crate) fn apply_name_mapping_to_arrow_schema(
     arrow_schema: ArrowSchemaRef,
     name_mapping: &NameMapping,
 ) -> Result<Arc<ArrowSchema>> {
@@ -1115,13 +1419,76 @@ fn add_fallback_field_ids_to_arrow_schema(arrow_schema: &ArrowSchemaRef) -> Arc<
     ))
 }
 
+/// Returns the Arrow schema of a Parquet file with its top-level INT96 columns typed as the
+/// timestamps of the matching table columns, or `None` if no INT96 column needs another type.
+///
+/// INT96 values are instants, so they are read with the UTC zone for `timestamptz` columns and
+/// without a zone for `timestamp` columns.
+fn int96_timestamps_as_table_type(
+    arrow_schema: &ArrowSchemaRef,
+    parquet_schema: &SchemaDescriptor,
+    iceberg_schema: &Schema,
+) -> Option<Arc<ArrowSchema>> {
+    let parquet_fields = parquet_schema.root_schema().get_fields();
+    if parquet_fields.len() != arrow_schema.fields().len() {
+        return None;
+    }
+
+    let mut changed = false;
+    let fields: Vec<FieldRef> = arrow_schema
+        .fields()
+        .iter()
+        .zip(parquet_fields)
+        .map(|(field, parquet_field)| {
+            let is_int96 = parquet_field.is_primitive()
+                && parquet_field.get_physical_type() == PhysicalType::INT96;
+            let table_type = field
+                .metadata()
+                .get(PARQUET_FIELD_ID_META_KEY)
+                .and_then(|field_id| field_id.parse::<i32>().ok())
+                .and_then(|field_id| iceberg_schema.field_by_id(field_id))
+                .and_then(|table_field| match table_field.field_type.as_ref() {
+                    Type::Primitive(PrimitiveType::Timestamp) => {
+                        Some(DataType::Timestamp(TimeUnit::Microsecond, None))
+                    }
+                    Type::Primitive(PrimitiveType::Timestamptz) => Some(DataType::Timestamp(
+                        TimeUnit::Microsecond,
+                        Some(UTC_TIME_ZONE.into()),
+                    )),
+                    Type::Primitive(PrimitiveType::TimestampNs) => {
+                        Some(DataType::Timestamp(TimeUnit::Nanosecond, None))
+                    }
+                    Type::Primitive(PrimitiveType::TimestamptzNs) => Some(DataType::Timestamp(
+                        TimeUnit::Nanosecond,
+                        Some(UTC_TIME_ZONE.into()),
+                    )),
+                    _ => None,
+                });
+            match table_type {
+                Some(data_type) if is_int96 && field.data_type() != &data_type => {
+                    changed = true;
+                    Arc::new(field.as_ref().clone().with_data_type(data_type))
+                }
+                _ => field.clone(),
+            }
+        })
+        .collect();
+
+    changed.then(|| {
+        Arc::new(ArrowSchema::new_with_metadata(
+            fields,
+            arrow_schema.metadata().clone(),
+        ))
+    })
+}
+
 /// A visitor to collect field ids from bound predicates.
-struct CollectFieldIdVisitor {
-    field_ids: HashSet<i32>,
+pub(crate) struct CollectFieldIdVisitor {
+    pub(crate) field_ids: HashSet<i32>,
 }
 
 impl CollectFieldIdVisitor {
-    fn field_ids(self) -> HashSet<i32> {
+    pub(crate) fn field_ids(self) -> HashSet<i32> {
         self.field_ids
     }
 }
@@ -1271,7 +1638,7 @@ impl BoundPredicateVisitor for CollectFieldIdVisitor {
 }
 
 /// A visitor to convert Iceberg bound predicates to Arrow predicates.
-struct PredicateConverter<'a> {
+pub(crate) struct PredicateConverter<'a> {
     /// The Parquet schema descriptor.
     pub parquet_schema: &'a SchemaDescriptor,
     /// The map between field id and leaf column index in Parquet schema.
@@ -1288,11 +1655,12 @@ impl PredicateConverter<'_> {
     fn bound_reference(&mut self, reference: &BoundReference) -> Result<Option<usize>> {
         // The leaf column's index in Parquet schema.
         if let Some(column_idx) = self.column_map.get(&reference.field().id) {
-            if self.parquet_schema.get_column_root(*column_idx).is_group() {
+            // Leaves nested in structs can be projected, but not the ones in lists or maps.
+            if self.parquet_schema.column(*column_idx).max_rep_level() > 0 {
                 return Err(Error::new(
                     ErrorKind::DataInvalid,
                     format!(
-                        "Leave column `{}` in predicates isn't a root column in Parquet schema.",
+                        "Leave column `{}` in predicates is nested in a list or map in Parquet schema.",
                         reference.field().name
                     ),
                 ));
@@ -1332,23 +1700,51 @@ impl PredicateConverter<'_> {
     }
 }
 
-/// Gets the leaf column from the record batch for the required column index. Only
-/// supports top-level columns for now.
+/// Projects the leaf column with the given index among the leaves of the batch, which may be
+/// nested in struct columns.
 fn project_column(
     batch: &RecordBatch,
-    column_idx: usize,
+    leaf_idx: usize,
 ) -> std::result::Result<ArrayRef, ArrowError> {
-    let column = batch.column(column_idx);
+    fn find_leaf(
+        column: &ArrayRef,
+        leaf_idx: &mut usize,
+    ) -> std::result::Result<Option<ArrayRef>, ArrowError> {
+        match column.data_type() {
+            DataType::Struct(_) => {
+                for child in column.as_struct().columns() {
+                    if let Some(leaf) = find_leaf(child, leaf_idx)? {
+                        return Ok(Some(leaf));
+                    }
+                }
+                Ok(None)
+            }
+            DataType::List(_)
+            | DataType::LargeList(_)
+            | DataType::FixedSizeList(_, _)
+            | DataType::Map(_, _) => Err(ArrowError::SchemaError(
+                "Does not support list or map column yet.".to_string(),
+            )),
+            _ if *leaf_idx == 0 => Ok(Some(column.clone())),
+            _ => {
+                *leaf_idx -= 1;
+                Ok(None)
+            }
+        }
+    }
 
-    match column.data_type() {
-        DataType::Struct(_) => Err(ArrowError::SchemaError(
-            "Does not support struct column yet.".to_string(),
-        )),
-        _ => Ok(column.clone()),
+    let mut remaining = leaf_idx;
+    for column in batch.columns() {
+        if let Some(leaf) = find_leaf(column, &mut remaining)? {
+            return Ok(leaf);
+        }
     }
+    Err(ArrowError::SchemaError(format!(
+        "Leaf column {leaf_idx} not found in record batch."
+    )))
 }
 
-type PredicateResult =
+pub(crate) type PredicateResult =
     dyn FnMut(RecordBatch) -> std::result::Result<BooleanArray, ArrowError> + Send + 'static;
 
 impl BoundPredicateVisitor for PredicateConverter<'_> {
@@ -1679,6 +2075,7 @@ pub struct ArrowFileReader {
     preload_offset_index: bool,
     preload_page_index: bool,
     metadata_size_hint: Option<usize>,
+    range_coalescing_gap: Option<u64>,
     r: Box<dyn FileRead>,
 }
 
@@ -1691,6 +2088,7 @@ impl ArrowFileReader {
             preload_offset_index: false,
             preload_page_index: false,
             metadata_size_hint: None,
+            range_coalescing_gap: None,
             r,
         }
     }
@@ -1721,6 +2119,27 @@ impl ArrowFileReader {
         self.metadata_size_hint = Some(hint);
         self
     }
+
+    /// Merge the byte ranges read together into a single read when they are at most `gap`
+    /// bytes apart. `None` reads every range on its own.
+    pub fn with_range_coalescing_gap(mut self, gap: Option<u64>) -> Self {
+        self.range_coalescing_gap = gap;
+        self
+    }
+}
+
+/// Merges the sorted `ranges` at most `gap` bytes apart, returning the merged ranges in order.
+fn coalesce_ranges(ranges: &[Range<u64>], gap: u64) -> Vec<Range<u64>> {
+    let mut coalesced: Vec<Range<u64>> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match coalesced.last_mut() {
+            Some(last) if range.start <= last.end.saturating_add(gap) => {
+                last.end = last.end.max(range.end);
+            }
+            _ => coalesced.push(range.clone()),
+        }
+    }
+    coalesced
 }
 
 impl AsyncFileReader for ArrowFileReader {
@@ -1732,12 +2151,49 @@ impl AsyncFileReader for ArrowFileReader {
         )
     }
 
-    // TODO: currently we don't respect `ArrowReaderOptions` cause it don't expose any method to access the option field
-    // we will fix it after `v55.1.0` is released in https://github.com/apache/arrow-rs/issues/7393
-    fn get_metadata(
+    fn get_byte_ranges(
         &mut self,
-        _options: Option<&'_ ArrowReaderOptions>,
-    ) -> BoxFuture<'_, parquet::errors::Result<Arc<ParquetMetaData>>> {
+        ranges: Vec<Range<u64>>,
+    ) -> BoxFuture<'_, parquet::errors::Result<Vec<Bytes>>> {
+        async move {
+            let Some(gap) = self.range_coalescing_gap else {
+                let mut result = Vec::with_capacity(ranges.len());
+                for range in ranges {
+                    result.push(self.get_bytes(range).await?);
+                }
+                return Ok(result);
+            };
+
+            let mut sorted_ranges = ranges.clone();
+            sorted_ranges.sort_by_key(|range| range.start);
+            let coalesced = coalesce_ranges(&sorted_ranges, gap);
+            let fetched = futures::future::try_join_all(
+                coalesced.iter().map(|range| self.r.read(range.clone())),
+            )
+            .await
+            .map_err(|err| parquet::errors::ParquetError::External(Box::new(err)))?;
+
+            Ok(ranges
+                .iter()
+                .map(|range| {
+                    // Every range is contained in the last coalesced range starting before it.
+                    let idx = coalesced.partition_point(|merged| merged.start <= range.start) - 1;
+                    let start = (range.start - coalesced[idx].start) as usize;
+                    let end = (range.end - coalesced[idx].start) as usize;
+                    fetched[idx].slice(start..end)
+                })
+                .collect())
+        }
+        .boxed()
+    }
+
+    // TODO: apart from the decryption properties, we don't respect `ArrowReaderOptions` yet
+    fn get_metadata<'a>(
+        &'a mut self,
+        options: Option<&'a ArrowReaderOptions>,
+    ) -> BoxFuture<'a, parquet::errors::Result<Arc<ParquetMetaData>>> {
+        #[cfg(not(feature = "encryption"))]
+        let _ = options;
         async move {
             let reader = ParquetMetaDataReader::new()
                 .with_prefetch_hint(self.metadata_size_hint)
@@ -1745,6 +2201,10 @@ impl AsyncFileReader for ArrowFileReader {
                 .with_page_index_policy(PageIndexPolicy::from(self.preload_page_index))
                 .with_column_index_policy(PageIndexPolicy::from(self.preload_column_index))
                 .with_offset_index_policy(PageIndexPolicy::from(self.preload_offset_index));
+            #[cfg(feature = "encryption")]
+            let reader = reader.with_decryption_properties(
+                options.and_then(|options| options.file_decryption_properties().cloned()),
+            );
             let size = self.meta.size;
             let meta = reader.load_and_finish(self, size).await?;
 
@@ -1779,11 +2239,13 @@ fn try_cast_literal(
 mod tests {
     use std::collections::{HashMap, HashSet};
     use std::fs::File;
-    use std::sync::Arc;
+    use std::ops::Range;
+    use std::sync::{Arc, Mutex};
 
     use arrow_array::cast::AsArray;
     use arrow_array::{ArrayRef, LargeStringArray, RecordBatch, StringArray};
     use arrow_schema::{DataType, Field, Schema as ArrowSchema, TimeUnit};
+    use bytes::Bytes;
     use futures::TryStreamExt;
     use parquet::arrow::arrow_reader::{RowSelection, RowSelector};
     use parquet::arrow::{ArrowWriter, ProjectionMask};
@@ -1801,7 +2263,7 @@ mod tests {
     use crate::delete_vector::DeleteVector;
     use crate::expr::visitors::bound_predicate_visitor::visit;
     use crate::expr::{Bind, Predicate, Reference};
-    use crate::io::FileIO;
+    use crate::io::{FileIO, FileRead};
     use crate::scan::{FileScanTask, FileScanTaskDeleteFile, FileScanTaskStream};
     use crate::spec::{
         DataContentType, DataFileFormat, Datum, NestedField, PrimitiveType, Schema, SchemaRef, Type,
@@ -1823,6 +2285,20 @@ mod tests {
         )
     }
 
+    /// Reads a file from memory, recording the requested ranges.
+    struct CountingFileRead {
+        bytes: Bytes,
+        ranges: Arc<Mutex<Vec<Range<u64>>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl FileRead for CountingFileRead {
+        async fn read(&self, range: Range<u64>) -> crate::Result<Bytes> {
+            self.ranges.lock().unwrap().push(range.clone());
+            Ok(self.bytes.slice(range.start as usize..range.end as usize))
+        }
+    }
+
     #[test]
     fn test_collect_field_id() {
         let schema = table_schema_simple();
@@ -2110,6 +2586,10 @@ message schema {
                 partition_spec: None,
                 name_mapping: None,
                 case_sensitive: false,
+                sort_order_id: None,
+                column_sizes: HashMap::new(),
+                value_counts: HashMap::new(),
+                timestamp_zone: None,
             })]
             .into_iter(),
         )) as FileScanTaskStream;
@@ -2432,6 +2912,10 @@ message schema {
             partition_spec: None,
             name_mapping: None,
             case_sensitive: false,
+            sort_order_id: None,
+            column_sizes: HashMap::new(),
+            value_counts: HashMap::new(),
+            timestamp_zone: None,
         };
 
         // Task 2: read the second and third row groups
@@ -2449,6 +2933,10 @@ message schema {
             partition_spec: None,
             name_mapping: None,
             case_sensitive: false,
+            sort_order_id: None,
+            column_sizes: HashMap::new(),
+            value_counts: HashMap::new(),
+            timestamp_zone: None,
         };
 
         let tasks1 = Box::pin(futures::stream::iter(vec![Ok(task1)])) as FileScanTaskStream;
@@ -2577,6 +3065,10 @@ message schema {
                 partition_spec: None,
                 name_mapping: None,
                 case_sensitive: false,
+                sort_order_id: None,
+                column_sizes: HashMap::new(),
+                value_counts: HashMap::new(),
+                timestamp_zone: None,
             })]
             .into_iter(),
         )) as FileScanTaskStream;
@@ -2744,11 +3236,20 @@ message schema {
                 file_type: DataContentType::PositionDeletes,
                 partition_spec_id: 0,
                 equality_ids: None,
+                referenced_data_file: None,
+                content_offset: None,
+                content_size_in_bytes: None,
+                file_size_in_bytes: 0,
+                record_count: 0,
             }],
             partition: None,
             partition_spec: None,
             name_mapping: None,
             case_sensitive: false,
+            sort_order_id: None,
+            column_sizes: HashMap::new(),
+            value_counts: HashMap::new(),
+            timestamp_zone: None,
         };
 
         let tasks = Box::pin(futures::stream::iter(vec![Ok(task)])) as FileScanTaskStream;
@@ -2798,44 +3299,16 @@ message schema {
         );
     }
 
-    /// Test for bug where position deletes are lost when skipping unselected row groups.
-    ///
-    /// This is a variant of `test_position_delete_across_multiple_row_groups` that exercises
-    /// the row group selection code path (`selected_row_groups: Some([...])`).
-    ///
-    /// When a file has multiple row groups and only some are selected for reading,
-    /// the `build_deletes_row_selection` function must correctly skip over deletes in
-    /// unselected row groups WITHOUT consuming deletes that belong to selected row groups.
-    ///
-    /// This test creates:
-    /// - A data file with 200 rows split into 2 row groups (0-99, 100-199)
-    /// - A position delete file that deletes row 199 (last row in second row group)
-    /// - Row group selection that reads ONLY row group 1 (rows 100-199)
-    ///
-    /// Expected behavior: Should return 99 rows (with row 199 deleted)
-    /// Bug behavior: Returns 100 rows (delete is lost when skipping row group 0)
-    ///
-    /// The bug occurs when processing row group 0 (unselected):
-    /// ```rust
-    /// delete_vector_iter.advance_to(next_row_group_base_idx); // Position at first delete >= 100
-    /// next_deleted_row_idx_opt = delete_vector_iter.next(); // BUG: Consumes delete at 199!
-    /// ```
-    ///
-    /// The fix is to NOT call `next()` after `advance_to()` when skipping unselected row groups,
-    /// because `advance_to()` already positions the iterator correctly without consuming elements.
     #[tokio::test]
-    async fn test_position_delete_with_row_group_selection() {
+    async fn test_read_with_fixed_batch_size_across_row_groups_and_deletes() {
         use arrow_array::{Int32Array, Int64Array};
-        use parquet::file::reader::{FileReader, SerializedFileReader};
 
-        // Field IDs for positional delete schema
         const FIELD_ID_POSITIONAL_DELETE_FILE_PATH: u64 = 2147483546;
         const FIELD_ID_POSITIONAL_DELETE_POS: u64 = 2147483545;
 
         let tmp_dir = TempDir::new().unwrap();
         let table_location = tmp_dir.path().to_str().unwrap().to_string();
 
-        // Create table schema with a single 'id' column
         let table_schema = Arc::new(
             Schema::builder()
                 .with_schema_id(1)
@@ -2845,7 +3318,6 @@ message schema {
                 .build()
                 .unwrap(),
         );
-
         let arrow_schema = Arc::new(ArrowSchema::new(vec![
             Field::new("id", DataType::Int32, false).with_metadata(HashMap::from([(
                 PARQUET_FIELD_ID_META_KEY.to_string(),
@@ -2853,45 +3325,21 @@ message schema {
             )])),
         ]));
 
-        // Step 1: Create data file with 200 rows in 2 row groups
-        // Row group 0: rows 0-99 (ids 1-100)
-        // Row group 1: rows 100-199 (ids 101-200)
+        // 2500 rows in row groups of 300 rows, the last one being larger than a batch.
         let data_file_path = format!("{table_location}/data.parquet");
-
-        let batch1 = RecordBatch::try_new(arrow_schema.clone(), vec![Arc::new(
-            Int32Array::from_iter_values(1..=100),
-        )])
-        .unwrap();
-
-        let batch2 = RecordBatch::try_new(arrow_schema.clone(), vec![Arc::new(
-            Int32Array::from_iter_values(101..=200),
-        )])
-        .unwrap();
-
-        // Force each batch into its own row group
         let props = WriterProperties::builder()
-            .set_compression(Compression::SNAPPY)
-            .set_max_row_group_size(100)
+            .set_max_row_group_size(300)
             .build();
-
         let file = File::create(&data_file_path).unwrap();
         let mut writer = ArrowWriter::try_new(file, arrow_schema.clone(), Some(props)).unwrap();
-        writer.write(&batch1).expect("Writing batch 1");
-        writer.write(&batch2).expect("Writing batch 2");
+        let batch = RecordBatch::try_new(arrow_schema.clone(), vec![Arc::new(
+            Int32Array::from_iter_values(0..2500),
+        )])
+        .unwrap();
+        writer.write(&batch).unwrap();
         writer.close().unwrap();
 
-        // Verify we created 2 row groups
-        let verify_file = File::open(&data_file_path).unwrap();
-        let verify_reader = SerializedFileReader::new(verify_file).unwrap();
-        assert_eq!(
-            verify_reader.metadata().num_row_groups(),
-            2,
-            "Should have 2 row groups"
-        );
-
-        // Step 2: Create position delete file that deletes row 199 (id=200, last row in row group 1)
         let delete_file_path = format!("{table_location}/deletes.parquet");
-
         let delete_schema = Arc::new(ArrowSchema::new(vec![
             Field::new("file_path", DataType::Utf8, false).with_metadata(HashMap::from([(
                 PARQUET_FIELD_ID_META_KEY.to_string(),
@@ -2902,32 +3350,349 @@ message schema {
                 FIELD_ID_POSITIONAL_DELETE_POS.to_string(),
             )])),
         ]));
-
-        // Delete row at position 199 (0-indexed, so it's the last row: id=200)
+        let deleted_positions = vec![0i64, 10, 299, 1200, 2499];
         let delete_batch = RecordBatch::try_new(delete_schema.clone(), vec![
-            Arc::new(StringArray::from_iter_values(vec![data_file_path.clone()])),
-            Arc::new(Int64Array::from_iter_values(vec![199i64])),
+            Arc::new(StringArray::from_iter_values(vec![
+                data_file_path.clone();
+                deleted_positions.len()
+            ])),
+            Arc::new(Int64Array::from_iter_values(deleted_positions.clone())),
         ])
         .unwrap();
-
-        let delete_props = WriterProperties::builder()
-            .set_compression(Compression::SNAPPY)
-            .build();
-
         let delete_file = File::create(&delete_file_path).unwrap();
-        let mut delete_writer =
-            ArrowWriter::try_new(delete_file, delete_schema, Some(delete_props)).unwrap();
+        let mut delete_writer = ArrowWriter::try_new(delete_file, delete_schema, None).unwrap();
         delete_writer.write(&delete_batch).unwrap();
         delete_writer.close().unwrap();
 
-        // Step 3: Get byte ranges to read ONLY row group 1 (rows 100-199)
-        // This exercises the row group selection code path where row group 0 is skipped
-        let metadata_file = File::open(&data_file_path).unwrap();
-        let metadata_reader = SerializedFileReader::new(metadata_file).unwrap();
-        let metadata = metadata_reader.metadata();
-
-        let row_group_0 = metadata.row_group(0);
-        let row_group_1 = metadata.row_group(1);
+        let file_io = FileIO::from_path(&table_location).unwrap().build().unwrap();
+        let reader = ArrowReaderBuilder::new(file_io)
+            .with_batch_size(1024)
+            .build();
+        let task = FileScanTask {
+            start: 0,
+            length: 0,
+            record_count: Some(2500),
+            data_file_path: data_file_path.clone(),
+            data_file_format: DataFileFormat::Parquet,
+            schema: table_schema.clone(),
+            project_field_ids: vec![1],
+            predicate: None,
+            deletes: vec![FileScanTaskDeleteFile {
+                file_path: delete_file_path,
+                file_type: DataContentType::PositionDeletes,
+                partition_spec_id: 0,
+                equality_ids: None,
+                referenced_data_file: None,
+                content_offset: None,
+                content_size_in_bytes: None,
+                file_size_in_bytes: 0,
+                record_count: 0,
+            }],
+            partition: None,
+            partition_spec: None,
+            name_mapping: None,
+            case_sensitive: false,
+            sort_order_id: None,
+            column_sizes: HashMap::new(),
+            value_counts: HashMap::new(),
+            timestamp_zone: None,
+        };
+
+        let tasks = Box::pin(futures::stream::iter(vec![Ok(task)])) as FileScanTaskStream;
+        let result = reader
+            .read(tasks)
+            .unwrap()
+            .try_collect::<Vec<RecordBatch>>()
+            .await
+            .unwrap();
+
+        let (last, rest) = result.split_last().unwrap();
+        assert!(rest.iter().all(|batch| batch.num_rows() == 1024));
+        assert_eq!(rest.len(), 2);
+        assert_eq!(last.num_rows(), 2500 - deleted_positions.len() - 2 * 1024);
+
+        let ids: Vec<i32> = result
+            .iter()
+            .flat_map(|batch| {
+                batch
+                    .column(0)
+                    .as_primitive::<arrow_array::types::Int32Type>()
+                    .values()
+                    .to_vec()
+            })
+            .collect();
+        let expected: Vec<i32> = (0..2500)
+            .filter(|id| !deleted_positions.contains(&(*id as i64)))
+            .collect();
+        assert_eq!(ids, expected);
+    }
+
+    #[tokio::test]
+    async fn test_row_group_filtering_consults_bloom_filters() {
+        use arrow_array::Int64Array;
+
+        use crate::arrow::ArrowFileReader;
+        use crate::io::FileMetadata;
+
+        let schema = Arc::new(
+            Schema::builder()
+                .with_schema_id(1)
+                .with_fields(vec![
+                    NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+                ])
+                .build()
+                .unwrap(),
+        );
+        let arrow_schema = Arc::new(ArrowSchema::new(vec![
+            Field::new("id", DataType::Int64, false).with_metadata(HashMap::from([(
+                PARQUET_FIELD_ID_META_KEY.to_string(),
+                "1".to_string(),
+            )])),
+        ]));
+
+        // Both row groups cover the same range of ids, the first one holding the even ids
+        // and the second one the odd ids, so only bloom filters can tell them apart.
+        let tmp_dir = TempDir::new().unwrap();
+        let table_location = tmp_dir.path().to_str().unwrap().to_string();
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(100)
+            .set_bloom_filter_enabled(true)
+            .build();
+        let file = File::create(format!("{table_location}/1.parquet")).unwrap();
+        let mut writer = ArrowWriter::try_new(file, arrow_schema.clone(), Some(props)).unwrap();
+        for ids in [(0..200).step_by(2), (1..200).step_by(2)] {
+            let batch = RecordBatch::try_new(arrow_schema.clone(), vec![Arc::new(
+                Int64Array::from_iter_values(ids),
+            )])
+            .unwrap();
+            writer.write(&batch).unwrap();
+        }
+        writer.close().unwrap();
+
+        let predicate = Reference::new("id")
+            .equal_to(Datum::long(51))
+            .bind(schema.clone(), true)
+            .unwrap();
+
+        let bytes = Bytes::from(std::fs::read(format!("{table_location}/1.parquet")).unwrap());
+        let ranges = Arc::new(Mutex::new(vec![]));
+        let file_reader = ArrowFileReader::new(
+            FileMetadata {
+                size: bytes.len() as u64,
+            },
+            Box::new(CountingFileRead {
+                bytes,
+                ranges: ranges.clone(),
+            }),
+        );
+        let mut builder = parquet::arrow::ParquetRecordBatchStreamBuilder::new(file_reader)
+            .await
+            .unwrap();
+        let parquet_metadata = builder.metadata().clone();
+        assert_eq!(parquet_metadata.num_row_groups(), 2);
+        let (_, field_id_map) =
+            ArrowReader::build_field_id_set_and_map(builder.parquet_schema(), &predicate).unwrap();
+
+        // The statistics of both row groups may contain the id.
+        let by_metrics = ArrowReader::get_selected_row_group_indices(
+            &predicate,
+            &parquet_metadata,
+            &field_id_map,
+            &schema,
+        )
+        .unwrap();
+        assert_eq!(by_metrics, vec![0, 1]);
+
+        ranges.lock().unwrap().clear();
+        let by_bloom_filters = ArrowReader::filter_row_groups_by_bloom_filters(
+            &mut builder,
+            &predicate,
+            by_metrics,
+            &field_id_map,
+        )
+        .await
+        .unwrap();
+        assert_eq!(by_bloom_filters, vec![1]);
+
+        // The bloom filter of each row group has been read.
+        let ranges = ranges.lock().unwrap().clone();
+        for row_group in parquet_metadata.row_groups() {
+            let offset = row_group.column(0).bloom_filter_offset().unwrap() as u64;
+            assert!(ranges.iter().any(|range| range.start == offset));
+        }
+
+        let reader =
+            ArrowReaderBuilder::new(FileIO::from_path(&table_location).unwrap().build().unwrap())
+                .with_row_group_filtering_enabled(true)
+                .build();
+        let tasks = Box::pin(futures::stream::iter(vec![Ok(FileScanTask {
+            start: 0,
+            length: 0,
+            record_count: None,
+            data_file_path: format!("{table_location}/1.parquet"),
+            data_file_format: DataFileFormat::Parquet,
+            schema: schema.clone(),
+            project_field_ids: vec![1],
+            predicate: Some(predicate),
+            deletes: vec![],
+            partition: None,
+            partition_spec: None,
+            name_mapping: None,
+            case_sensitive: false,
+            sort_order_id: None,
+            column_sizes: HashMap::new(),
+            value_counts: HashMap::new(),
+            timestamp_zone: None,
+        })])) as FileScanTaskStream;
+        let result = reader
+            .read(tasks)
+            .unwrap()
+            .try_collect::<Vec<RecordBatch>>()
+            .await
+            .unwrap();
+        let ids: Vec<i64> = result
+            .iter()
+            .flat_map(|batch| {
+                batch
+                    .column(0)
+                    .as_primitive::<arrow_array::types::Int64Type>()
+                    .values()
+                    .to_vec()
+            })
+            .collect();
+        assert_eq!(ids, vec![51]);
+    }
+
+    /// Test for bug where position deletes are lost when skipping unselected row groups.
+    ///
+    /// This is a variant of `test_position_delete_across_multiple_row_groups` that exercises
+    /// the row group selection code path (`selected_row_groups: Some([...])`).
+    ///
+    /// When a file has multiple row groups and only some are selected for reading,
+    /// the `build_deletes_row_selection` function must correctly skip over deletes in
+    /// unselected row groups WITHOUT consuming deletes that belong to selected row groups.
+    ///
+    /// This test creates:
+    /// - A data file with 200 rows split into 2 row groups (0-99, 100-199)
+    /// - A position delete file that deletes row 199 (last row in second row group)
+    /// - Row group selection that reads ONLY row group 1 (rows 100-199)
+    ///
+    /// Expected behavior: Should return 99 rows (with row 199 deleted)
+    /// Bug behavior: Returns 100 rows (delete is lost when skipping row group 0)
+    ///
+    /// The bug occurs when processing row group 0 (unselected):
+    /// ```rust
+    /// delete_vector_iter.advance_to(next_row_group_base_idx); // Position at first delete >= 100
+    /// next_deleted_row_idx_opt = delete_vector_iter.next(); // BUG: Consumes delete at 199!
+    /// ```
+    ///
+    /// The fix is to NOT call `next()` after `advance_to()` when skipping unselected row groups,
+    /// because `advance_to()` already positions the iterator correctly without consuming elements.
+    #[tokio::test]
+    async fn test_position_delete_with_row_group_selection() {
+        use arrow_array::{Int32Array, Int64Array};
+        use parquet::file::reader::{FileReader, SerializedFileReader};
+
+        // Field IDs for positional delete schema
+        const FIELD_ID_POSITIONAL_DELETE_FILE_PATH: u64 = 2147483546;
+        const FIELD_ID_POSITIONAL_DELETE_POS: u64 = 2147483545;
+
+        let tmp_dir = TempDir::new().unwrap();
+        let table_location = tmp_dir.path().to_str().unwrap().to_string();
+
+        // Create table schema with a single 'id' column
+        let table_schema = Arc::new(
+            Schema::builder()
+                .with_schema_id(1)
+                .with_fields(vec![
+                    NestedField::required(1, "id", Type::Primitive(PrimitiveType::Int)).into(),
+                ])
+                .build()
+                .unwrap(),
+        );
+
+        let arrow_schema = Arc::new(ArrowSchema::new(vec![
+            Field::new("id", DataType::Int32, false).with_metadata(HashMap::from([(
+                PARQUET_FIELD_ID_META_KEY.to_string(),
+                "1".to_string(),
+            )])),
+        ]));
+
+        // Step 1: Create data file with 200 rows in 2 row groups
+        // Row group 0: rows 0-99 (ids 1-100)
+        // Row group 1: rows 100-199 (ids 101-200)
+        let data_file_path = format!("{table_location}/data.parquet");
+
+        let batch1 = RecordBatch::try_new(arrow_schema.clone(), vec![Arc::new(
+            Int32Array::from_iter_values(1..=100),
+        )])
+        .unwrap();
+
+        let batch2 = RecordBatch::try_new(arrow_schema.clone(), vec![Arc::new(
+            Int32Array::from_iter_values(101..=200),
+        )])
+        .unwrap();
+
+        // Force each batch into its own row group
+        let props = WriterProperties::builder()
+            .set_compression(Compression::SNAPPY)
+            .set_max_row_group_size(100)
+            .build();
+
+        let file = File::create(&data_file_path).unwrap();
+        let mut writer = ArrowWriter::try_new(file, arrow_schema.clone(), Some(props)).unwrap();
+        writer.write(&batch1).expect("Writing batch 1");
+        writer.write(&batch2).expect("Writing batch 2");
+        writer.close().unwrap();
+
+        // Verify we created 2 row groups
+        let verify_file = File::open(&data_file_path).unwrap();
+        let verify_reader = SerializedFileReader::new(verify_file).unwrap();
+        assert_eq!(
+            verify_reader.metadata().num_row_groups(),
+            2,
+            "Should have 2 row groups"
+        );
+
+        // Step 2: Create position delete file that deletes row 199 (id=200, last row in row group 1)
+        let delete_file_path = format!("{table_location}/deletes.parquet");
+
+        let delete_schema = Arc::new(ArrowSchema::new(vec![
+            Field::new("file_path", DataType::Utf8, false).with_metadata(HashMap::from([(
+                PARQUET_FIELD_ID_META_KEY.to_string(),
+                FIELD_ID_POSITIONAL_DELETE_FILE_PATH.to_string(),
+            )])),
+            Field::new("pos", DataType::Int64, false).with_metadata(HashMap::from([(
+                PARQUET_FIELD_ID_META_KEY.to_string(),
+                FIELD_ID_POSITIONAL_DELETE_POS.to_string(),
+            )])),
+        ]));
+
+        // Delete row at position 199 (0-indexed, so it's the last row: id=200)
+        let delete_batch = RecordBatch::try_new(delete_schema.clone(), vec![
+            Arc::new(StringArray::from_iter_values(vec![data_file_path.clone()])),
+            Arc::new(Int64Array::from_iter_values(vec![199i64])),
+        ])
+        .unwrap();
+
+        let delete_props = WriterProperties::builder()
+            .set_compression(Compression::SNAPPY)
+            .build();
+
+        let delete_file = File::create(&delete_file_path).unwrap();
+        let mut delete_writer =
+            ArrowWriter::try_new(delete_file, delete_schema, Some(delete_props)).unwrap();
+        delete_writer.write(&delete_batch).unwrap();
+        delete_writer.close().unwrap();
+
+        // Step 3: Get byte ranges to read ONLY row group 1 (rows 100-199)
+        // This exercises the row group selection code path where row group 0 is skipped
+        let metadata_file = File::open(&data_file_path).unwrap();
+        let metadata_reader = SerializedFileReader::new(metadata_file).unwrap();
+        let metadata = metadata_reader.metadata();
+
+        let row_group_0 = metadata.row_group(0);
+        let row_group_1 = metadata.row_group(1);
 
         let rg0_start = 4u64; // Parquet files start with 4-byte magic "PAR1"
         let rg1_start = rg0_start + row_group_0.compressed_size() as u64;
@@ -2962,11 +3727,20 @@ message schema {
                 file_type: DataContentType::PositionDeletes,
                 partition_spec_id: 0,
                 equality_ids: None,
+                referenced_data_file: None,
+                content_offset: None,
+                content_size_in_bytes: None,
+                file_size_in_bytes: 0,
+                record_count: 0,
             }],
             partition: None,
             partition_spec: None,
             name_mapping: None,
             case_sensitive: false,
+            sort_order_id: None,
+            column_sizes: HashMap::new(),
+            value_counts: HashMap::new(),
+            timestamp_zone: None,
         };
 
         let tasks = Box::pin(futures::stream::iter(vec![Ok(task)])) as FileScanTaskStream;
@@ -3173,11 +3947,20 @@ message schema {
                 file_type: DataContentType::PositionDeletes,
                 partition_spec_id: 0,
                 equality_ids: None,
+                referenced_data_file: None,
+                content_offset: None,
+                content_size_in_bytes: None,
+                file_size_in_bytes: 0,
+                record_count: 0,
             }],
             partition: None,
             partition_spec: None,
             name_mapping: None,
             case_sensitive: false,
+            sort_order_id: None,
+            column_sizes: HashMap::new(),
+            value_counts: HashMap::new(),
+            timestamp_zone: None,
         };
 
         let tasks = Box::pin(futures::stream::iter(vec![Ok(task)])) as FileScanTaskStream;
@@ -3282,6 +4065,10 @@ message schema {
                 partition_spec: None,
                 name_mapping: None,
                 case_sensitive: false,
+                sort_order_id: None,
+                column_sizes: HashMap::new(),
+                value_counts: HashMap::new(),
+                timestamp_zone: None,
             })]
             .into_iter(),
         )) as FileScanTaskStream;
@@ -3380,6 +4167,10 @@ message schema {
                 partition_spec: None,
                 name_mapping: None,
                 case_sensitive: false,
+                sort_order_id: None,
+                column_sizes: HashMap::new(),
+                value_counts: HashMap::new(),
+                timestamp_zone: None,
             })]
             .into_iter(),
         )) as FileScanTaskStream;
@@ -3467,6 +4258,10 @@ message schema {
                 partition_spec: None,
                 name_mapping: None,
                 case_sensitive: false,
+                sort_order_id: None,
+                column_sizes: HashMap::new(),
+                value_counts: HashMap::new(),
+                timestamp_zone: None,
             })]
             .into_iter(),
         )) as FileScanTaskStream;
@@ -3568,6 +4363,10 @@ message schema {
                 partition_spec: None,
                 name_mapping: None,
                 case_sensitive: false,
+                sort_order_id: None,
+                column_sizes: HashMap::new(),
+                value_counts: HashMap::new(),
+                timestamp_zone: None,
             })]
             .into_iter(),
         )) as FileScanTaskStream;
@@ -3698,6 +4497,10 @@ message schema {
                 partition_spec: None,
                 name_mapping: None,
                 case_sensitive: false,
+                sort_order_id: None,
+                column_sizes: HashMap::new(),
+                value_counts: HashMap::new(),
+                timestamp_zone: None,
             })]
             .into_iter(),
         )) as FileScanTaskStream;
@@ -3795,6 +4598,10 @@ message schema {
                 partition_spec: None,
                 name_mapping: None,
                 case_sensitive: false,
+                sort_order_id: None,
+                column_sizes: HashMap::new(),
+                value_counts: HashMap::new(),
+                timestamp_zone: None,
             })]
             .into_iter(),
         )) as FileScanTaskStream;
@@ -3905,6 +4712,10 @@ message schema {
                 partition_spec: None,
                 name_mapping: None,
                 case_sensitive: false,
+                sort_order_id: None,
+                column_sizes: HashMap::new(),
+                value_counts: HashMap::new(),
+                timestamp_zone: None,
             })]
             .into_iter(),
         )) as FileScanTaskStream;
@@ -3996,6 +4807,10 @@ message schema {
                 partition_spec: None,
                 name_mapping: None,
                 case_sensitive: false,
+                sort_order_id: None,
+                column_sizes: HashMap::new(),
+                value_counts: HashMap::new(),
+                timestamp_zone: None,
             }),
             Ok(FileScanTask {
                 start: 0,
@@ -4011,6 +4826,10 @@ message schema {
                 partition_spec: None,
                 name_mapping: None,
                 case_sensitive: false,
+                sort_order_id: None,
+                column_sizes: HashMap::new(),
+                value_counts: HashMap::new(),
+                timestamp_zone: None,
             }),
             Ok(FileScanTask {
                 start: 0,
@@ -4026,6 +4845,10 @@ message schema {
                 partition_spec: None,
                 name_mapping: None,
                 case_sensitive: false,
+                sort_order_id: None,
+                column_sizes: HashMap::new(),
+                value_counts: HashMap::new(),
+                timestamp_zone: None,
             }),
         ];
 
@@ -4205,6 +5028,10 @@ message schema {
                 partition_spec: Some(partition_spec),
                 name_mapping: None,
                 case_sensitive: false,
+                sort_order_id: None,
+                column_sizes: HashMap::new(),
+                value_counts: HashMap::new(),
+                timestamp_zone: None,
             })]
             .into_iter(),
         )) as FileScanTaskStream;
@@ -4239,4 +5066,869 @@ message schema {
         assert_eq!(name_col.value(2), "Charlie");
         assert_eq!(name_col.value(3), "Dave");
     }
+
+    /// Test that `fixed[L]` and `binary` columns keep their Arrow types when written with
+    /// [`ParquetWriter`](crate::writer::file_writer::ParquetWriter) and read back, even when
+    /// another writer stored them as plain variable-length binary.
+    #[tokio::test]
+    async fn test_read_fixed_and_binary_round_trip() {
+        use arrow_array::{BinaryArray, FixedSizeBinaryArray, LargeBinaryArray};
+        use parquet::basic::Type as PhysicalType;
+
+        use crate::arrow::schema_to_arrow_schema;
+        use crate::writer::file_writer::{FileWriter, FileWriterBuilder, ParquetWriterBuilder};
+
+        let schema = Arc::new(
+            Schema::builder()
+                .with_schema_id(1)
+                .with_fields(vec![
+                    NestedField::required(1, "id", Type::Primitive(PrimitiveType::Fixed(16)))
+                        .into(),
+                    NestedField::optional(2, "payload", Type::Primitive(PrimitiveType::Binary))
+                        .into(),
+                ])
+                .build()
+                .unwrap(),
+        );
+        let ids = [[1u8; 16], [2u8; 16], [3u8; 16]];
+        let payloads = vec![
+            Some(b"a".as_slice()),
+            None,
+            Some(b"longer payload".as_slice()),
+        ];
+
+        let tmp_dir = TempDir::new().unwrap();
+        let table_location = tmp_dir.path().to_str().unwrap().to_string();
+        let file_io = FileIO::from_path(&table_location).unwrap().build().unwrap();
+
+        // Written by iceberg with the arrow schema derived from the table schema
+        let arrow_schema = Arc::new(schema_to_arrow_schema(&schema).unwrap());
+        let to_write = RecordBatch::try_new(arrow_schema, vec![
+            Arc::new(FixedSizeBinaryArray::try_from_iter(ids.iter()).unwrap()) as ArrayRef,
+            Arc::new(LargeBinaryArray::from_opt_vec(payloads.clone())) as ArrayRef,
+        ])
+        .unwrap();
+        let iceberg_file = format!("{table_location}/iceberg.parquet");
+        let mut writer =
+            ParquetWriterBuilder::new(WriterProperties::builder().build(), schema.clone())
+                .build(file_io.new_output(&iceberg_file).unwrap())
+                .await
+                .unwrap();
+        writer.write(&to_write).await.unwrap();
+        writer.close().await.unwrap();
+
+        // The file keeps the fixed width and the field ids of both columns
+        let parquet_reader = parquet::file::serialized_reader::SerializedFileReader::new(
+            File::open(&iceberg_file).unwrap(),
+        )
+        .unwrap();
+        let parquet_schema = parquet::file::reader::FileReader::metadata(&parquet_reader)
+            .file_metadata()
+            .schema_descr_ptr();
+        let id_column = parquet_schema.column(0);
+        assert_eq!(
+            id_column.physical_type(),
+            PhysicalType::FIXED_LEN_BYTE_ARRAY
+        );
+        assert_eq!(id_column.type_length(), 16);
+        assert_eq!(id_column.self_type().get_basic_info().id(), 1);
+        let payload_column = parquet_schema.column(1);
+        assert_eq!(payload_column.physical_type(), PhysicalType::BYTE_ARRAY);
+        assert_eq!(payload_column.self_type().get_basic_info().id(), 2);
+
+        // Written by another writer using variable-length binary for both columns
+        let other_schema = Arc::new(ArrowSchema::new(vec![
+            Field::new("id", DataType::Binary, false).with_metadata(HashMap::from([(
+                PARQUET_FIELD_ID_META_KEY.to_string(),
+                "1".to_string(),
+            )])),
+            Field::new("payload", DataType::Binary, true).with_metadata(HashMap::from([(
+                PARQUET_FIELD_ID_META_KEY.to_string(),
+                "2".to_string(),
+            )])),
+        ]));
+        let to_write = RecordBatch::try_new(other_schema.clone(), vec![
+            Arc::new(BinaryArray::from_iter_values(ids.iter())) as ArrayRef,
+            Arc::new(BinaryArray::from_opt_vec(payloads.clone())) as ArrayRef,
+        ])
+        .unwrap();
+        let other_file = format!("{table_location}/other.parquet");
+        let mut writer =
+            ArrowWriter::try_new(File::create(&other_file).unwrap(), other_schema, None).unwrap();
+        writer.write(&to_write).unwrap();
+        writer.close().unwrap();
+
+        for data_file_path in [iceberg_file, other_file] {
+            let reader = ArrowReaderBuilder::new(file_io.clone()).build();
+            let tasks = Box::pin(futures::stream::iter(
+                vec![Ok(FileScanTask {
+                    start: 0,
+                    length: 0,
+                    record_count: None,
+                    data_file_path: data_file_path.clone(),
+                    data_file_format: DataFileFormat::Parquet,
+                    schema: schema.clone(),
+                    project_field_ids: vec![1, 2],
+                    predicate: None,
+                    deletes: vec![],
+                    partition: None,
+                    partition_spec: None,
+                    name_mapping: None,
+                    case_sensitive: false,
+                    sort_order_id: None,
+                    column_sizes: HashMap::new(),
+                    value_counts: HashMap::new(),
+                    timestamp_zone: None,
+                })]
+                .into_iter(),
+            )) as FileScanTaskStream;
+
+            let result = reader
+                .read(tasks)
+                .unwrap()
+                .try_collect::<Vec<RecordBatch>>()
+                .await
+                .unwrap();
+            assert_eq!(result.len(), 1, "{data_file_path}");
+            let batch = &result[0];
+
+            assert_eq!(
+                batch.schema().field(0).data_type(),
+                &DataType::FixedSizeBinary(16),
+                "{data_file_path}"
+            );
+            assert_eq!(
+                batch.schema().field(1).data_type(),
+                &DataType::LargeBinary,
+                "{data_file_path}"
+            );
+
+            let id_col = batch.column(0).as_fixed_size_binary();
+            let payload_col = batch.column(1).as_binary::<i64>();
+            for (i, id) in ids.iter().enumerate() {
+                assert_eq!(id_col.value(i), id.as_slice(), "{data_file_path}");
+            }
+            assert_eq!(
+                payload_col.iter().collect::<Vec<_>>(),
+                payloads,
+                "{data_file_path}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_read_with_nested_struct_predicate() {
+        use arrow_array::{Int64Array, StructArray};
+
+        use crate::arrow::schema_to_arrow_schema;
+        use crate::spec::StructType;
+
+        let schema = Arc::new(
+            Schema::builder()
+                .with_schema_id(1)
+                .with_fields(vec![
+                    NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+                    NestedField::optional(
+                        2,
+                        "address",
+                        Type::Struct(StructType::new(vec![
+                            NestedField::optional(
+                                3,
+                                "country",
+                                Type::Primitive(PrimitiveType::String),
+                            )
+                            .into(),
+                            NestedField::optional(
+                                4,
+                                "city",
+                                Type::Primitive(PrimitiveType::String),
+                            )
+                            .into(),
+                        ])),
+                    )
+                    .into(),
+                ])
+                .build()
+                .unwrap(),
+        );
+        let arrow_schema = Arc::new(schema_to_arrow_schema(&schema).unwrap());
+        let DataType::Struct(address_fields) = arrow_schema.field(1).data_type().clone() else {
+            unreachable!()
+        };
+
+        let tmp_dir = TempDir::new().unwrap();
+        let table_location = tmp_dir.path().to_str().unwrap().to_string();
+        let file_io = FileIO::from_path(&table_location).unwrap().build().unwrap();
+
+        let address = StructArray::new(
+            address_fields,
+            vec![
+                Arc::new(StringArray::from(vec!["US", "CA", "CA", "CA"])) as ArrayRef,
+                Arc::new(StringArray::from(vec!["NYC", "TOR", "VAN", "MTL"])) as ArrayRef,
+            ],
+            None,
+        );
+        let to_write = RecordBatch::try_new(arrow_schema.clone(), vec![
+            Arc::new(Int64Array::from(vec![1, 2, 3, 4])) as ArrayRef,
+            Arc::new(address) as ArrayRef,
+        ])
+        .unwrap();
+
+        // Two row groups, the second one only has rows in `CA`.
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(2)
+            .build();
+        let file = File::create(format!("{table_location}/1.parquet")).unwrap();
+        let mut writer = ArrowWriter::try_new(file, arrow_schema, Some(props)).unwrap();
+        writer.write(&to_write).unwrap();
+        writer.close().unwrap();
+
+        let predicates = vec![
+            (
+                Reference::new("address.country").equal_to(Datum::string("US")),
+                vec![1],
+            ),
+            (
+                Reference::new("address.city").equal_to(Datum::string("TOR")),
+                vec![2],
+            ),
+            (
+                Reference::new("address.country")
+                    .equal_to(Datum::string("CA"))
+                    .and(Reference::new("id").greater_than(Datum::long(2))),
+                vec![3, 4],
+            ),
+        ];
+
+        let reader = ArrowReaderBuilder::new(file_io)
+            .with_row_group_filtering_enabled(true)
+            .build();
+        for (predicate, expected) in predicates {
+            let tasks = Box::pin(futures::stream::iter(
+                vec![Ok(FileScanTask {
+                    start: 0,
+                    length: 0,
+                    record_count: None,
+                    data_file_path: format!("{table_location}/1.parquet"),
+                    data_file_format: DataFileFormat::Parquet,
+                    schema: schema.clone(),
+                    project_field_ids: vec![1],
+                    predicate: Some(predicate.bind(schema.clone(), true).unwrap()),
+                    deletes: vec![],
+                    partition: None,
+                    partition_spec: None,
+                    name_mapping: None,
+                    case_sensitive: false,
+                    sort_order_id: None,
+                    column_sizes: HashMap::new(),
+                    value_counts: HashMap::new(),
+                    timestamp_zone: None,
+                })]
+                .into_iter(),
+            )) as FileScanTaskStream;
+
+            let result = reader
+                .clone()
+                .read(tasks)
+                .unwrap()
+                .try_collect::<Vec<RecordBatch>>()
+                .await
+                .unwrap();
+            let ids: Vec<i64> = result
+                .iter()
+                .flat_map(|batch| {
+                    batch
+                        .column(0)
+                        .as_primitive::<arrow_array::types::Int64Type>()
+                        .values()
+                        .to_vec()
+                })
+                .collect();
+            assert_eq!(ids, expected, "predicate={predicate}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_row_selection_skips_pages_by_page_index() {
+        use arrow_array::Int64Array;
+
+        use crate::io::FileIOBuilder;
+        use crate::test_utils::fixtures::InstrumentedStorage;
+
+        let schema = Arc::new(
+            Schema::builder()
+                .with_schema_id(1)
+                .with_fields(vec![
+                    NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+                ])
+                .build()
+                .unwrap(),
+        );
+        let arrow_schema = Arc::new(ArrowSchema::new(vec![
+            Field::new("id", DataType::Int64, false).with_metadata(HashMap::from([(
+                PARQUET_FIELD_ID_META_KEY.to_string(),
+                "1".to_string(),
+            )])),
+        ]));
+
+        // A single row group of sorted ids, split in pages of 100 rows.
+        let props = WriterProperties::builder()
+            .set_dictionary_enabled(false)
+            .set_data_page_row_count_limit(100)
+            .set_write_batch_size(100)
+            .build();
+        let batch = RecordBatch::try_new(arrow_schema, vec![Arc::new(
+            Int64Array::from_iter_values(0..10_000),
+        )])
+        .unwrap();
+        let path = "memory://t/1.parquet";
+        let file_io = FileIOBuilder::new("memory").build().unwrap();
+        let mut content = vec![];
+        let mut writer = ArrowWriter::try_new(&mut content, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        file_io
+            .new_output(path)
+            .unwrap()
+            .write(content.into())
+            .await
+            .unwrap();
+
+        let predicate = Reference::new("id")
+            .equal_to(Datum::long(4242))
+            .bind(schema.clone(), true)
+            .unwrap();
+
+        // Reads the file with or without the row selection of the page index, returning the
+        // ids read and the number of bytes read from the file.
+        let read = |row_selection_enabled: bool| {
+            let file_io = file_io.clone();
+            let predicate = predicate.clone();
+            let schema = schema.clone();
+            async move {
+                let mut storage = None;
+                let file_io = file_io.with_wrapped_storage(|inner| {
+                    let instrumented = InstrumentedStorage::new(inner);
+                    storage = Some(instrumented.clone());
+                    Arc::new(instrumented)
+                });
+                let reader = ArrowReaderBuilder::new(file_io)
+                    .with_row_selection_enabled(row_selection_enabled)
+                    .build();
+                let tasks = Box::pin(futures::stream::iter(vec![Ok(FileScanTask {
+                    start: 0,
+                    length: 0,
+                    record_count: None,
+                    data_file_path: path.to_string(),
+                    data_file_format: DataFileFormat::Parquet,
+                    schema,
+                    project_field_ids: vec![1],
+                    predicate: Some(predicate),
+                    deletes: vec![],
+                    partition: None,
+                    partition_spec: None,
+                    name_mapping: None,
+                    case_sensitive: false,
+                    sort_order_id: None,
+                    column_sizes: HashMap::new(),
+                    value_counts: HashMap::new(),
+                    timestamp_zone: None,
+                })])) as FileScanTaskStream;
+                let batches: Vec<RecordBatch> =
+                    reader.read(tasks).unwrap().try_collect().await.unwrap();
+                let ids: Vec<i64> = batches
+                    .iter()
+                    .flat_map(|batch| {
+                        batch
+                            .column(0)
+                            .as_primitive::<arrow_array::types::Int64Type>()
+                            .values()
+                            .to_vec()
+                    })
+                    .collect();
+                (ids, storage.unwrap().bytes_read(path))
+            }
+        };
+
+        let (all_ids, all_bytes_read) = read(false).await;
+        let (selected_ids, selected_bytes_read) = read(true).await;
+        assert_eq!(all_ids, vec![4242]);
+        assert_eq!(selected_ids, vec![4242]);
+        // Of the 80 000 bytes of ids, only the page of 100 rows which may contain the id is
+        // read next to the footer and the page index.
+        assert!(
+            all_bytes_read - selected_bytes_read > 60_000,
+            "{selected_bytes_read} bytes read with the page index, {all_bytes_read} without"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_range_coalescing_merges_adjacent_column_chunks() {
+        use arrow_array::Int64Array;
+
+        use crate::arrow::ArrowFileReader;
+        use crate::io::FileMetadata;
+
+        let arrow_schema = Arc::new(ArrowSchema::new(vec![
+            Field::new("a", DataType::Int64, false),
+            Field::new("b", DataType::Int64, false),
+            Field::new("c", DataType::Int64, false),
+        ]));
+        let batch = RecordBatch::try_new(arrow_schema.clone(), vec![
+            Arc::new(Int64Array::from_iter_values(0..1000)) as ArrayRef,
+            Arc::new(Int64Array::from_iter_values(1000..2000)) as ArrayRef,
+            Arc::new(Int64Array::from_iter_values(2000..3000)) as ArrayRef,
+        ])
+        .unwrap();
+        let mut buffer = vec![];
+        let mut writer = ArrowWriter::try_new(&mut buffer, arrow_schema, None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let bytes = Bytes::from(buffer);
+
+        // Reads the adjacent columns `a` and `b`, returning the values and the number of reads.
+        let read = |range_coalescing_gap| {
+            let bytes = bytes.clone();
+            async move {
+                let ranges = Arc::new(Mutex::new(vec![]));
+                let file_reader = ArrowFileReader::new(
+                    FileMetadata {
+                        size: bytes.len() as u64,
+                    },
+                    Box::new(CountingFileRead {
+                        bytes,
+                        ranges: ranges.clone(),
+                    }),
+                )
+                .with_range_coalescing_gap(range_coalescing_gap);
+                let builder = parquet::arrow::ParquetRecordBatchStreamBuilder::new(file_reader)
+                    .await
+                    .unwrap();
+                let projection = ProjectionMask::roots(builder.parquet_schema(), [0, 1]);
+                let batches: Vec<RecordBatch> = builder
+                    .with_projection(projection)
+                    .build()
+                    .unwrap()
+                    .try_collect()
+                    .await
+                    .unwrap();
+                let reads = ranges.lock().unwrap().len();
+                (batches, reads)
+            }
+        };
+
+        let (batches, reads) = read(None).await;
+        let (coalesced_batches, coalesced_reads) = read(Some(1024)).await;
+        assert_eq!(coalesced_batches, batches);
+        assert_eq!(coalesced_batches[0].num_columns(), 2);
+        // The column chunks of `a` and `b` are read at once instead of one after the other.
+        assert_eq!(coalesced_reads, reads - 1);
+    }
+
+    #[tokio::test]
+    async fn test_read_int96_timestamps() {
+        use parquet::data_type::{Int32Type, Int96, Int96Type};
+        use parquet::file::writer::SerializedFileWriter;
+
+        fn int96(micros: i64) -> Int96 {
+            const MICROS_PER_DAY: i64 = 86_400_000_000;
+            const JULIAN_DAY_OF_EPOCH: i64 = 2_440_588;
+            let nanos_of_day = micros.rem_euclid(MICROS_PER_DAY) * 1000;
+            let julian_day = micros.div_euclid(MICROS_PER_DAY) + JULIAN_DAY_OF_EPOCH;
+            let mut value = Int96::new();
+            value.set_data(
+                nanos_of_day as u32,
+                (nanos_of_day >> 32) as u32,
+                julian_day as u32,
+            );
+            value
+        }
+
+        let schema = Arc::new(
+            Schema::builder()
+                .with_schema_id(1)
+                .with_fields(vec![
+                    NestedField::required(1, "id", Type::Primitive(PrimitiveType::Int)).into(),
+                    NestedField::optional(2, "ts", Type::Primitive(PrimitiveType::Timestamp))
+                        .into(),
+                    NestedField::optional(3, "ts_tz", Type::Primitive(PrimitiveType::Timestamptz))
+                        .into(),
+                ])
+                .build()
+                .unwrap(),
+        );
+
+        // 2020-09-13T12:26:40.123456 and 2300-01-01T12:00:00.654321, the latter is out of the
+        // range of nanosecond timestamps.
+        let micros = [1_600_000_000_123_456_i64, 10_413_835_200_654_321];
+        let values: Vec<Int96> = micros.iter().map(|m| int96(*m)).collect();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let table_location = tmp_dir.path().to_str().unwrap().to_string();
+        let file_io = FileIO::from_path(&table_location).unwrap().build().unwrap();
+        let parquet_schema = parse_message_type(
+            "message schema {
+                required int32 id = 1;
+                optional int96 ts = 2;
+                optional int96 ts_tz = 3;
+            }",
+        )
+        .unwrap();
+        let file = File::create(format!("{table_location}/1.parquet")).unwrap();
+        let mut writer = SerializedFileWriter::new(
+            file,
+            Arc::new(parquet_schema),
+            Arc::new(WriterProperties::builder().build()),
+        )
+        .unwrap();
+        let mut row_group = writer.next_row_group().unwrap();
+        let mut column = row_group.next_column().unwrap().unwrap();
+        column
+            .typed::<Int32Type>()
+            .write_batch(&[1, 2], None, None)
+            .unwrap();
+        column.close().unwrap();
+        while let Some(mut column) = row_group.next_column().unwrap() {
+            column
+                .typed::<Int96Type>()
+                .write_batch(&values, Some(&[1, 1]), None)
+                .unwrap();
+            column.close().unwrap();
+        }
+        row_group.close().unwrap();
+        writer.close().unwrap();
+
+        let reader = ArrowReaderBuilder::new(file_io).build();
+        let tasks = Box::pin(futures::stream::iter(
+            vec![Ok(FileScanTask {
+                start: 0,
+                length: 0,
+                record_count: None,
+                data_file_path: format!("{table_location}/1.parquet"),
+                data_file_format: DataFileFormat::Parquet,
+                schema: schema.clone(),
+                project_field_ids: vec![1, 2, 3],
+                predicate: None,
+                deletes: vec![],
+                partition: None,
+                partition_spec: None,
+                name_mapping: None,
+                case_sensitive: false,
+                sort_order_id: None,
+                column_sizes: HashMap::new(),
+                value_counts: HashMap::new(),
+                timestamp_zone: None,
+            })]
+            .into_iter(),
+        )) as FileScanTaskStream;
+        let result = reader
+            .read(tasks)
+            .unwrap()
+            .try_collect::<Vec<RecordBatch>>()
+            .await
+            .unwrap();
+
+        assert_eq!(result.len(), 1);
+        let batch = &result[0];
+        assert_eq!(
+            batch.column(1).data_type(),
+            &DataType::Timestamp(TimeUnit::Microsecond, None)
+        );
+        assert_eq!(
+            batch.column(2).data_type(),
+            &DataType::Timestamp(TimeUnit::Microsecond, Some("+00:00".into()))
+        );
+        for column in [batch.column(1), batch.column(2)] {
+            let timestamps = column.as_primitive::<arrow_array::types::TimestampMicrosecondType>();
+            assert_eq!(timestamps.values().to_vec(), micros.to_vec());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_read_map_of_structs() {
+        use arrow_array::{Array, Int32Array, Int64Array, MapArray, StructArray};
+        use arrow_buffer::OffsetBuffer;
+
+        use crate::arrow::schema_to_arrow_schema;
+        use crate::spec::{MapType, StructType};
+
+        // The schema of the table, with the field `c` added to the map values after the file
+        // was written when `evolved` is set.
+        let map_schema = |evolved: bool| {
+            let mut value_fields = vec![
+                NestedField::optional(5, "a", Type::Primitive(PrimitiveType::Int)).into(),
+                NestedField::optional(6, "b", Type::Primitive(PrimitiveType::String)).into(),
+            ];
+            if evolved {
+                value_fields.push(
+                    NestedField::optional(7, "c", Type::Primitive(PrimitiveType::Long)).into(),
+                );
+            }
+            Arc::new(
+                Schema::builder()
+                    .with_schema_id(1)
+                    .with_fields(vec![
+                        NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+                        NestedField::optional(
+                            2,
+                            "m",
+                            Type::Map(MapType::new(
+                                NestedField::map_key_element(
+                                    3,
+                                    Type::Primitive(PrimitiveType::String),
+                                )
+                                .into(),
+                                NestedField::map_value_element(
+                                    4,
+                                    Type::Struct(StructType::new(value_fields)),
+                                    false,
+                                )
+                                .into(),
+                            )),
+                        )
+                        .into(),
+                    ])
+                    .build()
+                    .unwrap(),
+            )
+        };
+        let schema = map_schema(false);
+        let arrow_schema = Arc::new(schema_to_arrow_schema(&schema).unwrap());
+        let DataType::Map(entries_field, sorted) = arrow_schema.field(1).data_type().clone() else {
+            unreachable!()
+        };
+        let DataType::Struct(entry_fields) = entries_field.data_type().clone() else {
+            unreachable!()
+        };
+        let DataType::Struct(value_fields) = entry_fields[1].data_type().clone() else {
+            unreachable!()
+        };
+
+        let values = StructArray::new(
+            value_fields,
+            vec![
+                Arc::new(Int32Array::from(vec![Some(1), None, Some(3)])) as ArrayRef,
+                Arc::new(StringArray::from(vec![Some("x"), Some("y"), None])) as ArrayRef,
+            ],
+            None,
+        );
+        let entries = StructArray::new(
+            entry_fields,
+            vec![
+                Arc::new(StringArray::from(vec!["k1", "k2", "k3"])) as ArrayRef,
+                Arc::new(values) as ArrayRef,
+            ],
+            None,
+        );
+        let map = MapArray::new(
+            entries_field,
+            OffsetBuffer::new(vec![0, 2, 2, 3].into()),
+            entries,
+            Some(vec![true, false, true].into()),
+            sorted,
+        );
+        let batch = RecordBatch::try_new(arrow_schema.clone(), vec![
+            Arc::new(Int64Array::from(vec![1, 2, 3])) as ArrayRef,
+            Arc::new(map) as ArrayRef,
+        ])
+        .unwrap();
+
+        let result = read_batch_with_schema(batch.clone(), schema).await.unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].schema(), arrow_schema);
+        assert_eq!(result[0], batch);
+
+        let map = result[0].column(1).as_map();
+        assert!(map.is_null(1));
+        assert_eq!(map.value_offsets(), &[0, 2, 2, 3]);
+        let keys: Vec<_> = map.keys().as_string::<i32>().iter().collect();
+        assert_eq!(keys, vec![Some("k1"), Some("k2"), Some("k3")]);
+        let values = map.values().as_struct();
+        let a: Vec<_> = values
+            .column(0)
+            .as_primitive::<arrow_array::types::Int32Type>()
+            .iter()
+            .collect();
+        assert_eq!(a, vec![Some(1), None, Some(3)]);
+        let b: Vec<_> = values.column(1).as_string::<i32>().iter().collect();
+        assert_eq!(b, vec![Some("x"), Some("y"), None]);
+
+        // The field added to the map values is read as nulls, next to the values of the file.
+        let evolved_schema = map_schema(true);
+        let result = read_batch_with_schema(batch, evolved_schema.clone())
+            .await
+            .unwrap();
+        assert_eq!(
+            result[0].schema(),
+            Arc::new(schema_to_arrow_schema(&evolved_schema).unwrap())
+        );
+        let map = result[0].column(1).as_map();
+        assert!(map.is_null(1));
+        assert_eq!(map.value_offsets(), &[0, 2, 2, 3]);
+        let values = map.values().as_struct();
+        assert_eq!(values.num_columns(), 3);
+        let b: Vec<_> = values.column(1).as_string::<i32>().iter().collect();
+        assert_eq!(b, vec![Some("x"), Some("y"), None]);
+        assert_eq!(values.column(2).null_count(), 3);
+    }
+
+    /// Writes `batch` to a Parquet file and reads it back with the Iceberg `schema`.
+    async fn read_batch_with_schema(
+        batch: RecordBatch,
+        schema: SchemaRef,
+    ) -> crate::Result<Vec<RecordBatch>> {
+        let tmp_dir = TempDir::new().unwrap();
+        let table_location = tmp_dir.path().to_str().unwrap().to_string();
+        let file_io = FileIO::from_path(&table_location).unwrap().build().unwrap();
+
+        let file = File::create(format!("{table_location}/1.parquet")).unwrap();
+        let mut writer = ArrowWriter::try_new(file, batch.schema(), None).unwrap();
+        writer.write(&batch).expect("Writing batch");
+        writer.close().unwrap();
+
+        let project_field_ids = schema
+            .as_struct()
+            .fields()
+            .iter()
+            .map(|field| field.id)
+            .collect();
+        let tasks = Box::pin(futures::stream::iter(vec![Ok(FileScanTask {
+            start: 0,
+            length: 0,
+            record_count: None,
+            data_file_path: format!("{table_location}/1.parquet"),
+            data_file_format: DataFileFormat::Parquet,
+            schema,
+            project_field_ids,
+            predicate: None,
+            deletes: vec![],
+            partition: None,
+            partition_spec: None,
+            name_mapping: None,
+            case_sensitive: false,
+            sort_order_id: None,
+            column_sizes: HashMap::new(),
+            value_counts: HashMap::new(),
+            timestamp_zone: None,
+        })])) as FileScanTaskStream;
+
+        ArrowReaderBuilder::new(file_io)
+            .build()
+            .read(tasks)?
+            .try_collect()
+            .await
+    }
+
+    fn field_with_id(name: &str, data_type: DataType, nullable: bool, id: i32) -> Field {
+        Field::new(name, data_type, nullable).with_metadata(HashMap::from([(
+            PARQUET_FIELD_ID_META_KEY.to_string(),
+            id.to_string(),
+        )]))
+    }
+
+    #[tokio::test]
+    async fn test_read_with_nullability_mismatch() {
+        use arrow_array::{Int32Array, StructArray};
+        use arrow_schema::Fields;
+
+        let schema = Arc::new(
+            Schema::builder()
+                .with_schema_id(1)
+                .with_fields(vec![
+                    NestedField::optional(1, "a", Type::Primitive(PrimitiveType::Int)).into(),
+                    NestedField::required(2, "b", Type::Primitive(PrimitiveType::Int)).into(),
+                    NestedField::optional(
+                        3,
+                        "s",
+                        Type::Struct(crate::spec::StructType::new(vec![
+                            NestedField::optional(4, "x", Type::Primitive(PrimitiveType::Int))
+                                .into(),
+                        ])),
+                    )
+                    .into(),
+                ])
+                .build()
+                .unwrap(),
+        );
+
+        // The Parquet file marks `a` and `s` required and `b` optional.
+        let struct_fields = Fields::from(vec![field_with_id("x", DataType::Int32, false, 4)]);
+        let arrow_schema = Arc::new(ArrowSchema::new(vec![
+            field_with_id("a", DataType::Int32, false, 1),
+            field_with_id("b", DataType::Int32, true, 2),
+            field_with_id("s", DataType::Struct(struct_fields.clone()), false, 3),
+        ]));
+        let batch = RecordBatch::try_new(arrow_schema, vec![
+            Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef,
+            Arc::new(Int32Array::from(vec![3, 4])) as ArrayRef,
+            Arc::new(StructArray::new(
+                struct_fields,
+                vec![Arc::new(Int32Array::from(vec![5, 6])) as ArrayRef],
+                None,
+            )) as ArrayRef,
+        ])
+        .unwrap();
+
+        let result = read_batch_with_schema(batch, schema).await.unwrap();
+        assert_eq!(result.len(), 1);
+        let batch = &result[0];
+        let batch_schema = batch.schema();
+        assert!(batch_schema.field(0).is_nullable());
+        assert!(!batch_schema.field(1).is_nullable());
+        assert!(batch_schema.field(2).is_nullable());
+        let DataType::Struct(fields) = batch_schema.field(2).data_type() else {
+            panic!("s should be a struct");
+        };
+        assert!(fields[0].is_nullable());
+
+        let values = |index: usize| {
+            batch
+                .column(index)
+                .as_primitive::<arrow_array::types::Int32Type>()
+                .values()
+                .to_vec()
+        };
+        assert_eq!(values(0), vec![1, 2]);
+        assert_eq!(values(1), vec![3, 4]);
+        assert_eq!(
+            batch
+                .column(2)
+                .as_struct()
+                .column(0)
+                .as_primitive::<arrow_array::types::Int32Type>()
+                .values()
+                .to_vec(),
+            vec![5, 6]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_read_null_in_required_column() {
+        use arrow_array::Int32Array;
+
+        let schema = Arc::new(
+            Schema::builder()
+                .with_schema_id(1)
+                .with_fields(vec![
+                    NestedField::required(1, "a", Type::Primitive(PrimitiveType::Int)).into(),
+                ])
+                .build()
+                .unwrap(),
+        );
+        let arrow_schema = Arc::new(ArrowSchema::new(vec![field_with_id(
+            "a",
+            DataType::Int32,
+            true,
+            1,
+        )]));
+        let batch = RecordBatch::try_new(arrow_schema, vec![Arc::new(Int32Array::from(vec![
+            Some(1),
+            None,
+        ])) as ArrayRef])
+        .unwrap();
+
+        let err = read_batch_with_schema(batch, schema).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::DataInvalid);
+        assert!(err.to_string().contains("Required field a"), "{err}");
+    }
 }
@@ -216,15 +216,17 @@ impl RecordBatchPartitionSplitter {
 mod tests {
     use std::sync::Arc;
 
-    use arrow_array::{Int32Array, RecordBatch, StringArray};
+    use arrow_array::{
+        Date32Array, Int32Array, RecordBatch, StringArray, TimestampMicrosecondArray,
+    };
     use arrow_schema::DataType;
     use parquet::arrow::PARQUET_FIELD_ID_META_KEY;
 
     use super::*;
     use crate::arrow::schema_to_arrow_schema;
     use crate::spec::{
-        NestedField, PartitionSpecBuilder, PrimitiveLiteral, Schema, Struct, Transform, Type,
-        UnboundPartitionField,
+        NestedField, PartitionSpecBuilder, PrimitiveLiteral, PrimitiveType, Schema, Struct,
+        Transform, Type, UnboundPartitionField,
     };
 
     #[test]
@@ -338,6 +340,78 @@ mod tests {
         ]);
     }
 
+    #[test]
+    fn test_record_batch_partition_split_null_source_values() {
+        let schema = Arc::new(
+            Schema::builder()
+                .with_fields(vec![
+                    NestedField::optional(1, "id", Type::Primitive(PrimitiveType::Int)).into(),
+                    NestedField::optional(2, "name", Type::Primitive(PrimitiveType::String)).into(),
+                    NestedField::optional(3, "ts", Type::Primitive(PrimitiveType::Timestamp))
+                        .into(),
+                    NestedField::optional(4, "date", Type::Primitive(PrimitiveType::Date)).into(),
+                ])
+                .build()
+                .unwrap(),
+        );
+        let partition_spec = Arc::new(
+            PartitionSpecBuilder::new(schema.clone())
+                .add_partition_field("id", "id_identity", Transform::Identity)
+                .unwrap()
+                .add_partition_field("id", "id_bucket", Transform::Bucket(4))
+                .unwrap()
+                .add_partition_field("id", "id_void", Transform::Void)
+                .unwrap()
+                .add_partition_field("name", "name_trunc", Transform::Truncate(2))
+                .unwrap()
+                .add_partition_field("ts", "ts_hour", Transform::Hour)
+                .unwrap()
+                .add_partition_field("date", "date_month", Transform::Month)
+                .unwrap()
+                .build()
+                .unwrap(),
+        );
+        let partition_splitter = RecordBatchPartitionSplitter::try_new_with_computed_values(
+            schema.clone(),
+            partition_spec,
+        )
+        .unwrap();
+
+        let arrow_schema = Arc::new(schema_to_arrow_schema(&schema).unwrap());
+        let batch = RecordBatch::try_new(arrow_schema.clone(), vec![
+            Arc::new(Int32Array::from(vec![None, Some(1), None])),
+            Arc::new(StringArray::from(vec![None, Some("abc"), None])),
+            Arc::new(TimestampMicrosecondArray::from(vec![None, Some(0), None])),
+            Arc::new(Date32Array::from(vec![None, Some(0), None])),
+        ])
+        .unwrap();
+
+        let mut partitioned_batches = partition_splitter.split(&batch).unwrap();
+        partitioned_batches.sort_by_key(|(_, batch)| batch.num_rows());
+        assert_eq!(partitioned_batches.len(), 2);
+
+        // Every transform of a null source value is null.
+        let (null_partition_key, null_batch) = &partitioned_batches[1];
+        assert_eq!(
+            null_partition_key.data(),
+            &Struct::from_iter((0..6).map(|_| None))
+        );
+        assert_eq!(null_batch.num_rows(), 2);
+        assert_eq!(null_batch.column(0).null_count(), 2);
+
+        let (partition_key, batch) = &partitioned_batches[0];
+        assert_eq!(partition_key.data().fields()[0], Some(Literal::int(1)));
+        assert_eq!(partition_key.data().fields()[2], None);
+        assert!(
+            partition_key
+                .data()
+                .iter()
+                .enumerate()
+                .all(|(index, value)| (index == 2) == value.is_none())
+        );
+        assert_eq!(batch.num_rows(), 1);
+    }
+
     #[test]
     fn test_record_batch_partition_split_with_partition_column() {
         use arrow_array::StructArray;
@@ -18,8 +18,10 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use arrow_array::cast::AsArray;
 use arrow_array::{
-    Array as ArrowArray, ArrayRef, Int32Array, RecordBatch, RecordBatchOptions, RunArray,
+    Array as ArrowArray, ArrayRef, GenericListArray, Int32Array, MapArray, OffsetSizeTrait,
+    RecordBatch, RecordBatchOptions, RunArray, StructArray, new_null_array,
 };
 use arrow_cast::cast;
 use arrow_schema::{
@@ -31,7 +33,8 @@ use crate::arrow::value::{create_primitive_array_repeated, create_primitive_arra
 use crate::arrow::{datum_to_arrow_type_with_ree, schema_to_arrow_schema};
 use crate::metadata_columns::get_metadata_field;
 use crate::spec::{
-    Datum, Literal, PartitionSpec, PrimitiveLiteral, Schema as IcebergSchema, Struct, Transform,
+    Datum, Literal, NestedField, PartitionSpec, PrimitiveLiteral, Schema as IcebergSchema, Struct,
+    Transform,
 };
 use crate::{Error, ErrorKind, Result};
 
@@ -186,13 +189,15 @@ enum SchemaComparison {
 
 /// Builder for RecordBatchTransformer to improve ergonomics when constructing with optional parameters.
 ///
-/// Constant fields are pre-computed for both virtual/metadata fields (like _file) and
+/// Constant values are pre-computed for both virtual/metadata fields (like _file) and
 /// identity-partitioned fields to avoid duplicate work during batch processing.
 #[derive(Debug)]
 pub(crate) struct RecordBatchTransformerBuilder {
     snapshot_schema: Arc<IcebergSchema>,
     projected_iceberg_field_ids: Vec<i32>,
     constant_fields: HashMap<i32, Datum>,
+    partition_constants: HashMap<i32, Datum>,
+    timestamp_zone: Option<Arc<str>>,
 }
 
 impl RecordBatchTransformerBuilder {
@@ -204,6 +209,8 @@ impl RecordBatchTransformerBuilder {
             snapshot_schema,
             projected_iceberg_field_ids: projected_iceberg_field_ids.to_vec(),
             constant_fields: HashMap::new(),
+            partition_constants: HashMap::new(),
+            timestamp_zone: None,
         }
     }
 
@@ -222,29 +229,34 @@ impl RecordBatchTransformerBuilder {
     ///
     /// Both partition_spec and partition_data must be provided together since the spec defines
     /// which fields are identity-partitioned, and the data provides their constant values.
-    /// This method computes the partition constants and merges them into constant_fields.
+    /// The partition constants are used for identity-partitioned fields that are not present
+    /// in the data file.
     pub(crate) fn with_partition(
         mut self,
         partition_spec: Arc<PartitionSpec>,
         partition_data: Struct,
     ) -> Result<Self> {
         // Compute partition constants for identity-transformed fields (already returns Datum)
-        let partition_constants =
+        self.partition_constants =
             constants_map(&partition_spec, &partition_data, &self.snapshot_schema)?;
 
-        // Add partition constants to constant_fields
-        for (field_id, datum) in partition_constants {
-            self.constant_fields.insert(field_id, datum);
-        }
-
         Ok(self)
     }
 
+    /// Set the zone the values of timestamp columns stored without UTC adjustment are local
+    /// times in, converting them to UTC when they are read as `timestamptz` columns.
+    pub(crate) fn with_timestamp_zone(mut self, timestamp_zone: Option<&str>) -> Self {
+        self.timestamp_zone = timestamp_zone.map(Arc::from);
+        self
+    }
+
     pub(crate) fn build(self) -> RecordBatchTransformer {
         RecordBatchTransformer {
             snapshot_schema: self.snapshot_schema,
             projected_iceberg_field_ids: self.projected_iceberg_field_ids,
             constant_fields: self.constant_fields,
+            partition_constants: self.partition_constants,
+            timestamp_zone: self.timestamp_zone,
             batch_transform: None,
         }
     }
@@ -285,10 +297,18 @@ pub(crate) struct RecordBatchTransformer {
     snapshot_schema: Arc<IcebergSchema>,
     projected_iceberg_field_ids: Vec<i32>,
     // Pre-computed constant field information: field_id -> Datum
-    // Includes both virtual/metadata fields (like _file) and identity-partitioned fields
+    // for virtual/metadata fields (like _file)
     // Datum holds both the Iceberg type and the value
     constant_fields: HashMap<i32, Datum>,
 
+    // Values of identity-partitioned fields taken from the partition metadata,
+    // used when the field is not present in the data file
+    partition_constants: HashMap<i32, Datum>,
+
+    // The zone the values of timestamp columns stored without UTC adjustment are local
+    // times in, UTC if none
+    timestamp_zone: Option<Arc<str>>,
+
     // BatchTransform gets lazily constructed based on the schema of
     // the first RecordBatch we receive from the file
     batch_transform: Option<BatchTransform>,
@@ -308,11 +328,9 @@ impl RecordBatchTransformer {
                 let options = RecordBatchOptions::default()
                     .with_match_field_names(false)
                     .with_row_count(Some(record_batch.num_rows()));
-                RecordBatch::try_new_with_options(
-                    Arc::clone(target_schema),
-                    self.transform_columns(record_batch.columns(), operations)?,
-                    &options,
-                )?
+                let columns = self.transform_columns(record_batch.columns(), operations)?;
+                Self::check_required_columns(target_schema, &columns)?;
+                RecordBatch::try_new_with_options(Arc::clone(target_schema), columns, &options)?
             }
             Some(BatchTransform::ModifySchema { target_schema }) => {
                 let options = RecordBatchOptions::default()
@@ -330,6 +348,7 @@ impl RecordBatchTransformer {
                     self.snapshot_schema.as_ref(),
                     &self.projected_iceberg_field_ids,
                     &self.constant_fields,
+                    &self.partition_constants,
                 )?);
 
                 self.process_record_batch(record_batch)?
@@ -349,6 +368,7 @@ impl RecordBatchTransformer {
         snapshot_schema: &IcebergSchema,
         projected_iceberg_field_ids: &[i32],
         constant_fields: &HashMap<i32, Datum>,
+        partition_constants: &HashMap<i32, Datum>,
     ) -> Result<BatchTransform> {
         let mapped_unprojected_arrow_schema = Arc::new(schema_to_arrow_schema(snapshot_schema)?);
         let field_id_to_mapped_schema_map =
@@ -417,6 +437,7 @@ impl RecordBatchTransformer {
                     projected_iceberg_field_ids,
                     field_id_to_mapped_schema_map,
                     constant_fields,
+                    partition_constants,
                 )?,
                 target_schema,
             }),
@@ -474,6 +495,7 @@ impl RecordBatchTransformer {
         projected_iceberg_field_ids: &[i32],
         field_id_to_mapped_schema_map: HashMap<i32, (FieldRef, usize)>,
         constant_fields: &HashMap<i32, Datum>,
+        partition_constants: &HashMap<i32, Datum>,
     ) -> Result<Vec<ColumnSource>> {
         let field_id_to_source_schema_map =
             Self::build_field_id_to_arrow_schema_map(source_schema)?;
@@ -481,10 +503,8 @@ impl RecordBatchTransformer {
         projected_iceberg_field_ids
             .iter()
             .map(|field_id| {
-                // Check if this is a constant field (metadata/virtual or identity-partitioned)
-                // Constant fields always use their pre-computed constant values, regardless of whether
-                // they exist in the Parquet file. This is per Iceberg spec rule #1: partition metadata
-                // is authoritative and should be preferred over file data.
+                // Check if this is a constant (metadata/virtual) field. Constant fields always
+                // use their pre-computed constant values.
                 if let Some(datum) = constant_fields.get(field_id) {
                     let arrow_type = datum_to_arrow_type_with_ree(datum);
                     return Ok(ColumnSource::Add {
@@ -513,10 +533,6 @@ impl RecordBatchTransformer {
                 // 2. Use name mapping
                 // 3. Use initial_default
                 // 4. Return null
-                //
-                // Why check partition constants before Parquet field IDs (Java: BaseParquetReaders.java:299):
-                // In add_files scenarios, partition columns may exist in BOTH Parquet AND partition metadata.
-                // Partition metadata is authoritative - it defines which partition this file belongs to.
 
                 // Field ID resolution now happens in ArrowReader via:
                 // 1. Embedded field IDs (ParquetSchemaUtil.hasIds() = true) - trust them
@@ -541,24 +557,24 @@ impl RecordBatchTransformer {
                 );
 
                 // Apply spec's fallback steps for "not present" fields.
-                // Rule #1 (constants) is handled at the beginning of this function
                 let column_source = if let Some(source) = field_by_id {
                     source
+                } else if let Some(datum) = partition_constants.get(field_id) {
+                    // Rule #1: the identity-partitioned column was elided from the data file,
+                    // so it is reconstructed from the partition metadata. It keeps the type of
+                    // the table schema, so that the batches of files storing the column and of
+                    // files eliding it have the same schema.
+                    ColumnSource::Add {
+                        value: Some(datum.literal().clone()),
+                        target_type: target_type.clone(),
+                    }
                 } else {
                     // Rules #2, #3 and #4:
                     // Rule #2 (name mapping) was already applied in reader.rs if needed.
                     // If field_id is still not found, the column doesn't exist in the Parquet file.
                     // Fall through to rule #3 (initial_default) or rule #4 (null).
-                    let default_value = iceberg_field.initial_default.as_ref().and_then(|lit| {
-                        if let Literal::Primitive(prim) = lit {
-                            Some(prim.clone())
-                        } else {
-                            None
-                        }
-                    });
-
                     ColumnSource::Add {
-                        value: default_value,
+                        value: Self::missing_field_value(iceberg_field)?,
                         target_type: target_type.clone(),
                     }
                 };
@@ -568,6 +584,30 @@ impl RecordBatchTransformer {
             .collect()
     }
 
+    /// Returns the value of a field that is not present in a data file: its `initial-default`,
+    /// or null for optional fields without one. The `write-default` only applies to new rows
+    /// and is not used here.
+    fn missing_field_value(iceberg_field: &NestedField) -> Result<Option<PrimitiveLiteral>> {
+        match &iceberg_field.initial_default {
+            Some(Literal::Primitive(prim)) => Ok(Some(prim.clone())),
+            Some(_) => Err(Error::new(
+                ErrorKind::FeatureUnsupported,
+                format!(
+                    "Initial default of non-primitive field {} is not supported",
+                    iceberg_field.name
+                ),
+            )),
+            None if iceberg_field.required => Err(Error::new(
+                ErrorKind::DataInvalid,
+                format!(
+                    "Required field {} (id {}) is missing from the data file and has no initial default",
+                    iceberg_field.name, iceberg_field.id
+                ),
+            )),
+            None => Ok(None),
+        }
+    }
+
     fn build_field_id_to_arrow_schema_map(
         source_schema: &SchemaRef,
     ) -> Result<HashMap<i32, (FieldRef, usize)>> {
@@ -591,6 +631,26 @@ impl RecordBatchTransformer {
         Ok(field_id_to_source_schema)
     }
 
+    /// Checks that the columns of the fields that the Iceberg schema requires have no nulls.
+    ///
+    /// The nullability of the Iceberg schema is trusted over the one of the data file, so a
+    /// column the data file marks optional is read as required, and only fails once it
+    /// actually contains a null.
+    fn check_required_columns(target_schema: &ArrowSchemaRef, columns: &[ArrayRef]) -> Result<()> {
+        for (field, column) in target_schema.fields().iter().zip(columns) {
+            if !field.is_nullable() && column.null_count() > 0 {
+                return Err(Error::new(
+                    ErrorKind::DataInvalid,
+                    format!(
+                        "Required field {} contains null values in the data file",
+                        field.name()
+                    ),
+                ));
+            }
+        }
+        Ok(())
+    }
+
     fn transform_columns(
         &self,
         columns: &[Arc<dyn ArrowArray>],
@@ -610,7 +670,7 @@ impl RecordBatchTransformer {
                     ColumnSource::Promote {
                         target_type,
                         source_index,
-                    } => cast(&*columns[*source_index], target_type)?,
+                    } => self.promote_column(&columns[*source_index], target_type)?,
 
                     ColumnSource::Add { target_type, value } => {
                         Self::create_column(target_type, value, num_rows)?
@@ -620,6 +680,117 @@ impl RecordBatchTransformer {
             .collect()
     }
 
+    /// Promotes a column to the target type. The children of structs are matched by field id,
+    /// and children missing from the file, such as fields added to the struct after the file
+    /// was written, are filled with their initial default or nulls. The entries of maps and
+    /// the elements of lists are promoted the same way, so structs nested in them evolve too.
+    fn promote_column(&self, column: &ArrayRef, target_type: &DataType) -> Result<ArrayRef> {
+        match target_type {
+            DataType::Map(target_entries, sorted) => {
+                if let Some(source) = column.as_map_opt() {
+                    let entries: ArrayRef = Arc::new(source.entries().clone());
+                    let entries = self.promote_column(&entries, target_entries.data_type())?;
+                    return Ok(Arc::new(MapArray::try_new(
+                        target_entries.clone(),
+                        source.offsets().clone(),
+                        entries.as_struct().clone(),
+                        source.nulls().cloned(),
+                        *sorted,
+                    )?));
+                }
+            }
+            DataType::List(target_element) => {
+                if let Some(source) = column.as_list_opt::<i32>() {
+                    return self.promote_list(source, target_element);
+                }
+            }
+            DataType::LargeList(target_element) => {
+                if let Some(source) = column.as_list_opt::<i64>() {
+                    return self.promote_list(source, target_element);
+                }
+            }
+            _ => {}
+        }
+        if let (
+            DataType::Timestamp(_, Some(_)),
+            DataType::Timestamp(source_unit, None),
+            Some(timestamp_zone),
+        ) = (target_type, column.data_type(), &self.timestamp_zone)
+        {
+            // Casting to a zone reads the values as local times in it, casting between zones
+            // keeps the instants.
+            let instants = cast(
+                column,
+                &DataType::Timestamp(*source_unit, Some(timestamp_zone.clone())),
+            )?;
+            return Ok(cast(&instants, target_type)?);
+        }
+        let (DataType::Struct(target_fields), Some(source)) = (target_type, column.as_struct_opt())
+        else {
+            return Ok(cast(column, target_type)?);
+        };
+
+        let field_id = |field: &Field| field.metadata().get(PARQUET_FIELD_ID_META_KEY).cloned();
+        let source_indices: HashMap<String, usize> = source
+            .fields()
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, field)| field_id(field).map(|id| (id, idx)))
+            .collect();
+
+        let children = target_fields
+            .iter()
+            .map(|target_field| {
+                let source_index = match field_id(target_field) {
+                    Some(id) => source_indices.get(&id).copied(),
+                    None => source
+                        .column_names()
+                        .iter()
+                        .position(|name| name == target_field.name()),
+                };
+                if let Some(idx) = source_index {
+                    return self.promote_column(source.column(idx), target_field.data_type());
+                }
+                let default_value = match field_id(target_field)
+                    .and_then(|id| id.parse().ok())
+                    .and_then(|id| self.snapshot_schema.field_by_id(id))
+                {
+                    Some(iceberg_field) => Self::missing_field_value(iceberg_field)?,
+                    None => None,
+                };
+                match default_value {
+                    Some(value) => create_primitive_array_repeated(
+                        target_field.data_type(),
+                        &Some(value),
+                        source.len(),
+                    ),
+                    None => Ok(new_null_array(target_field.data_type(), source.len())),
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Arc::new(StructArray::try_new(
+            target_fields.clone(),
+            children,
+            source.nulls().cloned(),
+        )?))
+    }
+
+    /// Promotes the elements of a list column to the type of `target_element`.
+    fn promote_list<O: OffsetSizeTrait>(
+        &self,
+        source: &GenericListArray<O>,
+        target_element: &FieldRef,
+    ) -> Result<ArrayRef> {
+        let values = self.promote_column(source.values(), target_element.data_type())?;
+        Ok(Arc::new(GenericListArray::<O>::try_new(
+            target_element.clone(),
+            source.offsets().clone(),
+            values,
+            source.nulls().cloned(),
+        )?))
+    }
+
     fn create_column(
         target_type: &DataType,
         prim_lit: &Option<PrimitiveLiteral>,
@@ -921,6 +1092,73 @@ mod test {
         assert!(struct_column.is_null(2));
     }
 
+    #[test]
+    fn schema_evolution_adds_struct_sub_field_with_nulls() {
+        // A field added to an existing struct column after the data file was written
+        // is materialized with null values, while the existing sub-fields are kept.
+        let snapshot_schema = Arc::new(
+            Schema::builder()
+                .with_schema_id(1)
+                .with_fields(vec![
+                    NestedField::optional(
+                        1,
+                        "struct_col",
+                        Type::Struct(crate::spec::StructType::new(vec![
+                            NestedField::optional(2, "a", Type::Primitive(PrimitiveType::Int))
+                                .into(),
+                            NestedField::optional(3, "b", Type::Primitive(PrimitiveType::String))
+                                .into(),
+                        ])),
+                    )
+                    .into(),
+                ])
+                .build()
+                .unwrap(),
+        );
+        let projected_iceberg_field_ids = [1];
+
+        let mut transformer =
+            RecordBatchTransformerBuilder::new(snapshot_schema, &projected_iceberg_field_ids)
+                .build();
+
+        let file_struct_fields =
+            arrow_schema::Fields::from(vec![simple_field("a", DataType::Int32, true, "2")]);
+        let file_schema = Arc::new(ArrowSchema::new(vec![simple_field(
+            "struct_col",
+            DataType::Struct(file_struct_fields.clone()),
+            true,
+            "1",
+        )]));
+        let file_batch =
+            RecordBatch::try_new(file_schema, vec![Arc::new(arrow_array::StructArray::new(
+                file_struct_fields,
+                vec![Arc::new(Int32Array::from(vec![Some(1), None, Some(3)]))],
+                Some(vec![true, true, false].into()),
+            ))])
+            .unwrap();
+
+        let result = transformer.process_record_batch(file_batch).unwrap();
+
+        let struct_column = result
+            .column(0)
+            .as_any()
+            .downcast_ref::<arrow_array::StructArray>()
+            .unwrap();
+        assert_eq!(struct_column.num_columns(), 2);
+        assert!(struct_column.is_valid(0));
+        assert!(struct_column.is_null(2));
+
+        let a_column = struct_column
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap();
+        assert_eq!(a_column.value(0), 1);
+        assert!(a_column.is_null(1));
+        assert_eq!(struct_column.column(1).data_type(), &DataType::Utf8);
+        assert_eq!(struct_column.column(1).null_count(), 3);
+    }
+
     pub fn source_record_batch() -> RecordBatch {
         RecordBatch::try_new(
             arrow_schema_promotion_addition_and_renaming_required(),
@@ -1361,7 +1599,7 @@ mod test {
         assert_eq!(get_int_value(result.column(0).as_ref(), 0), 100);
         assert_eq!(get_int_value(result.column(0).as_ref(), 1), 200);
 
-        // dept column comes from partition metadata (constant) - will be REE
+        // dept column comes from partition metadata
         assert_eq!(
             get_string_value(result.column(1).as_ref(), 0),
             "engineering"
@@ -1577,7 +1815,7 @@ mod test {
         assert_eq!(get_int_value(result.column(0).as_ref(), 0), 100);
         assert_eq!(get_int_value(result.column(0).as_ref(), 1), 200);
 
-        // Rule #1: dept from partition metadata (identity transform) - will be REE
+        // Rule #1: dept from partition metadata (identity transform)
         assert_eq!(
             get_string_value(result.column(1).as_ref(), 0),
             "engineering"
@@ -620,6 +620,20 @@ pub fn arrow_primitive_to_literal(
     )
 }
 
+fn create_fixed_size_binary_array(width: i32, value: &[u8], num_rows: usize) -> Result<ArrayRef> {
+    let array = FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+        std::iter::repeat_n(Some(value), num_rows),
+        width,
+    )
+    .map_err(|e| {
+        Error::new(
+            ErrorKind::DataInvalid,
+            format!("Failed to create FixedSizeBinaryArray with width {width}: {e}"),
+        )
+    })?;
+    Ok(Arc::new(array))
+}
+
 /// Create a single-element array from a primitive literal.
 ///
 /// This is used for creating constant arrays (Run-End Encoded arrays) where we need
@@ -663,6 +677,18 @@ pub(crate) fn create_primitive_array_single_element(
         (DataType::Binary, None) => Ok(Arc::new(BinaryArray::from_opt_vec(vec![
             Option::<&[u8]>::None,
         ]))),
+        (DataType::LargeBinary, Some(PrimitiveLiteral::Binary(v))) => {
+            Ok(Arc::new(LargeBinaryArray::from_vec(vec![v.as_slice()])))
+        }
+        (DataType::LargeBinary, None) => Ok(Arc::new(LargeBinaryArray::from_opt_vec(vec![
+            Option::<&[u8]>::None,
+        ]))),
+        (DataType::FixedSizeBinary(width), Some(PrimitiveLiteral::Binary(v))) => {
+            create_fixed_size_binary_array(*width, v, 1)
+        }
+        (DataType::FixedSizeBinary(width), None) => {
+            Ok(Arc::new(FixedSizeBinaryArray::new_null(*width, 1)))
+        }
         (DataType::Decimal128(precision, scale), Some(PrimitiveLiteral::Int128(v))) => {
             let array = Decimal128Array::from(vec![{ *v }])
                 .with_precision_and_scale(*precision, *scale)
@@ -821,6 +847,19 @@ pub(crate) fn create_primitive_array_repeated(
             let vals: Vec<Option<&[u8]>> = vec![None; num_rows];
             Arc::new(BinaryArray::from_opt_vec(vals))
         }
+        (DataType::LargeBinary, Some(PrimitiveLiteral::Binary(value))) => {
+            Arc::new(LargeBinaryArray::from_vec(vec![value; num_rows]))
+        }
+        (DataType::LargeBinary, None) => {
+            let vals: Vec<Option<&[u8]>> = vec![None; num_rows];
+            Arc::new(LargeBinaryArray::from_opt_vec(vals))
+        }
+        (DataType::FixedSizeBinary(width), Some(PrimitiveLiteral::Binary(value))) => {
+            create_fixed_size_binary_array(*width, value, num_rows)?
+        }
+        (DataType::FixedSizeBinary(width), None) => {
+            Arc::new(FixedSizeBinaryArray::new_null(*width, num_rows))
+        }
         (DataType::Decimal128(precision, scale), Some(PrimitiveLiteral::Int128(value))) => {
             Arc::new(
                 Decimal128Array::from(vec![*value; num_rows])
@@ -1781,4 +1820,36 @@ mod test {
 
         assert_eq!(array.len(), num_rows);
     }
+
+    #[test]
+    fn test_create_fixed_size_binary_array_repeated() {
+        let target_type = DataType::FixedSizeBinary(4);
+
+        let array = create_primitive_array_repeated(
+            &target_type,
+            &Some(PrimitiveLiteral::Binary(vec![1, 2, 3, 4])),
+            3,
+        )
+        .unwrap();
+        assert_eq!(array.data_type(), &target_type);
+        let array = array
+            .as_any()
+            .downcast_ref::<FixedSizeBinaryArray>()
+            .unwrap();
+        assert!(array.iter().all(|v| v == Some([1, 2, 3, 4].as_slice())));
+        assert_eq!(array.len(), 3);
+
+        let array = create_primitive_array_repeated(&target_type, &None, 3).unwrap();
+        assert_eq!(array.data_type(), &target_type);
+        assert_eq!(array.null_count(), 3);
+
+        assert!(
+            create_primitive_array_repeated(
+                &target_type,
+                &Some(PrimitiveLiteral::Binary(vec![1, 2])),
+                3,
+            )
+            .is_err()
+        );
+    }
 }
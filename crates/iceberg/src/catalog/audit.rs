@@ -0,0 +1,391 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::table::Table;
+use crate::{Catalog, Namespace, NamespaceIdent, Result, TableCommit, TableCreation, TableIdent};
+
+/// A table mutation recorded by an [`AuditCatalog`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AuditOperation {
+    /// A table was created.
+    CreateTable,
+    /// An existing metadata file was registered as a table.
+    RegisterTable,
+    /// A table commit was applied.
+    UpdateTable,
+    /// A table was dropped.
+    DropTable,
+    /// A table was renamed.
+    RenameTable,
+}
+
+/// An entry of the audit log written by an [`AuditCatalog`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditRecord {
+    /// The kind of mutation.
+    pub operation: AuditOperation,
+    /// The mutated table, for renames the table before the rename.
+    pub table: TableIdent,
+    /// The new identifier of a renamed table.
+    pub renamed_to: Option<TableIdent>,
+    /// The metadata location of the table before the mutation, if it existed.
+    pub previous_metadata_location: Option<String>,
+    /// The metadata location of the table after the mutation, if it still exists.
+    pub metadata_location: Option<String>,
+    /// When the mutation completed, in milliseconds since the Unix epoch.
+    pub timestamp_ms: i64,
+    /// The actor the [`AuditCatalog`] was created for.
+    pub actor: String,
+}
+
+/// Destination of the records of an [`AuditCatalog`].
+#[async_trait]
+pub trait AuditSink: Debug + Send + Sync {
+    /// Persist `record`.
+    async fn record(&self, record: AuditRecord) -> Result<()>;
+}
+
+/// A [`Catalog`] decorator recording every successful table mutation of the wrapped catalog
+/// in an [`AuditSink`].
+///
+/// Creating, registering, updating, dropping and renaming tables is recorded together with the
+/// metadata locations before and after the mutation and the actor passed to
+/// [`AuditCatalog::new`]. Reads and namespace operations are forwarded without being recorded.
+///
+/// Records are written after the wrapped catalog completed the mutation, so a failure of the
+/// sink is returned to the caller although the mutation took effect.
+#[derive(Debug, Clone)]
+pub struct AuditCatalog {
+    inner: Arc<dyn Catalog>,
+    sink: Arc<dyn AuditSink>,
+    actor: String,
+}
+
+impl AuditCatalog {
+    /// Wrap `inner` to record its table mutations, performed on behalf of `actor`, in `sink`.
+    pub fn new(
+        inner: Arc<dyn Catalog>,
+        sink: Arc<dyn AuditSink>,
+        actor: impl Into<String>,
+    ) -> Self {
+        Self {
+            inner,
+            sink,
+            actor: actor.into(),
+        }
+    }
+
+    /// Returns the wrapped catalog.
+    pub fn inner(&self) -> &Arc<dyn Catalog> {
+        &self.inner
+    }
+
+    async fn record(
+        &self,
+        operation: AuditOperation,
+        table: &TableIdent,
+        renamed_to: Option<&TableIdent>,
+        previous_metadata_location: Option<String>,
+        metadata_location: Option<String>,
+    ) -> Result<()> {
+        self.sink
+            .record(AuditRecord {
+                operation,
+                table: table.clone(),
+                renamed_to: renamed_to.cloned(),
+                previous_metadata_location,
+                metadata_location,
+                timestamp_ms: Utc::now().timestamp_millis(),
+                actor: self.actor.clone(),
+            })
+            .await
+    }
+
+    async fn metadata_location_of(&self, table: &TableIdent) -> Option<String> {
+        let table = self.inner.load_table(table).await.ok()?;
+        table.metadata_location().map(str::to_string)
+    }
+}
+
+#[async_trait]
+impl Catalog for AuditCatalog {
+    async fn list_namespaces(
+        &self,
+        parent: Option<&NamespaceIdent>,
+    ) -> Result<Vec<NamespaceIdent>> {
+        self.inner.list_namespaces(parent).await
+    }
+
+    async fn create_namespace(
+        &self,
+        namespace: &NamespaceIdent,
+        properties: HashMap<String, String>,
+    ) -> Result<Namespace> {
+        self.inner.create_namespace(namespace, properties).await
+    }
+
+    async fn get_namespace(&self, namespace: &NamespaceIdent) -> Result<Namespace> {
+        self.inner.get_namespace(namespace).await
+    }
+
+    async fn namespace_exists(&self, namespace: &NamespaceIdent) -> Result<bool> {
+        self.inner.namespace_exists(namespace).await
+    }
+
+    async fn update_namespace(
+        &self,
+        namespace: &NamespaceIdent,
+        properties: HashMap<String, String>,
+    ) -> Result<()> {
+        self.inner.update_namespace(namespace, properties).await
+    }
+
+    async fn drop_namespace(&self, namespace: &NamespaceIdent) -> Result<()> {
+        self.inner.drop_namespace(namespace).await
+    }
+
+    async fn list_tables(&self, namespace: &NamespaceIdent) -> Result<Vec<TableIdent>> {
+        self.inner.list_tables(namespace).await
+    }
+
+    async fn create_table(
+        &self,
+        namespace: &NamespaceIdent,
+        creation: TableCreation,
+    ) -> Result<Table> {
+        let table = self.inner.create_table(namespace, creation).await?;
+        self.record(
+            AuditOperation::CreateTable,
+            table.identifier(),
+            None,
+            None,
+            table.metadata_location().map(str::to_string),
+        )
+        .await?;
+        Ok(table)
+    }
+
+    async fn load_table(&self, table: &TableIdent) -> Result<Table> {
+        self.inner.load_table(table).await
+    }
+
+    async fn drop_table(&self, table: &TableIdent) -> Result<()> {
+        let previous_metadata_location = self.metadata_location_of(table).await;
+        self.inner.drop_table(table).await?;
+        self.record(
+            AuditOperation::DropTable,
+            table,
+            None,
+            previous_metadata_location,
+            None,
+        )
+        .await
+    }
+
+    async fn table_exists(&self, table: &TableIdent) -> Result<bool> {
+        self.inner.table_exists(table).await
+    }
+
+    async fn rename_table(&self, src: &TableIdent, dest: &TableIdent) -> Result<()> {
+        self.inner.rename_table(src, dest).await?;
+        // Renames keep the metadata file of the table.
+        let metadata_location = self.metadata_location_of(dest).await;
+        self.record(
+            AuditOperation::RenameTable,
+            src,
+            Some(dest),
+            metadata_location.clone(),
+            metadata_location,
+        )
+        .await
+    }
+
+    async fn register_table(&self, table: &TableIdent, metadata_location: String) -> Result<Table> {
+        let registered = self.inner.register_table(table, metadata_location).await?;
+        self.record(
+            AuditOperation::RegisterTable,
+            table,
+            None,
+            None,
+            registered.metadata_location().map(str::to_string),
+        )
+        .await?;
+        Ok(registered)
+    }
+
+    async fn update_table(&self, commit: TableCommit) -> Result<Table> {
+        // The metadata log may be disabled, so the replaced metadata file is the one the
+        // commit was built on rather than the last one logged.
+        let previous_metadata_location = commit.base_metadata_location().map(str::to_string);
+        let table = self.inner.update_table(commit).await?;
+        self.record(
+            AuditOperation::UpdateTable,
+            table.identifier(),
+            None,
+            previous_metadata_location,
+            table.metadata_location().map(str::to_string),
+        )
+        .await?;
+        Ok(table)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+
+    use async_trait::async_trait;
+
+    use super::{AuditCatalog, AuditOperation, AuditRecord, AuditSink};
+    use crate::memory::tests::new_memory_catalog;
+    use crate::spec::{NestedField, PrimitiveType, Schema, TableProperties, Type};
+    use crate::transaction::{ApplyTransactionAction, Transaction};
+    use crate::{Catalog, NamespaceIdent, Result, TableCreation, TableIdent};
+
+    #[derive(Debug, Default)]
+    struct VecSink(Mutex<Vec<AuditRecord>>);
+
+    #[async_trait]
+    impl AuditSink for VecSink {
+        async fn record(&self, record: AuditRecord) -> Result<()> {
+            self.0.lock().unwrap().push(record);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_audit_catalog_records_mutations() {
+        let sink = Arc::new(VecSink::default());
+        let catalog =
+            AuditCatalog::new(Arc::new(new_memory_catalog().await), sink.clone(), "alice");
+        let namespace = NamespaceIdent::new("ns".to_string());
+        catalog
+            .create_namespace(&namespace, HashMap::new())
+            .await
+            .unwrap();
+
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+            ])
+            .build()
+            .unwrap();
+        let table = catalog
+            .create_table(
+                &namespace,
+                TableCreation::builder()
+                    .name("t".to_string())
+                    .schema(schema)
+                    .build(),
+            )
+            .await
+            .unwrap();
+
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .update_table_properties()
+            .set("owner".to_string(), "alice".to_string())
+            .apply(tx)
+            .unwrap();
+        let updated = tx.commit(&catalog).await.unwrap();
+
+        let renamed = TableIdent::new(namespace.clone(), "renamed".to_string());
+        catalog
+            .rename_table(table.identifier(), &renamed)
+            .await
+            .unwrap();
+        catalog.load_table(&renamed).await.unwrap();
+        catalog.drop_table(&renamed).await.unwrap();
+
+        let records = sink.0.lock().unwrap().clone();
+        assert_eq!(
+            records.iter().map(|r| r.operation).collect::<Vec<_>>(),
+            vec![
+                AuditOperation::CreateTable,
+                AuditOperation::UpdateTable,
+                AuditOperation::RenameTable,
+                AuditOperation::DropTable,
+            ]
+        );
+        assert!(records.iter().all(|r| r.actor == "alice"));
+
+        let original_location = table.metadata_location().map(str::to_string);
+        let updated_location = updated.metadata_location().map(str::to_string);
+        assert_ne!(original_location, updated_location);
+        assert_eq!(records[0].previous_metadata_location, None);
+        assert_eq!(records[0].metadata_location, original_location);
+
+        let commit = &records[1];
+        assert_eq!(&commit.table, table.identifier());
+        assert_eq!(commit.previous_metadata_location, original_location);
+        assert_eq!(commit.metadata_location, updated_location);
+
+        assert_eq!(records[2].renamed_to.as_ref(), Some(&renamed));
+        assert_eq!(records[2].metadata_location, updated_location);
+        assert_eq!(records[3].previous_metadata_location, updated_location);
+        assert_eq!(records[3].metadata_location, None);
+    }
+
+    #[tokio::test]
+    async fn test_audit_catalog_records_previous_location_without_metadata_log() {
+        let sink = Arc::new(VecSink::default());
+        let catalog =
+            AuditCatalog::new(Arc::new(new_memory_catalog().await), sink.clone(), "alice");
+        let namespace = NamespaceIdent::new("ns".to_string());
+        catalog
+            .create_namespace(&namespace, HashMap::new())
+            .await
+            .unwrap();
+        let table = catalog
+            .create_table(
+                &namespace,
+                TableCreation::builder()
+                    .name("t".to_string())
+                    .schema(Schema::builder().build().unwrap())
+                    .properties(HashMap::from([(
+                        TableProperties::PROPERTY_METADATA_PREVIOUS_VERSIONS_MAX.to_string(),
+                        "0".to_string(),
+                    )]))
+                    .build(),
+            )
+            .await
+            .unwrap();
+
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .update_table_properties()
+            .set("owner".to_string(), "alice".to_string())
+            .apply(tx)
+            .unwrap();
+        tx.commit(&catalog).await.unwrap();
+
+        let records = sink.0.lock().unwrap().clone();
+        assert_eq!(records[1].operation, AuditOperation::UpdateTable);
+        assert_eq!(
+            records[1].previous_metadata_location.as_deref(),
+            table.metadata_location()
+        );
+    }
+}
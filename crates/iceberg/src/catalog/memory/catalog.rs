@@ -18,6 +18,7 @@
 //! This module contains memory catalog implementation.
 
 use std::collections::HashMap;
+use std::sync::Arc;
 
 use async_trait::async_trait;
 use futures::lock::{Mutex, MutexGuard};
@@ -25,11 +26,11 @@ use itertools::Itertools;
 
 use super::namespace_state::NamespaceState;
 use crate::io::FileIO;
-use crate::spec::{TableMetadata, TableMetadataBuilder};
+use crate::spec::TableMetadataBuilder;
 use crate::table::Table;
 use crate::{
-    Catalog, CatalogBuilder, Error, ErrorKind, MetadataLocation, Namespace, NamespaceIdent, Result,
-    TableCommit, TableCreation, TableIdent,
+    Catalog, CatalogBuilder, Error, ErrorKind, FileIOMetadataStore, MetadataLocation,
+    MetadataStore, Namespace, NamespaceIdent, Result, TableCommit, TableCreation, TableIdent,
 };
 
 /// Memory catalog warehouse location
@@ -48,10 +49,20 @@ impl Default for MemoryCatalogBuilder {
             name: None,
             warehouse: "".to_string(),
             props: HashMap::new(),
+            metadata_store: None,
         })
     }
 }
 
+impl MemoryCatalogBuilder {
+    /// Keeps the table metadata in `metadata_store` instead of metadata files in the
+    /// warehouse.
+    pub fn with_metadata_store(mut self, metadata_store: Arc<dyn MetadataStore>) -> Self {
+        self.0.metadata_store = Some(metadata_store);
+        self
+    }
+}
+
 impl CatalogBuilder for MemoryCatalogBuilder {
     type C = MemoryCatalog;
 
@@ -100,24 +111,35 @@ pub(crate) struct MemoryCatalogConfig {
     name: Option<String>,
     warehouse: String,
     props: HashMap<String, String>,
+    metadata_store: Option<Arc<dyn MetadataStore>>,
 }
 
 /// Memory catalog implementation.
+///
+/// The table pointers are kept in memory, while the table metadata is written to metadata
+/// files in the warehouse, or to the [`MetadataStore`] set with
+/// [`MemoryCatalogBuilder::with_metadata_store`].
 #[derive(Debug)]
 pub struct MemoryCatalog {
     root_namespace_state: Mutex<NamespaceState>,
     file_io: FileIO,
+    metadata_store: Arc<dyn MetadataStore>,
     warehouse_location: String,
 }
 
 impl MemoryCatalog {
     /// Creates a memory catalog.
     fn new(config: MemoryCatalogConfig) -> Result<Self> {
+        let file_io = FileIO::from_path(&config.warehouse)?
+            .with_props(config.props)
+            .build()?;
+        let metadata_store = config
+            .metadata_store
+            .unwrap_or_else(|| Arc::new(FileIOMetadataStore::new(file_io.clone())));
         Ok(Self {
             root_namespace_state: Mutex::new(NamespaceState::default()),
-            file_io: FileIO::from_path(&config.warehouse)?
-                .with_props(config.props)
-                .build()?,
+            file_io,
+            metadata_store,
             warehouse_location: config.warehouse,
         })
     }
@@ -129,7 +151,7 @@ impl MemoryCatalog {
         root_namespace_state: &MutexGuard<'_, NamespaceState>,
     ) -> Result<Table> {
         let metadata_location = root_namespace_state.get_existing_table_location(table_ident)?;
-        let metadata = TableMetadata::read_from(&self.file_io, metadata_location).await?;
+        let metadata = self.metadata_store.read(metadata_location).await?;
 
         Table::builder()
             .identifier(table_ident.clone())
@@ -281,7 +303,9 @@ impl Catalog for MemoryCatalog {
             .metadata;
         let metadata_location = MetadataLocation::new_with_table_location(location).to_string();
 
-        metadata.write_to(&self.file_io, &metadata_location).await?;
+        self.metadata_store
+            .write(&metadata_location, &metadata)
+            .await?;
 
         root_namespace_state.insert_new_table(&table_ident, metadata_location.clone())?;
 
@@ -306,7 +330,7 @@ impl Catalog for MemoryCatalog {
         let mut root_namespace_state = self.root_namespace_state.lock().await;
 
         let metadata_location = root_namespace_state.remove_existing_table(table_ident)?;
-        self.file_io.delete(&metadata_location).await
+        self.metadata_store.delete(&metadata_location).await
     }
 
     /// Check if a table exists in the catalog.
@@ -343,7 +367,7 @@ impl Catalog for MemoryCatalog {
         let mut root_namespace_state = self.root_namespace_state.lock().await;
         root_namespace_state.insert_new_table(&table_ident.clone(), metadata_location.clone())?;
 
-        let metadata = TableMetadata::read_from(&self.file_io, &metadata_location).await?;
+        let metadata = self.metadata_store.read(&metadata_location).await?;
 
         Table::builder()
             .file_io(self.file_io.clone())
@@ -365,11 +389,10 @@ impl Catalog for MemoryCatalog {
         let staged_table = commit.apply(current_table)?;
 
         // Write table metadata to the new location
-        staged_table
-            .metadata()
-            .write_to(
-                staged_table.file_io(),
+        self.metadata_store
+            .write(
                 staged_table.metadata_location_result()?,
+                staged_table.metadata(),
             )
             .await?;
 
@@ -389,11 +412,15 @@ pub(crate) mod tests {
 
     use regex::Regex;
     use tempfile::TempDir;
+    use uuid::Uuid;
 
     use super::*;
     use crate::io::FileIOBuilder;
-    use crate::spec::{NestedField, PartitionSpec, PrimitiveType, Schema, SortOrder, Type};
+    use crate::spec::{
+        NestedField, PartitionSpec, PrimitiveType, Schema, SortOrder, TableMetadata, Type,
+    };
     use crate::transaction::{ApplyTransactionAction, Transaction};
+    use crate::{TableRequirement, TableUpdate};
 
     fn temp_path() -> String {
         let temp_dir = TempDir::new().unwrap();
@@ -1892,6 +1919,129 @@ pub(crate) mod tests {
         assert_eq!(err.kind(), ErrorKind::TableNotFound);
     }
 
+    #[tokio::test]
+    async fn test_update_table_rejects_stale_requirements() {
+        let catalog = new_memory_catalog().await;
+        let table = create_table_with_namespace(&catalog).await;
+
+        for requirement in [
+            TableRequirement::UuidMatch {
+                uuid: Uuid::new_v4(),
+            },
+            TableRequirement::NotExist,
+        ] {
+            let commit = TableCommit::builder()
+                .ident(table.identifier().clone())
+                .updates(vec![TableUpdate::SetProperties {
+                    updates: HashMap::from([("key".to_string(), "value".to_string())]),
+                }])
+                .requirements(vec![requirement])
+                .build();
+
+            let err = catalog.update_table(commit).await.unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::CatalogCommitConflicts);
+        }
+
+        let loaded = catalog.load_table(table.identifier()).await.unwrap();
+        assert_eq!(loaded.metadata_location(), table.metadata_location());
+        assert!(!loaded.metadata().properties().contains_key("key"));
+    }
+
+    /// A metadata store keeping the metadata JSON of each location in memory.
+    #[derive(Debug, Default)]
+    struct InMemoryMetadataStore {
+        blobs: std::sync::Mutex<HashMap<String, Vec<u8>>>,
+    }
+
+    #[async_trait]
+    impl MetadataStore for InMemoryMetadataStore {
+        async fn read(&self, metadata_location: &str) -> Result<TableMetadata> {
+            let blobs = self.blobs.lock().unwrap();
+            let blob = blobs.get(metadata_location).ok_or_else(|| {
+                Error::new(
+                    ErrorKind::DataInvalid,
+                    format!("No metadata stored at {metadata_location}"),
+                )
+            })?;
+            TableMetadata::from_json_bytes(blob)
+        }
+
+        async fn write(&self, metadata_location: &str, metadata: &TableMetadata) -> Result<()> {
+            let blob = metadata.to_json_bytes()?;
+            self.blobs
+                .lock()
+                .unwrap()
+                .insert(metadata_location.to_string(), blob);
+            Ok(())
+        }
+
+        async fn delete(&self, metadata_location: &str) -> Result<()> {
+            self.blobs.lock().unwrap().remove(metadata_location);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_metadata_store_keeps_metadata_out_of_warehouse() {
+        let metadata_store = Arc::new(InMemoryMetadataStore::default());
+        let catalog = MemoryCatalogBuilder::default()
+            .with_metadata_store(metadata_store.clone())
+            .load(
+                "memory",
+                HashMap::from([(
+                    MEMORY_CATALOG_WAREHOUSE.to_string(),
+                    "memory://warehouse".to_string(),
+                )]),
+            )
+            .await
+            .unwrap();
+        let namespace_ident = NamespaceIdent::new("n1".into());
+        create_namespace(&catalog, &namespace_ident).await;
+        let table_ident = TableIdent::new(namespace_ident, "t1".into());
+        let table = create_table(&catalog, &table_ident).await;
+
+        let tx = Transaction::new(&table);
+        let table = tx
+            .update_table_properties()
+            .set("key".to_string(), "value".to_string())
+            .apply(tx)
+            .unwrap()
+            .commit(&catalog)
+            .await
+            .unwrap();
+
+        let loaded = catalog.load_table(&table_ident).await.unwrap();
+        assert_eq!(loaded.metadata_location(), table.metadata_location());
+        assert_eq!(
+            loaded.metadata().properties().get("key"),
+            Some(&"value".to_string())
+        );
+
+        // Both metadata versions are in the store, and none was written to the warehouse.
+        let metadata_locations: Vec<String> = loaded
+            .metadata()
+            .metadata_log()
+            .iter()
+            .map(|log| log.metadata_file.clone())
+            .chain(loaded.metadata_location().map(String::from))
+            .collect();
+        assert_eq!(metadata_locations.len(), 2);
+        assert_eq!(metadata_store.blobs.lock().unwrap().len(), 2);
+        for metadata_location in &metadata_locations {
+            assert!(
+                metadata_store
+                    .blobs
+                    .lock()
+                    .unwrap()
+                    .contains_key(metadata_location)
+            );
+            assert!(!loaded.file_io().exists(metadata_location).await.unwrap());
+        }
+
+        catalog.drop_table(&table_ident).await.unwrap();
+        assert_eq!(metadata_store.blobs.lock().unwrap().len(), 1);
+    }
+
     fn build_table(ident: TableIdent) -> Table {
         let file_io = FileIOBuilder::new_fs_io().build().unwrap();
 
@@ -0,0 +1,70 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+
+use crate::Result;
+use crate::io::FileIO;
+use crate::spec::TableMetadata;
+
+/// The store of the table metadata files of a catalog.
+///
+/// A catalog keeps the pointer from each table to its current metadata location, while the
+/// metadata store holds the metadata at each location. The metadata is usually stored as
+/// `metadata.json` files next to the data with [`FileIOMetadataStore`], but a catalog may
+/// keep it elsewhere, such as in a database blob.
+#[async_trait]
+pub trait MetadataStore: Debug + Send + Sync {
+    /// Reads the table metadata stored at `metadata_location`.
+    async fn read(&self, metadata_location: &str) -> Result<TableMetadata>;
+
+    /// Stores `metadata` at `metadata_location`.
+    async fn write(&self, metadata_location: &str, metadata: &TableMetadata) -> Result<()>;
+
+    /// Deletes the table metadata stored at `metadata_location`.
+    async fn delete(&self, metadata_location: &str) -> Result<()>;
+}
+
+/// A [`MetadataStore`] keeping table metadata in `metadata.json` files through a [`FileIO`].
+#[derive(Debug, Clone)]
+pub struct FileIOMetadataStore {
+    file_io: FileIO,
+}
+
+impl FileIOMetadataStore {
+    /// Creates a metadata store reading and writing the metadata files with `file_io`.
+    pub fn new(file_io: FileIO) -> Self {
+        Self { file_io }
+    }
+}
+
+#[async_trait]
+impl MetadataStore for FileIOMetadataStore {
+    async fn read(&self, metadata_location: &str) -> Result<TableMetadata> {
+        TableMetadata::read_from(&self.file_io, metadata_location).await
+    }
+
+    async fn write(&self, metadata_location: &str, metadata: &TableMetadata) -> Result<()> {
+        metadata.write_to(&self.file_io, metadata_location).await
+    }
+
+    async fn delete(&self, metadata_location: &str) -> Result<()> {
+        self.file_io.delete(metadata_location).await
+    }
+}
@@ -17,8 +17,10 @@
 
 //! Catalog API for Apache Iceberg
 
+mod audit;
 pub mod memory;
 mod metadata_location;
+mod metadata_store;
 
 use std::collections::HashMap;
 use std::fmt::{Debug, Display};
@@ -30,8 +32,10 @@ use std::sync::Arc;
 
 use _serde::{deserialize_snapshot, serialize_snapshot};
 use async_trait::async_trait;
+pub use audit::*;
 pub use memory::MemoryCatalog;
 pub use metadata_location::*;
+pub use metadata_store::*;
 #[cfg(test)]
 use mockall::automock;
 use serde_derive::{Deserialize, Serialize};
@@ -312,6 +316,9 @@ pub struct TableCommit {
     requirements: Vec<TableRequirement>,
     /// The updates of the table.
     updates: Vec<TableUpdate>,
+    /// The metadata location of the table the commit was built on, if known.
+    #[builder(default)]
+    base_metadata_location: Option<String>,
 }
 
 impl TableCommit {
@@ -330,6 +337,11 @@ impl TableCommit {
         take(&mut self.updates)
     }
 
+    /// Return the metadata location of the table the commit was built on, if known.
+    pub fn base_metadata_location(&self) -> Option<&str> {
+        self.base_metadata_location.as_deref()
+    }
+
     /// Applies this [`TableCommit`] to the given [`Table`] as part of a catalog update.
     /// Typically used by [`Catalog::update_table`] to validate requirements and apply metadata updates.
     ///
@@ -865,6 +877,7 @@ pub(super) mod _serde {
                 schema_id,
                 row_range: _,
                 encryption_key_id: key_id,
+                manifests: _,
             } = snapshot;
             CatalogSnapshot {
                 snapshot_id,
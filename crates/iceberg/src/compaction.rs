@@ -0,0 +1,461 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Compaction of the data files of a table.
+//!
+//! [`RewriteDataFiles`] plans groups of data files of the same partition and commits the
+//! files written for them in place of the original ones. Writing the new files is left to
+//! the caller, so compaction can use any reader and writer configuration.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::mem::take;
+
+use futures::StreamExt;
+
+use crate::spec::{DataContentType, DataFile, ManifestContentType, Struct};
+use crate::table::Table;
+use crate::transaction::{ApplyTransactionAction, Transaction};
+use crate::{Catalog, Error, ErrorKind, Result};
+
+/// Default maximum total size in bytes of the data files rewritten in one file group.
+pub const DEFAULT_MAX_FILE_GROUP_SIZE_BYTES: u64 = 100 * 1024 * 1024 * 1024;
+/// Default maximum number of file groups rewritten at the same time.
+pub const DEFAULT_MAX_CONCURRENT_FILE_GROUP_REWRITES: usize = 5;
+
+/// Data files of one partition that are rewritten together.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileGroup {
+    partition: Struct,
+    data_files: Vec<DataFile>,
+}
+
+impl FileGroup {
+    /// Partition of all the data files of the group.
+    pub fn partition(&self) -> &Struct {
+        &self.partition
+    }
+
+    /// Data files to rewrite.
+    pub fn data_files(&self) -> &[DataFile] {
+        &self.data_files
+    }
+
+    /// Total size in bytes of the data files to rewrite.
+    pub fn total_size_bytes(&self) -> u64 {
+        self.data_files
+            .iter()
+            .map(|data_file| data_file.file_size_in_bytes())
+            .sum()
+    }
+}
+
+/// Outcome of [`RewriteDataFiles::execute`].
+#[derive(Debug)]
+pub struct RewriteDataFilesResult {
+    table: Table,
+    rewritten_file_groups_count: usize,
+    rewritten_data_files_count: usize,
+    added_data_files_count: usize,
+    commits_count: usize,
+}
+
+impl RewriteDataFilesResult {
+    /// The table after the last commit.
+    pub fn table(&self) -> &Table {
+        &self.table
+    }
+
+    /// Number of file groups whose rewrite was committed.
+    pub fn rewritten_file_groups_count(&self) -> usize {
+        self.rewritten_file_groups_count
+    }
+
+    /// Number of data files removed from the table.
+    pub fn rewritten_data_files_count(&self) -> usize {
+        self.rewritten_data_files_count
+    }
+
+    /// Number of data files added to the table.
+    pub fn added_data_files_count(&self) -> usize {
+        self.added_data_files_count
+    }
+
+    /// Number of snapshots committed.
+    pub fn commits_count(&self) -> usize {
+        self.commits_count
+    }
+}
+
+/// Rewrites the data files of a table in file groups.
+///
+/// Only data files of the default partition spec are rewritten, and tables with delete files
+/// are not supported yet.
+pub struct RewriteDataFiles<'a> {
+    table: Table,
+    catalog: &'a dyn Catalog,
+    max_file_group_size_bytes: u64,
+    max_concurrent_file_group_rewrites: usize,
+    partial_progress_commit_groups: Option<usize>,
+}
+
+impl<'a> RewriteDataFiles<'a> {
+    /// Creates a rewrite of the data files of `table`, committed to `catalog`.
+    pub fn new(table: Table, catalog: &'a dyn Catalog) -> Self {
+        Self {
+            table,
+            catalog,
+            max_file_group_size_bytes: DEFAULT_MAX_FILE_GROUP_SIZE_BYTES,
+            max_concurrent_file_group_rewrites: DEFAULT_MAX_CONCURRENT_FILE_GROUP_REWRITES,
+            partial_progress_commit_groups: None,
+        }
+    }
+
+    /// Set the maximum total size in bytes of the data files of one file group.
+    /// A data file larger than this forms a group on its own.
+    pub fn with_max_file_group_size_bytes(mut self, max_file_group_size_bytes: u64) -> Self {
+        self.max_file_group_size_bytes = max_file_group_size_bytes;
+        self
+    }
+
+    /// Set the maximum number of file groups rewritten at the same time.
+    pub fn with_max_concurrent_file_group_rewrites(mut self, max: usize) -> Self {
+        self.max_concurrent_file_group_rewrites = max;
+        self
+    }
+
+    /// Commit the rewritten file groups every `groups` completed groups instead of once
+    /// at the end, so that the progress made is kept if a later group fails.
+    pub fn with_partial_progress(mut self, groups: usize) -> Self {
+        self.partial_progress_commit_groups = Some(groups);
+        self
+    }
+
+    /// Plans the groups of data files to rewrite from the current snapshot.
+    ///
+    /// Data files are grouped by partition, and then packed in groups no larger than the
+    /// maximum file group size. Groups with a single data file are skipped since there is
+    /// nothing to compact.
+    pub async fn plan_file_groups(&self) -> Result<Vec<FileGroup>> {
+        let metadata = self.table.metadata();
+        let Some(snapshot) = metadata.current_snapshot() else {
+            return Ok(vec![]);
+        };
+
+        let manifest_list = snapshot
+            .load_manifest_list(self.table.file_io(), metadata)
+            .await?;
+        let mut partitions: HashMap<Struct, Vec<DataFile>> = HashMap::new();
+        for manifest_file in manifest_list.entries() {
+            if manifest_file.content == ManifestContentType::Deletes {
+                if manifest_file.has_added_files() || manifest_file.has_existing_files() {
+                    return Err(Error::new(
+                        ErrorKind::FeatureUnsupported,
+                        "Rewriting data files of a table with delete files is not supported yet",
+                    ));
+                }
+                continue;
+            }
+
+            let manifest = manifest_file
+                .load_manifest(self.table.file_io(), self.table.metadata())
+                .await?;
+            for entry in manifest.entries().iter().filter(|entry| entry.is_alive()) {
+                let data_file = entry.data_file();
+                if data_file.content_type() == DataContentType::Data
+                    && data_file.partition_spec_id == metadata.default_partition_spec_id()
+                {
+                    partitions
+                        .entry(data_file.partition().clone())
+                        .or_default()
+                        .push(data_file.clone());
+                }
+            }
+        }
+
+        let mut file_groups = vec![];
+        for (partition, mut data_files) in partitions {
+            data_files.sort_by(|a, b| a.file_path().cmp(b.file_path()));
+
+            let mut group: Vec<DataFile> = vec![];
+            let mut group_size = 0;
+            for data_file in data_files {
+                if !group.is_empty()
+                    && group_size + data_file.file_size_in_bytes() > self.max_file_group_size_bytes
+                {
+                    file_groups.push(FileGroup {
+                        partition: partition.clone(),
+                        data_files: take(&mut group),
+                    });
+                    group_size = 0;
+                }
+                group_size += data_file.file_size_in_bytes();
+                group.push(data_file);
+            }
+            file_groups.push(FileGroup {
+                partition,
+                data_files: group,
+            });
+        }
+        file_groups.retain(|group| group.data_files.len() > 1);
+
+        Ok(file_groups)
+    }
+
+    /// Rewrites the planned file groups and commits the result.
+    ///
+    /// `rewrite_group` writes the rows of the data files of a group to new data files of the
+    /// same partition, and returns them. At most the configured number of groups are
+    /// rewritten at the same time.
+    ///
+    /// When a rewrite fails, the groups that are not committed yet are discarded, and the
+    /// files written for them are left to orphan file cleanup.
+    pub async fn execute<F, Fut>(self, rewrite_group: F) -> Result<RewriteDataFilesResult>
+    where
+        F: Fn(FileGroup) -> Fut,
+        Fut: Future<Output = Result<Vec<DataFile>>>,
+    {
+        if self.max_concurrent_file_group_rewrites == 0 {
+            return Err(Error::new(
+                ErrorKind::DataInvalid,
+                "Max concurrent file group rewrites must be positive",
+            ));
+        }
+        if self.partial_progress_commit_groups == Some(0) {
+            return Err(Error::new(
+                ErrorKind::DataInvalid,
+                "Partial progress commit groups must be positive",
+            ));
+        }
+
+        let file_groups = self.plan_file_groups().await?;
+
+        let mut result = RewriteDataFilesResult {
+            table: self.table.clone(),
+            rewritten_file_groups_count: 0,
+            rewritten_data_files_count: 0,
+            added_data_files_count: 0,
+            commits_count: 0,
+        };
+        // The groups are rewritten while the completed ones are committed, so that a commit
+        // doesn't hold up the rewrites in flight. The groups completed during a commit are
+        // committed by the next one.
+        let (sender, mut receiver) = futures::channel::mpsc::unbounded();
+        let max_concurrent_file_group_rewrites = self.max_concurrent_file_group_rewrites;
+        let rewrite_groups = async move {
+            let mut rewrites = futures::stream::iter(file_groups)
+                .map(|file_group| {
+                    let rewrite = rewrite_group(file_group.clone());
+                    async move { rewrite.await.map(|data_files| (file_group, data_files)) }
+                })
+                .buffer_unordered(max_concurrent_file_group_rewrites);
+            while let Some(rewritten) = rewrites.next().await {
+                let failed = rewritten.is_err();
+                // The receiver is only dropped once a commit failed, failing the execution.
+                let _ = sender.unbounded_send(rewritten);
+                if failed {
+                    break;
+                }
+            }
+            Ok::<_, Error>(())
+        };
+        let commit_groups = async {
+            let mut completed = vec![];
+            while let Some(rewritten) = receiver.next().await {
+                completed.push(rewritten?);
+                if self
+                    .partial_progress_commit_groups
+                    .is_some_and(|groups| completed.len() >= groups)
+                {
+                    self.commit(&mut result, take(&mut completed)).await?;
+                }
+            }
+            if !completed.is_empty() {
+                self.commit(&mut result, completed).await?;
+            }
+            Ok(())
+        };
+        futures::try_join!(rewrite_groups, commit_groups)?;
+
+        Ok(result)
+    }
+
+    async fn commit(
+        &self,
+        result: &mut RewriteDataFilesResult,
+        rewritten: Vec<(FileGroup, Vec<DataFile>)>,
+    ) -> Result<()> {
+        let mut deleted_data_files = vec![];
+        let mut added_data_files = vec![];
+        for (file_group, data_files) in rewritten {
+            result.rewritten_file_groups_count += 1;
+            deleted_data_files.extend(file_group.data_files);
+            added_data_files.extend(data_files);
+        }
+        result.rewritten_data_files_count += deleted_data_files.len();
+        result.added_data_files_count += added_data_files.len();
+
+        let tx = Transaction::new(&result.table);
+        let tx = tx
+            .rewrite_files()
+            .delete_data_files(deleted_data_files)
+            .add_data_files(added_data_files)
+            .apply(tx)?;
+        result.table = tx.commit(self.catalog).await?;
+        result.commits_count += 1;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    use super::RewriteDataFiles;
+    use crate::inspect::snapshot_diff;
+    use crate::memory::tests::new_memory_catalog;
+    use crate::spec::{NestedField, Operation, PrimitiveType, Schema, Type};
+    use crate::table::Table;
+    use crate::test_utils::fixtures::data_file;
+    use crate::transaction::{ApplyTransactionAction, Transaction};
+    use crate::{Catalog, NamespaceIdent, TableCreation};
+
+    async fn create_table_with_files(catalog: &impl Catalog, files_count: usize) -> Table {
+        let namespace = NamespaceIdent::new("ns".to_string());
+        catalog
+            .create_namespace(&namespace, HashMap::new())
+            .await
+            .unwrap();
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+            ])
+            .build()
+            .unwrap();
+        let table = catalog
+            .create_table(
+                &namespace,
+                TableCreation::builder()
+                    .name("t".to_string())
+                    .schema(schema)
+                    .build(),
+            )
+            .await
+            .unwrap();
+
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .fast_append()
+            .add_data_files(
+                (0..files_count).map(|i| data_file(format!("data/small-{i}.parquet"), 1)),
+            )
+            .apply(tx)
+            .unwrap();
+        tx.commit(catalog).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_rewrite_data_files_bounds_concurrent_rewrites() {
+        let catalog = new_memory_catalog().await;
+        let table = create_table_with_files(&catalog, 12).await;
+        let append_snapshot_id = table.metadata().current_snapshot_id().unwrap();
+
+        let open_writers = Arc::new(AtomicUsize::new(0));
+        let max_open_writers = Arc::new(AtomicUsize::new(0));
+        let rewrite = RewriteDataFiles::new(table, &catalog)
+            .with_max_file_group_size_bytes(200)
+            .with_max_concurrent_file_group_rewrites(2)
+            .with_partial_progress(2);
+        assert_eq!(rewrite.plan_file_groups().await.unwrap().len(), 6);
+
+        let result = rewrite
+            .execute(|file_group| {
+                let open_writers = open_writers.clone();
+                let max_open_writers = max_open_writers.clone();
+                async move {
+                    let open = open_writers.fetch_add(1, Ordering::SeqCst) + 1;
+                    max_open_writers.fetch_max(open, Ordering::SeqCst);
+                    tokio::time::sleep(Duration::from_millis(20)).await;
+                    open_writers.fetch_sub(1, Ordering::SeqCst);
+
+                    let first_path = file_group.data_files()[0].file_path();
+                    let path = first_path.replace("small", "compacted");
+                    Ok(vec![data_file(&path, file_group.data_files().len() as u64)])
+                }
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(max_open_writers.load(Ordering::SeqCst), 2);
+        assert_eq!(result.rewritten_file_groups_count(), 6);
+        assert_eq!(result.rewritten_data_files_count(), 12);
+        assert_eq!(result.added_data_files_count(), 6);
+        assert_eq!(result.commits_count(), 3);
+
+        // Every partial commit is a replace snapshot on top of the previous one.
+        let table = result.table();
+        let mut snapshot = table.metadata().current_snapshot().unwrap().clone();
+        for _ in 0..3 {
+            assert_eq!(snapshot.summary().operation, Operation::Replace);
+            snapshot = table
+                .metadata()
+                .snapshot_by_id(snapshot.parent_snapshot_id().unwrap())
+                .unwrap()
+                .clone();
+        }
+        assert_eq!(snapshot.snapshot_id(), append_snapshot_id);
+
+        let diff = snapshot_diff(
+            table,
+            append_snapshot_id,
+            table.metadata().current_snapshot_id().unwrap(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(diff.removed_data_files().len(), 12);
+        assert!(
+            diff.added_data_files()
+                .iter()
+                .all(|data_file| data_file.file_path().contains("compacted")
+                    && data_file.record_count() == 2)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rewrite_data_files_commits_once_without_partial_progress() {
+        let catalog = new_memory_catalog().await;
+        let table = create_table_with_files(&catalog, 5).await;
+
+        let result = RewriteDataFiles::new(table, &catalog)
+            .execute(|file_group| async move {
+                Ok(vec![data_file(
+                    "data/compacted.parquet",
+                    file_group.data_files().len() as u64,
+                )])
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(result.rewritten_file_groups_count(), 1);
+        assert_eq!(result.rewritten_data_files_count(), 5);
+        assert_eq!(result.commits_count(), 1);
+    }
+}
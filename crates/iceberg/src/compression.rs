@@ -85,6 +85,24 @@ impl CompressionCodec {
     pub(crate) fn is_none(&self) -> bool {
         matches!(self, CompressionCodec::None)
     }
+
+    /// Detects a compression format wrapping the whole payload from its
+    /// leading magic bytes, returning [`CompressionCodec::None`] if there is none.
+    ///
+    /// Only self-describing frame formats can be detected, so [`CompressionCodec::Lz4`]
+    /// is never returned.
+    pub(crate) fn detect(bytes: &[u8]) -> Self {
+        const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+        const ZSTD_MAGIC: [u8; 4] = [0x28, 0xb5, 0x2f, 0xfd];
+
+        if bytes.starts_with(&GZIP_MAGIC) {
+            CompressionCodec::Gzip
+        } else if bytes.starts_with(&ZSTD_MAGIC) {
+            CompressionCodec::Zstd
+        } else {
+            CompressionCodec::None
+        }
+    }
 }
 
 #[cfg(test)]
@@ -116,6 +134,17 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_compression_codec_detect() {
+        let bytes_vec = b"Obj\x01 avro container".to_vec();
+        assert_eq!(CompressionCodec::detect(&bytes_vec), CompressionCodec::None);
+
+        for codec in [CompressionCodec::Zstd, CompressionCodec::Gzip] {
+            let compressed = codec.compress(bytes_vec.clone()).unwrap();
+            assert_eq!(CompressionCodec::detect(&compressed), codec);
+        }
+    }
+
     #[tokio::test]
     async fn test_compression_codec_unsupported() {
         let unsupported_codecs = [(CompressionCodec::Lz4, "LZ4")];
@@ -25,7 +25,7 @@ use tokio::sync::Notify;
 
 use crate::runtime::spawn;
 use crate::scan::{DeleteFileContext, FileScanTaskDeleteFile};
-use crate::spec::{DataContentType, DataFile, Struct};
+use crate::spec::{DataContentType, DataFile, DataFileFormat, Struct};
 
 /// Index of delete files
 #[derive(Debug, Clone)]
@@ -193,7 +193,8 @@ impl PopulatedDeleteFileIndex {
 
         // TODO: the spec states that:
         //     "The data file's file_path is equal to the delete file's referenced_data_file if it is non-null".
-        //     we're not yet doing that here. The referenced data file's name will also be present in the positional
+        //     we're only doing that here for deletion vectors, which are not filtered by data file path
+        //     when read. The referenced data file's name will also be present in the positional
         //     delete file's file path column.
         if let Some(deletes) = self.pos_deletes_by_partition.get(data_file.partition()) {
             deletes
@@ -204,6 +205,13 @@ impl PopulatedDeleteFileIndex {
                         .map(|seq_num| delete.manifest_entry.sequence_number() >= Some(seq_num))
                         .unwrap_or_else(|| true)
                         && data_file.partition_spec_id == delete.partition_spec_id
+                        && (delete.manifest_entry.file_format() != DataFileFormat::Puffin
+                            || delete
+                                .manifest_entry
+                                .data_file
+                                .referenced_data_file
+                                .as_ref()
+                                .is_none_or(|referenced| *referenced == data_file.file_path))
                 })
                 .for_each(|delete| results.push(delete.as_ref().into()));
         }
@@ -23,6 +23,9 @@ use roaring::treemap::BitmapIter;
 
 use crate::{Error, ErrorKind, Result};
 
+/// Magic bytes preceding the bitmap in a serialized deletion vector.
+const DELETION_VECTOR_MAGIC: [u8; 4] = [0xD1, 0xD3, 0x39, 0x64];
+
 #[derive(Debug, Default)]
 pub struct DeleteVector {
     inner: RoaringTreemap,
@@ -45,6 +48,12 @@ impl DeleteVector {
         self.inner.insert(pos)
     }
 
+    /// Returns whether the row at `pos` is deleted.
+    #[allow(unused)]
+    pub fn contains(&self, pos: u64) -> bool {
+        self.inner.contains(pos)
+    }
+
     /// Marks the given `positions` as deleted and returns the number of elements appended.
     ///
     /// The input slice must be strictly ordered in ascending order, and every value must be greater than all existing values already in the set.
@@ -68,6 +77,50 @@ impl DeleteVector {
     pub fn len(&self) -> u64 {
         self.inner.len()
     }
+
+    /// Serializes the delete vector as the content of a `deletion-vector-v1` Puffin blob.
+    ///
+    /// The bitmap is stored in the portable 64-bit Roaring format, preceded by its length and
+    /// magic bytes and followed by a CRC-32 checksum of the magic bytes and the bitmap.
+    pub(crate) fn to_puffin_blob_data(&self) -> Result<Vec<u8>> {
+        let mut vector = DELETION_VECTOR_MAGIC.to_vec();
+        self.inner.serialize_into(&mut vector)?;
+
+        let mut data = Vec::with_capacity(vector.len() + 8);
+        data.extend_from_slice(&u32::try_from(vector.len())?.to_be_bytes());
+        data.extend_from_slice(&vector);
+        data.extend_from_slice(&crc32fast::hash(&vector).to_be_bytes());
+        Ok(data)
+    }
+
+    /// Deserializes the content of a `deletion-vector-v1` Puffin blob.
+    pub(crate) fn from_puffin_blob_data(data: &[u8]) -> Result<Self> {
+        let invalid = |message: &str| {
+            Error::new(
+                ErrorKind::DataInvalid,
+                format!("Invalid deletion vector: {message}"),
+            )
+        };
+
+        let (length, rest) = data
+            .split_first_chunk::<4>()
+            .ok_or_else(|| invalid("missing length"))?;
+        let length = u32::from_be_bytes(*length) as usize;
+        if rest.len() != length + 4 {
+            return Err(invalid("length does not match blob size"));
+        }
+        let (vector, checksum) = rest.split_at(length);
+        if crc32fast::hash(vector).to_be_bytes() != checksum {
+            return Err(invalid("checksum mismatch"));
+        }
+        let bitmap = vector
+            .strip_prefix(&DELETION_VECTOR_MAGIC)
+            .ok_or_else(|| invalid("missing magic bytes"))?;
+
+        Ok(DeleteVector {
+            inner: RoaringTreemap::deserialize_from(bitmap)?,
+        })
+    }
 }
 
 // Ideally, we'd just wrap `roaring::RoaringTreemap`'s iterator, `roaring::treemap::Iter` here.
@@ -190,6 +243,22 @@ mod tests {
         assert!(res.is_err());
     }
 
+    #[test]
+    fn test_puffin_blob_data_roundtrip() {
+        let mut dv = DeleteVector::default();
+        dv.insert_positions(&[0, 7, 1 << 33]).unwrap();
+
+        let data = dv.to_puffin_blob_data().unwrap();
+        assert_eq!(data[4..8], DELETION_VECTOR_MAGIC);
+        let decoded = DeleteVector::from_puffin_blob_data(&data).unwrap();
+        assert_eq!(decoded.iter().collect::<Vec<_>>(), vec![0, 7, 1 << 33]);
+
+        let mut corrupted = data.clone();
+        corrupted[10] ^= 0xFF;
+        assert!(DeleteVector::from_puffin_blob_data(&corrupted).is_err());
+        assert!(DeleteVector::from_puffin_blob_data(&data[..data.len() - 1]).is_err());
+    }
+
     /// Testing scenario: bulk insertion fails because input positions have duplicates.
     #[test]
     fn test_failed_insertion_duplicate_elements() {
@@ -0,0 +1,272 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Parquet modular encryption of data files.
+//!
+//! Keys are never stored in data files. Instead, every key is identified by its key metadata,
+//! such as the id of the key in a key management service, which is stored in the file in plain
+//! text. Readers and writers look up the keys through a [`KeyRetriever`].
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use parquet::encryption::decrypt::{FileDecryptionProperties, KeyRetriever as ParquetKeyRetriever};
+use parquet::encryption::encrypt::FileEncryptionProperties;
+use parquet::errors::ParquetError;
+
+use crate::Result;
+
+/// Retrieves encryption keys by their key metadata.
+pub trait KeyRetriever: Debug + Send + Sync {
+    /// Returns the key identified by `key_metadata`.
+    fn retrieve_key(&self, key_metadata: &[u8]) -> Result<Vec<u8>>;
+}
+
+/// Configuration of the Parquet modular encryption of written data files.
+///
+/// The footer of the files is encrypted with the footer key. When column keys are configured,
+/// only those columns are encrypted, each with its own key. Otherwise, all columns are
+/// encrypted with the footer key.
+#[derive(Clone, Debug)]
+pub struct ParquetEncryption {
+    footer_key_metadata: Vec<u8>,
+    column_key_metadata: HashMap<String, Vec<u8>>,
+    key_retriever: Arc<dyn KeyRetriever>,
+}
+
+impl ParquetEncryption {
+    /// Creates a configuration encrypting files with the footer key identified by
+    /// `footer_key_metadata`.
+    pub fn new(
+        footer_key_metadata: impl Into<Vec<u8>>,
+        key_retriever: Arc<dyn KeyRetriever>,
+    ) -> Self {
+        Self {
+            footer_key_metadata: footer_key_metadata.into(),
+            column_key_metadata: HashMap::new(),
+            key_retriever,
+        }
+    }
+
+    /// Encrypts the column with the dot-separated path `column`, such as `location.city`, with
+    /// the key identified by `key_metadata`.
+    pub fn with_column_key(
+        mut self,
+        column: impl Into<String>,
+        key_metadata: impl Into<Vec<u8>>,
+    ) -> Self {
+        self.column_key_metadata
+            .insert(column.into(), key_metadata.into());
+        self
+    }
+
+    /// Returns the metadata of the footer key.
+    pub fn footer_key_metadata(&self) -> &[u8] {
+        &self.footer_key_metadata
+    }
+
+    /// Retrieves the keys and builds the Parquet encryption properties of a file.
+    pub(crate) fn file_encryption_properties(&self) -> Result<Arc<FileEncryptionProperties>> {
+        let footer_key = self.key_retriever.retrieve_key(&self.footer_key_metadata)?;
+        let mut builder = FileEncryptionProperties::builder(footer_key)
+            .with_footer_key_metadata(self.footer_key_metadata.clone());
+        for (column, key_metadata) in &self.column_key_metadata {
+            let key = self.key_retriever.retrieve_key(key_metadata)?;
+            builder = builder.with_column_key_and_metadata(column, key, key_metadata.clone());
+        }
+        Ok(builder.build()?)
+    }
+}
+
+/// Builds the Parquet decryption properties looking up the keys of the decrypted files
+/// through `key_retriever`.
+pub(crate) fn file_decryption_properties(
+    key_retriever: Arc<dyn KeyRetriever>,
+) -> Result<Arc<FileDecryptionProperties>> {
+    Ok(
+        FileDecryptionProperties::with_key_retriever(Arc::new(KeyRetrieverAdapter(key_retriever)))
+            .build()?,
+    )
+}
+
+/// Exposes a [`KeyRetriever`] to the Parquet reader.
+struct KeyRetrieverAdapter(Arc<dyn KeyRetriever>);
+
+impl ParquetKeyRetriever for KeyRetrieverAdapter {
+    fn retrieve_key(&self, key_metadata: &[u8]) -> parquet::errors::Result<Vec<u8>> {
+        self.0
+            .retrieve_key(key_metadata)
+            .map_err(|err| ParquetError::External(Box::new(err)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int64Type;
+    use arrow_array::{ArrayRef, Int64Array, RecordBatch, StringArray};
+    use futures::TryStreamExt;
+    use parquet::file::properties::WriterProperties;
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::arrow::{ArrowReaderBuilder, schema_to_arrow_schema};
+    use crate::io::FileIO;
+    use crate::scan::{FileScanTask, FileScanTaskStream};
+    use crate::spec::{DataFileFormat, NestedField, PrimitiveType, Schema, Type};
+    use crate::writer::file_writer::{FileWriter, FileWriterBuilder, ParquetWriterBuilder};
+    use crate::{Error, ErrorKind};
+
+    #[derive(Debug)]
+    struct StaticKeyRetriever(HashMap<Vec<u8>, Vec<u8>>);
+
+    impl KeyRetriever for StaticKeyRetriever {
+        fn retrieve_key(&self, key_metadata: &[u8]) -> Result<Vec<u8>> {
+            self.0.get(key_metadata).cloned().ok_or_else(|| {
+                Error::new(
+                    ErrorKind::DataInvalid,
+                    format!("Unknown key {}", String::from_utf8_lossy(key_metadata)),
+                )
+            })
+        }
+    }
+
+    fn key_retriever(keys: &[(&str, &[u8; 16])]) -> Arc<dyn KeyRetriever> {
+        Arc::new(StaticKeyRetriever(
+            keys.iter()
+                .map(|(id, key)| (id.as_bytes().to_vec(), key.to_vec()))
+                .collect(),
+        ))
+    }
+
+    async fn read(
+        file_io: FileIO,
+        schema: Arc<Schema>,
+        data_file_path: String,
+        key_retriever: Arc<dyn KeyRetriever>,
+    ) -> Result<Vec<RecordBatch>> {
+        let tasks = Box::pin(futures::stream::iter(vec![Ok(FileScanTask {
+            start: 0,
+            length: 0,
+            record_count: None,
+            data_file_path,
+            data_file_format: DataFileFormat::Parquet,
+            schema,
+            project_field_ids: vec![1, 2],
+            predicate: None,
+            deletes: vec![],
+            partition: None,
+            partition_spec: None,
+            name_mapping: None,
+            case_sensitive: true,
+            sort_order_id: None,
+            column_sizes: HashMap::new(),
+            value_counts: HashMap::new(),
+            timestamp_zone: None,
+        })])) as FileScanTaskStream;
+
+        ArrowReaderBuilder::new(file_io)
+            .with_key_retriever(key_retriever)
+            .build()
+            .read(tasks)?
+            .try_collect()
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_read_encrypted_column() {
+        let schema = Arc::new(
+            Schema::builder()
+                .with_schema_id(1)
+                .with_fields(vec![
+                    NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+                    NestedField::optional(2, "ssn", Type::Primitive(PrimitiveType::String)).into(),
+                ])
+                .build()
+                .unwrap(),
+        );
+        let batch = RecordBatch::try_new(Arc::new(schema_to_arrow_schema(&schema).unwrap()), vec![
+            Arc::new(Int64Array::from(vec![1, 2])) as ArrayRef,
+            Arc::new(StringArray::from(vec![Some("123-45-6789"), None])) as ArrayRef,
+        ])
+        .unwrap();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let file_io = FileIO::from_path(tmp_dir.path().to_str().unwrap())
+            .unwrap()
+            .build()
+            .unwrap();
+        let data_file_path = format!("{}/1.parquet", tmp_dir.path().to_str().unwrap());
+
+        let keys = key_retriever(&[
+            ("footer", b"0123456789012345"),
+            ("pii", b"1234567890123450"),
+        ]);
+        let encryption =
+            ParquetEncryption::new("footer", keys.clone()).with_column_key("ssn", "pii");
+        let mut writer =
+            ParquetWriterBuilder::new(WriterProperties::builder().build(), schema.clone())
+                .with_encryption(&encryption)
+                .unwrap()
+                .build(file_io.new_output(&data_file_path).unwrap())
+                .await
+                .unwrap();
+        writer.write(&batch).await.unwrap();
+        let mut data_files = writer.close().await.unwrap();
+        assert_eq!(data_files.len(), 1);
+        let mut data_file = data_files.pop().unwrap();
+        data_file.partition_spec_id(0);
+        assert_eq!(
+            data_file.build().unwrap().key_metadata(),
+            Some(b"footer".as_slice())
+        );
+
+        let batches = read(
+            file_io.clone(),
+            schema.clone(),
+            data_file_path.clone(),
+            keys,
+        )
+        .await
+        .unwrap();
+        assert_eq!(batches.len(), 1);
+        assert_eq!(
+            batches[0]
+                .column(0)
+                .as_primitive::<Int64Type>()
+                .values()
+                .to_vec(),
+            vec![1, 2]
+        );
+        let ssns: Vec<Option<&str>> = batches[0].column(1).as_string::<i32>().iter().collect();
+        assert_eq!(ssns, vec![Some("123-45-6789"), None]);
+
+        let wrong_keys = key_retriever(&[
+            ("footer", b"0123456789012345"),
+            ("pii", b"0000000000000000"),
+        ]);
+        assert!(
+            read(file_io, schema, data_file_path, wrong_keys)
+                .await
+                .is_err()
+        );
+    }
+}
@@ -63,6 +63,13 @@ pub enum ErrorKind {
 
     /// Catalog commit failed due to outdated metadata
     CatalogCommitConflicts,
+
+    /// Catalog backend could not be reached.
+    ///
+    /// This error is returned when the storage behind a catalog, e.g. a
+    /// database, is down or its connection pool is exhausted. Retrying the
+    /// operation later may succeed.
+    BackendUnavailable,
 }
 
 impl ErrorKind {
@@ -84,6 +91,7 @@ impl From<ErrorKind> for &'static str {
             ErrorKind::NamespaceNotFound => "NamespaceNotFound",
             ErrorKind::PreconditionFailed => "PreconditionFailed",
             ErrorKind::CatalogCommitConflicts => "CatalogCommitConflicts",
+            ErrorKind::BackendUnavailable => "BackendUnavailable",
         }
     }
 }
@@ -378,11 +386,19 @@ define_from_err!(
     "Failed to convert between uuid und iceberg value"
 );
 
-define_from_err!(
-    apache_avro::Error,
-    ErrorKind::DataInvalid,
-    "Failure in conversion with avro"
-);
+impl From<apache_avro::Error> for Error {
+    fn from(v: apache_avro::Error) -> Self {
+        // Snappy compressed avro blocks end with the CRC32 of the uncompressed data, a
+        // mismatch means the file is corrupted rather than not matching the schema.
+        if let apache_avro::error::Details::SnappyCrc32 { expected, actual } = v.details() {
+            let message = format!(
+                "Snappy compressed avro block is corrupted, expected CRC32 {expected:#010x} but got {actual:#010x}"
+            );
+            return Self::new(ErrorKind::DataInvalid, message).with_source(v);
+        }
+        Self::new(ErrorKind::DataInvalid, "Failure in conversion with avro").with_source(v)
+    }
+}
 
 define_from_err!(
     opendal::Error,
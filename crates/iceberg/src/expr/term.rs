@@ -310,18 +310,14 @@ impl Bind for Reference {
     type Bound = BoundReference;
 
     fn bind(&self, schema: SchemaRef, case_sensitive: bool) -> crate::Result<Self::Bound> {
-        let field = if case_sensitive {
-            schema.field_by_name(&self.name)
-        } else {
-            schema.field_by_name_case_insensitive(&self.name)
-        };
-
-        let field = field.ok_or_else(|| {
-            Error::new(
-                ErrorKind::DataInvalid,
-                format!("Field {} not found in schema", self.name),
-            )
-        })?;
+        let field = schema
+            .resolve_field_by_name(&self.name, case_sensitive)?
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorKind::DataInvalid,
+                    format!("Field {} not found in schema", self.name),
+                )
+            })?;
 
         let accessor = schema.accessor_by_field_id(field.id).ok_or_else(|| {
             Error::new(
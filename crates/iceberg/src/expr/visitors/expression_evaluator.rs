@@ -385,6 +385,280 @@ mod tests {
         }
     }
 
+    fn create_hour_partition_spec(r#type: PrimitiveType) -> Result<(PartitionSpecRef, SchemaRef)> {
+        let schema = Schema::builder()
+            .with_fields(vec![Arc::new(NestedField::optional(
+                1,
+                "ts",
+                Type::Primitive(r#type),
+            ))])
+            .build()?;
+
+        let spec = PartitionSpec::builder(schema.clone())
+            .with_spec_id(1)
+            .add_unbound_field(
+                UnboundPartitionField::builder()
+                    .source_id(1)
+                    .name("ts_hour".to_string())
+                    .field_id(1000)
+                    .transform(Transform::Hour)
+                    .build(),
+            )
+            .unwrap()
+            .build()
+            .unwrap();
+
+        Ok((Arc::new(spec), schema.into()))
+    }
+
+    fn create_data_file_hour(hour: i32) -> DataFile {
+        let partition = Struct::from_iter([Some(Literal::int(hour))]);
+
+        DataFile {
+            content: DataContentType::Data,
+            file_path: format!("/test/path/ts_hour={hour}"),
+            file_format: DataFileFormat::Parquet,
+            partition,
+            record_count: 1,
+            file_size_in_bytes: 1,
+            column_sizes: HashMap::new(),
+            value_counts: HashMap::new(),
+            null_value_counts: HashMap::new(),
+            nan_value_counts: HashMap::new(),
+            lower_bounds: HashMap::new(),
+            upper_bounds: HashMap::new(),
+            key_metadata: None,
+            split_offsets: None,
+            equality_ids: None,
+            sort_order_id: None,
+            partition_spec_id: 1,
+            first_row_id: None,
+            referenced_data_file: None,
+            content_offset: None,
+            content_size_in_bytes: None,
+        }
+    }
+
+    fn assert_hour_range_pruning(lower: Datum, upper: Datum, r#type: PrimitiveType) -> Result<()> {
+        let case_sensitive = true;
+        let (partition_spec, schema) = create_hour_partition_spec(r#type)?;
+
+        let predicate = Reference::new("ts")
+            .greater_than_or_equal_to(lower)
+            .and(Reference::new("ts").less_than(upper))
+            .bind(schema.clone(), case_sensitive)?;
+
+        let expression_evaluator =
+            create_expression_evaluator(partition_spec, &schema, &predicate, case_sensitive)?;
+
+        let surviving = (420032..420038)
+            .filter(|hour| {
+                expression_evaluator
+                    .eval(&create_data_file_hour(*hour))
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        // 2017-12-01T10:00:00 is hour 420034, so only hours 10 and 11 survive.
+        assert_eq!(surviving, vec![420034, 420035]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_expr_hour_partition_range_pruning() -> Result<()> {
+        assert_hour_range_pruning(
+            Datum::timestamp_from_str("2017-12-01T10:00:00")?,
+            Datum::timestamp_from_str("2017-12-01T12:00:00")?,
+            PrimitiveType::Timestamp,
+        )
+    }
+
+    #[test]
+    fn test_expr_hour_partition_range_pruning_timestamptz() -> Result<()> {
+        assert_hour_range_pruning(
+            Datum::timestamptz_from_str("2017-12-01T12:00:00+02:00")?,
+            Datum::timestamptz_from_str("2017-12-01T14:00:00+02:00")?,
+            PrimitiveType::Timestamptz,
+        )
+    }
+
+    #[test]
+    fn test_expr_hour_partition_range_pruning_pre_epoch() -> Result<()> {
+        let case_sensitive = true;
+        let (partition_spec, schema) = create_hour_partition_spec(PrimitiveType::Timestamp)?;
+
+        let predicate = Reference::new("ts")
+            .greater_than_or_equal_to(Datum::timestamp_from_str("1969-12-31T22:00:00")?)
+            .and(Reference::new("ts").less_than(Datum::timestamp_from_str("1970-01-01T00:00:00")?))
+            .bind(schema.clone(), case_sensitive)?;
+
+        let expression_evaluator =
+            create_expression_evaluator(partition_spec, &schema, &predicate, case_sensitive)?;
+
+        let surviving = (-4..2)
+            .filter(|hour| {
+                expression_evaluator
+                    .eval(&create_data_file_hour(*hour))
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(surviving, vec![-2, -1]);
+
+        Ok(())
+    }
+
+    fn create_truncate_partition_spec(
+        r#type: PrimitiveType,
+    ) -> Result<(PartitionSpecRef, SchemaRef)> {
+        let schema = Schema::builder()
+            .with_fields(vec![Arc::new(NestedField::optional(
+                1,
+                "event_id",
+                Type::Primitive(r#type),
+            ))])
+            .build()?;
+
+        let spec = PartitionSpec::builder(schema.clone())
+            .with_spec_id(1)
+            .add_unbound_field(
+                UnboundPartitionField::builder()
+                    .source_id(1)
+                    .name("event_id_trunc".to_string())
+                    .field_id(1000)
+                    .transform(Transform::Truncate(1000))
+                    .build(),
+            )
+            .unwrap()
+            .build()
+            .unwrap();
+
+        Ok((Arc::new(spec), schema.into()))
+    }
+
+    fn create_data_file_truncate(partition: Literal) -> DataFile {
+        DataFile {
+            content: DataContentType::Data,
+            file_path: format!("/test/path/event_id_trunc={partition:?}"),
+            file_format: DataFileFormat::Parquet,
+            partition: Struct::from_iter([Some(partition)]),
+            record_count: 1,
+            file_size_in_bytes: 1,
+            column_sizes: HashMap::new(),
+            value_counts: HashMap::new(),
+            null_value_counts: HashMap::new(),
+            nan_value_counts: HashMap::new(),
+            lower_bounds: HashMap::new(),
+            upper_bounds: HashMap::new(),
+            key_metadata: None,
+            split_offsets: None,
+            equality_ids: None,
+            sort_order_id: None,
+            partition_spec_id: 1,
+            first_row_id: None,
+            referenced_data_file: None,
+            content_offset: None,
+            content_size_in_bytes: None,
+        }
+    }
+
+    /// Returns the partitions of `truncate(1000, event_id)` from -3000 to 3000 that survive
+    /// the `predicate`.
+    fn truncate_range_pruning(
+        predicate: Predicate,
+        r#type: PrimitiveType,
+        literal: fn(i64) -> Literal,
+    ) -> Result<Vec<i64>> {
+        let case_sensitive = true;
+        let (partition_spec, schema) = create_truncate_partition_spec(r#type)?;
+        let predicate = predicate.bind(schema.clone(), case_sensitive)?;
+
+        let expression_evaluator =
+            create_expression_evaluator(partition_spec, &schema, &predicate, case_sensitive)?;
+
+        Ok((-3..=3)
+            .map(|partition| partition * 1000)
+            .filter(|partition| {
+                expression_evaluator
+                    .eval(&create_data_file_truncate(literal(*partition)))
+                    .unwrap()
+            })
+            .collect())
+    }
+
+    #[test]
+    fn test_expr_truncate_partition_range_pruning() -> Result<()> {
+        let between = |lower: i64, upper: i64, datum: fn(i64) -> Datum| {
+            Reference::new("event_id")
+                .greater_than_or_equal_to(datum(lower))
+                .and(Reference::new("event_id").less_than_or_equal_to(datum(upper)))
+        };
+        let int_datum = |v: i64| Datum::int(v as i32);
+        let int_literal = |v: i64| Literal::int(v as i32);
+
+        for (r#type, datum, literal) in [
+            (
+                PrimitiveType::Int,
+                int_datum as fn(i64) -> Datum,
+                int_literal as fn(i64) -> Literal,
+            ),
+            (PrimitiveType::Long, Datum::long, Literal::long),
+        ] {
+            assert_eq!(
+                truncate_range_pruning(between(1500, 2500, datum), r#type.clone(), literal)?,
+                vec![1000, 2000]
+            );
+            assert_eq!(
+                truncate_range_pruning(between(1000, 1999, datum), r#type.clone(), literal)?,
+                vec![1000]
+            );
+            // Negative values are truncated towards negative infinity.
+            assert_eq!(
+                truncate_range_pruning(between(-1500, -1, datum), r#type.clone(), literal)?,
+                vec![-2000, -1000]
+            );
+            assert_eq!(
+                truncate_range_pruning(
+                    Reference::new("event_id")
+                        .greater_than(datum(999))
+                        .and(Reference::new("event_id").less_than(datum(3000))),
+                    r#type.clone(),
+                    literal
+                )?,
+                vec![1000, 2000]
+            );
+            assert_eq!(
+                truncate_range_pruning(
+                    Reference::new("event_id").equal_to(datum(-1)),
+                    r#type,
+                    literal
+                )?,
+                vec![-1000]
+            );
+        }
+
+        // A boundary that cannot be shifted is not projected, so no partition is pruned.
+        assert_eq!(
+            truncate_range_pruning(
+                Reference::new("event_id").greater_than(Datum::int(i32::MAX)),
+                PrimitiveType::Int,
+                int_literal
+            )?,
+            vec![-3000, -2000, -1000, 0, 1000, 2000, 3000]
+        );
+        assert_eq!(
+            truncate_range_pruning(
+                Reference::new("event_id").less_than(Datum::long(i64::MIN)),
+                PrimitiveType::Long,
+                Literal::long
+            )?,
+            vec![-3000, -2000, -1000, 0, 1000, 2000, 3000]
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_expr_or() -> Result<()> {
         let case_sensitive = true;
@@ -26,6 +26,14 @@ const IN_PREDICATE_LIMIT: usize = 200;
 const ROWS_MIGHT_MATCH: crate::Result<bool> = Ok(true);
 const ROWS_CANNOT_MATCH: crate::Result<bool> = Ok(false);
 
+/// Evaluates whether a predicate might match the rows of a data file, from its metrics.
+///
+/// The bounds are only compared as bounds of the values, never as values of the file: the
+/// string and binary bounds of files written with `truncate(<length>)` metrics are truncated,
+/// with the lower bound rounded down to a prefix of the smallest value and the upper bound
+/// rounded up by incrementing the last character of its prefix. Each predicate is checked
+/// against the side of the range that keeps such bounds conservative, so a file is only
+/// skipped when no value within its bounds can match.
 pub(crate) struct InclusiveMetricsEvaluator<'a> {
     data_file: &'a DataFile,
 }
@@ -799,6 +807,55 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_nested_struct_field() {
+        let schema = Arc::new(
+            Schema::builder()
+                .with_fields(vec![
+                    NestedField::optional(
+                        1,
+                        "address",
+                        Type::Struct(crate::spec::StructType::new(vec![
+                            NestedField::optional(
+                                2,
+                                "country",
+                                Type::Primitive(PrimitiveType::String),
+                            )
+                            .into(),
+                        ])),
+                    )
+                    .into(),
+                ])
+                .build()
+                .unwrap(),
+        );
+        let predicate = Reference::new("address.country")
+            .equal_to(Datum::string("US"))
+            .bind(schema, true)
+            .unwrap();
+
+        let mut data_file = create_test_data_file();
+        assert!(
+            InclusiveMetricsEvaluator::eval(&predicate, &data_file, true).unwrap(),
+            "Should read: no stats for the nested field"
+        );
+
+        data_file.value_counts = HashMap::from([(2, 10)]);
+        data_file.null_value_counts = HashMap::from([(2, 0)]);
+        data_file.lower_bounds = HashMap::from([(2, Datum::string("CA"))]);
+        data_file.upper_bounds = HashMap::from([(2, Datum::string("CA"))]);
+        assert!(
+            !InclusiveMetricsEvaluator::eval(&predicate, &data_file, true).unwrap(),
+            "Should skip: nested field bounds do not contain the value"
+        );
+
+        data_file.upper_bounds = HashMap::from([(2, Datum::string("US"))]);
+        assert!(
+            InclusiveMetricsEvaluator::eval(&predicate, &data_file, true).unwrap(),
+            "Should read: nested field bounds contain the value"
+        );
+    }
+
     #[test]
     fn test_zero_record_file() {
         let zero_records_datafile = create_zero_records_data_file();
@@ -1330,9 +1387,8 @@ mod test {
         // to four character, and then appending the "ボ" character,
         // which occupies the next code point after the 5th
         // character in the string above, "ホ".
-        // In the Java implementation of Iceberg, this is done by
-        // the `truncateStringMax` function, but we don't yet have
-        // this implemented in iceberg-rust.
+        // This is how truncated upper bounds are rounded up, as by
+        // the `truncateStringMax` function of the Java implementation.
         let above_max = "イロハニボ";
 
         let result = InclusiveMetricsEvaluator::eval(
@@ -1344,6 +1400,51 @@ mod test {
         assert!(!result, "Should skip: range does not match");
     }
 
+    #[test]
+    fn test_string_truncated_bounds() {
+        // The values range from "abcdef" to "abcxyz", with bounds truncated to 3 characters.
+        let data_file = get_test_file_truncated_bounds();
+
+        for value in ["abcdef", "abcmmm", "abcxyz"] {
+            let result =
+                InclusiveMetricsEvaluator::eval(&equal("required", value), &data_file, true)
+                    .unwrap();
+            assert!(
+                result,
+                "Should read: {value} is within the truncated bounds"
+            );
+        }
+        let result =
+            InclusiveMetricsEvaluator::eval(&starts_with("required", "abcx"), &data_file, true)
+                .unwrap();
+        assert!(
+            result,
+            "Should read: the prefix is within the truncated bounds"
+        );
+        let result = InclusiveMetricsEvaluator::eval(
+            &in_str("required", &["aaa", "abczzz"]),
+            &data_file,
+            true,
+        )
+        .unwrap();
+        assert!(result, "Should read: abczzz is within the truncated bounds");
+        let result =
+            InclusiveMetricsEvaluator::eval(&less_than("required", "abca"), &data_file, true)
+                .unwrap();
+        assert!(result, "Should read: the lower bound is rounded down");
+        let result =
+            InclusiveMetricsEvaluator::eval(&greater_than("required", "abcz"), &data_file, true)
+                .unwrap();
+        assert!(result, "Should read: the upper bound is rounded up");
+
+        let result =
+            InclusiveMetricsEvaluator::eval(&equal("required", "abd0"), &data_file, true).unwrap();
+        assert!(!result, "Should skip: abd0 is above the upper bound");
+        let result =
+            InclusiveMetricsEvaluator::eval(&equal("required", "abb"), &data_file, true).unwrap();
+        assert!(!result, "Should skip: abb is below the lower bound");
+    }
+
     #[test]
     fn test_string_not_starts_with() {
         let result = InclusiveMetricsEvaluator::eval(
@@ -2157,6 +2258,14 @@ mod test {
         }
     }
 
+    fn get_test_file_truncated_bounds() -> DataFile {
+        DataFile {
+            lower_bounds: HashMap::from([(3, Datum::string("abc"))]),
+            upper_bounds: HashMap::from([(3, Datum::string("abd"))]),
+            ..get_test_file_4()
+        }
+    }
+
     fn get_test_file_4() -> DataFile {
         DataFile {
             content: DataContentType::Data,
@@ -23,6 +23,7 @@ pub(crate) mod manifest_evaluator;
 pub(crate) mod page_index_evaluator;
 pub(crate) mod predicate_visitor;
 pub(crate) mod rewrite_not;
+pub(crate) mod row_group_bloom_filter_evaluator;
 pub(crate) mod row_group_metrics_evaluator;
 pub(crate) mod strict_metrics_evaluator;
 pub(crate) mod strict_projection;
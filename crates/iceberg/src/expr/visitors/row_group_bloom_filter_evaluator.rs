@@ -0,0 +1,266 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Evaluates Parquet Row Group bloom filters
+
+use std::collections::{HashMap, HashSet};
+
+use fnv::FnvHashSet;
+use parquet::basic::Type as PhysicalType;
+use parquet::bloom_filter::Sbbf;
+use parquet::file::metadata::RowGroupMetaData;
+
+use crate::Result;
+use crate::expr::visitors::bound_predicate_visitor::{BoundPredicateVisitor, visit};
+use crate::expr::{BoundPredicate, BoundReference, PredicateOperator};
+use crate::spec::{Datum, PrimitiveLiteral};
+
+pub(crate) struct RowGroupBloomFilterEvaluator<'a> {
+    row_group_metadata: &'a RowGroupMetaData,
+    iceberg_field_id_to_parquet_column_index: &'a HashMap<i32, usize>,
+    bloom_filters: &'a HashMap<i32, Sbbf>,
+}
+
+const ROW_GROUP_MIGHT_MATCH: Result<bool> = Ok(true);
+const ROW_GROUP_CANT_MATCH: Result<bool> = Ok(false);
+
+impl<'a> RowGroupBloomFilterEvaluator<'a> {
+    /// Returns the ids of the fields compared for equality in `filter`, which are the only
+    /// fields whose bloom filters can prune row groups.
+    pub(crate) fn equality_field_ids(filter: &BoundPredicate) -> HashSet<i32> {
+        let mut field_ids = HashSet::new();
+        Self::collect_equality_field_ids(filter, &mut field_ids);
+        field_ids
+    }
+
+    fn collect_equality_field_ids(filter: &BoundPredicate, field_ids: &mut HashSet<i32>) {
+        match filter {
+            BoundPredicate::And(expr) | BoundPredicate::Or(expr) => {
+                for input in expr.inputs() {
+                    Self::collect_equality_field_ids(input, field_ids);
+                }
+            }
+            BoundPredicate::Binary(expr) if expr.op() == PredicateOperator::Eq => {
+                field_ids.insert(expr.term().field().id);
+            }
+            BoundPredicate::Set(expr) if expr.op() == PredicateOperator::In => {
+                field_ids.insert(expr.term().field().id);
+            }
+            _ => {}
+        }
+    }
+
+    /// Evaluate `filter` against the bloom filters of a Parquet file RowGroup, keyed by
+    /// Iceberg field id. Used by [`ArrowReader`] to see if the RowGroup could possibly
+    /// contain data that matches the scan's filter.
+    ///
+    /// Only equality and `IN` predicates are answered by bloom filters, everything else
+    /// might match.
+    pub(crate) fn eval(
+        filter: &'a BoundPredicate,
+        row_group_metadata: &'a RowGroupMetaData,
+        field_id_map: &'a HashMap<i32, usize>,
+        bloom_filters: &'a HashMap<i32, Sbbf>,
+    ) -> Result<bool> {
+        let mut evaluator = Self {
+            row_group_metadata,
+            iceberg_field_id_to_parquet_column_index: field_id_map,
+            bloom_filters,
+        };
+
+        visit(&mut evaluator, filter)
+    }
+
+    /// Returns `false` only when the bloom filter of the field proves that `datum` is absent.
+    fn might_contain(&self, field_id: i32, datum: &Datum) -> bool {
+        let Some(bloom_filter) = self.bloom_filters.get(&field_id) else {
+            return true;
+        };
+        let Some(parquet_column_index) =
+            self.iceberg_field_id_to_parquet_column_index.get(&field_id)
+        else {
+            return true;
+        };
+
+        // Values are hashed by their plain encoding, which depends on the physical type
+        // the column is stored as.
+        let physical_type = self
+            .row_group_metadata
+            .column(*parquet_column_index)
+            .column_type();
+        match (physical_type, datum.literal()) {
+            (PhysicalType::INT32, PrimitiveLiteral::Int(value)) => bloom_filter.check(value),
+            (PhysicalType::INT64, PrimitiveLiteral::Long(value)) => bloom_filter.check(value),
+            (PhysicalType::INT64, PrimitiveLiteral::Int(value)) => {
+                bloom_filter.check(&i64::from(*value))
+            }
+            (PhysicalType::BYTE_ARRAY, PrimitiveLiteral::String(value)) => {
+                bloom_filter.check(&value.as_str())
+            }
+            (PhysicalType::BYTE_ARRAY, PrimitiveLiteral::Binary(value)) => {
+                bloom_filter.check(value)
+            }
+            _ => true,
+        }
+    }
+}
+
+impl BoundPredicateVisitor for RowGroupBloomFilterEvaluator<'_> {
+    type T = bool;
+
+    fn always_true(&mut self) -> Result<bool> {
+        ROW_GROUP_MIGHT_MATCH
+    }
+
+    fn always_false(&mut self) -> Result<bool> {
+        ROW_GROUP_CANT_MATCH
+    }
+
+    fn and(&mut self, lhs: bool, rhs: bool) -> Result<bool> {
+        Ok(lhs && rhs)
+    }
+
+    fn or(&mut self, lhs: bool, rhs: bool) -> Result<bool> {
+        Ok(lhs || rhs)
+    }
+
+    fn not(&mut self, _inner: bool) -> Result<bool> {
+        // A bloom filter can't prove that all values match the inner predicate.
+        ROW_GROUP_MIGHT_MATCH
+    }
+
+    fn is_null(
+        &mut self,
+        _reference: &BoundReference,
+        _predicate: &BoundPredicate,
+    ) -> Result<bool> {
+        ROW_GROUP_MIGHT_MATCH
+    }
+
+    fn not_null(
+        &mut self,
+        _reference: &BoundReference,
+        _predicate: &BoundPredicate,
+    ) -> Result<bool> {
+        ROW_GROUP_MIGHT_MATCH
+    }
+
+    fn is_nan(&mut self, _reference: &BoundReference, _predicate: &BoundPredicate) -> Result<bool> {
+        ROW_GROUP_MIGHT_MATCH
+    }
+
+    fn not_nan(
+        &mut self,
+        _reference: &BoundReference,
+        _predicate: &BoundPredicate,
+    ) -> Result<bool> {
+        ROW_GROUP_MIGHT_MATCH
+    }
+
+    fn less_than(
+        &mut self,
+        _reference: &BoundReference,
+        _datum: &Datum,
+        _predicate: &BoundPredicate,
+    ) -> Result<bool> {
+        ROW_GROUP_MIGHT_MATCH
+    }
+
+    fn less_than_or_eq(
+        &mut self,
+        _reference: &BoundReference,
+        _datum: &Datum,
+        _predicate: &BoundPredicate,
+    ) -> Result<bool> {
+        ROW_GROUP_MIGHT_MATCH
+    }
+
+    fn greater_than(
+        &mut self,
+        _reference: &BoundReference,
+        _datum: &Datum,
+        _predicate: &BoundPredicate,
+    ) -> Result<bool> {
+        ROW_GROUP_MIGHT_MATCH
+    }
+
+    fn greater_than_or_eq(
+        &mut self,
+        _reference: &BoundReference,
+        _datum: &Datum,
+        _predicate: &BoundPredicate,
+    ) -> Result<bool> {
+        ROW_GROUP_MIGHT_MATCH
+    }
+
+    fn eq(
+        &mut self,
+        reference: &BoundReference,
+        datum: &Datum,
+        _predicate: &BoundPredicate,
+    ) -> Result<bool> {
+        Ok(self.might_contain(reference.field().id, datum))
+    }
+
+    fn not_eq(
+        &mut self,
+        _reference: &BoundReference,
+        _datum: &Datum,
+        _predicate: &BoundPredicate,
+    ) -> Result<bool> {
+        ROW_GROUP_MIGHT_MATCH
+    }
+
+    fn starts_with(
+        &mut self,
+        _reference: &BoundReference,
+        _datum: &Datum,
+        _predicate: &BoundPredicate,
+    ) -> Result<bool> {
+        ROW_GROUP_MIGHT_MATCH
+    }
+
+    fn not_starts_with(
+        &mut self,
+        _reference: &BoundReference,
+        _datum: &Datum,
+        _predicate: &BoundPredicate,
+    ) -> Result<bool> {
+        ROW_GROUP_MIGHT_MATCH
+    }
+
+    fn r#in(
+        &mut self,
+        reference: &BoundReference,
+        literals: &FnvHashSet<Datum>,
+        _predicate: &BoundPredicate,
+    ) -> Result<bool> {
+        let field_id = reference.field().id;
+        Ok(literals
+            .iter()
+            .any(|datum| self.might_contain(field_id, datum)))
+    }
+
+    fn not_in(
+        &mut self,
+        _reference: &BoundReference,
+        _literals: &FnvHashSet<Datum>,
+        _predicate: &BoundPredicate,
+    ) -> Result<bool> {
+        ROW_GROUP_MIGHT_MATCH
+    }
+}
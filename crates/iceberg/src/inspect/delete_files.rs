@@ -0,0 +1,374 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::VecDeque;
+
+use futures::StreamExt;
+use futures::stream::BoxStream;
+
+use crate::io::FileIO;
+use crate::metadata_columns::RESERVED_FIELD_ID_DELETE_FILE_PATH;
+use crate::spec::{
+    DataContentType, DataFile, ManifestContentType, ManifestFile, PrimitiveLiteral, SnapshotRef,
+    TableMetadataRef,
+};
+use crate::table::Table;
+use crate::{Error, ErrorKind, Result};
+
+/// A stream of the delete files of a snapshot, see [`delete_files`].
+pub type DeleteFileStream = BoxStream<'static, Result<DeleteFileDescriptor>>;
+
+/// A live delete file of a snapshot.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeleteFileDescriptor {
+    path: String,
+    delete_file: DataFile,
+    sequence_number: Option<i64>,
+    file_sequence_number: Option<i64>,
+}
+
+impl DeleteFileDescriptor {
+    /// The location of the delete file.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Whether the file holds position or equality deletes.
+    pub fn content_type(&self) -> DataContentType {
+        self.delete_file.content_type()
+    }
+
+    /// The data file whose rows are deleted by a position delete file, when it only deletes
+    /// rows of a single data file.
+    ///
+    /// This is the referenced data file of the delete file, or when it is not set, the data
+    /// file path the column metrics of the delete file are bounded to.
+    pub fn referenced_data_file(&self) -> Option<String> {
+        if self.content_type() != DataContentType::PositionDeletes {
+            return None;
+        }
+        if let Some(referenced_data_file) = self.delete_file.referenced_data_file() {
+            return Some(referenced_data_file);
+        }
+        let lower = self
+            .delete_file
+            .lower_bounds()
+            .get(&RESERVED_FIELD_ID_DELETE_FILE_PATH)?;
+        let upper = self
+            .delete_file
+            .upper_bounds()
+            .get(&RESERVED_FIELD_ID_DELETE_FILE_PATH)?;
+        match (lower.literal(), upper.literal()) {
+            (PrimitiveLiteral::String(lower), PrimitiveLiteral::String(upper))
+                if lower == upper =>
+            {
+                Some(lower.clone())
+            }
+            _ => None,
+        }
+    }
+
+    /// The ids of the fields compared by an equality delete file.
+    pub fn equality_ids(&self) -> Option<Vec<i32>> {
+        match self.content_type() {
+            DataContentType::EqualityDeletes => self.delete_file.equality_ids(),
+            _ => None,
+        }
+    }
+
+    /// The data sequence number of the delete file.
+    ///
+    /// Position deletes apply to data files with a data sequence number lower than or equal
+    /// to it, and equality deletes to data files with a lower one.
+    pub fn sequence_number(&self) -> Option<i64> {
+        self.sequence_number
+    }
+
+    /// The sequence number of the snapshot that added the delete file.
+    pub fn file_sequence_number(&self) -> Option<i64> {
+        self.file_sequence_number
+    }
+
+    /// The number of deletes in the file.
+    pub fn record_count(&self) -> u64 {
+        self.delete_file.record_count()
+    }
+
+    /// The delete file as recorded in its manifest.
+    pub fn delete_file(&self) -> &DataFile {
+        &self.delete_file
+    }
+}
+
+/// Lists the live position and equality delete files of the snapshot `snapshot_id`, without
+/// reading any data or delete file.
+///
+/// Only the delete manifests of the snapshot are read, lazily as the stream is polled.
+pub fn delete_files(table: &Table, snapshot_id: i64) -> Result<DeleteFileStream> {
+    let snapshot = table
+        .metadata()
+        .snapshot_by_id(snapshot_id)
+        .ok_or_else(|| {
+            Error::new(
+                ErrorKind::DataInvalid,
+                format!("Snapshot {snapshot_id} not found"),
+            )
+        })?
+        .clone();
+    let state = DeleteFilesState {
+        file_io: table.file_io().clone(),
+        metadata: table.metadata_ref(),
+        snapshot: Some(snapshot),
+        manifests: VecDeque::new(),
+        pending: VecDeque::new(),
+    };
+
+    Ok(futures::stream::try_unfold(state, |mut state| async move {
+        Ok(state.next().await?.map(|file| (file, state)))
+    })
+    .boxed())
+}
+
+struct DeleteFilesState {
+    file_io: FileIO,
+    metadata: TableMetadataRef,
+    snapshot: Option<SnapshotRef>,
+    manifests: VecDeque<ManifestFile>,
+    pending: VecDeque<DeleteFileDescriptor>,
+}
+
+impl DeleteFilesState {
+    async fn next(&mut self) -> Result<Option<DeleteFileDescriptor>> {
+        loop {
+            if let Some(file) = self.pending.pop_front() {
+                return Ok(Some(file));
+            }
+
+            if let Some(manifest_file) = self.manifests.pop_front() {
+                let manifest = manifest_file
+                    .load_manifest(&self.file_io, &self.metadata)
+                    .await?;
+                for entry in manifest.entries().iter().filter(|entry| entry.is_alive()) {
+                    if entry.content_type() == DataContentType::Data {
+                        continue;
+                    }
+                    self.pending.push_back(DeleteFileDescriptor {
+                        path: self.metadata.resolve_location(entry.file_path()),
+                        delete_file: entry.data_file().clone(),
+                        sequence_number: entry.sequence_number(),
+                        file_sequence_number: entry.file_sequence_number,
+                    });
+                }
+                continue;
+            }
+
+            let Some(snapshot) = self.snapshot.take() else {
+                return Ok(None);
+            };
+            let manifest_list = snapshot
+                .load_manifest_list(&self.file_io, &self.metadata)
+                .await?;
+            self.manifests.extend(
+                manifest_list
+                    .entries()
+                    .iter()
+                    .filter(|manifest_file| manifest_file.content == ManifestContentType::Deletes)
+                    .cloned(),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use futures::TryStreamExt;
+
+    use crate::metadata_columns::RESERVED_FIELD_ID_DELETE_FILE_PATH;
+    use crate::scan::tests::TableTestFixture;
+    use crate::spec::{
+        DataContentType, DataFile, DataFileBuilder, DataFileFormat, Datum, Literal, ManifestEntry,
+        ManifestListWriter, ManifestStatus, ManifestWriterBuilder, Struct,
+    };
+
+    fn file(content: DataContentType, path: &str) -> DataFileBuilder {
+        let mut builder = DataFileBuilder::default();
+        builder
+            .content(content)
+            .file_path(path.to_string())
+            .file_format(DataFileFormat::Parquet)
+            .file_size_in_bytes(100)
+            .record_count(2)
+            .partition_spec_id(0)
+            .partition(Struct::from_iter([Some(Literal::long(100))]));
+        builder
+    }
+
+    fn added_entry(data_file: DataFile) -> ManifestEntry {
+        ManifestEntry::builder()
+            .status(ManifestStatus::Added)
+            .data_file(data_file)
+            .build()
+    }
+
+    #[tokio::test]
+    async fn test_delete_files_of_snapshot() {
+        let fixture = TableTestFixture::new();
+        let table = &fixture.table;
+        let snapshot = table.metadata().current_snapshot().unwrap();
+        let schema = snapshot.schema(table.metadata()).unwrap();
+        let partition_spec = table.metadata().default_partition_spec().as_ref().clone();
+        let location = &fixture.table_location;
+        let data_file_path = format!("{location}/data/1.parquet");
+        let manifest_output = |name: &str| {
+            table
+                .file_io()
+                .new_output(format!("{location}/metadata/{name}.avro"))
+                .unwrap()
+        };
+
+        let mut writer = ManifestWriterBuilder::new(
+            manifest_output("data-manifest"),
+            Some(snapshot.snapshot_id()),
+            None,
+            schema.clone(),
+            partition_spec.clone(),
+        )
+        .build_v2_data();
+        writer
+            .add_entry(added_entry(
+                file(DataContentType::Data, &data_file_path)
+                    .build()
+                    .unwrap(),
+            ))
+            .unwrap();
+        let data_manifest = writer.write_manifest_file().await.unwrap();
+
+        // The position deletes only reference the data file through their column metrics.
+        let path_bound = HashMap::from([(
+            RESERVED_FIELD_ID_DELETE_FILE_PATH,
+            Datum::string(&data_file_path),
+        )]);
+        let position_deletes = file(
+            DataContentType::PositionDeletes,
+            &format!("{location}/data/pos-deletes.parquet"),
+        )
+        .lower_bounds(path_bound.clone())
+        .upper_bounds(path_bound)
+        .build()
+        .unwrap();
+        let equality_deletes = file(
+            DataContentType::EqualityDeletes,
+            &format!("{location}/data/eq-deletes.parquet"),
+        )
+        .equality_ids(Some(vec![1, 2]))
+        .build()
+        .unwrap();
+        let removed_deletes = file(
+            DataContentType::EqualityDeletes,
+            &format!("{location}/data/removed-deletes.parquet"),
+        )
+        .equality_ids(Some(vec![1]))
+        .build()
+        .unwrap();
+        let mut writer = ManifestWriterBuilder::new(
+            manifest_output("delete-manifest"),
+            Some(snapshot.snapshot_id()),
+            None,
+            schema,
+            partition_spec,
+        )
+        .build_v2_deletes();
+        writer
+            .add_existing_file(
+                position_deletes,
+                snapshot.parent_snapshot_id().unwrap(),
+                0,
+                Some(0),
+            )
+            .unwrap();
+        writer.add_entry(added_entry(equality_deletes)).unwrap();
+        writer.add_delete_file(removed_deletes, 0, Some(0)).unwrap();
+        let delete_manifest = writer.write_manifest_file().await.unwrap();
+
+        let mut manifest_list_writer = ManifestListWriter::v2(
+            table
+                .file_io()
+                .new_output(snapshot.manifest_list())
+                .unwrap(),
+            snapshot.snapshot_id(),
+            snapshot.parent_snapshot_id(),
+            snapshot.sequence_number(),
+        );
+        manifest_list_writer
+            .add_manifests(vec![data_manifest, delete_manifest].into_iter())
+            .unwrap();
+        manifest_list_writer.close().await.unwrap();
+
+        let delete_files: Vec<_> = table
+            .delete_files(snapshot.snapshot_id())
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(delete_files.len(), 2);
+
+        let position_deletes = &delete_files[0];
+        assert_eq!(
+            position_deletes.path(),
+            format!("{location}/data/pos-deletes.parquet")
+        );
+        assert_eq!(
+            position_deletes.content_type(),
+            DataContentType::PositionDeletes
+        );
+        assert_eq!(
+            position_deletes.referenced_data_file(),
+            Some(data_file_path)
+        );
+        assert_eq!(position_deletes.equality_ids(), None);
+        assert_eq!(position_deletes.sequence_number(), Some(0));
+        assert_eq!(position_deletes.file_sequence_number(), Some(0));
+        assert_eq!(position_deletes.record_count(), 2);
+
+        let equality_deletes = &delete_files[1];
+        assert_eq!(
+            equality_deletes.path(),
+            format!("{location}/data/eq-deletes.parquet")
+        );
+        assert_eq!(
+            equality_deletes.content_type(),
+            DataContentType::EqualityDeletes
+        );
+        assert_eq!(equality_deletes.referenced_data_file(), None);
+        assert_eq!(equality_deletes.equality_ids(), Some(vec![1, 2]));
+        assert_eq!(
+            equality_deletes.sequence_number(),
+            Some(snapshot.sequence_number())
+        );
+        assert_eq!(
+            equality_deletes.file_sequence_number(),
+            Some(snapshot.sequence_number())
+        );
+
+        let Err(err) = table.delete_files(42) else {
+            panic!("listing the delete files of a missing snapshot should fail");
+        };
+        assert_eq!(err.message(), "Snapshot 42 not found");
+    }
+}
@@ -15,12 +15,24 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! Metadata table APIs.
+//! Metadata table and table inspection APIs.
 
+mod delete_files;
 mod manifests;
 mod metadata_table;
+mod reachable_files;
+mod schema_history;
+mod snapshot_diff;
 mod snapshots;
+mod sort_order_summary;
+mod table_summary;
 
+pub use delete_files::{DeleteFileDescriptor, DeleteFileStream, delete_files};
 pub use manifests::ManifestsTable;
 pub use metadata_table::*;
+pub use reachable_files::{ReachableFile, ReachableFileStream, ReachableFileType, reachable_files};
+pub use schema_history::{SchemaHistoryEntry, schema_history};
+pub use snapshot_diff::{SnapshotDiff, snapshot_diff};
 pub use snapshots::SnapshotsTable;
+pub use sort_order_summary::{DataFileSortOrder, SortOrderSummary, sort_order_summary};
+pub use table_summary::{TableSummary, table_summary};
@@ -0,0 +1,271 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::{HashSet, VecDeque};
+
+use futures::StreamExt;
+use futures::stream::BoxStream;
+
+use crate::Result;
+use crate::io::FileIO;
+use crate::spec::{DataContentType, ManifestFile, SnapshotRef, TableMetadataRef};
+use crate::table::Table;
+
+/// A stream of the files reachable from a table, see [`reachable_files`].
+pub type ReachableFileStream = BoxStream<'static, Result<ReachableFile>>;
+
+/// The kind of a file reachable from a table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReachableFileType {
+    /// A table metadata JSON file.
+    Metadata,
+    /// A manifest list of a snapshot.
+    ManifestList,
+    /// A manifest file.
+    Manifest,
+    /// A data file.
+    Data,
+    /// A position or equality delete file.
+    Delete,
+}
+
+/// A file reachable from a table.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ReachableFile {
+    path: String,
+    file_type: ReachableFileType,
+}
+
+impl ReachableFile {
+    /// The location of the file.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The kind of the file.
+    pub fn file_type(&self) -> ReachableFileType {
+        self.file_type
+    }
+}
+
+/// Lists every file referenced by the table: the current and previous metadata files, and
+/// the manifest lists, manifests, data and delete files of all snapshots.
+///
+/// Each file is returned once, even when it is shared by several snapshots. Manifests are
+/// loaded lazily as the stream is polled, so large tables are not held in memory at once.
+pub fn reachable_files(table: &Table) -> ReachableFileStream {
+    let mut state = ReachableFilesState {
+        file_io: table.file_io().clone(),
+        metadata: table.metadata_ref(),
+        snapshots: table.metadata().snapshots().cloned().collect(),
+        manifests: VecDeque::new(),
+        pending: VecDeque::new(),
+        seen: HashSet::new(),
+    };
+
+    let metadata_files = table
+        .metadata_location()
+        .map(str::to_string)
+        .into_iter()
+        .chain(
+            table
+                .metadata()
+                .metadata_log()
+                .iter()
+                .map(|log| log.metadata_file.clone()),
+        )
+        .collect::<Vec<_>>();
+    for path in metadata_files {
+        state.push(path, ReachableFileType::Metadata);
+    }
+
+    futures::stream::try_unfold(state, |mut state| async move {
+        Ok(state.next().await?.map(|file| (file, state)))
+    })
+    .boxed()
+}
+
+struct ReachableFilesState {
+    file_io: FileIO,
+    metadata: TableMetadataRef,
+    snapshots: VecDeque<SnapshotRef>,
+    manifests: VecDeque<ManifestFile>,
+    pending: VecDeque<ReachableFile>,
+    seen: HashSet<String>,
+}
+
+impl ReachableFilesState {
+    fn push(&mut self, path: String, file_type: ReachableFileType) -> bool {
+        if !self.seen.insert(path.clone()) {
+            return false;
+        }
+        self.pending.push_back(ReachableFile { path, file_type });
+        true
+    }
+
+    async fn next(&mut self) -> Result<Option<ReachableFile>> {
+        loop {
+            if let Some(file) = self.pending.pop_front() {
+                return Ok(Some(file));
+            }
+
+            if let Some(manifest_file) = self.manifests.pop_front() {
+                let manifest = manifest_file
+                    .load_manifest(&self.file_io, &self.metadata)
+                    .await?;
+                for entry in manifest.entries().iter().filter(|entry| entry.is_alive()) {
+                    let file_type = match entry.content_type() {
+                        DataContentType::Data => ReachableFileType::Data,
+                        DataContentType::PositionDeletes | DataContentType::EqualityDeletes => {
+                            ReachableFileType::Delete
+                        }
+                    };
+                    self.push(self.metadata.resolve_location(entry.file_path()), file_type);
+                }
+                continue;
+            }
+
+            let Some(snapshot) = self.snapshots.pop_front() else {
+                return Ok(None);
+            };
+            // A v1 snapshot listing its manifests inline has no manifest list file.
+            if snapshot.manifests().is_none()
+                && !self.push(
+                    self.metadata.resolve_location(snapshot.manifest_list()),
+                    ReachableFileType::ManifestList,
+                )
+            {
+                continue;
+            }
+            let manifest_list = snapshot
+                .load_manifest_list(&self.file_io, &self.metadata)
+                .await?;
+            for manifest_file in manifest_list.entries() {
+                if self.push(
+                    self.metadata.resolve_location(&manifest_file.manifest_path),
+                    ReachableFileType::Manifest,
+                ) {
+                    self.manifests.push_back(manifest_file.clone());
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use futures::TryStreamExt;
+
+    use super::ReachableFileType;
+    use crate::memory::tests::new_memory_catalog;
+    use crate::spec::{NestedField, PrimitiveType, Schema, Type};
+    use crate::test_utils::fixtures::data_file;
+    use crate::transaction::{ApplyTransactionAction, Transaction};
+    use crate::{Catalog, NamespaceIdent, TableCreation};
+
+    #[tokio::test]
+    async fn test_reachable_files_of_two_snapshots() {
+        let catalog = new_memory_catalog().await;
+        let namespace = NamespaceIdent::new("ns".to_string());
+        catalog
+            .create_namespace(&namespace, HashMap::new())
+            .await
+            .unwrap();
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+            ])
+            .build()
+            .unwrap();
+        let mut table = catalog
+            .create_table(
+                &namespace,
+                TableCreation::builder()
+                    .name("t".to_string())
+                    .schema(schema)
+                    .build(),
+            )
+            .await
+            .unwrap();
+
+        for path in ["data/1.parquet", "data/2.parquet"] {
+            let tx = Transaction::new(&table);
+            let tx = tx
+                .fast_append()
+                .add_data_files(vec![data_file(path, 1)])
+                .apply(tx)
+                .unwrap();
+            table = tx.commit(&catalog).await.unwrap();
+        }
+
+        let files: Vec<_> = table.reachable_files().try_collect().await.unwrap();
+        let paths_of = |file_type| {
+            let mut paths = files
+                .iter()
+                .filter(|file| file.file_type() == file_type)
+                .map(|file| file.path().to_string())
+                .collect::<Vec<_>>();
+            paths.sort();
+            paths
+        };
+
+        // The second snapshot reuses the manifest of the first one.
+        let mut expected_manifests = vec![];
+        let mut expected_manifest_lists = vec![];
+        for snapshot in table.metadata().snapshots() {
+            expected_manifest_lists.push(snapshot.manifest_list().to_string());
+            let manifest_list = snapshot
+                .load_manifest_list(table.file_io(), table.metadata())
+                .await
+                .unwrap();
+            for manifest_file in manifest_list.entries() {
+                if !expected_manifests.contains(&manifest_file.manifest_path) {
+                    expected_manifests.push(manifest_file.manifest_path.clone());
+                }
+            }
+        }
+        expected_manifests.sort();
+        expected_manifest_lists.sort();
+
+        assert_eq!(expected_manifest_lists.len(), 2);
+        assert_eq!(expected_manifests.len(), 2);
+        assert_eq!(
+            paths_of(ReachableFileType::ManifestList),
+            expected_manifest_lists
+        );
+        assert_eq!(paths_of(ReachableFileType::Manifest), expected_manifests);
+        // Relative data file paths are resolved against the table location.
+        let location = table.metadata().location();
+        assert_eq!(paths_of(ReachableFileType::Data), vec![
+            format!("{location}/data/1.parquet"),
+            format!("{location}/data/2.parquet")
+        ]);
+        assert!(paths_of(ReachableFileType::Delete).is_empty());
+        assert!(
+            paths_of(ReachableFileType::Metadata)
+                .contains(&table.metadata_location().unwrap().to_string())
+        );
+
+        let mut all_paths = files.iter().map(|file| file.path()).collect::<Vec<_>>();
+        let total = all_paths.len();
+        all_paths.sort();
+        all_paths.dedup();
+        assert_eq!(all_paths.len(), total);
+    }
+}
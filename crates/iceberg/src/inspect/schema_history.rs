@@ -0,0 +1,154 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::spec::{SchemaId, SchemaRef};
+use crate::table::Table;
+
+/// A schema of a table along with the first snapshot written with it, see
+/// [`schema_history`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaHistoryEntry {
+    schema: SchemaRef,
+    first_snapshot_id: Option<i64>,
+}
+
+impl SchemaHistoryEntry {
+    /// Id of the schema.
+    pub fn schema_id(&self) -> SchemaId {
+        self.schema.schema_id()
+    }
+
+    /// The schema.
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    /// Id of the earliest snapshot of the table written with the schema, if any.
+    pub fn first_snapshot_id(&self) -> Option<i64> {
+        self.first_snapshot_id
+    }
+}
+
+/// Returns every schema of `table`, ordered by schema id, with the first snapshot that used
+/// each of them.
+///
+/// The first snapshot of a schema is the earliest snapshot still in the table metadata, by
+/// commit time, recording the schema as its schema id. It is unknown for schemas no snapshot
+/// was written with, for schemas whose snapshots were all expired, and for snapshots of v1
+/// tables that don't record their schema.
+pub fn schema_history(table: &Table) -> Vec<SchemaHistoryEntry> {
+    let metadata = table.metadata();
+    let mut snapshots: Vec<_> = metadata.snapshots().collect();
+    snapshots.sort_by_key(|snapshot| (snapshot.timestamp_ms(), snapshot.sequence_number()));
+
+    let mut history: Vec<SchemaHistoryEntry> = metadata
+        .schemas_iter()
+        .map(|schema| SchemaHistoryEntry {
+            schema: schema.clone(),
+            first_snapshot_id: snapshots
+                .iter()
+                .find(|snapshot| snapshot.schema_id() == Some(schema.schema_id()))
+                .map(|snapshot| snapshot.snapshot_id()),
+        })
+        .collect();
+    history.sort_by_key(SchemaHistoryEntry::schema_id);
+    history
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::memory::tests::new_memory_catalog;
+    use crate::spec::{NestedField, PrimitiveType, Schema, Type};
+    use crate::test_utils::fixtures::data_file;
+    use crate::transaction::{ApplyTransactionAction, Transaction};
+    use crate::{Catalog, NamespaceIdent, TableCreation};
+
+    #[tokio::test]
+    async fn test_schema_history_lists_schemas_with_first_snapshot() {
+        let catalog = new_memory_catalog().await;
+        let namespace = NamespaceIdent::new("ns".to_string());
+        catalog
+            .create_namespace(&namespace, HashMap::new())
+            .await
+            .unwrap();
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+            ])
+            .build()
+            .unwrap();
+        let mut table = catalog
+            .create_table(
+                &namespace,
+                TableCreation::builder()
+                    .name("t".to_string())
+                    .schema(schema)
+                    .build(),
+            )
+            .await
+            .unwrap();
+
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .fast_append()
+            .add_data_files([data_file("data/1.parquet", 10)])
+            .apply(tx)
+            .unwrap();
+        table = tx.commit(&catalog).await.unwrap();
+        let first_snapshot_id = table.metadata().current_snapshot_id();
+
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .update_schema()
+            .add_column("name", Type::Primitive(PrimitiveType::String))
+            .apply(tx)
+            .unwrap();
+        table = tx.commit(&catalog).await.unwrap();
+        // No snapshot was written with the new schema yet.
+        let history = table.schema_history();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[1].schema_id(), 1);
+        assert_eq!(history[1].first_snapshot_id(), None);
+
+        // Two snapshots with the new schema, the first one is reported.
+        let mut second_snapshot_id = None;
+        for path in ["data/2.parquet", "data/3.parquet"] {
+            let tx = Transaction::new(&table);
+            let tx = tx
+                .fast_append()
+                .add_data_files([data_file(path, 10)])
+                .apply(tx)
+                .unwrap();
+            table = tx.commit(&catalog).await.unwrap();
+            second_snapshot_id = second_snapshot_id.or(table.metadata().current_snapshot_id());
+        }
+
+        let history = table.schema_history();
+        assert_eq!(
+            history
+                .iter()
+                .map(|entry| (entry.schema_id(), entry.first_snapshot_id()))
+                .collect::<Vec<_>>(),
+            vec![(0, first_snapshot_id), (1, second_snapshot_id)]
+        );
+        assert_eq!(history[0].schema().as_struct().fields().len(), 1);
+        assert_eq!(history[1].schema().as_struct().fields().len(), 2);
+        assert_eq!(history[1].schema(), table.metadata().current_schema());
+    }
+}
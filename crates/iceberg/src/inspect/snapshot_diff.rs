@@ -0,0 +1,239 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::BTreeMap;
+
+use crate::spec::{DataContentType, DataFile, SnapshotRef};
+use crate::table::Table;
+use crate::{Error, ErrorKind, Result};
+
+/// The files that changed between two snapshots of a table.
+///
+/// Files are ordered by path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnapshotDiff {
+    added_data_files: Vec<DataFile>,
+    removed_data_files: Vec<DataFile>,
+    added_delete_files: Vec<DataFile>,
+}
+
+impl SnapshotDiff {
+    /// Data files that are live in the later snapshot but not in the earlier one.
+    pub fn added_data_files(&self) -> &[DataFile] {
+        &self.added_data_files
+    }
+
+    /// Data files that are live in the earlier snapshot but not in the later one.
+    pub fn removed_data_files(&self) -> &[DataFile] {
+        &self.removed_data_files
+    }
+
+    /// Delete files that are live in the later snapshot but not in the earlier one.
+    pub fn added_delete_files(&self) -> &[DataFile] {
+        &self.added_delete_files
+    }
+}
+
+/// Computes the files that changed from snapshot `from_snapshot_id` to snapshot
+/// `to_snapshot_id`, by comparing the live files listed in their manifests.
+///
+/// The snapshots don't need to be adjacent, but `from_snapshot_id` must be `to_snapshot_id`
+/// or one of its ancestors.
+pub async fn snapshot_diff(
+    table: &Table,
+    from_snapshot_id: i64,
+    to_snapshot_id: i64,
+) -> Result<SnapshotDiff> {
+    let from_snapshot = snapshot(table, from_snapshot_id)?;
+    let to_snapshot = snapshot(table, to_snapshot_id)?;
+
+    if !is_ancestor_of(table, from_snapshot_id, to_snapshot_id) {
+        return Err(Error::new(
+            ErrorKind::DataInvalid,
+            format!("Snapshot {from_snapshot_id} is not an ancestor of snapshot {to_snapshot_id}"),
+        ));
+    }
+
+    let mut from_files = live_files(table, from_snapshot).await?;
+    let to_files = live_files(table, to_snapshot).await?;
+
+    let mut added_data_files = vec![];
+    let mut added_delete_files = vec![];
+    for (path, data_file) in to_files {
+        if from_files.remove(&path).is_some() {
+            continue;
+        }
+        match data_file.content_type() {
+            DataContentType::Data => added_data_files.push(data_file),
+            DataContentType::PositionDeletes | DataContentType::EqualityDeletes => {
+                added_delete_files.push(data_file)
+            }
+        }
+    }
+    let removed_data_files = from_files
+        .into_values()
+        .filter(|data_file| data_file.content_type() == DataContentType::Data)
+        .collect();
+
+    Ok(SnapshotDiff {
+        added_data_files,
+        removed_data_files,
+        added_delete_files,
+    })
+}
+
+fn snapshot(table: &Table, snapshot_id: i64) -> Result<&SnapshotRef> {
+    table.metadata().snapshot_by_id(snapshot_id).ok_or_else(|| {
+        Error::new(
+            ErrorKind::DataInvalid,
+            format!("Snapshot {snapshot_id} not found"),
+        )
+    })
+}
+
+fn is_ancestor_of(table: &Table, ancestor_id: i64, snapshot_id: i64) -> bool {
+    table
+        .metadata()
+        .ancestors_of(snapshot_id)
+        .any(|snapshot| snapshot.snapshot_id() == ancestor_id)
+}
+
+async fn live_files(table: &Table, snapshot: &SnapshotRef) -> Result<BTreeMap<String, DataFile>> {
+    let manifest_list = snapshot
+        .load_manifest_list(table.file_io(), table.metadata())
+        .await?;
+
+    let mut files = BTreeMap::new();
+    for manifest_file in manifest_list.entries() {
+        let manifest = manifest_file
+            .load_manifest(table.file_io(), table.metadata())
+            .await?;
+        for entry in manifest.entries().iter().filter(|entry| entry.is_alive()) {
+            files.insert(entry.file_path().to_string(), entry.data_file().clone());
+        }
+    }
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::snapshot_diff;
+    use crate::memory::tests::new_memory_catalog;
+    use crate::spec::{DataFile, NestedField, PrimitiveType, Schema, Type};
+    use crate::table::Table;
+    use crate::test_utils::fixtures::data_file;
+    use crate::transaction::{ApplyTransactionAction, Transaction};
+    use crate::{Catalog, ErrorKind, NamespaceIdent, TableCreation};
+
+    fn paths(files: &[DataFile]) -> Vec<&str> {
+        files.iter().map(|file| file.file_path()).collect()
+    }
+
+    async fn create_table(catalog: &impl Catalog) -> Table {
+        let namespace = NamespaceIdent::new("ns".to_string());
+        catalog
+            .create_namespace(&namespace, HashMap::new())
+            .await
+            .unwrap();
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+            ])
+            .build()
+            .unwrap();
+        catalog
+            .create_table(
+                &namespace,
+                TableCreation::builder()
+                    .name("t".to_string())
+                    .schema(schema)
+                    .build(),
+            )
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_diff_append_then_overwrite() {
+        let catalog = new_memory_catalog().await;
+        let table = create_table(&catalog).await;
+
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .fast_append()
+            .add_data_files(vec![
+                data_file("data/1.parquet", 2),
+                data_file("data/2.parquet", 1),
+            ])
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+        let append_snapshot_id = table.metadata().current_snapshot_id().unwrap();
+
+        // Overwrite data/1.parquet with data/3.parquet in two commits.
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .delete()
+            .delete_positions(data_file("data/1.parquet", 2), [0, 1])
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+        let delete_snapshot_id = table.metadata().current_snapshot_id().unwrap();
+
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .fast_append()
+            .add_data_files(vec![data_file("data/3.parquet", 2)])
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+        let overwrite_snapshot_id = table.metadata().current_snapshot_id().unwrap();
+
+        // Non-adjacent snapshots
+        let diff = snapshot_diff(&table, append_snapshot_id, overwrite_snapshot_id)
+            .await
+            .unwrap();
+        assert_eq!(paths(diff.added_data_files()), vec!["data/3.parquet"]);
+        assert_eq!(paths(diff.removed_data_files()), vec!["data/1.parquet"]);
+        assert!(diff.added_delete_files().is_empty());
+
+        // Adjacent snapshots
+        let diff = snapshot_diff(&table, append_snapshot_id, delete_snapshot_id)
+            .await
+            .unwrap();
+        assert!(diff.added_data_files().is_empty());
+        assert_eq!(paths(diff.removed_data_files()), vec!["data/1.parquet"]);
+
+        let diff = snapshot_diff(&table, overwrite_snapshot_id, overwrite_snapshot_id)
+            .await
+            .unwrap();
+        assert!(diff.added_data_files().is_empty());
+        assert!(diff.removed_data_files().is_empty());
+
+        let err = snapshot_diff(&table, overwrite_snapshot_id, append_snapshot_id)
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::DataInvalid);
+
+        let err = snapshot_diff(&table, append_snapshot_id, 42)
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::DataInvalid);
+    }
+}
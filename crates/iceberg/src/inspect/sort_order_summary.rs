@@ -0,0 +1,266 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::Result;
+use crate::spec::{DataContentType, SortOrder, SortOrderRef};
+use crate::table::Table;
+
+/// A live data file and the sort order it was written with, see [`sort_order_summary`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataFileSortOrder {
+    file_path: String,
+    sort_order: Option<SortOrderRef>,
+}
+
+impl DataFileSortOrder {
+    /// Path of the data file.
+    pub fn file_path(&self) -> &str {
+        &self.file_path
+    }
+
+    /// The sort order of the table the file was written with, `None` when the file has no
+    /// sort order id or an unknown one.
+    pub fn sort_order(&self) -> Option<&SortOrderRef> {
+        self.sort_order.as_ref()
+    }
+
+    /// Id of the sort order the file was written with, the id of the unsorted order for a
+    /// file without a known sort order.
+    pub fn sort_order_id(&self) -> i64 {
+        self.sort_order
+            .as_ref()
+            .map_or(SortOrder::UNSORTED_ORDER_ID, |sort_order| {
+                sort_order.order_id
+            })
+    }
+}
+
+/// The sort orders of the live data files of the current snapshot of a table, see
+/// [`sort_order_summary`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SortOrderSummary {
+    default_sort_order_id: i64,
+    data_files: Vec<DataFileSortOrder>,
+}
+
+impl SortOrderSummary {
+    /// The live data files with their sort order, in manifest order.
+    pub fn data_files(&self) -> &[DataFileSortOrder] {
+        &self.data_files
+    }
+
+    /// Number of live data files written with the current sort order of the table.
+    pub fn matching_data_files(&self) -> usize {
+        self.data_files
+            .iter()
+            .filter(|data_file| data_file.sort_order_id() == self.default_sort_order_id)
+            .count()
+    }
+
+    /// Fraction of the live data files written with the current sort order of the table,
+    /// `1.0` for a table without data files.
+    ///
+    /// Files written with an older sort order, or unsorted files of a sorted table, are
+    /// candidates for a compaction sorting them.
+    pub fn matching_fraction(&self) -> f64 {
+        if self.data_files.is_empty() {
+            return 1.0;
+        }
+        self.matching_data_files() as f64 / self.data_files.len() as f64
+    }
+}
+
+/// Returns the sort order each live data file of the current snapshot of `table` was written
+/// with, resolved against the sort orders of the table.
+///
+/// Only metadata is read. A table without any snapshot has no data files.
+pub async fn sort_order_summary(table: &Table) -> Result<SortOrderSummary> {
+    let metadata = table.metadata();
+    let mut summary = SortOrderSummary {
+        default_sort_order_id: metadata.default_sort_order_id(),
+        data_files: vec![],
+    };
+    let Some(snapshot) = metadata.current_snapshot() else {
+        return Ok(summary);
+    };
+
+    let manifest_list = snapshot
+        .load_manifest_list(table.file_io(), &table.metadata_ref())
+        .await?;
+    for manifest_file in manifest_list.entries() {
+        let manifest = manifest_file
+            .load_manifest(table.file_io(), table.metadata())
+            .await?;
+        summary.data_files.extend(
+            manifest
+                .entries()
+                .iter()
+                .filter(|entry| entry.is_alive() && entry.content_type() == DataContentType::Data)
+                .map(|entry| DataFileSortOrder {
+                    file_path: entry.file_path().to_string(),
+                    sort_order: metadata.data_file_sort_order(entry.data_file()).cloned(),
+                }),
+        );
+    }
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use futures::TryStreamExt;
+
+    use crate::memory::tests::new_memory_catalog;
+    use crate::spec::{DataFile, NestedField, NullOrder, PrimitiveType, Schema, Type};
+    use crate::test_utils::fixtures::data_file;
+    use crate::transaction::{ApplyTransactionAction, Transaction};
+    use crate::{Catalog, NamespaceIdent, TableCreation};
+
+    #[tokio::test]
+    async fn test_sort_order_summary_resolves_sort_orders_of_files() {
+        let catalog = new_memory_catalog().await;
+        let namespace = NamespaceIdent::new("ns".to_string());
+        catalog
+            .create_namespace(&namespace, HashMap::new())
+            .await
+            .unwrap();
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+                NestedField::required(2, "ts", Type::Primitive(PrimitiveType::Long)).into(),
+            ])
+            .build()
+            .unwrap();
+        let mut table = catalog
+            .create_table(
+                &namespace,
+                TableCreation::builder()
+                    .name("t".to_string())
+                    .schema(schema)
+                    .build(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            table
+                .sort_order_summary()
+                .await
+                .unwrap()
+                .matching_fraction(),
+            1.0
+        );
+
+        // Files sorted by id, then files sorted by ts once the sort order of the table changed.
+        let mut sort_order_ids = vec![];
+        for (column, paths) in [
+            ("id", vec!["1.parquet"]),
+            ("ts", vec!["2.parquet", "3.parquet"]),
+        ] {
+            let tx = Transaction::new(&table);
+            let tx = tx
+                .replace_sort_order()
+                .asc(column, NullOrder::First)
+                .apply(tx)
+                .unwrap();
+            table = tx.commit(&catalog).await.unwrap();
+            let sort_order_id = table.metadata().default_sort_order_id() as i32;
+            sort_order_ids.push(sort_order_id);
+
+            let files = paths
+                .into_iter()
+                .map(|path| {
+                    let path = format!("{}/data/{path}", table.metadata().location());
+                    DataFile {
+                        sort_order_id: Some(sort_order_id),
+                        ..data_file(path, 10)
+                    }
+                })
+                .collect::<Vec<_>>();
+            let tx = Transaction::new(&table);
+            let tx = tx.fast_append().add_data_files(files).apply(tx).unwrap();
+            table = tx.commit(&catalog).await.unwrap();
+        }
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .fast_append()
+            .add_data_files([data_file(
+                format!("{}/data/4.parquet", table.metadata().location()),
+                10,
+            )])
+            .apply(tx)
+            .unwrap();
+        table = tx.commit(&catalog).await.unwrap();
+        assert_eq!(table.metadata().sort_orders_iter().len(), 3);
+
+        let summary = table.sort_order_summary().await.unwrap();
+        let mut files: Vec<_> = summary
+            .data_files()
+            .iter()
+            .map(|data_file| {
+                (
+                    data_file
+                        .file_path()
+                        .rsplit('/')
+                        .next()
+                        .unwrap()
+                        .to_string(),
+                    data_file.sort_order_id(),
+                )
+            })
+            .collect();
+        files.sort();
+        let (by_id, by_ts) = (sort_order_ids[0] as i64, sort_order_ids[1] as i64);
+        assert_eq!(files, vec![
+            ("1.parquet".to_string(), by_id),
+            ("2.parquet".to_string(), by_ts),
+            ("3.parquet".to_string(), by_ts),
+            ("4.parquet".to_string(), 0),
+        ]);
+        let by_id_order = summary
+            .data_files()
+            .iter()
+            .find(|data_file| data_file.file_path().ends_with("1.parquet"))
+            .unwrap()
+            .sort_order()
+            .unwrap();
+        assert_eq!(by_id_order.fields[0].source_id, 1);
+        assert_eq!(summary.matching_data_files(), 2);
+        assert_eq!(summary.matching_fraction(), 0.5);
+
+        // The scan tasks resolve the same sort orders.
+        let tasks: Vec<_> = table
+            .scan()
+            .build()
+            .unwrap()
+            .plan_files()
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(tasks.len(), 4);
+        for task in tasks {
+            let expected = summary
+                .data_files()
+                .iter()
+                .find(|data_file| data_file.file_path() == task.data_file_path())
+                .unwrap();
+            assert_eq!(task.sort_order(table.metadata()), expected.sort_order());
+        }
+    }
+}
@@ -0,0 +1,209 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::Result;
+use crate::spec::{DataContentType, Summary};
+use crate::table::Table;
+
+const TOTAL_DATA_FILES: &str = "total-data-files";
+const TOTAL_DELETE_FILES: &str = "total-delete-files";
+const TOTAL_RECORDS: &str = "total-records";
+const TOTAL_FILE_SIZE: &str = "total-files-size";
+const TOTAL_POSITION_DELETES: &str = "total-position-deletes";
+const TOTAL_EQUALITY_DELETES: &str = "total-equality-deletes";
+
+/// The size and file counts of the current snapshot of a table, see [`table_summary`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TableSummary {
+    data_files: u64,
+    delete_files: u64,
+    records: u64,
+    files_size_in_bytes: u64,
+    position_deletes: u64,
+    equality_deletes: u64,
+}
+
+impl TableSummary {
+    /// Number of live data files.
+    pub fn data_files(&self) -> u64 {
+        self.data_files
+    }
+
+    /// Number of live position and equality delete files.
+    pub fn delete_files(&self) -> u64 {
+        self.delete_files
+    }
+
+    /// Number of records in the live data files, before applying deletes.
+    pub fn records(&self) -> u64 {
+        self.records
+    }
+
+    /// Total size in bytes of the live data and delete files.
+    pub fn files_size_in_bytes(&self) -> u64 {
+        self.files_size_in_bytes
+    }
+
+    /// Number of position deletes in the live position delete files.
+    pub fn position_deletes(&self) -> u64 {
+        self.position_deletes
+    }
+
+    /// Number of equality deletes in the live equality delete files.
+    pub fn equality_deletes(&self) -> u64 {
+        self.equality_deletes
+    }
+
+    /// Reads the totals tracked by a snapshot summary, if it tracks all of them.
+    fn from_snapshot_summary(summary: &Summary) -> Option<Self> {
+        let total = |property| summary.additional_properties.get(property)?.parse().ok();
+        Some(Self {
+            data_files: total(TOTAL_DATA_FILES)?,
+            delete_files: total(TOTAL_DELETE_FILES)?,
+            records: total(TOTAL_RECORDS)?,
+            files_size_in_bytes: total(TOTAL_FILE_SIZE)?,
+            position_deletes: total(TOTAL_POSITION_DELETES)?,
+            equality_deletes: total(TOTAL_EQUALITY_DELETES)?,
+        })
+    }
+}
+
+/// Returns the size and file counts of the current snapshot of `table`.
+///
+/// Only metadata is read: the totals are taken from the snapshot summary when it tracks all
+/// of them, and aggregated from the manifests of the snapshot otherwise. A table without any
+/// snapshot is empty.
+pub async fn table_summary(table: &Table) -> Result<TableSummary> {
+    let Some(snapshot) = table.metadata().current_snapshot() else {
+        return Ok(TableSummary::default());
+    };
+    match TableSummary::from_snapshot_summary(snapshot.summary()) {
+        Some(summary) => Ok(summary),
+        None => summary_from_manifests(table).await,
+    }
+}
+
+/// Aggregates the size and file counts of the current snapshot of `table` from its manifests.
+async fn summary_from_manifests(table: &Table) -> Result<TableSummary> {
+    let mut summary = TableSummary::default();
+    let Some(snapshot) = table.metadata().current_snapshot() else {
+        return Ok(summary);
+    };
+
+    let manifest_list = snapshot
+        .load_manifest_list(table.file_io(), &table.metadata_ref())
+        .await?;
+    for manifest_file in manifest_list.entries() {
+        let manifest = manifest_file
+            .load_manifest(table.file_io(), table.metadata())
+            .await?;
+        for entry in manifest.entries().iter().filter(|entry| entry.is_alive()) {
+            let data_file = entry.data_file();
+            summary.files_size_in_bytes += data_file.file_size_in_bytes();
+            match data_file.content_type() {
+                DataContentType::Data => {
+                    summary.data_files += 1;
+                    summary.records += data_file.record_count();
+                }
+                DataContentType::PositionDeletes => {
+                    summary.delete_files += 1;
+                    summary.position_deletes += data_file.record_count();
+                }
+                DataContentType::EqualityDeletes => {
+                    summary.delete_files += 1;
+                    summary.equality_deletes += data_file.record_count();
+                }
+            }
+        }
+    }
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::summary_from_manifests;
+    use crate::memory::tests::new_memory_catalog;
+    use crate::spec::{DataFile, NestedField, PrimitiveType, Schema, Type};
+    use crate::test_utils::fixtures::data_file;
+    use crate::transaction::{ApplyTransactionAction, Transaction};
+    use crate::{Catalog, NamespaceIdent, TableCreation};
+
+    #[tokio::test]
+    async fn test_table_summary_matches_live_files() {
+        let catalog = new_memory_catalog().await;
+        let namespace = NamespaceIdent::new("ns".to_string());
+        catalog
+            .create_namespace(&namespace, HashMap::new())
+            .await
+            .unwrap();
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+            ])
+            .build()
+            .unwrap();
+        let mut table = catalog
+            .create_table(
+                &namespace,
+                TableCreation::builder()
+                    .name("t".to_string())
+                    .schema(schema)
+                    .build(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(table.summary().await.unwrap().data_files(), 0);
+
+        let deleted_file = DataFile {
+            file_size_in_bytes: 100,
+            ..data_file("data/1.parquet", 10)
+        };
+        for files in [
+            vec![deleted_file.clone(), DataFile {
+                file_size_in_bytes: 200,
+                ..data_file("data/2.parquet", 20)
+            }],
+            vec![DataFile {
+                file_size_in_bytes: 300,
+                ..data_file("data/3.parquet", 30)
+            }],
+        ] {
+            let tx = Transaction::new(&table);
+            let tx = tx.fast_append().add_data_files(files).apply(tx).unwrap();
+            table = tx.commit(&catalog).await.unwrap();
+        }
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .delete()
+            .delete_positions(deleted_file, 0..10)
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+
+        let summary = table.summary().await.unwrap();
+        assert_eq!(summary.data_files(), 2);
+        assert_eq!(summary.delete_files(), 0);
+        assert_eq!(summary.records(), 50);
+        assert_eq!(summary.files_size_in_bytes(), 500);
+        assert_eq!(summary.position_deletes(), 0);
+        assert_eq!(summary.equality_deletes(), 0);
+
+        assert_eq!(summary_from_manifests(&table).await.unwrap(), summary);
+    }
+}
@@ -0,0 +1,135 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Verification of files against Hadoop checksum files.
+//!
+//! Hadoop's checksummed file systems, such as its local file system, write a checksum file
+//! `.<name>.crc` next to every file `<name>`. It holds the `crc\0` magic, the number of bytes
+//! per checksum as a big-endian 32-bit integer, and the big-endian CRC32 of every chunk of
+//! that many bytes of the file.
+
+use crate::io::FileIO;
+use crate::{Error, ErrorKind, Result};
+
+const CHECKSUM_FILE_MAGIC: &[u8; 4] = b"crc\0";
+const CHECKSUM_FILE_HEADER_LEN: usize = 8;
+
+/// Returns the path of the checksum file of the file `path`.
+pub(crate) fn checksum_file_path(path: &str) -> String {
+    match path.rsplit_once('/') {
+        Some((parent, name)) => format!("{parent}/.{name}.crc"),
+        None => format!(".{path}.crc"),
+    }
+}
+
+/// Verifies the file `path` against its checksum file.
+///
+/// Returns `false` without reading the file when it has no checksum file, and an error naming
+/// the file when its content does not match the checksums.
+pub(crate) async fn verify_checksum(file_io: &FileIO, path: &str) -> Result<bool> {
+    let checksum_path = checksum_file_path(path);
+    if !file_io.exists(&checksum_path).await? {
+        return Ok(false);
+    }
+
+    let checksum_file = file_io.new_input(&checksum_path)?.read().await?;
+    let bytes_per_checksum = match checksum_file.get(..CHECKSUM_FILE_HEADER_LEN) {
+        Some(header) if header.starts_with(CHECKSUM_FILE_MAGIC) => {
+            u32::from_be_bytes(header[4..].try_into().unwrap()) as usize
+        }
+        _ => 0,
+    };
+    let checksums = &checksum_file[CHECKSUM_FILE_HEADER_LEN.min(checksum_file.len())..];
+    if bytes_per_checksum == 0 || checksums.len() % 4 != 0 {
+        return Err(Error::new(
+            ErrorKind::DataInvalid,
+            format!("Invalid checksum file {checksum_path} of file {path}"),
+        ));
+    }
+
+    let content = file_io.new_input(path)?.read().await?;
+    let chunks = content.chunks(bytes_per_checksum);
+    let matches = chunks.len() == checksums.len() / 4
+        && chunks
+            .zip(checksums.chunks_exact(4))
+            .all(|(chunk, checksum)| crc32fast::hash(chunk).to_be_bytes() == checksum);
+    if !matches {
+        return Err(Error::new(
+            ErrorKind::DataInvalid,
+            format!("Checksum mismatch of file {path}, it may be corrupted"),
+        ));
+    }
+    Ok(true)
+}
+
+/// Returns the content of the checksum file of a file with content `content`.
+#[cfg(test)]
+pub(crate) fn checksum_file(content: &[u8], bytes_per_checksum: u32) -> Vec<u8> {
+    let mut checksum_file = CHECKSUM_FILE_MAGIC.to_vec();
+    checksum_file.extend_from_slice(&bytes_per_checksum.to_be_bytes());
+    for chunk in content.chunks(bytes_per_checksum as usize) {
+        checksum_file.extend_from_slice(&crc32fast::hash(chunk).to_be_bytes());
+    }
+    checksum_file
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_verify_checksum() {
+        let tmp_dir = TempDir::new().unwrap();
+        let path = format!("{}/data/1.parquet", tmp_dir.path().to_str().unwrap());
+        assert_eq!(
+            checksum_file_path(&path),
+            format!("{}/data/.1.parquet.crc", tmp_dir.path().to_str().unwrap())
+        );
+
+        let file_io = FileIO::from_path(&path).unwrap().build().unwrap();
+        let content = b"0123456789".repeat(10);
+        file_io
+            .new_output(&path)
+            .unwrap()
+            .write(content.clone().into())
+            .await
+            .unwrap();
+        assert!(!verify_checksum(&file_io, &path).await.unwrap());
+
+        file_io
+            .new_output(checksum_file_path(&path))
+            .unwrap()
+            .write(checksum_file(&content, 16).into())
+            .await
+            .unwrap();
+        assert!(verify_checksum(&file_io, &path).await.unwrap());
+
+        let mut corrupted = content;
+        corrupted[42] ^= 1;
+        file_io
+            .new_output(&path)
+            .unwrap()
+            .write(corrupted.into())
+            .await
+            .unwrap();
+        let err = verify_checksum(&file_io, &path).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::DataInvalid);
+        assert!(err.message().contains(&path));
+    }
+}
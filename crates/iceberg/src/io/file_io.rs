@@ -24,6 +24,7 @@ use bytes::Bytes;
 use url::Url;
 
 use super::opendal::OpenDalStorage;
+use super::retry::{RetryPolicy, RetryStorage};
 use super::storage::Storage;
 use crate::{Error, ErrorKind, Result};
 
@@ -48,7 +49,7 @@ use crate::{Error, ErrorKind, Result};
 pub struct FileIO {
     builder: FileIOBuilder,
 
-    inner: Arc<OpenDalStorage>,
+    inner: Arc<dyn Storage>,
 }
 
 impl FileIO {
@@ -60,6 +61,21 @@ impl FileIO {
         self.builder
     }
 
+    /// Returns the properties this FileIO was built with.
+    pub(crate) fn props(&self) -> &HashMap<String, String> {
+        &self.builder.props
+    }
+
+    /// Wraps the storage of this FileIO with `wrap`, to instrument its operations in tests.
+    #[cfg(test)]
+    pub(crate) fn with_wrapped_storage(
+        mut self,
+        wrap: impl FnOnce(Arc<dyn Storage>) -> Arc<dyn Storage>,
+    ) -> Self {
+        self.inner = wrap(self.inner);
+        self
+    }
+
     /// Try to infer file io scheme from path. See [`FileIO`] for supported schemes.
     ///
     /// - If it's a valid url, for example `s3://bucket/a`, url scheme will be used, and the rest of the url will be ignored.
@@ -240,10 +256,13 @@ impl FileIOBuilder {
 
     /// Builds [`FileIO`].
     pub fn build(self) -> Result<FileIO> {
-        let storage = OpenDalStorage::build(self.clone())?;
+        let mut storage: Arc<dyn Storage> = Arc::new(OpenDalStorage::build(self.clone())?);
+        if let Some(retry_policy) = self.extension::<RetryPolicy>() {
+            storage = Arc::new(RetryStorage::new(storage, retry_policy.as_ref().clone()));
+        }
         Ok(FileIO {
             builder: self,
-            inner: Arc::new(storage),
+            inner: storage,
         })
     }
 }
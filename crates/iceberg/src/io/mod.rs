@@ -66,21 +66,26 @@
 //! - `new_input`: Create input file for reading.
 //! - `new_output`: Create output file for writing.
 
+mod checksum;
 mod config;
 mod file_io;
 mod local_fs;
 mod memory;
 mod opendal;
+mod retry;
 mod storage;
 
+pub(crate) use checksum::*;
 pub use config::*;
 pub use file_io::*;
 #[cfg(feature = "storage-s3")]
 pub use opendal::CustomAwsCredentialLoader;
 pub use opendal::{OpenDalStorage, OpenDalStorageFactory};
+pub use retry::{RetryPolicy, RetryStorage, is_retryable};
 pub use storage::{Storage, StorageConfig, StorageFactory};
 
 pub(crate) mod object_cache;
+pub use object_cache::{IO_MANIFEST_CACHE_ENABLED, IO_MANIFEST_CACHE_MAX_TOTAL_BYTES};
 
 pub(crate) fn is_truthy(value: &str) -> bool {
     ["true", "t", "1", "on"].contains(&value.to_lowercase().as_str())
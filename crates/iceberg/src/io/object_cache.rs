@@ -15,17 +15,25 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use std::mem::size_of_val;
+use std::mem::size_of;
 use std::sync::Arc;
 
-use crate::io::FileIO;
+use crate::io::{FileIO, is_truthy};
 use crate::spec::{
-    FormatVersion, Manifest, ManifestFile, ManifestList, SchemaId, SnapshotRef, TableMetadataRef,
+    Datum, FieldSummary, FormatVersion, Manifest, ManifestEntry, ManifestFile, ManifestList,
+    SchemaId, SnapshotRef, TableMetadataRef,
 };
 use crate::{Error, ErrorKind, Result};
 
 const DEFAULT_CACHE_SIZE_BYTES: u64 = 32 * 1024 * 1024; // 32MB
 
+/// FileIO property enabling the cache of manifests and manifest lists of the tables using the
+/// FileIO, `true` by default.
+pub const IO_MANIFEST_CACHE_ENABLED: &str = "io.manifest.cache-enabled";
+/// FileIO property bounding the memory, in bytes, of the manifest cache of each table using the
+/// FileIO. Defaults to 32MB, zero disables the cache.
+pub const IO_MANIFEST_CACHE_MAX_TOTAL_BYTES: &str = "io.manifest.cache.max-total-bytes";
+
 #[derive(Clone, Debug)]
 pub(crate) enum CachedItem {
     ManifestList(Arc<ManifestList>),
@@ -35,7 +43,9 @@ pub(crate) enum CachedItem {
 #[derive(Clone, Debug, Hash, Eq, PartialEq)]
 pub(crate) enum CachedObjectKey {
     ManifestList((String, FormatVersion, SchemaId)),
-    Manifest(String),
+    /// A manifest path, and the sorted field ids of the decoded column bounds if only some
+    /// of them are decoded.
+    Manifest((String, Option<Vec<i32>>)),
 }
 
 /// Caches metadata objects deserialized from immutable files
@@ -46,6 +56,51 @@ pub struct ObjectCache {
     cache_disabled: bool,
 }
 
+impl CachedItem {
+    /// Estimates the memory held by the item, including the entries it owns.
+    fn weight(&self) -> u32 {
+        let bytes = match self {
+            CachedItem::ManifestList(item) => {
+                size_of::<ManifestList>()
+                    + item
+                        .entries()
+                        .iter()
+                        .map(|manifest| {
+                            size_of::<ManifestFile>()
+                                + manifest.manifest_path.len()
+                                + manifest.partitions.as_ref().map_or(0, |partitions| {
+                                    partitions.len() * size_of::<FieldSummary>()
+                                })
+                        })
+                        .sum::<usize>()
+            }
+            CachedItem::Manifest(item) => {
+                size_of::<Manifest>()
+                    + item
+                        .entries()
+                        .iter()
+                        .map(|entry| estimated_entry_size(entry))
+                        .sum::<usize>()
+            }
+        };
+        u32::try_from(bytes).unwrap_or(u32::MAX)
+    }
+}
+
+fn estimated_entry_size(entry: &ManifestEntry) -> usize {
+    let data_file = entry.data_file();
+    let counters = data_file.column_sizes().len()
+        + data_file.value_counts().len()
+        + data_file.null_value_counts().len()
+        + data_file.nan_value_counts().len();
+    let bounds = data_file.lower_bounds().len() + data_file.upper_bounds().len();
+    size_of::<ManifestEntry>()
+        + data_file.file_path().len()
+        + counters * (size_of::<i32>() + size_of::<u64>())
+        + bounds * (size_of::<i32>() + size_of::<Datum>())
+        + data_file.split_offsets().map_or(0, <[i64]>::len) * size_of::<i64>()
+}
+
 impl ObjectCache {
     /// Creates a new [`ObjectCache`]
     /// with the default cache size
@@ -53,6 +108,31 @@ impl ObjectCache {
         Self::new_with_capacity(file_io, DEFAULT_CACHE_SIZE_BYTES)
     }
 
+    /// Creates a new [`ObjectCache`] configured by the
+    /// [`IO_MANIFEST_CACHE_ENABLED`] and [`IO_MANIFEST_CACHE_MAX_TOTAL_BYTES`]
+    /// properties of `file_io`
+    pub(crate) fn from_file_io_props(file_io: FileIO) -> Result<Self> {
+        let props = file_io.props();
+        if props
+            .get(IO_MANIFEST_CACHE_ENABLED)
+            .is_some_and(|enabled| !is_truthy(enabled))
+        {
+            return Ok(Self::with_disabled_cache(file_io));
+        }
+
+        let Some(max_total_bytes) = props.get(IO_MANIFEST_CACHE_MAX_TOTAL_BYTES) else {
+            return Ok(Self::new(file_io));
+        };
+        let cache_size_bytes = max_total_bytes.parse::<u64>().map_err(|err| {
+            Error::new(
+                ErrorKind::DataInvalid,
+                format!("Invalid value for {IO_MANIFEST_CACHE_MAX_TOTAL_BYTES}: {max_total_bytes}"),
+            )
+            .with_source(err)
+        })?;
+        Ok(Self::new_with_capacity(file_io, cache_size_bytes))
+    }
+
     /// Creates a new [`ObjectCache`]
     /// with a specific cache size
     pub(crate) fn new_with_capacity(file_io: FileIO, cache_size_bytes: u64) -> Self {
@@ -61,10 +141,7 @@ impl ObjectCache {
         } else {
             Self {
                 cache: moka::future::Cache::builder()
-                    .weigher(|_, val: &CachedItem| match val {
-                        CachedItem::ManifestList(item) => size_of_val(item.as_ref()),
-                        CachedItem::Manifest(item) => size_of_val(item.as_ref()),
-                    } as u32)
+                    .weigher(|_, val: &CachedItem| val.weight())
                     .max_capacity(cache_size_bytes)
                     .build(),
                 file_io,
@@ -85,20 +162,48 @@ impl ObjectCache {
 
     /// Retrieves an Arc [`Manifest`] from the cache
     /// or retrieves one from FileIO and parses it if not present
-    pub(crate) async fn get_manifest(&self, manifest_file: &ManifestFile) -> Result<Arc<Manifest>> {
+    ///
+    /// When `bound_field_ids` is set, only the column bounds of the fields with these sorted
+    /// ids are decoded, and the manifest is cached separately for each set of ids, unless the
+    /// manifest is already cached with all its column bounds.
+    ///
+    /// The entries that fail to decode are left out of the manifest, and their errors are
+    /// recorded in [`Manifest::corrupt_entries`] for the caller to handle.
+    pub(crate) async fn get_manifest(
+        &self,
+        manifest_file: &ManifestFile,
+        table_metadata: &TableMetadataRef,
+        bound_field_ids: Option<&[i32]>,
+    ) -> Result<Arc<Manifest>> {
         if self.cache_disabled {
             return manifest_file
-                .load_manifest(&self.file_io)
+                .load_manifest_with_bounds(&self.file_io, table_metadata, bound_field_ids, true)
                 .await
                 .map(Arc::new);
         }
 
-        let key = CachedObjectKey::Manifest(manifest_file.manifest_path.clone());
+        let manifest_path = table_metadata.resolve_location(&manifest_file.manifest_path);
+        // A manifest decoded with all its column bounds serves the scans decoding only some of
+        // them, such as the scans of the manifests pinned by a read transaction.
+        if bound_field_ids.is_some()
+            && let Some(CachedItem::Manifest(arc_manifest)) = self
+                .cache
+                .get(&CachedObjectKey::Manifest((manifest_path.clone(), None)))
+                .await
+        {
+            return Ok(arc_manifest);
+        }
+
+        let key = CachedObjectKey::Manifest((manifest_path, bound_field_ids.map(<[i32]>::to_vec)));
 
         let cache_entry = self
             .cache
             .entry_by_ref(&key)
-            .or_try_insert_with(self.fetch_and_parse_manifest(manifest_file))
+            .or_try_insert_with(self.fetch_and_parse_manifest(
+                manifest_file,
+                table_metadata,
+                bound_field_ids,
+            ))
             .await
             .map_err(|err| {
                 Error::new(
@@ -120,12 +225,15 @@ impl ObjectCache {
 
     /// Retrieves an Arc [`ManifestList`] from the cache
     /// or retrieves one from FileIO and parses it if not present
+    ///
+    /// The manifest lists of v1 snapshots listing their manifests inline have no file to be
+    /// cached by, so they are built again on each call.
     pub(crate) async fn get_manifest_list(
         &self,
         snapshot: &SnapshotRef,
         table_metadata: &TableMetadataRef,
     ) -> Result<Arc<ManifestList>> {
-        if self.cache_disabled {
+        if self.cache_disabled || snapshot.manifests().is_some() {
             return snapshot
                 .load_manifest_list(&self.file_io, table_metadata)
                 .await
@@ -162,8 +270,15 @@ impl ObjectCache {
         }
     }
 
-    async fn fetch_and_parse_manifest(&self, manifest_file: &ManifestFile) -> Result<CachedItem> {
-        let manifest = manifest_file.load_manifest(&self.file_io).await?;
+    async fn fetch_and_parse_manifest(
+        &self,
+        manifest_file: &ManifestFile,
+        table_metadata: &TableMetadataRef,
+        bound_field_ids: Option<&[i32]>,
+    ) -> Result<CachedItem> {
+        let manifest = manifest_file
+            .load_manifest_with_bounds(&self.file_io, table_metadata, bound_field_ids, true)
+            .await?;
 
         Ok(CachedItem::Manifest(Arc::new(manifest)))
     }
@@ -333,7 +448,10 @@ mod tests {
         assert_eq!(result_manifest_list.entries().len(), 1);
 
         let manifest_file = result_manifest_list.entries().first().unwrap();
-        let result_manifest = object_cache.get_manifest(manifest_file).await.unwrap();
+        let result_manifest = object_cache
+            .get_manifest(manifest_file, &fixture.table.metadata_ref(), None)
+            .await
+            .unwrap();
 
         assert_eq!(
             result_manifest
@@ -380,7 +498,10 @@ mod tests {
         let manifest_file = result_manifest_list.entries().first().unwrap();
 
         // not in cache
-        let result_manifest = object_cache.get_manifest(manifest_file).await.unwrap();
+        let result_manifest = object_cache
+            .get_manifest(manifest_file, &fixture.table.metadata_ref(), None)
+            .await
+            .unwrap();
 
         assert_eq!(
             result_manifest
@@ -395,7 +516,10 @@ mod tests {
         );
 
         // retrieve cached version
-        let result_manifest = object_cache.get_manifest(manifest_file).await.unwrap();
+        let result_manifest = object_cache
+            .get_manifest(manifest_file, &fixture.table.metadata_ref(), None)
+            .await
+            .unwrap();
 
         assert_eq!(
             result_manifest
@@ -409,4 +533,103 @@ mod tests {
             "1.parquet"
         );
     }
+
+    fn file_io_with_props(fixture: &TableTestFixture, props: &[(&str, &str)]) -> FileIO {
+        FileIO::from_path(&fixture.table_location)
+            .unwrap()
+            .with_props(props.iter().map(|(k, v)| (k.to_string(), v.to_string())))
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_disabled_cache_from_props_refetches_manifests() {
+        let mut fixture = TableTestFixture::new();
+        fixture.setup_manifest_files().await;
+        let snapshot = fixture.table.metadata().current_snapshot().unwrap();
+        let metadata = fixture.table.metadata_ref();
+
+        let enabled = ObjectCache::from_file_io_props(file_io_with_props(&fixture, &[])).unwrap();
+        let disabled = ObjectCache::from_file_io_props(file_io_with_props(&fixture, &[(
+            IO_MANIFEST_CACHE_ENABLED,
+            "false",
+        )]))
+        .unwrap();
+
+        let manifest_list = enabled
+            .get_manifest_list(snapshot, &metadata)
+            .await
+            .unwrap();
+        let manifest_file = manifest_list.entries().first().unwrap();
+        enabled
+            .get_manifest(manifest_file, &metadata, None)
+            .await
+            .unwrap();
+        disabled
+            .get_manifest(manifest_file, &metadata, None)
+            .await
+            .unwrap();
+
+        // Once the manifest is gone only the enabled cache can still serve it.
+        fixture
+            .table
+            .file_io()
+            .delete(&manifest_file.manifest_path)
+            .await
+            .unwrap();
+        enabled
+            .get_manifest(manifest_file, &metadata, None)
+            .await
+            .unwrap();
+        assert!(
+            disabled
+                .get_manifest(manifest_file, &metadata, None)
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_cache_from_props_evicts_beyond_max_total_bytes() {
+        let mut fixture = TableTestFixture::new();
+        fixture.setup_manifest_files().await;
+        let snapshot = fixture.table.metadata().current_snapshot().unwrap();
+        let metadata = fixture.table.metadata_ref();
+
+        let manifest_list = ObjectCache::new(fixture.table.file_io().clone())
+            .get_manifest_list(snapshot, &metadata)
+            .await
+            .unwrap();
+        let manifest_file = manifest_list.entries().first().unwrap();
+        let manifest = manifest_file
+            .load_manifest(fixture.table.file_io(), fixture.table.metadata())
+            .await
+            .unwrap();
+        // The weight accounts for the paths owned by the entries.
+        let manifest_weight = CachedItem::Manifest(Arc::new(manifest)).weight();
+        let manifest_list_weight = CachedItem::ManifestList(manifest_list.clone()).weight();
+        assert!(manifest_weight as usize > size_of::<Manifest>());
+
+        let max_total_bytes = (manifest_weight + manifest_list_weight - 1).to_string();
+        let bounded = ObjectCache::from_file_io_props(file_io_with_props(&fixture, &[(
+            IO_MANIFEST_CACHE_MAX_TOTAL_BYTES,
+            &max_total_bytes,
+        )]))
+        .unwrap();
+        bounded
+            .get_manifest_list(snapshot, &metadata)
+            .await
+            .unwrap();
+        bounded
+            .get_manifest(manifest_file, &metadata, None)
+            .await
+            .unwrap();
+        bounded.cache.run_pending_tasks().await;
+
+        assert!(bounded.cache.entry_count() <= 1);
+        assert!(bounded.cache.weighted_size() < (manifest_weight + manifest_list_weight) as u64);
+
+        let invalid = file_io_with_props(&fixture, &[(IO_MANIFEST_CACHE_MAX_TOTAL_BYTES, "lots")]);
+        assert!(ObjectCache::from_file_io_props(invalid).is_err());
+    }
 }
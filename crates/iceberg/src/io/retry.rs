@@ -0,0 +1,266 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Retrying storage decorator for flaky object stores.
+
+use std::ops::Range;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use backon::{ExponentialBuilder, Retryable};
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+
+use super::{FileMetadata, FileRead, FileWrite, InputFile, OutputFile, Storage};
+use crate::{Error, ErrorKind, Result};
+
+/// Policy deciding how often and how long [`RetryStorage`] retries failed operations.
+///
+/// Add it as an extension to a [`FileIOBuilder`](super::FileIOBuilder) to retry the
+/// operations of the built [`FileIO`](super::FileIO):
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use iceberg::io::{FileIOBuilder, RetryPolicy};
+///
+/// # fn test() -> iceberg::Result<()> {
+/// let file_io = FileIOBuilder::new("memory")
+///     .with_extension(RetryPolicy::default().with_max_retries(5))
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RetryPolicy {
+    max_retries: usize,
+    min_delay: Duration,
+    max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            min_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(5),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Set the maximum number of retries of an operation, `0` disables retries.
+    pub fn with_max_retries(mut self, max_retries: usize) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Set the delay before the first retry, it doubles with every further retry.
+    pub fn with_min_delay(mut self, min_delay: Duration) -> Self {
+        self.min_delay = min_delay;
+        self
+    }
+
+    /// Set the upper bound of the delay between two retries.
+    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    fn backoff(&self) -> ExponentialBuilder {
+        ExponentialBuilder::new()
+            .with_min_delay(self.min_delay)
+            .with_max_delay(self.max_delay)
+            .with_max_times(self.max_retries)
+            .with_factor(2.0)
+    }
+
+    async fn run<T, F, Fut>(&self, op: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        op.retry(self.backoff())
+            .sleep(tokio::time::sleep)
+            .when(is_retryable)
+            .await
+    }
+}
+
+/// Returns whether a failed storage operation may succeed when retried.
+///
+/// Errors marked retryable and errors of storage services that are temporary, such as
+/// throttling, server errors or timeouts, are retryable. Errors like a missing file or denied
+/// permission are permanent.
+pub fn is_retryable(err: &Error) -> bool {
+    if err.retryable() || err.kind() == ErrorKind::BackendUnavailable {
+        return true;
+    }
+
+    let Some(source) =
+        std::error::Error::source(err).and_then(|source| source.downcast_ref::<opendal::Error>())
+    else {
+        return false;
+    };
+    match source.kind() {
+        opendal::ErrorKind::RateLimited => true,
+        opendal::ErrorKind::NotFound | opendal::ErrorKind::PermissionDenied => false,
+        _ => source.is_temporary(),
+    }
+}
+
+/// A [`Storage`] decorator retrying failed operations of the wrapped storage with exponential
+/// backoff, as long as the error [`is_retryable`].
+///
+/// Reads, including the range reads of readers returned by [`Storage::reader`], are retried.
+/// Writes are only retried when they are idempotent: [`Storage::write`] writes the whole file at
+/// once and can be repeated, while the chunks written to a [`FileWrite`] are not retried.
+/// Deletes are retried as deleting a missing file is not an error.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetryStorage {
+    inner: Arc<dyn Storage>,
+    policy: RetryPolicy,
+}
+
+impl RetryStorage {
+    /// Wrap `inner` to retry its operations according to `policy`.
+    pub fn new(inner: Arc<dyn Storage>, policy: RetryPolicy) -> Self {
+        Self { inner, policy }
+    }
+}
+
+#[async_trait]
+#[typetag::serde]
+impl Storage for RetryStorage {
+    async fn exists(&self, path: &str) -> Result<bool> {
+        self.policy.run(|| self.inner.exists(path)).await
+    }
+
+    async fn metadata(&self, path: &str) -> Result<FileMetadata> {
+        self.policy.run(|| self.inner.metadata(path)).await
+    }
+
+    async fn read(&self, path: &str) -> Result<Bytes> {
+        self.policy.run(|| self.inner.read(path)).await
+    }
+
+    async fn reader(&self, path: &str) -> Result<Box<dyn FileRead>> {
+        let inner = self.policy.run(|| self.inner.reader(path)).await?;
+        Ok(Box::new(RetryFileRead {
+            inner,
+            policy: self.policy.clone(),
+        }))
+    }
+
+    async fn write(&self, path: &str, bs: Bytes) -> Result<()> {
+        self.policy.run(|| self.inner.write(path, bs.clone())).await
+    }
+
+    async fn writer(&self, path: &str) -> Result<Box<dyn FileWrite>> {
+        self.policy.run(|| self.inner.writer(path)).await
+    }
+
+    async fn delete(&self, path: &str) -> Result<()> {
+        self.policy.run(|| self.inner.delete(path)).await
+    }
+
+    async fn delete_prefix(&self, path: &str) -> Result<()> {
+        self.policy.run(|| self.inner.delete_prefix(path)).await
+    }
+
+    fn new_input(&self, path: &str) -> Result<InputFile> {
+        Ok(InputFile::new(Arc::new(self.clone()), path.to_string()))
+    }
+
+    fn new_output(&self, path: &str) -> Result<OutputFile> {
+        Ok(OutputFile::new(Arc::new(self.clone()), path.to_string()))
+    }
+}
+
+struct RetryFileRead {
+    inner: Box<dyn FileRead>,
+    policy: RetryPolicy,
+}
+
+#[async_trait]
+impl FileRead for RetryFileRead {
+    async fn read(&self, range: Range<u64>) -> Result<Bytes> {
+        self.policy.run(|| self.inner.read(range.clone())).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use bytes::Bytes;
+
+    use super::{RetryPolicy, RetryStorage};
+    use crate::io::Storage;
+    use crate::io::memory::MemoryStorage;
+    use crate::test_utils::fixtures::InstrumentedStorage;
+
+    /// Returns a storage holding `iceberg` at `memory://tmp/f`, whose first `failures` reads
+    /// fail with a permanent error when `not_found` is set and a temporary one otherwise.
+    async fn flaky_storage(failures: usize, not_found: bool) -> InstrumentedStorage {
+        let inner = MemoryStorage::new();
+        inner
+            .write("memory://tmp/f", Bytes::from_static(b"iceberg"))
+            .await
+            .unwrap();
+        let kind = if not_found {
+            opendal::ErrorKind::NotFound
+        } else {
+            opendal::ErrorKind::Unexpected
+        };
+        InstrumentedStorage::new(Arc::new(inner)).with_failures(failures, kind)
+    }
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy::default().with_min_delay(Duration::from_millis(1))
+    }
+
+    #[tokio::test]
+    async fn test_retry_read_succeeds_after_transient_failures() {
+        let flaky = flaky_storage(2, false).await;
+        let storage = RetryStorage::new(Arc::new(flaky.clone()), policy());
+
+        let input = storage.new_input("memory://tmp/f").unwrap();
+        assert_eq!(input.read().await.unwrap(), Bytes::from_static(b"iceberg"));
+        assert_eq!(flaky.attempts(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_retry_gives_up_after_max_retries() {
+        let flaky = flaky_storage(10, false).await;
+        let storage = RetryStorage::new(Arc::new(flaky.clone()), policy().with_max_retries(2));
+
+        assert!(storage.read("memory://tmp/f").await.is_err());
+        assert_eq!(flaky.attempts(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_retry_skips_permanent_errors() {
+        let flaky = flaky_storage(1, true).await;
+        let storage = RetryStorage::new(Arc::new(flaky.clone()), policy());
+
+        assert!(storage.metadata("memory://tmp/f").await.is_err());
+        assert_eq!(flaky.attempts(), 1);
+    }
+}
@@ -77,7 +77,10 @@ pub mod table;
 
 mod avro;
 pub mod cache;
+pub mod compaction;
 pub mod compression;
+#[cfg(feature = "encryption")]
+pub mod encryption;
 pub mod io;
 pub mod spec;
 
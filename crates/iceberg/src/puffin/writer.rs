@@ -87,6 +87,11 @@ impl PuffinWriter {
         Ok(())
     }
 
+    /// Returns the metadata of the blobs added so far, in the order they were added.
+    pub(crate) fn written_blobs_metadata(&self) -> &[BlobMetadata] {
+        &self.written_blobs_metadata
+    }
+
     /// Finalizes the Puffin file
     pub async fn close(mut self) -> Result<()> {
         self.write_header_once().await?;
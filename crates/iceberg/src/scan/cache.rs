@@ -18,10 +18,15 @@
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
 
+use parquet::arrow::async_reader::AsyncFileReader;
+use tokio::sync::Semaphore;
+
+use crate::arrow::ArrowFileReader;
 use crate::expr::visitors::expression_evaluator::ExpressionEvaluator;
 use crate::expr::visitors::inclusive_projection::InclusiveProjection;
 use crate::expr::visitors::manifest_evaluator::ManifestEvaluator;
 use crate::expr::{Bind, BoundPredicate};
+use crate::io::{FileIO, FileMetadata};
 use crate::spec::{Schema, TableMetadataRef};
 use crate::{Error, ErrorKind, Result};
 
@@ -235,3 +240,91 @@ impl ExpressionEvaluatorCache {
         Ok(read.get(&spec_id).unwrap().clone())
     }
 }
+
+/// Manages the caching of the split offsets of Parquet data files read from their footers,
+/// for the files whose manifest entries don't record them.
+///
+/// At most the concurrency limit of footers are read at once, and the footer of each file is
+/// read at most once while the cache lives.
+#[derive(Debug)]
+pub(crate) struct FooterSplitOffsetsCache {
+    file_io: FileIO,
+    semaphore: Semaphore,
+    cache: moka::future::Cache<String, Arc<Vec<i64>>>,
+}
+
+impl FooterSplitOffsetsCache {
+    /// Creates a new [`FooterSplitOffsetsCache`] reading at most `concurrency_limit` footers
+    /// at once with `file_io`.
+    pub(crate) fn new(file_io: FileIO, concurrency_limit: usize) -> Self {
+        Self {
+            file_io,
+            semaphore: Semaphore::new(concurrency_limit.max(1)),
+            cache: moka::future::Cache::builder().build(),
+        }
+    }
+
+    /// Retrieves the split offsets of the Parquet file `file_path` from the cache
+    /// or reads them from its footer if not present.
+    pub(crate) async fn get(
+        &self,
+        file_path: &str,
+        file_size_in_bytes: u64,
+    ) -> Result<Arc<Vec<i64>>> {
+        self.cache
+            .try_get_with_by_ref(
+                file_path,
+                self.read_split_offsets(file_path, file_size_in_bytes),
+            )
+            .await
+            .map_err(|err| {
+                Arc::try_unwrap(err).unwrap_or_else(|err| {
+                    Error::new(
+                        ErrorKind::Unexpected,
+                        format!("Failed to read the split offsets of {file_path}"),
+                    )
+                    .with_source(err)
+                })
+            })
+    }
+
+    async fn read_split_offsets(
+        &self,
+        file_path: &str,
+        file_size_in_bytes: u64,
+    ) -> Result<Arc<Vec<i64>>> {
+        let _permit = self.semaphore.acquire().await.map_err(|err| {
+            Error::new(
+                ErrorKind::Unexpected,
+                "FooterSplitOffsetsCache semaphore was closed",
+            )
+            .with_source(err)
+        })?;
+
+        let reader = self.file_io.new_input(file_path)?.reader().await?;
+        let file_metadata = FileMetadata {
+            size: file_size_in_bytes,
+        };
+        let metadata = ArrowFileReader::new(file_metadata, reader)
+            .get_metadata(None)
+            .await
+            .map_err(|err| {
+                Error::new(
+                    ErrorKind::DataInvalid,
+                    format!("Error reading Parquet metadata of {file_path}: {err}"),
+                )
+            })?;
+
+        // Row groups written without their file offset start at their first column chunk.
+        let split_offsets = metadata
+            .row_groups()
+            .iter()
+            .filter_map(|row_group| {
+                row_group.file_offset().or_else(|| {
+                    (row_group.num_columns() > 0).then(|| row_group.column(0).byte_range().0 as i64)
+                })
+            })
+            .collect();
+        Ok(Arc::new(split_offsets))
+    }
+}
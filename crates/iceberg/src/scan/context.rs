@@ -24,12 +24,12 @@ use crate::delete_file_index::DeleteFileIndex;
 use crate::expr::{Bind, BoundPredicate, Predicate};
 use crate::io::object_cache::ObjectCache;
 use crate::scan::{
-    BoundPredicates, ExpressionEvaluatorCache, FileScanTask, ManifestEvaluatorCache,
-    PartitionFilterCache,
+    BoundPredicates, CorruptEntryHandling, ExpressionEvaluatorCache, FileScanTask,
+    FooterSplitOffsetsCache, ManifestEvaluatorCache, PartitionFilterCache, ScanMetrics,
 };
 use crate::spec::{
-    ManifestContentType, ManifestEntryRef, ManifestFile, ManifestList, SchemaRef, SnapshotRef,
-    TableMetadataRef,
+    ManifestContentType, ManifestEntryRef, ManifestFile, ManifestList, NameMapping,
+    PartitionSpecRef, SchemaRef, SnapshotRef, TableMetadataRef,
 };
 use crate::{Error, ErrorKind, Result};
 
@@ -41,12 +41,21 @@ pub(crate) struct ManifestFileContext {
     sender: Sender<ManifestEntryContext>,
 
     field_ids: Arc<Vec<i32>>,
+    bound_field_ids: Option<Arc<Vec<i32>>>,
+    split_files: bool,
+    footer_split_offsets_cache: Option<Arc<FooterSplitOffsetsCache>>,
+    name_mapping: Option<Arc<NameMapping>>,
+    timestamp_zone: Option<String>,
+    on_corrupt_entry: CorruptEntryHandling,
     bound_predicates: Option<Arc<BoundPredicates>>,
     object_cache: Arc<ObjectCache>,
     snapshot_schema: SchemaRef,
+    partition_spec: Option<PartitionSpecRef>,
     expression_evaluator_cache: Arc<ExpressionEvaluatorCache>,
     delete_file_index: DeleteFileIndex,
     case_sensitive: bool,
+    scan_metrics: ScanMetrics,
+    table_metadata: TableMetadataRef,
 }
 
 /// Wraps a [`ManifestEntryRef`] alongside the objects that are needed
@@ -58,9 +67,16 @@ pub(crate) struct ManifestEntryContext {
     pub field_ids: Arc<Vec<i32>>,
     pub bound_predicates: Option<Arc<BoundPredicates>>,
     pub partition_spec_id: i32,
+    pub partition_spec: Option<PartitionSpecRef>,
     pub snapshot_schema: SchemaRef,
     pub delete_file_index: DeleteFileIndex,
     pub case_sensitive: bool,
+    pub scan_metrics: ScanMetrics,
+    pub split_files: bool,
+    pub footer_split_offsets_cache: Option<Arc<FooterSplitOffsetsCache>>,
+    pub name_mapping: Option<Arc<NameMapping>>,
+    pub timestamp_zone: Option<String>,
+    pub on_corrupt_entry: CorruptEntryHandling,
 }
 
 impl ManifestFileContext {
@@ -72,26 +88,94 @@ impl ManifestFileContext {
             manifest_file,
             bound_predicates,
             snapshot_schema,
+            partition_spec,
             field_ids,
+            bound_field_ids,
+            split_files,
+            footer_split_offsets_cache,
+            name_mapping,
+            timestamp_zone,
+            on_corrupt_entry,
             mut sender,
             expression_evaluator_cache,
             delete_file_index,
+            scan_metrics,
+            table_metadata,
             ..
         } = self;
 
-        let manifest = object_cache.get_manifest(&manifest_file).await?;
+        // Delete files are matched against data files by their bounds, so all of their
+        // bounds are decoded.
+        let bound_field_ids = bound_field_ids
+            .as_deref()
+            .filter(|_| manifest_file.content == ManifestContentType::Data);
+        let manifest = object_cache
+            .get_manifest(
+                &manifest_file,
+                &table_metadata,
+                bound_field_ids.map(Vec::as_slice),
+            )
+            .await?;
+
+        // Corrupt entries of delete manifests are never skipped, as the rows they delete
+        // would be returned.
+        if let Some(corrupt_entry) = manifest.corrupt_entries().first() {
+            if on_corrupt_entry == CorruptEntryHandling::Fail
+                || manifest_file.content == ManifestContentType::Deletes
+            {
+                return Err(Error::new(
+                    ErrorKind::DataInvalid,
+                    format!(
+                        "Corrupt {corrupt_entry} of manifest {}",
+                        manifest_file.manifest_path
+                    ),
+                ));
+            }
+            for corrupt_entry in manifest.corrupt_entries() {
+                tracing::warn!(
+                    "Skipping corrupt {corrupt_entry} of manifest {}",
+                    manifest_file.manifest_path
+                );
+            }
+            scan_metrics.add_skipped_corrupt_entries(manifest.corrupt_entries().len() as u64);
+        }
 
         for manifest_entry in manifest.entries() {
+            // Tasks reference files by absolute paths, so resolve relative paths against
+            // the table location.
+            let file_path = table_metadata.resolve_location(manifest_entry.file_path());
+            let referenced_data_file = manifest_entry
+                .data_file()
+                .referenced_data_file()
+                .map(|path| table_metadata.resolve_location(&path));
+            let manifest_entry = if file_path == manifest_entry.file_path()
+                && referenced_data_file == manifest_entry.data_file().referenced_data_file()
+            {
+                manifest_entry.clone()
+            } else {
+                let mut manifest_entry = manifest_entry.as_ref().clone();
+                manifest_entry.data_file.file_path = file_path;
+                manifest_entry.data_file.referenced_data_file = referenced_data_file;
+                Arc::new(manifest_entry)
+            };
+
             let manifest_entry_context = ManifestEntryContext {
                 // TODO: refactor to avoid the expensive ManifestEntry clone
-                manifest_entry: manifest_entry.clone(),
+                manifest_entry,
                 expression_evaluator_cache: expression_evaluator_cache.clone(),
                 field_ids: field_ids.clone(),
                 partition_spec_id: manifest_file.partition_spec_id,
+                partition_spec: partition_spec.clone(),
                 bound_predicates: bound_predicates.clone(),
                 snapshot_schema: snapshot_schema.clone(),
                 delete_file_index: delete_file_index.clone(),
                 case_sensitive: self.case_sensitive,
+                scan_metrics: scan_metrics.clone(),
+                split_files,
+                footer_split_offsets_cache: footer_split_offsets_cache.clone(),
+                name_mapping: name_mapping.clone(),
+                timestamp_zone: timestamp_zone.clone(),
+                on_corrupt_entry,
             };
 
             sender
@@ -134,18 +218,20 @@ impl ManifestEntryContext {
 
             // Include partition data and spec from manifest entry
             partition: Some(self.manifest_entry.data_file.partition.clone()),
-            // TODO: Pass actual PartitionSpec through context chain for native flow
-            partition_spec: None,
-            // TODO: Extract name_mapping from table metadata property "schema.name-mapping.default"
-            name_mapping: None,
+            partition_spec: self.partition_spec,
+            name_mapping: self.name_mapping,
             case_sensitive: self.case_sensitive,
+            sort_order_id: self.manifest_entry.data_file.sort_order_id,
+            column_sizes: self.manifest_entry.data_file.column_sizes.clone(),
+            value_counts: self.manifest_entry.data_file.value_counts.clone(),
+            timestamp_zone: self.timestamp_zone,
         })
     }
 }
 
 /// PlanContext wraps a [`SnapshotRef`] alongside all the other
 /// objects that are required to perform a scan file plan.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(crate) struct PlanContext {
     pub snapshot: SnapshotRef,
 
@@ -156,6 +242,21 @@ pub(crate) struct PlanContext {
     pub snapshot_bound_predicate: Option<Arc<BoundPredicate>>,
     pub object_cache: Arc<ObjectCache>,
     pub field_ids: Arc<Vec<i32>>,
+    /// The sorted field ids of the column bounds decoded from data manifests, if only the
+    /// bounds of the fields referenced by the predicate are decoded.
+    pub bound_field_ids: Option<Arc<Vec<i32>>>,
+    /// Whether data files are split into one task per row group.
+    pub split_files: bool,
+    /// The cache of the split offsets read from the footers of the split data files whose
+    /// manifest entries don't record them, if their footers are read.
+    pub footer_split_offsets_cache: Option<Arc<FooterSplitOffsetsCache>>,
+    /// The name mapping resolving the field ids of data files without field ids.
+    pub name_mapping: Option<Arc<NameMapping>>,
+    /// The zone of the timestamps stored without UTC adjustment, see
+    /// [`FileScanTask::timestamp_zone`].
+    pub timestamp_zone: Option<String>,
+    /// How corrupt entries of data manifests are handled.
+    pub on_corrupt_entry: CorruptEntryHandling,
 
     pub partition_filter_cache: Arc<PartitionFilterCache>,
     pub manifest_evaluator_cache: Arc<ManifestEvaluatorCache>,
@@ -197,6 +298,7 @@ impl PlanContext {
         tx_data: Sender<ManifestEntryContext>,
         delete_file_idx: DeleteFileIndex,
         delete_file_tx: Sender<ManifestEntryContext>,
+        scan_metrics: &ScanMetrics,
     ) -> Result<Box<impl Iterator<Item = Result<ManifestFileContext>> + 'static>> {
         let mut manifest_files = manifest_list.entries().iter().collect::<Vec<_>>();
         // Sort manifest files to process delete manifests first.
@@ -219,6 +321,8 @@ impl PlanContext {
                 tx_data.clone()
             };
 
+            let is_data = manifest_file.content == ManifestContentType::Data;
+
             let partition_bound_predicate = if self.predicate.is_some() {
                 let partition_bound_predicate = self.get_partition_filter(manifest_file)?;
 
@@ -232,6 +336,9 @@ impl PlanContext {
                     )
                     .eval(manifest_file)?
                 {
+                    if is_data {
+                        scan_metrics.add_data_manifest(true);
+                    }
                     continue;
                 }
 
@@ -240,11 +347,16 @@ impl PlanContext {
                 None
             };
 
+            if is_data {
+                scan_metrics.add_data_manifest(false);
+            }
+
             let mfc = self.create_manifest_file_context(
                 manifest_file,
                 partition_bound_predicate,
                 tx,
                 delete_file_idx.clone(),
+                scan_metrics.clone(),
             );
 
             filtered_mfcs.push(Ok(mfc));
@@ -259,6 +371,7 @@ impl PlanContext {
         partition_filter: Option<Arc<BoundPredicate>>,
         sender: Sender<ManifestEntryContext>,
         delete_file_index: DeleteFileIndex,
+        scan_metrics: ScanMetrics,
     ) -> ManifestFileContext {
         let bound_predicates =
             if let (Some(ref partition_bound_predicate), Some(snapshot_bound_predicate)) =
@@ -278,10 +391,22 @@ impl PlanContext {
             sender,
             object_cache: self.object_cache.clone(),
             snapshot_schema: self.snapshot_schema.clone(),
+            partition_spec: self
+                .table_metadata
+                .partition_spec_by_id(manifest_file.partition_spec_id)
+                .cloned(),
             field_ids: self.field_ids.clone(),
+            bound_field_ids: self.bound_field_ids.clone(),
+            split_files: self.split_files,
+            footer_split_offsets_cache: self.footer_split_offsets_cache.clone(),
+            name_mapping: self.name_mapping.clone(),
+            timestamp_zone: self.timestamp_zone.clone(),
+            on_corrupt_entry: self.on_corrupt_entry,
             expression_evaluator_cache: self.expression_evaluator_cache.clone(),
             delete_file_index,
             case_sensitive: self.case_sensitive,
+            scan_metrics,
+            table_metadata: self.table_metadata.clone(),
         }
     }
 }
@@ -0,0 +1,298 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Default expressions of columns missing from data files.
+//!
+//! Besides the literal `initial-default` of the schema, a top-level column may have a default
+//! expression recorded in the table property
+//! [`TableProperties::PROPERTY_DEFAULT_EXPRESSION_PREFIX`] followed by the column name. This is
+//! a vendor extension of the Iceberg spec. The expression is evaluated once per scan by a
+//! [`DefaultExpressionEvaluator`], and its value replaces the `initial-default` of the column.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use serde_json::Value as JsonValue;
+
+use crate::spec::{
+    Datum, Literal, NestedField, PrimitiveType, Schema, SchemaRef, TableProperties, Type,
+};
+use crate::{Error, ErrorKind, Result};
+
+/// Evaluates the default expressions of columns missing from data files.
+pub trait DefaultExpressionEvaluator: Debug + Send + Sync {
+    /// Returns the value of the default expression `expression` of the column `field`, or
+    /// `None` for a null default.
+    fn evaluate(&self, expression: &str, field: &NestedField) -> Result<Option<Literal>>;
+}
+
+/// Evaluates constant default expressions: a quoted literal such as `'42'`, or a cast of one
+/// to the type of the column such as `CAST('2024-01-01' AS date)`.
+///
+/// The quoted value is read as the JSON single-value serialization of the column type, so
+/// numbers and booleans may be quoted too. Doubled single quotes escape a quote.
+#[derive(Debug, Default)]
+pub struct ConstantDefaultExpressionEvaluator;
+
+impl DefaultExpressionEvaluator for ConstantDefaultExpressionEvaluator {
+    fn evaluate(&self, expression: &str, field: &NestedField) -> Result<Option<Literal>> {
+        let invalid = |message: &str| {
+            Error::new(
+                ErrorKind::DataInvalid,
+                format!(
+                    "Invalid default expression {expression} of column {}: {message}",
+                    field.name
+                ),
+            )
+        };
+
+        let expression = expression.trim();
+        let quoted = match strip_prefix_ignore_case(expression, "CAST(") {
+            Some(cast) => {
+                let cast = cast
+                    .strip_suffix(')')
+                    .ok_or_else(|| invalid("missing closing parenthesis"))?;
+                let (value, cast_type) = cast
+                    .rsplit_once(" AS ")
+                    .or_else(|| cast.rsplit_once(" as "))
+                    .ok_or_else(|| invalid("expected CAST(<literal> AS <type>)"))?;
+                let cast_type: PrimitiveType =
+                    serde_json::from_value(JsonValue::String(cast_type.trim().to_lowercase()))
+                        .map_err(|_| invalid("unknown cast type"))?;
+                if Type::Primitive(cast_type.clone()) != field.field_type.as_ref().clone() {
+                    return Err(invalid(&format!(
+                        "casts to {cast_type}, but the column has type {}",
+                        field.field_type
+                    )));
+                }
+                value.trim()
+            }
+            None => expression,
+        };
+        let value = quoted
+            .strip_prefix('\'')
+            .and_then(|value| value.strip_suffix('\''))
+            .ok_or_else(|| invalid("expected a quoted literal"))?
+            .replace("''", "'");
+
+        if let Ok(datum) = Datum::string(value.clone()).to(&field.field_type) {
+            return Ok(Some(Literal::Primitive(datum.literal().clone())));
+        }
+        let parsed = serde_json::from_str::<JsonValue>(&value)
+            .ok()
+            .and_then(|json| Literal::try_from_json(json, &field.field_type).ok());
+        match parsed {
+            Some(literal) => Ok(literal),
+            None => Literal::try_from_json(JsonValue::String(value), &field.field_type)
+                .map_err(|err| invalid(&err.to_string())),
+        }
+    }
+}
+
+fn strip_prefix_ignore_case<'a>(value: &'a str, prefix: &str) -> Option<&'a str> {
+    value
+        .get(..prefix.len())
+        .filter(|start| start.eq_ignore_ascii_case(prefix))
+        .map(|_| &value[prefix.len()..])
+}
+
+/// Returns `schema` with the `initial-default` of the top-level columns that have a default
+/// expression in `properties` replaced by the value of the expression.
+pub(crate) fn apply_default_expressions(
+    schema: SchemaRef,
+    properties: &HashMap<String, String>,
+    evaluator: &dyn DefaultExpressionEvaluator,
+) -> Result<SchemaRef> {
+    let expressions: HashMap<&str, &str> = properties
+        .iter()
+        .filter_map(|(key, value)| {
+            key.strip_prefix(TableProperties::PROPERTY_DEFAULT_EXPRESSION_PREFIX)
+                .map(|column| (column, value.as_str()))
+        })
+        .collect();
+    if expressions.is_empty() {
+        return Ok(schema);
+    }
+
+    let mut fields = Vec::with_capacity(schema.as_struct().fields().len());
+    for field in schema.as_struct().fields() {
+        let Some(expression) = expressions.get(field.name.as_str()) else {
+            fields.push(field.clone());
+            continue;
+        };
+        let mut field = field.as_ref().clone();
+        field.initial_default = evaluator.evaluate(expression, &field)?;
+        fields.push(Arc::new(field));
+    }
+
+    Ok(Arc::new(
+        Schema::builder()
+            .with_schema_id(schema.schema_id())
+            .with_identifier_field_ids(schema.identifier_field_ids())
+            .with_fields(fields)
+            .build()?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{Int32Type, Int64Type};
+    use arrow_array::{ArrayRef, Int64Array, RecordBatch};
+    use futures::TryStreamExt;
+    use parquet::arrow::ArrowWriter;
+
+    use super::*;
+    use crate::arrow::schema_to_arrow_schema;
+    use crate::memory::tests::new_memory_catalog;
+    use crate::spec::FormatVersion;
+    use crate::transaction::{ApplyTransactionAction, Transaction};
+    use crate::{Catalog, NamespaceIdent, TableCreation};
+
+    #[test]
+    fn test_evaluate_constant_default_expression() {
+        let evaluator = ConstantDefaultExpressionEvaluator;
+        let field = NestedField::optional(1, "d", Type::Primitive(PrimitiveType::Date));
+        assert_eq!(
+            evaluator
+                .evaluate("CAST('1970-01-02' AS date)", &field)
+                .unwrap(),
+            Some(Literal::date(1))
+        );
+        let field = NestedField::optional(2, "s", Type::Primitive(PrimitiveType::String));
+        assert_eq!(
+            evaluator.evaluate("'it''s'", &field).unwrap(),
+            Some(Literal::string("it's"))
+        );
+        assert!(evaluator.evaluate("CAST('1' AS int)", &field).is_err());
+        assert!(evaluator.evaluate("now()", &field).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_read_default_expression_of_added_column() {
+        let catalog = new_memory_catalog().await;
+        let namespace = NamespaceIdent::new("ns".to_string());
+        catalog
+            .create_namespace(&namespace, HashMap::new())
+            .await
+            .unwrap();
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+            ])
+            .build()
+            .unwrap();
+        let table = catalog
+            .create_table(
+                &namespace,
+                TableCreation::builder()
+                    .name("t".to_string())
+                    .schema(schema)
+                    .format_version(FormatVersion::V3)
+                    .build(),
+            )
+            .await
+            .unwrap();
+
+        let arrow_schema =
+            Arc::new(schema_to_arrow_schema(table.metadata().current_schema()).unwrap());
+        let batch =
+            RecordBatch::try_new(arrow_schema.clone(), vec![
+                Arc::new(Int64Array::from(vec![1, 2])) as ArrayRef,
+            ])
+            .unwrap();
+        let path = format!("{}/data/1.parquet", table.metadata().location());
+        let mut content = vec![];
+        let mut writer = ArrowWriter::try_new(&mut content, arrow_schema, None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        table
+            .file_io()
+            .new_output(&path)
+            .unwrap()
+            .write(content.into())
+            .await
+            .unwrap();
+        let tx = Transaction::new(&table);
+        let tx = tx.fast_append().add_file(&path, None).apply(tx).unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+
+        // The column with a default expression reads its value, the column with a literal
+        // default and no expression reads the literal. The empty snapshot makes the scan read
+        // the new schema.
+        let tx = Transaction::new(&table).with_empty_snapshot(true);
+        let tx = tx
+            .update_schema()
+            .add_column("score", Type::Primitive(PrimitiveType::Int))
+            .add_required_column(
+                "level",
+                Type::Primitive(PrimitiveType::Int),
+                Literal::int(7),
+            )
+            .apply(tx)
+            .unwrap();
+        let tx = tx
+            .update_table_properties()
+            .set(
+                format!(
+                    "{}score",
+                    TableProperties::PROPERTY_DEFAULT_EXPRESSION_PREFIX
+                ),
+                "CAST('42' AS int)".to_string(),
+            )
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+
+        let batches: Vec<_> = table
+            .scan()
+            .select(["id", "score", "level"])
+            .build()
+            .unwrap()
+            .to_arrow()
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(batches.len(), 1);
+        assert_eq!(
+            batches[0]
+                .column(0)
+                .as_primitive::<Int64Type>()
+                .values()
+                .to_vec(),
+            vec![1, 2]
+        );
+        let scores: Vec<_> = batches[0]
+            .column(1)
+            .as_primitive::<Int32Type>()
+            .iter()
+            .collect();
+        assert_eq!(scores, vec![Some(42), Some(42)]);
+        let levels: Vec<_> = batches[0]
+            .column(2)
+            .as_primitive::<Int32Type>()
+            .iter()
+            .collect();
+        assert_eq!(levels, vec![Some(7), Some(7)]);
+    }
+}
@@ -0,0 +1,93 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashSet;
+
+use crate::scan::FileScanTask;
+
+/// The approximate cost of a table scan, see
+/// [`TableScan::estimate`](crate::scan::TableScan::estimate).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScanEstimate {
+    data_files: u64,
+    delete_files: u64,
+    bytes: u64,
+    records: u64,
+    deleted_records: u64,
+}
+
+impl ScanEstimate {
+    /// Number of data files to read, after pruning.
+    pub fn data_files(&self) -> u64 {
+        self.data_files
+    }
+
+    /// Number of distinct delete files applied to the data files to read.
+    pub fn delete_files(&self) -> u64 {
+        self.delete_files
+    }
+
+    /// Total size in bytes of the data and delete files to read.
+    pub fn bytes(&self) -> u64 {
+        self.bytes
+    }
+
+    /// Number of records in the data files to read, before applying deletes.
+    pub fn records(&self) -> u64 {
+        self.records
+    }
+
+    /// Number of records in the delete files applied to the data files to read.
+    pub fn deleted_records(&self) -> u64 {
+        self.deleted_records
+    }
+
+    /// Approximate number of rows returned by the scan: the records of the data files to
+    /// read minus the deleted records.
+    ///
+    /// Each position delete removes one row. Each equality delete is assumed to remove one
+    /// row as well, and a delete file may also apply to data files that were pruned, so this
+    /// may underestimate the rows. The row filter of the scan is only applied to whole files.
+    pub fn rows(&self) -> u64 {
+        self.records.saturating_sub(self.deleted_records)
+    }
+
+    /// Aggregates the estimate of the planned file scan tasks `tasks`, each of a whole file.
+    pub(crate) fn from_tasks<'a>(tasks: impl IntoIterator<Item = &'a FileScanTask>) -> Self {
+        let mut estimate = Self::default();
+        let mut data_files = HashSet::new();
+        let mut delete_files = HashSet::new();
+        for task in tasks {
+            estimate.bytes += task.length;
+            if data_files.insert(task.data_file_path.as_str()) {
+                estimate.data_files += 1;
+                estimate.records += task.record_count.unwrap_or_default();
+            }
+            for delete in &task.deletes {
+                // Deletion vectors of several data files share the same Puffin file.
+                if delete_files.insert((delete.file_path.as_str(), delete.content_offset)) {
+                    estimate.delete_files += 1;
+                    estimate.bytes += delete
+                        .content_size_in_bytes
+                        .map_or(delete.file_size_in_bytes, |size| size as u64);
+                    estimate.deleted_records += delete.record_count;
+                }
+            }
+        }
+        estimate
+    }
+}
@@ -0,0 +1,128 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Counters collected while planning a table scan.
+///
+/// The counters are shared with the planning tasks and are updated while the
+/// [`FileScanTaskStream`](crate::scan::FileScanTaskStream) is being consumed,
+/// so they are only final once that stream has been fully drained.
+#[derive(Debug, Clone, Default)]
+pub struct ScanMetrics {
+    inner: Arc<ScanMetricsInner>,
+}
+
+#[derive(Debug, Default)]
+struct ScanMetricsInner {
+    total_data_manifests: AtomicU64,
+    skipped_data_manifests: AtomicU64,
+    total_data_files: AtomicU64,
+    partition_pruned_data_files: AtomicU64,
+    metrics_pruned_data_files: AtomicU64,
+    planned_data_files: AtomicU64,
+    planned_bytes: AtomicU64,
+    skipped_corrupt_entries: AtomicU64,
+}
+
+impl ScanMetrics {
+    /// Number of data manifests listed in the snapshot's manifest list.
+    pub fn total_data_manifests(&self) -> u64 {
+        self.inner.total_data_manifests.load(Ordering::Relaxed)
+    }
+
+    /// Number of data manifests skipped using the manifest partition summaries.
+    pub fn skipped_data_manifests(&self) -> u64 {
+        self.inner.skipped_data_manifests.load(Ordering::Relaxed)
+    }
+
+    /// Number of live data files read from the manifests that were not skipped.
+    pub fn total_data_files(&self) -> u64 {
+        self.inner.total_data_files.load(Ordering::Relaxed)
+    }
+
+    /// Number of data files skipped because their partition cannot match the filter.
+    pub fn partition_pruned_data_files(&self) -> u64 {
+        self.inner
+            .partition_pruned_data_files
+            .load(Ordering::Relaxed)
+    }
+
+    /// Number of data files skipped because their column bounds cannot match the filter.
+    pub fn metrics_pruned_data_files(&self) -> u64 {
+        self.inner.metrics_pruned_data_files.load(Ordering::Relaxed)
+    }
+
+    /// Number of data files planned for reading.
+    pub fn planned_data_files(&self) -> u64 {
+        self.inner.planned_data_files.load(Ordering::Relaxed)
+    }
+
+    /// Total size in bytes of the data files planned for reading.
+    pub fn planned_bytes(&self) -> u64 {
+        self.inner.planned_bytes.load(Ordering::Relaxed)
+    }
+
+    /// Number of corrupt manifest entries skipped, see
+    /// [`CorruptEntryHandling::SkipWithWarning`](crate::scan::CorruptEntryHandling::SkipWithWarning).
+    pub fn skipped_corrupt_entries(&self) -> u64 {
+        self.inner.skipped_corrupt_entries.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn add_data_manifest(&self, skipped: bool) {
+        self.inner
+            .total_data_manifests
+            .fetch_add(1, Ordering::Relaxed);
+        if skipped {
+            self.inner
+                .skipped_data_manifests
+                .fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    pub(crate) fn add_data_file(&self) {
+        self.inner.total_data_files.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn add_partition_pruned_data_file(&self) {
+        self.inner
+            .partition_pruned_data_files
+            .fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn add_metrics_pruned_data_file(&self) {
+        self.inner
+            .metrics_pruned_data_files
+            .fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn add_planned_data_file(&self, file_size_in_bytes: u64) {
+        self.inner
+            .planned_data_files
+            .fetch_add(1, Ordering::Relaxed);
+        self.inner
+            .planned_bytes
+            .fetch_add(file_size_in_bytes, Ordering::Relaxed);
+    }
+
+    pub(crate) fn add_skipped_corrupt_entries(&self, count: u64) {
+        self.inner
+            .skipped_corrupt_entries
+            .fetch_add(count, Ordering::Relaxed);
+    }
+}
@@ -21,24 +21,40 @@ mod cache;
 use cache::*;
 mod context;
 use context::*;
+mod default_expression;
+mod estimate;
+mod metrics;
+mod split;
 mod task;
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use arrow_array::RecordBatch;
+use arrow_array::timezone::Tz;
+use default_expression::apply_default_expressions;
+pub use default_expression::{ConstantDefaultExpressionEvaluator, DefaultExpressionEvaluator};
+pub use estimate::ScanEstimate;
 use futures::channel::mpsc::{Sender, channel};
 use futures::stream::BoxStream;
 use futures::{SinkExt, StreamExt, TryStreamExt};
+pub use metrics::ScanMetrics;
 pub use task::*;
 
-use crate::arrow::ArrowReaderBuilder;
+use crate::arrow::{ArrowReader, ArrowReaderBuilder, CollectFieldIdVisitor, PartitionColumns};
 use crate::delete_file_index::DeleteFileIndex;
+#[cfg(feature = "encryption")]
+use crate::encryption::KeyRetriever;
+use crate::expr::visitors::bound_predicate_visitor::visit;
 use crate::expr::visitors::inclusive_metrics_evaluator::InclusiveMetricsEvaluator;
 use crate::expr::{Bind, BoundPredicate, Predicate};
 use crate::io::FileIO;
 use crate::metadata_columns::{get_metadata_field_id, is_metadata_column_name};
 use crate::runtime::spawn;
-use crate::spec::{DataContentType, SnapshotRef};
+use crate::spec::{
+    DEFAULT_SCHEMA_NAME_MAPPING, DataContentType, DataFileFormat, NameMapping, SnapshotRef,
+    TableProperties,
+};
 use crate::table::Table;
 use crate::utils::available_parallelism;
 use crate::{Error, ErrorKind, Result};
@@ -46,6 +62,18 @@ use crate::{Error, ErrorKind, Result};
 /// A stream of arrow [`RecordBatch`]es.
 pub type ArrowRecordBatchStream = BoxStream<'static, Result<RecordBatch>>;
 
+/// How a scan handles the corrupt entries of data manifests: entries that fail to decode, or
+/// that reference delete files.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CorruptEntryHandling {
+    /// Fails the scan plan.
+    #[default]
+    Fail,
+    /// Skips the entry and logs a warning, counting it in
+    /// [`ScanMetrics::skipped_corrupt_entries`]. The scan returns the rows of the other files.
+    SkipWithWarning,
+}
+
 /// Builder to create table scan.
 pub struct TableScanBuilder<'a> {
     table: &'a Table,
@@ -60,6 +88,22 @@ pub struct TableScanBuilder<'a> {
     concurrency_limit_manifest_files: usize,
     row_group_filtering_enabled: bool,
     row_selection_enabled: bool,
+    selective_metrics_decoding: bool,
+    split_files: bool,
+    split_offsets_from_footers: bool,
+    concurrency_limit_footers: usize,
+    split_target_size: Option<u64>,
+    split_open_file_cost: Option<u64>,
+    name_mapping: Option<NameMapping>,
+    timestamp_zone: Option<String>,
+    on_corrupt_entry: CorruptEntryHandling,
+    default_expression_evaluator: Arc<dyn DefaultExpressionEvaluator>,
+    metadata_size_hint: Option<usize>,
+    range_coalescing_gap: Option<u64>,
+    verify_checksums: bool,
+    partition_columns: bool,
+    #[cfg(feature = "encryption")]
+    key_retriever: Option<Arc<dyn KeyRetriever>>,
 }
 
 impl<'a> TableScanBuilder<'a> {
@@ -78,16 +122,64 @@ impl<'a> TableScanBuilder<'a> {
             concurrency_limit_manifest_files: num_cpus,
             row_group_filtering_enabled: true,
             row_selection_enabled: false,
+            selective_metrics_decoding: false,
+            split_files: false,
+            split_offsets_from_footers: false,
+            concurrency_limit_footers: num_cpus,
+            split_target_size: None,
+            split_open_file_cost: None,
+            name_mapping: None,
+            timestamp_zone: None,
+            on_corrupt_entry: CorruptEntryHandling::default(),
+            default_expression_evaluator: Arc::new(ConstantDefaultExpressionEvaluator),
+            metadata_size_hint: None,
+            range_coalescing_gap: None,
+            verify_checksums: false,
+            partition_columns: false,
+            #[cfg(feature = "encryption")]
+            key_retriever: None,
         }
     }
 
-    /// Sets the desired size of batches in the response
-    /// to something other than the default
+    /// Sets the number of rows of the batches in the response, every batch but the last one
+    /// has exactly `batch_size` rows.
     pub fn with_batch_size(mut self, batch_size: Option<usize>) -> Self {
         self.batch_size = batch_size;
         self
     }
 
+    /// Sets the number of bytes read at once from the end of the data files to load their
+    /// footer. A hint large enough for the whole footer saves a second read.
+    pub fn with_metadata_size_hint(mut self, metadata_size_hint: Option<usize>) -> Self {
+        self.metadata_size_hint = metadata_size_hint;
+        self
+    }
+
+    /// Merges the byte ranges of a data file read together, such as the column chunks of a
+    /// row group, into a single read when they are at most `gap` bytes apart. This results in
+    /// fewer, larger requests to high-latency object stores.
+    pub fn with_range_coalescing_gap(mut self, gap: Option<u64>) -> Self {
+        self.range_coalescing_gap = gap;
+        self
+    }
+
+    /// Verifies each data file against the checksums of its Hadoop checksum file before
+    /// decoding it, failing the scan with the path of a data file that does not match.
+    /// A data file without a checksum file is read without verification and a warning is
+    /// logged. Defaults to `false`.
+    pub fn with_verify_checksums(mut self, verify_checksums: bool) -> Self {
+        self.verify_checksums = verify_checksums;
+        self
+    }
+
+    /// Decrypts data files encrypted with Parquet modular encryption, looking up their keys
+    /// through `key_retriever`.
+    #[cfg(feature = "encryption")]
+    pub fn with_key_retriever(mut self, key_retriever: Arc<dyn KeyRetriever>) -> Self {
+        self.key_retriever = Some(key_retriever);
+        self
+    }
+
     /// Sets the scan's case sensitivity
     pub fn with_case_sensitive(mut self, case_sensitive: bool) -> Self {
         self.case_sensitive = case_sensitive;
@@ -146,6 +238,13 @@ impl<'a> TableScanBuilder<'a> {
         self
     }
 
+    /// Sets the manifest file concurrency limit for this scan, i.e. how many
+    /// manifests are fetched and parsed at the same time during planning
+    pub fn with_manifest_file_concurrency_limit(mut self, limit: usize) -> Self {
+        self.concurrency_limit_manifest_files = limit;
+        self
+    }
+
     /// Sets the manifest entry concurrency limit for this scan
     pub fn with_manifest_entry_concurrency_limit(mut self, limit: usize) -> Self {
         self.concurrency_limit_manifest_entries = limit;
@@ -184,8 +283,161 @@ impl<'a> TableScanBuilder<'a> {
         self
     }
 
+    /// Determines whether to append the partition values of the data file of each row to the
+    /// scanned batches, as one `_partition.<field name>` column per partition field.
+    ///
+    /// The columns cover the partition fields of all the partition specs of the table, so
+    /// every batch has the same schema whatever the spec of its file; the fields missing from
+    /// the spec of a file are null. The values are the partition values recorded in the
+    /// manifests, such as the bucket of a `bucket` field rather than its source value.
+    ///
+    /// Defaults to being disabled.
+    pub fn with_partition_columns(mut self, partition_columns: bool) -> Self {
+        self.partition_columns = partition_columns;
+        self
+    }
+
+    /// Determines whether to only decode the column bounds of the fields referenced by the
+    /// filter predicate when planning.
+    ///
+    /// The bounds of the other fields are dropped from the data files of the manifests without
+    /// being decoded, which speeds up planning on wide tables. Planned tasks are the same.
+    ///
+    /// Defaults to being disabled.
+    pub fn with_selective_metrics_decoding(mut self, selective_metrics_decoding: bool) -> Self {
+        self.selective_metrics_decoding = selective_metrics_decoding;
+        self
+    }
+
+    /// Determines whether Parquet data files with several row groups are split into one task
+    /// per row group, at the split offsets recorded in their manifest entries.
+    ///
+    /// The split tasks of a file scan the byte range of their row group, see
+    /// [`FileScanTask::start`] and [`FileScanTask::length`], so that they can be read in
+    /// parallel. Deletes of the file apply to each of its split tasks.
+    ///
+    /// Defaults to being disabled.
+    pub fn with_file_splitting(mut self, split_files: bool) -> Self {
+        self.split_files = split_files;
+        self
+    }
+
+    /// Determines whether the split offsets of the Parquet data files split by this scan are
+    /// read from their footers when their manifest entries don't record them, as for files
+    /// imported from other writers.
+    ///
+    /// The footers are read concurrently while planning, up to the limit set with
+    /// [`with_footer_concurrency_limit`](Self::with_footer_concurrency_limit), and the footer
+    /// of each file is read at most once for the lifetime of the scan. Without it, such files
+    /// are never split.
+    ///
+    /// Defaults to being disabled.
+    pub fn with_split_offsets_from_footers(mut self, split_offsets_from_footers: bool) -> Self {
+        self.split_offsets_from_footers = split_offsets_from_footers;
+        self
+    }
+
+    /// Sets the maximum number of Parquet footers read concurrently for
+    /// [`with_split_offsets_from_footers`](Self::with_split_offsets_from_footers).
+    ///
+    /// Defaults to the number of available CPUs.
+    pub fn with_footer_concurrency_limit(mut self, limit: usize) -> Self {
+        self.concurrency_limit_footers = limit;
+        self
+    }
+
+    /// Sets the target size in bytes of the tasks planned by [`TableScan::plan_tasks`].
+    ///
+    /// Defaults to the table property
+    /// [`TableProperties::PROPERTY_READ_SPLIT_TARGET_SIZE`](crate::spec::TableProperties::PROPERTY_READ_SPLIT_TARGET_SIZE).
+    pub fn with_split_target_size(mut self, split_target_size: u64) -> Self {
+        self.split_target_size = Some(split_target_size);
+        self
+    }
+
+    /// Sets the estimated cost in bytes of opening a file, the minimum weight of a file in
+    /// the tasks planned by [`TableScan::plan_tasks`].
+    ///
+    /// Defaults to the table property
+    /// [`TableProperties::PROPERTY_READ_SPLIT_OPEN_FILE_COST`](crate::spec::TableProperties::PROPERTY_READ_SPLIT_OPEN_FILE_COST).
+    pub fn with_split_open_file_cost(mut self, split_open_file_cost: u64) -> Self {
+        self.split_open_file_cost = Some(split_open_file_cost);
+        self
+    }
+
+    /// Sets the name mapping resolving the field ids of the columns of data files written
+    /// without field ids, such as Parquet files written outside of Iceberg.
+    ///
+    /// Defaults to the name mapping of the table property
+    /// [`DEFAULT_SCHEMA_NAME_MAPPING`](crate::spec::DEFAULT_SCHEMA_NAME_MAPPING), if any.
+    pub fn with_name_mapping(mut self, name_mapping: NameMapping) -> Self {
+        self.name_mapping = Some(name_mapping);
+        self
+    }
+
+    /// Sets the zone the values of timestamp columns stored without UTC adjustment are local
+    /// times in, such as `America/New_York` or `+03:00`, for tables whose `timestamptz`
+    /// columns were written by engines with local-time semantics.
+    ///
+    /// The values of such columns of `timestamptz` type are converted from the zone to UTC when
+    /// read. Columns stored with UTC adjustment, and columns of `timestamp` type, are read as
+    /// stored. Filters are evaluated against the stored values.
+    ///
+    /// Defaults to the zone of the table property
+    /// [`TableProperties::PROPERTY_READ_TIMESTAMP_ZONE`], or UTC.
+    pub fn with_timestamp_zone(mut self, timestamp_zone: impl Into<String>) -> Self {
+        self.timestamp_zone = Some(timestamp_zone.into());
+        self
+    }
+
+    /// Sets how corrupt entries of data manifests are handled, see [`CorruptEntryHandling`].
+    ///
+    /// Corrupt entries of delete manifests always fail the scan plan, as skipping a delete
+    /// file would return deleted rows.
+    ///
+    /// Defaults to [`CorruptEntryHandling::Fail`].
+    pub fn with_on_corrupt_entry(mut self, on_corrupt_entry: CorruptEntryHandling) -> Self {
+        self.on_corrupt_entry = on_corrupt_entry;
+        self
+    }
+
+    /// Sets the evaluator of the default expressions of the columns missing from data files,
+    /// [`ConstantDefaultExpressionEvaluator`] by default.
+    ///
+    /// A top-level column with a default expression in the table property
+    /// [`TableProperties::PROPERTY_DEFAULT_EXPRESSION_PREFIX`](crate::spec::TableProperties::PROPERTY_DEFAULT_EXPRESSION_PREFIX)
+    /// followed by its name is read from the data files missing it as the value of the
+    /// expression, instead of its `initial-default`.
+    pub fn with_default_expression_evaluator(
+        mut self,
+        evaluator: Arc<dyn DefaultExpressionEvaluator>,
+    ) -> Self {
+        self.default_expression_evaluator = evaluator;
+        self
+    }
+
     /// Build the table scan.
     pub fn build(self) -> Result<TableScan> {
+        let table_properties = self.table.metadata().table_properties()?;
+        let split_target_size = self
+            .split_target_size
+            .unwrap_or(table_properties.read_split_target_size);
+        if split_target_size == 0 {
+            return Err(Error::new(
+                ErrorKind::DataInvalid,
+                "Split target size must be positive",
+            ));
+        }
+        let split_open_file_cost = self
+            .split_open_file_cost
+            .unwrap_or(table_properties.read_split_open_file_cost);
+        let split_planning_lookback = table_properties.read_split_planning_lookback.max(1);
+        let partition_columns = if self.partition_columns {
+            Some(Arc::new(PartitionColumns::try_new(self.table.metadata())?))
+        } else {
+            None
+        };
+
         let snapshot = match self.snapshot_id {
             Some(snapshot_id) => self
                 .table
@@ -210,13 +462,26 @@ impl<'a> TableScanBuilder<'a> {
                         concurrency_limit_manifest_files: self.concurrency_limit_manifest_files,
                         row_group_filtering_enabled: self.row_group_filtering_enabled,
                         row_selection_enabled: self.row_selection_enabled,
+                        metadata_size_hint: self.metadata_size_hint,
+                        range_coalescing_gap: self.range_coalescing_gap,
+                        verify_checksums: self.verify_checksums,
+                        partition_columns,
+                        split_target_size,
+                        split_open_file_cost,
+                        split_planning_lookback,
+                        #[cfg(feature = "encryption")]
+                        key_retriever: self.key_retriever,
                     });
                 };
                 current_snapshot_id.clone()
             }
         };
 
-        let schema = snapshot.schema(self.table.metadata())?;
+        let schema = apply_default_expressions(
+            snapshot.schema(self.table.metadata())?,
+            self.table.metadata().properties(),
+            self.default_expression_evaluator.as_ref(),
+        )?;
 
         // Check that all column names exist in the schema (skip reserved columns).
         if let Some(column_names) = self.column_names.as_ref() {
@@ -225,7 +490,10 @@ impl<'a> TableScanBuilder<'a> {
                 if is_metadata_column_name(column_name) {
                     continue;
                 }
-                if schema.field_by_name(column_name).is_none() {
+                if schema
+                    .resolve_field_by_name(column_name, self.case_sensitive)?
+                    .is_none()
+                {
                     return Err(Error::new(
                         ErrorKind::DataInvalid,
                         format!("Column {column_name} not found in table. Schema: {schema}"),
@@ -251,12 +519,15 @@ impl<'a> TableScanBuilder<'a> {
                 continue;
             }
 
-            let field_id = schema.field_id_by_name(column_name).ok_or_else(|| {
-                Error::new(
-                    ErrorKind::DataInvalid,
-                    format!("Column {column_name} not found in table. Schema: {schema}"),
-                )
-            })?;
+            let field_id = schema
+                .resolve_field_by_name(column_name, self.case_sensitive)?
+                .map(|field| field.id)
+                .ok_or_else(|| {
+                    Error::new(
+                        ErrorKind::DataInvalid,
+                        format!("Column {column_name} not found in table. Schema: {schema}"),
+                    )
+                })?;
 
             schema
                 .as_struct()
@@ -274,11 +545,62 @@ impl<'a> TableScanBuilder<'a> {
         }
 
         let snapshot_bound_predicate = if let Some(ref predicates) = self.filter {
-            Some(predicates.bind(schema.clone(), true)?)
+            Some(predicates.bind(schema.clone(), self.case_sensitive)?)
         } else {
             None
         };
 
+        let bound_field_ids = match &snapshot_bound_predicate {
+            Some(predicate) if self.selective_metrics_decoding => {
+                let mut collector = CollectFieldIdVisitor {
+                    field_ids: HashSet::default(),
+                };
+                visit(&mut collector, predicate)?;
+                let mut bound_field_ids: Vec<i32> = collector.field_ids().into_iter().collect();
+                bound_field_ids.sort_unstable();
+                Some(Arc::new(bound_field_ids))
+            }
+            _ => None,
+        };
+
+        let name_mapping = match self.name_mapping {
+            Some(name_mapping) => Some(name_mapping),
+            None => self
+                .table
+                .metadata()
+                .properties()
+                .get(DEFAULT_SCHEMA_NAME_MAPPING)
+                .map(|name_mapping| {
+                    serde_json::from_str::<NameMapping>(name_mapping).map_err(|err| {
+                        Error::new(
+                            ErrorKind::DataInvalid,
+                            format!("Invalid name mapping in {DEFAULT_SCHEMA_NAME_MAPPING}"),
+                        )
+                        .with_source(err)
+                    })
+                })
+                .transpose()?,
+        };
+
+        let timestamp_zone = match self.timestamp_zone {
+            Some(timestamp_zone) => Some(timestamp_zone),
+            None => self
+                .table
+                .metadata()
+                .properties()
+                .get(TableProperties::PROPERTY_READ_TIMESTAMP_ZONE)
+                .cloned(),
+        };
+        if let Some(timestamp_zone) = &timestamp_zone {
+            timestamp_zone.parse::<Tz>().map_err(|err| {
+                Error::new(
+                    ErrorKind::DataInvalid,
+                    format!("Invalid timestamp zone {timestamp_zone}"),
+                )
+                .with_source(err)
+            })?;
+        }
+
         let plan_context = PlanContext {
             snapshot,
             table_metadata: self.table.metadata_ref(),
@@ -288,6 +610,17 @@ impl<'a> TableScanBuilder<'a> {
             snapshot_bound_predicate: snapshot_bound_predicate.map(Arc::new),
             object_cache: self.table.object_cache(),
             field_ids: Arc::new(field_ids),
+            bound_field_ids,
+            split_files: self.split_files,
+            footer_split_offsets_cache: self.split_offsets_from_footers.then(|| {
+                Arc::new(FooterSplitOffsetsCache::new(
+                    self.table.file_io().clone(),
+                    self.concurrency_limit_footers,
+                ))
+            }),
+            name_mapping: name_mapping.map(Arc::new),
+            timestamp_zone,
+            on_corrupt_entry: self.on_corrupt_entry,
             partition_filter_cache: Arc::new(PartitionFilterCache::new()),
             manifest_evaluator_cache: Arc::new(ManifestEvaluatorCache::new()),
             expression_evaluator_cache: Arc::new(ExpressionEvaluatorCache::new()),
@@ -303,6 +636,15 @@ impl<'a> TableScanBuilder<'a> {
             concurrency_limit_manifest_files: self.concurrency_limit_manifest_files,
             row_group_filtering_enabled: self.row_group_filtering_enabled,
             row_selection_enabled: self.row_selection_enabled,
+            metadata_size_hint: self.metadata_size_hint,
+            range_coalescing_gap: self.range_coalescing_gap,
+            verify_checksums: self.verify_checksums,
+            partition_columns,
+            split_target_size,
+            split_open_file_cost,
+            split_planning_lookback,
+            #[cfg(feature = "encryption")]
+            key_retriever: self.key_retriever,
         })
     }
 }
@@ -331,14 +673,82 @@ pub struct TableScan {
 
     row_group_filtering_enabled: bool,
     row_selection_enabled: bool,
+    metadata_size_hint: Option<usize>,
+    range_coalescing_gap: Option<u64>,
+    verify_checksums: bool,
+    /// The partition columns appended to the scanned batches, if enabled.
+    partition_columns: Option<Arc<PartitionColumns>>,
+
+    /// The target size in bytes of the tasks planned by [`plan_tasks`](Self::plan_tasks).
+    split_target_size: u64,
+    /// The minimum weight in bytes of a file in the tasks planned by
+    /// [`plan_tasks`](Self::plan_tasks).
+    split_open_file_cost: u64,
+    /// The number of tasks kept open while combining files in
+    /// [`plan_tasks`](Self::plan_tasks).
+    split_planning_lookback: usize,
+
+    #[cfg(feature = "encryption")]
+    key_retriever: Option<Arc<dyn KeyRetriever>>,
 }
 
 impl TableScan {
     /// Returns a stream of [`FileScanTask`]s.
     pub async fn plan_files(&self) -> Result<FileScanTaskStream> {
+        Ok(self.plan_files_with_metrics().await?.0)
+    }
+
+    /// Returns a stream of [`FileScanTask`]s together with the [`ScanMetrics`]
+    /// collected while planning it.
+    ///
+    /// The metrics are updated as the stream is consumed and are final once
+    /// the stream has been fully drained.
+    pub async fn plan_files_with_metrics(&self) -> Result<(FileScanTaskStream, ScanMetrics)> {
         let Some(plan_context) = self.plan_context.as_ref() else {
-            return Ok(Box::pin(futures::stream::empty()));
+            return Ok((Box::pin(futures::stream::empty()), ScanMetrics::default()));
+        };
+        self.plan_files_with_context(plan_context).await
+    }
+
+    /// Returns the files of this scan combined into tasks of about the target split size.
+    ///
+    /// Parquet data files are split at the row group offsets recorded in their manifest
+    /// entries, and their consecutive row groups are merged back up to the target size, so
+    /// large files are read by several tasks. The files and parts of files are then
+    /// bin-packed into tasks, each weighing its length plus the size of its delete files but
+    /// at least the open file cost, so many small files are read by few tasks. A file without
+    /// split offsets is never split, unless they are read from its footer, see
+    /// [`TableScanBuilder::with_split_offsets_from_footers`].
+    ///
+    /// The target size and open file cost default to the table properties
+    /// [`TableProperties::PROPERTY_READ_SPLIT_TARGET_SIZE`](crate::spec::TableProperties::PROPERTY_READ_SPLIT_TARGET_SIZE)
+    /// and
+    /// [`TableProperties::PROPERTY_READ_SPLIT_OPEN_FILE_COST`](crate::spec::TableProperties::PROPERTY_READ_SPLIT_OPEN_FILE_COST).
+    /// The tasks of a combined task can be read with
+    /// [`to_arrow_from_tasks`](Self::to_arrow_from_tasks).
+    pub async fn plan_tasks(&self) -> Result<Vec<CombinedFileScanTask>> {
+        let Some(plan_context) = self.plan_context.as_ref() else {
+            return Ok(vec![]);
         };
+        let plan_context = PlanContext {
+            split_files: true,
+            ..plan_context.clone()
+        };
+        let (tasks, _) = self.plan_files_with_context(&plan_context).await?;
+        let tasks: Vec<FileScanTask> = tasks.try_collect().await?;
+        Ok(split::combine_tasks(
+            tasks,
+            self.split_target_size,
+            self.split_open_file_cost,
+            self.split_planning_lookback,
+        ))
+    }
+
+    async fn plan_files_with_context(
+        &self,
+        plan_context: &PlanContext,
+    ) -> Result<(FileScanTaskStream, ScanMetrics)> {
+        let scan_metrics = ScanMetrics::default();
 
         let concurrency_limit_manifest_files = self.concurrency_limit_manifest_files;
         let concurrency_limit_manifest_entries = self.concurrency_limit_manifest_entries;
@@ -364,6 +774,7 @@ impl TableScan {
             manifest_entry_data_ctx_tx,
             delete_file_idx.clone(),
             manifest_entry_delete_ctx_tx,
+            &scan_metrics,
         )?;
 
         let mut channel_for_manifest_error = file_scan_task_tx.clone();
@@ -427,21 +838,96 @@ impl TableScan {
             }
         });
 
-        Ok(file_scan_task_rx.boxed())
+        Ok((file_scan_task_rx.boxed(), scan_metrics))
+    }
+
+    /// Returns the approximate cost of this scan: the number of files, bytes and rows to read.
+    ///
+    /// The files are planned as with [`plan_files`](Self::plan_files), pruning them with the
+    /// filter of the scan, but only metadata is read and no data file is opened. The files
+    /// are planned whole even if the scan splits them, for their record counts to be known.
+    pub async fn estimate(&self) -> Result<ScanEstimate> {
+        let Some(plan_context) = self.plan_context.as_ref() else {
+            return Ok(ScanEstimate::default());
+        };
+        let plan_context = PlanContext {
+            split_files: false,
+            ..plan_context.clone()
+        };
+        let (tasks, _) = self.plan_files_with_context(&plan_context).await?;
+        let tasks: Vec<FileScanTask> = tasks.try_collect().await?;
+        Ok(ScanEstimate::from_tasks(&tasks))
     }
 
     /// Returns an [`ArrowRecordBatchStream`].
     pub async fn to_arrow(&self) -> Result<ArrowRecordBatchStream> {
+        Ok(self.to_arrow_with_metrics().await?.0)
+    }
+
+    /// Returns an [`ArrowRecordBatchStream`] together with the [`ScanMetrics`]
+    /// of the file plan backing it.
+    ///
+    /// The metrics are final once the record batch stream has been fully drained.
+    pub async fn to_arrow_with_metrics(&self) -> Result<(ArrowRecordBatchStream, ScanMetrics)> {
+        let (tasks, scan_metrics) = self.plan_files_with_metrics().await?;
+
+        Ok((self.arrow_reader().read(tasks)?, scan_metrics))
+    }
+
+    /// Returns an [`ArrowRecordBatchStream`] reading `tasks` instead of planning the files of
+    /// this scan.
+    ///
+    /// The tasks must have been planned by a scan of the same snapshot with the same filter,
+    /// such as with [`plan_files`](Self::plan_files). They are read with the projection of this
+    /// scan, so a file plan can be reused by scans selecting different columns.
+    pub fn to_arrow_from_tasks(
+        &self,
+        tasks: impl IntoIterator<Item = FileScanTask>,
+    ) -> Result<ArrowRecordBatchStream> {
+        let Some(plan_context) = self.plan_context.as_ref() else {
+            return Ok(Box::pin(futures::stream::empty()));
+        };
+        let field_ids = plan_context.field_ids.as_ref().clone();
+        let tasks: Vec<Result<FileScanTask>> = tasks
+            .into_iter()
+            .map(|mut task| {
+                task.project_field_ids = field_ids.clone();
+                Ok(task)
+            })
+            .collect();
+
+        self.arrow_reader()
+            .read(Box::pin(futures::stream::iter(tasks)))
+    }
+
+    /// Builds the reader of the file scan tasks of this scan.
+    fn arrow_reader(&self) -> ArrowReader {
         let mut arrow_reader_builder = ArrowReaderBuilder::new(self.file_io.clone())
             .with_data_file_concurrency_limit(self.concurrency_limit_data_files)
             .with_row_group_filtering_enabled(self.row_group_filtering_enabled)
-            .with_row_selection_enabled(self.row_selection_enabled);
+            .with_row_selection_enabled(self.row_selection_enabled)
+            .with_verify_checksums(self.verify_checksums);
 
         if let Some(batch_size) = self.batch_size {
             arrow_reader_builder = arrow_reader_builder.with_batch_size(batch_size);
         }
+        if let Some(partition_columns) = &self.partition_columns {
+            arrow_reader_builder =
+                arrow_reader_builder.with_partition_columns(Arc::clone(partition_columns));
+        }
+        if let Some(metadata_size_hint) = self.metadata_size_hint {
+            arrow_reader_builder = arrow_reader_builder.with_metadata_size_hint(metadata_size_hint);
+        }
+        if let Some(gap) = self.range_coalescing_gap {
+            arrow_reader_builder = arrow_reader_builder.with_range_coalescing_gap(gap);
+        }
+        #[cfg(feature = "encryption")]
+        if let Some(key_retriever) = &self.key_retriever {
+            arrow_reader_builder =
+                arrow_reader_builder.with_key_retriever(Arc::clone(key_retriever));
+        }
 
-        arrow_reader_builder.build().read(self.plan_files().await?)
+        arrow_reader_builder.build()
     }
 
     /// Returns a reference to the column names of the table scan.
@@ -463,14 +949,28 @@ impl TableScan {
             return Ok(());
         }
 
-        // abort the plan if we encounter a manifest entry for a delete file
+        // abort the plan if we encounter a manifest entry for a delete file, unless corrupt
+        // entries are skipped
         if manifest_entry_context.manifest_entry.content_type() != DataContentType::Data {
+            if manifest_entry_context.on_corrupt_entry == CorruptEntryHandling::SkipWithWarning {
+                tracing::warn!(
+                    "Skipping the entry for delete file {} in a data file manifest",
+                    manifest_entry_context.manifest_entry.file_path()
+                );
+                manifest_entry_context
+                    .scan_metrics
+                    .add_skipped_corrupt_entries(1);
+                return Ok(());
+            }
             return Err(Error::new(
                 ErrorKind::FeatureUnsupported,
                 "Encountered an entry for a delete file in a data file manifest",
             ));
         }
 
+        let scan_metrics = manifest_entry_context.scan_metrics.clone();
+        scan_metrics.add_data_file();
+
         if let Some(ref bound_predicates) = manifest_entry_context.bound_predicates {
             let BoundPredicates {
                 snapshot_bound_predicate,
@@ -488,6 +988,7 @@ impl TableScan {
             // skip any data file whose partition data indicates that it can't contain
             // any data that matches this scan's filter
             if !expression_evaluator.eval(manifest_entry_context.manifest_entry.data_file())? {
+                scan_metrics.add_partition_pruned_data_file();
                 return Ok(());
             }
 
@@ -497,20 +998,58 @@ impl TableScan {
                 manifest_entry_context.manifest_entry.data_file(),
                 false,
             )? {
+                scan_metrics.add_metrics_pruned_data_file();
                 return Ok(());
             }
         }
 
+        scan_metrics
+            .add_planned_data_file(manifest_entry_context.manifest_entry.file_size_in_bytes());
+
         // congratulations! the manifest entry has made its way through the
         // entire plan without getting filtered out. Create a corresponding
         // FileScanTask and push it to the result stream
-        file_scan_task_tx
-            .send(Ok(manifest_entry_context.into_file_scan_task().await?))
-            .await?;
+        let split_offsets = if manifest_entry_context.split_files {
+            Self::split_offsets(&manifest_entry_context).await?
+        } else {
+            None
+        };
+        let file_scan_task = manifest_entry_context.into_file_scan_task().await?;
+        match split_offsets {
+            Some(split_offsets) => {
+                for file_scan_task in file_scan_task.split_at_offsets(&split_offsets) {
+                    file_scan_task_tx.send(Ok(file_scan_task)).await?;
+                }
+            }
+            None => file_scan_task_tx.send(Ok(file_scan_task)).await?,
+        }
 
         Ok(())
     }
 
+    /// Returns the split offsets of the data file of `manifest_entry_context`, read from its
+    /// footer when its manifest entry doesn't record them and footers are read.
+    async fn split_offsets(
+        manifest_entry_context: &ManifestEntryContext,
+    ) -> Result<Option<Vec<i64>>> {
+        let manifest_entry = &manifest_entry_context.manifest_entry;
+        if let Some(split_offsets) = manifest_entry.data_file().split_offsets() {
+            return Ok(Some(split_offsets.to_vec()));
+        }
+        match &manifest_entry_context.footer_split_offsets_cache {
+            Some(cache) if manifest_entry.file_format() == DataFileFormat::Parquet => {
+                let split_offsets = cache
+                    .get(
+                        manifest_entry.file_path(),
+                        manifest_entry.file_size_in_bytes(),
+                    )
+                    .await?;
+                Ok(Some(split_offsets.to_vec()))
+            }
+            _ => Ok(None),
+        }
+    }
+
     async fn process_delete_manifest_entry(
         manifest_entry_context: ManifestEntryContext,
         mut delete_file_ctx_tx: Sender<DeleteFileContext>,
@@ -584,10 +1123,10 @@ pub mod tests {
     use tempfile::TempDir;
     use uuid::Uuid;
 
-    use crate::TableIdent;
     use crate::arrow::ArrowReaderBuilder;
     use crate::expr::{BoundPredicate, Reference};
-    use crate::io::{FileIO, OutputFile};
+    use crate::io::object_cache::ObjectCache;
+    use crate::io::{FileIO, OutputFile, checksum_file, checksum_file_path};
     use crate::metadata_columns::RESERVED_COL_NAME_FILE;
     use crate::scan::FileScanTask;
     use crate::spec::{
@@ -596,6 +1135,8 @@ pub mod tests {
         PrimitiveType, Schema, Struct, StructType, TableMetadata, Type,
     };
     use crate::table::Table;
+    use crate::test_utils::fixtures::{create_table, new_table};
+    use crate::{Result, TableIdent};
 
     fn render_template(template: &str, ctx: Value) -> String {
         let mut env = Environment::new();
@@ -1298,6 +1839,25 @@ pub mod tests {
         assert!(table_scan.is_err());
     }
 
+    #[test]
+    fn test_select_column_case_insensitive() {
+        let table = TableTestFixture::new().table;
+
+        let table_scan = table
+            .scan()
+            .select(["X"])
+            .with_case_sensitive(false)
+            .build()
+            .unwrap();
+        assert_eq!(
+            vec![1],
+            *table_scan.plan_context.as_ref().unwrap().field_ids.as_ref()
+        );
+
+        let table_scan = table.scan().select(["X"]).build();
+        assert!(table_scan.is_err());
+    }
+
     #[test]
     fn test_table_scan_default_snapshot_id() {
         let table = TableTestFixture::new().table;
@@ -1405,6 +1965,72 @@ pub mod tests {
         assert_eq!(int64_arr.value(0), 1);
     }
 
+    #[tokio::test]
+    async fn test_scan_verify_checksums() {
+        let mut fixture = TableTestFixture::new();
+        fixture.setup_manifest_files().await;
+        let scan = |verify_checksums| {
+            fixture
+                .table
+                .scan()
+                .with_verify_checksums(verify_checksums)
+                .build()
+                .unwrap()
+        };
+
+        // Without checksum files, the data files are read without verification.
+        let batches: Vec<_> = scan(true)
+            .to_arrow()
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        assert!(!batches.is_empty());
+
+        let file_io = fixture.table.file_io();
+        let corrupted_path = format!("{}/3.parquet", fixture.table_location);
+        for n in [1, 3] {
+            let path = format!("{}/{n}.parquet", fixture.table_location);
+            let content = file_io.new_input(&path).unwrap().read().await.unwrap();
+            file_io
+                .new_output(checksum_file_path(&path))
+                .unwrap()
+                .write(checksum_file(&content, 512).into())
+                .await
+                .unwrap();
+        }
+        let batches: Vec<_> = scan(true)
+            .to_arrow()
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        assert!(!batches.is_empty());
+
+        let mut content = file_io
+            .new_input(&corrupted_path)
+            .unwrap()
+            .read()
+            .await
+            .unwrap()
+            .to_vec();
+        let middle = content.len() / 2;
+        content[middle] ^= 0xff;
+        file_io
+            .new_output(&corrupted_path)
+            .unwrap()
+            .write(content.into())
+            .await
+            .unwrap();
+
+        let result: Result<Vec<_>> = scan(true).to_arrow().await.unwrap().try_collect().await;
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("Checksum mismatch"), "{err}");
+        assert!(err.contains(&corrupted_path), "{err}");
+    }
+
     #[tokio::test]
     async fn test_open_parquet_no_deletions_by_separate_reader() {
         let mut fixture = TableTestFixture::new();
@@ -1443,6 +2069,60 @@ pub mod tests {
         assert_eq!(batch_1, batch_2);
     }
 
+    #[tokio::test]
+    async fn test_to_arrow_from_tasks_applies_scan_projection() {
+        let mut fixture = TableTestFixture::new();
+        fixture.setup_manifest_files().await;
+
+        let tasks: Vec<_> = fixture
+            .table
+            .scan()
+            .build()
+            .unwrap()
+            .plan_files()
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+
+        let table_scan = fixture.table.scan().select(["z", "x"]).build().unwrap();
+        let expected: Vec<_> = table_scan
+            .to_arrow()
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        let batches: Vec<_> = table_scan
+            .to_arrow_from_tasks(tasks)
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+
+        // The files are read concurrently, so the batches may come in any order.
+        let sorted_rows = |batches: &[RecordBatch]| {
+            let mut rows: Vec<(i64, i64)> = batches
+                .iter()
+                .flat_map(|batch| {
+                    let z = batch
+                        .column(0)
+                        .as_primitive::<arrow_array::types::Int64Type>();
+                    let x = batch
+                        .column(1)
+                        .as_primitive::<arrow_array::types::Int64Type>();
+                    z.values().iter().copied().zip(x.values().iter().copied())
+                })
+                .collect();
+            rows.sort();
+            rows
+        };
+        assert_eq!(batches[0].schema(), expected[0].schema());
+        assert_eq!(batches[0].schema().field(0).name(), "z");
+        assert_eq!(sorted_rows(&batches), sorted_rows(&expected));
+    }
+
     #[tokio::test]
     async fn test_open_parquet_with_projection() {
         let mut fixture = TableTestFixture::new();
@@ -1886,6 +2566,10 @@ pub mod tests {
             partition_spec: None,
             name_mapping: None,
             case_sensitive: false,
+            sort_order_id: None,
+            column_sizes: HashMap::new(),
+            value_counts: HashMap::new(),
+            timestamp_zone: None,
         };
         test_fn(task);
 
@@ -1904,6 +2588,10 @@ pub mod tests {
             partition_spec: None,
             name_mapping: None,
             case_sensitive: false,
+            sort_order_id: None,
+            column_sizes: HashMap::new(),
+            value_counts: HashMap::new(),
+            timestamp_zone: None,
         };
         test_fn(task);
     }
@@ -2222,36 +2910,2123 @@ pub mod tests {
     }
 
     #[tokio::test]
-    async fn test_scan_deadlock() {
-        let mut fixture = TableTestFixture::new();
-        fixture.setup_deadlock_manifests().await;
-
-        // Create table scan with concurrency limit 1
-        // This sets channel size to 1.
-        // Data manifest has 10 entries -> will block producer.
-        // Delete manifest is 2nd in list -> won't be processed.
-        // Consumer 2 (Data) not started -> blocked.
-        // Consumer 1 (Delete) waiting -> blocked.
-        let table_scan = fixture
-            .table
-            .scan()
-            .with_concurrency_limit(1)
-            .build()
-            .unwrap();
+    async fn test_plan_files_with_metrics() {
+        let fixture = TableTestFixture::new();
+        let current_snapshot = fixture.table.metadata().current_snapshot().unwrap();
+        let current_schema = current_snapshot.schema(fixture.table.metadata()).unwrap();
+        let current_partition_spec = fixture.table.metadata().default_partition_spec();
+
+        let data_file = |name: &str, x: i64, y_lower: i64, y_upper: i64, size: u64| {
+            ManifestEntry::builder()
+                .status(ManifestStatus::Added)
+                .data_file(
+                    DataFileBuilder::default()
+                        .partition_spec_id(0)
+                        .content(DataContentType::Data)
+                        .file_path(format!("{}/{name}.parquet", &fixture.table_location))
+                        .file_format(DataFileFormat::Parquet)
+                        .file_size_in_bytes(size)
+                        .record_count(1)
+                        .partition(Struct::from_iter([Some(Literal::long(x))]))
+                        .lower_bounds(HashMap::from([
+                            (1, Datum::long(x)),
+                            (2, Datum::long(y_lower)),
+                        ]))
+                        .upper_bounds(HashMap::from([
+                            (1, Datum::long(x)),
+                            (2, Datum::long(y_upper)),
+                        ]))
+                        .build()
+                        .unwrap(),
+                )
+                .build()
+        };
 
-        // This should timeout/hang if deadlock exists
-        // We can use tokio::time::timeout
-        let result = tokio::time::timeout(std::time::Duration::from_secs(5), async {
-            table_scan
+        let mut manifests = vec![];
+        for entries in [
+            vec![
+                data_file("1", 100, 1, 5, 100),
+                data_file("2", 100, 10, 20, 200),
+                data_file("3", 300, 1, 5, 300),
+                data_file("4", 100, 3, 8, 400),
+            ],
+            // Only contains a partition that doesn't match, so the whole
+            // manifest is skipped based on its partition summary.
+            vec![data_file("5", 300, 1, 5, 500)],
+        ] {
+            let mut writer = ManifestWriterBuilder::new(
+                fixture.next_manifest_file(),
+                Some(current_snapshot.snapshot_id()),
+                None,
+                current_schema.clone(),
+                current_partition_spec.as_ref().clone(),
+            )
+            .build_v2_data();
+            for entry in entries {
+                writer.add_entry(entry).unwrap();
+            }
+            manifests.push(writer.write_manifest_file().await.unwrap());
+        }
+
+        let mut manifest_list_write = ManifestListWriter::v2(
+            fixture
+                .table
+                .file_io()
+                .new_output(current_snapshot.manifest_list())
+                .unwrap(),
+            current_snapshot.snapshot_id(),
+            current_snapshot.parent_snapshot_id(),
+            current_snapshot.sequence_number(),
+        );
+        manifest_list_write
+            .add_manifests(manifests.into_iter())
+            .unwrap();
+        manifest_list_write.close().await.unwrap();
+
+        let table_scan = fixture
+            .table
+            .scan()
+            .with_filter(
+                Reference::new("x")
+                    .equal_to(Datum::long(100))
+                    .and(Reference::new("y").less_than(Datum::long(6))),
+            )
+            .build()
+            .unwrap();
+
+        let (tasks, metrics) = table_scan.plan_files_with_metrics().await.unwrap();
+        let mut tasks = tasks.try_collect::<Vec<_>>().await.unwrap();
+        tasks.sort_by_key(|t| t.data_file_path.to_string());
+
+        assert_eq!(
+            tasks
+                .iter()
+                .map(|t| t.data_file_path.as_str())
+                .collect::<Vec<_>>(),
+            vec![
+                format!("{}/1.parquet", &fixture.table_location),
+                format!("{}/4.parquet", &fixture.table_location),
+            ]
+        );
+
+        assert_eq!(metrics.total_data_manifests(), 2);
+        assert_eq!(metrics.skipped_data_manifests(), 1);
+        assert_eq!(metrics.total_data_files(), 4);
+        assert_eq!(metrics.partition_pruned_data_files(), 1);
+        assert_eq!(metrics.metrics_pruned_data_files(), 1);
+        assert_eq!(metrics.planned_data_files(), 2);
+        assert_eq!(metrics.planned_bytes(), 500);
+    }
+
+    #[tokio::test]
+    async fn test_plan_files_with_selective_metrics_decoding() {
+        let fixture = TableTestFixture::new();
+        let current_snapshot = fixture.table.metadata().current_snapshot().unwrap();
+        let current_schema = current_snapshot.schema(fixture.table.metadata()).unwrap();
+        let current_partition_spec = fixture.table.metadata().default_partition_spec();
+
+        let mut writer = ManifestWriterBuilder::new(
+            fixture.next_manifest_file(),
+            Some(current_snapshot.snapshot_id()),
+            None,
+            current_schema.clone(),
+            current_partition_spec.as_ref().clone(),
+        )
+        .build_v2_data();
+        for (name, y_lower, y_upper) in [("1", 1, 5), ("2", 10, 20)] {
+            let bounds = |y| HashMap::from([(1, Datum::long(100)), (2, Datum::long(y))]);
+            writer
+                .add_entry(
+                    ManifestEntry::builder()
+                        .status(ManifestStatus::Added)
+                        .data_file(
+                            DataFileBuilder::default()
+                                .partition_spec_id(0)
+                                .content(DataContentType::Data)
+                                .file_path(format!("{}/{name}.parquet", &fixture.table_location))
+                                .file_format(DataFileFormat::Parquet)
+                                .file_size_in_bytes(100)
+                                .record_count(1)
+                                .partition(Struct::from_iter([Some(Literal::long(100))]))
+                                .lower_bounds(bounds(y_lower))
+                                .upper_bounds(bounds(y_upper))
+                                .build()
+                                .unwrap(),
+                        )
+                        .build(),
+                )
+                .unwrap();
+        }
+        let manifest_file = writer.write_manifest_file().await.unwrap();
+
+        let mut manifest_list_write = ManifestListWriter::v2(
+            fixture
+                .table
+                .file_io()
+                .new_output(current_snapshot.manifest_list())
+                .unwrap(),
+            current_snapshot.snapshot_id(),
+            current_snapshot.parent_snapshot_id(),
+            current_snapshot.sequence_number(),
+        );
+        manifest_list_write
+            .add_manifests(vec![manifest_file.clone()].into_iter())
+            .unwrap();
+        manifest_list_write.close().await.unwrap();
+
+        let mut planned = vec![];
+        for selective_metrics_decoding in [false, true] {
+            let table_scan = fixture
+                .table
+                .scan()
+                .with_filter(Reference::new("y").less_than(Datum::long(6)))
+                .with_selective_metrics_decoding(selective_metrics_decoding)
+                .build()
+                .unwrap();
+            let tasks = table_scan
                 .plan_files()
                 .await
                 .unwrap()
                 .try_collect::<Vec<_>>()
                 .await
-        })
+                .unwrap();
+            planned.push(
+                tasks
+                    .into_iter()
+                    .map(|t| t.data_file_path)
+                    .collect::<Vec<_>>(),
+            );
+        }
+        assert_eq!(planned[0], vec![format!(
+            "{}/1.parquet",
+            &fixture.table_location
+        )]);
+        assert_eq!(planned[0], planned[1]);
+
+        // A manifest decoded for planning with selective metrics decoding only holds the
+        // bounds of `y`. The table cache serves it from the manifest decoded with all its
+        // bounds by the first scan, so it is decoded again by a new cache.
+        let manifest = ObjectCache::new(fixture.table.file_io().clone())
+            .get_manifest(&manifest_file, &fixture.table.metadata_ref(), Some(&[2]))
+            .await
+            .unwrap();
+        for entry in manifest.entries() {
+            assert_eq!(
+                entry.data_file().lower_bounds().keys().collect::<Vec<_>>(),
+                vec![&2]
+            );
+            assert_eq!(
+                entry.data_file().upper_bounds().keys().collect::<Vec<_>>(),
+                vec![&2]
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_scan_estimate() {
+        let mut fixture = TableTestFixture::new();
+        fixture.setup_deadlock_manifests().await;
+
+        let table_scan = fixture
+            .table
+            .scan()
+            .with_filter(Reference::new("x").equal_to(Datum::long(100)))
+            .build()
+            .unwrap();
+        let estimate = table_scan.estimate().await.unwrap();
+        let planned_files = table_scan
+            .plan_files()
+            .await
+            .unwrap()
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap()
+            .len();
+        assert_eq!(planned_files, 10);
+        assert_eq!(estimate.data_files(), planned_files as u64);
+        assert_eq!(estimate.delete_files(), 1);
+        assert_eq!(estimate.bytes(), 1100);
+        assert_eq!(estimate.records(), 10);
+        assert_eq!(estimate.deleted_records(), 1);
+        assert_eq!(estimate.rows(), 9);
+
+        let table_scan = fixture
+            .table
+            .scan()
+            .with_filter(Reference::new("x").equal_to(Datum::long(300)))
+            .build()
+            .unwrap();
+        assert_eq!(
+            table_scan.estimate().await.unwrap(),
+            super::ScanEstimate::default()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_plan_files_skips_manifests_by_partition_summary() {
+        let fixture = TableTestFixture::new();
+        let current_snapshot = fixture.table.metadata().current_snapshot().unwrap();
+        let current_schema = current_snapshot.schema(fixture.table.metadata()).unwrap();
+        let current_partition_spec = fixture.table.metadata().default_partition_spec();
+
+        let data_file = |name: &str, x: i64| {
+            ManifestEntry::builder()
+                .status(ManifestStatus::Added)
+                .data_file(
+                    DataFileBuilder::default()
+                        .partition_spec_id(0)
+                        .content(DataContentType::Data)
+                        .file_path(format!("{}/{name}.parquet", &fixture.table_location))
+                        .file_format(DataFileFormat::Parquet)
+                        .file_size_in_bytes(100)
+                        .record_count(1)
+                        .partition(Struct::from_iter([Some(Literal::long(x))]))
+                        .build()
+                        .unwrap(),
+                )
+                .build()
+        };
+
+        // Each manifest covers a disjoint range of the partition column `x`.
+        let mut manifests = vec![];
+        for entries in [
+            vec![data_file("1", 100), data_file("2", 150)],
+            vec![data_file("3", 200), data_file("4", 250)],
+            vec![data_file("5", 300), data_file("6", 350)],
+        ] {
+            let mut writer = ManifestWriterBuilder::new(
+                fixture.next_manifest_file(),
+                Some(current_snapshot.snapshot_id()),
+                None,
+                current_schema.clone(),
+                current_partition_spec.as_ref().clone(),
+            )
+            .build_v2_data();
+            for entry in entries {
+                writer.add_entry(entry).unwrap();
+            }
+            manifests.push(writer.write_manifest_file().await.unwrap());
+        }
+
+        let mut manifest_list_write = ManifestListWriter::v2(
+            fixture
+                .table
+                .file_io()
+                .new_output(current_snapshot.manifest_list())
+                .unwrap(),
+            current_snapshot.snapshot_id(),
+            current_snapshot.parent_snapshot_id(),
+            current_snapshot.sequence_number(),
+        );
+        manifest_list_write
+            .add_manifests(manifests.clone().into_iter())
+            .unwrap();
+        manifest_list_write.close().await.unwrap();
+
+        // Planning must not open the manifests that can't match, so removing them
+        // doesn't affect the scan.
+        for manifest in [&manifests[0], &manifests[2]] {
+            fixture
+                .table
+                .file_io()
+                .delete(&manifest.manifest_path)
+                .await
+                .unwrap();
+        }
+
+        let table_scan = fixture
+            .table
+            .scan()
+            .with_filter(
+                Reference::new("x")
+                    .greater_than_or_equal_to(Datum::long(200))
+                    .and(Reference::new("x").less_than(Datum::long(300))),
+            )
+            .build()
+            .unwrap();
+
+        let (tasks, metrics) = table_scan.plan_files_with_metrics().await.unwrap();
+        let mut tasks = tasks.try_collect::<Vec<_>>().await.unwrap();
+        tasks.sort_by_key(|t| t.data_file_path.to_string());
+
+        assert_eq!(
+            tasks
+                .iter()
+                .map(|t| t.data_file_path.as_str())
+                .collect::<Vec<_>>(),
+            vec![
+                format!("{}/3.parquet", &fixture.table_location),
+                format!("{}/4.parquet", &fixture.table_location),
+            ]
+        );
+        assert_eq!(metrics.total_data_manifests(), 3);
+        assert_eq!(metrics.skipped_data_manifests(), 2);
+    }
+
+    /// Returns the table of `fixture` with a current snapshot referencing its manifest list, its
+    /// manifest and its data files `data/1.parquet` and `data/2.parquet` by relative paths.
+    async fn relative_paths_table(fixture: &TableTestFixture) -> Table {
+        let metadata = fixture.table.metadata();
+        let current_snapshot = metadata.current_snapshot().unwrap();
+        let current_schema = current_snapshot.schema(metadata).unwrap();
+        let current_partition_spec = metadata.default_partition_spec();
+
+        // The metadata references the manifest list, the manifest list the manifest and the
+        // manifest the data files by paths relative to the table location.
+        let mut writer = ManifestWriterBuilder::new(
+            fixture.next_manifest_file(),
+            Some(current_snapshot.snapshot_id()),
+            None,
+            current_schema.clone(),
+            current_partition_spec.as_ref().clone(),
+        )
+        .build_v2_data();
+        for name in ["1", "2"] {
+            writer
+                .add_entry(
+                    ManifestEntry::builder()
+                        .status(ManifestStatus::Added)
+                        .data_file(
+                            DataFileBuilder::default()
+                                .partition_spec_id(0)
+                                .content(DataContentType::Data)
+                                .file_path(format!("data/{name}.parquet"))
+                                .file_format(DataFileFormat::Parquet)
+                                .file_size_in_bytes(100)
+                                .record_count(1)
+                                .partition(Struct::from_iter([Some(Literal::long(100))]))
+                                .build()
+                                .unwrap(),
+                        )
+                        .build(),
+                )
+                .unwrap();
+        }
+        let mut manifest = writer.write_manifest_file().await.unwrap();
+        manifest.manifest_path = manifest
+            .manifest_path
+            .strip_prefix(&format!("{}/", fixture.table_location))
+            .unwrap()
+            .to_string();
+
+        let manifest_list = "metadata/relative_manifest_list.avro";
+        let mut manifest_list_write = ManifestListWriter::v2(
+            fixture
+                .table
+                .file_io()
+                .new_output(format!("{}/{manifest_list}", fixture.table_location))
+                .unwrap(),
+            current_snapshot.snapshot_id(),
+            current_snapshot.parent_snapshot_id(),
+            current_snapshot.sequence_number(),
+        );
+        manifest_list_write
+            .add_manifests(vec![manifest].into_iter())
+            .unwrap();
+        manifest_list_write.close().await.unwrap();
+
+        let mut metadata_json = serde_json::to_value(metadata).unwrap();
+        for snapshot in metadata_json["snapshots"].as_array_mut().unwrap() {
+            if snapshot["snapshot-id"] == current_snapshot.snapshot_id() {
+                snapshot["manifest-list"] = manifest_list.into();
+            }
+        }
+        Table::builder()
+            .metadata(serde_json::from_value::<TableMetadata>(metadata_json).unwrap())
+            .identifier(fixture.table.identifier().clone())
+            .file_io(fixture.table.file_io().clone())
+            .metadata_location(fixture.table.metadata_location().unwrap())
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_plan_files_resolves_relative_paths() {
+        let fixture = TableTestFixture::new();
+        let table = relative_paths_table(&fixture).await;
+
+        let mut tasks = table
+            .scan()
+            .build()
+            .unwrap()
+            .plan_files()
+            .await
+            .unwrap()
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        tasks.sort_by_key(|t| t.data_file_path.to_string());
+
+        assert_eq!(
+            tasks
+                .iter()
+                .map(|t| t.data_file_path.as_str())
+                .collect::<Vec<_>>(),
+            vec![
+                format!("{}/data/1.parquet", &fixture.table_location),
+                format!("{}/data/2.parquet", &fixture.table_location),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_commit_keeps_relative_paths() {
+        use crate::transaction::{Transaction, TransactionAction};
+
+        let fixture = TableTestFixture::new();
+        let table = relative_paths_table(&fixture).await;
+        let data_file = |path: String| {
+            DataFileBuilder::default()
+                .partition_spec_id(0)
+                .content(DataContentType::Data)
+                .file_path(path)
+                .file_format(DataFileFormat::Parquet)
+                .file_size_in_bytes(100)
+                .record_count(1)
+                .partition(Struct::from_iter([Some(Literal::long(100))]))
+                .build()
+                .unwrap()
+        };
+        let manifest_entries = |table: Table| async move {
+            let snapshot = table.metadata().current_snapshot().unwrap();
+            let manifest_list = snapshot
+                .load_manifest_list(table.file_io(), table.metadata())
+                .await
+                .unwrap();
+            let mut entries = vec![];
+            for manifest_file in manifest_list.entries() {
+                let manifest = manifest_file
+                    .load_manifest(table.file_io(), table.metadata())
+                    .await
+                    .unwrap();
+                for entry in manifest.entries() {
+                    entries.push((
+                        manifest_file.manifest_path.clone(),
+                        entry.file_path().to_string(),
+                        entry.status(),
+                    ));
+                }
+            }
+            entries.sort_by(|a, b| (&a.0, &a.1).cmp(&(&b.0, &b.1)));
+            entries
+        };
+        let relative_manifest_path = manifest_entries(table.clone()).await[0].0.clone();
+        assert!(relative_manifest_path.starts_with("metadata/"));
+
+        // Appending carries the existing manifest over with its relative path.
+        let action = Transaction::new(&table)
+            .fast_append()
+            .add_data_files(vec![data_file("data/3.parquet".to_string())]);
+        let mut action_commit = Arc::new(action).commit(&table).await.unwrap();
+        let table =
+            Transaction::update_table_metadata(table, &action_commit.take_updates()).unwrap();
+        let entries = manifest_entries(table.clone()).await;
+        assert_eq!(
+            entries
+                .iter()
+                .filter(|(manifest_path, ..)| *manifest_path == relative_manifest_path)
+                .map(|(_, file_path, _)| file_path.as_str())
+                .collect::<Vec<_>>(),
+            vec!["data/1.parquet", "data/2.parquet"]
+        );
+
+        // A data file deleted by its path as reported by scans is matched with its relative
+        // path, and the rewritten manifest keeps the relative paths of its entries.
+        let action = Transaction::new(&table).delete().delete_positions(
+            data_file(format!("{}/data/1.parquet", fixture.table_location)),
+            [0],
+        );
+        let mut action_commit = Arc::new(action).commit(&table).await.unwrap();
+        let table =
+            Transaction::update_table_metadata(table, &action_commit.take_updates()).unwrap();
+        let mut entries = manifest_entries(table)
+            .await
+            .into_iter()
+            .map(|(_, file_path, status)| (file_path, status))
+            .collect::<Vec<_>>();
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(entries, vec![
+            ("data/1.parquet".to_string(), ManifestStatus::Deleted),
+            ("data/2.parquet".to_string(), ManifestStatus::Existing),
+            ("data/3.parquet".to_string(), ManifestStatus::Added),
+        ]);
+    }
+
+    #[tokio::test]
+    async fn test_plan_files_exposes_sort_order_and_column_metrics() {
+        use crate::spec::NullOrder;
+        use crate::transaction::{ApplyTransactionAction, Transaction};
+        use crate::writer::base_writer::data_file_writer::DataFileWriterBuilder;
+        use crate::writer::file_writer::ParquetWriterBuilder;
+        use crate::writer::file_writer::location_generator::{
+            DefaultFileNameGenerator, DefaultLocationGenerator,
+        };
+        use crate::writer::file_writer::rolling_writer::RollingFileWriterBuilder;
+        use crate::writer::{IcebergWriter, IcebergWriterBuilder};
+
+        let (catalog, table) = new_table(
+            Schema::builder()
+                .with_fields(vec![
+                    NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+                ])
+                .build()
+                .unwrap(),
+        )
         .await;
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .replace_sort_order()
+            .asc("id", NullOrder::First)
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+        let sort_order_id = table.metadata().default_sort_order_id() as i32;
+        assert_ne!(sort_order_id, 0);
+
+        let schema = table.metadata().current_schema().clone();
+        let rolling_writer_builder = RollingFileWriterBuilder::new_with_default_file_size(
+            ParquetWriterBuilder::new(WriterProperties::builder().build(), schema.clone()),
+            table.file_io().clone(),
+            DefaultLocationGenerator::new(table.metadata().clone()).unwrap(),
+            DefaultFileNameGenerator::new("test".to_string(), None, DataFileFormat::Parquet),
+        );
+        let mut writer = DataFileWriterBuilder::new(
+            rolling_writer_builder,
+            table.metadata().default_partition_spec_id(),
+        )
+        .with_sort_order_id(sort_order_id)
+        .build(None)
+        .await
+        .unwrap();
+        writer
+            .write(
+                RecordBatch::try_new(
+                    Arc::new(crate::arrow::schema_to_arrow_schema(&schema).unwrap()),
+                    vec![Arc::new(Int64Array::from(vec![1, 2, 3])) as ArrayRef],
+                )
+                .unwrap(),
+            )
+            .await
+            .unwrap();
+        let data_files = writer.close().await.unwrap();
 
-        // Assert it finished (didn't timeout)
-        assert!(result.is_ok(), "Scan timed out - deadlock detected");
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .fast_append()
+            .add_data_files(data_files)
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+
+        let tasks = table
+            .scan()
+            .build()
+            .unwrap()
+            .plan_files()
+            .await
+            .unwrap()
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].sort_order_id, Some(sort_order_id));
+        assert_eq!(tasks[0].value_counts, HashMap::from([(1, 3)]));
+        assert!(tasks[0].column_sizes[&1] > 0);
+    }
+
+    #[tokio::test]
+    async fn test_scan_nanosecond_timestamps() {
+        use arrow_array::TimestampNanosecondArray;
+
+        use crate::TableCreation;
+        use crate::spec::FormatVersion;
+        use crate::transaction::{ApplyTransactionAction, Transaction};
+        use crate::writer::base_writer::data_file_writer::DataFileWriterBuilder;
+        use crate::writer::file_writer::ParquetWriterBuilder;
+        use crate::writer::file_writer::location_generator::{
+            DefaultFileNameGenerator, DefaultLocationGenerator,
+        };
+        use crate::writer::file_writer::rolling_writer::RollingFileWriterBuilder;
+        use crate::writer::{IcebergWriter, IcebergWriterBuilder};
+
+        let catalog = crate::memory::tests::new_memory_catalog().await;
+        let table = create_table(
+            &catalog,
+            TableCreation::builder()
+                .name("t".to_string())
+                .format_version(FormatVersion::V3)
+                .partition_spec(
+                    crate::spec::UnboundPartitionSpec::builder()
+                        .add_partition_field(2, "ts_tz", crate::spec::Transform::Identity)
+                        .unwrap()
+                        .build(),
+                )
+                .schema(
+                    Schema::builder()
+                        .with_fields(vec![
+                            NestedField::required(
+                                1,
+                                "ts",
+                                Type::Primitive(PrimitiveType::TimestampNs),
+                            )
+                            .into(),
+                            NestedField::optional(
+                                2,
+                                "ts_tz",
+                                Type::Primitive(PrimitiveType::TimestamptzNs),
+                            )
+                            .into(),
+                        ])
+                        .build()
+                        .unwrap(),
+                )
+                .build(),
+        )
+        .await;
+
+        // The files only differ below the microsecond, and are partitioned by their first value.
+        let schema = table.metadata().current_schema().clone();
+        let arrow_schema = Arc::new(crate::arrow::schema_to_arrow_schema(&schema).unwrap());
+        let mut data_files = vec![];
+        for nanos in [
+            vec![1_600_000_000_000_000_001_i64, 1_600_000_000_000_000_002],
+            vec![1_600_000_000_000_000_998, 1_600_000_000_000_000_999],
+        ] {
+            let rolling_writer_builder = RollingFileWriterBuilder::new_with_default_file_size(
+                ParquetWriterBuilder::new(WriterProperties::builder().build(), schema.clone()),
+                table.file_io().clone(),
+                DefaultLocationGenerator::new(table.metadata().clone()).unwrap(),
+                DefaultFileNameGenerator::new(
+                    format!("test-{}", nanos[0]),
+                    None,
+                    DataFileFormat::Parquet,
+                ),
+            );
+            let mut writer = DataFileWriterBuilder::new(
+                rolling_writer_builder,
+                table.metadata().default_partition_spec_id(),
+            )
+            .build(Some(crate::spec::PartitionKey::new(
+                table.metadata().default_partition_spec().as_ref().clone(),
+                schema.clone(),
+                Struct::from_iter([Some(Literal::timestamptz_nano(nanos[0]))]),
+            )))
+            .await
+            .unwrap();
+            writer
+                .write(
+                    RecordBatch::try_new(arrow_schema.clone(), vec![
+                        Arc::new(TimestampNanosecondArray::from(nanos.clone())) as ArrayRef,
+                        Arc::new(
+                            TimestampNanosecondArray::from(vec![nanos[0]; 2])
+                                .with_timezone("+00:00"),
+                        ) as ArrayRef,
+                    ])
+                    .unwrap(),
+                )
+                .await
+                .unwrap();
+            data_files.extend(writer.close().await.unwrap());
+        }
+        assert_eq!(
+            data_files[0].lower_bounds()[&1],
+            Datum::timestamp_nanos(1_600_000_000_000_000_001)
+        );
+        assert_eq!(
+            data_files[1].upper_bounds()[&2],
+            Datum::timestamptz_nanos(1_600_000_000_000_000_998)
+        );
+
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .fast_append()
+            .add_data_files(data_files)
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+
+        let scan = table
+            .scan()
+            .with_filter(
+                Reference::new("ts")
+                    .greater_than(Datum::timestamp_nanos(1_600_000_000_000_000_500)),
+            )
+            .build()
+            .unwrap();
+        let tasks: Vec<_> = scan
+            .plan_files()
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(tasks.len(), 1);
+
+        let batches: Vec<RecordBatch> = scan.to_arrow().await.unwrap().try_collect().await.unwrap();
+        assert_eq!(batches.len(), 1);
+        assert_eq!(
+            batches[0].column(0).data_type(),
+            &arrow_schema::DataType::Timestamp(arrow_schema::TimeUnit::Nanosecond, None)
+        );
+        let ts = batches[0]
+            .column(0)
+            .as_primitive::<arrow_array::types::TimestampNanosecondType>();
+        assert_eq!(ts.values().to_vec(), vec![
+            1_600_000_000_000_000_998,
+            1_600_000_000_000_000_999
+        ]);
+        let ts_tz = batches[0]
+            .column(1)
+            .as_primitive::<arrow_array::types::TimestampNanosecondType>();
+        assert_eq!(ts_tz.timezone(), Some("+00:00"));
+        assert_eq!(ts_tz.value(0), 1_600_000_000_000_000_998);
+
+        let tasks: Vec<_> = table
+            .scan()
+            .with_filter(
+                Reference::new("ts_tz")
+                    .equal_to(Datum::timestamptz_nanos(1_600_000_000_000_000_001)),
+            )
+            .build()
+            .unwrap()
+            .plan_files()
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(
+            tasks[0].partition,
+            Some(Struct::from_iter([Some(Literal::timestamptz_nano(
+                1_600_000_000_000_000_001
+            ))]))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_plan_files_reads_manifests_concurrently() {
+        let fixture = TableTestFixture::new();
+        let current_snapshot = fixture.table.metadata().current_snapshot().unwrap();
+        let current_schema = current_snapshot.schema(fixture.table.metadata()).unwrap();
+        let current_partition_spec = fixture.table.metadata().default_partition_spec();
+
+        let mut manifests = vec![];
+        for manifest_idx in 0..50 {
+            let mut writer = ManifestWriterBuilder::new(
+                fixture.next_manifest_file(),
+                Some(current_snapshot.snapshot_id()),
+                None,
+                current_schema.clone(),
+                current_partition_spec.as_ref().clone(),
+            )
+            .build_v2_data();
+            for file_idx in 0..2 {
+                writer
+                    .add_entry(
+                        ManifestEntry::builder()
+                            .status(ManifestStatus::Added)
+                            .data_file(
+                                DataFileBuilder::default()
+                                    .partition_spec_id(0)
+                                    .content(DataContentType::Data)
+                                    .file_path(format!(
+                                        "{}/{manifest_idx}-{file_idx}.parquet",
+                                        &fixture.table_location
+                                    ))
+                                    .file_format(DataFileFormat::Parquet)
+                                    .file_size_in_bytes(100)
+                                    .record_count(1)
+                                    .partition(Struct::from_iter([Some(Literal::long(
+                                        manifest_idx,
+                                    ))]))
+                                    .build()
+                                    .unwrap(),
+                            )
+                            .build(),
+                    )
+                    .unwrap();
+            }
+            manifests.push(writer.write_manifest_file().await.unwrap());
+        }
+
+        let mut manifest_list_write = ManifestListWriter::v2(
+            fixture
+                .table
+                .file_io()
+                .new_output(current_snapshot.manifest_list())
+                .unwrap(),
+            current_snapshot.snapshot_id(),
+            current_snapshot.parent_snapshot_id(),
+            current_snapshot.sequence_number(),
+        );
+        manifest_list_write
+            .add_manifests(manifests.into_iter())
+            .unwrap();
+        manifest_list_write.close().await.unwrap();
+
+        let planned_paths = |manifest_file_concurrency_limit: usize| {
+            let table_scan = fixture
+                .table
+                .scan()
+                .with_manifest_file_concurrency_limit(manifest_file_concurrency_limit)
+                .build()
+                .unwrap();
+            async move {
+                let mut paths = table_scan
+                    .plan_files()
+                    .await
+                    .unwrap()
+                    .map_ok(|task| task.data_file_path)
+                    .try_collect::<Vec<_>>()
+                    .await
+                    .unwrap();
+                paths.sort();
+                paths
+            }
+        };
+
+        let result = tokio::time::timeout(std::time::Duration::from_secs(30), async {
+            (planned_paths(1).await, planned_paths(8).await)
+        })
+        .await;
+        let (serial, concurrent) = result.expect("planning 50 manifests timed out");
+
+        assert_eq!(serial.len(), 100);
+        assert_eq!(serial, concurrent);
+    }
+
+    #[tokio::test]
+    async fn test_scan_backfills_identity_partition_column_missing_from_data_files() {
+        let fixture = TableTestFixture::new();
+        let current_snapshot = fixture.table.metadata().current_snapshot().unwrap();
+        let current_schema = current_snapshot.schema(fixture.table.metadata()).unwrap();
+        let current_partition_spec = fixture.table.metadata().default_partition_spec();
+
+        // The first data file only stores `y`, its identity-partitioned `x` lives in the
+        // partition tuple of its manifest entry. The second one stores both columns.
+        let x_field =
+            arrow_schema::Field::new("x", arrow_schema::DataType::Int64, false).with_metadata(
+                HashMap::from([(PARQUET_FIELD_ID_META_KEY.to_string(), "1".to_string())]),
+            );
+        let y_field =
+            arrow_schema::Field::new("y", arrow_schema::DataType::Int64, false).with_metadata(
+                HashMap::from([(PARQUET_FIELD_ID_META_KEY.to_string(), "2".to_string())]),
+            );
+
+        let mut writer = ManifestWriterBuilder::new(
+            fixture.next_manifest_file(),
+            Some(current_snapshot.snapshot_id()),
+            None,
+            current_schema.clone(),
+            current_partition_spec.as_ref().clone(),
+        )
+        .build_v2_data();
+        fs::create_dir_all(&fixture.table_location).unwrap();
+        for (x, y, stores_x) in [(100, 1, false), (200, 2, true)] {
+            let file_path = format!("{}/x={x}.parquet", &fixture.table_location);
+            let ys = Arc::new(Int64Array::from_iter_values(vec![y; 3])) as ArrayRef;
+            let batch = if stores_x {
+                RecordBatch::try_new(
+                    Arc::new(arrow_schema::Schema::new(vec![
+                        x_field.clone(),
+                        y_field.clone(),
+                    ])),
+                    vec![
+                        Arc::new(Int64Array::from_iter_values(vec![x; 3])) as ArrayRef,
+                        ys,
+                    ],
+                )
+            } else {
+                RecordBatch::try_new(
+                    Arc::new(arrow_schema::Schema::new(vec![y_field.clone()])),
+                    vec![ys],
+                )
+            }
+            .unwrap();
+            let mut parquet_writer =
+                ArrowWriter::try_new(File::create(&file_path).unwrap(), batch.schema(), None)
+                    .unwrap();
+            parquet_writer.write(&batch).unwrap();
+            parquet_writer.close().unwrap();
+
+            writer
+                .add_entry(
+                    ManifestEntry::builder()
+                        .status(ManifestStatus::Added)
+                        .data_file(
+                            DataFileBuilder::default()
+                                .partition_spec_id(0)
+                                .content(DataContentType::Data)
+                                .file_path(file_path)
+                                .file_format(DataFileFormat::Parquet)
+                                .file_size_in_bytes(100)
+                                .record_count(3)
+                                .partition(Struct::from_iter([Some(Literal::long(x))]))
+                                .build()
+                                .unwrap(),
+                        )
+                        .build(),
+                )
+                .unwrap();
+        }
+        let data_file_manifest = writer.write_manifest_file().await.unwrap();
+
+        let mut manifest_list_write = ManifestListWriter::v2(
+            fixture
+                .table
+                .file_io()
+                .new_output(current_snapshot.manifest_list())
+                .unwrap(),
+            current_snapshot.snapshot_id(),
+            current_snapshot.parent_snapshot_id(),
+            current_snapshot.sequence_number(),
+        );
+        manifest_list_write
+            .add_manifests(vec![data_file_manifest].into_iter())
+            .unwrap();
+        manifest_list_write.close().await.unwrap();
+
+        // Both files are read in one stream, coalescing their batches together.
+        for batch_size in [None, Some(4)] {
+            let table_scan = fixture
+                .table
+                .scan()
+                .select(["x", "y"])
+                .with_batch_size(batch_size)
+                .build()
+                .unwrap();
+            let batches: Vec<_> = table_scan
+                .to_arrow()
+                .await
+                .unwrap()
+                .try_collect()
+                .await
+                .unwrap();
+
+            let mut rows = vec![];
+            for batch in &batches {
+                // The partition values of both files have the type of the table schema.
+                assert_eq!(
+                    batch.schema().field(0).data_type(),
+                    &arrow_schema::DataType::Int64
+                );
+                let xs = batch
+                    .column(0)
+                    .as_primitive::<arrow_array::types::Int64Type>();
+                let ys = batch
+                    .column(1)
+                    .as_primitive::<arrow_array::types::Int64Type>();
+                rows.extend(xs.values().iter().copied().zip(ys.values().iter().copied()));
+            }
+            rows.sort();
+
+            assert_eq!(rows, vec![
+                (100, 1),
+                (100, 1),
+                (100, 1),
+                (200, 2),
+                (200, 2),
+                (200, 2)
+            ]);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_scan_v1_snapshot_with_inline_manifests() {
+        let tmp_dir = TempDir::new().unwrap();
+        let table_location = tmp_dir.path().join("table1");
+        let table_location = table_location.to_str().unwrap();
+        let manifest_locations: Vec<_> = (0..2)
+            .map(|i| format!("{table_location}/metadata/manifest-{i}.avro"))
+            .collect();
+        // A v1 snapshot listing its manifests inline, without a manifest list.
+        let metadata_json = format!(
+            r#"{{
+                "format-version": 1,
+                "table-uuid": "d20125c8-7284-442c-9aea-15fee620737c",
+                "location": "{table_location}",
+                "last-updated-ms": 1700000000000,
+                "last-column-id": 1,
+                "schema": {{
+                    "type": "struct",
+                    "schema-id": 0,
+                    "fields": [{{"id": 1, "name": "x", "required": true, "type": "long"}}]
+                }},
+                "partition-spec": [],
+                "properties": {{}},
+                "current-snapshot-id": 1,
+                "snapshots": [{{
+                    "snapshot-id": 1,
+                    "timestamp-ms": 1600000000000,
+                    "summary": {{"operation": "append"}},
+                    "manifests": {}
+                }}]
+            }}"#,
+            serde_json::to_string(&manifest_locations).unwrap()
+        );
+        let table_metadata = serde_json::from_str::<TableMetadata>(&metadata_json).unwrap();
+        let file_io = FileIO::from_path(table_location).unwrap().build().unwrap();
+        let table = Table::builder()
+            .metadata(table_metadata)
+            .identifier(TableIdent::from_strs(["db", "table1"]).unwrap())
+            .file_io(file_io.clone())
+            .metadata_location(format!("{table_location}/metadata/v1.json"))
+            .build()
+            .unwrap();
+        let snapshot = table.metadata().current_snapshot().unwrap();
+        assert_eq!(snapshot.manifests(), Some(manifest_locations.as_slice()));
+
+        let file_schema = Arc::new(arrow_schema::Schema::new(vec![
+            arrow_schema::Field::new("x", arrow_schema::DataType::Int64, false).with_metadata(
+                HashMap::from([(PARQUET_FIELD_ID_META_KEY.to_string(), "1".to_string())]),
+            ),
+        ]));
+        fs::create_dir_all(format!("{table_location}/data")).unwrap();
+        for (i, manifest_location) in manifest_locations.iter().enumerate() {
+            let file_path = format!("{table_location}/data/{i}.parquet");
+            let batch = RecordBatch::try_new(file_schema.clone(), vec![Arc::new(
+                Int64Array::from_iter_values((0..3).map(|x| i as i64 * 10 + x)),
+            ) as ArrayRef])
+            .unwrap();
+            let mut parquet_writer =
+                ArrowWriter::try_new(File::create(&file_path).unwrap(), file_schema.clone(), None)
+                    .unwrap();
+            parquet_writer.write(&batch).unwrap();
+            parquet_writer.close().unwrap();
+
+            let mut writer = ManifestWriterBuilder::new(
+                file_io.new_output(manifest_location).unwrap(),
+                Some(snapshot.snapshot_id()),
+                None,
+                table.metadata().current_schema().clone(),
+                table.metadata().default_partition_spec().as_ref().clone(),
+            )
+            .build_v1();
+            writer
+                .add_entry(
+                    ManifestEntry::builder()
+                        .status(ManifestStatus::Added)
+                        .data_file(
+                            DataFileBuilder::default()
+                                .partition_spec_id(0)
+                                .content(DataContentType::Data)
+                                .file_path(file_path)
+                                .file_format(DataFileFormat::Parquet)
+                                .file_size_in_bytes(100)
+                                .record_count(3)
+                                .partition(Struct::empty())
+                                .build()
+                                .unwrap(),
+                        )
+                        .build(),
+                )
+                .unwrap();
+            writer.write_manifest_file().await.unwrap();
+        }
+
+        let manifest_list = snapshot
+            .load_manifest_list(table.file_io(), table.metadata())
+            .await
+            .unwrap();
+        let manifest_paths: Vec<_> = manifest_list
+            .entries()
+            .iter()
+            .map(|manifest_file| manifest_file.manifest_path.clone())
+            .collect();
+        assert_eq!(manifest_paths, manifest_locations);
+        assert!(
+            manifest_list
+                .entries()
+                .iter()
+                .all(|manifest_file| manifest_file.added_files_count == Some(1)
+                    && manifest_file.added_rows_count == Some(3))
+        );
+
+        let table_scan = table.scan().build().unwrap();
+        let tasks: Vec<_> = table_scan
+            .plan_files()
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(tasks.len(), 2);
+        let batches: Vec<_> = table_scan
+            .to_arrow()
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        let mut xs: Vec<_> = batches
+            .iter()
+            .flat_map(|batch| {
+                batch
+                    .column(0)
+                    .as_primitive::<arrow_array::types::Int64Type>()
+                    .values()
+                    .to_vec()
+            })
+            .collect();
+        xs.sort();
+        assert_eq!(xs, vec![0, 1, 2, 10, 11, 12]);
+    }
+
+    #[tokio::test]
+    async fn test_scan_deadlock() {
+        let mut fixture = TableTestFixture::new();
+        fixture.setup_deadlock_manifests().await;
+
+        // Create table scan with concurrency limit 1
+        // This sets channel size to 1.
+        // Data manifest has 10 entries -> will block producer.
+        // Delete manifest is 2nd in list -> won't be processed.
+        // Consumer 2 (Data) not started -> blocked.
+        // Consumer 1 (Delete) waiting -> blocked.
+        let table_scan = fixture
+            .table
+            .scan()
+            .with_concurrency_limit(1)
+            .build()
+            .unwrap();
+
+        // This should timeout/hang if deadlock exists
+        // We can use tokio::time::timeout
+        let result = tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            table_scan
+                .plan_files()
+                .await
+                .unwrap()
+                .try_collect::<Vec<_>>()
+                .await
+        })
+        .await;
+
+        // Assert it finished (didn't timeout)
+        assert!(result.is_ok(), "Scan timed out - deadlock detected");
+    }
+
+    #[tokio::test]
+    async fn test_plan_files_with_file_splitting() {
+        use crate::transaction::{ApplyTransactionAction, Transaction};
+        use crate::writer::base_writer::data_file_writer::DataFileWriterBuilder;
+        use crate::writer::file_writer::ParquetWriterBuilder;
+        use crate::writer::file_writer::location_generator::{
+            DefaultFileNameGenerator, DefaultLocationGenerator,
+        };
+        use crate::writer::file_writer::rolling_writer::RollingFileWriterBuilder;
+        use crate::writer::{IcebergWriter, IcebergWriterBuilder};
+
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+            ])
+            .build()
+            .unwrap();
+        let (catalog, table) = new_table(schema).await;
+
+        // A file with three row groups of two rows.
+        let schema = table.metadata().current_schema().clone();
+        let rolling_writer_builder = RollingFileWriterBuilder::new_with_default_file_size(
+            ParquetWriterBuilder::new(
+                WriterProperties::builder()
+                    .set_max_row_group_size(2)
+                    .build(),
+                schema.clone(),
+            ),
+            table.file_io().clone(),
+            DefaultLocationGenerator::new(table.metadata().clone()).unwrap(),
+            DefaultFileNameGenerator::new("split".to_string(), None, DataFileFormat::Parquet),
+        );
+        let mut writer = DataFileWriterBuilder::new(
+            rolling_writer_builder,
+            table.metadata().default_partition_spec_id(),
+        )
+        .build(None)
+        .await
+        .unwrap();
+        let batch = RecordBatch::try_new(
+            Arc::new(crate::arrow::schema_to_arrow_schema(&schema).unwrap()),
+            vec![Arc::new(Int64Array::from_iter_values(0..6)) as ArrayRef],
+        )
+        .unwrap();
+        writer.write(batch).await.unwrap();
+        let data_files = writer.close().await.unwrap();
+        assert_eq!(data_files[0].split_offsets().unwrap().len(), 3);
+        let data_file = data_files[0].clone();
+
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .fast_append()
+            .add_data_files(data_files)
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .delete()
+            .delete_positions(data_file.clone(), [1, 4])
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+
+        let scan = table.scan().with_file_splitting(true).build().unwrap();
+        let tasks: Vec<_> = scan
+            .plan_files()
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(tasks.len(), 3);
+        assert_eq!(tasks[0].start, 4);
+        assert!(
+            tasks
+                .windows(2)
+                .all(|pair| { pair[0].start + pair[0].length == pair[1].start })
+        );
+        let last = tasks.last().unwrap();
+        assert_eq!(last.start + last.length, data_file.file_size_in_bytes());
+        assert!(tasks.iter().all(|task| task.deletes.len() == 1));
+
+        // The rows of the split tasks are the live rows of the file, the deletes apply at
+        // their position in the file.
+        let mut split_rows = vec![];
+        for task in tasks {
+            let batches: Vec<_> = table
+                .reader_builder()
+                .build()
+                .read(Box::pin(stream::iter(vec![Ok(task)])))
+                .unwrap()
+                .try_collect()
+                .await
+                .unwrap();
+            let rows: Vec<i64> = batches
+                .iter()
+                .flat_map(|batch| {
+                    batch
+                        .column(0)
+                        .as_primitive::<arrow_array::types::Int64Type>()
+                        .values()
+                        .to_vec()
+                })
+                .collect();
+            split_rows.push(rows);
+        }
+        assert_eq!(split_rows, vec![vec![0], vec![2, 3], vec![5]]);
+
+        // The estimate counts the records of the whole file.
+        let estimate = scan.estimate().await.unwrap();
+        assert_eq!(estimate.data_files(), 1);
+        assert_eq!(estimate.records(), 6);
+        assert_eq!(estimate.rows(), 4);
+
+        let unsplit_tasks: Vec<_> = table
+            .scan()
+            .build()
+            .unwrap()
+            .plan_files()
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(unsplit_tasks.len(), 1);
+        assert_eq!(unsplit_tasks[0].record_count, Some(6));
+    }
+
+    #[tokio::test]
+    async fn test_read_file_without_field_ids_with_name_mapping() {
+        use crate::spec::{MappedField, NameMapping};
+        use crate::transaction::{ApplyTransactionAction, Transaction};
+
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+                NestedField::optional(2, "name", Type::Primitive(PrimitiveType::String)).into(),
+            ])
+            .build()
+            .unwrap();
+        let (catalog, table) = new_table(schema).await;
+
+        // A foreign file without field ids, whose columns are not in the order of the table
+        // schema.
+        let arrow_schema = Arc::new(arrow_schema::Schema::new(vec![
+            arrow_schema::Field::new("full_name", arrow_schema::DataType::Utf8, true),
+            arrow_schema::Field::new("id", arrow_schema::DataType::Int64, false),
+        ]));
+        let batch = RecordBatch::try_new(arrow_schema.clone(), vec![
+            Arc::new(StringArray::from(vec!["a", "b"])) as ArrayRef,
+            Arc::new(Int64Array::from(vec![1, 2])) as ArrayRef,
+        ])
+        .unwrap();
+        let mut content = vec![];
+        let mut writer = ArrowWriter::try_new(&mut content, arrow_schema, None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let file_size_in_bytes = content.len() as u64;
+        let path = format!("{}/data/foreign.parquet", table.metadata().location());
+        table
+            .file_io()
+            .new_output(&path)
+            .unwrap()
+            .write(content.into())
+            .await
+            .unwrap();
+        let data_file = DataFileBuilder::default()
+            .content(DataContentType::Data)
+            .file_path(path)
+            .file_format(DataFileFormat::Parquet)
+            .file_size_in_bytes(file_size_in_bytes)
+            .record_count(2)
+            .partition_spec_id(table.metadata().default_partition_spec_id())
+            .partition(Struct::empty())
+            .build()
+            .unwrap();
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .fast_append()
+            .add_data_files(vec![data_file])
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+
+        let name_mapping = NameMapping::new(vec![
+            MappedField::new(Some(1), vec!["id".to_string()], vec![]),
+            MappedField::new(
+                Some(2),
+                vec!["name".to_string(), "full_name".to_string()],
+                vec![],
+            ),
+        ]);
+        let batches: Vec<_> = table
+            .scan()
+            .with_name_mapping(name_mapping)
+            .build()
+            .unwrap()
+            .to_arrow()
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(batches.len(), 1);
+        assert_eq!(
+            batches[0]
+                .column(0)
+                .as_primitive::<arrow_array::types::Int64Type>()
+                .values()
+                .to_vec(),
+            vec![1, 2]
+        );
+        let names: Vec<_> = batches[0].column(1).as_string::<i32>().iter().collect();
+        assert_eq!(names, vec![Some("a"), Some("b")]);
+    }
+
+    #[tokio::test]
+    async fn test_plan_files_skipping_corrupt_manifest_entry() {
+        use crate::ErrorKind;
+        use crate::scan::CorruptEntryHandling;
+        use crate::transaction::{ApplyTransactionAction, Transaction};
+
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Int)).into(),
+            ])
+            .build()
+            .unwrap();
+        let (catalog, table) = new_table(schema).await;
+
+        // The lower bound of the int column of the corrupt entry has the 8 bytes of a long,
+        // so the entry fails to decode.
+        let data_file = |name: &str, lower_bound: Datum| {
+            DataFileBuilder::default()
+                .content(DataContentType::Data)
+                .file_path(format!("{}/data/{name}", table.metadata().location()))
+                .file_format(DataFileFormat::Parquet)
+                .file_size_in_bytes(100)
+                .record_count(1)
+                .partition_spec_id(0)
+                .partition(Struct::empty())
+                .lower_bounds(HashMap::from([(1, lower_bound)]))
+                .build()
+                .unwrap()
+        };
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .fast_append()
+            .add_data_files(vec![
+                data_file("good.parquet", Datum::int(1)),
+                data_file("corrupt.parquet", Datum::long(1)),
+            ])
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+
+        let scan = table.scan().build().unwrap();
+        let Err(err) = async { scan.plan_files().await?.try_collect::<Vec<_>>().await }.await
+        else {
+            panic!("planning files with a corrupt manifest entry should fail");
+        };
+        assert_eq!(err.kind(), ErrorKind::DataInvalid);
+
+        let scan = table
+            .scan()
+            .with_on_corrupt_entry(CorruptEntryHandling::SkipWithWarning)
+            .build()
+            .unwrap();
+        let (tasks, scan_metrics) = scan.plan_files_with_metrics().await.unwrap();
+        let tasks: Vec<_> = tasks.try_collect().await.unwrap();
+        assert_eq!(tasks.len(), 1);
+        assert!(tasks[0].data_file_path.ends_with("/data/good.parquet"));
+        assert_eq!(scan_metrics.skipped_corrupt_entries(), 1);
+        assert_eq!(scan_metrics.planned_data_files(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_read_timestamps_without_utc_adjustment_in_assumed_zone() {
+        use arrow_array::TimestampMicrosecondArray;
+        use arrow_array::types::TimestampMicrosecondType;
+        use arrow_schema::{DataType, Field, Schema as ArrowSchema, TimeUnit};
+
+        use crate::TableCreation;
+        use crate::memory::tests::new_memory_catalog;
+        use crate::scan::TableScan;
+        use crate::spec::TableProperties;
+        use crate::transaction::{ApplyTransactionAction, Transaction};
+
+        let catalog = new_memory_catalog().await;
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "ts", Type::Primitive(PrimitiveType::Timestamptz)).into(),
+            ])
+            .build()
+            .unwrap();
+        let table = create_table(
+            &catalog,
+            TableCreation::builder()
+                .name("t".to_string())
+                .schema(schema)
+                .properties(HashMap::from([(
+                    TableProperties::PROPERTY_READ_TIMESTAMP_ZONE.to_string(),
+                    "+03:00".to_string(),
+                )]))
+                .build(),
+        )
+        .await;
+
+        // The file stores the local time 1970-01-01T03:00:00 without UTC adjustment.
+        let arrow_schema = Arc::new(ArrowSchema::new(vec![
+            Field::new(
+                "ts",
+                DataType::Timestamp(TimeUnit::Microsecond, None),
+                false,
+            )
+            .with_metadata(HashMap::from([(
+                PARQUET_FIELD_ID_META_KEY.to_string(),
+                "1".to_string(),
+            )])),
+        ]));
+        let local_time = 3 * 3600 * 1_000_000;
+        let batch = RecordBatch::try_new(arrow_schema.clone(), vec![Arc::new(
+            TimestampMicrosecondArray::from(vec![local_time]),
+        ) as ArrayRef])
+        .unwrap();
+        let path = format!("{}/data/1.parquet", table.metadata().location());
+        let mut content = vec![];
+        let mut writer = ArrowWriter::try_new(&mut content, arrow_schema, None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        table
+            .file_io()
+            .new_output(&path)
+            .unwrap()
+            .write(content.into())
+            .await
+            .unwrap();
+        let tx = Transaction::new(&table);
+        let tx = tx.fast_append().add_file(&path, None).apply(tx).unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+
+        let read_instants = |scan: TableScan| async move {
+            let batches: Vec<_> = scan.to_arrow().await.unwrap().try_collect().await.unwrap();
+            let column = batches[0]
+                .column(0)
+                .as_primitive::<TimestampMicrosecondType>();
+            assert_eq!(
+                column.data_type(),
+                &DataType::Timestamp(TimeUnit::Microsecond, Some("+00:00".into()))
+            );
+            column.values().to_vec()
+        };
+
+        // The table property assumes the zone +03:00, so the local time is midnight UTC.
+        let scan = table.scan().build().unwrap();
+        assert_eq!(read_instants(scan).await, vec![0]);
+
+        // The scan option overrides the zone of the table property.
+        let scan = table.scan().with_timestamp_zone("-05:00").build().unwrap();
+        assert_eq!(read_instants(scan).await, vec![8 * 3600 * 1_000_000]);
+
+        assert!(table.scan().with_timestamp_zone("nowhere").build().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_scan_with_partition_columns() {
+        use crate::TableCreation;
+        use crate::memory::tests::new_memory_catalog;
+        use crate::spec::{Transform, UnboundPartitionSpec};
+        use crate::transaction::{ApplyTransactionAction, Transaction};
+        use crate::transform::create_transform_function;
+
+        let catalog = new_memory_catalog().await;
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+            ])
+            .build()
+            .unwrap();
+        let partition_spec = UnboundPartitionSpec::builder()
+            .add_partition_field(1, "id_bucket", Transform::Bucket(16))
+            .unwrap()
+            .build();
+        let table = create_table(
+            &catalog,
+            TableCreation::builder()
+                .name("t".to_string())
+                .schema(schema)
+                .partition_spec(partition_spec)
+                .build(),
+        )
+        .await;
+
+        // One data file per bucket of the ids.
+        let bucket = create_transform_function(&Transform::Bucket(16)).unwrap();
+        let bucket_of = |id: i64| match bucket.transform_literal(&Datum::long(id)).unwrap() {
+            Some(datum) => match datum.literal() {
+                crate::spec::PrimitiveLiteral::Int(bucket) => *bucket,
+                literal => panic!("Unexpected bucket {literal:?}"),
+            },
+            None => panic!("Missing bucket of {id}"),
+        };
+        let mut ids_by_bucket: HashMap<i32, Vec<i64>> = HashMap::new();
+        for id in 0..20 {
+            ids_by_bucket.entry(bucket_of(id)).or_default().push(id);
+        }
+        let arrow_schema = Arc::new(arrow_schema::Schema::new(vec![
+            arrow_schema::Field::new("id", arrow_schema::DataType::Int64, false).with_metadata(
+                HashMap::from([(PARQUET_FIELD_ID_META_KEY.to_string(), "1".to_string())]),
+            ),
+        ]));
+        let mut data_files = vec![];
+        for (bucket, ids) in &ids_by_bucket {
+            let batch =
+                RecordBatch::try_new(arrow_schema.clone(), vec![Arc::new(Int64Array::from(
+                    ids.clone(),
+                )) as ArrayRef])
+                .unwrap();
+            let mut content = vec![];
+            let mut writer =
+                ArrowWriter::try_new(&mut content, arrow_schema.clone(), None).unwrap();
+            writer.write(&batch).unwrap();
+            writer.close().unwrap();
+            let file_size_in_bytes = content.len() as u64;
+            let path = format!(
+                "{}/data/id_bucket={bucket}/data.parquet",
+                table.metadata().location()
+            );
+            table
+                .file_io()
+                .new_output(&path)
+                .unwrap()
+                .write(content.into())
+                .await
+                .unwrap();
+            data_files.push(
+                DataFileBuilder::default()
+                    .content(DataContentType::Data)
+                    .file_path(path)
+                    .file_format(DataFileFormat::Parquet)
+                    .file_size_in_bytes(file_size_in_bytes)
+                    .record_count(ids.len() as u64)
+                    .partition_spec_id(table.metadata().default_partition_spec_id())
+                    .partition(Struct::from_iter([Some(Literal::int(*bucket))]))
+                    .build()
+                    .unwrap(),
+            );
+        }
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .fast_append()
+            .add_data_files(data_files)
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+
+        let batches: Vec<_> = table
+            .scan()
+            .with_partition_columns(true)
+            .build()
+            .unwrap()
+            .to_arrow()
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+
+        let mut rows = vec![];
+        for batch in &batches {
+            let schema = batch.schema();
+            assert_eq!(schema.fields().len(), 2);
+            assert_eq!(schema.field(1).name(), "_partition.id_bucket");
+            assert_eq!(schema.field(1).data_type(), &arrow_schema::DataType::Int32);
+            let ids = batch
+                .column(0)
+                .as_primitive::<arrow_array::types::Int64Type>();
+            let buckets = batch
+                .column(1)
+                .as_primitive::<arrow_array::types::Int32Type>();
+            assert_eq!(buckets.null_count(), 0);
+            rows.extend(
+                ids.values()
+                    .iter()
+                    .copied()
+                    .zip(buckets.values().iter().copied()),
+            );
+        }
+        rows.sort();
+        assert_eq!(
+            rows,
+            (0..20).map(|id| (id, bucket_of(id))).collect::<Vec<_>>()
+        );
+
+        // Without the option, only the table columns are scanned.
+        let batches: Vec<_> = table
+            .scan()
+            .build()
+            .unwrap()
+            .to_arrow()
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        assert!(
+            batches
+                .iter()
+                .all(|batch| batch.schema().fields().len() == 1)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_plan_tasks_reads_split_offsets_from_footers() {
+        use std::time::Duration;
+
+        use crate::test_utils::fixtures::InstrumentedStorage;
+        use crate::transaction::{ApplyTransactionAction, Transaction};
+
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+            ])
+            .build()
+            .unwrap();
+        let (catalog, table) = new_table(schema).await;
+
+        // Imported files of three row groups of two rows, without split offsets.
+        let arrow_schema = Arc::new(arrow_schema::Schema::new(vec![
+            arrow_schema::Field::new("id", arrow_schema::DataType::Int64, false).with_metadata(
+                HashMap::from([(PARQUET_FIELD_ID_META_KEY.to_string(), "1".to_string())]),
+            ),
+        ]));
+        let mut data_files = vec![];
+        for file in 0..4 {
+            let batch = RecordBatch::try_new(arrow_schema.clone(), vec![Arc::new(
+                Int64Array::from_iter_values(0..6),
+            ) as ArrayRef])
+            .unwrap();
+            let mut content = vec![];
+            let props = WriterProperties::builder()
+                .set_max_row_group_size(2)
+                .build();
+            let mut writer =
+                ArrowWriter::try_new(&mut content, arrow_schema.clone(), Some(props)).unwrap();
+            writer.write(&batch).unwrap();
+            writer.close().unwrap();
+            let file_size_in_bytes = content.len() as u64;
+            let path = format!("{}/data/{file}.parquet", table.metadata().location());
+            table
+                .file_io()
+                .new_output(&path)
+                .unwrap()
+                .write(content.into())
+                .await
+                .unwrap();
+            data_files.push(
+                DataFileBuilder::default()
+                    .content(DataContentType::Data)
+                    .file_path(path)
+                    .file_format(DataFileFormat::Parquet)
+                    .file_size_in_bytes(file_size_in_bytes)
+                    .record_count(6)
+                    .partition_spec_id(table.metadata().default_partition_spec_id())
+                    .partition(Struct::empty())
+                    .build()
+                    .unwrap(),
+            );
+        }
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .fast_append()
+            .add_data_files(data_files)
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+
+        let mut storage = None;
+        let file_io = table.file_io().clone().with_wrapped_storage(|inner| {
+            let instrumented =
+                InstrumentedStorage::new(inner).with_open_delay(Duration::from_millis(50));
+            storage = Some(instrumented.clone());
+            Arc::new(instrumented)
+        });
+        let storage = storage.unwrap();
+        let table = Table::builder()
+            .metadata(table.metadata_ref())
+            .metadata_location(table.metadata_location().unwrap())
+            .identifier(table.identifier().clone())
+            .file_io(file_io)
+            .build()
+            .unwrap();
+
+        // Without reading the footers, the files are not split.
+        let table_scan = table
+            .scan()
+            .with_split_target_size(1)
+            .with_split_open_file_cost(1)
+            .build()
+            .unwrap();
+        assert_eq!(table_scan.plan_tasks().await.unwrap().len(), 4);
+        assert!(storage.readers().is_empty());
+
+        let table_scan = table
+            .scan()
+            .with_split_target_size(1)
+            .with_split_open_file_cost(1)
+            .with_manifest_entry_concurrency_limit(4)
+            .with_split_offsets_from_footers(true)
+            .with_footer_concurrency_limit(2)
+            .build()
+            .unwrap();
+        for _ in 0..2 {
+            let tasks = table_scan.plan_tasks().await.unwrap();
+            assert_eq!(tasks.len(), 12);
+            assert!(
+                tasks
+                    .iter()
+                    .flat_map(|task| task.tasks())
+                    .all(|task| task.record_count.is_none())
+            );
+        }
+
+        // The footers were read two at a time, each once for both plans of the scan.
+        assert_eq!(storage.max_in_flight(), 2);
+        let readers = storage.readers();
+        assert_eq!(readers.len(), 4);
+        assert!(readers.values().all(|count| *count == 1));
+    }
+
+    #[tokio::test]
+    async fn test_scan_partitioned_table_without_snapshot() {
+        use crate::TableCreation;
+        use crate::memory::tests::new_memory_catalog;
+        use crate::spec::{Transform, UnboundPartitionSpec};
+
+        let catalog = new_memory_catalog().await;
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+                NestedField::optional(2, "category", Type::Primitive(PrimitiveType::String)).into(),
+            ])
+            .build()
+            .unwrap();
+        let partition_spec = UnboundPartitionSpec::builder()
+            .add_partition_field(2, "category", Transform::Identity)
+            .unwrap()
+            .add_partition_field(1, "id_bucket", Transform::Bucket(4))
+            .unwrap()
+            .build();
+        let table = create_table(
+            &catalog,
+            TableCreation::builder()
+                .name("t".to_string())
+                .schema(schema)
+                .partition_spec(partition_spec)
+                .build(),
+        )
+        .await;
+        assert!(table.metadata().current_snapshot().is_none());
+        assert_eq!(table.metadata().default_partition_spec().fields().len(), 2);
+
+        let table_scan = table
+            .scan()
+            .select(["id", "category"])
+            .with_filter(Reference::new("category").equal_to(Datum::string("a")))
+            .with_partition_columns(true)
+            .build()
+            .unwrap();
+        assert!(table_scan.snapshot().is_none());
+        let tasks: Vec<_> = table_scan
+            .plan_files()
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        assert!(tasks.is_empty());
+        assert!(table_scan.plan_tasks().await.unwrap().is_empty());
+        let batches: Vec<_> = table_scan
+            .to_arrow()
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(batches.iter().map(RecordBatch::num_rows).sum::<usize>(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_scan_nested_columns_with_filter_and_deletes() {
+        use arrow_array::builder::{
+            Int32Builder, ListBuilder, MapBuilder, MapFieldNames, StringBuilder,
+        };
+
+        use crate::spec::{ListType, MapType};
+        use crate::transaction::{ApplyTransactionAction, Transaction};
+        use crate::writer::base_writer::data_file_writer::DataFileWriterBuilder;
+        use crate::writer::file_writer::ParquetWriterBuilder;
+        use crate::writer::file_writer::location_generator::{
+            DefaultFileNameGenerator, DefaultLocationGenerator,
+        };
+        use crate::writer::file_writer::rolling_writer::RollingFileWriterBuilder;
+        use crate::writer::{IcebergWriter, IcebergWriterBuilder};
+
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+                NestedField::optional(
+                    2,
+                    "values",
+                    Type::List(ListType::new(
+                        NestedField::list_element(3, Type::Primitive(PrimitiveType::Int), false)
+                            .into(),
+                    )),
+                )
+                .into(),
+                NestedField::optional(
+                    4,
+                    "tags",
+                    Type::Map(MapType::new(
+                        NestedField::map_key_element(5, Type::Primitive(PrimitiveType::String))
+                            .into(),
+                        NestedField::map_value_element(
+                            6,
+                            Type::Primitive(PrimitiveType::Int),
+                            false,
+                        )
+                        .into(),
+                    )),
+                )
+                .into(),
+            ])
+            .build()
+            .unwrap();
+        let (catalog, table) = new_table(schema).await;
+
+        // Row `id` holds the list of `id` repeated `id % 4` times, null for the ids multiple
+        // of 5, and the map from `id` to `id`.
+        let expected_values = |id: i64| (id % 5 != 0).then(|| vec![id as i32; (id % 4) as usize]);
+        let schema = table.metadata().current_schema().clone();
+        let arrow_schema = Arc::new(crate::arrow::schema_to_arrow_schema(&schema).unwrap());
+        let arrow_schema::DataType::List(element_field) = arrow_schema.field(1).data_type() else {
+            panic!("values should be a list")
+        };
+        let arrow_schema::DataType::Map(entries_field, _) = arrow_schema.field(2).data_type()
+        else {
+            panic!("tags should be a map")
+        };
+        let arrow_schema::DataType::Struct(entry_fields) = entries_field.data_type() else {
+            panic!("map entries should be a struct")
+        };
+        let mut values = ListBuilder::new(Int32Builder::new()).with_field(element_field.clone());
+        let map_field_names = MapFieldNames {
+            entry: entries_field.name().clone(),
+            key: entry_fields[0].name().clone(),
+            value: entry_fields[1].name().clone(),
+        };
+        let mut tags = MapBuilder::new(
+            Some(map_field_names),
+            StringBuilder::new(),
+            Int32Builder::new(),
+        )
+        .with_keys_field(entry_fields[0].clone())
+        .with_values_field(entry_fields[1].clone());
+        for id in 0..10 {
+            match expected_values(id) {
+                Some(list) => values.append_value(list.into_iter().map(Some)),
+                None => values.append_null(),
+            }
+            tags.keys().append_value(id.to_string());
+            tags.values().append_value(id as i32);
+            tags.append(true).unwrap();
+        }
+        let batch = RecordBatch::try_new(arrow_schema.clone(), vec![
+            Arc::new(Int64Array::from_iter_values(0..10)) as ArrayRef,
+            Arc::new(values.finish()) as ArrayRef,
+            Arc::new(tags.finish()) as ArrayRef,
+        ])
+        .unwrap();
+
+        // A file with row groups of three rows.
+        let rolling_writer_builder = RollingFileWriterBuilder::new_with_default_file_size(
+            ParquetWriterBuilder::new(
+                WriterProperties::builder()
+                    .set_max_row_group_size(3)
+                    .build(),
+                schema.clone(),
+            ),
+            table.file_io().clone(),
+            DefaultLocationGenerator::new(table.metadata().clone()).unwrap(),
+            DefaultFileNameGenerator::new("nested".to_string(), None, DataFileFormat::Parquet),
+        );
+        let mut writer = DataFileWriterBuilder::new(
+            rolling_writer_builder,
+            table.metadata().default_partition_spec_id(),
+        )
+        .build(None)
+        .await
+        .unwrap();
+        writer.write(batch).await.unwrap();
+        let data_files = writer.close().await.unwrap();
+        let data_file = data_files[0].clone();
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .fast_append()
+            .add_data_files(data_files)
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .delete()
+            .delete_positions(data_file, [2, 7])
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+
+        let predicate = Reference::new("id")
+            .greater_than_or_equal_to(Datum::long(1))
+            .and(Reference::new("id").not_equal_to(Datum::long(4)));
+        for row_selection_enabled in [false, true] {
+            let batches: Vec<_> = table
+                .scan()
+                .select(["values", "tags", "id"])
+                .with_filter(predicate.clone())
+                .with_row_selection_enabled(row_selection_enabled)
+                .build()
+                .unwrap()
+                .to_arrow()
+                .await
+                .unwrap()
+                .try_collect()
+                .await
+                .unwrap();
+
+            let mut rows = vec![];
+            for batch in &batches {
+                let values = batch.column(0).as_list::<i32>();
+                let tags = batch.column(1).as_map();
+                let ids = batch
+                    .column(2)
+                    .as_primitive::<arrow_array::types::Int64Type>();
+                for row in 0..batch.num_rows() {
+                    let list = values.is_valid(row).then(|| {
+                        values
+                            .value(row)
+                            .as_primitive::<arrow_array::types::Int32Type>()
+                            .values()
+                            .to_vec()
+                    });
+                    let entries = tags.value(row);
+                    let keys = entries.column(0).as_string::<i32>();
+                    let map_values = entries
+                        .column(1)
+                        .as_primitive::<arrow_array::types::Int32Type>();
+                    let map: Vec<(String, i32)> = (0..entries.len())
+                        .map(|entry| (keys.value(entry).to_string(), map_values.value(entry)))
+                        .collect();
+                    rows.push((ids.value(row), list, map));
+                }
+            }
+            rows.sort();
+
+            let expected: Vec<_> = [1, 3, 5, 6, 8, 9]
+                .into_iter()
+                .map(|id: i64| (id, expected_values(id), vec![(id.to_string(), id as i32)]))
+                .collect();
+            assert_eq!(
+                rows, expected,
+                "row selection enabled: {row_selection_enabled}"
+            );
+
+            // The filtered column doesn't need to be projected, the rows keep the order of
+            // the file.
+            let batches: Vec<_> = table
+                .scan()
+                .select(["tags", "values"])
+                .with_filter(predicate.clone())
+                .with_row_selection_enabled(row_selection_enabled)
+                .build()
+                .unwrap()
+                .to_arrow()
+                .await
+                .unwrap()
+                .try_collect()
+                .await
+                .unwrap();
+            let mut rows = vec![];
+            for batch in &batches {
+                assert_eq!(batch.num_columns(), 2);
+                let tags = batch.column(0).as_map();
+                let values = batch.column(1).as_list::<i32>();
+                for row in 0..batch.num_rows() {
+                    let entries = tags.value(row);
+                    let id: i64 = entries
+                        .column(0)
+                        .as_string::<i32>()
+                        .value(0)
+                        .parse()
+                        .unwrap();
+                    let list = values.is_valid(row).then(|| {
+                        values
+                            .value(row)
+                            .as_primitive::<arrow_array::types::Int32Type>()
+                            .values()
+                            .to_vec()
+                    });
+                    rows.push((id, list));
+                }
+            }
+            let expected: Vec<_> = expected
+                .into_iter()
+                .map(|(id, list, _)| (id, list))
+                .collect();
+            assert_eq!(
+                rows, expected,
+                "row selection enabled: {row_selection_enabled}"
+            );
+        }
     }
 }
@@ -0,0 +1,209 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Combining the file scan tasks of a scan into tasks of about a target size.
+
+use std::collections::HashMap;
+
+use crate::scan::{CombinedFileScanTask, FileScanTask};
+
+/// Combines `tasks`, whole files or row groups of split files, into tasks of about
+/// `target_size` bytes.
+///
+/// The consecutive row groups of a file are merged up to the target size first. The
+/// resulting tasks are then bin-packed first-fit into at most `lookback` open tasks, the
+/// oldest open task being closed when a task fits in none of them.
+pub(crate) fn combine_tasks(
+    tasks: Vec<FileScanTask>,
+    target_size: u64,
+    open_file_cost: u64,
+    lookback: usize,
+) -> Vec<CombinedFileScanTask> {
+    let mut combined = vec![];
+    let mut open_bins: Vec<(u64, Vec<FileScanTask>)> = vec![];
+    for task in merge_row_groups(tasks, target_size) {
+        let weight = task_weight(&task, open_file_cost);
+        match open_bins
+            .iter_mut()
+            .find(|(bin_weight, _)| bin_weight + weight <= target_size)
+        {
+            Some((bin_weight, bin)) => {
+                *bin_weight += weight;
+                bin.push(task);
+            }
+            None => {
+                if open_bins.len() >= lookback {
+                    let (_, bin) = open_bins.remove(0);
+                    combined.push(CombinedFileScanTask::new(bin));
+                }
+                open_bins.push((weight, vec![task]));
+            }
+        }
+    }
+    combined.extend(
+        open_bins
+            .into_iter()
+            .map(|(_, bin)| CombinedFileScanTask::new(bin)),
+    );
+    combined
+}
+
+/// Returns the weight of `task` in a combined task: its length plus the size of its delete
+/// files, but at least `open_file_cost`.
+fn task_weight(task: &FileScanTask, open_file_cost: u64) -> u64 {
+    let deletes_size: u64 = task
+        .deletes
+        .iter()
+        .map(|delete| delete.file_size_in_bytes)
+        .sum();
+    (task.length + deletes_size).max(open_file_cost)
+}
+
+/// Merges the adjacent tasks of each file while they fit in `target_size` bytes, keeping the
+/// files in the order of their first task.
+fn merge_row_groups(tasks: Vec<FileScanTask>, target_size: u64) -> Vec<FileScanTask> {
+    let mut file_indexes: HashMap<String, usize> = HashMap::new();
+    let mut files: Vec<Vec<FileScanTask>> = vec![];
+    for task in tasks {
+        match file_indexes.get(&task.data_file_path) {
+            Some(index) => files[*index].push(task),
+            None => {
+                file_indexes.insert(task.data_file_path.clone(), files.len());
+                files.push(vec![task]);
+            }
+        }
+    }
+
+    let mut merged = vec![];
+    for mut file_tasks in files {
+        if file_tasks.len() == 1 {
+            merged.append(&mut file_tasks);
+            continue;
+        }
+        file_tasks.sort_by_key(|task| task.start);
+        let mut current: Option<FileScanTask> = None;
+        for task in file_tasks {
+            match current.as_mut() {
+                Some(current)
+                    if current.start + current.length == task.start
+                        && current.length + task.length <= target_size =>
+                {
+                    current.length += task.length;
+                }
+                _ => merged.extend(current.replace(task)),
+            }
+        }
+        merged.extend(current);
+    }
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::TableCreation;
+    use crate::memory::tests::new_memory_catalog;
+    use crate::spec::{DataFile, NestedField, PrimitiveType, Schema, TableProperties, Type};
+    use crate::test_utils::fixtures::{create_table, data_file};
+    use crate::transaction::{ApplyTransactionAction, Transaction};
+
+    #[tokio::test]
+    async fn test_plan_tasks_combines_small_files_and_splits_large_files() {
+        let catalog = new_memory_catalog().await;
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+            ])
+            .build()
+            .unwrap();
+        let table = create_table(
+            &catalog,
+            TableCreation::builder()
+                .name("t".to_string())
+                .schema(schema)
+                .properties(HashMap::from([
+                    (
+                        TableProperties::PROPERTY_READ_SPLIT_TARGET_SIZE.to_string(),
+                        "1000".to_string(),
+                    ),
+                    (
+                        TableProperties::PROPERTY_READ_SPLIT_OPEN_FILE_COST.to_string(),
+                        "30".to_string(),
+                    ),
+                ]))
+                .build(),
+        )
+        .await;
+
+        let location = table.metadata().location().to_string();
+        let mut files: Vec<DataFile> = (0..60)
+            .map(|i| DataFile {
+                file_size_in_bytes: 5,
+                split_offsets: Some(vec![4]),
+                ..data_file(format!("{location}/data/small-{i}.parquet"), 1)
+            })
+            .collect();
+        let large_path = format!("{location}/data/large.parquet");
+        files.push(DataFile {
+            file_size_in_bytes: 2500,
+            split_offsets: Some(vec![4, 800, 1600]),
+            ..data_file(large_path.clone(), 1)
+        });
+        let tx = Transaction::new(&table);
+        let tx = tx.fast_append().add_data_files(files).apply(tx).unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+
+        // Each small file weighs the open file cost, so 33 of them fit in a task. The row
+        // groups of the large file are too large to be merged.
+        let tasks = table
+            .scan()
+            .with_concurrency_limit(1)
+            .build()
+            .unwrap()
+            .plan_tasks()
+            .await
+            .unwrap();
+        let task_files: Vec<usize> = tasks.iter().map(|task| task.tasks().len()).collect();
+        assert_eq!(task_files, vec![33, 27, 1, 1, 1]);
+        let large_ranges: Vec<(u64, u64)> = tasks[2..]
+            .iter()
+            .map(|task| {
+                let task = &task.tasks()[0];
+                assert_eq!(task.data_file_path, large_path);
+                (task.start, task.length)
+            })
+            .collect();
+        assert_eq!(large_ranges, vec![(4, 796), (800, 800), (1600, 900)]);
+        assert!(tasks.iter().all(|task| task.length() <= 1000));
+
+        // With a larger target size, the first two row groups of the large file are merged.
+        let tasks = table
+            .scan()
+            .with_concurrency_limit(1)
+            .with_split_target_size(2000)
+            .build()
+            .unwrap()
+            .plan_tasks()
+            .await
+            .unwrap();
+        let task_files: Vec<usize> = tasks.iter().map(|task| task.tasks().len()).collect();
+        assert_eq!(task_files, vec![60, 1, 1]);
+        assert_eq!((tasks[1].tasks()[0].start, tasks[1].length()), (4, 1596));
+        assert_eq!((tasks[2].tasks()[0].start, tasks[2].length()), (1600, 900));
+    }
+}
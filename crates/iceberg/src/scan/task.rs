@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use futures::stream::BoxStream;
@@ -24,7 +25,7 @@ use crate::Result;
 use crate::expr::BoundPredicate;
 use crate::spec::{
     DataContentType, DataFileFormat, ManifestEntryRef, NameMapping, PartitionSpec, Schema,
-    SchemaRef, Struct,
+    SchemaRef, SortOrderRef, Struct, TableMetadata,
 };
 
 /// A stream of [`FileScanTask`].
@@ -107,6 +108,22 @@ pub struct FileScanTask {
 
     /// Whether this scan task should treat column names as case-sensitive when binding predicates.
     pub case_sensitive: bool,
+
+    /// The id of the sort order the data file was written with, if it's sorted.
+    #[serde(default)]
+    pub sort_order_id: Option<i32>,
+    /// The on-disk size of each column of the data file in bytes, keyed by field id.
+    #[serde(default)]
+    pub column_sizes: HashMap<i32, u64>,
+    /// The number of values, including nulls and NaNs, of each column of the data file, keyed
+    /// by field id.
+    #[serde(default)]
+    pub value_counts: HashMap<i32, u64>,
+
+    /// The zone the values of timestamp columns stored without UTC adjustment are local
+    /// times in, when read as `timestamptz` columns. UTC if not set.
+    #[serde(default)]
+    pub timestamp_zone: Option<String>,
 }
 
 impl FileScanTask {
@@ -134,6 +151,74 @@ impl FileScanTask {
     pub fn schema_ref(&self) -> SchemaRef {
         self.schema.clone()
     }
+
+    /// Returns the sort order of `table_metadata` the data file of this task was written with.
+    ///
+    /// Returns `None` when the file has no sort order id or one that is not a sort order of
+    /// the table, in which case the file is assumed to be unsorted.
+    pub fn sort_order<'a>(&self, table_metadata: &'a TableMetadata) -> Option<&'a SortOrderRef> {
+        table_metadata.sort_order_by_id(self.sort_order_id? as i64)
+    }
+
+    /// Splits this task of a whole Parquet file at the row group offsets `split_offsets`,
+    /// one task per row group.
+    ///
+    /// The record count of the split tasks is unknown. The task is kept whole when the file
+    /// is not Parquet or the offsets are not increasing offsets within the file.
+    pub(crate) fn split_at_offsets(self, split_offsets: &[i64]) -> Vec<FileScanTask> {
+        let file_end = self.start + self.length;
+        let valid_offsets = split_offsets.len() > 1
+            && split_offsets.windows(2).all(|pair| pair[0] < pair[1])
+            && split_offsets
+                .iter()
+                .all(|offset| *offset >= 0 && (*offset as u64) < file_end);
+        if self.data_file_format != DataFileFormat::Parquet || !valid_offsets {
+            return vec![self];
+        }
+
+        let ends = split_offsets[1..]
+            .iter()
+            .map(|offset| *offset as u64)
+            .chain([file_end]);
+        split_offsets
+            .iter()
+            .zip(ends)
+            .map(|(start, end)| FileScanTask {
+                start: *start as u64,
+                length: end - *start as u64,
+                record_count: None,
+                ..self.clone()
+            })
+            .collect()
+    }
+}
+
+/// A task scanning several files or parts of files, planned by
+/// [`TableScan::plan_tasks`](crate::scan::TableScan::plan_tasks).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CombinedFileScanTask {
+    tasks: Vec<FileScanTask>,
+}
+
+impl CombinedFileScanTask {
+    pub(crate) fn new(tasks: Vec<FileScanTask>) -> Self {
+        Self { tasks }
+    }
+
+    /// Returns the file scan tasks of this task.
+    pub fn tasks(&self) -> &[FileScanTask] {
+        &self.tasks
+    }
+
+    /// Returns the file scan tasks of this task, consuming it.
+    pub fn into_tasks(self) -> Vec<FileScanTask> {
+        self.tasks
+    }
+
+    /// Returns the number of bytes of data files scanned by this task.
+    pub fn length(&self) -> u64 {
+        self.tasks.iter().map(|task| task.length).sum()
+    }
 }
 
 #[derive(Debug)]
@@ -149,6 +234,11 @@ impl From<&DeleteFileContext> for FileScanTaskDeleteFile {
             file_type: ctx.manifest_entry.content_type(),
             partition_spec_id: ctx.partition_spec_id,
             equality_ids: ctx.manifest_entry.data_file.equality_ids.clone(),
+            referenced_data_file: ctx.manifest_entry.data_file.referenced_data_file.clone(),
+            content_offset: ctx.manifest_entry.data_file.content_offset,
+            content_size_in_bytes: ctx.manifest_entry.data_file.content_size_in_bytes,
+            file_size_in_bytes: ctx.manifest_entry.file_size_in_bytes(),
+            record_count: ctx.manifest_entry.record_count(),
         }
     }
 }
@@ -167,4 +257,23 @@ pub struct FileScanTaskDeleteFile {
 
     /// equality ids for equality deletes (null for anything other than equality-deletes)
     pub equality_ids: Option<Vec<i32>>,
+
+    /// The data file the deletes apply to, if they all apply to a single data file
+    pub referenced_data_file: Option<String>,
+
+    /// The offset of the deletion vector blob in the Puffin file (null for anything other
+    /// than deletion vectors)
+    pub content_offset: Option<i64>,
+
+    /// The length of the deletion vector blob in the Puffin file (null for anything other
+    /// than deletion vectors)
+    pub content_size_in_bytes: Option<i64>,
+
+    /// The size of the delete file in bytes
+    #[serde(default)]
+    pub file_size_in_bytes: u64,
+
+    /// The number of deleted rows, or of equality delete keys for equality deletes
+    #[serde(default)]
+    pub record_count: u64,
 }
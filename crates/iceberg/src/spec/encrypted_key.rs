@@ -73,7 +73,11 @@ pub(super) mod _serde {
         pub key_id: String,
         pub encrypted_key_metadata: String, // Base64 encoded
         pub encrypted_by_id: Option<String>,
-        #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+        #[serde(
+            default,
+            skip_serializing_if = "HashMap::is_empty",
+            serialize_with = "crate::utils::serialize_sorted_map"
+        )]
         pub properties: HashMap<String, String>,
     }
 
@@ -60,6 +60,10 @@ impl ManifestEntryV2 {
                 .try_into(partition_spec_id, partition_type, schema)?,
         })
     }
+
+    pub fn retain_bounds(&mut self, field_ids: &[i32]) {
+        self.data_file.retain_bounds(field_ids)
+    }
 }
 
 #[derive(Serialize, Deserialize)]
@@ -94,6 +98,10 @@ impl ManifestEntryV1 {
                 .try_into(partition_spec_id, partition_type, schema)?,
         })
     }
+
+    pub fn retain_bounds(&mut self, field_ids: &[i32]) {
+        self.data_file.retain_bounds(field_ids)
+    }
 }
 
 #[serde_as]
@@ -163,6 +171,17 @@ impl DataFileSerde {
         })
     }
 
+    /// Drops the lower and upper bounds of the fields missing from the sorted `field_ids`, so
+    /// that they are not decoded.
+    pub fn retain_bounds(&mut self, field_ids: &[i32]) {
+        for bounds in [&mut self.lower_bounds, &mut self.upper_bounds]
+            .into_iter()
+            .flatten()
+        {
+            bounds.retain(|entry| field_ids.binary_search(&entry.key).is_ok());
+        }
+    }
+
     pub fn try_into(
         self,
         partition_spec_id: i32,
@@ -24,15 +24,17 @@ pub use entry::*;
 mod metadata;
 pub use metadata::*;
 mod writer;
+use std::collections::HashMap;
 use std::sync::Arc;
 
-use apache_avro::{Reader as AvroReader, from_value};
+use apache_avro::{Reader as AvroReader, Schema as AvroSchema, from_value};
 pub use writer::*;
 
 use super::{
-    Datum, FormatVersion, ManifestContentType, PartitionSpec, PrimitiveType, Schema, Struct,
-    UNASSIGNED_SEQUENCE_NUMBER,
+    Datum, FormatVersion, ManifestContentType, NestedField, PartitionSpec, PrimitiveType, Schema,
+    Struct, StructType, UNASSIGNED_SEQUENCE_NUMBER,
 };
+use crate::compression::CompressionCodec;
 use crate::error::Result;
 use crate::{Error, ErrorKind};
 
@@ -41,11 +43,66 @@ use crate::{Error, ErrorKind};
 pub struct Manifest {
     metadata: ManifestMetadata,
     entries: Vec<ManifestEntryRef>,
+    /// The decoding errors of the entries left out of `entries`, see
+    /// [`corrupt_entries`](Self::corrupt_entries).
+    corrupt_entries: Vec<String>,
 }
 
 impl Manifest {
     /// Parse manifest metadata and entries from bytes of avro file.
+    ///
+    /// Block compression inside the avro container is handled by the avro
+    /// reader, which verifies the CRC32 trailing each snappy block. Files that
+    /// were additionally compressed as a whole with gzip or zstd are detected
+    /// and decompressed before parsing.
     pub(crate) fn try_from_avro_bytes(bs: &[u8]) -> Result<(ManifestMetadata, Vec<ManifestEntry>)> {
+        Self::try_from_avro_bytes_with_bounds(bs, None)
+    }
+
+    /// Parse manifest metadata and entries from bytes of avro file, only decoding the column
+    /// bounds of the fields in the sorted `bound_field_ids` when set.
+    pub(crate) fn try_from_avro_bytes_with_bounds(
+        bs: &[u8],
+        bound_field_ids: Option<&[i32]>,
+    ) -> Result<(ManifestMetadata, Vec<ManifestEntry>)> {
+        let (metadata, entries) = Self::decode_avro_bytes(bs, bound_field_ids)?;
+        Ok((metadata, entries.into_iter().collect::<Result<Vec<_>>>()?))
+    }
+
+    /// Parse manifest metadata and entries from bytes of avro file, leaving out the entries
+    /// that fail to decode and returning their decoding errors.
+    ///
+    /// The manifest still fails to parse when its metadata or avro records cannot be read.
+    pub(crate) fn try_from_avro_bytes_skipping_corrupt_entries(
+        bs: &[u8],
+        bound_field_ids: Option<&[i32]>,
+    ) -> Result<(ManifestMetadata, Vec<ManifestEntry>, Vec<String>)> {
+        let (metadata, decoded_entries) = Self::decode_avro_bytes(bs, bound_field_ids)?;
+        let mut entries = Vec::with_capacity(decoded_entries.len());
+        let mut corrupt_entries = vec![];
+        for (index, entry) in decoded_entries.into_iter().enumerate() {
+            match entry {
+                Ok(entry) => entries.push(entry),
+                Err(err) => corrupt_entries.push(format!("entry {index}: {err}")),
+            }
+        }
+        Ok((metadata, entries, corrupt_entries))
+    }
+
+    /// Decodes the metadata and the entries of a manifest, each entry separately.
+    fn decode_avro_bytes(
+        bs: &[u8],
+        bound_field_ids: Option<&[i32]>,
+    ) -> Result<(ManifestMetadata, Vec<Result<ManifestEntry>>)> {
+        let codec = CompressionCodec::detect(bs);
+        let decompressed;
+        let bs = if codec.is_none() {
+            bs
+        } else {
+            decompressed = codec.decompress(bs.to_vec())?;
+            decompressed.as_slice()
+        };
+
         let reader = AvroReader::new(bs)?;
 
         // Parse manifest metadata
@@ -53,7 +110,10 @@ impl Manifest {
         let metadata = ManifestMetadata::parse(meta)?;
 
         // Parse manifest entries
-        let partition_type = metadata.partition_spec.partition_type(&metadata.schema)?;
+        let partition_type = partition_type_with_written_names(
+            metadata.partition_spec.partition_type(&metadata.schema)?,
+            reader.writer_schema(),
+        );
 
         let entries = match metadata.format_version {
             FormatVersion::V1 => {
@@ -62,11 +122,19 @@ impl Manifest {
                 reader
                     .into_iter()
                     .map(|value| {
-                        from_value::<_serde::ManifestEntryV1>(&value?)?.try_into(
-                            metadata.partition_spec.spec_id(),
-                            &partition_type,
-                            &metadata.schema,
-                        )
+                        let value = value?;
+                        Ok(from_value::<_serde::ManifestEntryV1>(&value)
+                            .map_err(Error::from)
+                            .and_then(|mut entry| {
+                                if let Some(field_ids) = bound_field_ids {
+                                    entry.retain_bounds(field_ids);
+                                }
+                                entry.try_into(
+                                    metadata.partition_spec.spec_id(),
+                                    &partition_type,
+                                    &metadata.schema,
+                                )
+                            }))
                     })
                     .collect::<Result<Vec<_>>>()?
             }
@@ -77,11 +145,19 @@ impl Manifest {
                 reader
                     .into_iter()
                     .map(|value| {
-                        from_value::<_serde::ManifestEntryV2>(&value?)?.try_into(
-                            metadata.partition_spec.spec_id(),
-                            &partition_type,
-                            &metadata.schema,
-                        )
+                        let value = value?;
+                        Ok(from_value::<_serde::ManifestEntryV2>(&value)
+                            .map_err(Error::from)
+                            .and_then(|mut entry| {
+                                if let Some(field_ids) = bound_field_ids {
+                                    entry.retain_bounds(field_ids);
+                                }
+                                entry.try_into(
+                                    metadata.partition_spec.spec_id(),
+                                    &partition_type,
+                                    &metadata.schema,
+                                )
+                            }))
                     })
                     .collect::<Result<Vec<_>>>()?
             }
@@ -91,6 +167,9 @@ impl Manifest {
     }
 
     /// Parse manifest from bytes of avro file.
+    ///
+    /// Partition values are matched to the fields of the partition spec by field id, so they
+    /// decode correctly when partition fields were renamed or reordered by spec evolution.
     pub fn parse_avro(bs: &[u8]) -> Result<Self> {
         let (metadata, entries) = Self::try_from_avro_bytes(bs)?;
         Ok(Self::new(metadata, entries))
@@ -106,9 +185,23 @@ impl Manifest {
         &self.metadata
     }
 
+    /// The decoding errors of the entries that were left out of this manifest, when it was
+    /// loaded skipping its corrupt entries.
+    pub(crate) fn corrupt_entries(&self) -> &[String] {
+        &self.corrupt_entries
+    }
+
+    /// Records the decoding errors of the entries left out of this manifest.
+    pub(crate) fn with_corrupt_entries(mut self, corrupt_entries: Vec<String>) -> Self {
+        self.corrupt_entries = corrupt_entries;
+        self
+    }
+
     /// Consume this Manifest, returning its constituent parts
     pub fn into_parts(self) -> (Vec<ManifestEntryRef>, ManifestMetadata) {
-        let Self { entries, metadata } = self;
+        let Self {
+            entries, metadata, ..
+        } = self;
         (entries, metadata)
     }
 
@@ -117,6 +210,7 @@ impl Manifest {
         Self {
             metadata,
             entries: entries.into_iter().map(Arc::new).collect(),
+            corrupt_entries: vec![],
         }
     }
 }
@@ -155,6 +249,60 @@ pub fn deserialize_data_file_from_json(
     serde.try_into(partition_spec_id, partition_type, schema)
 }
 
+/// Renames the fields of `partition_type` to the names of the partition fields the manifest was
+/// written with, matching them by field id.
+///
+/// Avro resolves the partition record by field name, so decoding with the names of the partition
+/// spec would drop or swap values when the written names differ from the spec, e.g. after a
+/// partition field was renamed.
+fn partition_type_with_written_names(
+    partition_type: StructType,
+    writer_schema: &AvroSchema,
+) -> StructType {
+    let Some(written_names) = written_partition_field_names(writer_schema) else {
+        return partition_type;
+    };
+    let fields = partition_type
+        .fields()
+        .iter()
+        .map(|field| match written_names.get(&field.id) {
+            Some(name) if *name != field.name => Arc::new(NestedField {
+                name: name.clone(),
+                ..field.as_ref().clone()
+            }),
+            _ => field.clone(),
+        })
+        .collect();
+    StructType::new(fields)
+}
+
+fn written_partition_field_names(writer_schema: &AvroSchema) -> Option<HashMap<i32, String>> {
+    fn record_field<'a>(schema: &'a AvroSchema, name: &str) -> Option<&'a AvroSchema> {
+        match schema {
+            AvroSchema::Record(record) => record
+                .lookup
+                .get(name)
+                .map(|position| &record.fields[*position].schema),
+            _ => None,
+        }
+    }
+
+    let data_file = record_field(writer_schema, "data_file")?;
+    let AvroSchema::Record(partition) = record_field(data_file, "partition")? else {
+        return None;
+    };
+    Some(
+        partition
+            .fields
+            .iter()
+            .filter_map(|field| {
+                let field_id = field.custom_attributes.get("field-id")?.as_i64()?;
+                Some((i32::try_from(field_id).ok()?, field.name.clone()))
+            })
+            .collect(),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
@@ -166,7 +314,10 @@ mod tests {
 
     use super::*;
     use crate::io::FileIOBuilder;
-    use crate::spec::{Literal, NestedField, PrimitiveType, Struct, Transform, Type};
+    use crate::spec::{
+        Literal, NestedField, PrimitiveType, Struct, Transform, Type, UnboundPartitionField,
+    };
+    use crate::transform::create_transform_function;
 
     #[tokio::test]
     async fn test_parse_manifest_v2_unpartition() {
@@ -689,6 +840,436 @@ mod tests {
         assert_eq!(actual_manifest, Manifest::new(metadata, entries));
     }
 
+    async fn write_and_read_temporal_partition_manifest(
+        format_version: FormatVersion,
+        partition: Struct,
+    ) -> Struct {
+        let schema = Arc::new(
+            Schema::builder()
+                .with_fields(vec![
+                    Arc::new(NestedField::optional(
+                        1,
+                        "ts",
+                        Type::Primitive(PrimitiveType::Timestamp),
+                    )),
+                    Arc::new(NestedField::optional(
+                        2,
+                        "ts_tz",
+                        Type::Primitive(PrimitiveType::Timestamptz),
+                    )),
+                    Arc::new(NestedField::optional(
+                        3,
+                        "dt",
+                        Type::Primitive(PrimitiveType::Date),
+                    )),
+                ])
+                .build()
+                .unwrap(),
+        );
+        let partition_spec = PartitionSpec::builder(schema.clone())
+            .add_partition_field("ts", "ts_year", Transform::Year)
+            .unwrap()
+            .add_partition_field("ts_tz", "ts_tz_month", Transform::Month)
+            .unwrap()
+            .add_partition_field("dt", "dt_day", Transform::Day)
+            .unwrap()
+            .build()
+            .unwrap();
+        let entry = ManifestEntry {
+            status: ManifestStatus::Added,
+            snapshot_id: None,
+            sequence_number: None,
+            file_sequence_number: None,
+            data_file: DataFile {
+                content: DataContentType::Data,
+                file_path: "s3://testbucket/data/00000-0-temporal.parquet".to_string(),
+                file_format: DataFileFormat::Parquet,
+                partition,
+                record_count: 1,
+                file_size_in_bytes: 874,
+                column_sizes: HashMap::new(),
+                value_counts: HashMap::new(),
+                null_value_counts: HashMap::new(),
+                nan_value_counts: HashMap::new(),
+                lower_bounds: HashMap::new(),
+                upper_bounds: HashMap::new(),
+                key_metadata: None,
+                split_offsets: None,
+                equality_ids: None,
+                sort_order_id: None,
+                partition_spec_id: 0,
+                first_row_id: None,
+                referenced_data_file: None,
+                content_offset: None,
+                content_size_in_bytes: None,
+            },
+        };
+
+        let tmp_dir = TempDir::new().unwrap();
+        let path = tmp_dir.path().join("test_manifest.avro");
+        let io = FileIOBuilder::new_fs_io().build().unwrap();
+        let output_file = io.new_output(path.to_str().unwrap()).unwrap();
+        let builder =
+            ManifestWriterBuilder::new(output_file, Some(1), None, schema, partition_spec);
+        let mut writer = match format_version {
+            FormatVersion::V1 => builder.build_v1(),
+            FormatVersion::V2 => builder.build_v2_data(),
+            FormatVersion::V3 => builder.build_v3_data(),
+        };
+        writer.add_entry(entry).unwrap();
+        writer.write_manifest_file().await.unwrap();
+
+        let manifest =
+            Manifest::parse_avro(fs::read(path).expect("read_file must succeed").as_slice())
+                .unwrap();
+        assert_eq!(manifest.metadata().format_version, format_version);
+        manifest.entries()[0].data_file().partition().clone()
+    }
+
+    #[tokio::test]
+    async fn test_temporal_partition_values_consistent_across_versions() {
+        let ts = Datum::timestamp_from_str("2017-11-16T22:31:08").unwrap();
+        let ts_tz = Datum::timestamptz_from_str("2017-11-16T22:31:08+00:00").unwrap();
+        let dt = Datum::date_from_str("2017-11-16").unwrap();
+
+        let transformed = |transform: &Transform, datum: &Datum| {
+            Literal::from(
+                create_transform_function(transform)
+                    .unwrap()
+                    .transform_literal_result(datum)
+                    .unwrap(),
+            )
+        };
+        let partition = Struct::from_iter([
+            Some(transformed(&Transform::Year, &ts)),
+            Some(transformed(&Transform::Month, &ts_tz)),
+            Some(transformed(&Transform::Day, &dt)),
+        ]);
+
+        // Years, months and days since 1970-01-01 as mandated by the spec.
+        let expected = Struct::from_iter([
+            Some(Literal::int(47)),
+            Some(Literal::int(574)),
+            Some(Literal::date(17486)),
+        ]);
+        assert_eq!(partition, expected);
+
+        for format_version in [FormatVersion::V1, FormatVersion::V2, FormatVersion::V3] {
+            let actual =
+                write_and_read_temporal_partition_manifest(format_version, partition.clone()).await;
+            assert_eq!(actual, expected, "format version {format_version}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_temporal_partition_values_pre_epoch_consistent_across_versions() {
+        // 1969-12-31 is one year, one month and one day before the epoch.
+        let expected = Struct::from_iter([
+            Some(Literal::int(-1)),
+            Some(Literal::int(-1)),
+            Some(Literal::date(-1)),
+        ]);
+
+        for format_version in [FormatVersion::V1, FormatVersion::V2, FormatVersion::V3] {
+            let actual =
+                write_and_read_temporal_partition_manifest(format_version, expected.clone()).await;
+            assert_eq!(actual, expected, "format version {format_version}");
+        }
+    }
+
+    /// Re-encodes an avro container with the given block codec, keeping its
+    /// schema and user metadata.
+    fn reencode_avro(bs: &[u8], codec: apache_avro::Codec) -> Vec<u8> {
+        let reader = AvroReader::new(bs).unwrap();
+        let schema = reader.writer_schema().clone();
+        let user_metadata = reader.user_metadata().clone();
+        let values = reader.collect::<std::result::Result<Vec<_>, _>>().unwrap();
+
+        let mut writer = apache_avro::Writer::with_codec(&schema, Vec::new(), codec);
+        for (key, value) in user_metadata {
+            writer.add_user_metadata(key, value).unwrap();
+        }
+        writer.extend_from_slice(&values).unwrap();
+        writer.into_inner().unwrap()
+    }
+
+    /// Writes a manifest of 10 data files, each in its own partition, and returns its bytes.
+    async fn write_partitioned_manifest() -> Vec<u8> {
+        let schema = Arc::new(
+            Schema::builder()
+                .with_fields(vec![Arc::new(NestedField::optional(
+                    1,
+                    "id",
+                    Type::Primitive(PrimitiveType::Long),
+                ))])
+                .build()
+                .unwrap(),
+        );
+        let partition_spec = PartitionSpec::builder(schema.clone())
+            .add_partition_field("id", "id", Transform::Identity)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let path = tmp_dir.path().join("test_manifest.avro");
+        let io = FileIOBuilder::new_fs_io().build().unwrap();
+        let output_file = io.new_output(path.to_str().unwrap()).unwrap();
+        let mut writer =
+            ManifestWriterBuilder::new(output_file, Some(1), None, schema, partition_spec)
+                .build_v2_data();
+        for id in 0..10 {
+            writer
+                .add_entry(ManifestEntry {
+                    status: ManifestStatus::Added,
+                    snapshot_id: None,
+                    sequence_number: None,
+                    file_sequence_number: None,
+                    data_file: DataFile {
+                        content: DataContentType::Data,
+                        file_path: format!("s3://testbucket/data/id={id}/00000.parquet"),
+                        file_format: DataFileFormat::Parquet,
+                        partition: Struct::from_iter([Some(Literal::long(id))]),
+                        record_count: 1,
+                        file_size_in_bytes: 100,
+                        column_sizes: HashMap::new(),
+                        value_counts: HashMap::new(),
+                        null_value_counts: HashMap::new(),
+                        nan_value_counts: HashMap::new(),
+                        lower_bounds: HashMap::new(),
+                        upper_bounds: HashMap::new(),
+                        key_metadata: None,
+                        split_offsets: None,
+                        equality_ids: None,
+                        sort_order_id: None,
+                        partition_spec_id: 0,
+                        first_row_id: None,
+                        referenced_data_file: None,
+                        content_offset: None,
+                        content_size_in_bytes: None,
+                    },
+                })
+                .unwrap();
+        }
+        writer.write_manifest_file().await.unwrap();
+
+        fs::read(path).expect("read_file must succeed")
+    }
+
+    #[tokio::test]
+    async fn test_parse_compressed_manifest() {
+        let bs = write_partitioned_manifest().await;
+        let expected = Manifest::parse_avro(&bs).unwrap();
+        assert_eq!(expected.entries().len(), 10);
+
+        let block_codecs = [
+            apache_avro::Codec::Null,
+            apache_avro::Codec::Deflate(apache_avro::DeflateSettings::default()),
+            apache_avro::Codec::Snappy,
+            apache_avro::Codec::Zstandard(apache_avro::ZstandardSettings::default()),
+        ];
+        for block_codec in block_codecs {
+            let encoded = reencode_avro(&bs, block_codec);
+            assert_eq!(
+                Manifest::parse_avro(&encoded).unwrap(),
+                expected,
+                "avro codec {block_codec:?}"
+            );
+
+            for file_codec in [CompressionCodec::Gzip, CompressionCodec::Zstd] {
+                let compressed = file_codec.compress(encoded.clone()).unwrap();
+                assert_eq!(
+                    Manifest::parse_avro(&compressed).unwrap(),
+                    expected,
+                    "avro codec {block_codec:?} compressed with {file_codec:?}"
+                );
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_parse_manifest_with_corrupted_snappy_block() {
+        let bs = write_partitioned_manifest().await;
+
+        // The CRC32 of the last snappy block directly precedes the 16 byte sync marker.
+        let mut corrupted = reencode_avro(&bs, apache_avro::Codec::Snappy);
+        let crc_offset = corrupted.len() - 16 - 4;
+        corrupted[crc_offset] ^= 0xff;
+        let err = Manifest::parse_avro(&corrupted).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::DataInvalid);
+        assert!(err.message().contains("CRC32"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn test_parse_manifest_with_selected_bounds() {
+        let schema = Arc::new(
+            Schema::builder()
+                .with_fields(
+                    (1..=3)
+                        .map(|id| {
+                            Arc::new(NestedField::optional(
+                                id,
+                                format!("c{id}"),
+                                Type::Primitive(PrimitiveType::Long),
+                            ))
+                        })
+                        .collect::<Vec<_>>(),
+                )
+                .build()
+                .unwrap(),
+        );
+        let bounds: HashMap<i32, Datum> = (1..=3).map(|id| (id, Datum::long(id as i64))).collect();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let path = tmp_dir.path().join("test_manifest.avro");
+        let io = FileIOBuilder::new_fs_io().build().unwrap();
+        let output_file = io.new_output(path.to_str().unwrap()).unwrap();
+        let mut writer = ManifestWriterBuilder::new(
+            output_file,
+            Some(1),
+            None,
+            schema.clone(),
+            PartitionSpec::unpartition_spec(),
+        )
+        .build_v2_data();
+        writer
+            .add_entry(ManifestEntry {
+                status: ManifestStatus::Added,
+                snapshot_id: None,
+                sequence_number: None,
+                file_sequence_number: None,
+                data_file: DataFile {
+                    content: DataContentType::Data,
+                    file_path: "s3://testbucket/data/00000.parquet".to_string(),
+                    file_format: DataFileFormat::Parquet,
+                    partition: Struct::empty(),
+                    record_count: 1,
+                    file_size_in_bytes: 100,
+                    column_sizes: HashMap::new(),
+                    value_counts: HashMap::new(),
+                    null_value_counts: HashMap::new(),
+                    nan_value_counts: HashMap::new(),
+                    lower_bounds: bounds.clone(),
+                    upper_bounds: bounds.clone(),
+                    key_metadata: None,
+                    split_offsets: None,
+                    equality_ids: None,
+                    sort_order_id: None,
+                    partition_spec_id: 0,
+                    first_row_id: None,
+                    referenced_data_file: None,
+                    content_offset: None,
+                    content_size_in_bytes: None,
+                },
+            })
+            .unwrap();
+        writer.write_manifest_file().await.unwrap();
+        let bs = fs::read(path).expect("read_file must succeed");
+
+        let (_, entries) = Manifest::try_from_avro_bytes_with_bounds(&bs, None).unwrap();
+        assert_eq!(entries[0].data_file().lower_bounds(), &bounds);
+        assert_eq!(entries[0].data_file().upper_bounds(), &bounds);
+
+        let (_, entries) = Manifest::try_from_avro_bytes_with_bounds(&bs, Some(&[1, 3])).unwrap();
+        let selected: HashMap<i32, Datum> = [(1, Datum::long(1)), (3, Datum::long(3))].into();
+        assert_eq!(entries[0].data_file().lower_bounds(), &selected);
+        assert_eq!(entries[0].data_file().upper_bounds(), &selected);
+        assert_eq!(entries[0].record_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_parse_manifest_matches_partition_fields_by_id() {
+        let schema = Arc::new(
+            Schema::builder()
+                .with_fields(vec![
+                    NestedField::optional(1, "category", Type::Primitive(PrimitiveType::String))
+                        .into(),
+                    NestedField::optional(2, "region", Type::Primitive(PrimitiveType::String))
+                        .into(),
+                ])
+                .build()
+                .unwrap(),
+        );
+        let partition_field = |source_id: i32, field_id: i32, name: &str| {
+            UnboundPartitionField::builder()
+                .source_id(source_id)
+                .field_id(field_id)
+                .name(name.to_string())
+                .transform(Transform::Identity)
+                .build()
+        };
+        let written_spec = PartitionSpec::builder(schema.clone())
+            .add_unbound_fields([
+                partition_field(1, 1000, "category"),
+                partition_field(2, 1001, "region"),
+            ])
+            .unwrap()
+            .build()
+            .unwrap();
+        // The spec recorded for the manifest lists the fields in another order and renames one.
+        let evolved_spec = PartitionSpec::builder(schema.clone())
+            .add_unbound_fields([
+                partition_field(2, 1001, "region"),
+                partition_field(1, 1000, "category_name"),
+            ])
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let path = tmp_dir.path().join("test_manifest.avro");
+        let io = FileIOBuilder::new_fs_io().build().unwrap();
+        let output_file = io.new_output(path.to_str().unwrap()).unwrap();
+        let mut writer =
+            ManifestWriterBuilder::new(output_file, Some(1), None, schema, written_spec)
+                .build_v2_data();
+        writer
+            .add_entry(
+                ManifestEntry::builder()
+                    .status(ManifestStatus::Added)
+                    .data_file(
+                        DataFileBuilder::default()
+                            .content(DataContentType::Data)
+                            .file_path("s3://testbucket/data/00000.parquet".to_string())
+                            .file_format(DataFileFormat::Parquet)
+                            .file_size_in_bytes(100)
+                            .record_count(1)
+                            .partition_spec_id(0)
+                            .partition(Struct::from_iter([
+                                Some(Literal::string("books")),
+                                Some(Literal::string("eu")),
+                            ]))
+                            .build()
+                            .unwrap(),
+                    )
+                    .build(),
+            )
+            .unwrap();
+        writer.write_manifest_file().await.unwrap();
+
+        let bs = fs::read(path).unwrap();
+        let reader = AvroReader::new(bs.as_slice()).unwrap();
+        let avro_schema = reader.writer_schema().clone();
+        let mut user_metadata = reader.user_metadata().clone();
+        let values = reader.collect::<std::result::Result<Vec<_>, _>>().unwrap();
+        user_metadata.insert(
+            "partition-spec".to_string(),
+            serde_json::to_vec(evolved_spec.fields()).unwrap(),
+        );
+        let mut writer = apache_avro::Writer::new(&avro_schema, Vec::new());
+        for (key, value) in user_metadata {
+            writer.add_user_metadata(key, value).unwrap();
+        }
+        writer.extend_from_slice(&values).unwrap();
+        let manifest = Manifest::parse_avro(&writer.into_inner().unwrap()).unwrap();
+
+        assert_eq!(manifest.metadata().partition_spec(), &evolved_spec);
+        assert_eq!(
+            manifest.entries()[0].data_file().partition(),
+            &Struct::from_iter([Some(Literal::string("eu")), Some(Literal::string("books"))])
+        );
+    }
+
     #[tokio::test]
     async fn test_parse_manifest_with_schema_evolution() {
         let schema = Arc::new(
@@ -850,6 +1431,7 @@ mod tests {
                     content_size_in_bytes: None,
                 },
             })],
+            corrupt_entries: vec![],
         };
 
         assert_eq!(actual_manifest, expected_manifest);
@@ -28,7 +28,7 @@ use serde_derive::{Deserialize, Serialize};
 
 use self::_const_schema::{MANIFEST_LIST_AVRO_SCHEMA_V1, MANIFEST_LIST_AVRO_SCHEMA_V2};
 use self::_serde::{ManifestFileV1, ManifestFileV2};
-use super::{FormatVersion, Manifest};
+use super::{FormatVersion, Manifest, TableMetadata};
 use crate::error::Result;
 use crate::io::{FileIO, OutputFile};
 use crate::spec::manifest_list::_const_schema::MANIFEST_LIST_AVRO_SCHEMA_V3;
@@ -58,6 +58,11 @@ pub struct ManifestList {
 }
 
 impl ManifestList {
+    /// Creates a manifest list of `entries`.
+    pub(crate) fn new(entries: Vec<ManifestFile>) -> Self {
+        Self { entries }
+    }
+
     /// Parse manifest list from bytes.
     pub fn parse_with_version(bs: &[u8], version: FormatVersion) -> Result<ManifestList> {
         match version {
@@ -845,17 +850,49 @@ impl ManifestFile {
     /// Load [`Manifest`].
     ///
     /// This method will also initialize inherited values of [`ManifestEntry`], such as `sequence_number`.
-    pub async fn load_manifest(&self, file_io: &FileIO) -> Result<Manifest> {
-        let avro = file_io.new_input(&self.manifest_path)?.read().await?;
+    ///
+    /// A relative manifest path is resolved against the location of the table, see
+    /// [`TableMetadata::resolve_location`]. The paths of the entries are kept as stored.
+    pub async fn load_manifest(
+        &self,
+        file_io: &FileIO,
+        table_metadata: &TableMetadata,
+    ) -> Result<Manifest> {
+        self.load_manifest_with_bounds(file_io, table_metadata, None, false)
+            .await
+    }
 
-        let (metadata, mut entries) = Manifest::try_from_avro_bytes(&avro)?;
+    /// Load [`Manifest`], only decoding the column bounds of the fields in the sorted
+    /// `bound_field_ids` when set.
+    ///
+    /// With `skip_corrupt_entries`, the entries that fail to decode are left out instead of
+    /// failing the load, and their decoding errors are recorded in [`Manifest::corrupt_entries`].
+    pub(crate) async fn load_manifest_with_bounds(
+        &self,
+        file_io: &FileIO,
+        table_metadata: &TableMetadata,
+        bound_field_ids: Option<&[i32]>,
+        skip_corrupt_entries: bool,
+    ) -> Result<Manifest> {
+        let avro = file_io
+            .new_input(table_metadata.resolve_location(&self.manifest_path))?
+            .read()
+            .await?;
+
+        let (metadata, mut entries, corrupt_entries) = if skip_corrupt_entries {
+            Manifest::try_from_avro_bytes_skipping_corrupt_entries(&avro, bound_field_ids)?
+        } else {
+            let (metadata, entries) =
+                Manifest::try_from_avro_bytes_with_bounds(&avro, bound_field_ids)?;
+            (metadata, entries, vec![])
+        };
 
         // Let entries inherit values from the manifest list entry.
         for entry in &mut entries {
             entry.inherit_data(self);
         }
 
-        Ok(Manifest::new(metadata, entries))
+        Ok(Manifest::new(metadata, entries).with_corrupt_entries(corrupt_entries))
     }
 }
 
@@ -22,6 +22,12 @@ use std::sync::Arc;
 use serde::{Deserialize, Serialize};
 use serde_with::{DefaultOnNull, serde_as};
 
+use crate::spec::{
+    ListType, MapType, NestedFieldRef, PrimitiveType, Schema, SchemaVisitor, StructType, Type,
+    visit_schema,
+};
+use crate::{Error, ErrorKind, Result};
+
 /// Property name for name mapping.
 pub const DEFAULT_SCHEMA_NAME_MAPPING: &str = "schema.name-mapping.default";
 
@@ -42,6 +48,107 @@ impl NameMapping {
     pub fn fields(&self) -> &[MappedField] {
         &self.root
     }
+
+    /// Create the default name mapping of `schema`, mapping the name of each field to its id.
+    ///
+    /// List elements are mapped by the name `element`, and map keys and values by `key` and
+    /// `value`. Serialized to JSON, the mapping can be stored in the
+    /// [`DEFAULT_SCHEMA_NAME_MAPPING`] property of the table.
+    pub fn from_schema(schema: &Schema) -> Result<Self> {
+        Ok(Self::new(visit_schema(schema, &mut CreateMapping)?))
+    }
+
+    /// Validates that this name mapping maps every field of `schema`, failing with the fields
+    /// whose id is not mapped at their position in the schema.
+    pub fn validate(&self, schema: &Schema) -> Result<()> {
+        let mut unmapped = vec![];
+        collect_unmapped_fields(
+            schema.as_struct().fields(),
+            &self.root.iter().collect::<Vec<_>>(),
+            schema,
+            &mut unmapped,
+        );
+        if unmapped.is_empty() {
+            return Ok(());
+        }
+        Err(Error::new(
+            ErrorKind::DataInvalid,
+            format!(
+                "Name mapping does not map the fields of the schema: {}",
+                unmapped.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Creates the mapped fields of the fields of a schema.
+struct CreateMapping;
+
+impl SchemaVisitor for CreateMapping {
+    type T = Vec<MappedField>;
+
+    fn schema(&mut self, _schema: &Schema, value: Self::T) -> Result<Self::T> {
+        Ok(value)
+    }
+
+    fn field(&mut self, field: &NestedFieldRef, value: Self::T) -> Result<Self::T> {
+        Ok(vec![MappedField::new(
+            Some(field.id),
+            vec![field.name.clone()],
+            value,
+        )])
+    }
+
+    fn r#struct(&mut self, _struct: &StructType, results: Vec<Self::T>) -> Result<Self::T> {
+        Ok(results.into_iter().flatten().collect())
+    }
+
+    fn list(&mut self, list: &ListType, value: Self::T) -> Result<Self::T> {
+        Ok(vec![MappedField::new(
+            Some(list.element_field.id),
+            vec!["element".to_string()],
+            value,
+        )])
+    }
+
+    fn map(&mut self, map: &MapType, key_value: Self::T, value: Self::T) -> Result<Self::T> {
+        Ok(vec![
+            MappedField::new(Some(map.key_field.id), vec!["key".to_string()], key_value),
+            MappedField::new(Some(map.value_field.id), vec!["value".to_string()], value),
+        ])
+    }
+
+    fn primitive(&mut self, _p: &PrimitiveType) -> Result<Self::T> {
+        Ok(vec![])
+    }
+}
+
+/// Collects the names of the `fields` whose ids are not mapped by `mapped_fields`, and of
+/// their nested fields.
+fn collect_unmapped_fields(
+    fields: &[NestedFieldRef],
+    mapped_fields: &[&MappedField],
+    schema: &Schema,
+    unmapped: &mut Vec<String>,
+) {
+    for field in fields {
+        let Some(mapped_field) = mapped_fields
+            .iter()
+            .find(|mapped_field| mapped_field.field_id == Some(field.id))
+        else {
+            let name = schema.name_by_field_id(field.id).unwrap_or(&field.name);
+            unmapped.push(format!("{name} (id {})", field.id));
+            continue;
+        };
+        let nested_fields = match field.field_type.as_ref() {
+            Type::Primitive(_) => continue,
+            Type::Struct(struct_type) => struct_type.fields().to_vec(),
+            Type::List(list) => vec![list.element_field.clone()],
+            Type::Map(map) => vec![map.key_field.clone(), map.value_field.clone()],
+        };
+        let nested_mapped_fields: Vec<_> = mapped_field.fields.iter().map(AsRef::as_ref).collect();
+        collect_unmapped_fields(&nested_fields, &nested_mapped_fields, schema, unmapped);
+    }
 }
 
 /// Maps field names to IDs.
@@ -87,6 +194,7 @@ impl MappedField {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::spec::NestedField;
 
     #[test]
     fn test_json_mapped_field_deserialization() {
@@ -363,4 +471,105 @@ mod tests {
         let expected = r#"[{"names":["foo"]},{"field-id":2,"names":["bar"]},{"field-id":3,"names":["baz"]},{"field-id":4,"names":["qux"],"fields":[{"field-id":5,"names":["element"]}]},{"field-id":6,"names":["quux"],"fields":[{"field-id":7,"names":["key"]},{"field-id":8,"names":["value"],"fields":[{"field-id":9,"names":["key"]},{"field-id":10,"names":["value"]}]}]},{"field-id":11,"names":["location"],"fields":[{"field-id":12,"names":["element"],"fields":[{"field-id":13,"names":["latitude"]},{"field-id":14,"names":["longitude"]}]}]},{"field-id":15,"names":["person"],"fields":[{"field-id":16,"names":["name"]},{"field-id":17,"names":["age"]}]}]"#;
         assert_eq!(serde_json::to_string(&name_mapping).unwrap(), expected);
     }
+
+    fn nested_schema() -> Schema {
+        Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+                NestedField::optional(
+                    2,
+                    "locations",
+                    Type::List(ListType::new(
+                        NestedField::list_element(
+                            3,
+                            Type::Struct(StructType::new(vec![
+                                NestedField::required(
+                                    4,
+                                    "lat",
+                                    Type::Primitive(PrimitiveType::Double),
+                                )
+                                .into(),
+                                NestedField::required(
+                                    5,
+                                    "long",
+                                    Type::Primitive(PrimitiveType::Double),
+                                )
+                                .into(),
+                            ])),
+                            true,
+                        )
+                        .into(),
+                    )),
+                )
+                .into(),
+                NestedField::optional(
+                    6,
+                    "tags",
+                    Type::Map(MapType::new(
+                        NestedField::map_key_element(7, Type::Primitive(PrimitiveType::String))
+                            .into(),
+                        NestedField::map_value_element(
+                            8,
+                            Type::Primitive(PrimitiveType::String),
+                            false,
+                        )
+                        .into(),
+                    )),
+                )
+                .into(),
+            ])
+            .build()
+            .unwrap()
+    }
+
+    fn collect_field_ids(fields: &[&MappedField], field_ids: &mut Vec<i32>) {
+        for field in fields {
+            field_ids.extend(field.field_id());
+            let nested_fields: Vec<_> = field.fields().iter().map(AsRef::as_ref).collect();
+            collect_field_ids(&nested_fields, field_ids);
+        }
+    }
+
+    #[test]
+    fn test_name_mapping_from_schema() {
+        let schema = nested_schema();
+        let name_mapping = NameMapping::from_schema(&schema).unwrap();
+
+        let mut field_ids = vec![];
+        collect_field_ids(
+            &name_mapping.fields().iter().collect::<Vec<_>>(),
+            &mut field_ids,
+        );
+        field_ids.sort();
+        assert_eq!(field_ids, (1..=8).collect::<Vec<_>>());
+        name_mapping.validate(&schema).unwrap();
+
+        let expected = r#"[{"field-id":1,"names":["id"]},{"field-id":2,"names":["locations"],"fields":[{"field-id":3,"names":["element"],"fields":[{"field-id":4,"names":["lat"]},{"field-id":5,"names":["long"]}]}]},{"field-id":6,"names":["tags"],"fields":[{"field-id":7,"names":["key"]},{"field-id":8,"names":["value"]}]}]"#;
+        assert_eq!(serde_json::to_string(&name_mapping).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_name_mapping_validate_reports_unmapped_fields() {
+        let schema = nested_schema();
+        // The mapping of the struct of the list elements misses `long`, and `tags` is not
+        // mapped at all.
+        let name_mapping = NameMapping::new(vec![
+            MappedField::new(Some(1), vec!["id".to_string()], vec![]),
+            MappedField::new(Some(2), vec!["locations".to_string()], vec![
+                MappedField::new(Some(3), vec!["element".to_string()], vec![
+                    MappedField::new(Some(4), vec!["lat".to_string()], vec![]),
+                ]),
+            ]),
+        ]);
+
+        let Err(err) = name_mapping.validate(&schema) else {
+            panic!("Validating an incomplete name mapping should fail");
+        };
+        assert_eq!(err.kind(), ErrorKind::DataInvalid);
+        assert!(
+            err.message()
+                .ends_with("locations.element.long (id 5), tags (id 6)"),
+            "{err}"
+        );
+    }
 }
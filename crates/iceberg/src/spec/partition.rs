@@ -235,6 +235,38 @@ impl PartitionKey {
     }
 }
 
+/// Checks that the partition value `partition_value` is compatible with the partition type
+/// `partition_type`.
+pub(crate) fn validate_partition_value(
+    partition_value: &Struct,
+    partition_type: &StructType,
+) -> Result<()> {
+    if partition_value.fields().len() != partition_type.fields().len() {
+        return Err(Error::new(
+            ErrorKind::DataInvalid,
+            "Partition value is not compatible with partition type",
+        ));
+    }
+
+    for (value, field) in partition_value.fields().iter().zip(partition_type.fields()) {
+        let field = field.field_type.as_primitive_type().ok_or_else(|| {
+            Error::new(
+                ErrorKind::Unexpected,
+                "Partition field should only be primitive type.",
+            )
+        })?;
+        if let Some(value) = value
+            && !field.compatible(&value.as_primitive_literal().unwrap())
+        {
+            return Err(Error::new(
+                ErrorKind::DataInvalid,
+                "Partition value is not compatible partition type",
+            ));
+        }
+    }
+    Ok(())
+}
+
 /// Reference to [`UnboundPartitionSpec`].
 pub type UnboundPartitionSpecRef = Arc<UnboundPartitionSpec>;
 /// Unbound partition field can be built without a schema and later bound to a schema.
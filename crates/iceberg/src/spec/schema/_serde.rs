@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use itertools::Itertools;
 /// This is a helper module that defines types to help with serialization/deserialization.
 /// For deserialization the input first gets read into either the [SchemaV1] or [SchemaV2] struct
 /// and then converted into the [Schema] struct. Serialization works the other way around.
@@ -107,7 +108,7 @@ impl From<Schema> for SchemaV2 {
             identifier_field_ids: if value.identifier_field_ids.is_empty() {
                 None
             } else {
-                Some(value.identifier_field_ids.into_iter().collect())
+                Some(value.identifier_field_ids.into_iter().sorted().collect())
             },
             fields: value.r#struct,
         }
@@ -121,7 +122,7 @@ impl From<Schema> for SchemaV1 {
             identifier_field_ids: if value.identifier_field_ids.is_empty() {
                 None
             } else {
-                Some(value.identifier_field_ids.into_iter().collect())
+                Some(value.identifier_field_ids.into_iter().sorted().collect())
             },
             fields: value.r#struct,
         }
@@ -66,6 +66,7 @@ pub struct Schema {
 
     name_to_id: HashMap<String, i32>,
     lowercase_name_to_id: HashMap<String, i32>,
+    ambiguous_lowercase_names: HashSet<String>,
     id_to_name: HashMap<i32, String>,
 
     field_id_to_accessor: HashMap<i32, Arc<StructAccessor>>,
@@ -144,10 +145,20 @@ impl SchemaBuilder {
             index.indexes()
         };
 
-        let lowercase_name_to_id = name_to_id
-            .iter()
-            .map(|(k, v)| (k.to_lowercase(), *v))
-            .collect();
+        let mut lowercase_name_to_id = HashMap::with_capacity(name_to_id.len());
+        let mut ambiguous_lowercase_names = HashSet::new();
+        for (name, id) in &name_to_id {
+            let lowercase_name = name.to_lowercase();
+            match lowercase_name_to_id.get(&lowercase_name) {
+                Some(existing_id) if existing_id != id => {
+                    ambiguous_lowercase_names.insert(lowercase_name);
+                }
+                Some(_) => {}
+                None => {
+                    lowercase_name_to_id.insert(lowercase_name, *id);
+                }
+            }
+        }
 
         let highest_field_id = id_to_field.keys().max().cloned().unwrap_or(0);
 
@@ -161,6 +172,7 @@ impl SchemaBuilder {
 
             name_to_id,
             lowercase_name_to_id,
+            ambiguous_lowercase_names,
             id_to_name,
 
             field_id_to_accessor,
@@ -344,13 +356,40 @@ impl Schema {
 
     /// Get field by field name, but in case-insensitive way.
     ///
-    /// Both full name and short name could work here.
+    /// Both full name and short name could work here. When several fields have names
+    /// differing only by case, any of them may be returned, use [`Schema::resolve_field_by_name`]
+    /// to reject such names.
     pub fn field_by_name_case_insensitive(&self, field_name: &str) -> Option<&NestedFieldRef> {
         self.lowercase_name_to_id
             .get(&field_name.to_lowercase())
             .and_then(|id| self.field_by_id(*id))
     }
 
+    /// Get field by field name, matching the name case-sensitively or not.
+    ///
+    /// Returns an error when matching case-insensitively and the name matches several
+    /// fields whose names differ only by case.
+    pub fn resolve_field_by_name(
+        &self,
+        field_name: &str,
+        case_sensitive: bool,
+    ) -> Result<Option<&NestedFieldRef>> {
+        if case_sensitive {
+            return Ok(self.field_by_name(field_name));
+        }
+
+        if self
+            .ambiguous_lowercase_names
+            .contains(&field_name.to_lowercase())
+        {
+            return Err(Error::new(
+                ErrorKind::DataInvalid,
+                format!("Field name {field_name} is ambiguous in case-insensitive mode"),
+            ));
+        }
+        Ok(self.field_by_name_case_insensitive(field_name))
+    }
+
     /// Get field by alias.
     pub fn field_by_alias(&self, alias: &str) -> Option<&NestedFieldRef> {
         self.alias_to_id
@@ -707,6 +746,42 @@ table {
         }
     }
 
+    #[test]
+    fn test_resolve_field_by_name() {
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::optional(1, "mycol", Type::Primitive(PrimitiveType::Int)).into(),
+                NestedField::optional(2, "Dup", Type::Primitive(PrimitiveType::Int)).into(),
+                NestedField::optional(3, "dup", Type::Primitive(PrimitiveType::Int)).into(),
+            ])
+            .build()
+            .unwrap();
+
+        assert!(
+            schema
+                .resolve_field_by_name("MyCol", true)
+                .unwrap()
+                .is_none()
+        );
+        assert_eq!(
+            Some(1),
+            schema
+                .resolve_field_by_name("MyCol", false)
+                .unwrap()
+                .map(|f| f.id)
+        );
+
+        assert_eq!(
+            Some(2),
+            schema
+                .resolve_field_by_name("Dup", true)
+                .unwrap()
+                .map(|f| f.id)
+        );
+        let err = schema.resolve_field_by_name("DUP", false).unwrap_err();
+        assert_eq!(err.kind(), crate::ErrorKind::DataInvalid);
+    }
+
     #[test]
     fn test_schema_find_column_name() {
         let expected_column_name = HashMap::from([
@@ -28,7 +28,9 @@ use typed_builder::TypedBuilder;
 use super::table_metadata::SnapshotLog;
 use crate::error::{Result, timestamp_ms_to_utc};
 use crate::io::FileIO;
-use crate::spec::{ManifestList, SchemaId, SchemaRef, TableMetadata};
+use crate::spec::{
+    Manifest, ManifestFile, ManifestList, ManifestStatus, SchemaId, SchemaRef, TableMetadata,
+};
 use crate::{Error, ErrorKind};
 
 /// The ref name of the main branch of the table.
@@ -71,7 +73,7 @@ pub struct Summary {
     /// The type of operation in the snapshot
     pub operation: Operation,
     /// Other summary data.
-    #[serde(flatten)]
+    #[serde(flatten, serialize_with = "crate::utils::serialize_sorted_map")]
     pub additional_properties: HashMap<String, String>,
 }
 
@@ -108,9 +110,12 @@ pub struct Snapshot {
     pub(crate) timestamp_ms: i64,
     /// The location of a manifest list for this snapshot that
     /// tracks manifest files with additional metadata.
-    /// Currently we only support manifest list file, and manifest files are not supported.
+    /// Empty for v1 snapshots listing their manifests inline.
     #[builder(setter(into))]
     pub(crate) manifest_list: String,
+    /// The manifest files listed inline by a v1 snapshot without a manifest list.
+    #[builder(default)]
+    pub(crate) manifests: Option<Vec<String>>,
     /// A string map that summarizes the snapshot changes, including operation.
     pub(crate) summary: Summary,
     /// ID of the table’s current schema when the snapshot was created.
@@ -153,6 +158,12 @@ impl Snapshot {
         &self.manifest_list
     }
 
+    /// Get the manifest files listed inline by a v1 snapshot without a manifest list.
+    #[inline]
+    pub fn manifests(&self) -> Option<&[String]> {
+        self.manifests.as_deref()
+    }
+
     /// Get summary of the snapshot
     #[inline]
     pub fn summary(&self) -> &Summary {
@@ -202,12 +213,28 @@ impl Snapshot {
     }
 
     /// Load manifest list.
+    ///
+    /// The manifest list of a v1 snapshot listing its manifests inline is built from the
+    /// manifests themselves. Their partition summaries are unknown, so none of them is
+    /// skipped by partition filters.
+    ///
+    /// A relative manifest list path is resolved against the location of the table, see
+    /// [`TableMetadata::resolve_location`]. The manifest paths of the list are kept as stored.
     pub async fn load_manifest_list(
         &self,
         file_io: &FileIO,
         table_metadata: &TableMetadata,
     ) -> Result<ManifestList> {
-        let manifest_list_content = file_io.new_input(&self.manifest_list)?.read().await?;
+        if let Some(manifests) = &self.manifests {
+            return self
+                .load_inline_manifest_list(manifests, file_io, table_metadata)
+                .await;
+        }
+
+        let manifest_list_content = file_io
+            .new_input(table_metadata.resolve_location(&self.manifest_list))?
+            .read()
+            .await?;
         ManifestList::parse_with_version(
             &manifest_list_content,
             // TODO: You don't really need the version since you could just project any Avro in
@@ -216,6 +243,53 @@ impl Snapshot {
         )
     }
 
+    async fn load_inline_manifest_list(
+        &self,
+        manifests: &[String],
+        file_io: &FileIO,
+        table_metadata: &TableMetadata,
+    ) -> Result<ManifestList> {
+        let mut entries = Vec::with_capacity(manifests.len());
+        for manifest_path in manifests {
+            let content = file_io
+                .new_input(table_metadata.resolve_location(manifest_path))?
+                .read()
+                .await?;
+            let manifest = Manifest::parse_avro(&content)?;
+            let entries_with_status = |status| {
+                manifest
+                    .entries()
+                    .iter()
+                    .filter(move |entry| entry.status() == status)
+            };
+            let files_count = |status| entries_with_status(status).count() as u32;
+            let rows_count = |status| {
+                entries_with_status(status)
+                    .map(|entry| entry.record_count())
+                    .sum::<u64>()
+            };
+            entries.push(ManifestFile {
+                manifest_path: manifest_path.clone(),
+                manifest_length: content.len() as i64,
+                partition_spec_id: manifest.metadata().partition_spec.spec_id(),
+                content: manifest.metadata().content,
+                sequence_number: 0,
+                min_sequence_number: 0,
+                added_snapshot_id: self.snapshot_id,
+                added_files_count: Some(files_count(ManifestStatus::Added)),
+                existing_files_count: Some(files_count(ManifestStatus::Existing)),
+                deleted_files_count: Some(files_count(ManifestStatus::Deleted)),
+                added_rows_count: Some(rows_count(ManifestStatus::Added)),
+                existing_rows_count: Some(rows_count(ManifestStatus::Existing)),
+                deleted_rows_count: Some(rows_count(ManifestStatus::Deleted)),
+                partitions: None,
+                key_metadata: None,
+                first_row_id: None,
+            });
+        }
+        Ok(ManifestList::new(entries))
+    }
+
     #[allow(dead_code)]
     pub(crate) fn log(&self) -> SnapshotLog {
         SnapshotLog {
@@ -332,6 +406,7 @@ pub(super) mod _serde {
                 sequence_number: s.sequence_number,
                 timestamp_ms: s.timestamp_ms,
                 manifest_list: s.manifest_list,
+                manifests: None,
                 summary: s.summary,
                 schema_id: s.schema_id,
                 encryption_key_id: s.key_id,
@@ -378,6 +453,7 @@ pub(super) mod _serde {
                 sequence_number: v2.sequence_number,
                 timestamp_ms: v2.timestamp_ms,
                 manifest_list: v2.manifest_list,
+                manifests: None,
                 summary: v2.summary,
                 schema_id: v2.schema_id,
                 encryption_key_id: None,
@@ -404,26 +480,29 @@ pub(super) mod _serde {
         type Error = Error;
 
         fn try_from(v1: SnapshotV1) -> Result<Self, Self::Error> {
+            let (manifest_list, manifests) = match (v1.manifest_list, v1.manifests) {
+                (Some(file), None) => (file, None),
+                (None, Some(manifests)) => (String::new(), Some(manifests)),
+                (Some(_), Some(_)) => {
+                    return Err(Error::new(
+                        ErrorKind::DataInvalid,
+                        "Invalid v1 snapshot, when manifest list provided, manifest files should be omitted",
+                    ));
+                }
+                (None, None) => {
+                    return Err(Error::new(
+                        ErrorKind::DataInvalid,
+                        "Invalid v1 snapshot, either manifest list or manifest files should be provided",
+                    ));
+                }
+            };
             Ok(Snapshot {
                 snapshot_id: v1.snapshot_id,
                 parent_snapshot_id: v1.parent_snapshot_id,
                 sequence_number: 0,
                 timestamp_ms: v1.timestamp_ms,
-                manifest_list: match (v1.manifest_list, v1.manifests) {
-                    (Some(file), None) => file,
-                    (Some(_), Some(_)) => {
-                        return Err(Error::new(
-                            ErrorKind::DataInvalid,
-                            "Invalid v1 snapshot, when manifest list provided, manifest files should be omitted",
-                        ));
-                    }
-                    (None, _) => {
-                        return Err(Error::new(
-                            ErrorKind::DataInvalid,
-                            "Unsupported v1 snapshot, only manifest list is supported",
-                        ));
-                    }
-                },
+                manifest_list,
+                manifests,
                 summary: v1.summary.unwrap_or(Summary {
                     operation: Operation::default(),
                     additional_properties: HashMap::new(),
@@ -441,10 +520,10 @@ pub(super) mod _serde {
                 snapshot_id: v2.snapshot_id,
                 parent_snapshot_id: v2.parent_snapshot_id,
                 timestamp_ms: v2.timestamp_ms,
-                manifest_list: Some(v2.manifest_list),
+                manifest_list: v2.manifests.is_none().then_some(v2.manifest_list),
                 summary: Some(v2.summary),
                 schema_id: v2.schema_id,
-                manifests: None,
+                manifests: v2.manifests,
             }
         }
     }
@@ -687,12 +766,21 @@ mod tests {
         ]
     }
     "#;
-            let result_missing_manifest_list = serde_json::from_str::<TableMetadata>(metadata);
-            assert!(result_missing_manifest_list.is_err());
+            let metadata = serde_json::from_str::<TableMetadata>(metadata).unwrap();
+            let snapshot = metadata.current_snapshot().unwrap();
             assert_eq!(
-                result_missing_manifest_list.unwrap_err().to_string(),
-                "DataInvalid => Unsupported v1 snapshot, only manifest list is supported"
-            )
+                snapshot.manifests(),
+                Some(["s3://bucket/metadata/manifest-1.avro".to_string()].as_slice())
+            );
+
+            // The inline manifests are written back as they were read.
+            let serialized = serde_json::to_value(&metadata).unwrap();
+            let snapshot = &serialized["snapshots"][0];
+            assert!(snapshot.get("manifest-list").is_none());
+            assert_eq!(
+                snapshot["manifests"],
+                serde_json::json!(["s3://bucket/metadata/manifest-1.avro"])
+            );
         }
     }
 
@@ -53,7 +53,11 @@ pub struct BlobMetadata {
     /// Fields of the blob.
     pub fields: Vec<i32>,
     /// Properties of the blob.
-    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    #[serde(
+        default,
+        skip_serializing_if = "HashMap::is_empty",
+        serialize_with = "crate::utils::serialize_sorted_map"
+    )]
     pub properties: HashMap<String, String>,
 }
 
@@ -19,7 +19,7 @@
 //! The main struct here is [TableMetadataV2] which defines the data for a table.
 
 use std::cmp::Ordering;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt::{Display, Formatter};
 use std::hash::Hash;
 use std::sync::Arc;
@@ -33,8 +33,8 @@ use uuid::Uuid;
 use super::snapshot::SnapshotReference;
 pub use super::table_metadata_builder::{TableMetadataBuildResult, TableMetadataBuilder};
 use super::{
-    DEFAULT_PARTITION_SPEC_ID, PartitionSpecRef, PartitionStatisticsFile, SchemaId, SchemaRef,
-    SnapshotRef, SnapshotRetention, SortOrder, SortOrderRef, StatisticsFile, StructType,
+    DEFAULT_PARTITION_SPEC_ID, DataFile, PartitionSpecRef, PartitionStatisticsFile, SchemaId,
+    SchemaRef, SnapshotRef, SnapshotRetention, SortOrder, SortOrderRef, StatisticsFile, StructType,
     TableProperties,
 };
 use crate::compression::CompressionCodec;
@@ -182,6 +182,18 @@ impl TableMetadata {
         self.location.as_str()
     }
 
+    /// Resolves a location referenced by the table, such as a manifest or data file path.
+    ///
+    /// Absolute locations, with a scheme or a leading `/`, are returned unchanged, while
+    /// relative locations are resolved against the table location.
+    pub fn resolve_location(&self, location: &str) -> String {
+        if location.starts_with('/') || url::Url::parse(location).is_ok() {
+            location.to_string()
+        } else {
+            format!("{}/{}", self.location.trim_end_matches('/'), location)
+        }
+    }
+
     /// Returns last sequence number.
     #[inline]
     pub fn last_sequence_number(&self) -> i64 {
@@ -291,12 +303,85 @@ impl TableMetadata {
         self.snapshots.get(&snapshot_id)
     }
 
+    /// Lookup the snapshot a branch or tag points to.
+    ///
+    /// Unlike [`snapshot_for_ref`](Self::snapshot_for_ref), returns `None` rather than
+    /// panicking when the ref points to a missing snapshot.
+    #[inline]
+    pub fn snapshot_by_ref(&self, ref_name: &str) -> Option<&SnapshotRef> {
+        self.refs
+            .get(ref_name)
+            .and_then(|r| self.snapshot_by_id(r.snapshot_id))
+    }
+
+    /// Returns an iterator over the snapshot with `snapshot_id` and its ancestors, from the
+    /// snapshot to the oldest ancestor.
+    ///
+    /// The iterator is empty when the snapshot doesn't exist and stops at the first parent
+    /// that has been expired.
+    pub fn ancestors_of(&self, snapshot_id: i64) -> impl Iterator<Item = &SnapshotRef> + '_ {
+        let mut next = self.snapshot_by_id(snapshot_id);
+        std::iter::from_fn(move || {
+            let snapshot = next?;
+            next = snapshot
+                .parent_snapshot_id()
+                .and_then(|parent_id| self.snapshot_by_id(parent_id));
+            Some(snapshot)
+        })
+    }
+
     /// Returns snapshot history.
     #[inline]
     pub fn history(&self) -> &[SnapshotLog] {
         &self.snapshot_log
     }
 
+    /// Returns the ids of the minimal set of snapshots to retain to time-travel to any point
+    /// in time since `min_timestamp_ms` and to each of the tags `tags`.
+    ///
+    /// Time-travel to a timestamp reads the snapshot that was current at that time according
+    /// to the snapshot log, so the snapshots of the log entries since `min_timestamp_ms` are
+    /// required, and so is the snapshot that was current at `min_timestamp_ms`. The current
+    /// snapshot is always required, while the log entries of snapshots that have already been
+    /// expired are skipped. Fails if one of `tags` is not a tag of the table.
+    pub fn snapshots_to_retain<T: AsRef<str>>(
+        &self,
+        min_timestamp_ms: i64,
+        tags: impl IntoIterator<Item = T>,
+    ) -> Result<HashSet<i64>> {
+        let first_in_window = self
+            .snapshot_log
+            .partition_point(|log| log.timestamp_ms < min_timestamp_ms);
+        // The snapshot current at the start of the window is the one of the previous entry,
+        // unless an entry is right at the start.
+        let first_required = match self.snapshot_log.get(first_in_window) {
+            Some(log) if log.timestamp_ms == min_timestamp_ms => first_in_window,
+            _ => first_in_window.saturating_sub(1),
+        };
+
+        let mut snapshot_ids: HashSet<i64> = self.snapshot_log[first_required..]
+            .iter()
+            .map(|log| log.snapshot_id)
+            .filter(|snapshot_id| self.snapshots.contains_key(snapshot_id))
+            .collect();
+        snapshot_ids.extend(self.current_snapshot_id);
+        for tag in tags {
+            let tag = tag.as_ref();
+            match self.refs.get(tag) {
+                Some(reference) if !reference.is_branch() => {
+                    snapshot_ids.insert(reference.snapshot_id);
+                }
+                _ => {
+                    return Err(Error::new(
+                        ErrorKind::DataInvalid,
+                        format!("Tag {tag} not found"),
+                    ));
+                }
+            }
+        }
+        Ok(snapshot_ids)
+    }
+
     /// Returns the metadata log.
     #[inline]
     pub fn metadata_log(&self) -> &[MetadataLog] {
@@ -340,6 +425,15 @@ impl TableMetadata {
         self.sort_orders.get(&sort_order_id)
     }
 
+    /// Lookup the sort order `data_file` was written with.
+    ///
+    /// Returns `None` when the file has no sort order id or one that is not a sort order of
+    /// the table, in which case the file is assumed to be unsorted.
+    #[inline]
+    pub fn data_file_sort_order(&self, data_file: &DataFile) -> Option<&SortOrderRef> {
+        self.sort_order_by_id(data_file.sort_order_id()? as i64)
+    }
+
     /// Returns default sort order id.
     #[inline]
     pub fn default_sort_order(&self) -> &SortOrderRef {
@@ -454,14 +548,74 @@ impl TableMetadata {
                     .with_context("file_path", metadata_location)
                     .with_source(e)
                 })?;
-            serde_json::from_slice(&decompressed_data)?
+            Self::from_json_bytes(&decompressed_data)?
         } else {
-            serde_json::from_slice(&metadata_content)?
+            Self::from_json_bytes(&metadata_content)?
         };
 
         Ok(metadata)
     }
 
+    /// Read table metadata from the given location in strict mode, also validating its
+    /// snapshot log with [`TableMetadata::validate_snapshot_log`].
+    pub async fn read_from_strict(
+        file_io: &FileIO,
+        metadata_location: impl AsRef<str>,
+    ) -> Result<TableMetadata> {
+        let metadata_location = metadata_location.as_ref();
+        let metadata = Self::read_from(file_io, metadata_location).await?;
+        metadata
+            .validate_snapshot_log()
+            .map_err(|e| e.with_context("file_path", metadata_location))?;
+        Ok(metadata)
+    }
+
+    /// Validate that the snapshot log is consistent with the snapshots of the table.
+    ///
+    /// Every entry of the snapshot log must reference an existing snapshot, the entries must
+    /// be in timestamp order, and the last entry must reference the current snapshot. Unlike
+    /// the validation of any loaded metadata, no tolerance for clock skew between committers
+    /// is allowed.
+    pub fn validate_snapshot_log(&self) -> Result<()> {
+        for log in &self.snapshot_log {
+            if !self.snapshots.contains_key(&log.snapshot_id) {
+                return Err(Error::new(
+                    ErrorKind::DataInvalid,
+                    format!(
+                        "Snapshot log entry at {} references unknown snapshot {}",
+                        log.timestamp_ms, log.snapshot_id
+                    ),
+                ));
+            }
+        }
+
+        for window in self.snapshot_log.windows(2) {
+            let (prev, curr) = (&window[0], &window[1]);
+            if curr.timestamp_ms < prev.timestamp_ms {
+                return Err(Error::new(
+                    ErrorKind::DataInvalid,
+                    format!(
+                        "Snapshot log entry of snapshot {} at {} is before the entry of snapshot {} at {}",
+                        curr.snapshot_id, curr.timestamp_ms, prev.snapshot_id, prev.timestamp_ms
+                    ),
+                ));
+            }
+        }
+
+        if let Some(last) = self.snapshot_log.last()
+            && self.current_snapshot_id != Some(last.snapshot_id)
+        {
+            return Err(Error::new(
+                ErrorKind::DataInvalid,
+                format!(
+                    "Current snapshot {:?} does not match the last snapshot log entry of snapshot {}",
+                    self.current_snapshot_id, last.snapshot_id
+                ),
+            ));
+        }
+        Ok(())
+    }
+
     /// Write table metadata to the given location.
     pub async fn write_to(
         &self,
@@ -470,10 +624,26 @@ impl TableMetadata {
     ) -> Result<()> {
         file_io
             .new_output(metadata_location)?
-            .write(serde_json::to_vec(self)?.into())
+            .write(self.to_json_bytes()?.into())
             .await
     }
 
+    /// Parse table metadata from its JSON representation, as stored in a
+    /// `metadata.json` file.
+    pub fn from_json_bytes(bytes: &[u8]) -> Result<TableMetadata> {
+        Ok(serde_json::from_slice(bytes)?)
+    }
+
+    /// Serialize table metadata into its JSON representation, as stored in a
+    /// `metadata.json` file.
+    ///
+    /// The output is deterministic: schemas, partition specs, sort orders and
+    /// statistics are ordered by id, snapshots in commit order, and map entries
+    /// such as properties and refs by key.
+    pub fn to_json_bytes(&self) -> Result<Vec<u8>> {
+        Ok(serde_json::to_vec(self)?)
+    }
+
     /// Normalize this partition spec.
     ///
     /// This is an internal method
@@ -731,8 +901,8 @@ pub(super) mod _serde {
     use crate::spec::snapshot::_serde::{SnapshotV1, SnapshotV2, SnapshotV3};
     use crate::spec::{
         EncryptedKey, INITIAL_ROW_ID, PartitionField, PartitionSpec, PartitionSpecRef,
-        PartitionStatisticsFile, Schema, SchemaRef, Snapshot, SnapshotReference, SnapshotRetention,
-        SortOrder, StatisticsFile,
+        PartitionStatisticsFile, Schema, SchemaRef, Snapshot, SnapshotRef, SnapshotReference,
+        SnapshotRetention, SortOrder, StatisticsFile,
     };
     use crate::{Error, ErrorKind};
 
@@ -772,7 +942,10 @@ pub(super) mod _serde {
         pub partition_specs: Vec<PartitionSpec>,
         pub default_spec_id: i32,
         pub last_partition_id: i32,
-        #[serde(skip_serializing_if = "Option::is_none")]
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            serialize_with = "crate::utils::serialize_sorted_optional_map"
+        )]
         pub properties: Option<HashMap<String, String>>,
         #[serde(skip_serializing_if = "Option::is_none")]
         pub current_snapshot_id: Option<i64>,
@@ -782,7 +955,10 @@ pub(super) mod _serde {
         pub metadata_log: Option<Vec<MetadataLog>>,
         pub sort_orders: Vec<SortOrder>,
         pub default_sort_order_id: i64,
-        #[serde(skip_serializing_if = "Option::is_none")]
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            serialize_with = "crate::utils::serialize_sorted_optional_map"
+        )]
         pub refs: Option<HashMap<String, SnapshotReference>>,
         #[serde(default, skip_serializing_if = "Vec::is_empty")]
         pub statistics: Vec<StatisticsFile>,
@@ -825,7 +1001,10 @@ pub(super) mod _serde {
         pub default_spec_id: Option<i32>,
         #[serde(skip_serializing_if = "Option::is_none")]
         pub last_partition_id: Option<i32>,
-        #[serde(skip_serializing_if = "Option::is_none")]
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            serialize_with = "crate::utils::serialize_sorted_optional_map"
+        )]
         pub properties: Option<HashMap<String, String>>,
         #[serde(skip_serializing_if = "Option::is_none")]
         pub current_snapshot_id: Option<i64>,
@@ -1281,6 +1460,21 @@ pub(super) mod _serde {
         }
     }
 
+    /// Returns the values of the map ordered by key, so that the serialized
+    /// metadata doesn't depend on the iteration order of the map.
+    fn sorted_by_key<K: Ord, V>(map: HashMap<K, V>) -> impl Iterator<Item = V> {
+        let mut entries = map.into_iter().collect::<Vec<_>>();
+        entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+        entries.into_iter().map(|(_, v)| v)
+    }
+
+    /// Returns the snapshots in commit order.
+    fn sorted_snapshots(snapshots: HashMap<i64, SnapshotRef>) -> impl Iterator<Item = SnapshotRef> {
+        let mut snapshots = snapshots.into_values().collect::<Vec<_>>();
+        snapshots.sort_by_key(|s| (s.sequence_number(), s.timestamp_ms(), s.snapshot_id()));
+        snapshots.into_iter()
+    }
+
     impl TryFrom<TableMetadata> for TableMetadataV3 {
         type Error = Error;
 
@@ -1297,14 +1491,13 @@ pub(super) mod _serde {
                 encryption_keys: if encryption_keys.is_empty() {
                     None
                 } else {
-                    Some(encryption_keys.into_values().collect())
+                    Some(sorted_by_key(encryption_keys).collect())
                 },
                 snapshots: if snapshots.is_empty() {
                     None
                 } else {
                     Some(
-                        snapshots
-                            .into_values()
+                        sorted_snapshots(snapshots)
                             .map(|s| SnapshotV3::try_from(Arc::unwrap_or_clone(s)))
                             .collect::<Result<_, _>>()?,
                     )
@@ -1325,8 +1518,7 @@ pub(super) mod _serde {
                     None
                 } else {
                     Some(
-                        snapshots
-                            .into_values()
+                        sorted_snapshots(snapshots)
                             .map(|s| SnapshotV2::from(Arc::unwrap_or_clone(s)))
                             .collect(),
                     )
@@ -1343,9 +1535,7 @@ pub(super) mod _serde {
                 last_sequence_number: v.last_sequence_number,
                 last_updated_ms: v.last_updated_ms,
                 last_column_id: v.last_column_id,
-                schemas: v
-                    .schemas
-                    .into_values()
+                schemas: sorted_by_key(v.schemas)
                     .map(|x| {
                         Arc::try_unwrap(x)
                             .unwrap_or_else(|schema| schema.as_ref().clone())
@@ -1353,9 +1543,7 @@ pub(super) mod _serde {
                     })
                     .collect(),
                 current_schema_id: v.current_schema_id,
-                partition_specs: v
-                    .partition_specs
-                    .into_values()
+                partition_specs: sorted_by_key(v.partition_specs)
                     .map(|x| Arc::try_unwrap(x).unwrap_or_else(|s| s.as_ref().clone()))
                     .collect(),
                 default_spec_id: v.default_spec.spec_id(),
@@ -1376,15 +1564,13 @@ pub(super) mod _serde {
                 } else {
                     Some(v.metadata_log)
                 },
-                sort_orders: v
-                    .sort_orders
-                    .into_values()
+                sort_orders: sorted_by_key(v.sort_orders)
                     .map(|x| Arc::try_unwrap(x).unwrap_or_else(|s| s.as_ref().clone()))
                     .collect(),
                 default_sort_order_id: v.default_sort_order_id,
                 refs: Some(v.refs),
-                statistics: v.statistics.into_values().collect(),
-                partition_statistics: v.partition_statistics.into_values().collect(),
+                statistics: sorted_by_key(v.statistics).collect(),
+                partition_statistics: sorted_by_key(v.partition_statistics).collect(),
             }
         }
     }
@@ -1410,8 +1596,7 @@ pub(super) mod _serde {
                         .into(),
                 ),
                 schemas: Some(
-                    v.schemas
-                        .into_values()
+                    sorted_by_key(v.schemas)
                         .map(|x| {
                             Arc::try_unwrap(x)
                                 .unwrap_or_else(|schema| schema.as_ref().clone())
@@ -1422,8 +1607,7 @@ pub(super) mod _serde {
                 current_schema_id: Some(v.current_schema_id),
                 partition_spec: Some(v.default_spec.fields().to_vec()),
                 partition_specs: Some(
-                    v.partition_specs
-                        .into_values()
+                    sorted_by_key(v.partition_specs)
                         .map(|x| Arc::try_unwrap(x).unwrap_or_else(|s| s.as_ref().clone()))
                         .collect(),
                 ),
@@ -1439,8 +1623,7 @@ pub(super) mod _serde {
                     None
                 } else {
                     Some(
-                        v.snapshots
-                            .into_values()
+                        sorted_snapshots(v.snapshots)
                             .map(|x| Snapshot::clone(&x).into())
                             .collect(),
                     )
@@ -1456,14 +1639,13 @@ pub(super) mod _serde {
                     Some(v.metadata_log)
                 },
                 sort_orders: Some(
-                    v.sort_orders
-                        .into_values()
+                    sorted_by_key(v.sort_orders)
                         .map(|s| Arc::try_unwrap(s).unwrap_or_else(|s| s.as_ref().clone()))
                         .collect(),
                 ),
                 default_sort_order_id: Some(v.default_sort_order_id),
-                statistics: v.statistics.into_values().collect(),
-                partition_statistics: v.partition_statistics.into_values().collect(),
+                statistics: sorted_by_key(v.statistics).collect(),
+                partition_statistics: sorted_by_key(v.partition_statistics).collect(),
             })
         }
     }
@@ -1556,7 +1738,7 @@ impl SnapshotLog {
 
 #[cfg(test)]
 mod tests {
-    use std::collections::HashMap;
+    use std::collections::{HashMap, HashSet};
     use std::fs;
     use std::sync::Arc;
 
@@ -1566,7 +1748,7 @@ mod tests {
     use tempfile::TempDir;
     use uuid::Uuid;
 
-    use super::{FormatVersion, MetadataLog, SnapshotLog, TableMetadataBuilder};
+    use super::{FormatVersion, MAIN_BRANCH, MetadataLog, SnapshotLog, TableMetadataBuilder};
     use crate::compression::CompressionCodec;
     use crate::io::FileIOBuilder;
     use crate::spec::table_metadata::TableMetadata;
@@ -3591,6 +3773,81 @@ mod tests {
         assert_eq!(read_metadata, original_metadata);
     }
 
+    #[tokio::test]
+    async fn test_table_metadata_read_strict_validates_snapshot_log() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_io = FileIOBuilder::new_fs_io().build().unwrap();
+        let write_metadata = |name: &str, snapshot_log: serde_json::Value| {
+            let mut metadata: serde_json::Value = serde_json::from_str(
+                &fs::read_to_string("testdata/table_metadata/TableMetadataV2Valid.json").unwrap(),
+            )
+            .unwrap();
+            metadata["snapshot-log"] = snapshot_log;
+            let location = temp_dir.path().join(name);
+            fs::write(&location, metadata.to_string()).unwrap();
+            location.to_str().unwrap().to_string()
+        };
+
+        let valid = write_metadata(
+            "valid.metadata.json",
+            serde_json::json!([
+                {"snapshot-id": 3051729675574597004_i64, "timestamp-ms": 1515100955770_i64},
+                {"snapshot-id": 3055729675574597004_i64, "timestamp-ms": 1555100955770_i64},
+            ]),
+        );
+        TableMetadata::read_from_strict(&file_io, &valid)
+            .await
+            .unwrap();
+
+        // Out of order by less than the clock skew tolerated by the lenient load.
+        let out_of_order = write_metadata(
+            "out-of-order.metadata.json",
+            serde_json::json!([
+                {"snapshot-id": 3051729675574597004_i64, "timestamp-ms": 1555100965770_i64},
+                {"snapshot-id": 3055729675574597004_i64, "timestamp-ms": 1555100955770_i64},
+            ]),
+        );
+        let metadata = TableMetadata::read_from(&file_io, &out_of_order)
+            .await
+            .unwrap();
+        assert_eq!(metadata.history().len(), 2);
+        let err = TableMetadata::read_from_strict(&file_io, &out_of_order)
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::DataInvalid);
+        assert!(err.message().contains("is before the entry of snapshot"));
+
+        let not_current = write_metadata(
+            "not-current.metadata.json",
+            serde_json::json!([
+                {"snapshot-id": 3055729675574597004_i64, "timestamp-ms": 1555100955770_i64},
+                {"snapshot-id": 3051729675574597004_i64, "timestamp-ms": 1555100965770_i64},
+            ]),
+        );
+        TableMetadata::read_from(&file_io, &not_current)
+            .await
+            .unwrap();
+        let err = TableMetadata::read_from_strict(&file_io, &not_current)
+            .await
+            .unwrap_err();
+        assert!(
+            err.message()
+                .contains("does not match the last snapshot log entry")
+        );
+
+        let unknown = write_metadata(
+            "unknown.metadata.json",
+            serde_json::json!([
+                {"snapshot-id": 1_i64, "timestamp-ms": 1515100955770_i64},
+                {"snapshot-id": 3055729675574597004_i64, "timestamp-ms": 1555100955770_i64},
+            ]),
+        );
+        let err = TableMetadata::read_from_strict(&file_io, &unknown)
+            .await
+            .unwrap_err();
+        assert!(err.message().contains("references unknown snapshot 1"));
+    }
+
     #[tokio::test]
     async fn test_table_metadata_read_nonexistent_file() {
         // Create a FileIO instance
@@ -4168,4 +4425,203 @@ mod tests {
         assert_eq!(deserialized_first_row_id, 100);
         assert_eq!(deserialized_added_rows, 50);
     }
+
+    #[test]
+    fn test_table_metadata_json_bytes_golden_file() {
+        // The input lists schemas, specs, sort orders, snapshots and statistics
+        // out of id order, and its maps out of key order. The golden file is
+        // written by hand with every list and map in canonical order.
+        let metadata = get_test_table_metadata("TableMetadataV2ValidUnordered.json");
+        let golden =
+            fs::read("testdata/table_metadata/TableMetadataV2ValidCanonical.json").unwrap();
+
+        assert_eq!(
+            String::from_utf8(metadata.to_json_bytes().unwrap()).unwrap(),
+            String::from_utf8(golden.clone()).unwrap()
+        );
+        assert_eq!(TableMetadata::from_json_bytes(&golden).unwrap(), metadata);
+    }
+
+    #[test]
+    fn test_table_metadata_json_bytes_round_trip() {
+        for file_name in [
+            "TableMetadataV1Valid.json",
+            "TableMetadataV2Valid.json",
+            "TableMetadataV2ValidMinimal.json",
+            "TableMetadataV3ValidMinimal.json",
+        ] {
+            let metadata = get_test_table_metadata(file_name);
+            let bytes = metadata.to_json_bytes().unwrap();
+            let reparsed = TableMetadata::from_json_bytes(&bytes).unwrap();
+
+            assert_eq!(reparsed, metadata, "{file_name}");
+            assert_eq!(reparsed.to_json_bytes().unwrap(), bytes, "{file_name}");
+        }
+    }
+
+    #[test]
+    fn test_table_metadata_json_bytes_sorts_map_entries() {
+        let properties = ('a'..='z')
+            .rev()
+            .map(|c| (c.to_string(), c.to_string()))
+            .collect::<HashMap<_, _>>();
+        let metadata = get_test_table_metadata("TableMetadataV2Valid.json")
+            .into_builder(None)
+            .set_properties(properties)
+            .unwrap()
+            .build()
+            .unwrap()
+            .metadata;
+
+        let json = String::from_utf8(metadata.to_json_bytes().unwrap()).unwrap();
+        let expected_properties = format!(
+            "\"properties\":{{{}}}",
+            ('a'..='z')
+                .map(|c| format!("\"{c}\":\"{c}\""))
+                .collect::<Vec<_>>()
+                .join(",")
+        );
+        assert!(json.contains(&expected_properties), "{json}");
+
+        // A freshly parsed copy has a different map iteration order but must
+        // serialize to the same bytes.
+        let reparsed = TableMetadata::from_json_bytes(json.as_bytes()).unwrap();
+        assert_eq!(reparsed.to_json_bytes().unwrap(), json.into_bytes());
+    }
+
+    #[test]
+    fn test_snapshot_by_ref_and_ancestors() {
+        let metadata = get_test_table_metadata("TableMetadataV2Valid.json");
+        let snapshot = Snapshot::builder()
+            .with_snapshot_id(3059729675574597004)
+            .with_parent_snapshot_id(Some(3055729675574597004))
+            .with_timestamp_ms(metadata.last_updated_ms() + 1)
+            .with_sequence_number(metadata.last_sequence_number() + 1)
+            .with_manifest_list("s3://a/b/3.avro")
+            .with_schema_id(1)
+            .with_summary(Summary {
+                operation: Operation::Append,
+                additional_properties: HashMap::new(),
+            })
+            .build();
+        let metadata = metadata
+            .into_builder(None)
+            .set_branch_snapshot(snapshot, MAIN_BRANCH)
+            .unwrap()
+            .set_ref(
+                "v1",
+                SnapshotReference::new(3051729675574597004, SnapshotRetention::Tag {
+                    max_ref_age_ms: None,
+                }),
+            )
+            .unwrap()
+            .build()
+            .unwrap()
+            .metadata;
+
+        assert_eq!(
+            metadata.snapshot_by_ref("v1").unwrap().snapshot_id(),
+            3051729675574597004
+        );
+        assert_eq!(
+            metadata.snapshot_by_ref(MAIN_BRANCH).unwrap().snapshot_id(),
+            3059729675574597004
+        );
+        assert!(metadata.snapshot_by_ref("missing").is_none());
+
+        let ancestors = metadata
+            .ancestors_of(3059729675574597004)
+            .map(|snapshot| snapshot.snapshot_id())
+            .collect::<Vec<_>>();
+        assert_eq!(ancestors, vec![
+            3059729675574597004,
+            3055729675574597004,
+            3051729675574597004
+        ]);
+        assert_eq!(metadata.ancestors_of(3051729675574597004).count(), 1);
+        assert_eq!(metadata.ancestors_of(42).count(), 0);
+    }
+
+    #[test]
+    fn test_snapshots_to_retain() {
+        const DAY_MS: i64 = 24 * 60 * 60 * 1000;
+
+        // Daily snapshots over 30 days, the third one tagged.
+        let mut metadata = get_test_table_metadata("TableMetadataV2ValidMinimal.json");
+        let start_ms = metadata.last_updated_ms();
+        for snapshot_id in 1..=30 {
+            let snapshot = Snapshot::builder()
+                .with_snapshot_id(snapshot_id)
+                .with_parent_snapshot_id((snapshot_id > 1).then_some(snapshot_id - 1))
+                .with_timestamp_ms(start_ms + snapshot_id * DAY_MS)
+                .with_sequence_number(metadata.last_sequence_number() + 1)
+                .with_manifest_list(format!("s3://a/b/{snapshot_id}.avro"))
+                .with_schema_id(0)
+                .with_summary(Summary {
+                    operation: Operation::Append,
+                    additional_properties: HashMap::new(),
+                })
+                .build();
+            metadata = metadata
+                .into_builder(None)
+                .set_branch_snapshot(snapshot, MAIN_BRANCH)
+                .unwrap()
+                .build()
+                .unwrap()
+                .metadata;
+        }
+        metadata = metadata
+            .into_builder(None)
+            .set_ref(
+                "audit",
+                SnapshotReference::new(3, SnapshotRetention::Tag {
+                    max_ref_age_ms: None,
+                }),
+            )
+            .unwrap()
+            .build()
+            .unwrap()
+            .metadata;
+        assert_eq!(metadata.history().len(), 30);
+        let last_snapshot_ms = metadata.current_snapshot().unwrap().timestamp_ms();
+
+        // A 7-day window starting at a snapshot needs the last 7 snapshots and the tag.
+        let window_start_ms = last_snapshot_ms - 6 * DAY_MS;
+        let retained = metadata
+            .snapshots_to_retain(window_start_ms, ["audit"])
+            .unwrap();
+        assert_eq!(retained, HashSet::from([3, 24, 25, 26, 27, 28, 29, 30]));
+
+        // A window starting between two snapshots needs the one current at its start too.
+        let retained = metadata
+            .snapshots_to_retain(window_start_ms - 1, Vec::<String>::new())
+            .unwrap();
+        assert_eq!(retained, (23..=30).collect::<HashSet<_>>());
+
+        // The current snapshot is needed even without any commit in the window.
+        let retained = metadata
+            .snapshots_to_retain(last_snapshot_ms + DAY_MS, ["audit"])
+            .unwrap();
+        assert_eq!(retained, HashSet::from([3, 30]));
+
+        assert!(metadata.snapshots_to_retain(0, ["missing"]).is_err());
+        assert!(metadata.snapshots_to_retain(0, [MAIN_BRANCH]).is_err());
+    }
+
+    #[test]
+    fn test_resolve_location() {
+        let metadata = get_test_table_metadata("TableMetadataV2Valid.json");
+        assert_eq!(metadata.location(), "s3://bucket/test/location");
+
+        assert_eq!(
+            metadata.resolve_location("data/1.parquet"),
+            "s3://bucket/test/location/data/1.parquet"
+        );
+        for absolute in [
+            "s3://bucket/test/location/data/1.parquet",
+            "/tmp/location/data/1.parquet",
+        ] {
+            assert_eq!(metadata.resolve_location(absolute), absolute);
+        }
+    }
 }
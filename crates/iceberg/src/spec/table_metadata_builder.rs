@@ -1237,8 +1237,10 @@ impl TableMetadataBuilder {
     /// be corrected by suppressing the intermediate snapshot entries.
     ///     
     /// A snapshot is an intermediate snapshot if it was added but is not the current snapshot.
+    /// Snapshots that are ancestors of the current snapshot were current in turn, such as the
+    /// snapshots of several appends committed together, and keep their entries.
     fn get_intermediate_snapshots(&self) -> HashSet<i64> {
-        let added_snapshot_ids = self
+        let mut added_snapshot_ids = self
             .changes
             .iter()
             .filter_map(|update| match update {
@@ -1247,6 +1249,15 @@ impl TableMetadataBuilder {
             })
             .collect::<HashSet<_>>();
 
+        let mut ancestor_id = self.metadata.current_snapshot_id;
+        while let Some(snapshot) = ancestor_id.and_then(|id| self.metadata.snapshots.get(&id)) {
+            if added_snapshot_ids.is_empty() {
+                break;
+            }
+            added_snapshot_ids.remove(&snapshot.snapshot_id());
+            ancestor_id = snapshot.parent_snapshot_id();
+        }
+
         self.changes
             .iter()
             .filter_map(|update| match update {
@@ -2279,6 +2290,42 @@ mod tests {
         assert_eq!(result.metadata.current_snapshot().unwrap().snapshot_id(), 2);
     }
 
+    #[test]
+    fn test_snapshot_log_keeps_ancestors_of_current_snapshot() {
+        let builder = builder_without_changes(FormatVersion::V2);
+        let timestamp_ms = builder.metadata.last_updated_ms + 1;
+        let snapshot = |snapshot_id: i64, parent_snapshot_id: Option<i64>| {
+            Snapshot::builder()
+                .with_snapshot_id(snapshot_id)
+                .with_parent_snapshot_id(parent_snapshot_id)
+                .with_timestamp_ms(timestamp_ms)
+                .with_sequence_number(snapshot_id)
+                .with_schema_id(0)
+                .with_manifest_list(format!("/snap-{snapshot_id}.avro"))
+                .with_summary(Summary {
+                    operation: Operation::Append,
+                    additional_properties: HashMap::new(),
+                })
+                .build()
+        };
+
+        let result = builder
+            .set_branch_snapshot(snapshot(1, None), MAIN_BRANCH)
+            .unwrap()
+            .set_branch_snapshot(snapshot(2, Some(1)), MAIN_BRANCH)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let logged: Vec<_> = result
+            .metadata
+            .snapshot_log
+            .iter()
+            .map(|entry| entry.snapshot_id)
+            .collect();
+        assert_eq!(logged, vec![1, 2]);
+    }
+
     #[test]
     fn test_remove_main_ref_keeps_snapshot_log() {
         let builder = builder_without_changes(FormatVersion::V2);
@@ -34,6 +34,51 @@ where
     })
 }
 
+// Collects the columns with a `true` bloom filter property, sorted by name
+fn parse_bloom_filter_columns(
+    properties: &HashMap<String, String>,
+) -> Result<Vec<String>, anyhow::Error> {
+    let mut columns = Vec::new();
+    for (key, value) in properties {
+        let Some(column) =
+            key.strip_prefix(TableProperties::PROPERTY_PARQUET_BLOOM_FILTER_COLUMN_ENABLED_PREFIX)
+        else {
+            continue;
+        };
+        let enabled = value
+            .parse::<bool>()
+            .map_err(|e| anyhow::anyhow!("Invalid value for {key}: {e}"))?;
+        if enabled {
+            columns.push(column.to_string());
+        }
+    }
+    columns.sort();
+    Ok(columns)
+}
+
+// Parses the length of `truncate(<length>)` metrics, other modes keep full bounds
+fn parse_metrics_truncate_length(
+    properties: &HashMap<String, String>,
+) -> Result<Option<usize>, anyhow::Error> {
+    let key = TableProperties::PROPERTY_METRICS_DEFAULT;
+    let Some(value) = properties.get(key) else {
+        return Ok(None);
+    };
+    let Some(length) = value
+        .strip_prefix("truncate(")
+        .and_then(|value| value.strip_suffix(')'))
+    else {
+        return Ok(None);
+    };
+    match length.parse::<usize>() {
+        Ok(length) if length > 0 => Ok(Some(length)),
+        Ok(_) => Err(anyhow::anyhow!(
+            "Invalid value for {key}: truncate length must be positive"
+        )),
+        Err(e) => Err(anyhow::anyhow!("Invalid value for {key}: {e}")),
+    }
+}
+
 /// TableProperties that contains the properties of a table.
 #[derive(Debug)]
 pub struct TableProperties {
@@ -51,6 +96,18 @@ pub struct TableProperties {
     pub write_target_file_size_bytes: usize,
     /// Whether to use `FanoutWriter` for partitioned tables.
     pub write_datafusion_fanout_enabled: bool,
+    /// Whether the `FanoutWriter` sorts each batch by partition before writing it.
+    pub write_datafusion_local_partition_sort_enabled: bool,
+    /// The columns to write Parquet bloom filters for, sorted by name.
+    pub write_parquet_bloom_filter_columns: Vec<String>,
+    /// The length string and binary column bounds are truncated to, if any.
+    pub write_metadata_metrics_truncate_length: Option<usize>,
+    /// The target size of the tasks of a scan.
+    pub read_split_target_size: u64,
+    /// The estimated cost of opening a file, in bytes.
+    pub read_split_open_file_cost: u64,
+    /// The number of tasks considered at once when combining files into tasks.
+    pub read_split_planning_lookback: usize,
 }
 
 impl TableProperties {
@@ -81,6 +138,13 @@ impl TableProperties {
     /// Reserved table property for the JSON representation of current(default) sort order.
     pub const PROPERTY_DEFAULT_SORT_ORDER: &str = "default-sort-order";
 
+    /// Property key for the location of the data files written to the table, the `data`
+    /// directory of the table location by default.
+    pub const PROPERTY_WRITE_DATA_PATH: &str = "write.data.path";
+    /// Property key for the location of the metadata files written to the table, the
+    /// `metadata` directory of the table location by default.
+    pub const PROPERTY_WRITE_METADATA_PATH: &str = "write.metadata.path";
+
     /// Property key for max number of previous versions to keep.
     pub const PROPERTY_METADATA_PREVIOUS_VERSIONS_MAX: &str =
         "write.metadata.previous-versions-max";
@@ -144,6 +208,45 @@ impl TableProperties {
     pub const PROPERTY_DATAFUSION_WRITE_FANOUT_ENABLED: &str = "write.datafusion.fanout.enabled";
     /// Default value for fanout writer enabled
     pub const PROPERTY_DATAFUSION_WRITE_FANOUT_ENABLED_DEFAULT: bool = true;
+    /// Whether the `FanoutWriter` sorts each batch by partition before writing it, so it only
+    /// keeps one file open at a time at the cost of more files for unsorted input.
+    pub const PROPERTY_DATAFUSION_WRITE_LOCAL_PARTITION_SORT_ENABLED: &str =
+        "write.datafusion.local-partition-sort.enabled";
+    /// Default value for local partition sort enabled
+    pub const PROPERTY_DATAFUSION_WRITE_LOCAL_PARTITION_SORT_ENABLED_DEFAULT: bool = false;
+
+    /// Prefix of the properties enabling Parquet bloom filters for a column, the column name
+    /// follows the prefix, e.g. `write.parquet.bloom-filter-enabled.column.id`.
+    pub const PROPERTY_PARQUET_BLOOM_FILTER_COLUMN_ENABLED_PREFIX: &str =
+        "write.parquet.bloom-filter-enabled.column.";
+
+    /// Prefix of the properties holding the default expression of a top-level column, the
+    /// column name follows the prefix, e.g. `read.default-expression.column.id`. This is a
+    /// vendor extension, see [`crate::scan::DefaultExpressionEvaluator`].
+    pub const PROPERTY_DEFAULT_EXPRESSION_PREFIX: &str = "read.default-expression.column.";
+
+    /// The zone the timestamps of `timestamptz` columns stored without UTC adjustment are
+    /// local times in, UTC by default. This is a vendor extension, see
+    /// [`crate::scan::TableScanBuilder::with_timestamp_zone`].
+    pub const PROPERTY_READ_TIMESTAMP_ZONE: &str = "read.timestamp.assumed-zone";
+
+    /// The default metrics mode of the columns of written data files. Bounds of string and
+    /// binary columns are truncated to `length` characters or bytes by `truncate(<length>)`,
+    /// while the other modes keep full bounds.
+    pub const PROPERTY_METRICS_DEFAULT: &str = "write.metadata.metrics.default";
+
+    /// Target size of the tasks combining the files of a scan.
+    pub const PROPERTY_READ_SPLIT_TARGET_SIZE: &str = "read.split.target-size";
+    /// Default target size of the tasks of a scan.
+    pub const PROPERTY_READ_SPLIT_TARGET_SIZE_DEFAULT: u64 = 128 * 1024 * 1024; // 128 MB
+    /// Estimated cost of opening a file, the minimum weight of a file in a task.
+    pub const PROPERTY_READ_SPLIT_OPEN_FILE_COST: &str = "read.split.open-file-cost";
+    /// Default estimated cost of opening a file.
+    pub const PROPERTY_READ_SPLIT_OPEN_FILE_COST_DEFAULT: u64 = 4 * 1024 * 1024; // 4 MB
+    /// Number of tasks kept open when combining the files of a scan into tasks.
+    pub const PROPERTY_READ_SPLIT_PLANNING_LOOKBACK: &str = "read.split.planning-lookback";
+    /// Default number of tasks kept open when combining files.
+    pub const PROPERTY_READ_SPLIT_PLANNING_LOOKBACK_DEFAULT: usize = 10;
 }
 
 impl TryFrom<&HashMap<String, String>> for TableProperties {
@@ -187,6 +290,28 @@ impl TryFrom<&HashMap<String, String>> for TableProperties {
                 TableProperties::PROPERTY_DATAFUSION_WRITE_FANOUT_ENABLED,
                 TableProperties::PROPERTY_DATAFUSION_WRITE_FANOUT_ENABLED_DEFAULT,
             )?,
+            write_datafusion_local_partition_sort_enabled: parse_property(
+                props,
+                TableProperties::PROPERTY_DATAFUSION_WRITE_LOCAL_PARTITION_SORT_ENABLED,
+                TableProperties::PROPERTY_DATAFUSION_WRITE_LOCAL_PARTITION_SORT_ENABLED_DEFAULT,
+            )?,
+            write_parquet_bloom_filter_columns: parse_bloom_filter_columns(props)?,
+            write_metadata_metrics_truncate_length: parse_metrics_truncate_length(props)?,
+            read_split_target_size: parse_property(
+                props,
+                TableProperties::PROPERTY_READ_SPLIT_TARGET_SIZE,
+                TableProperties::PROPERTY_READ_SPLIT_TARGET_SIZE_DEFAULT,
+            )?,
+            read_split_open_file_cost: parse_property(
+                props,
+                TableProperties::PROPERTY_READ_SPLIT_OPEN_FILE_COST,
+                TableProperties::PROPERTY_READ_SPLIT_OPEN_FILE_COST_DEFAULT,
+            )?,
+            read_split_planning_lookback: parse_property(
+                props,
+                TableProperties::PROPERTY_READ_SPLIT_PLANNING_LOOKBACK,
+                TableProperties::PROPERTY_READ_SPLIT_PLANNING_LOOKBACK_DEFAULT,
+            )?,
         })
     }
 }
@@ -219,6 +344,23 @@ mod tests {
             table_properties.write_target_file_size_bytes,
             TableProperties::PROPERTY_WRITE_TARGET_FILE_SIZE_BYTES_DEFAULT
         );
+        assert!(
+            table_properties
+                .write_parquet_bloom_filter_columns
+                .is_empty()
+        );
+        assert_eq!(
+            table_properties.write_metadata_metrics_truncate_length,
+            None
+        );
+        assert_eq!(
+            table_properties.read_split_target_size,
+            TableProperties::PROPERTY_READ_SPLIT_TARGET_SIZE_DEFAULT
+        );
+        assert_eq!(
+            table_properties.read_split_open_file_cost,
+            TableProperties::PROPERTY_READ_SPLIT_OPEN_FILE_COST_DEFAULT
+        );
     }
 
     #[test]
@@ -240,12 +382,36 @@ mod tests {
                 TableProperties::PROPERTY_WRITE_TARGET_FILE_SIZE_BYTES.to_string(),
                 "512".to_string(),
             ),
+            (
+                "write.parquet.bloom-filter-enabled.column.name".to_string(),
+                "true".to_string(),
+            ),
+            (
+                "write.parquet.bloom-filter-enabled.column.id".to_string(),
+                "true".to_string(),
+            ),
+            (
+                "write.parquet.bloom-filter-enabled.column.ts".to_string(),
+                "false".to_string(),
+            ),
+            (
+                TableProperties::PROPERTY_METRICS_DEFAULT.to_string(),
+                "truncate(16)".to_string(),
+            ),
         ]);
         let table_properties = TableProperties::try_from(&props).unwrap();
         assert_eq!(table_properties.commit_num_retries, 10);
         assert_eq!(table_properties.commit_max_retry_wait_ms, 20);
         assert_eq!(table_properties.write_format_default, "avro".to_string());
         assert_eq!(table_properties.write_target_file_size_bytes, 512);
+        assert_eq!(table_properties.write_parquet_bloom_filter_columns, vec![
+            "id".to_string(),
+            "name".to_string()
+        ]);
+        assert_eq!(
+            table_properties.write_metadata_metrics_truncate_length,
+            Some(16)
+        );
     }
 
     #[test]
@@ -292,5 +458,30 @@ mod tests {
         assert!(table_properties.to_string().contains(
             "Invalid value for write.target-file-size-bytes: invalid digit found in string"
         ));
+
+        let invalid_bloom_filter = HashMap::from([(
+            "write.parquet.bloom-filter-enabled.column.id".to_string(),
+            "yes".to_string(),
+        )]);
+        let table_properties = TableProperties::try_from(&invalid_bloom_filter).unwrap_err();
+        assert!(
+            table_properties
+                .to_string()
+                .contains("Invalid value for write.parquet.bloom-filter-enabled.column.id")
+        );
+
+        for invalid in ["truncate(abc)", "truncate(0)"] {
+            let invalid_metrics = HashMap::from([(
+                TableProperties::PROPERTY_METRICS_DEFAULT.to_string(),
+                invalid.to_string(),
+            )]);
+            let table_properties = TableProperties::try_from(&invalid_metrics).unwrap_err();
+            assert!(
+                table_properties
+                    .to_string()
+                    .contains("Invalid value for write.metadata.metrics.default"),
+                "{invalid}"
+            );
+        }
     }
 }
@@ -654,30 +654,57 @@ impl Transform {
     /// the literal value within the given `Datum`. For operators that do not
     /// imply a boundary shift (`Eq`, `LessThanOrEq`, `GreaterThanOrEq`,
     /// `StartsWith`, `NotStartsWith`), the original datum is returned
-    /// unmodified.
+    /// unmodified. No boundary is returned when shifting it overflows, so the
+    /// predicate is not projected.
     fn adjust_boundary(op: &PredicateOperator, datum: &Datum) -> Result<Option<Datum>> {
         let adjusted_boundary = match op {
             PredicateOperator::LessThan => match (datum.data_type(), datum.literal()) {
-                (PrimitiveType::Int, PrimitiveLiteral::Int(v)) => Some(Datum::int(v - 1)),
-                (PrimitiveType::Long, PrimitiveLiteral::Long(v)) => Some(Datum::long(v - 1)),
+                (PrimitiveType::Int, PrimitiveLiteral::Int(v)) => v.checked_sub(1).map(Datum::int),
+                (PrimitiveType::Long, PrimitiveLiteral::Long(v)) => {
+                    v.checked_sub(1).map(Datum::long)
+                }
                 (PrimitiveType::Decimal { .. }, PrimitiveLiteral::Int128(v)) => {
                     Some(Datum::decimal(decimal_from_i128_with_scale(v - 1, 0))?)
                 }
-                (PrimitiveType::Date, PrimitiveLiteral::Int(v)) => Some(Datum::date(v - 1)),
+                (PrimitiveType::Date, PrimitiveLiteral::Int(v)) => {
+                    v.checked_sub(1).map(Datum::date)
+                }
                 (PrimitiveType::Timestamp, PrimitiveLiteral::Long(v)) => {
-                    Some(Datum::timestamp_micros(v - 1))
+                    v.checked_sub(1).map(Datum::timestamp_micros)
+                }
+                (PrimitiveType::Timestamptz, PrimitiveLiteral::Long(v)) => {
+                    v.checked_sub(1).map(Datum::timestamptz_micros)
+                }
+                (PrimitiveType::TimestampNs, PrimitiveLiteral::Long(v)) => {
+                    v.checked_sub(1).map(Datum::timestamp_nanos)
+                }
+                (PrimitiveType::TimestamptzNs, PrimitiveLiteral::Long(v)) => {
+                    v.checked_sub(1).map(Datum::timestamptz_nanos)
                 }
                 _ => Some(datum.to_owned()),
             },
             PredicateOperator::GreaterThan => match (datum.data_type(), datum.literal()) {
-                (PrimitiveType::Int, PrimitiveLiteral::Int(v)) => Some(Datum::int(v + 1)),
-                (PrimitiveType::Long, PrimitiveLiteral::Long(v)) => Some(Datum::long(v + 1)),
+                (PrimitiveType::Int, PrimitiveLiteral::Int(v)) => v.checked_add(1).map(Datum::int),
+                (PrimitiveType::Long, PrimitiveLiteral::Long(v)) => {
+                    v.checked_add(1).map(Datum::long)
+                }
                 (PrimitiveType::Decimal { .. }, PrimitiveLiteral::Int128(v)) => {
                     Some(Datum::decimal(decimal_from_i128_with_scale(v + 1, 0))?)
                 }
-                (PrimitiveType::Date, PrimitiveLiteral::Int(v)) => Some(Datum::date(v + 1)),
+                (PrimitiveType::Date, PrimitiveLiteral::Int(v)) => {
+                    v.checked_add(1).map(Datum::date)
+                }
                 (PrimitiveType::Timestamp, PrimitiveLiteral::Long(v)) => {
-                    Some(Datum::timestamp_micros(v + 1))
+                    v.checked_add(1).map(Datum::timestamp_micros)
+                }
+                (PrimitiveType::Timestamptz, PrimitiveLiteral::Long(v)) => {
+                    v.checked_add(1).map(Datum::timestamptz_micros)
+                }
+                (PrimitiveType::TimestampNs, PrimitiveLiteral::Long(v)) => {
+                    v.checked_add(1).map(Datum::timestamp_nanos)
+                }
+                (PrimitiveType::TimestamptzNs, PrimitiveLiteral::Long(v)) => {
+                    v.checked_add(1).map(Datum::timestamptz_nanos)
                 }
                 _ => Some(datum.to_owned()),
             },
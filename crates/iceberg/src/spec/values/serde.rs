@@ -418,6 +418,12 @@ pub(crate) mod _serde {
                     Type::Primitive(PrimitiveType::Timestamptz) => {
                         Ok(Some(Literal::timestamptz(v)))
                     }
+                    Type::Primitive(PrimitiveType::TimestampNs) => {
+                        Ok(Some(Literal::timestamp_nano(v)))
+                    }
+                    Type::Primitive(PrimitiveType::TimestamptzNs) => {
+                        Ok(Some(Literal::timestamptz_nano(v)))
+                    }
                     _ => Err(invalid_err("long")),
                 },
                 RawLiteralEnum::Float(v) => match ty {
@@ -673,22 +679,26 @@ pub(crate) mod _serde {
                     optional: _,
                 }) => match ty {
                     Type::Struct(struct_ty) => {
-                        let iters: Vec<Option<Literal>> = required
-                            .into_iter()
-                            .map(|(field_name, value)| {
-                                let field = struct_ty
-                                    .field_by_name(field_name.as_str())
-                                    .ok_or_else(|| {
-                                        invalid_err_with_reason(
-                                            "record",
-                                            &format!("field {} is not exist", &field_name),
-                                        )
-                                    })?;
-                                let value = value.try_into(&field.field_type)?;
-                                Ok(value)
-                            })
-                            .collect::<Result<_, Error>>()?;
-                        Ok(Some(Literal::Struct(Struct::from_iter(iters))))
+                        // Record fields may come in another order than the fields of the struct.
+                        let mut values = Vec::with_capacity(required.len());
+                        for (field_name, value) in required {
+                            let (position, field) = struct_ty
+                                .fields()
+                                .iter()
+                                .enumerate()
+                                .find(|(_, field)| field.name == field_name)
+                                .ok_or_else(|| {
+                                    invalid_err_with_reason(
+                                        "record",
+                                        &format!("field {} is not exist", &field_name),
+                                    )
+                                })?;
+                            values.push((position, value.try_into(&field.field_type)?));
+                        }
+                        values.sort_by_key(|(position, _)| *position);
+                        Ok(Some(Literal::Struct(Struct::from_iter(
+                            values.into_iter().map(|(_, value)| value),
+                        ))))
                     }
                     Type::Map(map_ty) => {
                         if *map_ty.key_field.field_type != Type::Primitive(PrimitiveType::String) {
@@ -726,6 +726,22 @@ fn avro_convert_test_timestamptz() {
     );
 }
 
+#[test]
+fn avro_convert_test_timestamp_ns() {
+    check_convert_with_avro(
+        Literal::Primitive(PrimitiveLiteral::Long(1510871468123456789)),
+        &Type::Primitive(PrimitiveType::TimestampNs),
+    );
+}
+
+#[test]
+fn avro_convert_test_timestamptz_ns() {
+    check_convert_with_avro(
+        Literal::Primitive(PrimitiveLiteral::Long(1510871468123456789)),
+        &Type::Primitive(PrimitiveType::TimestamptzNs),
+    );
+}
+
 #[test]
 fn avro_convert_test_list() {
     check_convert_with_avro(
@@ -20,11 +20,15 @@
 use std::sync::Arc;
 
 use crate::arrow::ArrowReaderBuilder;
-use crate::inspect::MetadataTable;
+use crate::inspect::{
+    DeleteFileStream, MetadataTable, ReachableFileStream, SchemaHistoryEntry, SortOrderSummary,
+    TableSummary, delete_files, reachable_files, schema_history, sort_order_summary, table_summary,
+};
 use crate::io::FileIO;
 use crate::io::object_cache::ObjectCache;
 use crate::scan::TableScanBuilder;
-use crate::spec::{SchemaRef, TableMetadata, TableMetadataRef};
+use crate::spec::{MetadataLog, SchemaRef, TableMetadata, TableMetadataRef};
+use crate::transaction::{Clock, SystemClock};
 use crate::{Error, ErrorKind, Result, TableIdent};
 
 /// Builder to create table scan.
@@ -84,6 +88,11 @@ impl TableBuilder {
     /// specifies if the Table's metadata cache will be disabled,
     /// so that reads of Manifests and ManifestLists will never
     /// get cached.
+    ///
+    /// Without this or [`TableBuilder::cache_size_bytes`], the cache is configured by the
+    /// [`IO_MANIFEST_CACHE_ENABLED`](crate::io::IO_MANIFEST_CACHE_ENABLED) and
+    /// [`IO_MANIFEST_CACHE_MAX_TOTAL_BYTES`](crate::io::IO_MANIFEST_CACHE_MAX_TOTAL_BYTES)
+    /// properties of the FileIO.
     pub fn disable_cache(mut self) -> Self {
         self.disable_cache = true;
         self
@@ -136,7 +145,7 @@ impl TableBuilder {
                 cache_size_bytes,
             ))
         } else {
-            Arc::new(ObjectCache::new(file_io.clone()))
+            Arc::new(ObjectCache::from_file_io_props(file_io.clone())?)
         };
 
         Ok(Table {
@@ -146,6 +155,7 @@ impl TableBuilder {
             identifier,
             readonly,
             object_cache,
+            clock: Arc::new(SystemClock),
         })
     }
 }
@@ -159,6 +169,7 @@ pub struct Table {
     identifier: TableIdent,
     readonly: bool,
     object_cache: Arc<ObjectCache>,
+    clock: Arc<dyn Clock>,
 }
 
 impl Table {
@@ -174,6 +185,23 @@ impl Table {
         self
     }
 
+    /// Sets the cache of the manifests and manifest lists read by the scans of the table.
+    pub(crate) fn with_object_cache(mut self, object_cache: Arc<ObjectCache>) -> Self {
+        self.object_cache = object_cache;
+        self
+    }
+
+    /// Sets the [`Clock`] reading the timestamps of commits.
+    pub(crate) fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Returns the clock reading the timestamps of commits.
+    pub(crate) fn clock(&self) -> &dyn Clock {
+        self.clock.as_ref()
+    }
+
     /// Returns a TableBuilder to build a table
     pub fn builder() -> TableBuilder {
         TableBuilder::new()
@@ -230,11 +258,58 @@ impl Table {
         MetadataTable::new(self)
     }
 
+    /// Returns a stream of every metadata, manifest list, manifest, data and delete file
+    /// referenced by the table, each listed once. See [`reachable_files`] for more details.
+    pub fn reachable_files(&self) -> ReachableFileStream {
+        reachable_files(self)
+    }
+
+    /// Returns a stream of the live position and equality delete files of the snapshot
+    /// `snapshot_id`. See [`delete_files`] for more details.
+    pub fn delete_files(&self, snapshot_id: i64) -> Result<DeleteFileStream> {
+        delete_files(self, snapshot_id)
+    }
+
+    /// Returns the size and file counts of the current snapshot, read from metadata only.
+    /// See [`table_summary`] for more details.
+    pub async fn summary(&self) -> Result<TableSummary> {
+        table_summary(self).await
+    }
+
+    /// Returns the sort order each live data file of the current snapshot was written with.
+    /// See [`sort_order_summary`] for more details.
+    pub async fn sort_order_summary(&self) -> Result<SortOrderSummary> {
+        sort_order_summary(self).await
+    }
+
+    /// Returns every schema of the table with the first snapshot written with each of them.
+    /// See [`schema_history`] for more details.
+    pub fn schema_history(&self) -> Vec<SchemaHistoryEntry> {
+        schema_history(self)
+    }
+
     /// Returns the flag indicating whether the `Table` is readonly or not
     pub fn readonly(&self) -> bool {
         self.readonly
     }
 
+    /// Returns the locations of the previous metadata files of the table with the time they
+    /// were written, oldest first. The current metadata file is not part of the log.
+    pub fn metadata_log(&self) -> &[MetadataLog] {
+        self.metadata.metadata_log()
+    }
+
+    /// Loads the version of the table recorded by the metadata log entry `metadata_log` as a
+    /// read-only table.
+    pub async fn load_metadata_version(&self, metadata_log: &MetadataLog) -> Result<StaticTable> {
+        StaticTable::from_metadata_file(
+            &metadata_log.metadata_file,
+            self.identifier.clone(),
+            self.file_io.clone(),
+        )
+        .await
+    }
+
     /// Returns the current schema as a shared reference.
     pub fn current_schema_ref(&self) -> SchemaRef {
         self.metadata.current_schema().clone()
@@ -333,9 +408,310 @@ impl StaticTable {
     }
 }
 
+/// A read-only view of a table pinned to the snapshot that was current when it was opened.
+///
+/// All scans created with [`ReadTransaction::scan`] read the pinned snapshot, so they plan
+/// the same files even when the table is changed in between. The manifest list and manifests
+/// of the snapshot are loaded when the transaction is opened and kept in a cache of the
+/// transaction, which never evicts them, until it is dropped.
+#[derive(Debug, Clone)]
+pub struct ReadTransaction {
+    table: Table,
+    snapshot_id: Option<i64>,
+}
+
+impl ReadTransaction {
+    /// Opens a read transaction on the current snapshot of `table`.
+    pub async fn new(table: &Table) -> Result<Self> {
+        let object_cache = Arc::new(ObjectCache::new_with_capacity(
+            table.file_io().clone(),
+            u64::MAX,
+        ));
+        let table = table.clone().with_object_cache(object_cache.clone());
+
+        let metadata = table.metadata_ref();
+        if let Some(snapshot) = metadata.current_snapshot() {
+            let manifest_list = object_cache.get_manifest_list(snapshot, &metadata).await?;
+            for manifest_file in manifest_list.entries() {
+                object_cache
+                    .get_manifest(manifest_file, &metadata, None)
+                    .await?;
+            }
+        }
+
+        Ok(Self {
+            snapshot_id: metadata.current_snapshot_id(),
+            table,
+        })
+    }
+
+    /// Returns the table as of the opening of the transaction.
+    pub fn table(&self) -> &Table {
+        &self.table
+    }
+
+    /// Returns the id of the pinned snapshot, or `None` if the table had no snapshot.
+    pub fn snapshot_id(&self) -> Option<i64> {
+        self.snapshot_id
+    }
+
+    /// Creates a scan of the pinned snapshot. A transaction on a table without snapshots
+    /// scans no rows.
+    pub fn scan(&self) -> TableScanBuilder<'_> {
+        let scan = self.table.scan();
+        match self.snapshot_id {
+            Some(snapshot_id) => scan.snapshot_id(snapshot_id),
+            None => scan,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+
+    use futures::TryStreamExt;
+
     use super::*;
+    use crate::expr::Reference;
+    use crate::memory::tests::new_memory_catalog;
+    use crate::spec::{Datum, NestedField, PrimitiveType, Schema, Type};
+    use crate::test_utils::fixtures::{InstrumentedStorage, data_file, new_table};
+    use crate::transaction::{ApplyTransactionAction, Transaction};
+    use crate::{Catalog, NamespaceIdent, TableCreation};
+
+    async fn planned_files(read_transaction: &ReadTransaction) -> Vec<String> {
+        read_transaction
+            .scan()
+            .build()
+            .unwrap()
+            .plan_files()
+            .await
+            .unwrap()
+            .map_ok(|task| task.data_file_path)
+            .try_collect()
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_read_transaction_pins_file_plan() {
+        let catalog = new_memory_catalog().await;
+        let namespace = NamespaceIdent::new("ns".to_string());
+        catalog
+            .create_namespace(&namespace, HashMap::new())
+            .await
+            .unwrap();
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+            ])
+            .build()
+            .unwrap();
+        let table = catalog
+            .create_table(
+                &namespace,
+                TableCreation::builder()
+                    .name("t".to_string())
+                    .schema(schema)
+                    .build(),
+            )
+            .await
+            .unwrap();
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .fast_append()
+            .add_data_files(vec![data_file(
+                format!("{}/data/1.parquet", table.metadata().location()),
+                1,
+            )])
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+
+        let read_transaction = ReadTransaction::new(&table).await.unwrap();
+        let file_plan = planned_files(&read_transaction).await;
+        assert_eq!(file_plan, vec![format!(
+            "{}/data/1.parquet",
+            table.metadata().location()
+        )]);
+
+        // Neither an external commit nor removing the metadata files of the pinned snapshot
+        // changes the file plan of the transaction.
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .fast_append()
+            .add_data_files(vec![data_file(
+                format!("{}/data/2.parquet", table.metadata().location()),
+                1,
+            )])
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+        assert_eq!(
+            table
+                .scan()
+                .build()
+                .unwrap()
+                .plan_files()
+                .await
+                .unwrap()
+                .try_collect::<Vec<_>>()
+                .await
+                .unwrap()
+                .len(),
+            2
+        );
+        let pinned_snapshot = table
+            .metadata()
+            .snapshot_by_id(read_transaction.snapshot_id().unwrap())
+            .unwrap();
+        let manifest_list = pinned_snapshot
+            .load_manifest_list(table.file_io(), table.metadata())
+            .await
+            .unwrap();
+        for manifest_file in manifest_list.entries() {
+            table
+                .file_io()
+                .delete(&manifest_file.manifest_path)
+                .await
+                .unwrap();
+        }
+        table
+            .file_io()
+            .delete(pinned_snapshot.manifest_list())
+            .await
+            .unwrap();
+
+        assert_eq!(planned_files(&read_transaction).await, file_plan);
+    }
+
+    #[tokio::test]
+    async fn test_read_transaction_serves_filtered_scans_from_pinned_manifests() {
+        let (catalog, table) = new_table(
+            Schema::builder()
+                .with_fields(vec![
+                    NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+                ])
+                .build()
+                .unwrap(),
+        )
+        .await;
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .fast_append()
+            .add_data_files(vec![data_file(
+                format!("{}/data/1.parquet", table.metadata().location()),
+                1,
+            )])
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+
+        let mut storage = None;
+        let file_io = table.file_io().clone().with_wrapped_storage(|inner| {
+            let instrumented = InstrumentedStorage::new(inner);
+            storage = Some(instrumented.clone());
+            Arc::new(instrumented)
+        });
+        let storage = storage.unwrap();
+        let table = Table::builder()
+            .metadata(table.metadata_ref())
+            .metadata_location(table.metadata_location().unwrap())
+            .identifier(table.identifier().clone())
+            .file_io(file_io)
+            .build()
+            .unwrap();
+        let read_transaction = ReadTransaction::new(&table).await.unwrap();
+        let attempts = storage.attempts();
+        let readers = storage.readers();
+
+        // Scans decoding only the column bounds of the filter reuse the pinned manifests.
+        let files: Vec<_> = read_transaction
+            .scan()
+            .with_filter(Reference::new("id").greater_than(Datum::long(0)))
+            .with_selective_metrics_decoding(true)
+            .build()
+            .unwrap()
+            .plan_files()
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(files.len(), 1);
+        assert_eq!(storage.attempts(), attempts);
+        assert_eq!(storage.readers(), readers);
+    }
+
+    #[tokio::test]
+    async fn test_metadata_log_grows_per_commit() {
+        let catalog = new_memory_catalog().await;
+        let namespace = NamespaceIdent::new("ns".to_string());
+        catalog
+            .create_namespace(&namespace, HashMap::new())
+            .await
+            .unwrap();
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+            ])
+            .build()
+            .unwrap();
+        let mut table = catalog
+            .create_table(
+                &namespace,
+                TableCreation::builder()
+                    .name("t".to_string())
+                    .schema(schema)
+                    .build(),
+            )
+            .await
+            .unwrap();
+        assert!(table.metadata_log().is_empty());
+
+        let mut metadata_locations = vec![table.metadata_location().unwrap().to_string()];
+        for commit in 1..=3 {
+            let tx = Transaction::new(&table);
+            let tx = tx
+                .update_table_properties()
+                .set("commit".to_string(), commit.to_string())
+                .apply(tx)
+                .unwrap();
+            table = tx.commit(&catalog).await.unwrap();
+
+            let metadata_log = table.metadata_log();
+            assert_eq!(metadata_log.len(), commit);
+            let logged_locations: Vec<_> = metadata_log
+                .iter()
+                .map(|log| log.metadata_file.clone())
+                .collect();
+            assert_eq!(logged_locations, metadata_locations);
+            assert!(
+                metadata_log
+                    .windows(2)
+                    .all(|window| window[0].timestamp_ms <= window[1].timestamp_ms)
+            );
+            metadata_locations.push(table.metadata_location().unwrap().to_string());
+        }
+
+        let first_version = table
+            .load_metadata_version(&table.metadata_log()[0])
+            .await
+            .unwrap();
+        assert!(
+            first_version
+                .metadata()
+                .properties()
+                .get("commit")
+                .is_none()
+        );
+        let last_version = table
+            .load_metadata_version(table.metadata_log().last().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(last_version.metadata().properties()["commit"], "2");
+    }
 
     #[tokio::test]
     async fn test_static_table_from_file() {
@@ -23,6 +23,9 @@ use arrow_array::RecordBatch;
 use expect_test::Expect;
 use itertools::Itertools;
 
+#[cfg(test)]
+pub(crate) mod fixtures;
+
 /// Snapshot testing to check the resulting record batch.
 ///
 /// - `expected_schema/data`: put `expect![[""]]` as a placeholder,
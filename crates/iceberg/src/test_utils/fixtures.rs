@@ -0,0 +1,334 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Fixtures shared by the unit tests of the crate.
+
+use std::collections::HashMap;
+use std::ops::Range;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use arrow_array::RecordBatch;
+use async_trait::async_trait;
+use bytes::Bytes;
+use parquet::arrow::ArrowWriter;
+use parquet::file::properties::WriterProperties;
+use serde::{Deserialize, Serialize};
+
+use crate::io::{FileIO, FileMetadata, FileRead, FileWrite, InputFile, OutputFile, Storage};
+use crate::memory::tests::new_memory_catalog;
+use crate::spec::{
+    DataContentType, DataFile, DataFileBuilder, DataFileFormat, Literal, Schema, Struct,
+};
+use crate::table::Table;
+use crate::{Catalog, Error, ErrorKind, NamespaceIdent, Result, TableCreation};
+
+/// Returns a builder of an unpartitioned Parquet data file at `path` with one record in 100
+/// bytes, for tests to override.
+pub(crate) fn data_file_builder(path: impl Into<String>) -> DataFileBuilder {
+    let mut builder = DataFileBuilder::default();
+    builder
+        .content(DataContentType::Data)
+        .file_path(path.into())
+        .file_format(DataFileFormat::Parquet)
+        .file_size_in_bytes(100)
+        .record_count(1)
+        .partition_spec_id(0)
+        .partition(Struct::empty());
+    builder
+}
+
+/// Returns an unpartitioned Parquet data file at `path` with `record_count` records in 100
+/// bytes.
+pub(crate) fn data_file(path: impl Into<String>, record_count: u64) -> DataFile {
+    data_file_builder(path)
+        .record_count(record_count)
+        .build()
+        .unwrap()
+}
+
+/// Returns a data file at `path` of the default partition spec of `table` with `record_count`
+/// records, whose single partition field is `partition`.
+pub(crate) fn partitioned_data_file(
+    table: &Table,
+    path: impl Into<String>,
+    partition: Literal,
+    record_count: u64,
+) -> DataFile {
+    data_file_builder(path)
+        .record_count(record_count)
+        .partition_spec_id(table.metadata().default_partition_spec_id())
+        .partition(Struct::from_iter([Some(partition)]))
+        .build()
+        .unwrap()
+}
+
+/// Creates the namespace `ns` if missing and the table `creation` in it.
+pub(crate) async fn create_table(
+    catalog: &(impl Catalog + ?Sized),
+    creation: TableCreation,
+) -> Table {
+    let namespace = NamespaceIdent::new("ns".to_string());
+    if !catalog.namespace_exists(&namespace).await.unwrap() {
+        catalog
+            .create_namespace(&namespace, HashMap::new())
+            .await
+            .unwrap();
+    }
+    catalog.create_table(&namespace, creation).await.unwrap()
+}
+
+/// Creates a memory catalog with the table `ns.t` of `schema`.
+pub(crate) async fn new_table(schema: Schema) -> (impl Catalog, Table) {
+    let catalog = new_memory_catalog().await;
+    let table = create_table(
+        &catalog,
+        TableCreation::builder()
+            .name("t".to_string())
+            .schema(schema)
+            .build(),
+    )
+    .await;
+    (catalog, table)
+}
+
+/// Writes `batch` as a Parquet file at `path` through `file_io`, returning the size of the
+/// file.
+pub(crate) async fn write_parquet_file(
+    file_io: &FileIO,
+    path: &str,
+    batch: &RecordBatch,
+    props: Option<WriterProperties>,
+) -> u64 {
+    let mut content = vec![];
+    let mut writer = ArrowWriter::try_new(&mut content, batch.schema(), props).unwrap();
+    writer.write(batch).unwrap();
+    writer.close().unwrap();
+    let file_size_in_bytes = content.len() as u64;
+    file_io
+        .new_output(path)
+        .unwrap()
+        .write(content.into())
+        .await
+        .unwrap();
+    file_size_in_bytes
+}
+
+/// Returns the sorted paths of the live files of the current snapshot of `table`.
+pub(crate) async fn live_file_paths(table: &Table) -> Vec<String> {
+    let manifest_list = table
+        .metadata()
+        .current_snapshot()
+        .unwrap()
+        .load_manifest_list(table.file_io(), table.metadata())
+        .await
+        .unwrap();
+    let mut paths = vec![];
+    for manifest_file in manifest_list.entries() {
+        let manifest = manifest_file
+            .load_manifest(table.file_io(), table.metadata())
+            .await
+            .unwrap();
+        paths.extend(
+            manifest
+                .entries()
+                .iter()
+                .filter(|entry| entry.is_alive())
+                .map(|entry| entry.file_path().to_string()),
+        );
+    }
+    paths.sort();
+    paths
+}
+
+/// A storage wrapping another one to record its reads, for tests to assert on the I/O of an
+/// operation, and to fail or slow down some of them.
+///
+/// Clones share their records, so a test keeps a clone of the storage it wraps a [`FileIO`]
+/// with, see [`FileIO::with_wrapped_storage`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct InstrumentedStorage {
+    inner: Arc<dyn Storage>,
+    /// The number of whole reads and metadata requests, failed or not.
+    #[serde(skip)]
+    attempts: Arc<AtomicUsize>,
+    /// The number of readers opened on each path.
+    #[serde(skip)]
+    readers: Arc<Mutex<HashMap<String, usize>>>,
+    /// The ranges read through the readers of each path.
+    #[serde(skip)]
+    ranges: Arc<Mutex<HashMap<String, Vec<Range<u64>>>>>,
+    #[serde(skip)]
+    in_flight: Arc<AtomicUsize>,
+    #[serde(skip)]
+    max_in_flight: Arc<AtomicUsize>,
+    /// How long opening a reader takes, so that concurrent opens overlap.
+    #[serde(skip)]
+    open_delay: Option<Duration>,
+    /// The number of first whole reads and metadata requests failing, and the kind of their
+    /// temporary error.
+    #[serde(skip)]
+    failures: Option<(usize, opendal::ErrorKind)>,
+}
+
+impl InstrumentedStorage {
+    /// Creates a storage recording the reads of `inner`.
+    pub(crate) fn new(inner: Arc<dyn Storage>) -> Self {
+        Self {
+            inner,
+            attempts: Default::default(),
+            readers: Default::default(),
+            ranges: Default::default(),
+            in_flight: Default::default(),
+            max_in_flight: Default::default(),
+            open_delay: None,
+            failures: None,
+        }
+    }
+
+    /// Makes opening a reader take `delay`.
+    pub(crate) fn with_open_delay(mut self, delay: Duration) -> Self {
+        self.open_delay = Some(delay);
+        self
+    }
+
+    /// Makes the first `failures` whole reads and metadata requests fail with a temporary
+    /// error of `kind`.
+    pub(crate) fn with_failures(mut self, failures: usize, kind: opendal::ErrorKind) -> Self {
+        self.failures = Some((failures, kind));
+        self
+    }
+
+    /// Returns the number of whole reads and metadata requests.
+    pub(crate) fn attempts(&self) -> usize {
+        self.attempts.load(Ordering::SeqCst)
+    }
+
+    /// Returns the number of readers opened on each path.
+    pub(crate) fn readers(&self) -> HashMap<String, usize> {
+        self.readers.lock().unwrap().clone()
+    }
+
+    /// Returns the most readers being opened at once.
+    pub(crate) fn max_in_flight(&self) -> usize {
+        self.max_in_flight.load(Ordering::SeqCst)
+    }
+
+    /// Returns the number of bytes read through the readers of `path`.
+    pub(crate) fn bytes_read(&self, path: &str) -> u64 {
+        self.ranges
+            .lock()
+            .unwrap()
+            .get(path)
+            .map(|ranges| ranges.iter().map(|range| range.end - range.start).sum())
+            .unwrap_or_default()
+    }
+
+    fn attempt(&self) -> Result<()> {
+        let attempt = self.attempts.fetch_add(1, Ordering::SeqCst);
+        match self.failures {
+            Some((failures, kind)) if attempt < failures => {
+                let source = opendal::Error::new(kind, "injected failure").set_temporary();
+                Err(Error::new(ErrorKind::Unexpected, "read failed").with_source(source))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[async_trait]
+#[typetag::serde]
+impl Storage for InstrumentedStorage {
+    async fn exists(&self, path: &str) -> Result<bool> {
+        self.inner.exists(path).await
+    }
+
+    async fn metadata(&self, path: &str) -> Result<FileMetadata> {
+        self.attempt()?;
+        self.inner.metadata(path).await
+    }
+
+    async fn read(&self, path: &str) -> Result<Bytes> {
+        self.attempt()?;
+        self.inner.read(path).await
+    }
+
+    async fn reader(&self, path: &str) -> Result<Box<dyn FileRead>> {
+        *self
+            .readers
+            .lock()
+            .unwrap()
+            .entry(path.to_string())
+            .or_default() += 1;
+        let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        self.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+        if let Some(delay) = self.open_delay {
+            tokio::time::sleep(delay).await;
+        }
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+        Ok(Box::new(InstrumentedFileRead {
+            inner: self.inner.reader(path).await?,
+            path: path.to_string(),
+            ranges: self.ranges.clone(),
+        }))
+    }
+
+    async fn write(&self, path: &str, bs: Bytes) -> Result<()> {
+        self.inner.write(path, bs).await
+    }
+
+    async fn writer(&self, path: &str) -> Result<Box<dyn FileWrite>> {
+        self.inner.writer(path).await
+    }
+
+    async fn delete(&self, path: &str) -> Result<()> {
+        self.inner.delete(path).await
+    }
+
+    async fn delete_prefix(&self, path: &str) -> Result<()> {
+        self.inner.delete_prefix(path).await
+    }
+
+    fn new_input(&self, path: &str) -> Result<InputFile> {
+        Ok(InputFile::new(Arc::new(self.clone()), path.to_string()))
+    }
+
+    fn new_output(&self, path: &str) -> Result<OutputFile> {
+        Ok(OutputFile::new(Arc::new(self.clone()), path.to_string()))
+    }
+}
+
+/// A reader of an [`InstrumentedStorage`], recording the ranges it reads.
+struct InstrumentedFileRead {
+    inner: Box<dyn FileRead>,
+    path: String,
+    ranges: Arc<Mutex<HashMap<String, Vec<Range<u64>>>>>,
+}
+
+#[async_trait]
+impl FileRead for InstrumentedFileRead {
+    async fn read(&self, range: Range<u64>) -> Result<Bytes> {
+        self.ranges
+            .lock()
+            .unwrap()
+            .entry(self.path.clone())
+            .or_default()
+            .push(range.clone());
+        self.inner.read(range).await
+    }
+}
@@ -22,12 +22,13 @@ use async_trait::async_trait;
 use uuid::Uuid;
 
 use crate::error::Result;
-use crate::spec::{DataFile, ManifestEntry, ManifestFile, Operation};
+use crate::spec::{DataFile, ManifestEntry, Operation, Struct};
 use crate::table::Table;
 use crate::transaction::snapshot::{
     DefaultManifestProcess, SnapshotProduceOperation, SnapshotProducer,
 };
 use crate::transaction::{ActionCommit, TransactionAction};
+use crate::writer::file_writer::ParquetWriter;
 
 /// FastAppendAction is a transaction action for fast append data files to the table.
 pub struct FastAppendAction {
@@ -37,6 +38,9 @@ pub struct FastAppendAction {
     key_metadata: Option<Vec<u8>>,
     snapshot_properties: HashMap<String, String>,
     added_data_files: Vec<DataFile>,
+    added_files: Vec<(String, Option<Struct>)>,
+    allow_empty: bool,
+    target_branch: Option<String>,
 }
 
 impl FastAppendAction {
@@ -47,6 +51,18 @@ impl FastAppendAction {
             key_metadata: None,
             snapshot_properties: HashMap::default(),
             added_data_files: vec![],
+            added_files: vec![],
+            allow_empty: false,
+            target_branch: None,
+        }
+    }
+
+    /// Creates an action appending an empty snapshot, which keeps the files of the current
+    /// snapshot.
+    pub(crate) fn empty() -> Self {
+        Self {
+            allow_empty: true,
+            ..Self::new()
         }
     }
 
@@ -62,6 +78,19 @@ impl FastAppendAction {
         self
     }
 
+    /// Add the existing Parquet file at `file_path`, written outside of this crate, to the
+    /// snapshot.
+    ///
+    /// The record count and column metrics of the data file are read from the footer of the
+    /// file when committing. The columns of the file are matched to the current schema by
+    /// their field ids. The partition of the file must match the default partition spec of
+    /// the table. When `partition` is `None`, it is derived from the column bounds of the
+    /// file, which requires every partition source column to hold a single value.
+    pub fn add_file(mut self, file_path: impl Into<String>, partition: Option<Struct>) -> Self {
+        self.added_files.push((file_path.into(), partition));
+        self
+    }
+
     /// Set commit UUID for the snapshot.
     pub fn set_commit_uuid(mut self, commit_uuid: Uuid) -> Self {
         self.commit_uuid = Some(commit_uuid);
@@ -79,18 +108,46 @@ impl FastAppendAction {
         self.snapshot_properties = snapshot_properties;
         self
     }
+
+    /// Set the branch the snapshot is committed to instead of `main`.
+    ///
+    /// The files are appended to the head of the branch, which is created off the current
+    /// snapshot of the table when it doesn't exist yet. Other branches are left untouched.
+    pub fn set_target_branch(mut self, target_branch: impl Into<String>) -> Self {
+        self.target_branch = Some(target_branch.into());
+        self
+    }
 }
 
 #[async_trait]
 impl TransactionAction for FastAppendAction {
     async fn commit(self: Arc<Self>, table: &Table) -> Result<ActionCommit> {
-        let snapshot_producer = SnapshotProducer::new(
+        let mut added_data_files = self.added_data_files.clone();
+        for (file_path, partition) in &self.added_files {
+            added_data_files.push(
+                ParquetWriter::parquet_file_to_data_file(
+                    table.file_io(),
+                    file_path.clone(),
+                    partition.clone(),
+                    table.metadata(),
+                )
+                .await?,
+            );
+        }
+
+        let mut snapshot_producer = SnapshotProducer::new(
             table,
             self.commit_uuid.unwrap_or_else(Uuid::now_v7),
             self.key_metadata.clone(),
             self.snapshot_properties.clone(),
-            self.added_data_files.clone(),
+            added_data_files,
         );
+        if self.allow_empty {
+            snapshot_producer = snapshot_producer.with_allow_empty();
+        }
+        if let Some(target_branch) = &self.target_branch {
+            snapshot_producer = snapshot_producer.with_target_branch(target_branch.clone());
+        }
 
         // validate added files
         snapshot_producer.validate_added_data_files()?;
@@ -119,29 +176,6 @@ impl SnapshotProduceOperation for FastAppendOperation {
     ) -> Result<Vec<ManifestEntry>> {
         Ok(vec![])
     }
-
-    async fn existing_manifest(
-        &self,
-        snapshot_produce: &SnapshotProducer<'_>,
-    ) -> Result<Vec<ManifestFile>> {
-        let Some(snapshot) = snapshot_produce.table.metadata().current_snapshot() else {
-            return Ok(vec![]);
-        };
-
-        let manifest_list = snapshot
-            .load_manifest_list(
-                snapshot_produce.table.file_io(),
-                &snapshot_produce.table.metadata_ref(),
-            )
-            .await?;
-
-        Ok(manifest_list
-            .entries()
-            .iter()
-            .filter(|entry| entry.has_added_files() || entry.has_existing_files())
-            .cloned()
-            .collect())
-    }
 }
 
 #[cfg(test)]
@@ -149,12 +183,22 @@ mod tests {
     use std::collections::HashMap;
     use std::sync::Arc;
 
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int64Type;
+    use arrow_array::{ArrayRef, Int64Array, RecordBatch, StringArray};
+    use futures::TryStreamExt;
+
+    use crate::arrow::schema_to_arrow_schema;
+    use crate::memory::tests::new_memory_catalog;
     use crate::spec::{
-        DataContentType, DataFileBuilder, DataFileFormat, Literal, MAIN_BRANCH, Struct,
+        DataContentType, DataFileBuilder, DataFileFormat, Literal, MAIN_BRANCH, NestedField,
+        PrimitiveType, Schema, Struct, Transform, Type, UnboundPartitionSpec,
     };
+    use crate::table::Table;
+    use crate::test_utils::fixtures::write_parquet_file;
     use crate::transaction::tests::make_v2_minimal_table;
-    use crate::transaction::{Transaction, TransactionAction};
-    use crate::{TableRequirement, TableUpdate};
+    use crate::transaction::{ApplyTransactionAction, Transaction, TransactionAction};
+    use crate::{Catalog, NamespaceIdent, TableCreation, TableRequirement, TableUpdate};
 
     #[tokio::test]
     async fn test_empty_data_append_action() {
@@ -316,7 +360,7 @@ mod tests {
 
         // check manifest
         let manifest = manifest_list.entries()[0]
-            .load_manifest(table.file_io())
+            .load_manifest(table.file_io(), table.metadata())
             .await
             .unwrap();
         assert_eq!(1, manifest.entries().len());
@@ -333,4 +377,231 @@ mod tests {
         );
         assert_eq!(data_file, *manifest.entries()[0].data_file());
     }
+
+    #[tokio::test]
+    async fn test_fast_append_to_branch() {
+        let catalog = new_memory_catalog().await;
+        let namespace = NamespaceIdent::new("ns".to_string());
+        catalog
+            .create_namespace(&namespace, HashMap::new())
+            .await
+            .unwrap();
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+            ])
+            .build()
+            .unwrap();
+        let mut table = catalog
+            .create_table(
+                &namespace,
+                TableCreation::builder()
+                    .name("t".to_string())
+                    .schema(schema)
+                    .build(),
+            )
+            .await
+            .unwrap();
+
+        let location = table.metadata().location().to_string();
+        let data_file = |name: &str| {
+            DataFileBuilder::default()
+                .content(DataContentType::Data)
+                .file_path(format!("{location}/data/{name}"))
+                .file_format(DataFileFormat::Parquet)
+                .file_size_in_bytes(100)
+                .record_count(1)
+                .partition_spec_id(0)
+                .partition(Struct::empty())
+                .build()
+                .unwrap()
+        };
+        let mut commits = vec![];
+        for (name, branch) in [
+            ("1.parquet", MAIN_BRANCH),
+            ("2.parquet", "dev"),
+            ("3.parquet", "dev"),
+            ("4.parquet", MAIN_BRANCH),
+        ] {
+            let file = data_file(name);
+            let tx = Transaction::new(&table);
+            let tx = tx
+                .fast_append()
+                .set_target_branch(branch)
+                .add_data_files([file])
+                .apply(tx)
+                .unwrap();
+            table = tx.commit(&catalog).await.unwrap();
+            commits.push(table.metadata().snapshot_by_ref(branch).unwrap().clone());
+        }
+
+        let files_of = |snapshot_id: i64| {
+            let table = table.clone();
+            async move {
+                let mut files: Vec<String> = table
+                    .scan()
+                    .snapshot_id(snapshot_id)
+                    .build()
+                    .unwrap()
+                    .plan_files()
+                    .await
+                    .unwrap()
+                    .map_ok(|task| task.data_file_path.rsplit('/').next().unwrap().to_string())
+                    .try_collect()
+                    .await
+                    .unwrap();
+                files.sort();
+                files
+            }
+        };
+
+        // The dev branch was created off main, then moved on its own.
+        assert_eq!(
+            commits[1].parent_snapshot_id(),
+            Some(commits[0].snapshot_id())
+        );
+        assert_eq!(
+            commits[2].parent_snapshot_id(),
+            Some(commits[1].snapshot_id())
+        );
+        let dev_head = table.metadata().snapshot_by_ref("dev").unwrap();
+        assert_eq!(dev_head.snapshot_id(), commits[2].snapshot_id());
+        assert_eq!(files_of(dev_head.snapshot_id()).await, vec![
+            "1.parquet",
+            "2.parquet",
+            "3.parquet"
+        ]);
+        assert_eq!(
+            dev_head
+                .summary()
+                .additional_properties
+                .get("total-data-files"),
+            Some(&"3".to_string())
+        );
+
+        // Main only has its own appends.
+        let main_head = table.metadata().current_snapshot().unwrap();
+        assert_eq!(main_head.snapshot_id(), commits[3].snapshot_id());
+        assert_eq!(
+            main_head.parent_snapshot_id(),
+            Some(commits[0].snapshot_id())
+        );
+        assert_eq!(files_of(main_head.snapshot_id()).await, vec![
+            "1.parquet",
+            "4.parquet"
+        ]);
+    }
+
+    async fn write_region_file(
+        table: &Table,
+        name: &str,
+        ids: Vec<i64>,
+        regions: Vec<&str>,
+    ) -> String {
+        let schema = Arc::new(schema_to_arrow_schema(table.metadata().current_schema()).unwrap());
+        let batch = RecordBatch::try_new(schema, vec![
+            Arc::new(Int64Array::from(ids)) as ArrayRef,
+            Arc::new(StringArray::from(regions)) as ArrayRef,
+        ])
+        .unwrap();
+        let path = format!("{}/data/{name}", table.metadata().location());
+        write_parquet_file(table.file_io(), &path, &batch, None).await;
+        path
+    }
+
+    #[tokio::test]
+    async fn test_add_existing_parquet_files() {
+        let catalog = new_memory_catalog().await;
+        let namespace = NamespaceIdent::new("ns".to_string());
+        catalog
+            .create_namespace(&namespace, HashMap::new())
+            .await
+            .unwrap();
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+                NestedField::required(2, "region", Type::Primitive(PrimitiveType::String)).into(),
+            ])
+            .build()
+            .unwrap();
+        let table = catalog
+            .create_table(
+                &namespace,
+                TableCreation::builder()
+                    .name("t".to_string())
+                    .schema(schema)
+                    .partition_spec(
+                        UnboundPartitionSpec::builder()
+                            .add_partition_field(2, "region", Transform::Identity)
+                            .unwrap()
+                            .build(),
+                    )
+                    .build(),
+            )
+            .await
+            .unwrap();
+
+        let us = write_region_file(&table, "us.parquet", vec![1, 2], vec!["us", "us"]).await;
+        let eu = write_region_file(&table, "eu.parquet", vec![3, 4], vec!["eu", "eu"]).await;
+        let mixed = write_region_file(&table, "mixed.parquet", vec![5, 6], vec!["us", "eu"]).await;
+
+        // The partition of a file with several values of a partition source column cannot be
+        // derived.
+        let tx = Transaction::new(&table);
+        let tx = tx.fast_append().add_file(&mixed, None).apply(tx).unwrap();
+        assert!(tx.commit(&catalog).await.is_err());
+
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .fast_append()
+            .add_file(&us, None)
+            .add_file(&eu, Some(Struct::from_iter([Some(Literal::string("eu"))])))
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+
+        let mut tasks: Vec<_> = table
+            .scan()
+            .build()
+            .unwrap()
+            .plan_files()
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        tasks.sort_by(|a, b| a.data_file_path.cmp(&b.data_file_path));
+        let partitions: Vec<_> = tasks
+            .iter()
+            .map(|task| (task.data_file_path.clone(), task.partition.clone().unwrap()))
+            .collect();
+        assert_eq!(partitions, vec![
+            (eu, Struct::from_iter([Some(Literal::string("eu"))])),
+            (us, Struct::from_iter([Some(Literal::string("us"))])),
+        ]);
+        assert!(tasks.iter().all(|task| task.record_count == Some(2)));
+
+        let batches: Vec<_> = table
+            .scan()
+            .build()
+            .unwrap()
+            .to_arrow()
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        let mut ids: Vec<i64> = batches
+            .iter()
+            .flat_map(|batch| {
+                batch
+                    .column(0)
+                    .as_primitive::<Int64Type>()
+                    .values()
+                    .to_vec()
+            })
+            .collect();
+        ids.sort();
+        assert_eq!(ids, vec![1, 2, 3, 4]);
+    }
 }
@@ -0,0 +1,192 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::Result;
+use crate::table::Table;
+use crate::transaction::{ActionCommit, TransactionAction};
+
+/// A transaction action rewriting the current metadata of a table in its canonical form.
+///
+/// The action commits a metadata-only update without any change, so that the catalog writes
+/// the current metadata to a new metadata file. Metadata files written through
+/// [`TableMetadata::to_json_bytes`](crate::spec::TableMetadata::to_json_bytes) are canonical:
+/// their fields and optional fields are the same for semantically-equal metadata, whatever
+/// the writer of the previous file, and their map entries are ordered by key. Like any other
+/// update, the rewrite records the previous file in the metadata log and sets the last
+/// updated timestamp of the table.
+///
+/// Catalogs writing metadata files themselves, such as the REST catalog, decide on the form
+/// of the files they write.
+pub struct CanonicalizeMetadataAction;
+
+impl CanonicalizeMetadataAction {
+    /// Creates a new [`CanonicalizeMetadataAction`].
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for CanonicalizeMetadataAction {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl TransactionAction for CanonicalizeMetadataAction {
+    async fn commit(self: Arc<Self>, _table: &Table) -> Result<ActionCommit> {
+        Ok(ActionCommit::new(vec![], vec![]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde_json::Value;
+
+    use crate::memory::{MEMORY_CATALOG_WAREHOUSE, MemoryCatalogBuilder};
+    use crate::spec::{
+        FormatVersion, NestedField, PartitionSpec, PrimitiveType, Schema, SortOrder,
+        TableMetadataBuilder, Type,
+    };
+    use crate::transaction::{ApplyTransactionAction, Transaction};
+    use crate::{Catalog, CatalogBuilder, NamespaceIdent, TableCreation, TableIdent};
+
+    const METADATA_LOCATION: &str =
+        "memory://warehouse/ns/t/metadata/00000-2cc52516-5e73-41f2-b139-545d41a4e151.metadata.json";
+
+    /// Registers `content` as the metadata file of a table in a new catalog, rewrites the
+    /// metadata in its canonical form and returns the content of the rewritten metadata
+    /// file, with its last updated timestamp replaced by `0`.
+    async fn canonicalize(content: &[u8]) -> String {
+        let catalog = MemoryCatalogBuilder::default()
+            .load(
+                "memory",
+                HashMap::from([(
+                    MEMORY_CATALOG_WAREHOUSE.to_string(),
+                    "memory://warehouse".to_string(),
+                )]),
+            )
+            .await
+            .unwrap();
+        let namespace = NamespaceIdent::new("ns".to_string());
+        catalog
+            .create_namespace(&namespace, HashMap::new())
+            .await
+            .unwrap();
+        let schema = Schema::builder().build().unwrap();
+        let file_io = catalog
+            .create_table(
+                &namespace,
+                TableCreation::builder()
+                    .name("other".to_string())
+                    .schema(schema)
+                    .build(),
+            )
+            .await
+            .unwrap()
+            .file_io()
+            .clone();
+        file_io
+            .new_output(METADATA_LOCATION)
+            .unwrap()
+            .write(content.to_vec().into())
+            .await
+            .unwrap();
+        let table = catalog
+            .register_table(
+                &TableIdent::new(namespace, "t".to_string()),
+                METADATA_LOCATION.to_string(),
+            )
+            .await
+            .unwrap();
+
+        let tx = Transaction::new(&table);
+        let tx = tx.canonicalize_metadata().apply(tx).unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+
+        let metadata_location = table.metadata_location().unwrap();
+        assert_ne!(metadata_location, METADATA_LOCATION);
+        assert_eq!(
+            table
+                .metadata()
+                .metadata_log()
+                .last()
+                .unwrap()
+                .metadata_file,
+            METADATA_LOCATION
+        );
+        let bytes = file_io
+            .new_input(metadata_location)
+            .unwrap()
+            .read()
+            .await
+            .unwrap();
+        String::from_utf8(bytes.to_vec()).unwrap().replace(
+            &format!("\"last-updated-ms\":{}", table.metadata().last_updated_ms()),
+            "\"last-updated-ms\":0",
+        )
+    }
+
+    #[tokio::test]
+    async fn test_canonicalize_metadata_of_different_writers() {
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+                NestedField::optional(2, "name", Type::Primitive(PrimitiveType::String)).into(),
+            ])
+            .build()
+            .unwrap();
+        let metadata = TableMetadataBuilder::new(
+            schema,
+            PartitionSpec::unpartition_spec(),
+            SortOrder::unsorted_order(),
+            "memory://warehouse/ns/t".to_string(),
+            FormatVersion::V2,
+            HashMap::from([
+                ("b".to_string(), "2".to_string()),
+                ("a".to_string(), "1".to_string()),
+                ("c".to_string(), "3".to_string()),
+            ]),
+        )
+        .unwrap()
+        .build()
+        .unwrap()
+        .metadata;
+
+        // The same metadata written by two writers, one ordering the fields alphabetically
+        // with indentation, the other omitting the optional fields holding empty lists.
+        let json: Value = serde_json::from_slice(&metadata.to_json_bytes().unwrap()).unwrap();
+        let pretty = serde_json::to_vec_pretty(&json).unwrap();
+        let mut compact = json.clone();
+        if let Value::Object(fields) = &mut compact {
+            fields.retain(|_, value| !matches!(value, Value::Array(values) if values.is_empty()));
+        }
+        let compact = serde_json::to_vec(&compact).unwrap();
+        assert_ne!(pretty, compact);
+
+        let canonical = canonicalize(&pretty).await;
+        assert_eq!(canonical, canonicalize(&compact).await);
+        assert_ne!(canonical.as_bytes(), pretty);
+        assert_ne!(canonical.as_bytes(), compact);
+    }
+}
@@ -0,0 +1,484 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::error::Result;
+use crate::spec::{
+    DataContentType, DataFile, DataFileFormat, ManifestContentType, ManifestEntry, Operation,
+};
+use crate::table::Table;
+use crate::transaction::snapshot::{
+    DefaultManifestProcess, SnapshotProduceOperation, SnapshotProducer,
+};
+use crate::transaction::{ActionCommit, TransactionAction};
+use crate::writer::file_writer::ParquetWriter;
+use crate::{Error, ErrorKind};
+
+/// ComputeStatsAction is a transaction action that recomputes the column metrics of data files
+/// from their Parquet footers, without rewriting the files.
+///
+/// The manifest entries of the files are rewritten with the column sizes, value counts, null
+/// value counts and bounds read from the footers, keeping their NaN value counts, which the
+/// footers don't record. This is useful for files imported without metrics or with metrics
+/// that are known to be inaccurate. The new snapshot has the `replace` operation and leaves the
+/// rows of the table unchanged.
+///
+/// The columns of files written without field ids are matched to the fields of the table
+/// through the name mapping of the table, see
+/// [`DEFAULT_SCHEMA_NAME_MAPPING`](crate::spec::DEFAULT_SCHEMA_NAME_MAPPING), or by name.
+pub struct ComputeStatsAction {
+    // below are properties used to create SnapshotProducer when commit
+    commit_uuid: Option<Uuid>,
+    key_metadata: Option<Vec<u8>>,
+    snapshot_properties: HashMap<String, String>,
+    data_file_paths: HashSet<String>,
+}
+
+impl ComputeStatsAction {
+    pub(crate) fn new() -> Self {
+        Self {
+            commit_uuid: None,
+            key_metadata: None,
+            snapshot_properties: HashMap::default(),
+            data_file_paths: HashSet::new(),
+        }
+    }
+
+    /// Only recompute the metrics of the data files at these paths, all of which must be live
+    /// Parquet data files of the table.
+    ///
+    /// By default, the metrics of all the live Parquet data files of the table are recomputed.
+    pub fn refresh_data_files(mut self, file_paths: impl IntoIterator<Item = String>) -> Self {
+        self.data_file_paths.extend(file_paths);
+        self
+    }
+
+    /// Set commit UUID for the snapshot.
+    pub fn set_commit_uuid(mut self, commit_uuid: Uuid) -> Self {
+        self.commit_uuid = Some(commit_uuid);
+        self
+    }
+
+    /// Set key metadata for manifest files.
+    pub fn set_key_metadata(mut self, key_metadata: Vec<u8>) -> Self {
+        self.key_metadata = Some(key_metadata);
+        self
+    }
+
+    /// Set snapshot summary properties.
+    pub fn set_snapshot_properties(mut self, snapshot_properties: HashMap<String, String>) -> Self {
+        self.snapshot_properties = snapshot_properties;
+        self
+    }
+
+    /// Returns the live data files of the current snapshot whose metrics are recomputed.
+    async fn data_files_to_refresh(&self, table: &Table) -> Result<Vec<DataFile>> {
+        let Some(snapshot) = table.metadata().current_snapshot() else {
+            return Err(Error::new(
+                ErrorKind::PreconditionFailed,
+                "Cannot compute stats of a table without a current snapshot",
+            ));
+        };
+        let manifest_list = snapshot
+            .load_manifest_list(table.file_io(), &table.metadata_ref())
+            .await?;
+
+        // Files are matched by their resolved paths.
+        let requested_files: HashSet<String> = self
+            .data_file_paths
+            .iter()
+            .map(|file_path| table.metadata().resolve_location(file_path))
+            .collect();
+        let mut data_files = vec![];
+        for manifest_file in manifest_list.entries() {
+            if manifest_file.content != ManifestContentType::Data {
+                continue;
+            }
+            let manifest = manifest_file
+                .load_manifest(table.file_io(), table.metadata())
+                .await?;
+            for entry in manifest.entries() {
+                if !entry.is_alive() || entry.content_type() != DataContentType::Data {
+                    continue;
+                }
+                let requested =
+                    requested_files.contains(&table.metadata().resolve_location(entry.file_path()));
+                if !requested_files.is_empty() && !requested {
+                    continue;
+                }
+                if entry.file_format() != DataFileFormat::Parquet {
+                    if requested {
+                        return Err(Error::new(
+                            ErrorKind::FeatureUnsupported,
+                            format!(
+                                "Cannot compute stats of data file {} in format {}",
+                                entry.file_path(),
+                                entry.file_format()
+                            ),
+                        ));
+                    }
+                    continue;
+                }
+                data_files.push(entry.data_file().clone());
+            }
+        }
+
+        let found_files: HashSet<String> = data_files
+            .iter()
+            .map(|data_file| table.metadata().resolve_location(data_file.file_path()))
+            .collect();
+        let mut missing_files: Vec<&str> = requested_files
+            .iter()
+            .filter(|file_path| !found_files.contains(*file_path))
+            .map(String::as_str)
+            .collect();
+        missing_files.sort();
+        if !missing_files.is_empty() {
+            return Err(Error::new(
+                ErrorKind::DataInvalid,
+                format!(
+                    "Cannot compute stats of files that are not referenced by table, files: {}",
+                    missing_files.join(", ")
+                ),
+            ));
+        }
+        Ok(data_files)
+    }
+}
+
+#[async_trait]
+impl TransactionAction for ComputeStatsAction {
+    async fn commit(self: Arc<Self>, table: &Table) -> Result<ActionCommit> {
+        let data_files = self.data_files_to_refresh(table).await?;
+        if data_files.is_empty() {
+            return Err(Error::new(
+                ErrorKind::PreconditionFailed,
+                "No Parquet data files found when committing a compute stats action",
+            ));
+        }
+
+        let mut refreshed_data_files = Vec::with_capacity(data_files.len());
+        for mut data_file in data_files {
+            let footer_data_file = ParquetWriter::parquet_file_to_data_file(
+                table.file_io(),
+                table.metadata().resolve_location(&data_file.file_path),
+                Some(data_file.partition.clone()),
+                table.metadata(),
+            )
+            .await?;
+            data_file.column_sizes = footer_data_file.column_sizes;
+            data_file.value_counts = footer_data_file.value_counts;
+            data_file.null_value_counts = footer_data_file.null_value_counts;
+            data_file.lower_bounds = footer_data_file.lower_bounds;
+            data_file.upper_bounds = footer_data_file.upper_bounds;
+            refreshed_data_files.push(data_file);
+        }
+
+        SnapshotProducer::new(
+            table,
+            self.commit_uuid.unwrap_or_else(Uuid::now_v7),
+            self.key_metadata.clone(),
+            self.snapshot_properties.clone(),
+            vec![],
+        )
+        .with_refreshed_data_files(refreshed_data_files)
+        .commit(ComputeStatsOperation, DefaultManifestProcess)
+        .await
+    }
+}
+
+struct ComputeStatsOperation;
+
+impl SnapshotProduceOperation for ComputeStatsOperation {
+    fn operation(&self) -> Operation {
+        Operation::Replace
+    }
+
+    async fn delete_entries(
+        &self,
+        _snapshot_produce: &SnapshotProducer<'_>,
+    ) -> Result<Vec<ManifestEntry>> {
+        Ok(vec![])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use arrow_array::{ArrayRef, Int64Array, RecordBatch, StringArray};
+    use arrow_schema::{DataType, Field, Schema as ArrowSchema};
+    use parquet::arrow::ArrowWriter;
+
+    use crate::arrow::schema_to_arrow_schema;
+    use crate::memory::tests::new_memory_catalog;
+    use crate::spec::{
+        DEFAULT_SCHEMA_NAME_MAPPING, DataContentType, DataFileBuilder, DataFileFormat, Datum,
+        MappedField, NameMapping, NestedField, Operation, PrimitiveType, Schema, Struct, Type,
+    };
+    use crate::transaction::{ApplyTransactionAction, Transaction};
+    use crate::{Catalog, ErrorKind, NamespaceIdent, TableCreation};
+
+    #[tokio::test]
+    async fn test_compute_stats_only_refreshes_metrics_of_imported_file() {
+        let catalog = new_memory_catalog().await;
+        let namespace = NamespaceIdent::new("ns".to_string());
+        catalog
+            .create_namespace(&namespace, HashMap::new())
+            .await
+            .unwrap();
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+                NestedField::optional(2, "region", Type::Primitive(PrimitiveType::String)).into(),
+            ])
+            .build()
+            .unwrap();
+        let table = catalog
+            .create_table(
+                &namespace,
+                TableCreation::builder()
+                    .name("t".to_string())
+                    .schema(schema)
+                    .build(),
+            )
+            .await
+            .unwrap();
+
+        // A table without snapshot has no stats to compute.
+        let tx = Transaction::new(&table);
+        let tx = tx.compute_stats_only().apply(tx).unwrap();
+        let Err(err) = tx.commit(&catalog).await else {
+            panic!("Computing stats of a table without snapshot should fail");
+        };
+        assert_eq!(err.kind(), ErrorKind::PreconditionFailed);
+
+        let arrow_schema =
+            Arc::new(schema_to_arrow_schema(table.metadata().current_schema()).unwrap());
+        let batch = RecordBatch::try_new(arrow_schema.clone(), vec![
+            Arc::new(Int64Array::from(vec![3, 1, 7])) as ArrayRef,
+            Arc::new(StringArray::from(vec![Some("us"), None, Some("eu")])) as ArrayRef,
+        ])
+        .unwrap();
+        let path = format!("{}/data/imported.parquet", table.metadata().location());
+        let mut content = vec![];
+        let mut writer = ArrowWriter::try_new(&mut content, arrow_schema, None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let file_size_in_bytes = content.len() as u64;
+        table
+            .file_io()
+            .new_output(&path)
+            .unwrap()
+            .write(content.into())
+            .await
+            .unwrap();
+
+        // Import the file without any metrics.
+        let data_file = DataFileBuilder::default()
+            .content(DataContentType::Data)
+            .file_path(path.clone())
+            .file_format(DataFileFormat::Parquet)
+            .file_size_in_bytes(file_size_in_bytes)
+            .record_count(3)
+            .partition_spec_id(0)
+            .partition(Struct::empty())
+            .build()
+            .unwrap();
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .fast_append()
+            .add_data_files(vec![data_file])
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+        let appended_snapshot = table.metadata().current_snapshot().unwrap().clone();
+
+        // Unknown files are rejected.
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .compute_stats_only()
+            .refresh_data_files(vec![format!("{path}.missing")])
+            .apply(tx)
+            .unwrap();
+        let Err(err) = tx.commit(&catalog).await else {
+            panic!("Computing stats of an unknown file should fail");
+        };
+        assert_eq!(err.kind(), ErrorKind::DataInvalid);
+
+        let tx = Transaction::new(&table);
+        let tx = tx.compute_stats_only().apply(tx).unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+
+        let snapshot = table.metadata().current_snapshot().unwrap();
+        assert_eq!(
+            snapshot.parent_snapshot_id(),
+            Some(appended_snapshot.snapshot_id())
+        );
+        assert_eq!(snapshot.summary().operation, Operation::Replace);
+        let summary = &snapshot.summary().additional_properties;
+        assert_eq!(summary.get("total-data-files").unwrap(), "1");
+        assert_eq!(summary.get("total-records").unwrap(), "3");
+
+        let manifest_list = snapshot
+            .load_manifest_list(table.file_io(), table.metadata())
+            .await
+            .unwrap();
+        assert_eq!(manifest_list.entries().len(), 1);
+        let manifest = manifest_list.entries()[0]
+            .load_manifest(table.file_io(), table.metadata())
+            .await
+            .unwrap();
+        assert_eq!(manifest.entries().len(), 1);
+        let entry = &manifest.entries()[0];
+        assert!(entry.is_alive());
+        // The file keeps the sequence number of its append.
+        assert_eq!(
+            entry.sequence_number(),
+            Some(appended_snapshot.sequence_number())
+        );
+        let data_file = entry.data_file();
+        assert_eq!(data_file.file_path(), path);
+        assert_eq!(
+            data_file.lower_bounds(),
+            &HashMap::from([(1, Datum::long(1)), (2, Datum::string("eu"))])
+        );
+        assert_eq!(
+            data_file.upper_bounds(),
+            &HashMap::from([(1, Datum::long(7)), (2, Datum::string("us"))])
+        );
+        assert_eq!(
+            data_file.null_value_counts(),
+            &HashMap::from([(1, 0), (2, 1)])
+        );
+        assert_eq!(data_file.value_counts(), &HashMap::from([(1, 3), (2, 3)]));
+    }
+
+    #[tokio::test]
+    async fn test_compute_stats_maps_columns_without_field_ids_by_name_mapping() {
+        let catalog = new_memory_catalog().await;
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+                NestedField::optional(2, "region", Type::Primitive(PrimitiveType::String)).into(),
+            ])
+            .build()
+            .unwrap();
+        let name_mapping = NameMapping::new(vec![
+            MappedField::new(
+                Some(1),
+                vec!["id".to_string(), "user_id".to_string()],
+                vec![],
+            ),
+            MappedField::new(Some(2), vec!["region".to_string()], vec![]),
+        ]);
+        let namespace = NamespaceIdent::new("ns".to_string());
+        catalog
+            .create_namespace(&namespace, HashMap::new())
+            .await
+            .unwrap();
+        let table = catalog
+            .create_table(
+                &namespace,
+                TableCreation::builder()
+                    .name("t".to_string())
+                    .schema(schema)
+                    .properties(HashMap::from([(
+                        DEFAULT_SCHEMA_NAME_MAPPING.to_string(),
+                        serde_json::to_string(&name_mapping).unwrap(),
+                    )]))
+                    .build(),
+            )
+            .await
+            .unwrap();
+
+        // A file written without field ids, under a former name of the `id` column.
+        let arrow_schema = Arc::new(ArrowSchema::new(vec![
+            Field::new("user_id", DataType::Int64, false),
+            Field::new("region", DataType::Utf8, true),
+        ]));
+        let batch = RecordBatch::try_new(arrow_schema.clone(), vec![
+            Arc::new(Int64Array::from(vec![3, 1, 7])) as ArrayRef,
+            Arc::new(StringArray::from(vec![Some("us"), None, Some("eu")])) as ArrayRef,
+        ])
+        .unwrap();
+        let path = format!("{}/data/migrated.parquet", table.metadata().location());
+        let mut content = vec![];
+        let mut writer = ArrowWriter::try_new(&mut content, arrow_schema, None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let file_size_in_bytes = content.len() as u64;
+        table
+            .file_io()
+            .new_output(&path)
+            .unwrap()
+            .write(content.into())
+            .await
+            .unwrap();
+        let data_file = DataFileBuilder::default()
+            .content(DataContentType::Data)
+            .file_path(path)
+            .file_format(DataFileFormat::Parquet)
+            .file_size_in_bytes(file_size_in_bytes)
+            .record_count(3)
+            .partition_spec_id(0)
+            .partition(Struct::empty())
+            .build()
+            .unwrap();
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .fast_append()
+            .add_data_files(vec![data_file])
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+
+        let tx = Transaction::new(&table);
+        let tx = tx.compute_stats_only().apply(tx).unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+
+        let manifest_list = table
+            .metadata()
+            .current_snapshot()
+            .unwrap()
+            .load_manifest_list(table.file_io(), table.metadata())
+            .await
+            .unwrap();
+        assert_eq!(manifest_list.entries().len(), 1);
+        let manifest = manifest_list.entries()[0]
+            .load_manifest(table.file_io(), table.metadata())
+            .await
+            .unwrap();
+        assert_eq!(manifest.entries().len(), 1);
+        let data_file = manifest.entries()[0].data_file();
+        assert_eq!(
+            data_file.lower_bounds(),
+            &HashMap::from([(1, Datum::long(1)), (2, Datum::string("eu"))])
+        );
+        assert_eq!(
+            data_file.upper_bounds(),
+            &HashMap::from([(1, Datum::long(7)), (2, Datum::string("us"))])
+        );
+        assert_eq!(data_file.value_counts(), &HashMap::from([(1, 3), (2, 3)]));
+    }
+}
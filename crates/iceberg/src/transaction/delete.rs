@@ -0,0 +1,1053 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, Int64Array, RecordBatch, StringArray};
+use async_trait::async_trait;
+use parquet::file::properties::WriterProperties;
+use uuid::Uuid;
+
+use crate::arrow::schema_to_arrow_schema;
+use crate::compression::CompressionCodec;
+use crate::delete_vector::DeleteVector;
+use crate::error::Result;
+use crate::expr::Predicate;
+use crate::metadata_columns::{
+    RESERVED_FIELD_ID_POS, delete_file_path_field, delete_file_pos_field,
+};
+use crate::puffin::{Blob, DELETION_VECTOR_V1, PuffinWriter};
+use crate::spec::{
+    DataContentType, DataFile, DataFileBuilder, DataFileFormat, FormatVersion, ManifestContentType,
+    ManifestEntry, Operation, Schema,
+};
+use crate::table::Table;
+use crate::transaction::row_filter::data_files_matching_filter;
+use crate::transaction::snapshot::{
+    DefaultManifestProcess, SnapshotProduceOperation, SnapshotProducer,
+};
+use crate::transaction::{ActionCommit, TransactionAction};
+use crate::writer::file_writer::location_generator::{DefaultLocationGenerator, LocationGenerator};
+use crate::writer::file_writer::{FileWriter, FileWriterBuilder, ParquetWriterBuilder};
+use crate::{Error, ErrorKind};
+
+/// The format of the delete files written by a [`DeleteAction`] for rows deleted by position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeleteFormat {
+    /// Position delete files in Parquet, one per data file, as introduced in format version 2.
+    PositionV2,
+    /// Deletion vectors stored in a Puffin file, as introduced in format version 3.
+    DeletionVectorV3,
+}
+
+/// DeleteAction is a transaction action for deleting rows, identified by their positions,
+/// from the data files of a table.
+///
+/// When every row of a data file is deleted, the data file is removed from the new snapshot
+/// instead of writing position deletes for each of its rows. The deletes of other data files
+/// are written in the [`DeleteFormat`] set with [`DeleteAction::with_delete_format`].
+///
+/// Rows can also be deleted by a row filter. When the filter aligns with partition
+/// boundaries or the column metrics of the data files, the matching data files are removed
+/// wholesale, without writing any delete or data file.
+///
+/// The rows to delete are usually planned from a snapshot that a concurrent commit, such as
+/// a compaction, may replace before the delete commits. Committing fails with
+/// [`ErrorKind::CatalogCommitConflicts`] when a data file that rows are deleted from is no
+/// longer in the table and the table changed since the snapshot the delete was planned from,
+/// see [`DeleteAction::validate_from_snapshot`].
+pub struct DeleteAction {
+    base_snapshot_id: Option<i64>,
+    drop_fully_deleted_files: bool,
+    delete_format: Option<DeleteFormat>,
+    delete_filter: Option<Predicate>,
+    case_sensitive: bool,
+    // below are properties used to create SnapshotProducer when commit
+    commit_uuid: Option<Uuid>,
+    key_metadata: Option<Vec<u8>>,
+    snapshot_properties: HashMap<String, String>,
+    deleted_positions: Vec<(DataFile, BTreeSet<u64>)>,
+}
+
+impl DeleteAction {
+    pub(crate) fn new(base_snapshot_id: Option<i64>) -> Self {
+        Self {
+            base_snapshot_id,
+            drop_fully_deleted_files: true,
+            delete_format: None,
+            delete_filter: None,
+            case_sensitive: true,
+            commit_uuid: None,
+            key_metadata: None,
+            snapshot_properties: HashMap::default(),
+            deleted_positions: vec![],
+        }
+    }
+
+    /// Set the snapshot that the deleted rows were planned from. Defaults to the current
+    /// snapshot of the table of the transaction.
+    pub fn validate_from_snapshot(mut self, snapshot_id: Option<i64>) -> Self {
+        self.base_snapshot_id = snapshot_id;
+        self
+    }
+
+    /// Set whether data files with all of their rows deleted are removed from the snapshot
+    /// instead of being covered by delete files. Defaults to `true`.
+    pub fn with_drop_fully_deleted_files(mut self, v: bool) -> Self {
+        self.drop_fully_deleted_files = v;
+        self
+    }
+
+    /// Set the format of the delete files written for rows deleted by position.
+    ///
+    /// Defaults to [`DeleteFormat::DeletionVectorV3`] for tables of format version 3, and to
+    /// [`DeleteFormat::PositionV2`] otherwise. Deletion vectors require format version 3.
+    pub fn with_delete_format(mut self, delete_format: DeleteFormat) -> Self {
+        self.delete_format = Some(delete_format);
+        self
+    }
+
+    /// Delete the rows matching `filter`.
+    ///
+    /// Data files whose rows all match the filter are removed from the snapshot, along with
+    /// the positions deleted from them. Committing fails if a data file has only some rows
+    /// matching the filter.
+    pub fn delete_filter(mut self, filter: Predicate) -> Self {
+        self.delete_filter = Some(filter);
+        self
+    }
+
+    /// Set whether column names of the delete filter are matched case sensitively.
+    /// Defaults to `true`.
+    pub fn with_case_sensitive(mut self, case_sensitive: bool) -> Self {
+        self.case_sensitive = case_sensitive;
+        self
+    }
+
+    /// Delete the rows at the given positions of a data file.
+    pub fn delete_positions(
+        mut self,
+        data_file: DataFile,
+        positions: impl IntoIterator<Item = u64>,
+    ) -> Self {
+        let positions = positions.into_iter();
+        match self
+            .deleted_positions
+            .iter_mut()
+            .find(|(file, _)| file.file_path == data_file.file_path)
+        {
+            Some((_, existing)) => existing.extend(positions),
+            None => self
+                .deleted_positions
+                .push((data_file, positions.collect())),
+        }
+        self
+    }
+
+    /// Set commit UUID for the snapshot.
+    pub fn set_commit_uuid(mut self, commit_uuid: Uuid) -> Self {
+        self.commit_uuid = Some(commit_uuid);
+        self
+    }
+
+    /// Set key metadata for manifest files.
+    pub fn set_key_metadata(mut self, key_metadata: Vec<u8>) -> Self {
+        self.key_metadata = Some(key_metadata);
+        self
+    }
+
+    /// Set snapshot summary properties.
+    pub fn set_snapshot_properties(mut self, snapshot_properties: HashMap<String, String>) -> Self {
+        self.snapshot_properties = snapshot_properties;
+        self
+    }
+
+    fn is_fully_deleted(data_file: &DataFile, positions: &BTreeSet<u64>) -> bool {
+        positions.len() as u64 == data_file.record_count
+            && positions
+                .last()
+                .is_none_or(|last| *last < data_file.record_count)
+    }
+
+    fn resolve_delete_format(&self, table: &Table) -> Result<DeleteFormat> {
+        match (table.metadata().format_version(), self.delete_format) {
+            (FormatVersion::V1, _) => Err(Error::new(
+                ErrorKind::FeatureUnsupported,
+                "Deleting rows by position requires format version 2 or later",
+            )),
+            (FormatVersion::V2, Some(DeleteFormat::DeletionVectorV3)) => Err(Error::new(
+                ErrorKind::DataInvalid,
+                "Deletion vectors require format version 3",
+            )),
+            (_, Some(delete_format)) => Ok(delete_format),
+            (FormatVersion::V2, None) => Ok(DeleteFormat::PositionV2),
+            (FormatVersion::V3, None) => Ok(DeleteFormat::DeletionVectorV3),
+        }
+    }
+}
+
+/// Writes a Parquet position delete file for each of the data files.
+async fn write_position_delete_files(
+    table: &Table,
+    commit_uuid: Uuid,
+    deleted_positions: &[(&DataFile, &BTreeSet<u64>)],
+) -> Result<Vec<DataFile>> {
+    let schema = Arc::new(
+        Schema::builder()
+            .with_fields(vec![
+                delete_file_path_field().clone(),
+                delete_file_pos_field().clone(),
+            ])
+            .build()?,
+    );
+    let arrow_schema = Arc::new(schema_to_arrow_schema(&schema)?);
+    let location_generator = DefaultLocationGenerator::new(table.metadata().clone())?;
+
+    let mut delete_files = Vec::with_capacity(deleted_positions.len());
+    for (index, (data_file, positions)) in deleted_positions.iter().enumerate() {
+        let batch = RecordBatch::try_new(arrow_schema.clone(), vec![
+            Arc::new(StringArray::from(vec![
+                data_file.file_path.as_str();
+                positions.len()
+            ])) as ArrayRef,
+            Arc::new(Int64Array::from_iter_values(
+                positions.iter().map(|pos| *pos as i64),
+            )) as ArrayRef,
+        ])?;
+
+        let location = location_generator.generate_location(
+            None,
+            &format!(
+                "{commit_uuid}-{index:05}-deletes.{}",
+                DataFileFormat::Parquet
+            ),
+        );
+        let mut writer =
+            ParquetWriterBuilder::new(WriterProperties::builder().build(), schema.clone())
+                .build(table.file_io().new_output(location)?)
+                .await?;
+        writer.write(&batch).await?;
+        for mut builder in writer.close().await? {
+            delete_files.push(build_delete_file(
+                builder
+                    .content(DataContentType::PositionDeletes)
+                    .partition(data_file.partition.clone())
+                    .partition_spec_id(data_file.partition_spec_id)
+                    .referenced_data_file(Some(data_file.file_path.clone())),
+            )?);
+        }
+    }
+    Ok(delete_files)
+}
+
+/// Writes a Puffin file with a deletion vector for each of the data files.
+async fn write_deletion_vectors(
+    table: &Table,
+    commit_uuid: Uuid,
+    deleted_positions: &[(&DataFile, &BTreeSet<u64>)],
+) -> Result<Vec<DataFile>> {
+    ensure_no_deletion_vectors(table, deleted_positions).await?;
+
+    let location = DefaultLocationGenerator::new(table.metadata().clone())?.generate_location(
+        None,
+        &format!("{commit_uuid}-deletes.{}", DataFileFormat::Puffin),
+    );
+    let output_file = table.file_io().new_output(&location)?;
+    let mut writer = PuffinWriter::new(&output_file, HashMap::new(), false).await?;
+    let mut blobs = Vec::with_capacity(deleted_positions.len());
+    for (data_file, positions) in deleted_positions {
+        let mut delete_vector = DeleteVector::default();
+        delete_vector.insert_positions(&positions.iter().copied().collect::<Vec<_>>())?;
+        let blob = Blob::builder()
+            .r#type(DELETION_VECTOR_V1.to_string())
+            .fields(vec![RESERVED_FIELD_ID_POS])
+            .snapshot_id(-1)
+            .sequence_number(-1)
+            .data(delete_vector.to_puffin_blob_data()?)
+            .properties(HashMap::from([
+                (
+                    "referenced-data-file".to_string(),
+                    data_file.file_path.clone(),
+                ),
+                ("cardinality".to_string(), positions.len().to_string()),
+            ]))
+            .build();
+        writer.add(blob, CompressionCodec::None).await?;
+        let blob_metadata = writer.written_blobs_metadata().last().unwrap();
+        blobs.push((
+            *data_file,
+            positions.len(),
+            blob_metadata.offset(),
+            blob_metadata.length(),
+        ));
+    }
+    writer.close().await?;
+    let file_size_in_bytes = table.file_io().new_input(&location)?.metadata().await?.size;
+
+    blobs
+        .into_iter()
+        .map(|(data_file, cardinality, offset, length)| {
+            build_delete_file(
+                DataFileBuilder::default()
+                    .content(DataContentType::PositionDeletes)
+                    .file_path(location.clone())
+                    .file_format(DataFileFormat::Puffin)
+                    .file_size_in_bytes(file_size_in_bytes)
+                    .record_count(cardinality as u64)
+                    .partition(data_file.partition.clone())
+                    .partition_spec_id(data_file.partition_spec_id)
+                    .referenced_data_file(Some(data_file.file_path.clone()))
+                    .content_offset(Some(offset.try_into()?))
+                    .content_size_in_bytes(Some(length.try_into()?)),
+            )
+        })
+        .collect()
+}
+
+/// Fails if any of the data files is not live in the current snapshot of the table.
+///
+/// A missing file is a conflict when the table changed since the snapshot `base_snapshot_id`
+/// that the deletes were planned from: a concurrent commit removed or rewrote it, and
+/// deleting from it would drop the deletes or apply them twice.
+async fn ensure_data_files_live(
+    table: &Table,
+    base_snapshot_id: Option<i64>,
+    deleted_positions: &[(DataFile, BTreeSet<u64>)],
+) -> Result<()> {
+    let metadata = table.metadata();
+    let mut missing_files: HashSet<String> = deleted_positions
+        .iter()
+        .map(|(data_file, _)| metadata.resolve_location(&data_file.file_path))
+        .collect();
+    if let Some(snapshot) = table.metadata().current_snapshot() {
+        let manifest_list = snapshot
+            .load_manifest_list(table.file_io(), &table.metadata_ref())
+            .await?;
+        for manifest_file in manifest_list.entries() {
+            if missing_files.is_empty() {
+                break;
+            }
+            if manifest_file.content != ManifestContentType::Data {
+                continue;
+            }
+            let manifest = manifest_file
+                .load_manifest(table.file_io(), table.metadata())
+                .await?;
+            for entry in manifest.entries().iter().filter(|entry| entry.is_alive()) {
+                missing_files.remove(&metadata.resolve_location(entry.file_path()));
+            }
+        }
+    }
+    if missing_files.is_empty() {
+        return Ok(());
+    }
+
+    let mut missing_files: Vec<String> = missing_files.into_iter().collect();
+    missing_files.sort();
+    let current_snapshot_id = table.metadata().current_snapshot_id();
+    if current_snapshot_id != base_snapshot_id {
+        return Err(Error::new(
+            ErrorKind::CatalogCommitConflicts,
+            format!(
+                "Data files were removed by a concurrent commit since the delete was planned, files: {}",
+                missing_files.join(", ")
+            ),
+        )
+        .with_context("base-snapshot-id", format!("{base_snapshot_id:?}"))
+        .with_context("current-snapshot-id", format!("{current_snapshot_id:?}")));
+    }
+    Err(Error::new(
+        ErrorKind::DataInvalid,
+        format!(
+            "Cannot delete rows of files that are not referenced by table, files: {}",
+            missing_files.join(", ")
+        ),
+    ))
+}
+
+fn build_delete_file(builder: &DataFileBuilder) -> Result<DataFile> {
+    builder.build().map_err(|e| {
+        Error::new(
+            ErrorKind::DataInvalid,
+            format!("Failed to build delete file: {e}"),
+        )
+    })
+}
+
+/// Fails if any of the data files already has a deletion vector, as a data file may have at
+/// most one and merging them is not supported yet.
+async fn ensure_no_deletion_vectors(
+    table: &Table,
+    deleted_positions: &[(&DataFile, &BTreeSet<u64>)],
+) -> Result<()> {
+    let Some(snapshot) = table.metadata().current_snapshot() else {
+        return Ok(());
+    };
+    let metadata = table.metadata();
+    let data_file_paths: HashSet<String> = deleted_positions
+        .iter()
+        .map(|(data_file, _)| metadata.resolve_location(&data_file.file_path))
+        .collect();
+
+    let manifest_list = snapshot
+        .load_manifest_list(table.file_io(), &table.metadata_ref())
+        .await?;
+    for manifest_file in manifest_list.entries() {
+        if manifest_file.content != ManifestContentType::Deletes {
+            continue;
+        }
+        let manifest = manifest_file
+            .load_manifest(table.file_io(), table.metadata())
+            .await?;
+        for entry in manifest.entries().iter().filter(|entry| entry.is_alive()) {
+            let delete_file = entry.data_file();
+            if delete_file.file_format() == DataFileFormat::Puffin
+                && let Some(referenced) = delete_file.referenced_data_file()
+                && data_file_paths.contains(&metadata.resolve_location(&referenced))
+            {
+                return Err(Error::new(
+                    ErrorKind::FeatureUnsupported,
+                    format!(
+                        "Data file {referenced} already has a deletion vector, merging deletion vectors is not supported yet"
+                    ),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl TransactionAction for DeleteAction {
+    async fn commit(self: Arc<Self>, table: &Table) -> Result<ActionCommit> {
+        if self.deleted_positions.is_empty() && self.delete_filter.is_none() {
+            return Err(Error::new(
+                ErrorKind::PreconditionFailed,
+                "No rows to delete found when committing a delete action",
+            ));
+        }
+
+        ensure_data_files_live(table, self.base_snapshot_id, &self.deleted_positions).await?;
+
+        // Data files removed by the filter need no deletes of their positions.
+        let filtered_data_files = match &self.delete_filter {
+            Some(delete_filter) => {
+                data_files_matching_filter(table, delete_filter, self.case_sensitive).await?
+            }
+            None => vec![],
+        };
+        let filtered_paths: HashSet<String> = filtered_data_files
+            .iter()
+            .map(|data_file| table.metadata().resolve_location(&data_file.file_path))
+            .collect();
+
+        let commit_uuid = self.commit_uuid.unwrap_or_else(Uuid::now_v7);
+        let mut removed_data_files = Vec::with_capacity(self.deleted_positions.len());
+        let mut partially_deleted = vec![];
+        for (data_file, positions) in &self.deleted_positions {
+            if data_file.content_type() != DataContentType::Data {
+                return Err(Error::new(
+                    ErrorKind::DataInvalid,
+                    format!(
+                        "Only rows of data files can be deleted, file: {}",
+                        data_file.file_path
+                    ),
+                ));
+            }
+            if filtered_paths.contains(&table.metadata().resolve_location(&data_file.file_path)) {
+                continue;
+            }
+            if self.drop_fully_deleted_files && Self::is_fully_deleted(data_file, positions) {
+                removed_data_files.push(data_file.clone());
+            } else if !positions.is_empty() {
+                partially_deleted.push((data_file, positions));
+            }
+        }
+
+        let added_delete_files = if partially_deleted.is_empty() {
+            vec![]
+        } else {
+            match self.resolve_delete_format(table)? {
+                DeleteFormat::PositionV2 => {
+                    write_position_delete_files(table, commit_uuid, &partially_deleted).await?
+                }
+                DeleteFormat::DeletionVectorV3 => {
+                    write_deletion_vectors(table, commit_uuid, &partially_deleted).await?
+                }
+            }
+        };
+
+        if self.delete_filter.is_some() {
+            removed_data_files.extend(filtered_data_files);
+            if removed_data_files.is_empty() && added_delete_files.is_empty() {
+                return Ok(ActionCommit::new(vec![], vec![]));
+            }
+        }
+
+        let snapshot_producer = SnapshotProducer::new(
+            table,
+            commit_uuid,
+            self.key_metadata.clone(),
+            self.snapshot_properties.clone(),
+            vec![],
+        )
+        .with_removed_data_files(removed_data_files)
+        .with_added_delete_files(added_delete_files);
+
+        snapshot_producer
+            .commit(DeleteOperation, DefaultManifestProcess)
+            .await
+    }
+}
+
+struct DeleteOperation;
+
+impl SnapshotProduceOperation for DeleteOperation {
+    fn operation(&self) -> Operation {
+        Operation::Delete
+    }
+
+    async fn delete_entries(
+        &self,
+        _snapshot_produce: &SnapshotProducer<'_>,
+    ) -> Result<Vec<ManifestEntry>> {
+        Ok(vec![])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int64Type;
+    use arrow_array::{ArrayRef, Int64Array, RecordBatch};
+    use futures::TryStreamExt;
+    use parquet::file::properties::WriterProperties;
+
+    use super::DeleteFormat;
+    use crate::arrow::schema_to_arrow_schema;
+    use crate::expr::Reference;
+    use crate::memory::tests::new_memory_catalog;
+    use crate::spec::{
+        DataContentType, DataFile, DataFileBuilder, DataFileFormat, Datum, FormatVersion, Literal,
+        ManifestContentType, ManifestStatus, NestedField, Operation, PrimitiveType, Schema, Struct,
+        Type,
+    };
+    use crate::table::Table;
+    use crate::test_utils::fixtures::partitioned_data_file;
+    use crate::transaction::tests::make_v2_minimal_table;
+    use crate::transaction::{ApplyTransactionAction, Transaction, TransactionAction};
+    use crate::writer::file_writer::{FileWriter, FileWriterBuilder, ParquetWriterBuilder};
+    use crate::{Catalog, ErrorKind, NamespaceIdent, TableCreation, TableUpdate};
+
+    async fn append(table: Table, data_files: Vec<DataFile>) -> Table {
+        let action = Transaction::new(&table)
+            .fast_append()
+            .add_data_files(data_files);
+        let mut action_commit = Arc::new(action).commit(&table).await.unwrap();
+        Transaction::update_table_metadata(table, &action_commit.take_updates()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_delete_all_rows_removes_data_file() {
+        let table = make_v2_minimal_table();
+        let deleted_file = partitioned_data_file(&table, "test/1.parquet", Literal::long(300), 3);
+        let kept_file = partitioned_data_file(&table, "test/2.parquet", Literal::long(300), 2);
+        let table = append(table, vec![deleted_file.clone(), kept_file.clone()]).await;
+
+        let action = Transaction::new(&table)
+            .delete()
+            .delete_positions(deleted_file.clone(), [0, 1, 2]);
+        let mut action_commit = Arc::new(action).commit(&table).await.unwrap();
+        let updates = action_commit.take_updates();
+
+        let new_snapshot = if let TableUpdate::AddSnapshot { snapshot } = &updates[0] {
+            snapshot
+        } else {
+            unreachable!()
+        };
+        assert_eq!(new_snapshot.summary().operation, Operation::Delete);
+        assert_eq!(
+            new_snapshot
+                .summary()
+                .additional_properties
+                .get("deleted-data-files")
+                .unwrap(),
+            "1"
+        );
+
+        let manifest_list = new_snapshot
+            .load_manifest_list(table.file_io(), table.metadata())
+            .await
+            .unwrap();
+        assert!(
+            manifest_list
+                .entries()
+                .iter()
+                .all(|manifest| manifest.content == ManifestContentType::Data)
+        );
+
+        let mut entries = vec![];
+        for manifest_file in manifest_list.entries() {
+            let manifest = manifest_file
+                .load_manifest(table.file_io(), table.metadata())
+                .await
+                .unwrap();
+            entries.extend(manifest.entries().iter().cloned());
+        }
+        let live_files: Vec<&str> = entries
+            .iter()
+            .filter(|entry| entry.is_alive())
+            .map(|entry| entry.file_path())
+            .collect();
+        assert_eq!(live_files, vec![kept_file.file_path()]);
+
+        let deleted_entry = entries
+            .iter()
+            .find(|entry| entry.file_path() == deleted_file.file_path())
+            .unwrap();
+        assert_eq!(deleted_entry.status(), ManifestStatus::Deleted);
+        assert_eq!(
+            deleted_entry.snapshot_id(),
+            Some(new_snapshot.snapshot_id())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delete_updates_snapshot_summary() {
+        let table = make_v2_minimal_table();
+        let deleted_file = partitioned_data_file(&table, "test/1.parquet", Literal::long(300), 3);
+        let kept_file = partitioned_data_file(&table, "test/2.parquet", Literal::long(300), 2);
+        let table = append(table, vec![deleted_file.clone(), kept_file]).await;
+        let append_summary = &table.metadata().current_snapshot().unwrap().summary();
+        assert_eq!(append_summary.additional_properties["added-records"], "5");
+        assert_eq!(append_summary.additional_properties["total-records"], "5");
+
+        // The removed file is summarized as recorded in the manifest.
+        let mut stale_file = deleted_file;
+        stale_file.file_size_in_bytes = 0;
+        let action = Transaction::new(&table)
+            .delete()
+            .delete_positions(stale_file, [0, 1, 2]);
+        let mut action_commit = Arc::new(action).commit(&table).await.unwrap();
+        let updates = action_commit.take_updates();
+        let TableUpdate::AddSnapshot { snapshot } = &updates[0] else {
+            unreachable!()
+        };
+
+        let properties = &snapshot.summary().additional_properties;
+        for (property, value) in [
+            ("deleted-data-files", "1"),
+            ("deleted-records", "3"),
+            ("removed-files-size", "100"),
+            ("total-data-files", "1"),
+            ("total-records", "2"),
+            ("total-files-size", "100"),
+            ("total-delete-files", "0"),
+        ] {
+            assert_eq!(properties.get(property).map(String::as_str), Some(value));
+        }
+        assert!(!properties.contains_key("added-records"));
+        assert!(!properties.contains_key("added-data-files"));
+    }
+
+    #[tokio::test]
+    async fn test_delete_some_rows_writes_position_delete_file() {
+        let table = make_v2_minimal_table();
+        let file = partitioned_data_file(&table, "test/1.parquet", Literal::long(300), 3);
+        let table = append(table, vec![file.clone()]).await;
+
+        let action = Transaction::new(&table)
+            .delete()
+            .delete_positions(file.clone(), [0, 2]);
+        let mut action_commit = Arc::new(action).commit(&table).await.unwrap();
+        let updates = action_commit.take_updates();
+        let TableUpdate::AddSnapshot { snapshot } = &updates[0] else {
+            unreachable!()
+        };
+        let properties = &snapshot.summary().additional_properties;
+        assert_eq!(properties["added-position-delete-files"], "1");
+        assert_eq!(properties["added-position-deletes"], "2");
+        assert_eq!(properties["total-data-files"], "1");
+
+        let manifest_list = snapshot
+            .load_manifest_list(table.file_io(), table.metadata())
+            .await
+            .unwrap();
+        let delete_manifest = manifest_list
+            .entries()
+            .iter()
+            .find(|manifest| manifest.content == ManifestContentType::Deletes)
+            .unwrap();
+        let manifest = delete_manifest
+            .load_manifest(table.file_io(), table.metadata())
+            .await
+            .unwrap();
+        assert_eq!(manifest.entries().len(), 1);
+        let delete_file = manifest.entries()[0].data_file();
+        assert_eq!(delete_file.content_type(), DataContentType::PositionDeletes);
+        assert_eq!(delete_file.file_format(), DataFileFormat::Parquet);
+        assert_eq!(delete_file.record_count(), 2);
+        assert_eq!(
+            delete_file.referenced_data_file(),
+            Some(file.file_path().to_string())
+        );
+
+        // Deletion vectors are only supported by format version 3.
+        let action = Transaction::new(&table)
+            .delete()
+            .with_delete_format(DeleteFormat::DeletionVectorV3)
+            .delete_positions(file, [0]);
+        let Err(err) = Arc::new(action).commit(&table).await else {
+            panic!("delete action should fail");
+        };
+        assert_eq!(err.kind(), ErrorKind::DataInvalid);
+    }
+
+    async fn live_delete_files(table: &Table) -> Vec<DataFile> {
+        let manifest_list = table
+            .metadata()
+            .current_snapshot()
+            .unwrap()
+            .load_manifest_list(table.file_io(), table.metadata())
+            .await
+            .unwrap();
+        let mut delete_files = vec![];
+        for manifest_file in manifest_list.entries() {
+            if manifest_file.content != ManifestContentType::Deletes {
+                continue;
+            }
+            let manifest = manifest_file
+                .load_manifest(table.file_io(), table.metadata())
+                .await
+                .unwrap();
+            delete_files.extend(
+                manifest
+                    .entries()
+                    .iter()
+                    .filter(|entry| entry.is_alive())
+                    .map(|entry| entry.data_file().clone()),
+            );
+        }
+        delete_files
+    }
+
+    async fn scan_ids(table: &Table) -> Vec<i64> {
+        let batches: Vec<RecordBatch> = table
+            .scan()
+            .build()
+            .unwrap()
+            .to_arrow()
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        batches
+            .iter()
+            .flat_map(|batch| {
+                batch
+                    .column(0)
+                    .as_primitive::<Int64Type>()
+                    .values()
+                    .to_vec()
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_position_deletes_and_deletion_vectors_are_applied_on_read() {
+        let catalog = new_memory_catalog().await;
+        let namespace = NamespaceIdent::new("ns".to_string());
+        catalog
+            .create_namespace(&namespace, HashMap::new())
+            .await
+            .unwrap();
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+            ])
+            .build()
+            .unwrap();
+        let table = catalog
+            .create_table(
+                &namespace,
+                TableCreation::builder()
+                    .name("t".to_string())
+                    .schema(schema)
+                    .format_version(FormatVersion::V3)
+                    .build(),
+            )
+            .await
+            .unwrap();
+
+        let schema = table.metadata().current_schema().clone();
+        let mut writer =
+            ParquetWriterBuilder::new(WriterProperties::builder().build(), schema.clone())
+                .build(
+                    table
+                        .file_io()
+                        .new_output(format!("{}/data/1.parquet", table.metadata().location()))
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+        writer
+            .write(
+                &RecordBatch::try_new(Arc::new(schema_to_arrow_schema(&schema).unwrap()), vec![
+                    Arc::new(Int64Array::from(vec![0, 1, 2, 3])) as ArrayRef,
+                ])
+                .unwrap(),
+            )
+            .await
+            .unwrap();
+        let file = writer.close().await.unwrap()[0]
+            .partition_spec_id(0)
+            .partition(Struct::empty())
+            .build()
+            .unwrap();
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .fast_append()
+            .add_data_files(vec![file.clone()])
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .delete()
+            .with_delete_format(DeleteFormat::PositionV2)
+            .delete_positions(file.clone(), [1])
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+        let delete_files = live_delete_files(&table).await;
+        assert_eq!(delete_files.len(), 1);
+        assert_eq!(delete_files[0].file_format(), DataFileFormat::Parquet);
+        assert_eq!(scan_ids(&table).await, vec![0, 2, 3]);
+
+        // Tables of format version 3 default to deletion vectors.
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .delete()
+            .delete_positions(file.clone(), [2])
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+        let delete_files = live_delete_files(&table).await;
+        assert_eq!(delete_files.len(), 2);
+        let deletion_vector = delete_files
+            .iter()
+            .find(|delete_file| delete_file.file_format() == DataFileFormat::Puffin)
+            .unwrap();
+        assert_eq!(
+            deletion_vector.content_type(),
+            DataContentType::PositionDeletes
+        );
+        assert_eq!(deletion_vector.record_count(), 1);
+        assert!(deletion_vector.content_offset().is_some());
+        assert_eq!(scan_ids(&table).await, vec![0, 3]);
+
+        // A data file has at most one deletion vector.
+        let tx = Transaction::new(&table);
+        let tx = tx.delete().delete_positions(file, [3]).apply(tx).unwrap();
+        let Err(err) = tx.commit(&catalog).await else {
+            panic!("delete action should fail");
+        };
+        assert_eq!(err.kind(), ErrorKind::FeatureUnsupported);
+    }
+
+    #[tokio::test]
+    async fn test_delete_by_partition_filter_removes_data_files() {
+        let table = make_v2_minimal_table();
+        let mut other_partition_file =
+            partitioned_data_file(&table, "test/3.parquet", Literal::long(300), 4);
+        other_partition_file.partition = Struct::from_iter([Some(Literal::long(400))]);
+        let table = append(table.clone(), vec![
+            partitioned_data_file(&table, "test/1.parquet", Literal::long(300), 3),
+            partitioned_data_file(&table, "test/2.parquet", Literal::long(300), 2),
+            other_partition_file.clone(),
+        ])
+        .await;
+
+        // The positions deleted from a file that the filter removes are dropped.
+        let action = Transaction::new(&table)
+            .delete()
+            .delete_positions(
+                partitioned_data_file(&table, "test/1.parquet", Literal::long(300), 3),
+                [0],
+            )
+            .delete_filter(Reference::new("x").equal_to(Datum::long(300)));
+        let mut action_commit = Arc::new(action).commit(&table).await.unwrap();
+        let updates = action_commit.take_updates();
+        let TableUpdate::AddSnapshot { snapshot } = &updates[0] else {
+            unreachable!()
+        };
+
+        let summary = snapshot.summary();
+        assert_eq!(summary.operation, Operation::Delete);
+        assert_eq!(summary.additional_properties["deleted-data-files"], "2");
+        assert_eq!(summary.additional_properties["deleted-records"], "5");
+        assert!(
+            !summary
+                .additional_properties
+                .contains_key("added-data-files")
+        );
+        assert!(
+            !summary
+                .additional_properties
+                .contains_key("added-delete-files")
+        );
+
+        // Only manifests are written, no delete or data file.
+        let manifest_list = snapshot
+            .load_manifest_list(table.file_io(), table.metadata())
+            .await
+            .unwrap();
+        let mut live_files = vec![];
+        for manifest_file in manifest_list.entries() {
+            assert_eq!(manifest_file.content, ManifestContentType::Data);
+            let manifest = manifest_file
+                .load_manifest(table.file_io(), table.metadata())
+                .await
+                .unwrap();
+            live_files.extend(
+                manifest
+                    .entries()
+                    .iter()
+                    .filter(|entry| entry.is_alive())
+                    .map(|entry| entry.file_path().to_string()),
+            );
+        }
+        assert_eq!(live_files, vec![other_partition_file.file_path()]);
+    }
+
+    #[tokio::test]
+    async fn test_delete_unknown_file_fails() {
+        let table = make_v2_minimal_table();
+        let table = append(table.clone(), vec![partitioned_data_file(
+            &table,
+            "test/1.parquet",
+            Literal::long(300),
+            1,
+        )])
+        .await;
+
+        let action = Transaction::new(&table).delete().delete_positions(
+            partitioned_data_file(&table, "test/unknown.parquet", Literal::long(300), 1),
+            [0],
+        );
+        let Err(err) = Arc::new(action).commit(&table).await else {
+            panic!("delete action should fail");
+        };
+        assert_eq!(err.kind(), ErrorKind::DataInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_delete_from_concurrently_rewritten_file_conflicts() {
+        let catalog = new_memory_catalog().await;
+        let namespace = NamespaceIdent::new("ns".to_string());
+        catalog
+            .create_namespace(&namespace, HashMap::new())
+            .await
+            .unwrap();
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+            ])
+            .build()
+            .unwrap();
+        let table = catalog
+            .create_table(
+                &namespace,
+                TableCreation::builder()
+                    .name("t".to_string())
+                    .schema(schema)
+                    .build(),
+            )
+            .await
+            .unwrap();
+        let unpartitioned_file = |path: &str| {
+            DataFileBuilder::default()
+                .content(DataContentType::Data)
+                .file_path(format!("{}/data/{path}", table.metadata().location()))
+                .file_format(DataFileFormat::Parquet)
+                .file_size_in_bytes(100)
+                .record_count(3)
+                .partition_spec_id(table.metadata().default_partition_spec_id())
+                .partition(Struct::empty())
+                .build()
+                .unwrap()
+        };
+        let original_file = unpartitioned_file("1.parquet");
+        let compacted_file = unpartitioned_file("2.parquet");
+
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .fast_append()
+            .add_data_files(vec![original_file.clone()])
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+
+        // The delete is planned, then a compaction rewrites the file before it commits.
+        let delete_tx = Transaction::new(&table);
+        let delete_tx = delete_tx
+            .delete()
+            .delete_positions(original_file.clone(), [0])
+            .apply(delete_tx)
+            .unwrap();
+
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .rewrite_files()
+            .delete_data_files(vec![original_file])
+            .add_data_files(vec![compacted_file.clone()])
+            .apply(tx)
+            .unwrap();
+        let compacted = tx.commit(&catalog).await.unwrap();
+
+        let Err(err) = delete_tx.commit(&catalog).await else {
+            panic!("delete from a rewritten file should conflict");
+        };
+        assert_eq!(err.kind(), ErrorKind::CatalogCommitConflicts);
+        assert!(!err.retryable());
+
+        let table = catalog.load_table(table.identifier()).await.unwrap();
+        assert_eq!(
+            table.metadata().current_snapshot_id(),
+            compacted.metadata().current_snapshot_id()
+        );
+        let tasks: Vec<_> = table
+            .scan()
+            .build()
+            .unwrap()
+            .plan_files()
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].data_file_path, compacted_file.file_path);
+        assert!(tasks[0].deletes.is_empty());
+    }
+}
@@ -54,10 +54,22 @@ mod action;
 
 pub use action::*;
 mod append;
+mod canonicalize_metadata;
+mod clock;
+pub use clock::{Clock, FixedClock, SystemClock};
+mod compute_stats;
+mod delete;
+pub use delete::DeleteFormat;
+mod overwrite;
+mod remove_snapshot;
+mod replace_partitions;
+mod rewrite_files;
+mod row_filter;
 mod snapshot;
 mod sort_order;
 mod update_location;
 mod update_properties;
+mod update_schema;
 mod update_statistics;
 mod upgrade_format_version;
 
@@ -67,22 +79,32 @@ use std::time::Duration;
 use backon::{BackoffBuilder, ExponentialBackoff, ExponentialBuilder, RetryableWithContext};
 
 use crate::error::Result;
-use crate::spec::TableProperties;
+use crate::spec::{TableMetadata, TableProperties};
 use crate::table::Table;
 use crate::transaction::action::BoxedTransactionAction;
 use crate::transaction::append::FastAppendAction;
+use crate::transaction::canonicalize_metadata::CanonicalizeMetadataAction;
+use crate::transaction::compute_stats::ComputeStatsAction;
+use crate::transaction::delete::DeleteAction;
+use crate::transaction::overwrite::OverwriteAction;
+use crate::transaction::remove_snapshot::RemoveSnapshotAction;
+use crate::transaction::replace_partitions::ReplacePartitionsAction;
+use crate::transaction::rewrite_files::RewriteFilesAction;
 use crate::transaction::sort_order::ReplaceSortOrderAction;
 use crate::transaction::update_location::UpdateLocationAction;
 use crate::transaction::update_properties::UpdatePropertiesAction;
+use crate::transaction::update_schema::UpdateSchemaAction;
 use crate::transaction::update_statistics::UpdateStatisticsAction;
 use crate::transaction::upgrade_format_version::UpgradeFormatVersionAction;
-use crate::{Catalog, TableCommit, TableRequirement, TableUpdate};
+use crate::{Catalog, Error, ErrorKind, TableCommit, TableRequirement, TableUpdate};
 
 /// Table transaction.
 #[derive(Clone)]
 pub struct Transaction {
     table: Table,
     actions: Vec<BoxedTransactionAction>,
+    clock: Option<Arc<dyn Clock>>,
+    empty_snapshot: bool,
 }
 
 impl Transaction {
@@ -91,10 +113,28 @@ impl Transaction {
         Self {
             table: table.clone(),
             actions: vec![],
+            clock: None,
+            empty_snapshot: false,
         }
     }
 
-    fn update_table_metadata(table: Table, updates: &[TableUpdate]) -> Result<Table> {
+    /// Sets the clock reading the timestamps of the snapshots committed by this transaction,
+    /// [`SystemClock`] by default.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.table = self.table.with_clock(clock.clone());
+        self.clock = Some(clock);
+        self
+    }
+
+    /// Sets whether a transaction that changes the table metadata without adding a snapshot,
+    /// such as a schema or property update, also adds an empty `append` snapshot, so that
+    /// watchers of the snapshots of the table see the change. Defaults to `false`.
+    pub fn with_empty_snapshot(mut self, empty_snapshot: bool) -> Self {
+        self.empty_snapshot = empty_snapshot;
+        self
+    }
+
+    pub(crate) fn update_table_metadata(table: Table, updates: &[TableUpdate]) -> Result<Table> {
         let mut metadata_builder = table.metadata().clone().into_builder(None);
         for update in updates {
             metadata_builder = update.clone().apply(metadata_builder)?;
@@ -105,8 +145,14 @@ impl Transaction {
 
     /// Applies an [`ActionCommit`] to the given [`Table`], returning a new [`Table`] with updated metadata.
     /// Also appends any derived [`TableUpdate`]s and [`TableRequirement`]s to the provided vectors.
+    ///
+    /// The requirements that `base`, the metadata the transaction started from, doesn't meet
+    /// are dropped when the updates of earlier actions of the transaction changed what they
+    /// assert, such as the ref moved by an earlier snapshot, and fail the transaction
+    /// otherwise.
     fn apply(
         table: Table,
+        base: &TableMetadata,
         mut action_commit: ActionCommit,
         existing_updates: &mut Vec<TableUpdate>,
         existing_requirements: &mut Vec<TableRequirement>,
@@ -118,10 +164,21 @@ impl Transaction {
             requirement.check(Some(table.metadata()))?;
         }
 
+        for requirement in requirements {
+            if let Err(err) = requirement.check(Some(base)) {
+                if is_obsoleted_by(&requirement, existing_updates) {
+                    continue;
+                }
+                return Err(err);
+            }
+            if !existing_requirements.contains(&requirement) {
+                existing_requirements.push(requirement);
+            }
+        }
+
         let updated_table = Self::update_table_metadata(table, &updates)?;
 
         existing_updates.extend(updates);
-        existing_requirements.extend(requirements);
 
         Ok(updated_table)
     }
@@ -136,11 +193,47 @@ impl Transaction {
         UpdatePropertiesAction::new()
     }
 
+    /// Update table's current schema.
+    pub fn update_schema(&self) -> UpdateSchemaAction {
+        UpdateSchemaAction::new()
+    }
+
     /// Creates a fast append action.
     pub fn fast_append(&self) -> FastAppendAction {
         FastAppendAction::new()
     }
 
+    /// Creates a delete action.
+    pub fn delete(&self) -> DeleteAction {
+        DeleteAction::new(self.table.metadata().current_snapshot_id())
+    }
+
+    /// Creates an action that replaces the data files matching a filter with new data files.
+    pub fn overwrite(&self) -> OverwriteAction {
+        OverwriteAction::new()
+    }
+
+    /// Creates an action that overwrites the partitions touched by new data files.
+    pub fn replace_partitions(&self) -> ReplacePartitionsAction {
+        ReplacePartitionsAction::new()
+    }
+
+    /// Creates an action that removes the snapshot `snapshot_id`, which no ref may need.
+    pub fn remove_snapshot(&self, snapshot_id: i64) -> RemoveSnapshotAction {
+        RemoveSnapshotAction::new(snapshot_id)
+    }
+
+    /// Creates an action that replaces data files with rewritten ones.
+    pub fn rewrite_files(&self) -> RewriteFilesAction {
+        RewriteFilesAction::new()
+    }
+
+    /// Creates an action that recomputes the column metrics of data files from their footers,
+    /// producing a snapshot that only rewrites manifests.
+    pub fn compute_stats_only(&self) -> ComputeStatsAction {
+        ComputeStatsAction::new()
+    }
+
     /// Creates replace sort order action.
     pub fn replace_sort_order(&self) -> ReplaceSortOrderAction {
         ReplaceSortOrderAction::new()
@@ -156,6 +249,11 @@ impl Transaction {
         UpdateStatisticsAction::new()
     }
 
+    /// Rewrite the current metadata of table in its canonical form.
+    pub fn canonicalize_metadata(&self) -> CanonicalizeMetadataAction {
+        CanonicalizeMetadataAction::new()
+    }
+
     /// Commit transaction.
     pub async fn commit(self, catalog: &dyn Catalog) -> Result<Table> {
         if self.actions.is_empty() {
@@ -194,14 +292,41 @@ impl Transaction {
 
     async fn do_commit(&mut self, catalog: &dyn Catalog) -> Result<Table> {
         let refreshed = catalog.load_table(self.table.identifier()).await?;
+        if refreshed.metadata().uuid() != self.table.metadata().uuid() {
+            // The table was dropped and recreated, re-applying the actions would change a
+            // different table
+            return Err(Error::new(
+                ErrorKind::CatalogCommitConflicts,
+                format!(
+                    "Table {} was replaced by another table since the transaction started",
+                    self.table.identifier()
+                ),
+            )
+            .with_context("expected", self.table.metadata().uuid().to_string())
+            .with_context("found", refreshed.metadata().uuid().to_string()));
+        }
 
         if self.table.metadata() != refreshed.metadata()
             || self.table.metadata_location() != refreshed.metadata_location()
         {
             // current base is stale, use refreshed as base and re-apply transaction actions
-            self.table = refreshed.clone();
+            self.table = match &self.clock {
+                Some(clock) => refreshed.with_clock(clock.clone()),
+                None => refreshed,
+            };
         }
 
+        let table_commit = self.table_commit().await?;
+        catalog.update_table(table_commit).await
+    }
+
+    /// Applies the actions of this transaction to its table and returns the resulting
+    /// [`TableCommit`] without committing it.
+    ///
+    /// This is useful to commit changes to several tables atomically with catalogs that
+    /// support multi-table commits. Unlike [`Transaction::commit`], the table is not refreshed
+    /// and the commit is not retried on conflicts.
+    pub async fn table_commit(&self) -> Result<TableCommit> {
         let mut current_table = self.table.clone();
         let mut existing_updates: Vec<TableUpdate> = vec![];
         let mut existing_requirements: Vec<TableRequirement> = vec![];
@@ -211,22 +336,70 @@ impl Transaction {
             // apply action commit to current_table
             current_table = Self::apply(
                 current_table,
+                self.table.metadata(),
                 action_commit,
                 &mut existing_updates,
                 &mut existing_requirements,
             )?;
         }
 
-        let table_commit = TableCommit::builder()
+        let adds_snapshot = existing_updates
+            .iter()
+            .any(|update| matches!(update, TableUpdate::AddSnapshot { .. }));
+        if self.empty_snapshot && !existing_updates.is_empty() && !adds_snapshot {
+            let action_commit = Arc::new(FastAppendAction::empty())
+                .commit(&current_table)
+                .await?;
+            Self::apply(
+                current_table,
+                self.table.metadata(),
+                action_commit,
+                &mut existing_updates,
+                &mut existing_requirements,
+            )?;
+        }
+
+        Ok(TableCommit::builder()
             .ident(self.table.identifier().to_owned())
             .updates(existing_updates)
             .requirements(existing_requirements)
-            .build();
-
-        catalog.update_table(table_commit).await
+            .base_metadata_location(self.table.metadata_location().map(str::to_string))
+            .build())
     }
 }
 
+/// Returns whether `updates` change what `requirement` asserts, so that the requirement of an
+/// action applied after them can't hold for the metadata the transaction started from.
+fn is_obsoleted_by(requirement: &TableRequirement, updates: &[TableUpdate]) -> bool {
+    updates.iter().any(|update| match requirement {
+        TableRequirement::NotExist => false,
+        TableRequirement::UuidMatch { .. } => matches!(update, TableUpdate::AssignUuid { .. }),
+        TableRequirement::RefSnapshotIdMatch { r#ref, .. } => match update {
+            TableUpdate::SetSnapshotRef { ref_name, .. }
+            | TableUpdate::RemoveSnapshotRef { ref_name } => ref_name == r#ref,
+            _ => false,
+        },
+        TableRequirement::LastAssignedFieldIdMatch { .. } => {
+            matches!(update, TableUpdate::AddSchema { .. })
+        }
+        TableRequirement::CurrentSchemaIdMatch { .. } => matches!(
+            update,
+            TableUpdate::AddSchema { .. } | TableUpdate::SetCurrentSchema { .. }
+        ),
+        TableRequirement::LastAssignedPartitionIdMatch { .. } => {
+            matches!(update, TableUpdate::AddSpec { .. })
+        }
+        TableRequirement::DefaultSpecIdMatch { .. } => matches!(
+            update,
+            TableUpdate::AddSpec { .. } | TableUpdate::SetDefaultSpec { .. }
+        ),
+        TableRequirement::DefaultSortOrderIdMatch { .. } => matches!(
+            update,
+            TableUpdate::AddSortOrder { .. } | TableUpdate::SetDefaultSortOrder { .. }
+        ),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
@@ -237,10 +410,16 @@ mod tests {
 
     use crate::catalog::MockCatalog;
     use crate::io::FileIOBuilder;
-    use crate::spec::TableMetadata;
+    use crate::memory::tests::new_memory_catalog;
+    use crate::spec::{
+        DataContentType, DataFileBuilder, DataFileFormat, Literal, MAIN_BRANCH, Operation,
+        SnapshotReference, SnapshotRetention, Struct, TableMetadata,
+    };
     use crate::table::Table;
-    use crate::transaction::{ApplyTransactionAction, Transaction};
-    use crate::{Catalog, Error, ErrorKind, TableCreation, TableIdent};
+    use crate::transaction::{ActionCommit, ApplyTransactionAction, FixedClock, Transaction};
+    use crate::{
+        Catalog, Error, ErrorKind, TableCreation, TableIdent, TableRequirement, TableUpdate,
+    };
 
     pub fn make_v1_table() -> Table {
         let file = File::open(format!(
@@ -498,6 +677,181 @@ mod tests {
             assert!(err.retryable(), "Error should be retryable");
         }
     }
+
+    #[tokio::test]
+    async fn test_commit_with_fixed_clock() {
+        let catalog = new_memory_catalog().await;
+        let mut table = make_v3_minimal_table_in_catalog(&catalog).await;
+
+        // Snapshots may not be older than the table, so the fixed times lie ahead.
+        let base_ms = chrono::Utc::now().timestamp_millis() + 60 * 60 * 1000;
+        for (i, timestamp_ms) in [base_ms, base_ms + 1000].into_iter().enumerate() {
+            let data_file = DataFileBuilder::default()
+                .content(DataContentType::Data)
+                .file_path(format!("test/{i}.parquet"))
+                .file_format(DataFileFormat::Parquet)
+                .file_size_in_bytes(100)
+                .record_count(1)
+                .partition(Struct::from_iter([Some(Literal::long(0))]))
+                .partition_spec_id(0)
+                .build()
+                .unwrap();
+            let tx = Transaction::new(&table).with_clock(Arc::new(FixedClock(timestamp_ms)));
+            let tx = tx
+                .fast_append()
+                .add_data_files(vec![data_file])
+                .apply(tx)
+                .unwrap();
+            table = tx.commit(&catalog).await.unwrap();
+
+            let snapshot = table.metadata().current_snapshot().unwrap();
+            assert_eq!(snapshot.timestamp_ms(), timestamp_ms);
+            assert_eq!(table.metadata().last_updated_ms(), timestamp_ms);
+        }
+
+        let logged: Vec<i64> = table
+            .metadata()
+            .history()
+            .iter()
+            .map(|log| log.timestamp_ms)
+            .collect();
+        assert_eq!(logged, vec![base_ms, base_ms + 1000]);
+    }
+
+    #[tokio::test]
+    async fn test_commit_to_recreated_table_fails() {
+        let catalog = new_memory_catalog().await;
+        let table = make_v3_minimal_table_in_catalog(&catalog).await;
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .update_table_properties()
+            .set("key".to_string(), "value".to_string())
+            .apply(tx)
+            .unwrap();
+
+        catalog.drop_table(table.identifier()).await.unwrap();
+        let recreated = catalog
+            .create_table(
+                table.identifier().namespace(),
+                TableCreation::builder()
+                    .name(table.identifier().name().to_string())
+                    .schema((**table.metadata().current_schema()).clone())
+                    .build(),
+            )
+            .await
+            .unwrap();
+
+        let Err(err) = tx.commit(&catalog).await else {
+            panic!("Committing to a recreated table should fail");
+        };
+        assert_eq!(err.kind(), ErrorKind::CatalogCommitConflicts);
+        assert!(!err.retryable());
+        let loaded = catalog.load_table(table.identifier()).await.unwrap();
+        assert_eq!(loaded.metadata_location(), recreated.metadata_location());
+        assert!(loaded.metadata().properties().get("key").is_none());
+    }
+
+    #[test]
+    fn test_apply_drops_only_obsoleted_requirements() {
+        let table = make_v2_table();
+        let base = table.metadata().clone();
+        let rollback = TableUpdate::SetSnapshotRef {
+            ref_name: MAIN_BRANCH.to_string(),
+            reference: SnapshotReference::new(
+                3051729675574597004,
+                SnapshotRetention::branch(None, None, None),
+            ),
+        };
+        let mut updates = vec![rollback.clone()];
+        let mut requirements = vec![];
+        let table = Transaction::update_table_metadata(table, &updates).unwrap();
+        let ref_requirement = TableRequirement::RefSnapshotIdMatch {
+            r#ref: MAIN_BRANCH.to_string(),
+            snapshot_id: Some(3051729675574597004),
+        };
+
+        // The ref was moved by an earlier update of the transaction.
+        let table = Transaction::apply(
+            table,
+            &base,
+            ActionCommit::new(vec![], vec![ref_requirement.clone()]),
+            &mut updates,
+            &mut requirements,
+        )
+        .unwrap();
+        assert!(requirements.is_empty());
+
+        // Without the earlier update, the ref was moved concurrently.
+        let err = Transaction::apply(
+            table,
+            &base,
+            ActionCommit::new(vec![], vec![ref_requirement]),
+            &mut vec![],
+            &mut requirements,
+        )
+        .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::CatalogCommitConflicts);
+        assert!(requirements.is_empty());
+        assert_eq!(updates, vec![rollback]);
+    }
+
+    #[tokio::test]
+    async fn test_commit_with_empty_snapshot() {
+        let catalog = new_memory_catalog().await;
+        let table = make_v3_minimal_table_in_catalog(&catalog).await;
+
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .update_table_properties()
+            .set("key".to_string(), "value".to_string())
+            .apply(tx)
+            .unwrap();
+        let updated = tx.commit(&catalog).await.unwrap();
+        assert_ne!(updated.metadata_location(), table.metadata_location());
+        assert_eq!(
+            updated.metadata().metadata_log().len(),
+            table.metadata().metadata_log().len() + 1
+        );
+        assert_eq!(
+            updated
+                .metadata()
+                .metadata_log()
+                .last()
+                .unwrap()
+                .metadata_file,
+            table.metadata_location().unwrap()
+        );
+        assert!(updated.metadata().current_snapshot().is_none());
+
+        let tx = Transaction::new(&updated).with_empty_snapshot(true);
+        let tx = tx
+            .update_table_properties()
+            .set("key".to_string(), "other".to_string())
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+        assert_eq!(table.metadata().properties().get("key").unwrap(), "other");
+        let snapshot = table.metadata().current_snapshot().unwrap();
+        assert_eq!(snapshot.summary().operation, Operation::Append);
+        assert_eq!(
+            snapshot
+                .summary()
+                .additional_properties
+                .get("total-data-files")
+                .unwrap(),
+            "0"
+        );
+
+        // Transactions adding a snapshot don't add an empty one.
+        let tx = Transaction::new(&table).with_empty_snapshot(true);
+        let tx = tx
+            .fast_append()
+            .set_snapshot_properties(HashMap::from([("k".to_string(), "v".to_string())]))
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+        assert_eq!(table.metadata().snapshots().len(), 2);
+    }
 }
 
 #[cfg(test)]
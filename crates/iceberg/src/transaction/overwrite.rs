@@ -0,0 +1,338 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::error::Result;
+use crate::expr::Predicate;
+use crate::spec::{DataFile, ManifestEntry, Operation};
+use crate::table::Table;
+use crate::transaction::row_filter::{
+    data_files_matching_filter, validate_data_files_match_filter,
+};
+use crate::transaction::snapshot::{
+    DefaultManifestProcess, SnapshotProduceOperation, SnapshotProducer,
+};
+use crate::transaction::{ActionCommit, TransactionAction};
+use crate::{Error, ErrorKind};
+
+/// OverwriteAction is a transaction action that removes the data files of a table matching
+/// a row filter and adds new data files in the same snapshot.
+///
+/// A data file is removed when all of its rows match the filter, as shown by its partition
+/// or its column metrics, and is kept when none of its rows can match. A data file that may
+/// contain both matching and non-matching rows would require row-level deletes, so the commit
+/// fails for it instead.
+///
+/// With [`OverwriteAction::with_validate_added_files`], all rows of the added data files must
+/// match the filter too, as shown by their partition or their column metrics, so that rows
+/// outside of the overwritten data, such as rows of another partition, can't be added by
+/// mistake.
+///
+/// The new snapshot has the `overwrite` operation.
+pub struct OverwriteAction {
+    overwrite_filter: Option<Predicate>,
+    case_sensitive: bool,
+    validate_added_files: bool,
+    // below are properties used to create SnapshotProducer when commit
+    commit_uuid: Option<Uuid>,
+    key_metadata: Option<Vec<u8>>,
+    snapshot_properties: HashMap<String, String>,
+    added_data_files: Vec<DataFile>,
+}
+
+impl OverwriteAction {
+    pub(crate) fn new() -> Self {
+        Self {
+            overwrite_filter: None,
+            case_sensitive: true,
+            validate_added_files: false,
+            commit_uuid: None,
+            key_metadata: None,
+            snapshot_properties: HashMap::default(),
+            added_data_files: vec![],
+        }
+    }
+
+    /// Remove the data files whose rows all match `filter`.
+    pub fn overwrite_filter(mut self, filter: Predicate) -> Self {
+        self.overwrite_filter = Some(filter);
+        self
+    }
+
+    /// Set whether column names of the overwrite filter are matched case sensitively.
+    /// Defaults to `true`.
+    pub fn with_case_sensitive(mut self, case_sensitive: bool) -> Self {
+        self.case_sensitive = case_sensitive;
+        self
+    }
+
+    /// Set whether the commit fails when an added data file may contain rows not matching
+    /// the overwrite filter. Defaults to `false`.
+    pub fn with_validate_added_files(mut self, validate_added_files: bool) -> Self {
+        self.validate_added_files = validate_added_files;
+        self
+    }
+
+    /// Add the data files replacing the overwritten rows to the snapshot.
+    pub fn add_data_files(mut self, data_files: impl IntoIterator<Item = DataFile>) -> Self {
+        self.added_data_files.extend(data_files);
+        self
+    }
+
+    /// Set commit UUID for the snapshot.
+    pub fn set_commit_uuid(mut self, commit_uuid: Uuid) -> Self {
+        self.commit_uuid = Some(commit_uuid);
+        self
+    }
+
+    /// Set key metadata for manifest files.
+    pub fn set_key_metadata(mut self, key_metadata: Vec<u8>) -> Self {
+        self.key_metadata = Some(key_metadata);
+        self
+    }
+
+    /// Set snapshot summary properties.
+    pub fn set_snapshot_properties(mut self, snapshot_properties: HashMap<String, String>) -> Self {
+        self.snapshot_properties = snapshot_properties;
+        self
+    }
+}
+
+#[async_trait]
+impl TransactionAction for OverwriteAction {
+    async fn commit(self: Arc<Self>, table: &Table) -> Result<ActionCommit> {
+        let Some(overwrite_filter) = &self.overwrite_filter else {
+            return Err(Error::new(
+                ErrorKind::PreconditionFailed,
+                "No overwrite filter found when committing an overwrite action",
+            ));
+        };
+
+        let snapshot_producer = SnapshotProducer::new(
+            table,
+            self.commit_uuid.unwrap_or_else(Uuid::now_v7),
+            self.key_metadata.clone(),
+            self.snapshot_properties.clone(),
+            self.added_data_files.clone(),
+        );
+        snapshot_producer.validate_added_data_files()?;
+        snapshot_producer.validate_duplicate_files().await?;
+        if self.validate_added_files {
+            validate_data_files_match_filter(
+                table,
+                overwrite_filter,
+                self.case_sensitive,
+                &self.added_data_files,
+            )?;
+        }
+
+        let overwritten_data_files =
+            data_files_matching_filter(table, overwrite_filter, self.case_sensitive).await?;
+
+        snapshot_producer
+            .with_removed_data_files(overwritten_data_files)
+            .commit(OverwriteOperation, DefaultManifestProcess)
+            .await
+    }
+}
+
+struct OverwriteOperation;
+
+impl SnapshotProduceOperation for OverwriteOperation {
+    fn operation(&self) -> Operation {
+        Operation::Overwrite
+    }
+
+    async fn delete_entries(
+        &self,
+        _snapshot_produce: &SnapshotProducer<'_>,
+    ) -> Result<Vec<ManifestEntry>> {
+        Ok(vec![])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::expr::Reference;
+    use crate::memory::tests::new_memory_catalog;
+    use crate::spec::{
+        Datum, Literal, NestedField, Operation, PrimitiveType, Schema, Transform, Type,
+        UnboundPartitionSpec,
+    };
+    use crate::table::Table;
+    use crate::test_utils::fixtures::{create_table, live_file_paths, partitioned_data_file};
+    use crate::transaction::{ApplyTransactionAction, Transaction};
+    use crate::{Catalog, ErrorKind, TableCreation};
+
+    async fn make_table_partitioned_by_region(catalog: &impl Catalog) -> Table {
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+                NestedField::required(2, "region", Type::Primitive(PrimitiveType::String)).into(),
+            ])
+            .build()
+            .unwrap();
+        let partition_spec = UnboundPartitionSpec::builder()
+            .add_partition_field(2, "region", Transform::Identity)
+            .unwrap()
+            .build();
+        create_table(
+            catalog,
+            TableCreation::builder()
+                .name("t".to_string())
+                .schema(schema)
+                .partition_spec(partition_spec)
+                .build(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn test_overwrite_by_filter_replaces_matching_files() {
+        let catalog = new_memory_catalog().await;
+        let table = make_table_partitioned_by_region(&catalog).await;
+        let files = vec![
+            partitioned_data_file(&table, "data/us-1.parquet", Literal::string("us"), 1),
+            partitioned_data_file(&table, "data/us-2.parquet", Literal::string("us"), 1),
+            partitioned_data_file(&table, "data/eu-1.parquet", Literal::string("eu"), 1),
+        ];
+        let tx = Transaction::new(&table);
+        let tx = tx.fast_append().add_data_files(files).apply(tx).unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .overwrite()
+            .overwrite_filter(Reference::new("region").equal_to(Datum::string("us")))
+            .add_data_files(vec![partitioned_data_file(
+                &table,
+                "data/us-3.parquet",
+                Literal::string("us"),
+                1,
+            )])
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+
+        assert_eq!(live_file_paths(&table).await, vec![
+            "data/eu-1.parquet",
+            "data/us-3.parquet"
+        ]);
+        let summary = table.metadata().current_snapshot().unwrap().summary();
+        assert_eq!(summary.operation, Operation::Overwrite);
+        let properties = &summary.additional_properties;
+        assert_eq!(properties.get("added-data-files").unwrap(), "1");
+        assert_eq!(properties.get("deleted-data-files").unwrap(), "2");
+        assert_eq!(properties.get("total-data-files").unwrap(), "2");
+    }
+
+    #[tokio::test]
+    async fn test_overwrite_by_filter_rejects_partially_matching_files() {
+        let catalog = new_memory_catalog().await;
+        let table = make_table_partitioned_by_region(&catalog).await;
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .fast_append()
+            .add_data_files(vec![partitioned_data_file(
+                &table,
+                "data/us-1.parquet",
+                Literal::string("us"),
+                1,
+            )])
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+
+        // Without column metrics, any row of the file may or may not match the filter.
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .overwrite()
+            .overwrite_filter(Reference::new("id").equal_to(Datum::long(1)))
+            .apply(tx)
+            .unwrap();
+        let Err(err) = tx.commit(&catalog).await else {
+            panic!("overwriting a partially matching file should fail");
+        };
+        assert_eq!(err.kind(), ErrorKind::FeatureUnsupported);
+    }
+
+    #[tokio::test]
+    async fn test_overwrite_rejects_added_files_of_other_partitions() {
+        let catalog = new_memory_catalog().await;
+        let table = make_table_partitioned_by_region(&catalog).await;
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .fast_append()
+            .add_data_files(vec![
+                partitioned_data_file(&table, "data/us-1.parquet", Literal::string("us"), 1),
+                partitioned_data_file(&table, "data/eu-1.parquet", Literal::string("eu"), 1),
+            ])
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+
+        // An eu file added by an overwrite of the us partition.
+        let region_is_us = Reference::new("region").equal_to(Datum::string("us"));
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .overwrite()
+            .overwrite_filter(region_is_us.clone())
+            .with_validate_added_files(true)
+            .add_data_files(vec![
+                partitioned_data_file(&table, "data/us-2.parquet", Literal::string("us"), 1),
+                partitioned_data_file(&table, "data/eu-2.parquet", Literal::string("eu"), 1),
+            ])
+            .apply(tx)
+            .unwrap();
+        let Err(err) = tx.commit(&catalog).await else {
+            panic!("adding rows of another partition should fail");
+        };
+        assert_eq!(err.kind(), ErrorKind::DataInvalid);
+        let message = format!("{err}");
+        assert!(message.contains("eu-2.parquet"), "{message}");
+        assert!(message.contains("region=eu"), "{message}");
+        let table = catalog.load_table(table.identifier()).await.unwrap();
+        assert_eq!(live_file_paths(&table).await, vec![
+            "data/eu-1.parquet",
+            "data/us-1.parquet"
+        ]);
+
+        // Without the validation, the eu file is added.
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .overwrite()
+            .overwrite_filter(region_is_us)
+            .add_data_files(vec![partitioned_data_file(
+                &table,
+                "data/eu-2.parquet",
+                Literal::string("eu"),
+                1,
+            )])
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+        assert_eq!(live_file_paths(&table).await, vec![
+            "data/eu-1.parquet",
+            "data/eu-2.parquet"
+        ]);
+    }
+}
@@ -0,0 +1,305 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::spec::{SnapshotRef, TableMetadata};
+use crate::table::Table;
+use crate::transaction::{ActionCommit, TransactionAction};
+use crate::{Error, ErrorKind, Result, TableRequirement, TableUpdate};
+
+/// A transaction action that removes one snapshot from the table metadata, such as a staged
+/// snapshot that should never be published.
+///
+/// Only a snapshot that no ref needs can be removed: it must neither be the target of a
+/// branch or tag nor an ancestor of one. Its statistics are removed with it. The files that
+/// are no longer referenced once it is removed are returned by
+/// [`orphaned_files`](Self::orphaned_files) and are left to the caller to delete.
+pub struct RemoveSnapshotAction {
+    snapshot_id: i64,
+}
+
+impl RemoveSnapshotAction {
+    pub(crate) fn new(snapshot_id: i64) -> Self {
+        Self { snapshot_id }
+    }
+
+    /// Returns the files of the removed snapshot that no other snapshot of `table` references:
+    /// its manifest list and statistics files, and the manifests and live data and delete
+    /// files that only it references. Relative paths are resolved against the table location.
+    pub async fn orphaned_files(&self, table: &Table) -> Result<Vec<String>> {
+        let metadata = table.metadata();
+        let snapshot = self.removed_snapshot(metadata)?;
+
+        let mut referenced_files = HashSet::new();
+        let mut loaded_manifests = HashSet::new();
+        for other in metadata
+            .snapshots()
+            .filter(|other| other.snapshot_id() != self.snapshot_id)
+        {
+            referenced_files.extend(
+                snapshot_files(table, other, &mut loaded_manifests)
+                    .await?
+                    .into_iter(),
+            );
+            referenced_files.extend(self.statistics_files(metadata, other.snapshot_id()));
+        }
+
+        let mut files = snapshot_files(table, snapshot, &mut HashSet::new()).await?;
+        files.extend(self.statistics_files(metadata, self.snapshot_id));
+        let mut seen = HashSet::new();
+        files.retain(|file| !referenced_files.contains(file) && seen.insert(file.clone()));
+        Ok(files)
+    }
+
+    /// Returns the snapshot to remove from `metadata`, checking that no ref needs it.
+    fn removed_snapshot<'a>(&self, metadata: &'a TableMetadata) -> Result<&'a SnapshotRef> {
+        let snapshot = metadata.snapshot_by_id(self.snapshot_id).ok_or_else(|| {
+            Error::new(
+                ErrorKind::DataInvalid,
+                format!("Snapshot {} not found", self.snapshot_id),
+            )
+        })?;
+        for (ref_name, reference) in &metadata.refs {
+            if metadata
+                .ancestors_of(reference.snapshot_id)
+                .any(|ancestor| ancestor.snapshot_id() == self.snapshot_id)
+            {
+                return Err(Error::new(
+                    ErrorKind::DataInvalid,
+                    format!(
+                        "Cannot remove snapshot {} referenced by ref {ref_name} or one of its ancestors",
+                        self.snapshot_id
+                    ),
+                ));
+            }
+        }
+        Ok(snapshot)
+    }
+
+    /// Returns the paths of the statistics files of the snapshot `snapshot_id`.
+    fn statistics_files(&self, metadata: &TableMetadata, snapshot_id: i64) -> Vec<String> {
+        metadata
+            .statistics_for_snapshot(snapshot_id)
+            .map(|statistics| metadata.resolve_location(&statistics.statistics_path))
+            .into_iter()
+            .chain(
+                metadata
+                    .partition_statistics_for_snapshot(snapshot_id)
+                    .map(|statistics| metadata.resolve_location(&statistics.statistics_path)),
+            )
+            .collect()
+    }
+}
+
+/// Returns the manifest list of `snapshot`, and its manifests and their live files that are
+/// not in `loaded_manifests` yet, adding them to it.
+async fn snapshot_files(
+    table: &Table,
+    snapshot: &SnapshotRef,
+    loaded_manifests: &mut HashSet<String>,
+) -> Result<Vec<String>> {
+    // A v1 snapshot listing its manifests inline has no manifest list file.
+    let metadata = table.metadata();
+    let mut files = match snapshot.manifests() {
+        Some(_) => vec![],
+        None => vec![metadata.resolve_location(snapshot.manifest_list())],
+    };
+    let manifest_list = snapshot
+        .load_manifest_list(table.file_io(), metadata)
+        .await?;
+    for manifest_file in manifest_list.entries() {
+        let manifest_path = metadata.resolve_location(&manifest_file.manifest_path);
+        if !loaded_manifests.insert(manifest_path.clone()) {
+            continue;
+        }
+        files.push(manifest_path);
+        let manifest = manifest_file
+            .load_manifest(table.file_io(), metadata)
+            .await?;
+        files.extend(
+            manifest
+                .entries()
+                .iter()
+                .filter(|entry| entry.is_alive())
+                .map(|entry| metadata.resolve_location(entry.file_path())),
+        );
+    }
+    Ok(files)
+}
+
+#[async_trait]
+impl TransactionAction for RemoveSnapshotAction {
+    async fn commit(self: Arc<Self>, table: &Table) -> Result<ActionCommit> {
+        let metadata = table.metadata();
+        self.removed_snapshot(metadata)?;
+
+        let mut updates = vec![TableUpdate::RemoveSnapshots {
+            snapshot_ids: vec![self.snapshot_id],
+        }];
+        if metadata.statistics_for_snapshot(self.snapshot_id).is_some() {
+            updates.push(TableUpdate::RemoveStatistics {
+                snapshot_id: self.snapshot_id,
+            });
+        }
+        if metadata
+            .partition_statistics_for_snapshot(self.snapshot_id)
+            .is_some()
+        {
+            updates.push(TableUpdate::RemovePartitionStatistics {
+                snapshot_id: self.snapshot_id,
+            });
+        }
+
+        // No ref may have moved onto the snapshot or one of its descendants since it was
+        // checked.
+        let mut requirements = vec![TableRequirement::UuidMatch {
+            uuid: metadata.uuid(),
+        }];
+        requirements.extend(metadata.refs.iter().map(|(ref_name, reference)| {
+            TableRequirement::RefSnapshotIdMatch {
+                r#ref: ref_name.clone(),
+                snapshot_id: Some(reference.snapshot_id),
+            }
+        }));
+
+        Ok(ActionCommit::new(updates, requirements))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::memory::tests::new_memory_catalog;
+    use crate::spec::{
+        MAIN_BRANCH, NestedField, PrimitiveType, Schema, SnapshotReference, SnapshotRetention, Type,
+    };
+    use crate::test_utils::fixtures::data_file;
+    use crate::transaction::{ApplyTransactionAction, Transaction};
+    use crate::{
+        Catalog, ErrorKind, NamespaceIdent, TableCommit, TableCreation, TableRequirement,
+        TableUpdate,
+    };
+
+    #[tokio::test]
+    async fn test_remove_staged_snapshot() {
+        let catalog = new_memory_catalog().await;
+        let namespace = NamespaceIdent::new("ns".to_string());
+        catalog
+            .create_namespace(&namespace, HashMap::new())
+            .await
+            .unwrap();
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+            ])
+            .build()
+            .unwrap();
+        let table = catalog
+            .create_table(
+                &namespace,
+                TableCreation::builder()
+                    .name("t".to_string())
+                    .schema(schema)
+                    .build(),
+            )
+            .await
+            .unwrap();
+
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .fast_append()
+            .add_data_files(vec![data_file(
+                format!("{}/data/published.parquet", table.metadata().location()),
+                1,
+            )])
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+        let published = table.metadata().current_snapshot().unwrap().clone();
+
+        // Stage a snapshot by committing it and moving the main branch back.
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .fast_append()
+            .add_data_files(vec![data_file(
+                format!("{}/data/staged.parquet", table.metadata().location()),
+                1,
+            )])
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+        let staged = table.metadata().current_snapshot().unwrap().clone();
+        let table = catalog
+            .update_table(
+                TableCommit::builder()
+                    .ident(table.identifier().clone())
+                    .requirements(vec![TableRequirement::UuidMatch {
+                        uuid: table.metadata().uuid(),
+                    }])
+                    .updates(vec![TableUpdate::SetSnapshotRef {
+                        ref_name: MAIN_BRANCH.to_string(),
+                        reference: SnapshotReference::new(
+                            published.snapshot_id(),
+                            SnapshotRetention::branch(None, None, None),
+                        ),
+                    }])
+                    .build(),
+            )
+            .await
+            .unwrap();
+
+        // The published snapshot is the target of the main branch.
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .remove_snapshot(published.snapshot_id())
+            .apply(tx)
+            .unwrap();
+        let Err(err) = tx.commit(&catalog).await else {
+            panic!("removing the current snapshot should fail");
+        };
+        assert_eq!(err.kind(), ErrorKind::DataInvalid);
+
+        let tx = Transaction::new(&table);
+        let action = tx.remove_snapshot(staged.snapshot_id());
+        let orphaned_files = action.orphaned_files(&table).await.unwrap();
+        let tx = action.apply(tx).unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+
+        assert!(
+            table
+                .metadata()
+                .snapshot_by_id(staged.snapshot_id())
+                .is_none()
+        );
+        assert_eq!(
+            table.metadata().current_snapshot_id(),
+            Some(published.snapshot_id())
+        );
+        assert_eq!(orphaned_files.len(), 3);
+        assert_eq!(orphaned_files[0], staged.manifest_list());
+        assert!(orphaned_files[1].ends_with(".avro"));
+        assert_eq!(
+            orphaned_files[2],
+            format!("{}/data/staged.parquet", table.metadata().location())
+        );
+    }
+}
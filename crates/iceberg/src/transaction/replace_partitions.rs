@@ -0,0 +1,254 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::error::Result;
+use crate::spec::{DataFile, ManifestContentType, ManifestEntry, Operation, Struct};
+use crate::table::Table;
+use crate::transaction::snapshot::{
+    DefaultManifestProcess, SnapshotProduceOperation, SnapshotProducer,
+};
+use crate::transaction::{ActionCommit, TransactionAction};
+use crate::{Error, ErrorKind};
+
+/// Snapshot summary property marking snapshots written by a [`ReplacePartitionsAction`].
+const REPLACE_PARTITIONS_PROPERTY: &str = "replace-partitions";
+
+/// ReplacePartitionsAction is a transaction action that overwrites the partitions of a table
+/// touched by new data files, also known as a dynamic partition overwrite.
+///
+/// Every data file of the current snapshot in the partition of one of the added files is
+/// removed, data files of all other partitions are left untouched. For an unpartitioned table
+/// this replaces all data files of the table.
+///
+/// The new snapshot has the `overwrite` operation.
+pub struct ReplacePartitionsAction {
+    fail_on_empty: bool,
+    // below are properties used to create SnapshotProducer when commit
+    commit_uuid: Option<Uuid>,
+    key_metadata: Option<Vec<u8>>,
+    snapshot_properties: HashMap<String, String>,
+    added_data_files: Vec<DataFile>,
+}
+
+impl ReplacePartitionsAction {
+    pub(crate) fn new() -> Self {
+        Self {
+            fail_on_empty: false,
+            commit_uuid: None,
+            key_metadata: None,
+            snapshot_properties: HashMap::default(),
+            added_data_files: vec![],
+        }
+    }
+
+    /// Set whether committing the action without any added data files fails instead of
+    /// leaving the table unchanged. Defaults to `false`.
+    pub fn with_fail_on_empty(mut self, v: bool) -> Self {
+        self.fail_on_empty = v;
+        self
+    }
+
+    /// Add the data files replacing the partitions they belong to.
+    pub fn add_data_files(mut self, data_files: impl IntoIterator<Item = DataFile>) -> Self {
+        self.added_data_files.extend(data_files);
+        self
+    }
+
+    /// Set commit UUID for the snapshot.
+    pub fn set_commit_uuid(mut self, commit_uuid: Uuid) -> Self {
+        self.commit_uuid = Some(commit_uuid);
+        self
+    }
+
+    /// Set key metadata for manifest files.
+    pub fn set_key_metadata(mut self, key_metadata: Vec<u8>) -> Self {
+        self.key_metadata = Some(key_metadata);
+        self
+    }
+
+    /// Set snapshot summary properties.
+    pub fn set_snapshot_properties(mut self, snapshot_properties: HashMap<String, String>) -> Self {
+        self.snapshot_properties = snapshot_properties;
+        self
+    }
+
+    /// Returns the live data files of the current snapshot in one of `partitions` of the
+    /// default partition spec.
+    async fn replaced_data_files(
+        table: &Table,
+        partitions: &HashSet<&Struct>,
+    ) -> Result<Vec<DataFile>> {
+        let Some(snapshot) = table.metadata().current_snapshot() else {
+            return Ok(vec![]);
+        };
+        let default_spec_id = table.metadata().default_partition_spec_id();
+
+        let manifest_list = snapshot
+            .load_manifest_list(table.file_io(), &table.metadata_ref())
+            .await?;
+        let mut replaced_data_files = vec![];
+        for manifest_file in manifest_list.entries() {
+            if manifest_file.content != ManifestContentType::Data
+                || manifest_file.partition_spec_id != default_spec_id
+            {
+                continue;
+            }
+            let manifest = manifest_file
+                .load_manifest(table.file_io(), table.metadata())
+                .await?;
+            replaced_data_files.extend(
+                manifest
+                    .entries()
+                    .iter()
+                    .filter(|entry| {
+                        entry.is_alive() && partitions.contains(entry.data_file().partition())
+                    })
+                    .map(|entry| entry.data_file().clone()),
+            );
+        }
+        Ok(replaced_data_files)
+    }
+}
+
+#[async_trait]
+impl TransactionAction for ReplacePartitionsAction {
+    async fn commit(self: Arc<Self>, table: &Table) -> Result<ActionCommit> {
+        if self.added_data_files.is_empty() {
+            if self.fail_on_empty {
+                return Err(Error::new(
+                    ErrorKind::PreconditionFailed,
+                    "No data files found when committing a replace partitions action",
+                ));
+            }
+            return Ok(ActionCommit::new(vec![], vec![]));
+        }
+
+        let mut snapshot_properties = self.snapshot_properties.clone();
+        snapshot_properties.insert(REPLACE_PARTITIONS_PROPERTY.to_string(), "true".to_string());
+
+        let snapshot_producer = SnapshotProducer::new(
+            table,
+            self.commit_uuid.unwrap_or_else(Uuid::now_v7),
+            self.key_metadata.clone(),
+            snapshot_properties,
+            self.added_data_files.clone(),
+        );
+        snapshot_producer.validate_added_data_files()?;
+        snapshot_producer.validate_duplicate_files().await?;
+
+        let partitions: HashSet<&Struct> = self
+            .added_data_files
+            .iter()
+            .map(DataFile::partition)
+            .collect();
+        let replaced_data_files = Self::replaced_data_files(table, &partitions).await?;
+
+        snapshot_producer
+            .with_removed_data_files(replaced_data_files)
+            .commit(ReplacePartitionsOperation, DefaultManifestProcess)
+            .await
+    }
+}
+
+struct ReplacePartitionsOperation;
+
+impl SnapshotProduceOperation for ReplacePartitionsOperation {
+    fn operation(&self) -> Operation {
+        Operation::Overwrite
+    }
+
+    async fn delete_entries(
+        &self,
+        _snapshot_produce: &SnapshotProducer<'_>,
+    ) -> Result<Vec<ManifestEntry>> {
+        Ok(vec![])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::ErrorKind;
+    use crate::spec::{Literal, Operation};
+    use crate::table::Table;
+    use crate::test_utils::fixtures::{live_file_paths, partitioned_data_file};
+    use crate::transaction::tests::make_v2_minimal_table;
+    use crate::transaction::{Transaction, TransactionAction};
+
+    async fn commit(table: Table, action: impl TransactionAction + 'static) -> Table {
+        let mut action_commit = Arc::new(action).commit(&table).await.unwrap();
+        Transaction::update_table_metadata(table, &action_commit.take_updates()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_replace_partitions_only_replaces_touched_partitions() {
+        let table = make_v2_minimal_table();
+        let files = vec![
+            partitioned_data_file(&table, "test/1.parquet", Literal::long(1), 1),
+            partitioned_data_file(&table, "test/2.parquet", Literal::long(1), 1),
+            partitioned_data_file(&table, "test/3.parquet", Literal::long(2), 1),
+        ];
+        let action = Transaction::new(&table).fast_append().add_data_files(files);
+        let table = commit(table, action).await;
+
+        let action = Transaction::new(&table)
+            .replace_partitions()
+            .add_data_files(vec![partitioned_data_file(
+                &table,
+                "test/4.parquet",
+                Literal::long(1),
+                1,
+            )]);
+        let table = commit(table, action).await;
+
+        assert_eq!(live_file_paths(&table).await, vec![
+            "test/3.parquet",
+            "test/4.parquet"
+        ]);
+        let summary = table.metadata().current_snapshot().unwrap().summary();
+        assert_eq!(summary.operation, Operation::Overwrite);
+        let properties = &summary.additional_properties;
+        assert_eq!(properties.get("replace-partitions").unwrap(), "true");
+        assert_eq!(properties.get("added-data-files").unwrap(), "1");
+        assert_eq!(properties.get("deleted-data-files").unwrap(), "2");
+    }
+
+    #[tokio::test]
+    async fn test_replace_partitions_with_empty_input() {
+        let table = make_v2_minimal_table();
+
+        let action = Transaction::new(&table).replace_partitions();
+        let mut action_commit = Arc::new(action).commit(&table).await.unwrap();
+        assert!(action_commit.take_updates().is_empty());
+        assert!(action_commit.take_requirements().is_empty());
+
+        let action = Transaction::new(&table)
+            .replace_partitions()
+            .with_fail_on_empty(true);
+        let Err(err) = Arc::new(action).commit(&table).await else {
+            panic!("committing without data files should fail");
+        };
+        assert_eq!(err.kind(), ErrorKind::PreconditionFailed);
+    }
+}
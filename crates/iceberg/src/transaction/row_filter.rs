@@ -0,0 +1,191 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::sync::Arc;
+
+use crate::error::Result;
+use crate::expr::visitors::expression_evaluator::ExpressionEvaluator;
+use crate::expr::visitors::inclusive_metrics_evaluator::InclusiveMetricsEvaluator;
+use crate::expr::visitors::inclusive_projection::InclusiveProjection;
+use crate::expr::visitors::strict_metrics_evaluator::StrictMetricsEvaluator;
+use crate::expr::visitors::strict_projection::StrictProjection;
+use crate::expr::{Bind, BoundPredicate, Predicate};
+use crate::spec::{DataFile, ManifestContentType, Schema};
+use crate::table::Table;
+use crate::{Error, ErrorKind};
+
+/// Returns the live data files of the current snapshot of `table` whose rows all match the
+/// row filter `filter`.
+///
+/// A data file matches when its partition or its column metrics show that all of its rows
+/// match the filter, and does not when none of its rows can match. Removing a data file with
+/// both matching and non-matching rows would require row-level deletes, so an error is
+/// returned for it instead.
+pub(crate) async fn data_files_matching_filter(
+    table: &Table,
+    filter: &Predicate,
+    case_sensitive: bool,
+) -> Result<Vec<DataFile>> {
+    let Some(snapshot) = table.metadata().current_snapshot() else {
+        return Ok(vec![]);
+    };
+    let bound_filter = filter
+        .clone()
+        .rewrite_not()
+        .bind(table.metadata().current_schema().clone(), case_sensitive)?;
+
+    let manifest_list = snapshot
+        .load_manifest_list(table.file_io(), &table.metadata_ref())
+        .await?;
+    let mut partition_filters = HashMap::new();
+    let mut matching_data_files = vec![];
+    for manifest_file in manifest_list.entries() {
+        if manifest_file.content != ManifestContentType::Data {
+            continue;
+        }
+        let (inclusive, strict) = match partition_filters.entry(manifest_file.partition_spec_id) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(partition_filters_of_spec(
+                table,
+                manifest_file.partition_spec_id,
+                &bound_filter,
+                case_sensitive,
+            )?),
+        };
+
+        let manifest = manifest_file
+            .load_manifest(table.file_io(), table.metadata())
+            .await?;
+        for entry in manifest.entries().iter().filter(|entry| entry.is_alive()) {
+            let data_file = entry.data_file();
+            if !inclusive.eval(data_file)?
+                || !InclusiveMetricsEvaluator::eval(&bound_filter, data_file, false)?
+            {
+                continue;
+            }
+            if !strict.eval(data_file)? && !StrictMetricsEvaluator::eval(&bound_filter, data_file)?
+            {
+                return Err(Error::new(
+                    ErrorKind::FeatureUnsupported,
+                    format!(
+                        "Cannot remove data file {} with only some rows matching filter {filter}, row-level deletes are not supported yet",
+                        data_file.file_path
+                    ),
+                ));
+            }
+            matching_data_files.push(data_file.clone());
+        }
+    }
+    Ok(matching_data_files)
+}
+
+/// Checks that all rows of each of `data_files` match the row filter `filter`, as shown by
+/// the partition or the column metrics of the file.
+///
+/// An error naming the first file that may hold rows not matching the filter is returned
+/// otherwise.
+pub(crate) fn validate_data_files_match_filter(
+    table: &Table,
+    filter: &Predicate,
+    case_sensitive: bool,
+    data_files: &[DataFile],
+) -> Result<()> {
+    let bound_filter = filter
+        .clone()
+        .rewrite_not()
+        .bind(table.metadata().current_schema().clone(), case_sensitive)?;
+
+    let mut partition_filters = HashMap::new();
+    for data_file in data_files {
+        let (_, strict) = match partition_filters.entry(data_file.partition_spec_id) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(partition_filters_of_spec(
+                table,
+                data_file.partition_spec_id,
+                &bound_filter,
+                case_sensitive,
+            )?),
+        };
+        if strict.eval(data_file)? || StrictMetricsEvaluator::eval(&bound_filter, data_file)? {
+            continue;
+        }
+
+        let mut err = Error::new(
+            ErrorKind::DataInvalid,
+            format!(
+                "Cannot add data file {} with rows that may not match filter {filter}",
+                data_file.file_path
+            ),
+        );
+        if let Some(partition_spec) = table
+            .metadata()
+            .partition_spec_by_id(data_file.partition_spec_id)
+            .filter(|partition_spec| !partition_spec.is_unpartitioned())
+        {
+            err = err.with_context(
+                "partition",
+                partition_spec.partition_to_path(
+                    data_file.partition(),
+                    table.metadata().current_schema().clone(),
+                ),
+            );
+        }
+        return Err(err);
+    }
+    Ok(())
+}
+
+/// Returns the evaluators of the inclusive and strict projections of `filter` onto the
+/// partition spec `spec_id`.
+fn partition_filters_of_spec(
+    table: &Table,
+    spec_id: i32,
+    filter: &BoundPredicate,
+    case_sensitive: bool,
+) -> Result<(ExpressionEvaluator, ExpressionEvaluator)> {
+    let partition_spec = table
+        .metadata()
+        .partition_spec_by_id(spec_id)
+        .ok_or_else(|| {
+            Error::new(
+                ErrorKind::DataInvalid,
+                format!("Could not find partition spec for id {spec_id}"),
+            )
+        })?;
+    let partition_type = partition_spec.partition_type(table.metadata().current_schema())?;
+    let partition_schema = Arc::new(
+        Schema::builder()
+            .with_schema_id(partition_spec.spec_id())
+            .with_fields(partition_type.fields().to_owned())
+            .build()?,
+    );
+
+    let inclusive = InclusiveProjection::new(partition_spec.clone())
+        .project(filter)?
+        .rewrite_not()
+        .bind(partition_schema.clone(), case_sensitive)?;
+    let strict = StrictProjection::new(partition_spec.clone())
+        .strict_project(filter)?
+        .rewrite_not()
+        .bind(partition_schema, case_sensitive)?;
+    Ok((
+        ExpressionEvaluator::new(inclusive),
+        ExpressionEvaluator::new(strict),
+    ))
+}
@@ -15,7 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::future::Future;
 use std::ops::RangeFrom;
 
@@ -24,9 +24,10 @@ use uuid::Uuid;
 use crate::error::Result;
 use crate::spec::{
     DataFile, DataFileFormat, FormatVersion, MAIN_BRANCH, ManifestContentType, ManifestEntry,
-    ManifestFile, ManifestListWriter, ManifestWriter, ManifestWriterBuilder, Operation, Snapshot,
-    SnapshotReference, SnapshotRetention, SnapshotSummaryCollector, Struct, StructType, Summary,
-    TableProperties, update_snapshot_summaries,
+    ManifestFile, ManifestListWriter, ManifestWriter, ManifestWriterBuilder, Operation,
+    PartitionSpec, Snapshot, SnapshotRef, SnapshotReference, SnapshotRetention,
+    SnapshotSummaryCollector, Summary, TableProperties, update_snapshot_summaries,
+    validate_partition_value,
 };
 use crate::table::Table;
 use crate::transaction::ActionCommit;
@@ -66,6 +67,13 @@ pub(crate) trait SnapshotProduceOperation: Send + Sync {
     /// which is stored in the snapshot metadata for tracking and auditing purposes.
     fn operation(&self) -> Operation;
 
+    /// Returns whether the new snapshot replaces all files of the table, in which case the
+    /// totals of the snapshot summary restart from zero instead of carrying forward the totals
+    /// of the previous snapshot.
+    fn truncates_table(&self) -> bool {
+        false
+    }
+
     /// Returns manifest entries that should be marked as deleted in the new snapshot.
     #[allow(unused)]
     fn delete_entries(
@@ -81,10 +89,34 @@ pub(crate) trait SnapshotProduceOperation: Send + Sync {
     /// - **Append operations**: Typically include all existing manifests
     /// - **Overwrite operations**: May exclude manifests for partitions being overwritten
     /// - **Delete operations**: May exclude manifests for partitions being deleted
+    ///
+    /// By default, all the manifests of the parent snapshot with added or existing files are
+    /// carried forward. The manifests referencing removed or refreshed data files are rewritten
+    /// by the [`SnapshotProducer`] afterwards.
     fn existing_manifest(
         &self,
         snapshot_produce: &SnapshotProducer<'_>,
-    ) -> impl Future<Output = Result<Vec<ManifestFile>>> + Send;
+    ) -> impl Future<Output = Result<Vec<ManifestFile>>> + Send {
+        async move {
+            let Some(snapshot) = snapshot_produce.parent_snapshot() else {
+                return Ok(vec![]);
+            };
+
+            let manifest_list = snapshot
+                .load_manifest_list(
+                    snapshot_produce.table.file_io(),
+                    &snapshot_produce.table.metadata_ref(),
+                )
+                .await?;
+
+            Ok(manifest_list
+                .entries()
+                .iter()
+                .filter(|entry| entry.has_added_files() || entry.has_existing_files())
+                .cloned()
+                .collect())
+        }
+    }
 }
 
 pub(crate) struct DefaultManifestProcess;
@@ -114,6 +146,14 @@ pub(crate) struct SnapshotProducer<'a> {
     key_metadata: Option<Vec<u8>>,
     snapshot_properties: HashMap<String, String>,
     added_data_files: Vec<DataFile>,
+    added_delete_files: Vec<DataFile>,
+    removed_data_files: Vec<DataFile>,
+    // Data files of the table whose entries are rewritten with these metadata, such as metrics.
+    refreshed_data_files: Vec<DataFile>,
+    // Whether a snapshot without any change of the files of the table may be produced.
+    allow_empty: bool,
+    // The branch the new snapshot is committed to.
+    target_branch: String,
     // A counter used to generate unique manifest file names.
     // It starts from 0 and increments for each new manifest file.
     // Note: This counter is limited to the range of (0..u64::MAX).
@@ -135,10 +175,79 @@ impl<'a> SnapshotProducer<'a> {
             key_metadata,
             snapshot_properties,
             added_data_files,
+            added_delete_files: vec![],
+            removed_data_files: vec![],
+            refreshed_data_files: vec![],
+            allow_empty: false,
+            target_branch: MAIN_BRANCH.to_string(),
             manifest_counter: (0..),
         }
     }
 
+    /// Allows producing a snapshot that neither adds nor removes files, which only keeps the
+    /// manifests of the current snapshot.
+    pub(crate) fn with_allow_empty(mut self) -> Self {
+        self.allow_empty = true;
+        self
+    }
+
+    /// Sets the branch the new snapshot is committed to, `main` by default.
+    ///
+    /// A branch that doesn't exist yet is created off the current snapshot of the table.
+    pub(crate) fn with_target_branch(mut self, target_branch: impl Into<String>) -> Self {
+        self.target_branch = target_branch.into();
+        self
+    }
+
+    /// Returns the snapshot the new snapshot is based on: the head of the target branch, or
+    /// the current snapshot of the table when the branch doesn't exist yet.
+    pub(crate) fn parent_snapshot(&self) -> Option<&'a SnapshotRef> {
+        let metadata = self.table.metadata();
+        if self.target_branch == MAIN_BRANCH {
+            return metadata.current_snapshot();
+        }
+        metadata
+            .snapshot_by_ref(&self.target_branch)
+            .or_else(|| metadata.current_snapshot())
+    }
+
+    /// Sets the data files that are removed from the table by the new snapshot.
+    ///
+    /// Manifests referencing any of these files are rewritten, recording the files as
+    /// `Deleted` entries and carrying the remaining entries forward as `Existing`.
+    pub(crate) fn with_removed_data_files(
+        mut self,
+        removed_data_files: impl IntoIterator<Item = DataFile>,
+    ) -> Self {
+        self.removed_data_files.extend(removed_data_files);
+        self
+    }
+
+    /// Sets the data files of the table whose manifest entries are replaced by these ones,
+    /// matched by file path.
+    ///
+    /// Manifests referencing any of these files are rewritten, carrying all their entries
+    /// forward as `Existing` with their sequence numbers, so the files stay live.
+    pub(crate) fn with_refreshed_data_files(
+        mut self,
+        refreshed_data_files: impl IntoIterator<Item = DataFile>,
+    ) -> Self {
+        self.refreshed_data_files.extend(refreshed_data_files);
+        self
+    }
+
+    /// Sets the position delete files and deletion vectors that are added to the table by the
+    /// new snapshot.
+    ///
+    /// They are written to delete manifests, one per partition spec of the delete files.
+    pub(crate) fn with_added_delete_files(
+        mut self,
+        added_delete_files: impl IntoIterator<Item = DataFile>,
+    ) -> Self {
+        self.added_delete_files.extend(added_delete_files);
+        self
+    }
+
     pub(crate) fn validate_added_data_files(&self) -> Result<()> {
         for data_file in &self.added_data_files {
             if data_file.content_type() != crate::spec::DataContentType::Data {
@@ -154,7 +263,7 @@ impl<'a> SnapshotProducer<'a> {
                     "Data file partition spec id does not match table default partition spec id",
                 ));
             }
-            Self::validate_partition_value(
+            validate_partition_value(
                 data_file.partition(),
                 self.table.metadata().default_partition_type(),
             )?;
@@ -164,25 +273,26 @@ impl<'a> SnapshotProducer<'a> {
     }
 
     pub(crate) async fn validate_duplicate_files(&self) -> Result<()> {
-        let new_files: HashSet<&str> = self
+        let metadata = self.table.metadata();
+        let new_files: HashSet<String> = self
             .added_data_files
             .iter()
-            .map(|df| df.file_path.as_str())
+            .map(|df| metadata.resolve_location(&df.file_path))
             .collect();
 
         let mut referenced_files = Vec::new();
-        if let Some(current_snapshot) = self.table.metadata().current_snapshot() {
-            let manifest_list = current_snapshot
+        if let Some(parent_snapshot) = self.parent_snapshot() {
+            let manifest_list = parent_snapshot
                 .load_manifest_list(self.table.file_io(), &self.table.metadata_ref())
                 .await?;
             for manifest_list_entry in manifest_list.entries() {
                 let manifest = manifest_list_entry
-                    .load_manifest(self.table.file_io())
+                    .load_manifest(self.table.file_io(), self.table.metadata())
                     .await?;
                 for entry in manifest.entries() {
-                    let file_path = entry.file_path();
-                    if new_files.contains(file_path) && entry.is_alive() {
-                        referenced_files.push(file_path.to_string());
+                    let file_path = metadata.resolve_location(entry.file_path());
+                    if new_files.contains(&file_path) && entry.is_alive() {
+                        referenced_files.push(file_path);
                     }
                 }
             }
@@ -224,6 +334,20 @@ impl<'a> SnapshotProducer<'a> {
     }
 
     fn new_manifest_writer(&mut self, content: ManifestContentType) -> Result<ManifestWriter> {
+        let partition_spec = self
+            .table
+            .metadata()
+            .default_partition_spec()
+            .as_ref()
+            .clone();
+        self.new_manifest_writer_for_spec(content, partition_spec)
+    }
+
+    fn new_manifest_writer_for_spec(
+        &mut self,
+        content: ManifestContentType,
+        partition_spec: PartitionSpec,
+    ) -> Result<ManifestWriter> {
         let new_manifest_path = format!(
             "{}/{}/{}-m{}.{}",
             self.table.metadata().location(),
@@ -238,11 +362,7 @@ impl<'a> SnapshotProducer<'a> {
             Some(self.snapshot_id),
             self.key_metadata.clone(),
             self.table.metadata().current_schema().clone(),
-            self.table
-                .metadata()
-                .default_partition_spec()
-                .as_ref()
-                .clone(),
+            partition_spec,
         );
         match self.table.metadata().format_version() {
             FormatVersion::V1 => Ok(builder.build_v1()),
@@ -257,37 +377,6 @@ impl<'a> SnapshotProducer<'a> {
         }
     }
 
-    // Check if the partition value is compatible with the partition type.
-    fn validate_partition_value(
-        partition_value: &Struct,
-        partition_type: &StructType,
-    ) -> Result<()> {
-        if partition_value.fields().len() != partition_type.fields().len() {
-            return Err(Error::new(
-                ErrorKind::DataInvalid,
-                "Partition value is not compatible with partition type",
-            ));
-        }
-
-        for (value, field) in partition_value.fields().iter().zip(partition_type.fields()) {
-            let field = field.field_type.as_primitive_type().ok_or_else(|| {
-                Error::new(
-                    ErrorKind::Unexpected,
-                    "Partition field should only be primitive type.",
-                )
-            })?;
-            if let Some(value) = value
-                && !field.compatible(&value.as_primitive_literal().unwrap())
-            {
-                return Err(Error::new(
-                    ErrorKind::DataInvalid,
-                    "Partition value is not compatible partition type",
-                ));
-            }
-        }
-        Ok(())
-    }
-
     // Write manifest file for added data files and return the ManifestFile for ManifestList.
     async fn write_added_manifest(&mut self) -> Result<ManifestFile> {
         let added_data_files = std::mem::take(&mut self.added_data_files);
@@ -319,17 +408,193 @@ impl<'a> SnapshotProducer<'a> {
         writer.write_manifest_file().await
     }
 
-    async fn manifest_file<OP: SnapshotProduceOperation, MP: ManifestProcess>(
+    // Write delete manifests for added delete files, one per partition spec, and return the
+    // ManifestFiles for ManifestList.
+    async fn write_added_delete_manifests(&mut self) -> Result<Vec<ManifestFile>> {
+        let mut delete_files_by_spec: BTreeMap<i32, Vec<DataFile>> = BTreeMap::new();
+        for delete_file in &self.added_delete_files {
+            delete_files_by_spec
+                .entry(delete_file.partition_spec_id)
+                .or_default()
+                .push(delete_file.clone());
+        }
+
+        let mut manifest_files = Vec::with_capacity(delete_files_by_spec.len());
+        for (spec_id, delete_files) in delete_files_by_spec {
+            let partition_spec = self
+                .table
+                .metadata()
+                .partition_spec_by_id(spec_id)
+                .ok_or_else(|| {
+                    Error::new(
+                        ErrorKind::DataInvalid,
+                        format!(
+                            "Partition spec {spec_id} of delete files not found in table metadata"
+                        ),
+                    )
+                })?
+                .as_ref()
+                .clone();
+            let mut writer =
+                self.new_manifest_writer_for_spec(ManifestContentType::Deletes, partition_spec)?;
+            for delete_file in delete_files {
+                writer.add_entry(
+                    ManifestEntry::builder()
+                        .status(crate::spec::ManifestStatus::Added)
+                        .data_file(delete_file)
+                        .build(),
+                )?;
+            }
+            manifest_files.push(writer.write_manifest_file().await?);
+        }
+        Ok(manifest_files)
+    }
+
+    // Rewrite the data manifests that reference removed or refreshed data files, so that the removed
+    // files are recorded as deleted entries of the new snapshot and the refreshed files replace their
+    // existing entries. Manifests without removed or refreshed files are kept as is.
+    //
+    // Files are matched by their resolved paths, while the rewritten entries keep their paths as
+    // stored in the table.
+    async fn rewrite_manifests_with_changed_files(
+        &mut self,
+        manifests: Vec<ManifestFile>,
+    ) -> Result<Vec<ManifestFile>> {
+        let metadata = self.table.metadata_ref();
+        let removed_files: HashSet<String> = self
+            .removed_data_files
+            .iter()
+            .map(|data_file| metadata.resolve_location(&data_file.file_path))
+            .collect();
+        let refreshed_files: HashMap<String, DataFile> = self
+            .refreshed_data_files
+            .iter()
+            .map(|data_file| {
+                (
+                    metadata.resolve_location(&data_file.file_path),
+                    data_file.clone(),
+                )
+            })
+            .collect();
+        let mut found_files = HashSet::new();
+        let mut found_data_files = Vec::with_capacity(removed_files.len());
+        let mut found_refreshed_files = HashSet::new();
+        let mut manifest_files = Vec::with_capacity(manifests.len());
+
+        for manifest_file in manifests {
+            if manifest_file.content != ManifestContentType::Data {
+                manifest_files.push(manifest_file);
+                continue;
+            }
+
+            let manifest = manifest_file
+                .load_manifest(self.table.file_io(), self.table.metadata())
+                .await?;
+            if !manifest.entries().iter().any(|entry| {
+                let file_path = metadata.resolve_location(entry.file_path());
+                entry.is_alive()
+                    && (removed_files.contains(&file_path)
+                        || refreshed_files.contains_key(&file_path))
+            }) {
+                manifest_files.push(manifest_file);
+                continue;
+            }
+
+            let partition_spec = self
+                .table
+                .metadata()
+                .partition_spec_by_id(manifest_file.partition_spec_id)
+                .ok_or_else(|| {
+                    Error::new(
+                        ErrorKind::DataInvalid,
+                        format!(
+                            "Partition spec {} of manifest {} not found in table metadata",
+                            manifest_file.partition_spec_id, manifest_file.manifest_path
+                        ),
+                    )
+                })?
+                .as_ref()
+                .clone();
+            let mut writer =
+                self.new_manifest_writer_for_spec(ManifestContentType::Data, partition_spec)?;
+            for entry in manifest.entries() {
+                if !entry.is_alive() {
+                    continue;
+                }
+                let file_path = metadata.resolve_location(entry.file_path());
+                if removed_files.contains(&file_path) {
+                    if found_files.insert(file_path) {
+                        found_data_files.push(entry.data_file().clone());
+                    }
+                    writer.add_delete_entry(entry.as_ref().clone())?;
+                } else if let Some(data_file) = refreshed_files.get(&file_path) {
+                    found_refreshed_files.insert(file_path);
+                    let mut entry = entry.as_ref().clone();
+                    entry.data_file = DataFile {
+                        file_path: entry.data_file.file_path,
+                        ..data_file.clone()
+                    };
+                    writer.add_existing_entry(entry)?;
+                } else {
+                    writer.add_existing_entry(entry.as_ref().clone())?;
+                }
+            }
+            manifest_files.push(writer.write_manifest_file().await?);
+        }
+
+        let missing_files: Vec<&str> = removed_files
+            .iter()
+            .filter(|file_path| !found_files.contains(*file_path))
+            .map(String::as_str)
+            .collect();
+        if !missing_files.is_empty() {
+            return Err(Error::new(
+                ErrorKind::DataInvalid,
+                format!(
+                    "Cannot remove files that are not referenced by table, files: {}",
+                    missing_files.join(", ")
+                ),
+            ));
+        }
+        let missing_files: Vec<&str> = refreshed_files
+            .keys()
+            .filter(|file_path| !found_refreshed_files.contains(*file_path))
+            .map(String::as_str)
+            .collect();
+        if !missing_files.is_empty() {
+            return Err(Error::new(
+                ErrorKind::DataInvalid,
+                format!(
+                    "Cannot refresh files that are not referenced by table, files: {}",
+                    missing_files.join(", ")
+                ),
+            ));
+        }
+
+        // Summarize the removed files as recorded in the table rather than as passed by the
+        // caller, whose record counts and sizes may be incomplete.
+        self.removed_data_files = found_data_files;
+        Ok(manifest_files)
+    }
+
+    // Returns the existing manifests to keep in the new snapshot, rewriting the ones that reference
+    // removed or refreshed data files.
+    async fn existing_manifest_files<OP: SnapshotProduceOperation>(
         &mut self,
         snapshot_produce_operation: &OP,
-        manifest_process: &MP,
     ) -> Result<Vec<ManifestFile>> {
         // Assert current snapshot producer contains new content to add to new snapshot.
         //
         // TODO: Allowing snapshot property setup with no added data files is a workaround.
         // We should clean it up after all necessary actions are supported.
         // For details, please refer to https://github.com/apache/iceberg-rust/issues/1548
-        if self.added_data_files.is_empty() && self.snapshot_properties.is_empty() {
+        if self.added_data_files.is_empty()
+            && self.added_delete_files.is_empty()
+            && self.removed_data_files.is_empty()
+            && self.refreshed_data_files.is_empty()
+            && self.snapshot_properties.is_empty()
+            && !self.allow_empty
+        {
             return Err(Error::new(
                 ErrorKind::PreconditionFailed,
                 "No added data files or added snapshot properties found when write a manifest file",
@@ -337,13 +602,28 @@ impl<'a> SnapshotProducer<'a> {
         }
 
         let existing_manifests = snapshot_produce_operation.existing_manifest(self).await?;
-        let mut manifest_files = existing_manifests;
+        if self.removed_data_files.is_empty() && self.refreshed_data_files.is_empty() {
+            Ok(existing_manifests)
+        } else {
+            self.rewrite_manifests_with_changed_files(existing_manifests)
+                .await
+        }
+    }
 
+    async fn manifest_file<MP: ManifestProcess>(
+        &mut self,
+        mut manifest_files: Vec<ManifestFile>,
+        manifest_process: &MP,
+    ) -> Result<Vec<ManifestFile>> {
         // Process added entries.
         if !self.added_data_files.is_empty() {
             let added_manifest = self.write_added_manifest().await?;
             manifest_files.push(added_manifest);
         }
+        if !self.added_delete_files.is_empty() {
+            let added_delete_manifests = self.write_added_delete_manifests().await?;
+            manifest_files.extend(added_delete_manifests);
+        }
 
         // # TODO
         // Support process delete entries.
@@ -383,10 +663,31 @@ impl<'a> SnapshotProducer<'a> {
             );
         }
 
-        let previous_snapshot = table_metadata
-            .snapshot_by_id(self.snapshot_id)
-            .and_then(|snapshot| snapshot.parent_snapshot_id())
-            .and_then(|parent_id| table_metadata.snapshot_by_id(parent_id));
+        for delete_file in &self.added_delete_files {
+            let partition_spec = table_metadata
+                .partition_spec_by_id(delete_file.partition_spec_id)
+                .cloned()
+                .unwrap_or_else(|| table_metadata.default_partition_spec().clone());
+            summary_collector.add_file(
+                delete_file,
+                table_metadata.current_schema().clone(),
+                partition_spec,
+            );
+        }
+
+        for data_file in &self.removed_data_files {
+            let partition_spec = table_metadata
+                .partition_spec_by_id(data_file.partition_spec_id)
+                .cloned()
+                .unwrap_or_else(|| table_metadata.default_partition_spec().clone());
+            summary_collector.remove_file(
+                data_file,
+                table_metadata.current_schema().clone(),
+                partition_spec,
+            );
+        }
+
+        let previous_snapshot = self.parent_snapshot();
 
         let mut additional_properties = summary_collector.build();
         additional_properties.extend(self.snapshot_properties.clone());
@@ -399,7 +700,7 @@ impl<'a> SnapshotProducer<'a> {
         update_snapshot_summaries(
             summary,
             previous_snapshot.map(|s| s.summary()),
-            snapshot_produce_operation.operation() == Operation::Overwrite,
+            snapshot_produce_operation.truncates_table(),
         )
     }
 
@@ -421,7 +722,21 @@ impl<'a> SnapshotProducer<'a> {
         snapshot_produce_operation: OP,
         process: MP,
     ) -> Result<ActionCommit> {
+        let branch_ref = self.table.metadata().refs.get(&self.target_branch);
+        if branch_ref.is_some_and(|reference| !reference.is_branch()) {
+            return Err(Error::new(
+                ErrorKind::DataInvalid,
+                format!(
+                    "Cannot commit a snapshot to {}, it is a tag and not a branch",
+                    self.target_branch
+                ),
+            ));
+        }
+
         let manifest_list_path = self.generate_manifest_list_file_path(0);
+        let parent_snapshot_id = self
+            .parent_snapshot()
+            .map(|snapshot| snapshot.snapshot_id());
         let next_seq_num = self.table.metadata().next_sequence_number();
         let first_row_id = self.table.metadata().next_row_id();
         let mut manifest_list_writer = match self.table.metadata().format_version() {
@@ -430,14 +745,14 @@ impl<'a> SnapshotProducer<'a> {
                     .file_io()
                     .new_output(manifest_list_path.clone())?,
                 self.snapshot_id,
-                self.table.metadata().current_snapshot_id(),
+                parent_snapshot_id,
             ),
             FormatVersion::V2 => ManifestListWriter::v2(
                 self.table
                     .file_io()
                     .new_output(manifest_list_path.clone())?,
                 self.snapshot_id,
-                self.table.metadata().current_snapshot_id(),
+                parent_snapshot_id,
                 next_seq_num,
             ),
             FormatVersion::V3 => ManifestListWriter::v3(
@@ -445,32 +760,35 @@ impl<'a> SnapshotProducer<'a> {
                     .file_io()
                     .new_output(manifest_list_path.clone())?,
                 self.snapshot_id,
-                self.table.metadata().current_snapshot_id(),
+                parent_snapshot_id,
                 next_seq_num,
                 Some(first_row_id),
             ),
         };
 
-        // Calling self.summary() before self.manifest_file() is important because self.added_data_files
-        // will be set to an empty vec after self.manifest_file() returns, resulting in an empty summary
-        // being generated.
+        let existing_manifests = self
+            .existing_manifest_files(&snapshot_produce_operation)
+            .await?;
+
+        // Calling self.summary() between self.existing_manifest_files() and self.manifest_file() is
+        // important because the removed data files are replaced by their manifest entries while the
+        // manifests are rewritten, and self.added_data_files will be set to an empty vec after
+        // self.manifest_file() returns.
         let summary = self.summary(&snapshot_produce_operation).map_err(|err| {
             Error::new(ErrorKind::Unexpected, "Failed to create snapshot summary.").with_source(err)
         })?;
 
-        let new_manifests = self
-            .manifest_file(&snapshot_produce_operation, &process)
-            .await?;
+        let new_manifests = self.manifest_file(existing_manifests, &process).await?;
 
         manifest_list_writer.add_manifests(new_manifests.into_iter())?;
         let writer_next_row_id = manifest_list_writer.next_row_id();
         manifest_list_writer.close().await?;
 
-        let commit_ts = chrono::Utc::now().timestamp_millis();
+        let commit_ts = self.table.clock().now_ms();
         let new_snapshot = Snapshot::builder()
             .with_manifest_list(manifest_list_path)
             .with_snapshot_id(self.snapshot_id)
-            .with_parent_snapshot_id(self.table.metadata().current_snapshot_id())
+            .with_parent_snapshot_id(parent_snapshot_id)
             .with_sequence_number(next_seq_num)
             .with_summary(summary)
             .with_schema_id(self.table.metadata().current_schema_id())
@@ -490,21 +808,30 @@ impl<'a> SnapshotProducer<'a> {
                 snapshot: new_snapshot,
             },
             TableUpdate::SetSnapshotRef {
-                ref_name: MAIN_BRANCH.to_string(),
+                ref_name: self.target_branch.clone(),
                 reference: SnapshotReference::new(
                     self.snapshot_id,
-                    SnapshotRetention::branch(None, None, None),
+                    branch_ref.map_or(SnapshotRetention::branch(None, None, None), |reference| {
+                        reference.retention.clone()
+                    }),
                 ),
             },
         ];
 
+        // The head of the target branch must not have moved, or the branch must still not
+        // exist when it's created by this snapshot.
+        let branch_snapshot_id = if self.target_branch == MAIN_BRANCH {
+            self.table.metadata().current_snapshot_id()
+        } else {
+            branch_ref.map(|reference| reference.snapshot_id)
+        };
         let requirements = vec![
             TableRequirement::UuidMatch {
                 uuid: self.table.metadata().uuid(),
             },
             TableRequirement::RefSnapshotIdMatch {
-                r#ref: MAIN_BRANCH.to_string(),
-                snapshot_id: self.table.metadata().current_snapshot_id(),
+                r#ref: self.target_branch.clone(),
+                snapshot_id: branch_snapshot_id,
             },
         ];
 
@@ -15,27 +15,40 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use async_trait::async_trait;
 
+use crate::spec::TableProperties;
 use crate::table::Table;
 use crate::transaction::action::{ActionCommit, TransactionAction};
 use crate::{Error, ErrorKind, Result, TableUpdate};
 
-/// A transaction action that sets or updates the location of a table.
+/// A transaction action that sets or updates the location of a table, such as to migrate
+/// it to another storage.
 ///
-/// This action is used to explicitly set a new metadata location during a transaction,
-/// typically as part of advanced commit or recovery flows. The location is optional until
-/// explicitly set via [`set_location`].
+/// Files written after the update go under the new location, while the existing files are
+/// neither moved nor rewritten: they stay readable at their absolute paths, and copying them
+/// is left to the caller. The location is optional until explicitly set via [`set_location`].
+///
+/// By default, the paths of the properties
+/// [`PROPERTY_WRITE_DATA_PATH`](TableProperties::PROPERTY_WRITE_DATA_PATH) and
+/// [`PROPERTY_WRITE_METADATA_PATH`](TableProperties::PROPERTY_WRITE_METADATA_PATH) under the
+/// old location are moved under the new one too, see
+/// [`rewrite_write_paths`](Self::rewrite_write_paths).
 pub struct UpdateLocationAction {
     location: Option<String>,
+    rewrite_write_paths: bool,
 }
 
 impl UpdateLocationAction {
     /// Creates a new [`UpdateLocationAction`] with no location set.
     pub fn new() -> Self {
-        UpdateLocationAction { location: None }
+        UpdateLocationAction {
+            location: None,
+            rewrite_write_paths: true,
+        }
     }
 
     /// Sets the target location for this action and returns the updated instance.
@@ -51,6 +64,21 @@ impl UpdateLocationAction {
         self.location = Some(location);
         self
     }
+
+    /// Sets whether the data and metadata write paths of the table properties that are under
+    /// the old location are moved under the new location, keeping their path relative to the
+    /// table location. Write paths elsewhere are kept either way. Defaults to `true`.
+    pub fn rewrite_write_paths(mut self, rewrite_write_paths: bool) -> Self {
+        self.rewrite_write_paths = rewrite_write_paths;
+        self
+    }
+}
+
+/// Returns `path` moved from under `old_location` to under `new_location`, or `None` if it is
+/// not under `old_location`.
+fn relocate_path(path: &str, old_location: &str, new_location: &str) -> Option<String> {
+    let relative = path.strip_prefix(old_location)?;
+    (relative.is_empty() || relative.starts_with('/')).then(|| format!("{new_location}{relative}"))
 }
 
 impl Default for UpdateLocationAction {
@@ -61,16 +89,36 @@ impl Default for UpdateLocationAction {
 
 #[async_trait]
 impl TransactionAction for UpdateLocationAction {
-    async fn commit(self: Arc<Self>, _table: &Table) -> Result<ActionCommit> {
-        let updates: Vec<TableUpdate>;
-        if let Some(location) = self.location.clone() {
-            updates = vec![TableUpdate::SetLocation { location }];
-        } else {
+    async fn commit(self: Arc<Self>, table: &Table) -> Result<ActionCommit> {
+        let Some(location) = self.location.clone() else {
             return Err(Error::new(
                 ErrorKind::DataInvalid,
                 "Location is not set for UpdateLocationAction!",
             ));
+        };
+        let location = location.trim_end_matches('/').to_string();
+
+        let mut updates = vec![];
+        if self.rewrite_write_paths {
+            let old_location = table.metadata().location().trim_end_matches('/');
+            let properties = table.metadata().properties();
+            let write_paths: HashMap<String, String> = [
+                TableProperties::PROPERTY_WRITE_DATA_PATH,
+                TableProperties::PROPERTY_WRITE_METADATA_PATH,
+            ]
+            .into_iter()
+            .filter_map(|key| {
+                let path = relocate_path(properties.get(key)?, old_location, &location)?;
+                Some((key.to_string(), path))
+            })
+            .collect();
+            if !write_paths.is_empty() {
+                updates.push(TableUpdate::SetProperties {
+                    updates: write_paths,
+                });
+            }
         }
+        updates.push(TableUpdate::SetLocation { location });
 
         Ok(ActionCommit::new(updates, vec![]))
     }
@@ -78,12 +126,44 @@ impl TransactionAction for UpdateLocationAction {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int64Type;
+    use arrow_array::{ArrayRef, Int64Array, RecordBatch};
     use as_any::Downcast;
+    use futures::TryStreamExt;
 
+    use crate::arrow::schema_to_arrow_schema;
+    use crate::memory::tests::new_memory_catalog;
+    use crate::spec::{DataFile, NestedField, PrimitiveType, Schema, TableProperties, Type};
+    use crate::table::Table;
+    use crate::test_utils::fixtures::{data_file, write_parquet_file};
     use crate::transaction::Transaction;
     use crate::transaction::action::ApplyTransactionAction;
     use crate::transaction::tests::make_v2_table;
     use crate::transaction::update_location::UpdateLocationAction;
+    use crate::writer::file_writer::location_generator::{
+        DefaultLocationGenerator, LocationGenerator,
+    };
+    use crate::{Catalog, NamespaceIdent, TableCreation};
+
+    async fn write_data_file(table: &Table, id: i64) -> DataFile {
+        let schema = Arc::new(schema_to_arrow_schema(table.metadata().current_schema()).unwrap());
+        let batch = RecordBatch::try_new(schema, vec![
+            Arc::new(Int64Array::from(vec![id])) as ArrayRef
+        ])
+        .unwrap();
+        let path = DefaultLocationGenerator::new(table.metadata().clone())
+            .unwrap()
+            .generate_location(None, &format!("f{id}.parquet"));
+        let file_size_in_bytes = write_parquet_file(table.file_io(), &path, &batch, None).await;
+        DataFile {
+            file_size_in_bytes,
+            ..data_file(path, 1)
+        }
+    }
 
     #[test]
     fn test_set_location() {
@@ -106,4 +186,117 @@ mod tests {
             Some(String::from("s3://bucket/prefix/new_table"))
         )
     }
+
+    #[tokio::test]
+    async fn test_set_location_moves_future_writes() {
+        let catalog = new_memory_catalog().await;
+        let namespace = NamespaceIdent::new("ns".to_string());
+        catalog
+            .create_namespace(&namespace, HashMap::new())
+            .await
+            .unwrap();
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+            ])
+            .build()
+            .unwrap();
+        let table = catalog
+            .create_table(
+                &namespace,
+                TableCreation::builder()
+                    .name("t".to_string())
+                    .schema(schema)
+                    .build(),
+            )
+            .await
+            .unwrap();
+        let old_location = table.metadata().location().to_string();
+        let new_location = format!("{old_location}-migrated");
+
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .update_table_properties()
+            .set(
+                TableProperties::PROPERTY_WRITE_DATA_PATH.to_string(),
+                format!("{old_location}/custom-data"),
+            )
+            .set(
+                TableProperties::PROPERTY_WRITE_METADATA_PATH.to_string(),
+                "s3://elsewhere/metadata".to_string(),
+            )
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+        let old_file = write_data_file(&table, 1).await;
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .fast_append()
+            .add_data_files(vec![old_file.clone()])
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .update_location()
+            .set_location(format!("{new_location}/"))
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+        assert_eq!(table.metadata().location(), new_location);
+        let properties = table.metadata().properties();
+        assert_eq!(
+            properties.get(TableProperties::PROPERTY_WRITE_DATA_PATH),
+            Some(&format!("{new_location}/custom-data"))
+        );
+        // Write paths outside of the old location are kept.
+        assert_eq!(
+            properties.get(TableProperties::PROPERTY_WRITE_METADATA_PATH),
+            Some(&"s3://elsewhere/metadata".to_string())
+        );
+
+        let new_file = write_data_file(&table, 2).await;
+        assert!(
+            new_file
+                .file_path()
+                .starts_with(&format!("{new_location}/custom-data/"))
+        );
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .fast_append()
+            .add_data_files(vec![new_file])
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+
+        // The files written before the update are read at their old paths.
+        assert!(
+            old_file
+                .file_path()
+                .starts_with(&format!("{old_location}/custom-data/"))
+        );
+        let batches: Vec<_> = table
+            .scan()
+            .build()
+            .unwrap()
+            .to_arrow()
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        let mut ids: Vec<i64> = batches
+            .iter()
+            .flat_map(|batch| {
+                batch
+                    .column(0)
+                    .as_primitive::<Int64Type>()
+                    .values()
+                    .to_vec()
+            })
+            .collect();
+        ids.sort();
+        assert_eq!(ids, vec![1, 2]);
+    }
 }
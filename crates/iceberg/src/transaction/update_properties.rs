@@ -20,6 +20,7 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 
+use crate::spec::TableProperties;
 use crate::table::Table;
 use crate::transaction::action::{ActionCommit, TransactionAction};
 use crate::{Error, ErrorKind, Result, TableUpdate};
@@ -29,7 +30,9 @@ use crate::{Error, ErrorKind, Result, TableUpdate};
 /// This action is used to modify key-value pairs in a table's metadata
 /// properties during a transaction. It supports setting new values for existing keys
 /// or adding new keys, as well as removing existing keys. Each key can only be updated
-/// or removed in a single action, not both.
+/// or removed in a single action, not both. Values of known table properties, such as
+/// `commit.retry.num-retries`, must parse as their type, and removing a missing key does
+/// nothing.
 pub struct UpdatePropertiesAction {
     updates: HashMap<String, String>,
     removals: HashSet<String>,
@@ -91,6 +94,10 @@ impl TransactionAction for UpdatePropertiesAction {
             ));
         }
 
+        TableProperties::try_from(&self.updates).map_err(|e| {
+            Error::new(ErrorKind::DataInvalid, "Invalid table properties").with_source(e)
+        })?;
+
         let updates: Vec<TableUpdate> = vec![
             TableUpdate::SetProperties {
                 updates: self.updates.clone(),
@@ -110,10 +117,13 @@ mod tests {
 
     use as_any::Downcast;
 
+    use crate::memory::tests::new_memory_catalog;
+    use crate::spec::{NestedField, PrimitiveType, Schema, TableProperties, Type};
     use crate::transaction::Transaction;
     use crate::transaction::action::ApplyTransactionAction;
     use crate::transaction::tests::make_v2_table;
     use crate::transaction::update_properties::UpdatePropertiesAction;
+    use crate::{Catalog, ErrorKind, NamespaceIdent, TableCreation};
 
     #[test]
     fn test_update_table_property() {
@@ -138,4 +148,74 @@ mod tests {
 
         assert_eq!(action.removals, HashSet::from(["b".to_string()]));
     }
+
+    #[tokio::test]
+    async fn test_update_table_property_commit() {
+        let catalog = new_memory_catalog().await;
+        let namespace = NamespaceIdent::new("ns".to_string());
+        catalog
+            .create_namespace(&namespace, HashMap::new())
+            .await
+            .unwrap();
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+            ])
+            .build()
+            .unwrap();
+        let table = catalog
+            .create_table(
+                &namespace,
+                TableCreation::builder()
+                    .name("t".to_string())
+                    .schema(schema)
+                    .properties(HashMap::from([("old.prop".to_string(), "1".to_string())]))
+                    .build(),
+            )
+            .await
+            .unwrap();
+
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .update_table_properties()
+            .set(
+                TableProperties::PROPERTY_COMMIT_NUM_RETRIES.to_string(),
+                "5".to_string(),
+            )
+            .remove("old.prop".to_string())
+            .remove("missing.prop".to_string())
+            .apply(tx)
+            .unwrap();
+        tx.commit(&catalog).await.unwrap();
+
+        let table = catalog.load_table(table.identifier()).await.unwrap();
+        let properties = table.metadata().properties();
+        assert_eq!(
+            properties
+                .get(TableProperties::PROPERTY_COMMIT_NUM_RETRIES)
+                .map(String::as_str),
+            Some("5")
+        );
+        assert!(!properties.contains_key("old.prop"));
+        assert_eq!(
+            table
+                .metadata()
+                .table_properties()
+                .unwrap()
+                .commit_num_retries,
+            5
+        );
+
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .update_table_properties()
+            .set(
+                TableProperties::PROPERTY_COMMIT_NUM_RETRIES.to_string(),
+                "five".to_string(),
+            )
+            .apply(tx)
+            .unwrap();
+        let err = tx.commit(&catalog).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::DataInvalid);
+    }
 }
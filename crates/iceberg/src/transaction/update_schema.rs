@@ -0,0 +1,711 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::spec::{
+    FormatVersion, ListType, Literal, MapType, NestedField, NestedFieldRef, Schema, StructType,
+    TableMetadata, Type,
+};
+use crate::table::Table;
+use crate::transaction::action::{ActionCommit, TransactionAction};
+use crate::{Error, ErrorKind, Result, TableRequirement, TableUpdate};
+
+/// A transactional action that evolves the current schema of a table by adding or
+/// deleting columns.
+///
+/// Columns are addressed by their full name: nested fields use a dotted path, such as
+/// `address.zip` for the field `zip` of the struct column `address`. Added columns
+/// receive fresh field ids. Files written before the change read optional columns as null
+/// and required columns as their initial default.
+pub struct UpdateSchemaAction {
+    additions: Vec<(String, Type, Option<Literal>)>,
+    deletions: Vec<String>,
+}
+
+impl UpdateSchemaAction {
+    /// Creates a new [`UpdateSchemaAction`] with no changes.
+    pub fn new() -> Self {
+        UpdateSchemaAction {
+            additions: vec![],
+            deletions: vec![],
+        }
+    }
+
+    /// Adds an optional column to the schema.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The full name of the new column. When it contains a `.`, the part before the
+    ///   last `.` names the struct that the column is added to.
+    /// * `field_type` - The type of the new column. Field ids of nested types are reassigned.
+    ///
+    /// # Returns
+    ///
+    /// The updated [`UpdateSchemaAction`] with the column added.
+    pub fn add_column(mut self, name: impl ToString, field_type: Type) -> Self {
+        self.additions.push((name.to_string(), field_type, None));
+        self
+    }
+
+    /// Adds a required primitive column with a default value to the schema, which needs
+    /// format version 3.
+    ///
+    /// The default is used both as the `initial-default`, the value of the column for rows
+    /// written before the change, and as the `write-default`, the value writers use for new
+    /// rows that don't set the column.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The full name of the new column, see [`UpdateSchemaAction::add_column`].
+    /// * `field_type` - The primitive type of the new column.
+    /// * `default` - The default value of the new column.
+    ///
+    /// # Returns
+    ///
+    /// The updated [`UpdateSchemaAction`] with the column added.
+    pub fn add_required_column(
+        mut self,
+        name: impl ToString,
+        field_type: Type,
+        default: Literal,
+    ) -> Self {
+        self.additions
+            .push((name.to_string(), field_type, Some(default)));
+        self
+    }
+
+    /// Deletes a column from the schema.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The full name of the column to delete, using a dotted path for nested fields.
+    ///
+    /// # Returns
+    ///
+    /// The updated [`UpdateSchemaAction`] with the column deleted.
+    pub fn delete_column(mut self, name: impl ToString) -> Self {
+        self.deletions.push(name.to_string());
+        self
+    }
+
+    fn split_name(name: &str) -> (Option<&str>, &str) {
+        match name.rsplit_once('.') {
+            Some((parent, leaf)) => (Some(parent), leaf),
+            None => (None, name),
+        }
+    }
+
+    /// Resolves the struct that holds the fields of `parent`, along with the id of the field
+    /// owning that struct. The root struct of the schema has no owner.
+    fn resolve_parent<'a>(
+        schema: &'a Schema,
+        parent: Option<&str>,
+    ) -> Result<(Option<i32>, &'a StructType)> {
+        let Some(parent) = parent else {
+            return Ok((None, schema.as_struct()));
+        };
+
+        let field = schema.field_by_name(parent).ok_or_else(|| {
+            Error::new(
+                ErrorKind::DataInvalid,
+                format!("Cannot find parent struct: {parent}"),
+            )
+        })?;
+
+        let owner = match field.field_type.as_ref() {
+            Type::Struct(_) => field,
+            Type::List(list) if list.element_field.field_type.is_struct() => &list.element_field,
+            Type::Map(map) if map.value_field.field_type.is_struct() => &map.value_field,
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::DataInvalid,
+                    format!("Cannot change fields of non-struct type: {parent}"),
+                ));
+            }
+        };
+
+        match owner.field_type.as_ref() {
+            Type::Struct(struct_type) => Ok((Some(owner.id), struct_type)),
+            _ => unreachable!("owner type is checked to be a struct"),
+        }
+    }
+
+    fn validate_default(
+        metadata: &TableMetadata,
+        name: &str,
+        field_type: &Type,
+        default: &Literal,
+    ) -> Result<()> {
+        if metadata.format_version() < FormatVersion::V3 {
+            return Err(Error::new(
+                ErrorKind::FeatureUnsupported,
+                format!(
+                    "Cannot add required column {name} with a default, default values need format version 3, got {}",
+                    metadata.format_version()
+                ),
+            ));
+        }
+        if !field_type.is_primitive() {
+            return Err(Error::new(
+                ErrorKind::FeatureUnsupported,
+                format!("Cannot add required column {name} of non-primitive type {field_type}"),
+            ));
+        }
+        default.clone().try_into_json(field_type).map_err(|e| {
+            Error::new(
+                ErrorKind::DataInvalid,
+                format!("Invalid default of column {name} of type {field_type}"),
+            )
+            .with_source(e)
+        })?;
+        Ok(())
+    }
+
+    fn validate_deletion(metadata: &TableMetadata, name: &str, field: &NestedField) -> Result<()> {
+        let mut deleted_ids = HashSet::new();
+        collect_field_ids(field, &mut deleted_ids);
+
+        if metadata
+            .default_partition_spec()
+            .fields()
+            .iter()
+            .any(|partition_field| deleted_ids.contains(&partition_field.source_id))
+        {
+            return Err(Error::new(
+                ErrorKind::PreconditionFailed,
+                format!("Cannot delete column {name}, it is used by the default partition spec"),
+            ));
+        }
+
+        if metadata
+            .current_schema()
+            .identifier_field_ids()
+            .any(|id| deleted_ids.contains(&id))
+        {
+            return Err(Error::new(
+                ErrorKind::PreconditionFailed,
+                format!("Cannot delete column {name}, it is referenced by identifier fields"),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for UpdateSchemaAction {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl TransactionAction for UpdateSchemaAction {
+    async fn commit(self: Arc<Self>, table: &Table) -> Result<ActionCommit> {
+        let metadata = table.metadata();
+        let schema = metadata.current_schema();
+
+        let mut deleted_ids = HashSet::new();
+        for name in &self.deletions {
+            let (parent, leaf) = Self::split_name(name);
+            let (_, parent_struct) = Self::resolve_parent(schema, parent)?;
+            let field = parent_struct.field_by_name(leaf).ok_or_else(|| {
+                Error::new(
+                    ErrorKind::DataInvalid,
+                    format!("Cannot delete missing column: {name}"),
+                )
+            })?;
+            Self::validate_deletion(metadata, name, field)?;
+            deleted_ids.insert(field.id);
+        }
+
+        let mut last_column_id = metadata.last_column_id();
+        let mut additions: HashMap<Option<i32>, Vec<NestedFieldRef>> = HashMap::new();
+        for (name, field_type, default) in &self.additions {
+            if let Some(default) = default {
+                Self::validate_default(metadata, name, field_type, default)?;
+            }
+            let (parent, leaf) = Self::split_name(name);
+            let (owner_id, parent_struct) = Self::resolve_parent(schema, parent)?;
+            if owner_id.is_some_and(|id| deleted_ids.contains(&id)) {
+                return Err(Error::new(
+                    ErrorKind::PreconditionFailed,
+                    format!("Cannot add column {name} to a struct that is deleted"),
+                ));
+            }
+
+            let siblings = additions.entry(owner_id).or_default();
+            let existing = parent_struct
+                .field_by_name(leaf)
+                .filter(|field| !deleted_ids.contains(&field.id));
+            if existing.is_some() || siblings.iter().any(|field| field.name == leaf) {
+                return Err(Error::new(
+                    ErrorKind::DataInvalid,
+                    format!("Cannot add column, name already exists: {name}"),
+                ));
+            }
+
+            last_column_id += 1;
+            let id = last_column_id;
+            let field_type = assign_fresh_ids(field_type, &mut last_column_id);
+            let field = match default {
+                Some(default) => NestedField::required(id, leaf, field_type)
+                    .with_initial_default(default.clone())
+                    .with_write_default(default.clone()),
+                None => NestedField::optional(id, leaf, field_type),
+            };
+            siblings.push(field.into());
+        }
+
+        let fields = update_fields(schema.as_struct().fields(), None, &deleted_ids, &additions);
+        let new_schema = Schema::builder()
+            .with_schema_id(schema.schema_id())
+            .with_fields(fields)
+            .with_identifier_field_ids(schema.identifier_field_ids())
+            .build()?;
+
+        let updates = vec![
+            TableUpdate::AddSchema { schema: new_schema },
+            TableUpdate::SetCurrentSchema { schema_id: -1 },
+        ];
+        let requirements = vec![
+            TableRequirement::CurrentSchemaIdMatch {
+                current_schema_id: schema.schema_id(),
+            },
+            TableRequirement::LastAssignedFieldIdMatch {
+                last_assigned_field_id: metadata.last_column_id(),
+            },
+        ];
+
+        Ok(ActionCommit::new(updates, requirements))
+    }
+}
+
+fn collect_field_ids(field: &NestedField, ids: &mut HashSet<i32>) {
+    ids.insert(field.id);
+    match field.field_type.as_ref() {
+        Type::Primitive(_) => {}
+        Type::Struct(struct_type) => struct_type
+            .fields()
+            .iter()
+            .for_each(|field| collect_field_ids(field, ids)),
+        Type::List(list) => collect_field_ids(&list.element_field, ids),
+        Type::Map(map) => {
+            collect_field_ids(&map.key_field, ids);
+            collect_field_ids(&map.value_field, ids);
+        }
+    }
+}
+
+/// Assigns fresh ids to all fields nested in `field_type`, level by level.
+fn assign_fresh_ids(field_type: &Type, last_column_id: &mut i32) -> Type {
+    let mut next_id = || {
+        *last_column_id += 1;
+        *last_column_id
+    };
+    let with_id = |field: &NestedFieldRef, id: i32| NestedField {
+        id,
+        ..field.as_ref().clone()
+    };
+
+    match field_type {
+        Type::Primitive(_) => field_type.clone(),
+        Type::Struct(struct_type) => {
+            let fields = struct_type
+                .fields()
+                .iter()
+                .map(|field| with_id(field, next_id()))
+                .collect::<Vec<_>>();
+            let fields = fields
+                .into_iter()
+                .map(|mut field| {
+                    field.field_type =
+                        Box::new(assign_fresh_ids(&field.field_type, last_column_id));
+                    Arc::new(field)
+                })
+                .collect();
+            Type::Struct(StructType::new(fields))
+        }
+        Type::List(list) => {
+            let mut element_field = with_id(&list.element_field, next_id());
+            element_field.field_type =
+                Box::new(assign_fresh_ids(&element_field.field_type, last_column_id));
+            Type::List(ListType::new(element_field.into()))
+        }
+        Type::Map(map) => {
+            let mut key_field = with_id(&map.key_field, next_id());
+            let mut value_field = with_id(&map.value_field, next_id());
+            key_field.field_type =
+                Box::new(assign_fresh_ids(&key_field.field_type, last_column_id));
+            value_field.field_type =
+                Box::new(assign_fresh_ids(&value_field.field_type, last_column_id));
+            Type::Map(MapType::new(key_field.into(), value_field.into()))
+        }
+    }
+}
+
+/// Rebuilds the fields of the struct owned by `owner_id`, dropping the deleted fields and
+/// appending the added ones.
+fn update_fields(
+    fields: &[NestedFieldRef],
+    owner_id: Option<i32>,
+    deleted_ids: &HashSet<i32>,
+    additions: &HashMap<Option<i32>, Vec<NestedFieldRef>>,
+) -> Vec<NestedFieldRef> {
+    fields
+        .iter()
+        .filter(|field| !deleted_ids.contains(&field.id))
+        .map(|field| update_field(field, deleted_ids, additions))
+        .chain(additions.get(&owner_id).into_iter().flatten().cloned())
+        .collect()
+}
+
+fn update_field(
+    field: &NestedFieldRef,
+    deleted_ids: &HashSet<i32>,
+    additions: &HashMap<Option<i32>, Vec<NestedFieldRef>>,
+) -> NestedFieldRef {
+    let field_type = match field.field_type.as_ref() {
+        Type::Primitive(_) => return field.clone(),
+        Type::Struct(struct_type) => Type::Struct(StructType::new(update_fields(
+            struct_type.fields(),
+            Some(field.id),
+            deleted_ids,
+            additions,
+        ))),
+        Type::List(list) => Type::List(ListType::new(update_field(
+            &list.element_field,
+            deleted_ids,
+            additions,
+        ))),
+        Type::Map(map) => Type::Map(MapType::new(
+            update_field(&map.key_field, deleted_ids, additions),
+            update_field(&map.value_field, deleted_ids, additions),
+        )),
+    };
+
+    Arc::new(NestedField {
+        field_type: Box::new(field_type),
+        ..field.as_ref().clone()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use arrow_array::cast::AsArray;
+    use arrow_array::{Array, ArrayRef, Int64Array, RecordBatch, StringArray, StructArray};
+    use arrow_schema::DataType;
+    use futures::TryStreamExt;
+    use tempfile::TempDir;
+
+    use crate::arrow::{ArrowReaderBuilder, schema_to_arrow_schema};
+    use crate::io::FileIOBuilder;
+    use crate::scan::{FileScanTask, FileScanTaskStream};
+    use crate::spec::{
+        DataFileFormat, FormatVersion, Literal, NestedField, PrimitiveType, Schema, SortOrder,
+        StructType, TableMetadataBuilder, Transform, Type, UnboundPartitionSpec,
+    };
+    use crate::table::Table;
+    use crate::test_utils::fixtures::write_parquet_file;
+    use crate::transaction::tests::make_v2_table;
+    use crate::transaction::{Transaction, TransactionAction};
+    use crate::{ErrorKind, TableIdent, TableRequirement, TableUpdate};
+
+    fn address_type() -> Type {
+        Type::Struct(StructType::new(vec![
+            NestedField::optional(3, "street", Type::Primitive(PrimitiveType::String)).into(),
+            NestedField::optional(4, "city", Type::Primitive(PrimitiveType::String)).into(),
+        ]))
+    }
+
+    fn make_table_with_struct(location: &str, format_version: FormatVersion) -> Table {
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+                NestedField::optional(2, "address", address_type()).into(),
+            ])
+            .build()
+            .unwrap();
+        let spec = UnboundPartitionSpec::builder()
+            .add_partition_field(4, "city", Transform::Identity)
+            .unwrap()
+            .build();
+        let metadata = TableMetadataBuilder::new(
+            schema,
+            spec,
+            SortOrder::unsorted_order(),
+            location.to_string(),
+            format_version,
+            HashMap::new(),
+        )
+        .unwrap()
+        .build()
+        .unwrap()
+        .metadata;
+
+        Table::builder()
+            .metadata(metadata)
+            .metadata_location(format!("{location}/metadata/v1.json"))
+            .identifier(TableIdent::from_strs(["ns1", "test1"]).unwrap())
+            .file_io(FileIOBuilder::new_fs_io().build().unwrap())
+            .build()
+            .unwrap()
+    }
+
+    async fn apply(table: Table, tx_action: super::UpdateSchemaAction) -> Table {
+        let mut action_commit = Arc::new(tx_action).commit(&table).await.unwrap();
+        Transaction::update_table_metadata(table, &action_commit.take_updates()).unwrap()
+    }
+
+    /// Writes a data file with the current schema of `table` and returns its path.
+    async fn write_data_file(table: &Table, location: &str) -> String {
+        let schema = table.metadata().current_schema().clone();
+        let arrow_schema = Arc::new(schema_to_arrow_schema(&schema).unwrap());
+        let DataType::Struct(address_fields) = arrow_schema.field(1).data_type().clone() else {
+            unreachable!()
+        };
+        let address = StructArray::new(
+            address_fields,
+            vec![
+                Arc::new(StringArray::from(vec!["Main St", "High St"])) as ArrayRef,
+                Arc::new(StringArray::from(vec!["Springfield", "Shelbyville"])) as ArrayRef,
+            ],
+            None,
+        );
+        let batch = RecordBatch::try_new(arrow_schema, vec![
+            Arc::new(Int64Array::from(vec![1, 2])) as ArrayRef,
+            Arc::new(address) as ArrayRef,
+        ])
+        .unwrap();
+        let data_file_path = format!("{location}/old.parquet");
+        write_parquet_file(table.file_io(), &data_file_path, &batch, None).await;
+        data_file_path
+    }
+
+    /// Reads the data file at `data_file_path` with the current schema of `table`.
+    async fn read_data_file(
+        table: &Table,
+        data_file_path: String,
+        project_field_ids: Vec<i32>,
+    ) -> Vec<RecordBatch> {
+        let reader = ArrowReaderBuilder::new(table.file_io().clone()).build();
+        let tasks = Box::pin(futures::stream::iter(
+            vec![Ok(FileScanTask {
+                start: 0,
+                length: 0,
+                record_count: None,
+                data_file_path,
+                data_file_format: DataFileFormat::Parquet,
+                schema: table.metadata().current_schema().clone(),
+                project_field_ids,
+                predicate: None,
+                deletes: vec![],
+                partition: None,
+                partition_spec: None,
+                name_mapping: None,
+                case_sensitive: false,
+                sort_order_id: None,
+                column_sizes: HashMap::new(),
+                value_counts: HashMap::new(),
+                timestamp_zone: None,
+            })]
+            .into_iter(),
+        )) as FileScanTaskStream;
+        reader
+            .read(tasks)
+            .unwrap()
+            .try_collect::<Vec<RecordBatch>>()
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_add_nested_column_reads_old_file_as_null() {
+        let tmp_dir = TempDir::new().unwrap();
+        let location = tmp_dir.path().to_str().unwrap().to_string();
+        let table = make_table_with_struct(&location, FormatVersion::V2);
+        let data_file_path = write_data_file(&table, &location).await;
+
+        let action = Transaction::new(&table)
+            .update_schema()
+            .add_column("address.zip", Type::Primitive(PrimitiveType::String));
+        let table = apply(table, action).await;
+
+        let new_schema = table.metadata().current_schema().clone();
+        let zip = new_schema.field_by_name("address.zip").unwrap();
+        assert_eq!(zip.id, 5);
+        assert!(!zip.required);
+        assert_eq!(table.metadata().last_column_id(), 5);
+        assert_eq!(new_schema.field_by_name("address.city").unwrap().id, 4);
+
+        let result = read_data_file(&table, data_file_path, vec![1, 2]).await;
+        assert_eq!(result.len(), 1);
+
+        let address = result[0].column(1).as_struct();
+        let field_names: Vec<&str> = address
+            .fields()
+            .iter()
+            .map(|field| field.name().as_str())
+            .collect();
+        assert_eq!(field_names, vec!["street", "city", "zip"]);
+        assert_eq!(address.column(1).as_string::<i32>().value(1), "Shelbyville");
+        assert_eq!(address.column(2).null_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_add_required_column_reads_old_file_as_initial_default() {
+        let tmp_dir = TempDir::new().unwrap();
+        let location = tmp_dir.path().to_str().unwrap().to_string();
+        let table = make_table_with_struct(&location, FormatVersion::V3);
+        let data_file_path = write_data_file(&table, &location).await;
+
+        let string_type = Type::Primitive(PrimitiveType::String);
+        let action = Transaction::new(&table)
+            .update_schema()
+            .add_required_column("region", string_type.clone(), Literal::string("emea"))
+            .add_required_column("address.country", string_type, Literal::string("NL"));
+        let table = apply(table, action).await;
+
+        let new_schema = table.metadata().current_schema().clone();
+        let region = new_schema.field_by_name("region").unwrap();
+        assert!(region.required);
+        assert_eq!(region.initial_default, Some(Literal::string("emea")));
+        assert_eq!(region.write_default, Some(Literal::string("emea")));
+
+        let result = read_data_file(&table, data_file_path, vec![1, 2, region.id]).await;
+        assert_eq!(result.len(), 1);
+        let batch = &result[0];
+        assert_eq!(batch.num_rows(), 2);
+
+        let region = batch.column(2).as_string::<i32>();
+        assert_eq!(region.null_count(), 0);
+        assert!(region.iter().all(|value| value == Some("emea")));
+
+        let country = batch.column(1).as_struct().column(2).as_string::<i32>();
+        assert_eq!(country.null_count(), 0);
+        assert!(country.iter().all(|value| value == Some("NL")));
+    }
+
+    #[tokio::test]
+    async fn test_add_required_column_needs_v3_and_valid_default() {
+        let tmp_dir = TempDir::new().unwrap();
+        let location = tmp_dir.path().to_str().unwrap();
+
+        let table = make_table_with_struct(location, FormatVersion::V2);
+        let action = Transaction::new(&table)
+            .update_schema()
+            .add_required_column(
+                "region",
+                Type::Primitive(PrimitiveType::String),
+                Literal::string("emea"),
+            );
+        let Err(err) = Arc::new(action).commit(&table).await else {
+            panic!("adding a column with a default to a v2 table should fail");
+        };
+        assert_eq!(err.kind(), ErrorKind::FeatureUnsupported);
+
+        let table = make_table_with_struct(location, FormatVersion::V3);
+        let action = Transaction::new(&table)
+            .update_schema()
+            .add_required_column(
+                "region",
+                Type::Primitive(PrimitiveType::Long),
+                Literal::string("emea"),
+            );
+        let Err(err) = Arc::new(action).commit(&table).await else {
+            panic!("adding a column with a default of another type should fail");
+        };
+        assert_eq!(err.kind(), ErrorKind::DataInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_add_nested_struct_column_assigns_fresh_ids() {
+        let tmp_dir = TempDir::new().unwrap();
+        let table = make_table_with_struct(tmp_dir.path().to_str().unwrap(), FormatVersion::V2);
+
+        let geo = Type::Struct(StructType::new(vec![
+            NestedField::required(1, "lat", Type::Primitive(PrimitiveType::Double)).into(),
+            NestedField::required(1, "lon", Type::Primitive(PrimitiveType::Double)).into(),
+        ]));
+        let action = Transaction::new(&table)
+            .update_schema()
+            .add_column("address.geo", geo)
+            .delete_column("address.street");
+        let mut action_commit = Arc::new(action).commit(&table).await.unwrap();
+        assert_eq!(action_commit.take_requirements(), vec![
+            TableRequirement::CurrentSchemaIdMatch {
+                current_schema_id: 0
+            },
+            TableRequirement::LastAssignedFieldIdMatch {
+                last_assigned_field_id: 4
+            },
+        ]);
+        let updates = action_commit.take_updates();
+        let TableUpdate::AddSchema { schema } = &updates[0] else {
+            unreachable!()
+        };
+
+        assert!(schema.field_by_name("address.street").is_none());
+        assert_eq!(schema.field_by_name("address.geo").unwrap().id, 5);
+        assert_eq!(schema.field_by_name("address.geo.lat").unwrap().id, 6);
+        assert_eq!(schema.field_by_name("address.geo.lon").unwrap().id, 7);
+    }
+
+    #[tokio::test]
+    async fn test_delete_nested_partition_source_fails() {
+        let tmp_dir = TempDir::new().unwrap();
+        let table = make_table_with_struct(tmp_dir.path().to_str().unwrap(), FormatVersion::V2);
+
+        for name in ["address.city", "address"] {
+            let action = Transaction::new(&table).update_schema().delete_column(name);
+            let Err(err) = Arc::new(action).commit(&table).await else {
+                panic!("deleting {name} should fail");
+            };
+            assert_eq!(err.kind(), ErrorKind::PreconditionFailed);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_invalid_column_changes_fail() {
+        let table = make_v2_table();
+
+        let long_type = Type::Primitive(PrimitiveType::Long);
+        for action in [
+            Transaction::new(&table)
+                .update_schema()
+                .add_column("y", long_type.clone()),
+            Transaction::new(&table)
+                .update_schema()
+                .add_column("y.a", long_type.clone()),
+            Transaction::new(&table)
+                .update_schema()
+                .add_column("missing.a", long_type.clone()),
+            Transaction::new(&table)
+                .update_schema()
+                .delete_column("missing"),
+            Transaction::new(&table).update_schema().delete_column("y"),
+        ] {
+            assert!(Arc::new(action).commit(&table).await.is_err());
+        }
+    }
+}
@@ -348,6 +348,11 @@ impl TransformFunction for Hour {
                 .downcast_ref::<TimestampMicrosecondArray>()
                 .unwrap()
                 .unary(|v| -> i32 { Self::hour_timestamp_micro(v) }),
+            DataType::Timestamp(TimeUnit::Nanosecond, _) => input
+                .as_any()
+                .downcast_ref::<TimestampNanosecondArray>()
+                .unwrap()
+                .unary(|v| -> i32 { Self::hour_timestamp_nano(v) }),
             _ => {
                 return Err(crate::Error::new(
                     crate::ErrorKind::FeatureUnsupported,
@@ -391,7 +396,9 @@ impl TransformFunction for Hour {
 mod test {
     use std::sync::Arc;
 
-    use arrow_array::{ArrayRef, Date32Array, Int32Array, TimestampMicrosecondArray};
+    use arrow_array::{
+        ArrayRef, Date32Array, Int32Array, TimestampMicrosecondArray, TimestampNanosecondArray,
+    };
     use chrono::{NaiveDate, NaiveDateTime};
 
     use crate::Result;
@@ -749,6 +756,148 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_projection_timestamptz_hour_lower_bound() -> Result<()> {
+        // 420034
+        let value = "2017-12-01T12:00:00.000000+02:00";
+
+        let fixture = TestProjectionFixture::new(
+            Transform::Hour,
+            "name",
+            NestedField::required(1, "value", Type::Primitive(PrimitiveType::Timestamptz)),
+        );
+
+        fixture.assert_projection(
+            &fixture.binary_predicate(
+                PredicateOperator::LessThan,
+                Datum::timestamptz_from_str(value)?,
+            ),
+            Some("name <= 420033"),
+        )?;
+
+        fixture.assert_projection(
+            &fixture.binary_predicate(
+                PredicateOperator::LessThanOrEq,
+                Datum::timestamptz_from_str(value)?,
+            ),
+            Some("name <= 420034"),
+        )?;
+
+        fixture.assert_projection(
+            &fixture.binary_predicate(
+                PredicateOperator::GreaterThan,
+                Datum::timestamptz_from_str(value)?,
+            ),
+            Some("name >= 420034"),
+        )?;
+
+        fixture.assert_projection(
+            &fixture.binary_predicate(
+                PredicateOperator::GreaterThanOrEq,
+                Datum::timestamptz_from_str(value)?,
+            ),
+            Some("name >= 420034"),
+        )?;
+
+        fixture.assert_projection(
+            &fixture.binary_predicate(PredicateOperator::Eq, Datum::timestamptz_from_str(value)?),
+            Some("name = 420034"),
+        )?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_projection_timestamp_hour_negative_lower_bound() -> Result<()> {
+        // -1
+        let value = "1969-12-31T23:00:00.000000";
+        // -2
+        let another = "1969-12-31T22:59:59.999999";
+
+        let fixture = TestProjectionFixture::new(
+            Transform::Hour,
+            "name",
+            NestedField::required(1, "value", Type::Primitive(PrimitiveType::Timestamp)),
+        );
+
+        fixture.assert_projection(
+            &fixture.binary_predicate(
+                PredicateOperator::LessThan,
+                Datum::timestamp_from_str(value)?,
+            ),
+            Some("name <= -2"),
+        )?;
+
+        fixture.assert_projection(
+            &fixture.binary_predicate(
+                PredicateOperator::LessThanOrEq,
+                Datum::timestamp_from_str(value)?,
+            ),
+            Some("name <= -1"),
+        )?;
+
+        fixture.assert_projection(
+            &fixture.binary_predicate(
+                PredicateOperator::GreaterThan,
+                Datum::timestamp_from_str(value)?,
+            ),
+            Some("name >= -1"),
+        )?;
+
+        fixture.assert_projection(
+            &fixture.binary_predicate(
+                PredicateOperator::GreaterThanOrEq,
+                Datum::timestamp_from_str(value)?,
+            ),
+            Some("name >= -1"),
+        )?;
+
+        fixture.assert_projection(
+            &fixture.binary_predicate(PredicateOperator::Eq, Datum::timestamp_from_str(value)?),
+            Some("name = -1"),
+        )?;
+
+        fixture.assert_projection(
+            &fixture.set_predicate(PredicateOperator::In, vec![
+                Datum::timestamp_from_str(value)?,
+                Datum::timestamp_from_str(another)?,
+            ]),
+            Some("name IN (-2, -1)"),
+        )?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_projection_timestamp_hour_negative_upper_bound() -> Result<()> {
+        // -1
+        let value = "1969-12-31T23:59:59.999999";
+
+        let fixture = TestProjectionFixture::new(
+            Transform::Hour,
+            "name",
+            NestedField::required(1, "value", Type::Primitive(PrimitiveType::Timestamp)),
+        );
+
+        fixture.assert_projection(
+            &fixture.binary_predicate(
+                PredicateOperator::LessThan,
+                Datum::timestamp_from_str(value)?,
+            ),
+            Some("name <= -1"),
+        )?;
+
+        fixture.assert_projection(
+            &fixture.binary_predicate(
+                PredicateOperator::GreaterThan,
+                Datum::timestamp_from_str(value)?,
+            ),
+            Some("name >= 0"),
+        )?;
+
+        Ok(())
+    }
+
     #[test]
     fn test_projection_timestamp_year_upper_bound() -> Result<()> {
         let value = "2017-12-31T23:59:59.999999";
@@ -2431,6 +2580,68 @@ mod test {
         assert_eq!(res, expect);
     }
 
+    #[test]
+    fn test_temporal_transforms_reference_values() {
+        // (date, days, years, months) since 1970-01-01 as mandated by the spec.
+        let cases = [
+            ("2017-11-16", 17486, 47, 574),
+            ("1970-01-01", 0, 0, 0),
+            ("1969-12-31", -1, -1, -1),
+            ("1969-01-01", -365, -1, -12),
+            ("1900-01-01", -25567, -70, -840),
+        ];
+
+        let year = Box::new(super::Year) as BoxedTransformFunction;
+        let month = Box::new(super::Month) as BoxedTransformFunction;
+        let day = Box::new(super::Day) as BoxedTransformFunction;
+
+        for (date, days, years, months) in cases {
+            let micros = i64::from(days) * 86_400_000_000;
+            let nanos = micros * 1_000;
+            let time = format!("{date}T00:00:00.000000");
+
+            test_date(days, &year, Datum::int(years));
+            test_date(days, &month, Datum::int(months));
+            test_date(days, &day, Datum::date(days));
+            test_timestamp_and_tz_transform(&time, &year, Datum::int(years));
+            test_timestamp_and_tz_transform(&time, &month, Datum::int(months));
+            test_timestamp_and_tz_transform(&time, &day, Datum::date(days));
+            test_timestamp_ns_and_tz_transform_using_i64(nanos, &year, Datum::int(years));
+            test_timestamp_ns_and_tz_transform_using_i64(nanos, &month, Datum::int(months));
+            test_timestamp_ns_and_tz_transform_using_i64(nanos, &day, Datum::date(days));
+
+            // The array path must produce the same encoding as the literal path.
+            let inputs: [ArrayRef; 3] = [
+                Arc::new(Date32Array::from(vec![days])),
+                Arc::new(TimestampMicrosecondArray::from(vec![micros])),
+                Arc::new(TimestampNanosecondArray::from(vec![nanos])),
+            ];
+            for input in inputs {
+                let res = year.transform(input.clone()).unwrap();
+                let res = res.as_any().downcast_ref::<Int32Array>().unwrap();
+                assert_eq!(
+                    res.value(0),
+                    years,
+                    "year({date}) for {}",
+                    input.data_type()
+                );
+
+                let res = month.transform(input.clone()).unwrap();
+                let res = res.as_any().downcast_ref::<Int32Array>().unwrap();
+                assert_eq!(
+                    res.value(0),
+                    months,
+                    "month({date}) for {}",
+                    input.data_type()
+                );
+
+                let res = day.transform(input.clone()).unwrap();
+                let res = res.as_any().downcast_ref::<Date32Array>().unwrap();
+                assert_eq!(res.value(0), days, "day({date}) for {}", input.data_type());
+            }
+        }
+    }
+
     #[test]
     fn test_transform_year_literal() {
         let year = Box::new(super::Year) as BoxedTransformFunction;
@@ -2714,6 +2925,21 @@ mod test {
         assert_eq!(res.value(2), expect_hour[2]);
         assert_eq!(res.value(3), expect_hour[3]);
         assert_eq!(res.value(4), -1);
+
+        // Test TimestampNanosecond
+        let date_array: ArrayRef = Arc::new(TimestampNanosecondArray::from(vec![
+            1512151975038194000,
+            -1,
+            -3_600_000_000_000,
+            -3_600_000_000_001,
+        ]));
+        let res = hour.transform(date_array).unwrap();
+        let res = res.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(res.len(), 4);
+        assert_eq!(res.value(0), 420042);
+        assert_eq!(res.value(1), -1);
+        assert_eq!(res.value(2), -1);
+        assert_eq!(res.value(3), -2);
     }
 
     #[test]
@@ -15,8 +15,11 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::{BTreeMap, HashMap};
 use std::num::NonZeroUsize;
 
+use serde::{Serialize, Serializer};
+
 // Use a default value of 1 as the safest option.
 // See https://doc.rust-lang.org/std/thread/fn.available_parallelism.html#limitations
 // for more details.
@@ -40,3 +43,33 @@ pub(crate) fn available_parallelism() -> NonZeroUsize {
         NonZeroUsize::new(DEFAULT_PARALLELISM).unwrap()
     })
 }
+
+/// Serializes a [`HashMap`] with its entries ordered by key, so that the output
+/// does not depend on the iteration order of the map.
+pub(crate) fn serialize_sorted_map<S, K, V>(
+    map: &HashMap<K, V>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    K: Serialize + Ord,
+    V: Serialize,
+{
+    serializer.collect_map(map.iter().collect::<BTreeMap<_, _>>())
+}
+
+/// Same as [`serialize_sorted_map`] for an optional map.
+pub(crate) fn serialize_sorted_optional_map<S, K, V>(
+    map: &Option<HashMap<K, V>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    K: Serialize + Ord,
+    V: Serialize,
+{
+    match map {
+        Some(map) => serialize_sorted_map(map, serializer),
+        None => serializer.serialize_none(),
+    }
+}
@@ -19,7 +19,7 @@
 
 use arrow_array::RecordBatch;
 
-use crate::spec::{DataContentType, DataFile, PartitionKey};
+use crate::spec::{DataContentType, DataFile, PartitionKey, validate_partition_value};
 use crate::writer::file_writer::FileWriterBuilder;
 use crate::writer::file_writer::location_generator::{FileNameGenerator, LocationGenerator};
 use crate::writer::file_writer::rolling_writer::{RollingFileWriter, RollingFileWriterBuilder};
@@ -30,6 +30,9 @@ use crate::{Error, ErrorKind, Result};
 #[derive(Debug)]
 pub struct DataFileWriterBuilder<B: FileWriterBuilder, L: LocationGenerator, F: FileNameGenerator> {
     inner: RollingFileWriterBuilder<B, L, F>,
+    partition_spec_id: i32,
+    sort_order_id: Option<i32>,
+    partition_key: Option<PartitionKey>,
 }
 
 impl<B, L, F> DataFileWriterBuilder<B, L, F>
@@ -38,9 +41,42 @@ where
     L: LocationGenerator,
     F: FileNameGenerator,
 {
-    /// Create a new `DataFileWriterBuilder` using a `RollingFileWriterBuilder`.
-    pub fn new(inner: RollingFileWriterBuilder<B, L, F>) -> Self {
-        Self { inner }
+    /// Create a new `DataFileWriterBuilder` using a `RollingFileWriterBuilder`, tagging the
+    /// files written without a partition key with the partition spec `partition_spec_id`.
+    ///
+    /// Files of an unpartitioned table must be tagged with the default spec of the table,
+    /// `metadata.default_partition_spec_id()`, which is not always 0. Files written with a
+    /// partition key are tagged with the spec of the key.
+    pub fn new(inner: RollingFileWriterBuilder<B, L, F>, partition_spec_id: i32) -> Self {
+        Self {
+            inner,
+            partition_spec_id,
+            sort_order_id: None,
+            partition_key: None,
+        }
+    }
+
+    /// Tag all the files written with the partition of `partition_key`, regardless of the
+    /// partition key the writers are built with and of the values of the written rows.
+    ///
+    /// This is useful when the partition is known externally, such as for backfills where
+    /// the partition source columns are null or absent. Fails if the partition values don't
+    /// match the partition type of the spec of the key.
+    pub fn with_partition(mut self, partition_key: PartitionKey) -> Result<Self> {
+        let partition_type = partition_key
+            .spec()
+            .partition_type(partition_key.schema())?;
+        validate_partition_value(partition_key.data(), &partition_type)?;
+        self.partition_key = Some(partition_key);
+        Ok(self)
+    }
+
+    /// Record that the written files are sorted by the table sort order with `sort_order_id`.
+    ///
+    /// The writer doesn't sort the data, the input batches must already be in that order.
+    pub fn with_sort_order_id(mut self, sort_order_id: i32) -> Self {
+        self.sort_order_id = Some(sort_order_id);
+        self
     }
 }
 
@@ -54,9 +90,16 @@ where
     type R = DataFileWriter<B, L, F>;
 
     async fn build(&self, partition_key: Option<PartitionKey>) -> Result<Self::R> {
+        let partition_key = self.partition_key.clone().or(partition_key);
+        let partition_spec_id = match &partition_key {
+            Some(partition_key) => partition_key.spec().spec_id(),
+            None => self.partition_spec_id,
+        };
         Ok(DataFileWriter {
             inner: Some(self.inner.build()),
             partition_key,
+            partition_spec_id,
+            sort_order_id: self.sort_order_id,
         })
     }
 }
@@ -66,6 +109,8 @@ where
 pub struct DataFileWriter<B: FileWriterBuilder, L: LocationGenerator, F: FileNameGenerator> {
     inner: Option<RollingFileWriter<B, L, F>>,
     partition_key: Option<PartitionKey>,
+    partition_spec_id: i32,
+    sort_order_id: Option<i32>,
 }
 
 #[async_trait::async_trait]
@@ -96,7 +141,10 @@ where
                     res.content(DataContentType::Data);
                     if let Some(pk) = self.partition_key.as_ref() {
                         res.partition(pk.data().clone());
-                        res.partition_spec_id(pk.spec().spec_id());
+                    }
+                    res.partition_spec_id(self.partition_spec_id);
+                    if let Some(sort_order_id) = self.sort_order_id {
+                        res.sort_order_id(sort_order_id);
                     }
                     res.build().map_err(|e| {
                         Error::new(
@@ -146,12 +194,14 @@ mod test {
     use parquet::file::properties::WriterProperties;
     use tempfile::TempDir;
 
-    use crate::Result;
+    use crate::arrow::schema_to_arrow_schema;
     use crate::io::FileIOBuilder;
+    use crate::memory::tests::new_memory_catalog;
     use crate::spec::{
         DataContentType, DataFileFormat, Literal, NestedField, PartitionKey, PartitionSpec,
-        PrimitiveType, Schema, Struct, Type,
+        PrimitiveType, Schema, Struct, TableMetadata, Transform, Type, UnboundPartitionSpec,
     };
+    use crate::transaction::{ApplyTransactionAction, Transaction};
     use crate::writer::base_writer::data_file_writer::DataFileWriterBuilder;
     use crate::writer::file_writer::ParquetWriterBuilder;
     use crate::writer::file_writer::location_generator::{
@@ -159,6 +209,7 @@ mod test {
     };
     use crate::writer::file_writer::rolling_writer::RollingFileWriterBuilder;
     use crate::writer::{IcebergWriter, IcebergWriterBuilder, RecordBatch};
+    use crate::{Catalog, NamespaceIdent, Result, TableCommit, TableCreation, TableUpdate};
 
     #[tokio::test]
     async fn test_parquet_writer() -> Result<()> {
@@ -187,10 +238,13 @@ mod test {
             file_name_gen,
         );
 
-        let mut data_file_writer = DataFileWriterBuilder::new(rolling_file_writer_builder)
-            .build(None)
-            .await
-            .unwrap();
+        let mut data_file_writer = DataFileWriterBuilder::new(
+            rolling_file_writer_builder,
+            PartitionSpec::unpartition_spec().spec_id(),
+        )
+        .build(None)
+        .await
+        .unwrap();
 
         let arrow_schema = arrow_schema::Schema::new(vec![
             Field::new("foo", DataType::Int32, false).with_metadata(HashMap::from([(
@@ -273,9 +327,11 @@ mod test {
             file_name_gen,
         );
 
-        let mut data_file_writer = DataFileWriterBuilder::new(rolling_file_writer_builder)
-            .build(Some(partition_key))
-            .await?;
+        let partition_spec_id = partition_key.spec().spec_id();
+        let mut data_file_writer =
+            DataFileWriterBuilder::new(rolling_file_writer_builder, partition_spec_id)
+                .build(Some(partition_key))
+                .await?;
 
         let arrow_schema = arrow_schema::Schema::new(vec![
             Field::new("id", DataType::Int32, false).with_metadata(HashMap::from([(
@@ -325,4 +381,239 @@ mod test {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_unpartitioned_files_are_tagged_with_default_spec_id() -> Result<()> {
+        let temp_dir = TempDir::new().unwrap();
+        let file_io = FileIOBuilder::new_fs_io().build().unwrap();
+        // A table written by another engine, whose unpartitioned default spec has id 2.
+        let metadata: TableMetadata = serde_json::from_value(serde_json::json!({
+            "format-version": 2,
+            "table-uuid": "9c12d441-03fe-4693-9a96-a0705ddf69c1",
+            "location": temp_dir.path().to_str().unwrap(),
+            "last-sequence-number": 0,
+            "last-updated-ms": 1602638573590i64,
+            "last-column-id": 1,
+            "current-schema-id": 0,
+            "schemas": [{
+                "type": "struct",
+                "schema-id": 0,
+                "fields": [{"id": 1, "name": "id", "required": true, "type": "int"}]
+            }],
+            "default-spec-id": 2,
+            "partition-specs": [
+                {
+                    "spec-id": 0,
+                    "fields": [
+                        {"name": "id", "transform": "identity", "source-id": 1, "field-id": 1000}
+                    ]
+                },
+                {"spec-id": 2, "fields": []}
+            ],
+            "last-partition-id": 1000,
+            "default-sort-order-id": 0,
+            "sort-orders": [{"order-id": 0, "fields": []}]
+        }))
+        .unwrap();
+        assert!(metadata.default_partition_spec().is_unpartitioned());
+
+        let parquet_writer_builder = ParquetWriterBuilder::new(
+            WriterProperties::builder().build(),
+            metadata.current_schema().clone(),
+        );
+        let rolling_file_writer_builder = RollingFileWriterBuilder::new_with_default_file_size(
+            parquet_writer_builder,
+            file_io,
+            DefaultLocationGenerator::new(metadata.clone())?,
+            DefaultFileNameGenerator::new("test".to_string(), None, DataFileFormat::Parquet),
+        );
+        let mut data_file_writer = DataFileWriterBuilder::new(
+            rolling_file_writer_builder,
+            metadata.default_partition_spec_id(),
+        )
+        .build(None)
+        .await?;
+
+        let arrow_schema = arrow_schema::Schema::new(vec![
+            Field::new("id", DataType::Int32, false).with_metadata(HashMap::from([(
+                PARQUET_FIELD_ID_META_KEY.to_string(),
+                1.to_string(),
+            )])),
+        ]);
+        let batch = RecordBatch::try_new(Arc::new(arrow_schema), vec![Arc::new(
+            Int32Array::from(vec![1, 2, 3]),
+        )])?;
+        data_file_writer.write(batch).await?;
+
+        let data_files = data_file_writer.close().await?;
+        assert_eq!(data_files.len(), 1);
+        assert_eq!(data_files[0].partition_spec_id, 2);
+        assert_eq!(data_files[0].partition, Struct::empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_overridden_partition_is_recorded_in_manifest() -> Result<()> {
+        let catalog = new_memory_catalog().await;
+        let namespace = NamespaceIdent::new("ns".to_string());
+        catalog.create_namespace(&namespace, HashMap::new()).await?;
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Int)).into(),
+                NestedField::optional(2, "region", Type::Primitive(PrimitiveType::String)).into(),
+            ])
+            .build()?;
+        let table = catalog
+            .create_table(
+                &namespace,
+                TableCreation::builder()
+                    .name("t".to_string())
+                    .schema(schema)
+                    .partition_spec(
+                        UnboundPartitionSpec::builder()
+                            .add_partition_field(2, "region", Transform::Identity)?
+                            .build(),
+                    )
+                    .build(),
+            )
+            .await?;
+        let schema = table.metadata().current_schema().clone();
+        let spec = table.metadata().default_partition_spec().as_ref().clone();
+
+        let rolling_file_writer_builder = RollingFileWriterBuilder::new_with_default_file_size(
+            ParquetWriterBuilder::new(WriterProperties::builder().build(), schema.clone()),
+            table.file_io().clone(),
+            DefaultLocationGenerator::new(table.metadata().clone())?,
+            DefaultFileNameGenerator::new("backfill".to_string(), None, DataFileFormat::Parquet),
+        );
+
+        // The partition values must match the partition type.
+        let invalid = PartitionKey::new(
+            spec.clone(),
+            schema.clone(),
+            Struct::from_iter([Some(Literal::int(1))]),
+        );
+        assert!(
+            DataFileWriterBuilder::new(rolling_file_writer_builder.clone(), spec.spec_id())
+                .with_partition(invalid)
+                .is_err()
+        );
+
+        let partition_value = Struct::from_iter([Some(Literal::string("eu"))]);
+        let mut data_file_writer =
+            DataFileWriterBuilder::new(rolling_file_writer_builder, spec.spec_id())
+                .with_partition(PartitionKey::new(
+                    spec.clone(),
+                    schema.clone(),
+                    partition_value.clone(),
+                ))?
+                .build(None)
+                .await?;
+        let batch = RecordBatch::try_new(Arc::new(schema_to_arrow_schema(&schema)?), vec![
+            Arc::new(Int32Array::from(vec![1, 2])),
+            Arc::new(StringArray::from(vec![None, Some("us")])),
+        ])?;
+        data_file_writer.write(batch).await?;
+        let data_files = data_file_writer.close().await?;
+        assert_eq!(data_files.len(), 1);
+        assert_eq!(data_files[0].partition, partition_value);
+        assert_eq!(data_files[0].partition_spec_id, spec.spec_id());
+
+        let tx = Transaction::new(&table);
+        let tx = tx.fast_append().add_data_files(data_files).apply(tx)?;
+        let table = tx.commit(&catalog).await?;
+        let manifest_list = table
+            .metadata()
+            .current_snapshot()
+            .unwrap()
+            .load_manifest_list(table.file_io(), table.metadata())
+            .await?;
+        let manifest = manifest_list.entries()[0]
+            .load_manifest(table.file_io(), table.metadata())
+            .await?;
+        assert_eq!(manifest.entries().len(), 1);
+        assert_eq!(
+            manifest.entries()[0].data_file().partition(),
+            &partition_value
+        );
+
+        Ok(())
+    }
+    #[tokio::test]
+    async fn test_append_to_table_with_evolved_spec() -> Result<()> {
+        let catalog = new_memory_catalog().await;
+        let namespace = NamespaceIdent::new("ns".to_string());
+        catalog.create_namespace(&namespace, HashMap::new()).await?;
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Int)).into(),
+            ])
+            .build()?;
+        let table = catalog
+            .create_table(
+                &namespace,
+                TableCreation::builder()
+                    .name("t".to_string())
+                    .schema(schema)
+                    .partition_spec(
+                        UnboundPartitionSpec::builder()
+                            .add_partition_field(1, "id", Transform::Identity)?
+                            .build(),
+                    )
+                    .build(),
+            )
+            .await?;
+
+        // Evolve the spec of the table to an unpartitioned one, with spec id 1.
+        let table = catalog
+            .update_table(
+                TableCommit::builder()
+                    .ident(table.identifier().clone())
+                    .updates(vec![
+                        TableUpdate::AddSpec {
+                            spec: UnboundPartitionSpec::builder().build(),
+                        },
+                        TableUpdate::SetDefaultSpec { spec_id: -1 },
+                    ])
+                    .requirements(vec![])
+                    .build(),
+            )
+            .await?;
+        assert_eq!(table.metadata().default_partition_spec_id(), 1);
+
+        let schema = table.metadata().current_schema().clone();
+        let mut data_file_writer = DataFileWriterBuilder::new(
+            RollingFileWriterBuilder::new_with_default_file_size(
+                ParquetWriterBuilder::new(WriterProperties::builder().build(), schema.clone()),
+                table.file_io().clone(),
+                DefaultLocationGenerator::new(table.metadata().clone())?,
+                DefaultFileNameGenerator::new("test".to_string(), None, DataFileFormat::Parquet),
+            ),
+            table.metadata().default_partition_spec_id(),
+        )
+        .build(None)
+        .await?;
+        let batch =
+            RecordBatch::try_new(Arc::new(schema_to_arrow_schema(&schema)?), vec![Arc::new(
+                Int32Array::from(vec![1, 2]),
+            )])?;
+        data_file_writer.write(batch).await?;
+        let data_files = data_file_writer.close().await?;
+        assert_eq!(data_files[0].partition_spec_id, 1);
+
+        let tx = Transaction::new(&table);
+        let tx = tx.fast_append().add_data_files(data_files).apply(tx)?;
+        let table = tx.commit(&catalog).await?;
+        let manifest_list = table
+            .metadata()
+            .current_snapshot()
+            .unwrap()
+            .load_manifest_list(table.file_io(), table.metadata())
+            .await?;
+        assert_eq!(manifest_list.entries().len(), 1);
+        assert_eq!(manifest_list.entries()[0].partition_spec_id, 1);
+
+        Ok(())
+    }
 }
@@ -70,7 +70,24 @@ pub struct EqualityDeleteWriterConfig {
 
 impl EqualityDeleteWriterConfig {
     /// Create a new `DataFileWriterConfig` with equality ids.
+    ///
+    /// When `equality_ids` is empty, the identifier fields of `original_schema` are used.
     pub fn new(equality_ids: Vec<i32>, original_schema: SchemaRef) -> Result<Self> {
+        let equality_ids = if equality_ids.is_empty() {
+            let identifier_field_ids = original_schema
+                .identifier_field_ids()
+                .sorted()
+                .collect_vec();
+            if identifier_field_ids.is_empty() {
+                return Err(Error::new(
+                    ErrorKind::DataInvalid,
+                    "Equality ids are required when the schema has no identifier fields",
+                ));
+            }
+            identifier_field_ids
+        } else {
+            equality_ids
+        };
         let original_arrow_schema = Arc::new(schema_to_arrow_schema(&original_schema)?);
         let projector = RecordBatchProjector::new(
             original_arrow_schema,
@@ -214,6 +231,7 @@ mod test {
 
     use crate::arrow::{arrow_schema_to_schema, schema_to_arrow_schema};
     use crate::io::{FileIO, FileIOBuilder};
+    use crate::memory::tests::new_memory_catalog;
     use crate::spec::{
         DataFile, DataFileFormat, ListType, MapType, NestedField, PrimitiveType, Schema,
         StructType, Type,
@@ -227,6 +245,7 @@ mod test {
     };
     use crate::writer::file_writer::rolling_writer::RollingFileWriterBuilder;
     use crate::writer::{IcebergWriter, IcebergWriterBuilder};
+    use crate::{Catalog, NamespaceIdent, TableCreation};
 
     async fn check_parquet_data_file_with_equality_delete_write(
         file_io: &FileIO,
@@ -458,6 +477,80 @@ mod test {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_equality_delete_defaults_to_identifier_fields() -> Result<(), anyhow::Error> {
+        let fields = || {
+            vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+                NestedField::optional(2, "name", Type::Primitive(PrimitiveType::String)).into(),
+            ]
+        };
+        // Identifier fields must be required.
+        assert!(
+            Schema::builder()
+                .with_fields(fields())
+                .with_identifier_field_ids(vec![2])
+                .build()
+                .is_err()
+        );
+
+        let catalog = new_memory_catalog().await;
+        let namespace = NamespaceIdent::new("ns".to_string());
+        catalog.create_namespace(&namespace, HashMap::new()).await?;
+        let table = catalog
+            .create_table(
+                &namespace,
+                TableCreation::builder()
+                    .name("t".to_string())
+                    .schema(
+                        Schema::builder()
+                            .with_fields(fields())
+                            .with_identifier_field_ids(vec![1])
+                            .build()?,
+                    )
+                    .build(),
+            )
+            .await?;
+        let schema = table.metadata().current_schema().clone();
+        assert_eq!(schema.identifier_field_ids().collect_vec(), vec![1]);
+
+        let equality_config = EqualityDeleteWriterConfig::new(vec![], schema.clone())?;
+        let delete_schema = arrow_schema_to_schema(equality_config.projected_arrow_schema_ref())?;
+        assert_eq!(delete_schema.as_struct().fields().len(), 1);
+        assert_eq!(delete_schema.as_struct().fields()[0].id, 1);
+
+        let temp_dir = TempDir::new().unwrap();
+        let file_io = FileIOBuilder::new_fs_io().build().unwrap();
+        let rolling_writer_builder = RollingFileWriterBuilder::new_with_default_file_size(
+            ParquetWriterBuilder::new(WriterProperties::builder().build(), Arc::new(delete_schema)),
+            file_io,
+            DefaultLocationGenerator::with_data_location(
+                temp_dir.path().to_str().unwrap().to_string(),
+            ),
+            DefaultFileNameGenerator::new("test".to_string(), None, DataFileFormat::Parquet),
+        );
+        let mut equality_delete_writer =
+            EqualityDeleteFileWriterBuilder::new(rolling_writer_builder, equality_config)
+                .build(None)
+                .await?;
+
+        let arrow_schema = Arc::new(schema_to_arrow_schema(&schema)?);
+        equality_delete_writer
+            .write(RecordBatch::try_new(arrow_schema, vec![
+                Arc::new(Int64Array::from(vec![1, 2])) as ArrayRef,
+                Arc::new(arrow_array::StringArray::from(vec![Some("a"), None])) as ArrayRef,
+            ])?)
+            .await?;
+        let data_files = equality_delete_writer.close().await?;
+        assert_eq!(data_files.len(), 1);
+        assert_eq!(data_files[0].equality_ids(), Some(vec![1]));
+
+        // Without identifier fields the equality ids must be given explicitly.
+        let schema = Arc::new(Schema::builder().with_fields(fields()).build()?);
+        assert!(EqualityDeleteWriterConfig::new(vec![], schema).is_err());
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_equality_delete_unreachable_column() -> Result<(), anyhow::Error> {
         let schema = Arc::new(
@@ -20,8 +20,8 @@
 use std::sync::Arc;
 use std::sync::atomic::AtomicU64;
 
-use crate::Result;
-use crate::spec::{DataFileFormat, PartitionKey, TableMetadata};
+use crate::spec::{DataFileFormat, PartitionKey, TableMetadata, TableProperties};
+use crate::{Error, ErrorKind, Result};
 
 /// `LocationGenerator` used to generate the location of data file.
 pub trait LocationGenerator: Clone + Send + Sync + 'static {
@@ -41,15 +41,31 @@ pub trait LocationGenerator: Clone + Send + Sync + 'static {
     fn generate_location(&self, partition_key: Option<&PartitionKey>, file_name: &str) -> String;
 }
 
-const WRITE_DATA_LOCATION: &str = "write.data.path";
+const WRITE_DATA_LOCATION: &str = TableProperties::PROPERTY_WRITE_DATA_PATH;
 const WRITE_FOLDER_STORAGE_LOCATION: &str = "write.folder-storage.path";
+const WRITE_OBJECT_STORAGE_LOCATION: &str = "write.object-storage.path";
+const WRITE_OBJECT_STORAGE_ENABLED: &str = "write.object-storage.enabled";
+const WRITE_OBJECT_STORAGE_PARTITIONED_PATHS: &str = "write.object-storage.partitioned-paths";
 const DEFAULT_DATA_DIR: &str = "/data";
 
+/// Number of bits of the hash of a file name used in its hashed prefix.
+const HASH_BINARY_STRING_BITS: usize = 20;
+/// Number of bits of the hash in each directory of the hashed prefix.
+const ENTROPY_DIR_LENGTH: usize = 4;
+/// Number of directories of the hashed prefix with `ENTROPY_DIR_LENGTH` bits.
+const ENTROPY_DIR_DEPTH: usize = 3;
+
 #[derive(Clone, Debug)]
 /// `DefaultLocationGenerator` used to generate the data dir location of data file.
 /// The location is generated based on the table location and the data location in table properties.
+///
+/// When `write.object-storage.enabled` is set, the files are spread under hash-prefixed
+/// directories of the data location, e.g., "/table/data/0101/0110/1001/10110010/id=1/part-00000.parquet",
+/// in the same way as the object storage location provider of the Java implementation.
 pub struct DefaultLocationGenerator {
     data_location: String,
+    object_storage: bool,
+    object_storage_partitioned_paths: bool,
 }
 
 impl DefaultLocationGenerator {
@@ -57,15 +73,27 @@ impl DefaultLocationGenerator {
     pub fn new(table_metadata: TableMetadata) -> Result<Self> {
         let table_location = table_metadata.location();
         let prop = table_metadata.properties();
-        let configured_data_location = prop
-            .get(WRITE_DATA_LOCATION)
-            .or(prop.get(WRITE_FOLDER_STORAGE_LOCATION));
+        let object_storage = parse_bool_property(prop.get(WRITE_OBJECT_STORAGE_ENABLED), false)?;
+        let object_storage_partitioned_paths =
+            parse_bool_property(prop.get(WRITE_OBJECT_STORAGE_PARTITIONED_PATHS), true)?;
+        let configured_data_location = if object_storage {
+            prop.get(WRITE_DATA_LOCATION)
+                .or(prop.get(WRITE_OBJECT_STORAGE_LOCATION))
+                .or(prop.get(WRITE_FOLDER_STORAGE_LOCATION))
+        } else {
+            prop.get(WRITE_DATA_LOCATION)
+                .or(prop.get(WRITE_FOLDER_STORAGE_LOCATION))
+        };
         let data_location = if let Some(data_location) = configured_data_location {
             data_location.clone()
         } else {
             format!("{table_location}{DEFAULT_DATA_DIR}")
         };
-        Ok(Self { data_location })
+        Ok(Self {
+            data_location,
+            object_storage,
+            object_storage_partitioned_paths,
+        })
     }
 
     /// Create a new `DefaultLocationGenerator` with a specified data location.
@@ -74,13 +102,65 @@ impl DefaultLocationGenerator {
     ///
     /// * `data_location` - The data location to use for generating file locations.
     pub fn with_data_location(data_location: String) -> Self {
-        Self { data_location }
+        Self {
+            data_location,
+            object_storage: false,
+            object_storage_partitioned_paths: true,
+        }
     }
 }
 
+fn parse_bool_property(value: Option<&String>, default: bool) -> Result<bool> {
+    value.map_or(Ok(default), |value| {
+        value.to_lowercase().parse::<bool>().map_err(|e| {
+            Error::new(
+                ErrorKind::DataInvalid,
+                format!("Invalid boolean table property value: {value}"),
+            )
+            .with_source(e)
+        })
+    })
+}
+
+/// Returns the hashed prefix of `path`: the low `HASH_BINARY_STRING_BITS` bits of its murmur3
+/// hash in binary, split into directories.
+fn hashed_prefix(path: &str) -> String {
+    let hash = murmur3::murmur3_32(&mut path.as_bytes(), 0).unwrap();
+    let bits = format!(
+        "{:0width$b}",
+        hash & ((1 << HASH_BINARY_STRING_BITS) - 1),
+        width = HASH_BINARY_STRING_BITS
+    );
+    let (dirs, rest) = bits.split_at(ENTROPY_DIR_DEPTH * ENTROPY_DIR_LENGTH);
+    let mut prefix = dirs
+        .as_bytes()
+        .chunks(ENTROPY_DIR_LENGTH)
+        .map(|dir| std::str::from_utf8(dir).unwrap())
+        .collect::<Vec<_>>()
+        .join("/");
+    if !rest.is_empty() {
+        prefix.push('/');
+        prefix.push_str(rest);
+    }
+    prefix
+}
+
 impl LocationGenerator for DefaultLocationGenerator {
     fn generate_location(&self, partition_key: Option<&PartitionKey>, file_name: &str) -> String {
-        if PartitionKey::is_effectively_none(partition_key) {
+        if self.object_storage {
+            // The hash covers the partition path too, so files of a partition are spread
+            // over the prefixes as well.
+            let path = match partition_key {
+                Some(partition_key)
+                    if self.object_storage_partitioned_paths
+                        && !PartitionKey::is_effectively_none(Some(partition_key)) =>
+                {
+                    format!("{}/{}", partition_key.to_path(), file_name)
+                }
+                _ => file_name.to_string(),
+            };
+            format!("{}/{}/{}", self.data_location, hashed_prefix(&path), path)
+        } else if PartitionKey::is_effectively_none(partition_key) {
             format!("{}/{}", self.data_location, file_name)
         } else {
             format!(
@@ -154,7 +234,8 @@ pub(crate) mod test {
     };
     use crate::writer::file_writer::location_generator::{
         DefaultLocationGenerator, FileNameGenerator, WRITE_DATA_LOCATION,
-        WRITE_FOLDER_STORAGE_LOCATION,
+        WRITE_FOLDER_STORAGE_LOCATION, WRITE_OBJECT_STORAGE_ENABLED,
+        WRITE_OBJECT_STORAGE_PARTITIONED_PATHS,
     };
 
     #[test]
@@ -309,4 +390,90 @@ pub(crate) mod test {
             "s3://data.db/table/data/id=42/name=alice/data-00000.parquet"
         );
     }
+
+    #[test]
+    fn test_object_storage_location_generate() {
+        let schema = Arc::new(
+            Schema::builder()
+                .with_schema_id(1)
+                .with_fields(vec![
+                    NestedField::required(1, "id", Type::Primitive(PrimitiveType::Int)).into(),
+                    NestedField::required(2, "name", Type::Primitive(PrimitiveType::String)).into(),
+                ])
+                .build()
+                .unwrap(),
+        );
+        let partition_spec = PartitionSpec::builder(schema.clone())
+            .add_partition_field("id", "id", Transform::Identity)
+            .unwrap()
+            .add_partition_field("name", "name", Transform::Identity)
+            .unwrap()
+            .build()
+            .unwrap();
+        let partition_key = PartitionKey::new(
+            partition_spec,
+            schema,
+            Struct::from_iter([Some(Literal::int(42)), Some(Literal::string("alice"))]),
+        );
+
+        let mut table_metadata = TableMetadata {
+            format_version: FormatVersion::V2,
+            table_uuid: Uuid::parse_str("fb072c92-a02b-11e9-ae9c-1bb7bc9eca94").unwrap(),
+            location: "s3://data.db/table".to_string(),
+            last_updated_ms: 1515100955770,
+            last_column_id: 2,
+            schemas: HashMap::new(),
+            current_schema_id: 1,
+            partition_specs: HashMap::new(),
+            default_spec: PartitionSpec::unpartition_spec().into(),
+            default_partition_type: StructType::new(vec![]),
+            last_partition_id: 1000,
+            default_sort_order_id: 0,
+            sort_orders: HashMap::from_iter(vec![]),
+            snapshots: HashMap::default(),
+            current_snapshot_id: None,
+            last_sequence_number: 1,
+            properties: HashMap::from([(
+                WRITE_OBJECT_STORAGE_ENABLED.to_string(),
+                "true".to_string(),
+            )]),
+            snapshot_log: Vec::new(),
+            metadata_log: vec![],
+            refs: HashMap::new(),
+            statistics: HashMap::new(),
+            partition_statistics: HashMap::new(),
+            encryption_keys: HashMap::new(),
+            next_row_id: 0,
+        };
+        let file_name = "data-00000.parquet";
+
+        let location_gen = DefaultLocationGenerator::new(table_metadata.clone()).unwrap();
+        assert_eq!(
+            location_gen.generate_location(None, file_name),
+            "s3://data.db/table/data/0011/0110/1011/10110001/data-00000.parquet"
+        );
+        assert_eq!(
+            location_gen.generate_location(Some(&partition_key), file_name),
+            "s3://data.db/table/data/0101/0000/0110/11111110/id=42/name=alice/data-00000.parquet"
+        );
+
+        table_metadata.properties.insert(
+            WRITE_DATA_LOCATION.to_string(),
+            "s3://bucket/data".to_string(),
+        );
+        table_metadata.properties.insert(
+            WRITE_OBJECT_STORAGE_PARTITIONED_PATHS.to_string(),
+            "false".to_string(),
+        );
+        let location_gen = DefaultLocationGenerator::new(table_metadata.clone()).unwrap();
+        assert_eq!(
+            location_gen.generate_location(Some(&partition_key), file_name),
+            "s3://bucket/data/0011/0110/1011/10110001/data-00000.parquet"
+        );
+
+        table_metadata
+            .properties
+            .insert(WRITE_OBJECT_STORAGE_ENABLED.to_string(), "yes".to_string());
+        assert!(DefaultLocationGenerator::new(table_metadata).is_err());
+    }
 }
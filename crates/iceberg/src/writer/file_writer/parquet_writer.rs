@@ -30,17 +30,20 @@ use parquet::arrow::async_writer::AsyncFileWriter as ArrowAsyncFileWriter;
 use parquet::file::metadata::ParquetMetaData;
 use parquet::file::properties::WriterProperties;
 use parquet::file::statistics::Statistics;
+use parquet::schema::types::{ColumnDescriptor, ColumnPath};
 
 use super::{FileWriter, FileWriterBuilder};
 use crate::arrow::{
     ArrowFileReader, DEFAULT_MAP_FIELD_NAME, FieldMatchMode, NanValueCountVisitor,
     get_parquet_stat_max_as_datum, get_parquet_stat_min_as_datum,
 };
+#[cfg(feature = "encryption")]
+use crate::encryption::ParquetEncryption;
 use crate::io::{FileIO, FileWrite, OutputFile};
 use crate::spec::{
-    DataContentType, DataFileBuilder, DataFileFormat, Datum, ListType, Literal, MapType,
-    NestedFieldRef, PartitionSpec, PrimitiveType, Schema, SchemaRef, SchemaVisitor, Struct,
-    StructType, TableMetadata, Type, visit_schema,
+    DEFAULT_SCHEMA_NAME_MAPPING, DataContentType, DataFileBuilder, DataFileFormat, Datum, ListType,
+    Literal, MapType, NameMapping, NestedFieldRef, PartitionSpec, PrimitiveLiteral, PrimitiveType,
+    Schema, SchemaRef, SchemaVisitor, Struct, StructType, TableMetadata, Type, visit_schema,
 };
 use crate::transform::create_transform_function;
 use crate::writer::{CurrentFileStatus, DataFile};
@@ -52,6 +55,8 @@ pub struct ParquetWriterBuilder {
     props: WriterProperties,
     schema: SchemaRef,
     match_mode: FieldMatchMode,
+    key_metadata: Option<Vec<u8>>,
+    truncate_length: Option<usize>,
 }
 
 impl ParquetWriterBuilder {
@@ -71,8 +76,51 @@ impl ParquetWriterBuilder {
             props,
             schema,
             match_mode,
+            key_metadata: None,
+            truncate_length: None,
         }
     }
+
+    /// Enable Parquet bloom filters for the given columns, named by their dot-separated path
+    /// such as `location.city`.
+    pub fn with_bloom_filter_columns(
+        mut self,
+        columns: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Self {
+        let mut props = self.props.into_builder();
+        for column in columns {
+            let path = ColumnPath::new(column.as_ref().split('.').map(str::to_string).collect());
+            props = props.set_column_bloom_filter_enabled(path, true);
+        }
+        self.props = props.build();
+        self
+    }
+
+    /// Truncate the lower and upper bounds of string and binary columns of the written data
+    /// files to at most `length` characters for strings and `length` bytes for binaries.
+    ///
+    /// Truncated lower bounds are rounded down and upper bounds are rounded up, so they still
+    /// bound the column values when filtering. An upper bound that can't be rounded up is left
+    /// out. `None` keeps full bounds.
+    pub fn with_statistics_truncate_length(mut self, length: Option<usize>) -> Self {
+        self.truncate_length = length;
+        self
+    }
+
+    /// Encrypt the written files with Parquet modular encryption.
+    ///
+    /// The keys are retrieved once, when calling this method. The metadata of the footer key
+    /// is recorded as the key metadata of the written data files.
+    #[cfg(feature = "encryption")]
+    pub fn with_encryption(mut self, encryption: &ParquetEncryption) -> Result<Self> {
+        self.props = self
+            .props
+            .into_builder()
+            .with_file_encryption_properties(encryption.file_encryption_properties()?)
+            .build();
+        self.key_metadata = Some(encryption.footer_key_metadata().to_vec());
+        Ok(self)
+    }
 }
 
 impl FileWriterBuilder for ParquetWriterBuilder {
@@ -83,6 +131,8 @@ impl FileWriterBuilder for ParquetWriterBuilder {
             schema: self.schema.clone(),
             inner_writer: None,
             writer_properties: self.props.clone(),
+            key_metadata: self.key_metadata.clone(),
+            truncate_length: self.truncate_length,
             current_row_num: 0,
             output_file,
             nan_value_count_visitor: NanValueCountVisitor::new_with_match_mode(self.match_mode),
@@ -214,6 +264,8 @@ pub struct ParquetWriter {
     output_file: OutputFile,
     inner_writer: Option<AsyncArrowWriter<AsyncFileWriter>>,
     writer_properties: WriterProperties,
+    key_metadata: Option<Vec<u8>>,
+    truncate_length: Option<usize>,
     current_row_num: usize,
     nan_value_count_visitor: NanValueCountVisitor,
 }
@@ -275,7 +327,11 @@ impl MinMaxColAggregator {
             ));
         };
 
-        if value.min_is_exact() {
+        // Truncated string and binary statistics are inexact, but the lower bound is rounded
+        // down and the upper bound rounded up, so they are still valid bounds.
+        let truncatable = matches!(ty, PrimitiveType::String | PrimitiveType::Binary);
+
+        if value.min_is_exact() || (truncatable && value.min_bytes_opt().is_some()) {
             let Some(min_datum) = get_parquet_stat_min_as_datum(&ty, &value)? else {
                 return Err(Error::new(
                     ErrorKind::Unexpected,
@@ -286,7 +342,7 @@ impl MinMaxColAggregator {
             self.update_state_min(field_id, min_datum);
         }
 
-        if value.max_is_exact() {
+        if value.max_is_exact() || (truncatable && value.max_bytes_opt().is_some()) {
             let Some(max_datum) = get_parquet_stat_max_as_datum(&ty, &value)? else {
                 return Err(Error::new(
                     ErrorKind::Unexpected,
@@ -300,59 +356,157 @@ impl MinMaxColAggregator {
         Ok(())
     }
 
-    /// Returns lower and upper bounds
-    fn produce(self) -> (HashMap<i32, Datum>, HashMap<i32, Datum>) {
-        (self.lower_bounds, self.upper_bounds)
+    /// Returns lower and upper bounds, with the string and binary bounds truncated to
+    /// `truncate_length` when set.
+    fn produce(self, truncate_length: Option<usize>) -> (HashMap<i32, Datum>, HashMap<i32, Datum>) {
+        let Some(length) = truncate_length else {
+            return (self.lower_bounds, self.upper_bounds);
+        };
+        let lower_bounds = self
+            .lower_bounds
+            .into_iter()
+            .map(|(field_id, datum)| (field_id, truncate_lower_bound(datum, length)))
+            .collect();
+        let upper_bounds = self
+            .upper_bounds
+            .into_iter()
+            .filter_map(|(field_id, datum)| {
+                truncate_upper_bound(datum, length).map(|datum| (field_id, datum))
+            })
+            .collect();
+        (lower_bounds, upper_bounds)
+    }
+}
+
+/// Truncates string and binary lower bounds to `length` characters or bytes, rounding down.
+fn truncate_lower_bound(datum: Datum, length: usize) -> Datum {
+    match datum.literal() {
+        PrimitiveLiteral::String(value) => match value.char_indices().nth(length) {
+            Some((end, _)) => Datum::string(&value[..end]),
+            None => datum,
+        },
+        PrimitiveLiteral::Binary(value) if value.len() > length => {
+            Datum::binary(value[..length].to_vec())
+        }
+        _ => datum,
+    }
+}
+
+/// Truncates string and binary upper bounds to `length` characters or bytes, rounding up by
+/// incrementing the last character or byte that can be incremented. Returns `None` when no
+/// truncated bound is larger than the value.
+fn truncate_upper_bound(datum: Datum, length: usize) -> Option<Datum> {
+    match datum.literal() {
+        PrimitiveLiteral::String(value) if value.chars().nth(length).is_some() => {
+            let mut prefix: Vec<char> = value.chars().take(length).collect();
+            while let Some(last) = prefix.pop() {
+                // Skips the surrogate code points, which aren't valid characters
+                if let Some(next) = (last as u32 + 1..=char::MAX as u32).find_map(char::from_u32) {
+                    prefix.push(next);
+                    return Some(Datum::string(prefix.into_iter().collect::<String>()));
+                }
+            }
+            None
+        }
+        PrimitiveLiteral::Binary(value) if value.len() > length => {
+            let mut prefix = value[..length].to_vec();
+            while let Some(last) = prefix.pop() {
+                if last < u8::MAX {
+                    prefix.push(last + 1);
+                    return Some(Datum::binary(prefix));
+                }
+            }
+            None
+        }
+        _ => Some(datum),
     }
 }
 
 impl ParquetWriter {
-    /// Converts parquet files to data files
-    #[allow(dead_code)]
-    pub(crate) async fn parquet_files_to_data_files(
+    /// Converts the existing Parquet file `file_path` into a data file of the default
+    /// partition spec of `table_metadata`, with the metrics of its footer.
+    ///
+    /// The partition of the data file is `partition`, or is derived from the column bounds of
+    /// the file when `None`, which requires every partition source column to hold a single
+    /// value.
+    ///
+    /// The columns of the file are matched to the fields of the current schema by their field
+    /// ids, or, for files written without field ids, by the name mapping of the table in
+    /// [`DEFAULT_SCHEMA_NAME_MAPPING`], falling back to the names of the current schema.
+    pub(crate) async fn parquet_file_to_data_file(
         file_io: &FileIO,
-        file_paths: Vec<String>,
+        file_path: String,
+        partition: Option<Struct>,
         table_metadata: &TableMetadata,
-    ) -> Result<Vec<DataFile>> {
-        // TODO: support adding to partitioned table
-        let mut data_files: Vec<DataFile> = Vec::new();
-
-        for file_path in file_paths {
-            let input_file = file_io.new_input(&file_path)?;
-            let file_metadata = input_file.metadata().await?;
-            let file_size_in_bytes = file_metadata.size as usize;
-            let reader = input_file.reader().await?;
-
-            let mut parquet_reader = ArrowFileReader::new(file_metadata, reader);
-            let parquet_metadata = parquet_reader.get_metadata(None).await.map_err(|err| {
-                Error::new(
-                    ErrorKind::DataInvalid,
-                    format!("Error reading Parquet metadata: {err}"),
-                )
-            })?;
-            let mut builder = ParquetWriter::parquet_to_data_file_builder(
-                table_metadata.current_schema().clone(),
-                parquet_metadata,
-                file_size_in_bytes,
-                file_path,
-                // TODO: Implement nan_value_counts here
-                HashMap::new(),
-            )?;
-            builder.partition_spec_id(table_metadata.default_partition_spec_id());
-            let data_file = builder.build().unwrap();
-            data_files.push(data_file);
-        }
+    ) -> Result<DataFile> {
+        let input_file = file_io.new_input(&file_path)?;
+        let file_metadata = input_file.metadata().await?;
+        let file_size_in_bytes = file_metadata.size as usize;
+        let reader = input_file.reader().await?;
+
+        let mut parquet_reader = ArrowFileReader::new(file_metadata, reader);
+        let parquet_metadata = parquet_reader.get_metadata(None).await.map_err(|err| {
+            Error::new(
+                ErrorKind::DataInvalid,
+                format!("Error reading Parquet metadata of {file_path}: {err}"),
+            )
+        })?;
+        let name_mapping = table_metadata
+            .properties()
+            .get(DEFAULT_SCHEMA_NAME_MAPPING)
+            .map(|name_mapping| {
+                serde_json::from_str::<NameMapping>(name_mapping).map_err(|err| {
+                    Error::new(
+                        ErrorKind::DataInvalid,
+                        format!("Invalid name mapping in {DEFAULT_SCHEMA_NAME_MAPPING}"),
+                    )
+                    .with_source(err)
+                })
+            })
+            .transpose()?;
+        let mut builder = ParquetWriter::parquet_to_data_file_builder(
+            table_metadata.current_schema().clone(),
+            parquet_metadata,
+            file_size_in_bytes,
+            file_path.clone(),
+            // TODO: Implement nan_value_counts here
+            HashMap::new(),
+            None,
+            name_mapping.as_ref(),
+        )?;
+        builder.partition_spec_id(table_metadata.default_partition_spec_id());
+        let mut data_file = builder.build().map_err(|e| {
+            Error::new(
+                ErrorKind::DataInvalid,
+                format!("Failed to build data file: {e}"),
+            )
+        })?;
 
-        Ok(data_files)
+        data_file.partition = match partition {
+            Some(partition) => partition,
+            None => Self::partition_value_from_bounds(
+                table_metadata.default_partition_spec().clone(),
+                &data_file.lower_bounds,
+                &data_file.upper_bounds,
+            )
+            .map_err(|e| e.with_context("file_path", file_path))?,
+        };
+        Ok(data_file)
     }
 
     /// `ParquetMetadata` to data file builder
+    ///
+    /// The columns without field id are matched to the fields of `schema` through
+    /// `name_mapping`, if any, or by name.
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn parquet_to_data_file_builder(
         schema: SchemaRef,
         metadata: Arc<ParquetMetaData>,
         written_size: usize,
         file_path: String,
         nan_value_counts: HashMap<i32, u64>,
+        truncate_length: Option<usize>,
+        name_mapping: Option<&NameMapping>,
     ) -> Result<DataFileBuilder> {
         let index_by_parquet_path = {
             let mut visitor = IndexByParquetPathName::new();
@@ -364,13 +518,16 @@ impl ParquetWriter {
             let mut per_col_size: HashMap<i32, u64> = HashMap::new();
             let mut per_col_val_num: HashMap<i32, u64> = HashMap::new();
             let mut per_col_null_val_num: HashMap<i32, u64> = HashMap::new();
-            let mut min_max_agg = MinMaxColAggregator::new(schema);
+            let mut min_max_agg = MinMaxColAggregator::new(schema.clone());
 
             for row_group in metadata.row_groups() {
                 for column_chunk_metadata in row_group.columns() {
-                    let parquet_path = column_chunk_metadata.column_descr().path().string();
-
-                    let Some(&field_id) = index_by_parquet_path.get(&parquet_path) else {
+                    let Some(field_id) = Self::column_field_id(
+                        column_chunk_metadata.column_descr(),
+                        &schema,
+                        name_mapping,
+                        &index_by_parquet_path,
+                    ) else {
                         continue;
                     };
 
@@ -392,7 +549,7 @@ impl ParquetWriter {
                 per_col_size,
                 per_col_val_num,
                 per_col_null_val_num,
-                min_max_agg.produce(),
+                min_max_agg.produce(truncate_length),
             )
         };
 
@@ -423,7 +580,37 @@ impl ParquetWriter {
         Ok(builder)
     }
 
-    #[allow(dead_code)]
+    /// Returns the id of the field of `schema` held by `column`, which is the field id of the
+    /// column if it has one.
+    ///
+    /// Otherwise, as when reading, the top-level column of `column` is mapped to its field
+    /// through `name_mapping`, and the columns are matched by their names in `schema`.
+    fn column_field_id(
+        column: &ColumnDescriptor,
+        schema: &Schema,
+        name_mapping: Option<&NameMapping>,
+        index_by_parquet_path: &IndexByParquetPathName,
+    ) -> Option<i32> {
+        let basic_info = column.self_type().get_basic_info();
+        if basic_info.has_id() {
+            let field_id = basic_info.id();
+            return schema.field_by_id(field_id).map(|_| field_id);
+        }
+
+        let mut path = column.path().parts().to_vec();
+        if let Some(name_mapping) = name_mapping {
+            let field_id = name_mapping
+                .fields()
+                .iter()
+                .find(|field| field.names().contains(&path[0]))?
+                .field_id()?;
+            path[0] = schema.name_by_field_id(field_id)?.to_string();
+        }
+        index_by_parquet_path
+            .get(&ColumnPath::new(path).string())
+            .copied()
+    }
+
     fn partition_value_from_bounds(
         table_spec: Arc<PartitionSpec>,
         lower_bounds: &HashMap<i32, Datum>,
@@ -540,13 +727,17 @@ impl FileWriter for ParquetWriter {
         } else {
             let parquet_metadata = Arc::new(metadata);
 
-            Ok(vec![Self::parquet_to_data_file_builder(
+            let mut builder = Self::parquet_to_data_file_builder(
                 self.schema,
                 parquet_metadata,
                 written_size,
                 self.output_file.location().to_string(),
                 self.nan_value_count_visitor.nan_value_counts,
-            )?])
+                self.truncate_length,
+                None,
+            )?;
+            builder.key_metadata(self.key_metadata);
+            Ok(vec![builder])
         }
     }
 }
@@ -1620,6 +1811,132 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_write_bloom_filter_columns() -> Result<()> {
+        let temp_dir = TempDir::new().unwrap();
+        let file_io = FileIOBuilder::new_fs_io().build().unwrap();
+        let location_gen = DefaultLocationGenerator::with_data_location(
+            temp_dir.path().to_str().unwrap().to_string(),
+        );
+        let file_name_gen =
+            DefaultFileNameGenerator::new("test".to_string(), None, DataFileFormat::Parquet);
+
+        let schema = Arc::new(
+            Schema::builder()
+                .with_fields(vec![
+                    NestedField::required(0, "id", Type::Primitive(PrimitiveType::Long)).into(),
+                    NestedField::required(1, "value", Type::Primitive(PrimitiveType::Long)).into(),
+                ])
+                .build()
+                .unwrap(),
+        );
+        let arrow_schema: ArrowSchemaRef = Arc::new(schema_to_arrow_schema(&schema).unwrap());
+        let to_write = RecordBatch::try_new(arrow_schema, vec![
+            Arc::new(Int64Array::from_iter_values(0..1024)) as ArrayRef,
+            Arc::new(Int64Array::from_iter_values(0..1024)) as ArrayRef,
+        ])
+        .unwrap();
+
+        let output_file = file_io.new_output(
+            location_gen.generate_location(None, &file_name_gen.generate_file_name()),
+        )?;
+        let mut pw = ParquetWriterBuilder::new(WriterProperties::builder().build(), schema)
+            .with_bloom_filter_columns(["id"])
+            .build(output_file)
+            .await?;
+        pw.write(&to_write).await?;
+        let res = pw.close().await?;
+        assert_eq!(res.len(), 1);
+        let data_file = res[0].clone().partition(Struct::empty()).build().unwrap();
+
+        let input_file = file_io.new_input(data_file.file_path())?;
+        let reader_builder =
+            parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder::try_new(
+                input_file.read().await?,
+            )?;
+        let row_group = reader_builder.metadata().row_group(0);
+        assert!(row_group.column(0).bloom_filter_offset().is_some());
+        assert!(row_group.column(1).bloom_filter_offset().is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_write_statistics_truncate_length() -> Result<()> {
+        let temp_dir = TempDir::new().unwrap();
+        let file_io = FileIOBuilder::new_fs_io().build().unwrap();
+        let location_gen = DefaultLocationGenerator::with_data_location(
+            temp_dir.path().to_str().unwrap().to_string(),
+        );
+        let file_name_gen =
+            DefaultFileNameGenerator::new("test".to_string(), None, DataFileFormat::Parquet);
+
+        let schema = Arc::new(
+            Schema::builder()
+                .with_fields(vec![
+                    NestedField::required(0, "name", Type::Primitive(PrimitiveType::String)).into(),
+                    NestedField::required(1, "city", Type::Primitive(PrimitiveType::String)).into(),
+                ])
+                .build()
+                .unwrap(),
+        );
+        let min = format!("apple{}", "x".repeat(50));
+        let max = format!("zebra{}", "y".repeat(50));
+        let arrow_schema: ArrowSchemaRef = Arc::new(schema_to_arrow_schema(&schema).unwrap());
+        let to_write = RecordBatch::try_new(arrow_schema, vec![
+            Arc::new(arrow_array::StringArray::from(vec![
+                max.as_str(),
+                "mango",
+                min.as_str(),
+            ])) as ArrayRef,
+            Arc::new(arrow_array::StringArray::from(vec![
+                "東京都千代田区丸の内一丁目",
+                "東京",
+                "大阪府大阪市北区梅田三丁目",
+            ])) as ArrayRef,
+        ])
+        .unwrap();
+
+        let output_file = file_io.new_output(
+            location_gen.generate_location(None, &file_name_gen.generate_file_name()),
+        )?;
+        let mut pw = ParquetWriterBuilder::new(WriterProperties::builder().build(), schema)
+            .with_statistics_truncate_length(Some(8))
+            .build(output_file)
+            .await?;
+        pw.write(&to_write).await?;
+        let res = pw.close().await?;
+        let data_file = res[0].clone().partition(Struct::empty()).build().unwrap();
+
+        // The lower bound is rounded down to a prefix and the upper bound rounded up by
+        // incrementing the last character of its prefix.
+        let lower = data_file.lower_bounds().get(&0).unwrap();
+        let upper = data_file.upper_bounds().get(&0).unwrap();
+        assert_eq!(lower, &Datum::string("applexxx"));
+        assert_eq!(upper, &Datum::string("zebrayyz"));
+        assert!(lower <= &Datum::string(&min));
+        assert!(upper >= &Datum::string(&max));
+
+        // Multibyte strings are truncated to 8 characters rather than 8 bytes.
+        let lower = data_file.lower_bounds().get(&1).unwrap();
+        let upper = data_file.upper_bounds().get(&1).unwrap();
+        assert_eq!(lower, &Datum::string("大阪府大阪市北区"));
+        assert_eq!(upper, &Datum::string("東京都千代田区丹"));
+
+        // Upper bounds that can't be rounded up are left out.
+        assert_eq!(
+            truncate_upper_bound(Datum::binary([0x01, 0xff, 0xff]), 2),
+            Some(Datum::binary([0x02]))
+        );
+        assert_eq!(truncate_upper_bound(Datum::binary([0xff; 3]), 2), None);
+        assert_eq!(
+            truncate_upper_bound(Datum::string(format!("{}a", char::MAX)), 1),
+            None
+        );
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_nan_val_cnts_primitive_type() -> Result<()> {
         let temp_dir = TempDir::new().unwrap();
@@ -2274,7 +2591,7 @@ mod tests {
             .update(0, create_statistics(None, None))
             .unwrap();
 
-        let (lower_bounds, upper_bounds) = min_max_agg.produce();
+        let (lower_bounds, upper_bounds) = min_max_agg.produce(None);
 
         assert_eq!(lower_bounds, HashMap::from([(0, Datum::int(i32::MIN))]));
         assert_eq!(upper_bounds, HashMap::from([(0, Datum::int(i32::MAX))]));
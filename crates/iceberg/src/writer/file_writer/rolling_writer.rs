@@ -269,7 +269,7 @@ mod tests {
 
     use super::*;
     use crate::io::FileIOBuilder;
-    use crate::spec::{DataFileFormat, NestedField, PrimitiveType, Schema, Type};
+    use crate::spec::{DataFileFormat, NestedField, PartitionSpec, PrimitiveType, Schema, Type};
     use crate::writer::base_writer::data_file_writer::DataFileWriterBuilder;
     use crate::writer::file_writer::ParquetWriterBuilder;
     use crate::writer::file_writer::location_generator::{
@@ -327,7 +327,10 @@ mod tests {
             file_name_gen,
         );
 
-        let data_file_writer_builder = DataFileWriterBuilder::new(rolling_file_writer_builder);
+        let data_file_writer_builder = DataFileWriterBuilder::new(
+            rolling_file_writer_builder,
+            PartitionSpec::unpartition_spec().spec_id(),
+        );
 
         // Create writer
         let mut writer = data_file_writer_builder.build(None).await?;
@@ -385,7 +388,10 @@ mod tests {
             file_name_gen,
         );
 
-        let data_file_writer_builder = DataFileWriterBuilder::new(rolling_writer_builder);
+        let data_file_writer_builder = DataFileWriterBuilder::new(
+            rolling_writer_builder,
+            PartitionSpec::unpartition_spec().spec_id(),
+        );
 
         // Create writer
         let mut writer = data_file_writer_builder.build(None).await?;
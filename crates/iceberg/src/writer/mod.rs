@@ -100,7 +100,10 @@
 //!     );
 //!
 //!     // Create a data file writer using parquet file writer builder.
-//!     let data_file_writer_builder = DataFileWriterBuilder::new(rolling_file_writer_builder);
+//!     let data_file_writer_builder = DataFileWriterBuilder::new(
+//!         rolling_file_writer_builder,
+//!         table.metadata().default_partition_spec_id(),
+//!     );
 //!     // Build the data file writer
 //!     let mut data_file_writer = data_file_writer_builder.build(None).await?;
 //!
@@ -230,7 +233,10 @@
 //!     );
 //!
 //!     // Create a data file writer builder using rolling file writer.
-//!     let data_file_writer_builder = DataFileWriterBuilder::new(rolling_file_writer_builder);
+//!     let data_file_writer_builder = DataFileWriterBuilder::new(
+//!         rolling_file_writer_builder,
+//!         table.metadata().default_partition_spec_id(),
+//!     );
 //!     // Create latency record writer using data file writer builder.
 //!     let latency_record_builder = LatencyRecordWriterBuilder::new(data_file_writer_builder);
 //!     // Build the final writer
@@ -277,7 +283,8 @@
 //! # let parquet_writer_builder = ParquetWriterBuilder::new(WriterProperties::default(), table.metadata().current_schema().clone());
 //! # let rolling_writer_builder = RollingFileWriterBuilder::new_with_default_file_size(
 //! #     parquet_writer_builder, table.file_io().clone(), location_generator, file_name_generator);
-//! # let data_file_writer_builder = DataFileWriterBuilder::new(rolling_writer_builder);
+//! # let data_file_writer_builder = DataFileWriterBuilder::new(
+//! #     rolling_writer_builder, table.metadata().default_partition_spec_id());
 //!
 //! // Wrap the data file writer with FanoutWriter for partitioning
 //! use iceberg::writer::partitioning::fanout_writer::FanoutWriter;
@@ -341,7 +348,8 @@
 //! # let parquet_writer_builder = ParquetWriterBuilder::new(WriterProperties::default(), table.metadata().current_schema().clone());
 //! # let rolling_writer_builder = RollingFileWriterBuilder::new_with_default_file_size(
 //! #     parquet_writer_builder, table.file_io().clone(), location_generator, file_name_generator);
-//! # let data_file_writer_builder = DataFileWriterBuilder::new(rolling_writer_builder);
+//! # let data_file_writer_builder = DataFileWriterBuilder::new(
+//! #     rolling_writer_builder, table.metadata().default_partition_spec_id());
 //!
 //! // Wrap the data file writer with ClusteredWriter for sorted partitioning
 //! use iceberg::writer::partitioning::clustered_writer::ClusteredWriter;
@@ -387,6 +395,7 @@
 pub mod base_writer;
 pub mod file_writer;
 pub mod partitioning;
+pub mod streaming_append_writer;
 
 use arrow_array::RecordBatch;
 
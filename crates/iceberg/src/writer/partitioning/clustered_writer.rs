@@ -204,7 +204,8 @@ mod tests {
         );
 
         // Create data file writer builder
-        let data_file_writer_builder = DataFileWriterBuilder::new(rolling_writer_builder);
+        let data_file_writer_builder =
+            DataFileWriterBuilder::new(rolling_writer_builder, partition_key.spec().spec_id());
 
         // Create clustered writer
         let mut writer = ClusteredWriter::new(data_file_writer_builder);
@@ -322,7 +323,8 @@ mod tests {
         );
 
         // Create data file writer builder
-        let data_file_writer_builder = DataFileWriterBuilder::new(rolling_writer_builder);
+        let data_file_writer_builder =
+            DataFileWriterBuilder::new(rolling_writer_builder, partition_spec.spec_id());
 
         // Create clustered writer
         let mut writer = ClusteredWriter::new(data_file_writer_builder);
@@ -455,7 +457,8 @@ mod tests {
         );
 
         // Create data file writer builder
-        let data_file_writer_builder = DataFileWriterBuilder::new(rolling_writer_builder);
+        let data_file_writer_builder =
+            DataFileWriterBuilder::new(rolling_writer_builder, partition_spec.spec_id());
 
         // Create clustered writer
         let mut writer = ClusteredWriter::new(data_file_writer_builder);
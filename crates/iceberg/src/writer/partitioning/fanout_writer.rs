@@ -34,6 +34,10 @@ use crate::{Error, ErrorKind, Result};
 /// This allows writing to any partition at any time, but uses more memory as all writers
 /// remain active until the writer is closed.
 ///
+/// When the input is sorted by partition within each batch, see
+/// [`FanoutWriter::with_local_partition_sort`], only the writer of the partition being written
+/// is kept active.
+///
 /// # Type Parameters
 ///
 /// * `B` - The inner writer builder type
@@ -48,6 +52,7 @@ where
     inner_builder: B,
     partition_writers: HashMap<Struct, B::R>,
     output: Vec<<O as IntoIterator>::Item>,
+    local_partition_sort: bool,
     _phantom: PhantomData<I>,
 }
 
@@ -64,10 +69,41 @@ where
             inner_builder,
             partition_writers: HashMap::new(),
             output: Vec::new(),
+            local_partition_sort: false,
             _phantom: PhantomData,
         }
     }
 
+    /// Set whether the input is sorted by partition within each batch, such as the batches
+    /// written by a [`RecordBatchPartitionSplitter`] ordered by partition key. Defaults to
+    /// `false`.
+    ///
+    /// When enabled, the writers of the other partitions are closed when a partition is
+    /// written, so at most one file is open at a time. A partition written again later gets
+    /// a new file, so unsorted input produces more and smaller files.
+    ///
+    /// [`RecordBatchPartitionSplitter`]: crate::arrow::RecordBatchPartitionSplitter
+    pub fn with_local_partition_sort(mut self, local_partition_sort: bool) -> Self {
+        self.local_partition_sort = local_partition_sort;
+        self
+    }
+
+    /// Close the writers of all partitions but `partition`.
+    async fn close_other_writers(&mut self, partition: &Struct) -> Result<()> {
+        let other_partitions: Vec<Struct> = self
+            .partition_writers
+            .keys()
+            .filter(|key| *key != partition)
+            .cloned()
+            .collect();
+        for key in other_partitions {
+            if let Some(mut writer) = self.partition_writers.remove(&key) {
+                self.output.extend(writer.close().await?);
+            }
+        }
+        Ok(())
+    }
+
     /// Get or create a writer for the specified partition.
     async fn get_or_create_writer(&mut self, partition_key: &PartitionKey) -> Result<&mut B::R> {
         if !self.partition_writers.contains_key(partition_key.data()) {
@@ -99,6 +135,9 @@ where
     <O as IntoIterator>::Item: Send + Clone,
 {
     async fn write(&mut self, partition_key: PartitionKey, input: I) -> Result<()> {
+        if self.local_partition_sort {
+            self.close_other_writers(partition_key.data()).await?;
+        }
         let writer = self.get_or_create_writer(&partition_key).await?;
         writer.write(input).await
     }
@@ -180,7 +219,8 @@ mod tests {
         );
 
         // Create data file writer builder
-        let data_file_writer_builder = DataFileWriterBuilder::new(rolling_writer_builder);
+        let data_file_writer_builder =
+            DataFileWriterBuilder::new(rolling_writer_builder, partition_key.spec().spec_id());
 
         // Create fanout writer
         let mut writer = FanoutWriter::new(data_file_writer_builder);
@@ -295,7 +335,8 @@ mod tests {
         );
 
         // Create data file writer builder
-        let data_file_writer_builder = DataFileWriterBuilder::new(rolling_writer_builder);
+        let data_file_writer_builder =
+            DataFileWriterBuilder::new(rolling_writer_builder, partition_spec.spec_id());
 
         // Create fanout writer
         let mut writer = FanoutWriter::new(data_file_writer_builder);
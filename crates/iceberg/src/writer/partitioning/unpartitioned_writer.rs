@@ -116,7 +116,7 @@ mod tests {
     use super::*;
     use crate::Result;
     use crate::io::FileIOBuilder;
-    use crate::spec::{DataFileFormat, NestedField, PrimitiveType, Struct, Type};
+    use crate::spec::{DataFileFormat, NestedField, PartitionSpec, PrimitiveType, Struct, Type};
     use crate::writer::base_writer::data_file_writer::DataFileWriterBuilder;
     use crate::writer::file_writer::ParquetWriterBuilder;
     use crate::writer::file_writer::location_generator::{
@@ -166,7 +166,10 @@ mod tests {
             location_gen,
             file_name_gen,
         );
-        let writer_builder = DataFileWriterBuilder::new(rolling_writer_builder);
+        let writer_builder = DataFileWriterBuilder::new(
+            rolling_writer_builder,
+            PartitionSpec::unpartition_spec().spec_id(),
+        );
 
         let mut writer = UnpartitionedWriter::new(writer_builder);
 
@@ -0,0 +1,274 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! This module provides the `StreamingAppendWriter` for continuous ingestion.
+
+use arrow_array::RecordBatch;
+
+use crate::spec::DataFile;
+use crate::table::Table;
+use crate::transaction::{ApplyTransactionAction, Transaction};
+use crate::writer::{IcebergWriter, IcebergWriterBuilder};
+use crate::{Catalog, Result};
+
+/// A writer appending a stream of micro-batches to an unpartitioned table.
+///
+/// The writer stays open across batches: [`flush`](Self::flush) closes the files being
+/// written, and [`commit`](Self::commit) appends all the files flushed since the last commit
+/// to the table in one snapshot. Rolling files at the target size and collecting their
+/// metrics is left to the inner writer, such as a
+/// [`DataFileWriter`](crate::writer::base_writer::data_file_writer::DataFileWriter) over a
+/// [`RollingFileWriter`](crate::writer::file_writer::rolling_writer::RollingFileWriter).
+pub struct StreamingAppendWriter<B: IcebergWriterBuilder> {
+    table: Table,
+    inner_builder: B,
+    writer: Option<B::R>,
+    flushed_files: Vec<DataFile>,
+    /// The number of files in `flushed_files` at the end of each flush since the last commit.
+    flush_ends: Vec<usize>,
+    snapshot_per_flush: bool,
+}
+
+impl<B: IcebergWriterBuilder> StreamingAppendWriter<B> {
+    /// Create a new `StreamingAppendWriter` appending to `table` the files written by the
+    /// writers of `inner_builder`.
+    pub fn new(table: Table, inner_builder: B) -> Self {
+        Self {
+            table,
+            inner_builder,
+            writer: None,
+            flushed_files: vec![],
+            flush_ends: vec![],
+            snapshot_per_flush: false,
+        }
+    }
+
+    /// Sets whether [`commit`](Self::commit) adds one snapshot per flush instead of a single
+    /// snapshot, keeping the granularity of the flushed batches in the snapshot history.
+    ///
+    /// The snapshots are still committed together in one update of the table, so frequent
+    /// flushes don't cost one catalog round-trip each. Each snapshot is the parent of the next
+    /// one and has its own entry in the snapshot log. Defaults to `false`.
+    pub fn with_snapshot_per_flush(mut self, snapshot_per_flush: bool) -> Self {
+        self.snapshot_per_flush = snapshot_per_flush;
+        self
+    }
+
+    /// Returns the table as of the last commit.
+    pub fn table(&self) -> &Table {
+        &self.table
+    }
+
+    /// Returns the files flushed since the last commit.
+    pub fn flushed_files(&self) -> &[DataFile] {
+        &self.flushed_files
+    }
+
+    /// Write a batch to the open files, opening new ones if needed.
+    pub async fn write(&mut self, batch: RecordBatch) -> Result<()> {
+        if self.writer.is_none() {
+            self.writer = Some(self.inner_builder.build(None).await?);
+        }
+        self.writer
+            .as_mut()
+            .expect("Writer should be initialized")
+            .write(batch)
+            .await
+    }
+
+    /// Close the open files, making them part of the next commit.
+    pub async fn flush(&mut self) -> Result<()> {
+        if let Some(mut writer) = self.writer.take() {
+            self.flushed_files.extend(writer.close().await?);
+            if self.flush_ends.last() != Some(&self.flushed_files.len()) {
+                self.flush_ends.push(self.flushed_files.len());
+            }
+        }
+        Ok(())
+    }
+
+    /// Flush the open files and append all the files flushed since the last commit to the
+    /// table in one snapshot, or in one snapshot per flush with
+    /// [`with_snapshot_per_flush`](Self::with_snapshot_per_flush), returning the updated
+    /// table.
+    ///
+    /// Nothing is committed when no file was flushed. When the commit fails, the flushed
+    /// files are kept for the next commit.
+    pub async fn commit(&mut self, catalog: &dyn Catalog) -> Result<Table> {
+        self.flush().await?;
+        if self.flushed_files.is_empty() {
+            return Ok(self.table.clone());
+        }
+
+        let mut tx = Transaction::new(&self.table);
+        if self.snapshot_per_flush {
+            let mut start = 0;
+            for end in &self.flush_ends {
+                tx = tx
+                    .fast_append()
+                    .add_data_files(self.flushed_files[start..*end].to_vec())
+                    .apply(tx)?;
+                start = *end;
+            }
+        } else {
+            tx = tx
+                .fast_append()
+                .add_data_files(self.flushed_files.clone())
+                .apply(tx)?;
+        }
+        self.table = tx.commit(catalog).await?;
+        self.flushed_files.clear();
+        self.flush_ends.clear();
+        Ok(self.table.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use arrow_array::{ArrayRef, Int64Array, RecordBatch};
+    use arrow_schema::Schema as ArrowSchema;
+    use parquet::file::properties::WriterProperties;
+
+    use super::*;
+    use crate::NamespaceIdent;
+    use crate::arrow::schema_to_arrow_schema;
+    use crate::catalog::TableCreation;
+    use crate::memory::tests::new_memory_catalog;
+    use crate::spec::{DataFileFormat, NestedField, PrimitiveType, Schema, Type};
+    use crate::writer::base_writer::data_file_writer::DataFileWriterBuilder;
+    use crate::writer::file_writer::ParquetWriterBuilder;
+    use crate::writer::file_writer::location_generator::{
+        DefaultFileNameGenerator, DefaultLocationGenerator,
+    };
+    use crate::writer::file_writer::rolling_writer::RollingFileWriterBuilder;
+
+    type TestWriter = StreamingAppendWriter<
+        DataFileWriterBuilder<
+            ParquetWriterBuilder,
+            DefaultLocationGenerator,
+            DefaultFileNameGenerator,
+        >,
+    >;
+
+    /// Creates a table with a single long column and a writer appending to it.
+    async fn streaming_writer(catalog: &dyn Catalog) -> Result<(TestWriter, Arc<ArrowSchema>)> {
+        let namespace = NamespaceIdent::new("ns".to_string());
+        catalog.create_namespace(&namespace, HashMap::new()).await?;
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+            ])
+            .build()?;
+        let table = catalog
+            .create_table(
+                &namespace,
+                TableCreation::builder()
+                    .name("t".to_string())
+                    .schema(schema)
+                    .build(),
+            )
+            .await?;
+
+        let schema = table.metadata().current_schema().clone();
+        let arrow_schema = Arc::new(schema_to_arrow_schema(&schema)?);
+        let rolling_writer_builder = RollingFileWriterBuilder::new_with_default_file_size(
+            ParquetWriterBuilder::new(WriterProperties::builder().build(), schema),
+            table.file_io().clone(),
+            DefaultLocationGenerator::new(table.metadata().clone())?,
+            DefaultFileNameGenerator::new("stream".to_string(), None, DataFileFormat::Parquet),
+        );
+        let data_file_writer_builder = DataFileWriterBuilder::new(
+            rolling_writer_builder,
+            table.metadata().default_partition_spec_id(),
+        );
+        let writer = StreamingAppendWriter::new(table, data_file_writer_builder);
+        Ok((writer, arrow_schema))
+    }
+
+    #[tokio::test]
+    async fn test_streaming_append_commits_flushed_batches_once() -> Result<()> {
+        let catalog = new_memory_catalog().await;
+        let (mut writer, arrow_schema) = streaming_writer(&catalog).await?;
+
+        for micro_batch in 0..5 {
+            let batch = RecordBatch::try_new(arrow_schema.clone(), vec![
+                Arc::new(Int64Array::from(vec![micro_batch * 2, micro_batch * 2 + 1])) as ArrayRef,
+            ])?;
+            writer.write(batch).await?;
+            writer.flush().await?;
+            assert_eq!(writer.flushed_files().len(), micro_batch as usize + 1);
+        }
+        assert!(writer.table().metadata().current_snapshot().is_none());
+
+        let table = writer.commit(&catalog).await?;
+        assert!(writer.flushed_files().is_empty());
+        assert_eq!(table.metadata().snapshots().count(), 1);
+        let snapshot = table.metadata().current_snapshot().unwrap();
+        let summary = &snapshot.summary().additional_properties;
+        assert_eq!(summary.get("added-data-files").unwrap(), "5");
+        assert_eq!(summary.get("total-records").unwrap(), "10");
+
+        // A commit without flushed files does not add a snapshot.
+        let table = writer.commit(&catalog).await?;
+        assert_eq!(table.metadata().snapshots().count(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_streaming_append_commits_snapshot_per_flush_in_one_update() -> Result<()> {
+        let catalog = new_memory_catalog().await;
+        let (writer, arrow_schema) = streaming_writer(&catalog).await?;
+        let mut writer = writer.with_snapshot_per_flush(true);
+        let metadata_files = writer.table().metadata().metadata_log().len();
+
+        for micro_batch in 0..3 {
+            let batch = RecordBatch::try_new(arrow_schema.clone(), vec![
+                Arc::new(Int64Array::from(vec![micro_batch])) as ArrayRef,
+            ])?;
+            writer.write(batch).await?;
+            writer.flush().await?;
+        }
+        let table = writer.commit(&catalog).await?;
+
+        // The three snapshots were written to the catalog in a single metadata file.
+        assert_eq!(table.metadata().metadata_log().len(), metadata_files + 1);
+        let current_snapshot_id = table.metadata().current_snapshot_id().unwrap();
+        let snapshots: Vec<_> = table.metadata().ancestors_of(current_snapshot_id).collect();
+        assert_eq!(snapshots.len(), 3);
+        let logged: Vec<_> = table
+            .metadata()
+            .history()
+            .iter()
+            .rev()
+            .map(|entry| entry.snapshot_id)
+            .collect();
+        let snapshot_ids: Vec<_> = snapshots
+            .iter()
+            .map(|snapshot| snapshot.snapshot_id())
+            .collect();
+        assert_eq!(logged, snapshot_ids);
+        for (snapshot, total_records) in snapshots.iter().zip(["3", "2", "1"]) {
+            let summary = &snapshot.summary().additional_properties;
+            assert_eq!(summary.get("added-data-files").unwrap(), "1");
+            assert_eq!(summary.get("total-records").unwrap(), total_records);
+        }
+        Ok(())
+    }
+}
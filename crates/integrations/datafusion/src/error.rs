@@ -15,18 +15,102 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use anyhow::anyhow;
+use std::error::Error as StdError;
+
+use datafusion::error::DataFusionError;
 use iceberg::{Error, ErrorKind};
 
 /// Converts a datafusion error into an iceberg error.
-pub fn from_datafusion_error(error: datafusion::error::DataFusionError) -> Error {
-    Error::new(
-        ErrorKind::Unexpected,
-        "Operation failed for hitting datafusion error".to_string(),
-    )
-    .with_source(anyhow!("datafusion error: {error:?}"))
+///
+/// An iceberg error carried by the datafusion error, such as one converted by
+/// [`to_datafusion_error`], is returned unchanged. Any other datafusion error is kept as the
+/// source of the returned error.
+pub fn from_datafusion_error(error: DataFusionError) -> Error {
+    match into_iceberg_error(error) {
+        Ok(error) => error,
+        Err(error) => Error::new(
+            ErrorKind::Unexpected,
+            "Operation failed for hitting datafusion error".to_string(),
+        )
+        .with_source(error),
+    }
 }
+
 /// Converts an iceberg error into a datafusion error.
-pub fn to_datafusion_error(error: Error) -> datafusion::error::DataFusionError {
-    datafusion::error::DataFusionError::External(error.into())
+///
+/// The iceberg error is kept as an [`DataFusionError::External`] source, which is the only
+/// datafusion error holding an arbitrary source, so its kind and source chain can be
+/// inspected with [`find_iceberg_error`]. Errors other than [`ErrorKind::Unexpected`] are
+/// wrapped in a [`DataFusionError::Context`] describing the kind of failure.
+pub fn to_datafusion_error(error: Error) -> DataFusionError {
+    let context = match error.kind() {
+        ErrorKind::NamespaceNotFound
+        | ErrorKind::TableNotFound
+        | ErrorKind::NamespaceAlreadyExists
+        | ErrorKind::TableAlreadyExists => "Iceberg catalog error",
+        ErrorKind::DataInvalid => "Invalid Iceberg data",
+        ErrorKind::FeatureUnsupported => "Unsupported Iceberg feature",
+        ErrorKind::PreconditionFailed | ErrorKind::CatalogCommitConflicts => {
+            "Iceberg commit failed"
+        }
+        ErrorKind::BackendUnavailable => "Iceberg catalog unavailable",
+        _ => return DataFusionError::External(Box::new(error)),
+    };
+    DataFusionError::Context(
+        context.to_string(),
+        Box::new(DataFusionError::External(Box::new(error))),
+    )
+}
+
+/// Returns the first iceberg error in the source chain of a datafusion error.
+pub fn find_iceberg_error(error: &DataFusionError) -> Option<&Error> {
+    let mut source: Option<&(dyn StdError + 'static)> = Some(error);
+    while let Some(error) = source {
+        if let Some(error) = error.downcast_ref::<Error>() {
+            return Some(error);
+        }
+        source = error.source();
+    }
+    None
+}
+
+/// Unwraps the iceberg error converted by [`to_datafusion_error`], or returns the datafusion
+/// error back.
+fn into_iceberg_error(error: DataFusionError) -> Result<Error, DataFusionError> {
+    match error {
+        DataFusionError::External(source) => source
+            .downcast::<Error>()
+            .map(|error| *error)
+            .map_err(DataFusionError::External),
+        DataFusionError::Context(context, error) => into_iceberg_error(*error)
+            .map_err(|error| DataFusionError::Context(context, Box::new(error))),
+        error => Err(error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iceberg_error_round_trip() {
+        let error = to_datafusion_error(Error::new(ErrorKind::TableNotFound, "no table t"));
+        assert!(matches!(error, DataFusionError::Context(_, _)));
+        assert_eq!(
+            find_iceberg_error(&error).map(Error::kind),
+            Some(ErrorKind::TableNotFound)
+        );
+        let error = from_datafusion_error(error);
+        assert_eq!(error.kind(), ErrorKind::TableNotFound);
+        assert_eq!(error.message(), "no table t");
+
+        let error = from_datafusion_error(DataFusionError::Plan("bad plan".to_string()));
+        assert_eq!(error.kind(), ErrorKind::Unexpected);
+        assert!(
+            error
+                .source()
+                .and_then(|source| source.downcast_ref::<DataFusionError>())
+                .is_some()
+        );
+    }
 }
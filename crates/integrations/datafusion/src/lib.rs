@@ -28,3 +28,7 @@ pub use table::table_provider_factory::IcebergTableProviderFactory;
 pub use table::*;
 
 pub(crate) mod task_writer;
+mod time_travel;
+pub use time_travel::*;
+mod type_converter;
+pub use type_converter::*;
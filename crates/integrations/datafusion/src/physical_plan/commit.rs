@@ -39,7 +39,8 @@ use crate::physical_plan::DATA_FILES_COL_NAME;
 use crate::to_datafusion_error;
 
 /// IcebergCommitExec is responsible for collecting the files written and use
-/// [`Transaction::fast_append`] to commit the data files written.
+/// [`Transaction::fast_append`] to commit the data files written, or
+/// [`Transaction::replace_partitions`] when overwriting the partitions they belong to.
 #[derive(Debug)]
 pub(crate) struct IcebergCommitExec {
     table: Table,
@@ -48,6 +49,8 @@ pub(crate) struct IcebergCommitExec {
     schema: ArrowSchemaRef,
     count_schema: ArrowSchemaRef,
     plan_properties: PlanProperties,
+    replace_partitions: bool,
+    branch: Option<String>,
 }
 
 impl IcebergCommitExec {
@@ -68,9 +71,24 @@ impl IcebergCommitExec {
             schema,
             count_schema,
             plan_properties,
+            replace_partitions: false,
+            branch: None,
         }
     }
 
+    /// Set whether the data files written replace the partitions they belong to instead of
+    /// being appended. Defaults to `false`.
+    pub fn with_replace_partitions(mut self, v: bool) -> Self {
+        self.replace_partitions = v;
+        self
+    }
+
+    /// Set the branch the data files are committed to, `main` if `None`. Defaults to `None`.
+    pub fn with_branch(mut self, branch: Option<String>) -> Self {
+        self.branch = branch;
+        self
+    }
+
     // Compute the plan properties for this execution plan
     fn compute_properties(schema: ArrowSchemaRef) -> PlanProperties {
         PlanProperties::new(
@@ -160,12 +178,16 @@ impl ExecutionPlan for IcebergCommitExec {
             )));
         }
 
-        Ok(Arc::new(IcebergCommitExec::new(
-            self.table.clone(),
-            self.catalog.clone(),
-            children[0].clone(),
-            self.schema.clone(),
-        )))
+        Ok(Arc::new(
+            IcebergCommitExec::new(
+                self.table.clone(),
+                self.catalog.clone(),
+                children[0].clone(),
+                self.schema.clone(),
+            )
+            .with_replace_partitions(self.replace_partitions)
+            .with_branch(self.branch.clone()),
+        ))
     }
 
     fn execute(
@@ -183,6 +205,8 @@ impl ExecutionPlan for IcebergCommitExec {
         let table = self.table.clone();
         let input_plan = self.input.clone();
         let count_schema = Arc::clone(&self.count_schema);
+        let replace_partitions = self.replace_partitions;
+        let branch = self.branch.clone();
 
         // todo revisit this
         let spec_id = self.table.metadata().default_partition_spec_id();
@@ -247,12 +271,19 @@ impl ExecutionPlan for IcebergCommitExec {
 
             // Create a transaction and commit the data files
             let tx = Transaction::new(&table);
-            let action = tx.fast_append().add_data_files(data_files);
+            let tx = if replace_partitions {
+                tx.replace_partitions().add_data_files(data_files).apply(tx)
+            } else {
+                let action = tx.fast_append().add_data_files(data_files);
+                match branch {
+                    Some(branch) => action.set_target_branch(branch).apply(tx),
+                    None => action.apply(tx),
+                }
+            }
+            .map_err(to_datafusion_error)?;
 
-            // Apply the action and commit the transaction
-            let _updated_table = action
-                .apply(tx)
-                .map_err(to_datafusion_error)?
+            // Commit the transaction
+            let _updated_table = tx
                 .commit(catalog.as_ref())
                 .await
                 .map_err(to_datafusion_error)?;
@@ -513,7 +544,7 @@ mod tests {
 
         // Load the first manifest and verify it contains our data files
         let manifest = manifest_list.entries()[0]
-            .load_manifest(updated_table.file_io())
+            .load_manifest(updated_table.file_io(), updated_table.metadata())
             .await?;
 
         // Verify that the manifest contains our data files
@@ -0,0 +1,187 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Conversion of inserted columns with Arrow types handled by an [`ArrowTypeConverter`].
+
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use datafusion::arrow::array::RecordBatch;
+use datafusion::arrow::datatypes::{DataType, Schema as ArrowSchema};
+use datafusion::common::{DataFusionError, Result as DFResult};
+use datafusion::physical_expr::PhysicalExpr;
+use datafusion::physical_expr::expressions::Column;
+use datafusion::physical_plan::projection::ProjectionExec;
+use datafusion::physical_plan::{ColumnarValue, ExecutionPlan};
+use iceberg::arrow::schema_to_arrow_schema;
+use iceberg::table::Table;
+
+use crate::{ArrowTypeConverter, to_datafusion_error};
+
+/// Converts the columns of `input` whose Arrow type differs from the table column and is
+/// handled by `converter` to the Arrow type of the table column.
+///
+/// The other columns are left unchanged, and the plan is returned unchanged when no column
+/// is converted.
+pub(crate) fn convert_arrow_types(
+    input: Arc<dyn ExecutionPlan>,
+    table: &Table,
+    converter: Arc<dyn ArrowTypeConverter>,
+) -> DFResult<Arc<dyn ExecutionPlan>> {
+    let table_schema = table.metadata().current_schema();
+    let table_arrow_schema =
+        schema_to_arrow_schema(table_schema.as_ref()).map_err(to_datafusion_error)?;
+    let input_schema = input.schema();
+
+    let mut converted = false;
+    let mut projection_exprs: Vec<(Arc<dyn PhysicalExpr>, String)> =
+        Vec::with_capacity(input_schema.fields().len());
+    for (index, field) in input_schema.fields().iter().enumerate() {
+        let column = Arc::new(Column::new(field.name(), index));
+        let (Ok(table_arrow_field), Some(table_field)) = (
+            table_arrow_schema.field_with_name(field.name()),
+            table_schema.field_by_name(field.name()),
+        ) else {
+            projection_exprs.push((column, field.name().clone()));
+            continue;
+        };
+        let iceberg_type = match converter.iceberg_type(field.data_type()) {
+            Some(iceberg_type) if table_arrow_field.data_type() != field.data_type() => {
+                iceberg_type
+            }
+            _ => {
+                projection_exprs.push((column, field.name().clone()));
+                continue;
+            }
+        };
+        if iceberg_type != *table_field.field_type {
+            return Err(DataFusionError::Plan(format!(
+                "Column {} of Arrow type {} is converted to Iceberg type {iceberg_type}, but the table column has type {}",
+                field.name(),
+                field.data_type(),
+                table_field.field_type
+            )));
+        }
+
+        let convert_expr = Arc::new(ConvertTypeExpr {
+            input: column,
+            column_name: field.name().clone(),
+            converter: converter.clone(),
+            target_type: table_arrow_field.data_type().clone(),
+        });
+        projection_exprs.push((convert_expr, field.name().clone()));
+        converted = true;
+    }
+
+    if !converted {
+        return Ok(input);
+    }
+    Ok(Arc::new(ProjectionExec::try_new(projection_exprs, input)?))
+}
+
+/// PhysicalExpr converting a column with an [`ArrowTypeConverter`].
+#[derive(Debug, Clone)]
+struct ConvertTypeExpr {
+    input: Arc<dyn PhysicalExpr>,
+    column_name: String,
+    converter: Arc<dyn ArrowTypeConverter>,
+    target_type: DataType,
+}
+
+// Converters are compared by pointer, as two ConvertTypeExpr are equal if they share the same
+// converter instance
+impl PartialEq for ConvertTypeExpr {
+    fn eq(&self, other: &Self) -> bool {
+        self.input.eq(&other.input)
+            && Arc::ptr_eq(&self.converter, &other.converter)
+            && self.target_type == other.target_type
+    }
+}
+
+impl Eq for ConvertTypeExpr {}
+
+impl Hash for ConvertTypeExpr {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.input.hash(state);
+        Arc::as_ptr(&self.converter).cast::<()>().hash(state);
+        self.target_type.hash(state);
+    }
+}
+
+impl PhysicalExpr for ConvertTypeExpr {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn data_type(&self, _input_schema: &ArrowSchema) -> DFResult<DataType> {
+        Ok(self.target_type.clone())
+    }
+
+    fn nullable(&self, input_schema: &ArrowSchema) -> DFResult<bool> {
+        self.input.nullable(input_schema)
+    }
+
+    fn evaluate(&self, batch: &RecordBatch) -> DFResult<ColumnarValue> {
+        let array = self.input.evaluate(batch)?.into_array(batch.num_rows())?;
+        let converted = self
+            .converter
+            .convert(&array, &self.target_type)
+            .map_err(to_datafusion_error)?;
+        if converted.data_type() != &self.target_type {
+            return Err(DataFusionError::Execution(format!(
+                "Arrow type converter returned an array of type {} for column {}, expected {}",
+                converted.data_type(),
+                self.column_name,
+                self.target_type
+            )));
+        }
+        Ok(ColumnarValue::Array(converted))
+    }
+
+    fn children(&self) -> Vec<&Arc<dyn PhysicalExpr>> {
+        vec![&self.input]
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        mut children: Vec<Arc<dyn PhysicalExpr>>,
+    ) -> DFResult<Arc<dyn PhysicalExpr>> {
+        let input = children.pop().ok_or_else(|| {
+            DataFusionError::Internal("ConvertTypeExpr requires one child".to_string())
+        })?;
+        Ok(Arc::new(Self {
+            input,
+            ..self.as_ref().clone()
+        }))
+    }
+
+    fn fmt_sql(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "iceberg_convert_type(")?;
+        self.input.fmt_sql(f)?;
+        write!(f, ", {})", self.target_type)
+    }
+}
+
+impl std::fmt::Display for ConvertTypeExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "iceberg_convert_type({}, {})",
+            self.input, self.target_type
+        )
+    }
+}
@@ -97,6 +97,18 @@ fn to_iceberg_predicate(expr: &Expr) -> TransformedResult {
             }
         }
         Expr::Column(column) => TransformedResult::Column(Reference::new(column.name())),
+        // Nested struct fields are referenced by their dotted full name, e.g. `address.country`.
+        Expr::ScalarFunction(func) if func.name() == "get_field" => match func.args.as_slice() {
+            [base, Expr::Literal(ScalarValue::Utf8(Some(field_name)), _)] => {
+                match to_iceberg_predicate(base) {
+                    TransformedResult::Column(r) => TransformedResult::Column(Reference::new(
+                        format!("{}.{field_name}", r.name()),
+                    )),
+                    _ => TransformedResult::NotTransformed,
+                }
+            }
+            _ => TransformedResult::NotTransformed,
+        },
         Expr::Literal(literal, _) => match scalar_value_to_datum(literal) {
             Some(data) => TransformedResult::Literal(data),
             None => TransformedResult::NotTransformed,
@@ -301,7 +313,7 @@ fn scalar_value_to_datum(value: &ScalarValue) -> Option<Datum> {
 mod tests {
     use std::collections::HashMap;
 
-    use datafusion::arrow::datatypes::{DataType, Field, Schema, TimeUnit};
+    use datafusion::arrow::datatypes::{DataType, Field, Fields, Schema, TimeUnit};
     use datafusion::common::DFSchema;
     use datafusion::logical_expr::utils::split_conjunction;
     use datafusion::prelude::{Expr, SessionContext};
@@ -391,6 +403,35 @@ mod tests {
         assert_eq!(predicate, !Reference::new("foo").equal_to(Datum::long(1)));
     }
 
+    #[test]
+    fn test_predicate_conversion_with_nested_struct_field() {
+        let address_fields = Fields::from(vec![
+            Field::new("country", DataType::Utf8, true).with_metadata(HashMap::from([(
+                PARQUET_FIELD_ID_META_KEY.to_string(),
+                "2".to_string(),
+            )])),
+        ]);
+        let arrow_schema = Schema::new(vec![
+            Field::new("address", DataType::Struct(address_fields), true).with_metadata(
+                HashMap::from([(PARQUET_FIELD_ID_META_KEY.to_string(), "1".to_string())]),
+            ),
+        ]);
+        let df_schema = DFSchema::try_from_qualified_schema("my_table", &arrow_schema).unwrap();
+        let expr = SessionContext::new()
+            .parse_sql_expr(
+                "address.country = 'US' and address['country'] is not null",
+                &df_schema,
+            )
+            .unwrap();
+        let exprs: Vec<Expr> = split_conjunction(&expr).into_iter().cloned().collect();
+
+        let predicate = convert_filters_to_predicate(&exprs[..]).unwrap();
+        let expected_predicate = Reference::new("address.country")
+            .equal_to(Datum::string("US"))
+            .and(Reference::new("address.country").is_not_null());
+        assert_eq!(predicate, expected_predicate);
+    }
+
     #[test]
     fn test_predicate_conversion_with_single_unsupported_condition() {
         let predicate = convert_to_iceberg_predicate("foo + 1 = 1");
@@ -63,7 +63,8 @@ pub fn project_with_partition(
     let input_schema = input.schema();
 
     // Validate that input_schema matches the Iceberg table schema
-    // Strip metadata from both schemas before comparison to ignore metadata differences
+    // Strip metadata from both schemas before comparison to ignore metadata differences,
+    // and allow required input columns for optional table columns
     let expected_arrow_schema =
         schema_to_arrow_schema(table_schema.as_ref()).map_err(to_datafusion_error)?;
     let input_schema_cleaned =
@@ -71,7 +72,7 @@ pub fn project_with_partition(
     let expected_schema_cleaned =
         strip_metadata_from_schema(&expected_arrow_schema).map_err(to_datafusion_error)?;
 
-    if input_schema_cleaned != expected_schema_cleaned {
+    if !matches_table_schema(&input_schema_cleaned, &expected_schema_cleaned) {
         return Err(DataFusionError::Plan(format!(
             "Input schema does not match Iceberg table schema.\n\
              Expected schema: {expected_schema_cleaned}\n\
@@ -98,6 +99,19 @@ pub fn project_with_partition(
     Ok(Arc::new(projection))
 }
 
+/// Returns whether the columns of `input_schema` have the names and types of the columns of
+/// `table_schema`, and are only nullable where the table columns are.
+fn matches_table_schema(input_schema: &ArrowSchema, table_schema: &ArrowSchema) -> bool {
+    input_schema.fields().len() == table_schema.fields().len()
+        && input_schema.fields().iter().zip(table_schema.fields()).all(
+            |(input_field, table_field)| {
+                input_field.name() == table_field.name()
+                    && input_field.data_type() == table_field.data_type()
+                    && (table_field.is_nullable() || !input_field.is_nullable())
+            },
+        )
+}
+
 /// PhysicalExpr implementation for partition value calculation
 #[derive(Debug, Clone)]
 struct PartitionExpr {
@@ -587,4 +601,26 @@ mod tests {
             "Schema validation should pass even with metadata differences"
         );
     }
+
+    #[test]
+    fn test_schema_validation_nullability() {
+        let table_schema = ArrowSchema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("name", DataType::Utf8, true),
+        ]);
+
+        // Required input columns may be written to optional table columns
+        let input_schema = ArrowSchema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("name", DataType::Utf8, false),
+        ]);
+        assert!(matches_table_schema(&input_schema, &table_schema));
+
+        // but optional input columns can't be written to required table columns.
+        let input_schema = ArrowSchema::new(vec![
+            Field::new("id", DataType::Int32, true),
+            Field::new("name", DataType::Utf8, true),
+        ]);
+        assert!(!matches_table_schema(&input_schema, &table_schema));
+    }
 }
@@ -16,21 +16,29 @@
 // under the License.
 
 use std::any::Any;
+use std::collections::HashMap;
 use std::pin::Pin;
 use std::sync::Arc;
+use std::task::Poll;
 use std::vec;
 
-use datafusion::arrow::array::RecordBatch;
+use datafusion::arrow::array::{RecordBatch, RecordBatchOptions, new_null_array};
 use datafusion::arrow::datatypes::SchemaRef as ArrowSchemaRef;
+use datafusion::common::tree_node::{Transformed, TreeNode};
+use datafusion::common::{Column, DataFusionError};
 use datafusion::error::Result as DFResult;
 use datafusion::execution::{SendableRecordBatchStream, TaskContext};
 use datafusion::physical_expr::EquivalenceProperties;
 use datafusion::physical_plan::execution_plan::{Boundedness, EmissionType};
+use datafusion::physical_plan::metrics::{
+    Count, ExecutionPlanMetricsSet, MetricBuilder, MetricsSet,
+};
 use datafusion::physical_plan::stream::RecordBatchStreamAdapter;
 use datafusion::physical_plan::{DisplayAs, ExecutionPlan, Partitioning, PlanProperties};
 use datafusion::prelude::Expr;
-use futures::{Stream, TryStreamExt};
+use futures::{Stream, StreamExt, TryStreamExt};
 use iceberg::expr::Predicate;
+use iceberg::scan::{FileScanTask, ScanMetrics};
 use iceberg::table::Table;
 
 use super::expr_to_predicate::convert_filters_to_predicate;
@@ -53,6 +61,15 @@ pub struct IcebergTableScan {
     predicates: Option<Predicate>,
     /// Optional limit on the number of rows to return
     limit: Option<usize>,
+    /// File planning metrics, reported by `EXPLAIN ANALYZE`
+    metrics: ExecutionPlanMetricsSet,
+    /// Whether the scanned table columns are exposed under the names of the output schema
+    rename_columns: bool,
+    /// A file plan of the scanned snapshot and predicates, read instead of planning the files
+    file_plan: Option<Arc<Vec<FileScanTask>>>,
+    /// The schema of the read columns, when the columns of the output schema missing from the
+    /// table are filled with nulls
+    read_schema: Option<ArrowSchemaRef>,
 }
 
 impl IcebergTableScan {
@@ -80,9 +97,90 @@ impl IcebergTableScan {
             projection,
             predicates,
             limit,
+            metrics: ExecutionPlanMetricsSet::new(),
+            rename_columns: false,
+            file_plan: None,
+            read_schema: None,
         }
     }
 
+    /// Creates a new [`IcebergTableScan`] exposing table columns under the names of `schema`.
+    ///
+    /// `table_columns` maps each field name of `schema` to the name of the table column it
+    /// reads. The projection and filters refer to the exposed names and are translated to the
+    /// table columns, columns missing from `schema` are never read.
+    pub(crate) fn new_with_column_aliases(
+        table: Table,
+        snapshot_id: Option<i64>,
+        schema: ArrowSchemaRef,
+        projection: Option<&Vec<usize>>,
+        filters: &[Expr],
+        limit: Option<usize>,
+        table_columns: &HashMap<String, String>,
+    ) -> DFResult<Self> {
+        let table_column = |name: &str| {
+            table_columns.get(name).cloned().ok_or_else(|| {
+                DataFusionError::Plan(format!("column {name} is not exposed by the table"))
+            })
+        };
+
+        let filters = filters
+            .iter()
+            .map(|filter| {
+                filter
+                    .clone()
+                    .transform(|expr| match expr {
+                        Expr::Column(column) => {
+                            let name = table_column(&column.name)?;
+                            Ok(Transformed::yes(Expr::Column(Column::new(
+                                column.relation,
+                                name,
+                            ))))
+                        }
+                        expr => Ok(Transformed::no(expr)),
+                    })
+                    .map(|transformed| transformed.data)
+            })
+            .collect::<DFResult<Vec<_>>>()?;
+
+        let exposed_columns = get_column_names(schema.clone(), projection).unwrap_or_else(|| {
+            schema
+                .fields()
+                .iter()
+                .map(|field| field.name().clone())
+                .collect()
+        });
+        let projection_columns = exposed_columns
+            .iter()
+            .map(|name| table_column(name))
+            .collect::<DFResult<Vec<_>>>()?;
+
+        let mut scan = Self::new(table, snapshot_id, schema, projection, &filters, limit);
+        scan.projection = Some(projection_columns);
+        scan.rename_columns = true;
+        Ok(scan)
+    }
+
+    /// Reads the files of `file_plan` instead of planning the files of the scan.
+    ///
+    /// The file plan must have been planned for the scanned snapshot with the predicates of
+    /// this scan, see [`plan_file_tasks`].
+    pub(crate) fn with_file_plan(mut self, file_plan: Arc<Vec<FileScanTask>>) -> Self {
+        self.file_plan = Some(file_plan);
+        self
+    }
+
+    /// Outputs the columns of `output_schema`, filling the columns this scan doesn't read
+    /// with nulls.
+    ///
+    /// The columns read by this scan are matched by name with the fields of `output_schema`,
+    /// which must hold them all. The filled fields must be nullable.
+    pub(crate) fn with_missing_columns_as_null(mut self, output_schema: ArrowSchemaRef) -> Self {
+        self.read_schema = Some(self.schema());
+        self.plan_properties = Self::compute_properties(output_schema);
+        self
+    }
+
     pub fn table(&self) -> &Table {
         &self.table
     }
@@ -143,7 +241,7 @@ impl ExecutionPlan for IcebergTableScan {
 
     fn execute(
         &self,
-        _partition: usize,
+        partition: usize,
         _context: Arc<TaskContext>,
     ) -> DFResult<SendableRecordBatchStream> {
         let fut = get_batch_stream(
@@ -151,9 +249,55 @@ impl ExecutionPlan for IcebergTableScan {
             self.snapshot_id,
             self.projection.clone(),
             self.predicates.clone(),
+            self.file_plan.clone(),
+            IcebergScanMetrics::new(&self.metrics, partition),
         );
         let stream = futures::stream::once(fut).try_flatten();
 
+        // Expose the table columns under the names of the output schema
+        let stream: Pin<Box<dyn Stream<Item = DFResult<RecordBatch>> + Send>> =
+            if self.rename_columns {
+                let schema = self.read_schema.clone().unwrap_or_else(|| self.schema());
+                Box::pin(stream.map(move |batch| {
+                    let batch = batch?;
+                    Ok(RecordBatch::try_new_with_options(
+                        schema.clone(),
+                        batch.columns().to_vec(),
+                        &RecordBatchOptions::new().with_row_count(Some(batch.num_rows())),
+                    )?)
+                }))
+            } else {
+                Box::pin(stream)
+            };
+
+        // Fill the columns missing from the table with nulls
+        let stream: Pin<Box<dyn Stream<Item = DFResult<RecordBatch>> + Send>> = if self
+            .read_schema
+            .is_some()
+        {
+            let schema = self.schema();
+            Box::pin(stream.map(move |batch| {
+                let batch = batch?;
+                let columns = schema
+                    .fields()
+                    .iter()
+                    .map(|field| {
+                        batch
+                            .column_by_name(field.name())
+                            .cloned()
+                            .unwrap_or_else(|| new_null_array(field.data_type(), batch.num_rows()))
+                    })
+                    .collect();
+                Ok(RecordBatch::try_new_with_options(
+                    schema.clone(),
+                    columns,
+                    &RecordBatchOptions::new().with_row_count(Some(batch.num_rows())),
+                )?)
+            }))
+        } else {
+            stream
+        };
+
         // Apply limit if specified
         let limited_stream: Pin<Box<dyn Stream<Item = DFResult<RecordBatch>> + Send>> =
             if let Some(limit) = self.limit {
@@ -179,6 +323,10 @@ impl ExecutionPlan for IcebergTableScan {
             limited_stream,
         )))
     }
+
+    fn metrics(&self) -> Option<MetricsSet> {
+        Some(self.metrics.clone_inner())
+    }
 }
 
 impl DisplayAs for IcebergTableScan {
@@ -200,16 +348,63 @@ impl DisplayAs for IcebergTableScan {
     }
 }
 
+/// DataFusion counters mirroring the [`ScanMetrics`] of an Iceberg file plan.
+struct IcebergScanMetrics {
+    manifests_skipped: Count,
+    files_pruned_by_partition: Count,
+    files_pruned_by_metrics: Count,
+    files_planned: Count,
+    bytes_planned: Count,
+}
+
+impl IcebergScanMetrics {
+    fn new(metrics: &ExecutionPlanMetricsSet, partition: usize) -> Self {
+        Self {
+            manifests_skipped: MetricBuilder::new(metrics).counter("manifests_skipped", partition),
+            files_pruned_by_partition: MetricBuilder::new(metrics)
+                .counter("files_pruned_by_partition", partition),
+            files_pruned_by_metrics: MetricBuilder::new(metrics)
+                .counter("files_pruned_by_metrics", partition),
+            files_planned: MetricBuilder::new(metrics).counter("files_planned", partition),
+            bytes_planned: MetricBuilder::new(metrics).counter("bytes_planned", partition),
+        }
+    }
+
+    fn record_file_plan(&self, file_plan: &[FileScanTask]) {
+        self.files_planned.add(file_plan.len());
+        self.bytes_planned
+            .add(file_plan.iter().map(|task| task.length as usize).sum());
+    }
+
+    fn record(&self, scan_metrics: &ScanMetrics) {
+        self.manifests_skipped
+            .add(scan_metrics.skipped_data_manifests() as usize);
+        self.files_pruned_by_partition
+            .add(scan_metrics.partition_pruned_data_files() as usize);
+        self.files_pruned_by_metrics
+            .add(scan_metrics.metrics_pruned_data_files() as usize);
+        self.files_planned
+            .add(scan_metrics.planned_data_files() as usize);
+        self.bytes_planned
+            .add(scan_metrics.planned_bytes() as usize);
+    }
+}
+
 /// Asynchronously retrieves a stream of [`RecordBatch`] instances
 /// from a given table.
 ///
 /// This function initializes a [`TableScan`], builds it,
 /// and then converts it into a stream of Arrow [`RecordBatch`]es.
+/// The planning metrics are recorded once the stream is exhausted or dropped.
+///
+/// When a file plan is given, its files are read instead of planning the files of the scan.
 async fn get_batch_stream(
     table: Table,
     snapshot_id: Option<i64>,
     column_names: Option<Vec<String>>,
     predicates: Option<Predicate>,
+    file_plan: Option<Arc<Vec<FileScanTask>>>,
+    metrics: IcebergScanMetrics,
 ) -> DFResult<Pin<Box<dyn Stream<Item = DFResult<RecordBatch>> + Send>>> {
     let scan_builder = match snapshot_id {
         Some(snapshot_id) => table.scan().snapshot_id(snapshot_id),
@@ -225,14 +420,81 @@ async fn get_batch_stream(
     }
     let table_scan = scan_builder.build().map_err(to_datafusion_error)?;
 
-    let stream = table_scan
-        .to_arrow()
+    if let Some(file_plan) = file_plan {
+        metrics.record_file_plan(&file_plan);
+        let stream = table_scan
+            .to_arrow_from_tasks(file_plan.iter().cloned())
+            .map_err(to_datafusion_error)?
+            .map_err(to_datafusion_error);
+        return Ok(Box::pin(stream));
+    }
+
+    let (stream, scan_metrics) = table_scan
+        .to_arrow_with_metrics()
         .await
-        .map_err(to_datafusion_error)?
-        .map_err(to_datafusion_error);
+        .map_err(to_datafusion_error)?;
+
+    let mut stream = stream.map_err(to_datafusion_error);
+    let mut recorder = ScanMetricsRecorder {
+        metrics,
+        scan_metrics,
+        recorded: false,
+    };
+    let stream = futures::stream::poll_fn(move |cx| {
+        let poll = stream.poll_next_unpin(cx);
+        if let Poll::Ready(None) = poll {
+            recorder.record();
+        }
+        poll
+    });
     Ok(Box::pin(stream))
 }
 
+/// Records the [`ScanMetrics`] of a batch stream into its DataFusion counters, once the
+/// stream is exhausted or, if it is dropped early by a limit or an error, on drop.
+struct ScanMetricsRecorder {
+    metrics: IcebergScanMetrics,
+    scan_metrics: ScanMetrics,
+    recorded: bool,
+}
+
+impl ScanMetricsRecorder {
+    fn record(&mut self) {
+        if !self.recorded {
+            self.recorded = true;
+            self.metrics.record(&self.scan_metrics);
+        }
+    }
+}
+
+impl Drop for ScanMetricsRecorder {
+    fn drop(&mut self) {
+        self.record();
+    }
+}
+
+/// Plans the files of a scan of the snapshot `snapshot_id` of `table` with `predicates`,
+/// reading all columns.
+pub(crate) async fn plan_file_tasks(
+    table: &Table,
+    snapshot_id: i64,
+    predicates: Option<Predicate>,
+) -> DFResult<Vec<FileScanTask>> {
+    let mut scan_builder = table.scan().snapshot_id(snapshot_id).select_all();
+    if let Some(pred) = predicates {
+        scan_builder = scan_builder.with_filter(pred);
+    }
+    scan_builder
+        .build()
+        .map_err(to_datafusion_error)?
+        .plan_files()
+        .await
+        .map_err(to_datafusion_error)?
+        .try_collect()
+        .await
+        .map_err(to_datafusion_error)
+}
+
 fn get_column_names(
     schema: ArrowSchemaRef,
     projection: Option<&Vec<usize>>,
@@ -65,6 +65,8 @@ pub(crate) struct IcebergWriteExec {
     input: Arc<dyn ExecutionPlan>,
     result_schema: ArrowSchemaRef,
     plan_properties: PlanProperties,
+    /// The seed of the data file names, which are random when unset
+    file_name_seed: Option<String>,
 }
 
 impl IcebergWriteExec {
@@ -76,9 +78,17 @@ impl IcebergWriteExec {
             input,
             result_schema: Self::make_result_schema(),
             plan_properties,
+            file_name_seed: None,
         }
     }
 
+    /// Names the data files written by partition `p` of this plan `{seed}-{p}-{count}`,
+    /// instead of using a random prefix.
+    pub fn with_file_name_seed(mut self, file_name_seed: Option<String>) -> Self {
+        self.file_name_seed = file_name_seed;
+        self
+    }
+
     fn compute_properties(
         input: &Arc<dyn ExecutionPlan>,
         schema: ArrowSchemaRef,
@@ -172,11 +182,10 @@ impl ExecutionPlan for IcebergWriteExec {
             )));
         }
 
-        Ok(Arc::new(Self::new(
-            self.table.clone(),
-            Arc::clone(&children[0]),
-            self.schema(),
-        )))
+        Ok(Arc::new(
+            Self::new(self.table.clone(), Arc::clone(&children[0]), self.schema())
+                .with_file_name_seed(self.file_name_seed.clone()),
+        ))
     }
 
     /// Executes the write operation for the given partition.
@@ -230,16 +239,23 @@ impl ExecutionPlan for IcebergWriteExec {
             WriterProperties::default(),
             self.table.metadata().current_schema().clone(),
             FieldMatchMode::Name,
-        );
+        )
+        .with_bloom_filter_columns(&table_props.write_parquet_bloom_filter_columns)
+        .with_statistics_truncate_length(table_props.write_metadata_metrics_truncate_length);
         let target_file_size = table_props.write_target_file_size_bytes;
 
         let file_io = self.table.file_io().clone();
         // todo location_gen and file_name_gen should be configurable
         let location_generator = DefaultLocationGenerator::new(self.table.metadata().clone())
             .map_err(to_datafusion_error)?;
-        // todo filename prefix/suffix should be configurable
+        // The partition keeps the names of the files written by different partitions apart,
+        // and the file count the names of the files written by one partition.
+        let file_name_prefix = match &self.file_name_seed {
+            Some(seed) => format!("{seed}-{partition:05}"),
+            None => Uuid::now_v7().to_string(),
+        };
         let file_name_generator =
-            DefaultFileNameGenerator::new(Uuid::now_v7().to_string(), None, file_format);
+            DefaultFileNameGenerator::new(file_name_prefix, None, file_format);
         let rolling_writer_builder = RollingFileWriterBuilder::new(
             parquet_file_writer_builder,
             target_file_size,
@@ -247,7 +263,10 @@ impl ExecutionPlan for IcebergWriteExec {
             location_generator,
             file_name_generator,
         );
-        let data_file_writer_builder = DataFileWriterBuilder::new(rolling_writer_builder);
+        let data_file_writer_builder = DataFileWriterBuilder::new(
+            rolling_writer_builder,
+            self.table.metadata().default_partition_spec_id(),
+        );
 
         // Create TaskWriter
         let fanout_enabled = table_props.write_datafusion_fanout_enabled;
@@ -259,7 +278,8 @@ impl ExecutionPlan for IcebergWriteExec {
             schema.clone(),
             partition_spec,
         )
-        .map_err(to_datafusion_error)?;
+        .map_err(to_datafusion_error)?
+        .with_local_partition_sort(table_props.write_datafusion_local_partition_sort_enabled);
 
         // Get input data
         let data = execute_input_stream(
@@ -24,18 +24,25 @@
 //!
 //! - [`IcebergStaticTableProvider`]: Static provider for read-only access to a specific
 //!   table snapshot. Use for consistent analytical queries or time-travel scenarios.
+//!
+//! The [`to_streaming_table`] adapter also reads a table snapshot as a DataFusion
+//! `StreamingTable`, opening each file only when its partition is read.
 
 pub mod metadata_table;
+mod streaming;
 pub mod table_provider_factory;
 
 use std::any::Any;
+use std::collections::{HashMap, HashSet};
 use std::num::NonZeroUsize;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex, RwLock};
 
 use async_trait::async_trait;
-use datafusion::arrow::datatypes::SchemaRef as ArrowSchemaRef;
+use datafusion::arrow::datatypes::{
+    DataType, Field, Schema as ArrowSchema, SchemaRef as ArrowSchemaRef, TimeUnit,
+};
 use datafusion::catalog::Session;
-use datafusion::common::DataFusionError;
+use datafusion::common::{DataFusionError, ScalarValue};
 use datafusion::datasource::{TableProvider, TableType};
 use datafusion::error::Result as DFResult;
 use datafusion::logical_expr::dml::InsertOp;
@@ -43,17 +50,23 @@ use datafusion::logical_expr::{Expr, TableProviderFilterPushDown};
 use datafusion::physical_plan::ExecutionPlan;
 use datafusion::physical_plan::coalesce_partitions::CoalescePartitionsExec;
 use iceberg::arrow::schema_to_arrow_schema;
+use iceberg::expr::Predicate;
 use iceberg::inspect::MetadataTableType;
-use iceberg::spec::TableProperties;
+use iceberg::scan::FileScanTask;
+use iceberg::spec::{Literal, PrimitiveLiteral, Schema, SchemaId, TableProperties};
 use iceberg::table::Table;
 use iceberg::{Catalog, Error, ErrorKind, NamespaceIdent, Result, TableIdent};
 use metadata_table::IcebergMetadataTableProvider;
+use parquet::arrow::PARQUET_FIELD_ID_META_KEY;
+pub use streaming::{IcebergPartitionStream, to_streaming_table};
 
+use crate::ArrowTypeConverter;
 use crate::error::to_datafusion_error;
 use crate::physical_plan::commit::IcebergCommitExec;
+use crate::physical_plan::convert::convert_arrow_types;
 use crate::physical_plan::project::project_with_partition;
 use crate::physical_plan::repartition::repartition;
-use crate::physical_plan::scan::IcebergTableScan;
+use crate::physical_plan::scan::{IcebergTableScan, plan_file_tasks};
 use crate::physical_plan::sort::sort_by_partition;
 use crate::physical_plan::write::IcebergWriteExec;
 
@@ -65,14 +78,113 @@ use crate::physical_plan::write::IcebergWriteExec;
 ///
 /// For read-only access to a specific snapshot without catalog overhead, use
 /// [`IcebergStaticTableProvider`] instead.
+///
+/// The most recently loaded table is cached and can be reloaded on demand with
+/// [`IcebergTableProvider::refresh`].
+///
+/// Filters are pushed down to the scan and projected onto the partitions of the data files,
+/// so `=` and `IN` filters on a column partitioned by `bucket` only read the files of the
+/// buckets of their values. Range filters can't prune buckets and are only evaluated against
+/// the column metrics of the files.
 #[derive(Debug, Clone)]
 pub struct IcebergTableProvider {
     /// The catalog that manages this table
     catalog: Arc<dyn Catalog>,
     /// The table identifier (namespace + name)
     table_ident: TableIdent,
+    /// The most recently loaded table, shared by all clones of this provider
+    table: Arc<RwLock<Table>>,
     /// A reference-counted arrow `Schema` (cached at construction)
     schema: ArrowSchemaRef,
+    /// The ids of the table fields read by the columns of a projected schema, keyed by the
+    /// exposed column name
+    projected_field_ids: Option<Arc<HashMap<String, i32>>>,
+    /// The file plans cached by [`IcebergTableProvider::prewarm`], shared by all clones of
+    /// this provider
+    file_plan_cache: Arc<Mutex<FilePlanCache>>,
+    /// The write-default values of the columns, keyed by column name (cached at construction)
+    column_defaults: Arc<HashMap<String, Expr>>,
+    /// Converts inserted columns with Arrow types that Iceberg doesn't support natively
+    arrow_type_converter: Option<Arc<dyn ArrowTypeConverter>>,
+    /// The snapshot read by all scans instead of the latest one, if pinned
+    pinned_snapshot: Option<ConsistentReadToken>,
+    /// The seed of the names of inserted data files, which are random when unset
+    file_name_seed: Option<String>,
+    /// How scans read the columns of the provider schema missing from the table schema
+    missing_column_behavior: MissingColumnBehavior,
+    /// The branch inserts commit to instead of `main`, if set
+    write_branch: Option<String>,
+}
+
+/// How [`IcebergTableProvider`] scans the columns of its schema that are missing from the
+/// schema of the scanned table, such as columns dropped after the provider was created.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MissingColumnBehavior {
+    /// Fail the scan with an error naming the missing columns and listing the available ones.
+    #[default]
+    Error,
+    /// Read the missing columns as all null.
+    Null,
+}
+
+/// A table state pinned by [`IcebergTableProvider::pin_snapshot`].
+///
+/// Providers given the token with [`IcebergTableProvider::with_consistent_read_token`] read
+/// the pinned snapshot in all their scans, so that several queries see the same table state
+/// even if writers commit in between.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConsistentReadToken {
+    snapshot_id: Option<i64>,
+}
+
+impl ConsistentReadToken {
+    /// Returns the id of the pinned snapshot, or `None` if the table had no snapshot.
+    pub fn snapshot_id(&self) -> Option<i64> {
+        self.snapshot_id
+    }
+}
+
+/// File plans of scans of a snapshot with a table schema, keyed by the projected columns
+/// and the predicates of the scans.
+#[derive(Debug, Default)]
+struct FilePlanCache {
+    snapshot_id: Option<i64>,
+    schema_id: Option<SchemaId>,
+    plans: Vec<(FilePlanKey, Arc<Vec<FileScanTask>>)>,
+}
+
+/// The projected columns and the predicates of a cached file plan.
+#[derive(Debug, PartialEq)]
+struct FilePlanKey {
+    projection: Vec<usize>,
+    predicates: Option<Predicate>,
+}
+
+impl FilePlanCache {
+    fn get(&self, key: &FilePlanKey) -> Option<Arc<Vec<FileScanTask>>> {
+        self.plans
+            .iter()
+            .find(|(cached, _)| cached == key)
+            .map(|(_, file_plan)| file_plan.clone())
+    }
+
+    /// Caches the file plan of a scan of `snapshot_id` with the table schema `schema_id`,
+    /// dropping the plans of other snapshots and schemas.
+    fn insert(
+        &mut self,
+        snapshot_id: i64,
+        schema_id: SchemaId,
+        key: FilePlanKey,
+        file_plan: Arc<Vec<FileScanTask>>,
+    ) {
+        if self.snapshot_id != Some(snapshot_id) || self.schema_id != Some(schema_id) {
+            self.snapshot_id = Some(snapshot_id);
+            self.schema_id = Some(schema_id);
+            self.plans.clear();
+        }
+        self.plans.retain(|(cached, _)| *cached != key);
+        self.plans.push((key, file_plan));
+    }
 }
 
 impl IcebergTableProvider {
@@ -90,24 +202,531 @@ impl IcebergTableProvider {
         // Load table once to get initial schema
         let table = catalog.load_table(&table_ident).await?;
         let schema = Arc::new(schema_to_arrow_schema(table.metadata().current_schema())?);
+        let column_defaults = column_defaults(table.metadata().current_schema(), &schema);
 
         Ok(IcebergTableProvider {
             catalog,
             table_ident,
+            table: Arc::new(RwLock::new(table)),
             schema,
+            projected_field_ids: None,
+            file_plan_cache: Arc::new(Mutex::new(FilePlanCache::default())),
+            column_defaults: Arc::new(column_defaults),
+            arrow_type_converter: None,
+            pinned_snapshot: None,
+            file_name_seed: None,
+            missing_column_behavior: MissingColumnBehavior::default(),
+            write_branch: None,
         })
     }
 
+    /// Reloads the table from the catalog and returns a token pinning its current snapshot.
+    pub async fn pin_snapshot(&self) -> Result<ConsistentReadToken> {
+        let table = self.refresh().await?;
+        Ok(ConsistentReadToken {
+            snapshot_id: table.metadata().current_snapshot_id(),
+        })
+    }
+
+    /// Reads the snapshot pinned by `token` in all scans through this provider, ignoring the
+    /// latest snapshot of the catalog.
+    ///
+    /// A table pinned without any snapshot is scanned as empty. Writes through this provider
+    /// still commit on top of the latest snapshot. The provider gets its own file plan cache,
+    /// shared by its clones only.
+    pub fn with_consistent_read_token(mut self, token: ConsistentReadToken) -> Self {
+        self.pinned_snapshot = Some(token);
+        self.file_plan_cache = Arc::new(Mutex::new(FilePlanCache::default()));
+        self
+    }
+
+    /// Derives the names of the data files written by inserts from `seed` instead of random
+    /// UUIDs, such as for reproducible tests or content-addressable storage.
+    ///
+    /// Inserting the same data with the same seed writes the same file paths: the files of
+    /// each write partition are numbered in order and prefixed with `seed` and the partition,
+    /// so they don't collide with the files of other partitions. As files at an existing path
+    /// are overwritten, inserting different data needs a different seed.
+    pub fn with_deterministic_file_names(mut self, seed: impl Into<String>) -> Self {
+        self.file_name_seed = Some(seed.into());
+        self
+    }
+
+    /// Commits the data files written by inserts to the branch `branch` instead of `main`,
+    /// such as for a staging pipeline writing to a `dev` branch without changing `main`.
+    ///
+    /// The branch is created off the current snapshot of the table by the first insert if it
+    /// doesn't exist yet. Scans still read the current snapshot of `main`, and overwrites are
+    /// not supported on other branches.
+    pub fn with_write_branch(mut self, branch: impl Into<String>) -> Self {
+        self.write_branch = Some(branch.into());
+        self
+    }
+
+    /// Sets how scans read the projected columns of this provider that are missing from the
+    /// schema of the scanned table, which fail the scan by default.
+    ///
+    /// With [`MissingColumnBehavior::Null`], the missing columns are read as all null and the
+    /// filters on them are evaluated by DataFusion only, for clients that tolerate columns
+    /// being dropped from the table. Only the columns missing from the most recently loaded
+    /// table are exposed as nullable, the other ones keep the nullability of the table
+    /// columns, so that inserts still reject nulls in the required ones.
+    pub fn with_missing_column_behavior(mut self, behavior: MissingColumnBehavior) -> Self {
+        self.missing_column_behavior = behavior;
+        self
+    }
+
+    /// Registers a converter of the Arrow types of inserted columns that Iceberg doesn't
+    /// support natively.
+    ///
+    /// An inserted column with an Arrow type handled by `converter` is converted to the
+    /// Arrow type of the table column, which must have the Iceberg type the converter maps
+    /// the column type to. Without a converter, inserting such a column fails.
+    pub fn with_arrow_type_converter(mut self, converter: Arc<dyn ArrowTypeConverter>) -> Self {
+        self.arrow_type_converter = Some(converter);
+        self
+    }
+
+    /// Restricts the columns exposed by this provider to the fields of `schema`.
+    ///
+    /// Every field of `schema` must carry the id of the top-level table field it reads in its
+    /// `PARQUET:field_id` metadata and have the same data type, but may use a different name.
+    /// Scans read the exposed columns by field id, while the table columns missing from
+    /// `schema` can't be queried. This allows masking and aliasing columns, and as the
+    /// exposed schema doesn't match the table, writes through the provider are rejected.
+    pub fn with_projected_schema(mut self, schema: ArrowSchemaRef) -> Result<Self> {
+        let table = self.table();
+        let table_schema = table.metadata().current_schema();
+        let table_arrow_schema = schema_to_arrow_schema(table_schema)?;
+
+        let mut field_ids = HashMap::with_capacity(schema.fields().len());
+        for field in schema.fields() {
+            let field_id = field
+                .metadata()
+                .get(PARQUET_FIELD_ID_META_KEY)
+                .and_then(|value| value.parse::<i32>().ok())
+                .ok_or_else(|| {
+                    Error::new(
+                        ErrorKind::DataInvalid,
+                        format!("Projected column {} has no field id", field.name()),
+                    )
+                })?;
+            let table_field = table_schema
+                .as_struct()
+                .field_by_id(field_id)
+                .ok_or_else(|| {
+                    Error::new(
+                        ErrorKind::DataInvalid,
+                        format!(
+                            "Projected column {} reads field {field_id}, which is not a top-level field of table {}",
+                            field.name(),
+                            self.table_ident
+                        ),
+                    )
+                })?;
+            let (_, table_arrow_field) = table_arrow_schema
+                .column_with_name(&table_field.name)
+                .ok_or_else(|| {
+                    Error::new(
+                        ErrorKind::Unexpected,
+                        format!("Column {} missing from the arrow schema", table_field.name),
+                    )
+                })?;
+            if table_arrow_field.data_type() != field.data_type() {
+                return Err(Error::new(
+                    ErrorKind::DataInvalid,
+                    format!(
+                        "Projected column {} has type {}, but field {field_id} has type {}",
+                        field.name(),
+                        field.data_type(),
+                        table_arrow_field.data_type()
+                    ),
+                ));
+            }
+            field_ids.insert(field.name().clone(), field_id);
+        }
+
+        self.schema = schema;
+        self.projected_field_ids = Some(Arc::new(field_ids));
+        self.column_defaults = Arc::default();
+        Ok(self)
+    }
+
+    /// Returns the most recently loaded table.
+    ///
+    /// This is the table as of the last [`refresh`](Self::refresh), scan, or write and does
+    /// not contact the catalog.
+    pub fn table(&self) -> Table {
+        self.table
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+
+    /// Reloads the latest table metadata from the catalog and returns the refreshed table.
+    ///
+    /// The cached table is replaced as a whole under a short write lock, so concurrent
+    /// callers of [`table`](Self::table), including other clones of this provider, observe
+    /// either the previous or the refreshed table, never a mix of both. Plans that were
+    /// already built keep the table they were planned against. The arrow schema exposed
+    /// through [`TableProvider::schema`] is cached at construction and is not refreshed.
+    pub async fn refresh(&self) -> Result<Table> {
+        let table = self.catalog.load_table(&self.table_ident).await?;
+        *self
+            .table
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = table.clone();
+        Ok(table)
+    }
+
+    /// Returns the table to scan and the id of the scanned snapshot, or `None` to scan the
+    /// current snapshot.
+    ///
+    /// The latest table is loaded from the catalog, unless a snapshot is pinned and the
+    /// cached table already knows it.
+    async fn table_to_scan(&self) -> Result<(Table, Option<i64>)> {
+        let Some(ConsistentReadToken { snapshot_id }) = self.pinned_snapshot else {
+            return Ok((self.refresh().await?, None));
+        };
+        let Some(snapshot_id) = snapshot_id else {
+            return Ok((self.table(), None));
+        };
+
+        let cached = self.table();
+        let table = if cached.metadata().snapshot_by_id(snapshot_id).is_some() {
+            cached
+        } else {
+            self.refresh().await?
+        };
+        if table.metadata().snapshot_by_id(snapshot_id).is_none() {
+            return Err(Error::new(
+                ErrorKind::DataInvalid,
+                format!(
+                    "Pinned snapshot {snapshot_id} of table {} no longer exists",
+                    self.table_ident
+                ),
+            ));
+        }
+        Ok((table, Some(snapshot_id)))
+    }
+
+    /// Builds a scan of the snapshot `snapshot_id` of `table` through this provider, or of
+    /// the current snapshot if `None`.
+    ///
+    /// The projected columns missing from the table schema are handled according to the
+    /// [`MissingColumnBehavior`] of this provider.
+    fn table_scan(
+        &self,
+        table: Table,
+        snapshot_id: Option<i64>,
+        projection: Option<&Vec<usize>>,
+        filters: &[Expr],
+        limit: Option<usize>,
+    ) -> DFResult<IcebergTableScan> {
+        let missing_columns = self
+            .missing_columns(&table, snapshot_id)
+            .map_err(to_datafusion_error)?;
+        let all_columns = (0..self.schema.fields().len()).collect();
+        let (read_projection, missing_projection): (Vec<usize>, Vec<usize>) = projection
+            .unwrap_or(&all_columns)
+            .iter()
+            .partition(|index| !missing_columns.contains(index));
+        if missing_projection.is_empty() {
+            return self.table_columns_scan(table, snapshot_id, projection, filters, limit);
+        }
+
+        let missing_names: HashSet<&str> = missing_projection
+            .iter()
+            .map(|index| self.schema.field(*index).name().as_str())
+            .collect();
+        if self.missing_column_behavior == MissingColumnBehavior::Error {
+            let mut missing_names: Vec<&str> = missing_names.into_iter().collect();
+            missing_names.sort();
+            let available_names: Vec<&str> = (0..self.schema.fields().len())
+                .filter(|index| !missing_columns.contains(index))
+                .map(|index| self.schema.field(index).name().as_str())
+                .collect();
+            return Err(to_datafusion_error(Error::new(
+                ErrorKind::DataInvalid,
+                format!(
+                    "Projected columns [{}] are missing from the schema of table {}, available columns: [{}]",
+                    missing_names.join(", "),
+                    self.table_ident,
+                    available_names.join(", ")
+                ),
+            )));
+        }
+
+        // Filters on the missing columns are left to DataFusion, which evaluates them on nulls
+        let filters: Vec<Expr> = filters
+            .iter()
+            .filter(|filter| {
+                filter
+                    .column_refs()
+                    .iter()
+                    .all(|column| !missing_names.contains(column.name.as_str()))
+            })
+            .cloned()
+            .collect();
+        let output_schema = Arc::new(
+            with_nullable_fields(&self.schema, &missing_columns)
+                .project(projection.unwrap_or(&all_columns))?,
+        );
+        Ok(self
+            .table_columns_scan(table, snapshot_id, Some(&read_projection), &filters, limit)?
+            .with_missing_columns_as_null(output_schema))
+    }
+
+    /// Returns the indexes of the columns of this provider that are missing from the schema
+    /// of `table` scanned at `snapshot_id`, or at the current snapshot if `None`.
+    fn missing_columns(&self, table: &Table, snapshot_id: Option<i64>) -> Result<HashSet<usize>> {
+        let metadata = table.metadata();
+        let columns = self.schema.fields().iter().enumerate();
+        if let Some(field_ids) = &self.projected_field_ids {
+            let table_schema = metadata.current_schema();
+            return Ok(columns
+                .filter(|(_, field)| {
+                    field_ids.get(field.name()).is_none_or(|field_id| {
+                        table_schema.as_struct().field_by_id(*field_id).is_none()
+                    })
+                })
+                .map(|(index, _)| index)
+                .collect());
+        }
+
+        let table_schema = match snapshot_id.and_then(|id| metadata.snapshot_by_id(id)) {
+            Some(snapshot) => snapshot.schema(metadata)?,
+            None => metadata.current_schema().clone(),
+        };
+        Ok(columns
+            .filter(|(_, field)| {
+                table_schema
+                    .as_struct()
+                    .field_by_name(field.name())
+                    .is_none()
+            })
+            .map(|(index, _)| index)
+            .collect())
+    }
+
+    /// Builds a scan of the columns of `projection`, which must all be in the table schema.
+    fn table_columns_scan(
+        &self,
+        table: Table,
+        snapshot_id: Option<i64>,
+        projection: Option<&Vec<usize>>,
+        filters: &[Expr],
+        limit: Option<usize>,
+    ) -> DFResult<IcebergTableScan> {
+        if let Some(field_ids) = &self.projected_field_ids {
+            // Resolve the exposed columns by field id, so they survive column renames. Columns
+            // whose field no longer exists are not projected.
+            let table_schema = table.metadata().current_schema();
+            let table_columns: HashMap<String, String> = field_ids
+                .iter()
+                .filter_map(|(name, field_id)| {
+                    let field = table_schema.as_struct().field_by_id(*field_id)?;
+                    Some((name.clone(), field.name.clone()))
+                })
+                .collect();
+
+            return IcebergTableScan::new_with_column_aliases(
+                table,
+                snapshot_id,
+                self.schema.clone(),
+                projection,
+                filters,
+                limit,
+                &table_columns,
+            );
+        }
+
+        Ok(IcebergTableScan::new(
+            table,
+            snapshot_id,
+            self.schema.clone(),
+            projection,
+            filters,
+            limit,
+        ))
+    }
+
+    /// Plans a scan of all the columns of the scanned snapshot of the table with `filters` and
+    /// caches its file plan.
+    ///
+    /// See [`IcebergTableProvider::prewarm_projection`].
+    pub async fn prewarm(&self, filters: &[Expr]) -> DFResult<()> {
+        self.prewarm_projection(None, filters).await
+    }
+
+    /// Plans a scan of the columns of `projection` of the scanned snapshot of the table with
+    /// `filters` and caches its file plan.
+    ///
+    /// The scanned snapshot is the pinned one if this provider has a consistent read token,
+    /// and the latest one otherwise. The manifests are read and pruned once, and later scans
+    /// of the same snapshot and table schema selecting the same columns, whose filters push
+    /// down to the same predicate, read the cached files instead. The cache is shared by all
+    /// clones of this provider, and its plans are dropped once a scan finds a new snapshot or
+    /// schema.
+    pub async fn prewarm_projection(
+        &self,
+        projection: Option<&Vec<usize>>,
+        filters: &[Expr],
+    ) -> DFResult<()> {
+        let (table, pinned_snapshot_id) =
+            self.table_to_scan().await.map_err(to_datafusion_error)?;
+        if self.pinned_snapshot.is_some() && pinned_snapshot_id.is_none() {
+            // The table had no snapshot when it was pinned
+            return Ok(());
+        }
+        let Some(snapshot_id) = pinned_snapshot_id.or(table.metadata().current_snapshot_id())
+        else {
+            return Ok(());
+        };
+        let scan = self.table_scan(table.clone(), pinned_snapshot_id, projection, filters, None)?;
+        let key = self.file_plan_key(projection, scan.predicates());
+        let file_plan = plan_file_tasks(&table, snapshot_id, key.predicates.clone()).await?;
+
+        self.file_plan_cache
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .insert(
+                snapshot_id,
+                table.metadata().current_schema_id(),
+                key,
+                Arc::new(file_plan),
+            );
+        Ok(())
+    }
+
+    /// Returns the key of the cached file plan of a scan of the columns of `projection`
+    /// with `predicates`.
+    fn file_plan_key(
+        &self,
+        projection: Option<&Vec<usize>>,
+        predicates: Option<&Predicate>,
+    ) -> FilePlanKey {
+        FilePlanKey {
+            projection: projection
+                .cloned()
+                .unwrap_or_else(|| (0..self.schema.fields().len()).collect()),
+            predicates: predicates.cloned(),
+        }
+    }
+
+    /// Returns the cached file plan of a scan of the snapshot `snapshot_id` with the table
+    /// schema `schema_id`.
+    ///
+    /// Without a pinned snapshot, `snapshot_id` is the latest snapshot and the plans of other
+    /// snapshots and schemas are dropped.
+    fn cached_file_plan(
+        &self,
+        snapshot_id: Option<i64>,
+        schema_id: SchemaId,
+        key: &FilePlanKey,
+    ) -> Option<Arc<Vec<FileScanTask>>> {
+        let mut cache = self
+            .file_plan_cache
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if cache.snapshot_id != snapshot_id || cache.schema_id != Some(schema_id) {
+            if self.pinned_snapshot.is_none() {
+                *cache = FilePlanCache::default();
+            }
+            return None;
+        }
+        cache.get(key)
+    }
+
     pub(crate) async fn metadata_table(
         &self,
         r#type: MetadataTableType,
     ) -> Result<IcebergMetadataTableProvider> {
         // Load fresh table metadata for metadata table access
-        let table = self.catalog.load_table(&self.table_ident).await?;
+        let table = self.refresh().await?;
         Ok(IcebergMetadataTableProvider { table, r#type })
     }
 }
 
+/// Returns the write-default values of the top-level columns of `schema` as literal
+/// expressions of the column types in `arrow_schema`, keyed by column name.
+///
+/// The columns an insert doesn't set are filled with these values before the partition
+/// values of the inserted rows are computed, and with nulls when they have none. Defaults of
+/// nested columns are not supported, so these columns are filled with nulls as well.
+fn column_defaults(schema: &Schema, arrow_schema: &ArrowSchema) -> HashMap<String, Expr> {
+    schema
+        .as_struct()
+        .fields()
+        .iter()
+        .zip(arrow_schema.fields())
+        .filter_map(|(field, arrow_field)| {
+            let Some(Literal::Primitive(value)) = &field.write_default else {
+                return None;
+            };
+            let value = primitive_literal_to_scalar(value, arrow_field.data_type())?;
+            Some((field.name.clone(), Expr::Literal(value, None)))
+        })
+        .collect()
+}
+
+/// Converts a primitive literal to a scalar value of the arrow type `data_type`.
+fn primitive_literal_to_scalar(
+    value: &PrimitiveLiteral,
+    data_type: &DataType,
+) -> Option<ScalarValue> {
+    let value = match (value, data_type) {
+        (PrimitiveLiteral::Boolean(v), DataType::Boolean) => ScalarValue::Boolean(Some(*v)),
+        (PrimitiveLiteral::Int(v), DataType::Int32) => ScalarValue::Int32(Some(*v)),
+        (PrimitiveLiteral::Int(v), DataType::Date32) => ScalarValue::Date32(Some(*v)),
+        (PrimitiveLiteral::Long(v), DataType::Int64) => ScalarValue::Int64(Some(*v)),
+        (PrimitiveLiteral::Long(v), DataType::Time64(TimeUnit::Microsecond)) => {
+            ScalarValue::Time64Microsecond(Some(*v))
+        }
+        (PrimitiveLiteral::Long(v), DataType::Timestamp(TimeUnit::Microsecond, tz)) => {
+            ScalarValue::TimestampMicrosecond(Some(*v), tz.clone())
+        }
+        (PrimitiveLiteral::Long(v), DataType::Timestamp(TimeUnit::Nanosecond, tz)) => {
+            ScalarValue::TimestampNanosecond(Some(*v), tz.clone())
+        }
+        (PrimitiveLiteral::Float(v), DataType::Float32) => ScalarValue::Float32(Some(v.0)),
+        (PrimitiveLiteral::Double(v), DataType::Float64) => ScalarValue::Float64(Some(v.0)),
+        (PrimitiveLiteral::String(v), DataType::Utf8) => ScalarValue::Utf8(Some(v.clone())),
+        (PrimitiveLiteral::String(v), DataType::LargeUtf8) => {
+            ScalarValue::LargeUtf8(Some(v.clone()))
+        }
+        (PrimitiveLiteral::Binary(v), DataType::Binary) => ScalarValue::Binary(Some(v.clone())),
+        (PrimitiveLiteral::Binary(v), DataType::LargeBinary) => {
+            ScalarValue::LargeBinary(Some(v.clone()))
+        }
+        (PrimitiveLiteral::Binary(v), DataType::FixedSizeBinary(size)) => {
+            ScalarValue::FixedSizeBinary(*size, Some(v.clone()))
+        }
+        (PrimitiveLiteral::UInt128(v), DataType::FixedSizeBinary(16)) => {
+            ScalarValue::FixedSizeBinary(16, Some(v.to_be_bytes().to_vec()))
+        }
+        (PrimitiveLiteral::Int128(v), DataType::Decimal128(precision, scale)) => {
+            ScalarValue::Decimal128(Some(*v), *precision, *scale)
+        }
+        _ => return None,
+    };
+    Some(value)
+}
+
+/// Returns `schema` with its fields at `indexes` nullable.
+fn with_nullable_fields(schema: &ArrowSchema, indexes: &HashSet<usize>) -> ArrowSchema {
+    let fields: Vec<Field> = schema
+        .fields()
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let nullable = field.is_nullable() || indexes.contains(&index);
+            field.as_ref().clone().with_nullable(nullable)
+        })
+        .collect();
+    ArrowSchema::new_with_metadata(fields, schema.metadata().clone())
+}
+
 #[async_trait]
 impl TableProvider for IcebergTableProvider {
     fn as_any(&self) -> &dyn Any {
@@ -115,6 +734,14 @@ impl TableProvider for IcebergTableProvider {
     }
 
     fn schema(&self) -> ArrowSchemaRef {
+        if self.missing_column_behavior == MissingColumnBehavior::Null {
+            let snapshot_id = self.pinned_snapshot.and_then(|token| token.snapshot_id);
+            if let Ok(missing_columns) = self.missing_columns(&self.table(), snapshot_id)
+                && !missing_columns.is_empty()
+            {
+                return Arc::new(with_nullable_fields(&self.schema, &missing_columns));
+            }
+        }
         self.schema.clone()
     }
 
@@ -122,6 +749,10 @@ impl TableProvider for IcebergTableProvider {
         TableType::Base
     }
 
+    fn get_column_default(&self, column: &str) -> Option<&Expr> {
+        self.column_defaults.get(column)
+    }
+
     async fn scan(
         &self,
         _state: &dyn Session,
@@ -129,22 +760,24 @@ impl TableProvider for IcebergTableProvider {
         filters: &[Expr],
         limit: Option<usize>,
     ) -> DFResult<Arc<dyn ExecutionPlan>> {
-        // Load fresh table metadata from catalog
-        let table = self
-            .catalog
-            .load_table(&self.table_ident)
-            .await
-            .map_err(to_datafusion_error)?;
+        let (table, snapshot_id) = self.table_to_scan().await.map_err(to_datafusion_error)?;
+        let scan = self.table_scan(table.clone(), snapshot_id, projection, filters, limit)?;
+        if self.pinned_snapshot.is_some() && snapshot_id.is_none() {
+            // The table had no snapshot when it was pinned
+            return Ok(Arc::new(scan.with_file_plan(Arc::new(vec![]))));
+        }
 
-        // Create scan with fresh metadata (always use current snapshot)
-        Ok(Arc::new(IcebergTableScan::new(
-            table,
-            None, // Always use current snapshot for catalog-backed provider
-            self.schema.clone(),
-            projection,
-            filters,
-            limit,
-        )))
+        let scanned_snapshot_id = snapshot_id.or(table.metadata().current_snapshot_id());
+        let key = self.file_plan_key(projection, scan.predicates());
+        let scan = match self.cached_file_plan(
+            scanned_snapshot_id,
+            table.metadata().current_schema_id(),
+            &key,
+        ) {
+            Some(file_plan) => scan.with_file_plan(file_plan),
+            None => scan,
+        };
+        Ok(Arc::new(scan))
     }
 
     fn supports_filters_pushdown(
@@ -159,14 +792,42 @@ impl TableProvider for IcebergTableProvider {
         &self,
         state: &dyn Session,
         input: Arc<dyn ExecutionPlan>,
-        _insert_op: InsertOp,
+        insert_op: InsertOp,
     ) -> DFResult<Arc<dyn ExecutionPlan>> {
+        if self.projected_field_ids.is_some() {
+            return Err(to_datafusion_error(Error::new(
+                ErrorKind::FeatureUnsupported,
+                "Write operations are not supported on an IcebergTableProvider with a projected schema"
+                    .to_string(),
+            )));
+        }
+
+        // Overwriting replaces the partitions touched by the input, leaving the other
+        // partitions untouched
+        let replace_partitions = match insert_op {
+            InsertOp::Append => false,
+            InsertOp::Overwrite if self.write_branch.is_some() => {
+                return Err(to_datafusion_error(Error::new(
+                    ErrorKind::FeatureUnsupported,
+                    "Overwrite is not supported by IcebergTableProvider on a write branch",
+                )));
+            }
+            InsertOp::Overwrite => true,
+            InsertOp::Replace => {
+                return Err(to_datafusion_error(Error::new(
+                    ErrorKind::FeatureUnsupported,
+                    "Replace is not supported by IcebergTableProvider, use overwrite instead",
+                )));
+            }
+        };
+
         // Load fresh table metadata from catalog
-        let table = self
-            .catalog
-            .load_table(&self.table_ident)
-            .await
-            .map_err(to_datafusion_error)?;
+        let table = self.refresh().await.map_err(to_datafusion_error)?;
+
+        let input = match &self.arrow_type_converter {
+            Some(converter) => convert_arrow_types(input, &table, converter.clone())?,
+            None => input,
+        };
 
         let partition_spec = table.metadata().default_partition_spec();
 
@@ -217,21 +878,24 @@ impl TableProvider for IcebergTableProvider {
             sort_by_partition(repartitioned_plan)?
         };
 
-        let write_plan = Arc::new(IcebergWriteExec::new(
-            table.clone(),
-            write_input,
-            self.schema.clone(),
-        ));
+        let write_plan = Arc::new(
+            IcebergWriteExec::new(table.clone(), write_input, self.schema.clone())
+                .with_file_name_seed(self.file_name_seed.clone()),
+        );
 
         // Merge the outputs of write_plan into one so we can commit all files together
         let coalesce_partitions = Arc::new(CoalescePartitionsExec::new(write_plan));
 
-        Ok(Arc::new(IcebergCommitExec::new(
-            table,
-            self.catalog.clone(),
-            coalesce_partitions,
-            self.schema.clone(),
-        )))
+        Ok(Arc::new(
+            IcebergCommitExec::new(
+                table,
+                self.catalog.clone(),
+                coalesce_partitions,
+                self.schema.clone(),
+            )
+            .with_replace_partitions(replace_partitions)
+            .with_branch(self.write_branch.clone()),
+        ))
     }
 }
 
@@ -291,6 +955,11 @@ impl IcebergStaticTableProvider {
             schema,
         })
     }
+
+    /// Returns the table served by this provider.
+    pub fn table(&self) -> &Table {
+        &self.table
+    }
 }
 
 #[async_trait]
@@ -594,6 +1263,289 @@ mod tests {
         assert!(execution_result.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_insert_with_deterministic_file_names() {
+        use futures::TryStreamExt;
+
+        // The paths of the data files of the table, relative to its location.
+        async fn insert_with_seed(seed: &str) -> Vec<String> {
+            let (catalog, namespace, table_name, _temp_dir) = get_test_catalog_and_table().await;
+            let provider =
+                IcebergTableProvider::try_new(catalog.clone(), namespace.clone(), table_name)
+                    .await
+                    .unwrap()
+                    .with_deterministic_file_names(seed);
+            let ctx = SessionContext::new();
+            ctx.register_table("test_table", Arc::new(provider.clone()))
+                .unwrap();
+            ctx.sql("INSERT INTO test_table VALUES (1, 'a'), (2, 'b')")
+                .await
+                .unwrap()
+                .collect()
+                .await
+                .unwrap();
+
+            let table = provider.refresh().await.unwrap();
+            let location = table.metadata().location().to_string();
+            let tasks: Vec<_> = table
+                .scan()
+                .build()
+                .unwrap()
+                .plan_files()
+                .await
+                .unwrap()
+                .try_collect()
+                .await
+                .unwrap();
+            let mut paths: Vec<String> = tasks
+                .iter()
+                .map(|task| {
+                    task.data_file_path
+                        .strip_prefix(&location)
+                        .unwrap()
+                        .to_string()
+                })
+                .collect();
+            paths.sort();
+            paths
+        }
+
+        let paths = insert_with_seed("seed").await;
+        assert!(!paths.is_empty());
+        assert!(paths.iter().all(|path| path.starts_with("/data/seed-")));
+        assert_eq!(insert_with_seed("seed").await, paths);
+        assert_ne!(insert_with_seed("other").await, paths);
+    }
+
+    /// Stores `Decimal256` values with a precision of at most 38 as Iceberg decimals.
+    #[derive(Debug)]
+    struct Decimal256Converter;
+
+    impl ArrowTypeConverter for Decimal256Converter {
+        fn iceberg_type(&self, data_type: &DataType) -> Option<Type> {
+            match data_type {
+                DataType::Decimal256(precision, scale) if *precision <= 38 => {
+                    Type::decimal(*precision as u32, *scale as u32).ok()
+                }
+                _ => None,
+            }
+        }
+
+        fn convert(
+            &self,
+            array: &datafusion::arrow::array::ArrayRef,
+            target_type: &DataType,
+        ) -> Result<datafusion::arrow::array::ArrayRef> {
+            datafusion::arrow::compute::cast(array, target_type)
+                .map_err(|e| Error::new(ErrorKind::DataInvalid, e.to_string()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_insert_with_arrow_type_converter() {
+        use datafusion::arrow::array::{Decimal256Array, Int32Array, RecordBatch};
+        use datafusion::arrow::datatypes::{Field, i256};
+        use datafusion::dataframe::DataFrameWriteOptions;
+
+        let (catalog, namespace, _, _temp_dir) = get_test_catalog_and_table().await;
+        let schema = Schema::builder()
+            .with_schema_id(0)
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Int)).into(),
+                NestedField::optional(2, "amount", Type::decimal(20, 2).unwrap()).into(),
+            ])
+            .build()
+            .unwrap();
+        catalog
+            .create_table(
+                &namespace,
+                TableCreation::builder()
+                    .name("amounts".to_string())
+                    .schema(schema)
+                    .build(),
+            )
+            .await
+            .unwrap();
+
+        let batch = RecordBatch::try_new(
+            Arc::new(ArrowSchema::new(vec![
+                Field::new("id", DataType::Int32, false),
+                Field::new("amount", DataType::Decimal256(20, 2), true),
+            ])),
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2])),
+                Arc::new(
+                    Decimal256Array::from(vec![Some(i256::from_i128(12345)), None])
+                        .with_precision_and_scale(20, 2)
+                        .unwrap(),
+                ),
+            ],
+        )
+        .unwrap();
+
+        let provider = IcebergTableProvider::try_new(catalog.clone(), namespace.clone(), "amounts")
+            .await
+            .unwrap();
+        let ctx = SessionContext::new();
+        ctx.register_table("amounts", Arc::new(provider.clone()))
+            .unwrap();
+        let Err(err) = ctx
+            .read_batch(batch.clone())
+            .unwrap()
+            .write_table("amounts", DataFrameWriteOptions::new())
+            .await
+        else {
+            panic!("inserting an unsupported Arrow type should fail without a converter");
+        };
+        assert!(err.to_string().contains("Decimal256"), "{err}");
+
+        let ctx = SessionContext::new();
+        ctx.register_table(
+            "amounts",
+            Arc::new(provider.with_arrow_type_converter(Arc::new(Decimal256Converter))),
+        )
+        .unwrap();
+        ctx.read_batch(batch)
+            .unwrap()
+            .write_table("amounts", DataFrameWriteOptions::new())
+            .await
+            .unwrap();
+
+        let batches = ctx
+            .sql("SELECT amount FROM amounts ORDER BY id")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        let amounts: Vec<Option<i128>> = batches
+            .iter()
+            .flat_map(|batch| {
+                batch
+                    .column(0)
+                    .as_any()
+                    .downcast_ref::<datafusion::arrow::array::Decimal128Array>()
+                    .unwrap()
+                    .iter()
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        assert_eq!(amounts, vec![Some(12345), None]);
+    }
+
+    #[tokio::test]
+    async fn test_catalog_backed_provider_refresh() {
+        use iceberg::spec::{DataContentType, DataFileBuilder, DataFileFormat, Struct};
+        use iceberg::transaction::{ApplyTransactionAction, Transaction};
+
+        let (catalog, namespace, table_name, _temp_dir) = get_test_catalog_and_table().await;
+
+        let provider =
+            IcebergTableProvider::try_new(catalog.clone(), namespace.clone(), table_name.clone())
+                .await
+                .unwrap();
+        assert!(provider.table().metadata().current_snapshot().is_none());
+
+        // Commit to the table without going through the provider
+        let table = catalog
+            .load_table(&TableIdent::new(namespace.clone(), table_name.clone()))
+            .await
+            .unwrap();
+        let data_file = DataFileBuilder::default()
+            .content(DataContentType::Data)
+            .file_path(format!(
+                "{}/data/external.parquet",
+                table.metadata().location()
+            ))
+            .file_format(DataFileFormat::Parquet)
+            .file_size_in_bytes(100)
+            .record_count(1)
+            .partition_spec_id(table.metadata().default_partition_spec_id())
+            .partition(Struct::empty())
+            .build()
+            .unwrap();
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .fast_append()
+            .add_data_files(vec![data_file])
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(catalog.as_ref()).await.unwrap();
+        let snapshot_id = table.metadata().current_snapshot_id();
+        assert!(snapshot_id.is_some());
+
+        // The cached table is only updated on refresh
+        assert!(provider.table().metadata().current_snapshot().is_none());
+
+        let refreshed = provider.refresh().await.unwrap();
+        assert_eq!(refreshed.metadata().current_snapshot_id(), snapshot_id);
+        assert_eq!(
+            provider.table().metadata().current_snapshot_id(),
+            snapshot_id
+        );
+    }
+
+    #[tokio::test]
+    async fn test_catalog_backed_provider_pinned_snapshot() {
+        let (catalog, namespace, table_name, _temp_dir) = get_test_catalog_and_table().await;
+
+        let provider = IcebergTableProvider::try_new(catalog, namespace, table_name)
+            .await
+            .unwrap();
+        let ctx = SessionContext::new();
+        ctx.register_table("test_table", Arc::new(provider.clone()))
+            .unwrap();
+        let count = |table: &'static str| {
+            let ctx = ctx.clone();
+            async move {
+                let batches = ctx
+                    .sql(&format!("SELECT * FROM {table}"))
+                    .await
+                    .unwrap()
+                    .collect()
+                    .await
+                    .unwrap();
+                batches.iter().map(|batch| batch.num_rows()).sum::<usize>()
+            }
+        };
+
+        let empty = provider.pin_snapshot().await.unwrap();
+        assert_eq!(empty.snapshot_id(), None);
+        ctx.register_table(
+            "empty_table",
+            Arc::new(provider.clone().with_consistent_read_token(empty)),
+        )
+        .unwrap();
+
+        ctx.sql("INSERT INTO test_table VALUES (1, 'a')")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        let token = provider.pin_snapshot().await.unwrap();
+        assert_eq!(
+            token.snapshot_id(),
+            provider.table().metadata().current_snapshot_id()
+        );
+        ctx.register_table(
+            "pinned_table",
+            Arc::new(provider.clone().with_consistent_read_token(token)),
+        )
+        .unwrap();
+        assert_eq!(count("pinned_table").await, 1);
+
+        ctx.sql("INSERT INTO test_table VALUES (2, 'b')")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(count("pinned_table").await, 1);
+        assert_eq!(count("empty_table").await, 0);
+        assert_eq!(count("test_table").await, 2);
+    }
+
     #[tokio::test]
     async fn test_physical_input_schema_consistent_with_logical_input_schema() {
         let (catalog, namespace, table_name, _temp_dir) = get_test_catalog_and_table().await;
@@ -868,4 +1820,463 @@ mod tests {
             "Limit should be None when not specified"
         );
     }
+
+    #[tokio::test]
+    async fn test_catalog_backed_provider_projected_schema() {
+        use datafusion::arrow::array::{Int32Array, StringArray};
+        use datafusion::arrow::datatypes::{DataType, Field, Schema as ArrowSchema};
+
+        let (catalog, namespace, _, _temp_dir) = get_test_catalog_and_table().await;
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Int)).into(),
+                NestedField::required(2, "name", Type::Primitive(PrimitiveType::String)).into(),
+                NestedField::required(3, "ssn", Type::Primitive(PrimitiveType::String)).into(),
+                NestedField::required(4, "salary", Type::Primitive(PrimitiveType::Long)).into(),
+            ])
+            .build()
+            .unwrap();
+        catalog
+            .create_table(
+                &namespace,
+                TableCreation::builder()
+                    .name("employees".to_string())
+                    .schema(schema)
+                    .build(),
+            )
+            .await
+            .unwrap();
+
+        let provider = IcebergTableProvider::try_new(catalog.clone(), namespace, "employees")
+            .await
+            .unwrap();
+        let ctx = SessionContext::new();
+        ctx.register_table("employees", Arc::new(provider.clone()))
+            .unwrap();
+        ctx.sql("INSERT INTO employees VALUES (1, 'a', '111', 10), (2, 'b', '222', 20), (3, 'c', '333', 30)")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+
+        let field = |name: &str, data_type: DataType, field_id: i32| {
+            Field::new(name, data_type, false).with_metadata(HashMap::from([(
+                PARQUET_FIELD_ID_META_KEY.to_string(),
+                field_id.to_string(),
+            )]))
+        };
+        let masked = provider
+            .clone()
+            .with_projected_schema(Arc::new(ArrowSchema::new(vec![
+                field("employee_id", DataType::Int32, 1),
+                field("employee_name", DataType::Utf8, 2),
+            ])))
+            .unwrap();
+        ctx.register_table("masked", Arc::new(masked)).unwrap();
+
+        let batches = ctx
+            .sql("SELECT employee_name, employee_id FROM masked WHERE employee_id > 1 ORDER BY employee_id")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        let batch =
+            datafusion::arrow::compute::concat_batches(&batches[0].schema(), &batches).unwrap();
+        assert_eq!(
+            batch.column(0).as_ref(),
+            &StringArray::from(vec!["b", "c"]) as &dyn datafusion::arrow::array::Array
+        );
+        assert_eq!(
+            batch.column(1).as_ref(),
+            &Int32Array::from(vec![2, 3]) as &dyn datafusion::arrow::array::Array
+        );
+
+        let df = ctx.sql("SELECT * FROM masked").await.unwrap();
+        let names = df
+            .schema()
+            .fields()
+            .iter()
+            .map(|field| field.name().clone())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["employee_id", "employee_name"]);
+        assert_eq!(df.count().await.unwrap(), 3);
+
+        // Hidden columns can't be queried, neither under their table name nor by writing
+        assert!(ctx.sql("SELECT ssn FROM masked").await.is_err());
+        assert!(ctx.sql("SELECT id FROM masked").await.is_err());
+        assert!(
+            ctx.sql("INSERT INTO masked VALUES (4, 'd')")
+                .await
+                .unwrap()
+                .collect()
+                .await
+                .is_err()
+        );
+
+        // Projected columns must read an existing field of the same type
+        for projected in [
+            Field::new("employee_id", DataType::Int32, false),
+            field("employee_id", DataType::Int32, 5),
+            field("employee_id", DataType::Utf8, 1),
+        ] {
+            assert!(
+                provider
+                    .clone()
+                    .with_projected_schema(Arc::new(ArrowSchema::new(vec![projected])))
+                    .is_err()
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_catalog_backed_provider_missing_column_behavior() {
+        use datafusion::arrow::array::{Array, Int32Array, StringArray};
+        use iceberg::transaction::{ApplyTransactionAction, Transaction};
+
+        let (catalog, namespace, table_name, _temp_dir) = get_test_catalog_and_table().await;
+        let provider =
+            IcebergTableProvider::try_new(catalog.clone(), namespace.clone(), table_name.clone())
+                .await
+                .unwrap();
+        let ctx = SessionContext::new();
+        ctx.register_table("strict", Arc::new(provider.clone()))
+            .unwrap();
+        ctx.register_table(
+            "lenient",
+            Arc::new(
+                provider
+                    .clone()
+                    .with_missing_column_behavior(MissingColumnBehavior::Null),
+            ),
+        )
+        .unwrap();
+        ctx.sql("INSERT INTO strict VALUES (1, 'a'), (2, 'b')")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        let batches = ctx
+            .sql("SELECT * FROM lenient ORDER BY id")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(
+            batches.iter().map(|batch| batch.num_rows()).sum::<usize>(),
+            2
+        );
+        // Inserts still reject nulls in the required columns
+        assert!(
+            ctx.sql("INSERT INTO lenient VALUES (3, NULL)")
+                .await
+                .unwrap()
+                .collect()
+                .await
+                .is_err()
+        );
+
+        // Drop a column after the providers cached the table schema
+        let table = catalog
+            .load_table(&TableIdent::new(namespace, table_name))
+            .await
+            .unwrap();
+        let tx = Transaction::new(&table);
+        let tx = tx.update_schema().delete_column("name").apply(tx).unwrap();
+        tx.commit(catalog.as_ref()).await.unwrap();
+
+        let Err(err) = ctx
+            .sql("SELECT * FROM strict")
+            .await
+            .unwrap()
+            .collect()
+            .await
+        else {
+            panic!("Scanning a dropped column should fail");
+        };
+        let err = crate::find_iceberg_error(&err).unwrap();
+        assert_eq!(err.kind(), ErrorKind::DataInvalid);
+        assert_eq!(
+            err.message(),
+            "Projected columns [name] are missing from the schema of table test_ns.test_table, available columns: [id]"
+        );
+        // The remaining columns can still be scanned
+        let df = ctx.sql("SELECT id FROM strict").await.unwrap();
+        assert_eq!(df.count().await.unwrap(), 2);
+
+        let batches = ctx
+            .sql("SELECT id, name FROM lenient ORDER BY id")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        let batch =
+            datafusion::arrow::compute::concat_batches(&batches[0].schema(), &batches).unwrap();
+        assert_eq!(
+            batch.column(0).as_ref(),
+            &Int32Array::from(vec![1, 2]) as &dyn Array
+        );
+        assert_eq!(
+            batch.column(1).as_ref(),
+            &StringArray::from(vec![None::<&str>, None]) as &dyn Array
+        );
+
+        // Filters on a missing column are evaluated on its nulls
+        let df = ctx
+            .sql("SELECT id FROM lenient WHERE name = 'a'")
+            .await
+            .unwrap();
+        assert_eq!(df.count().await.unwrap(), 0);
+        let df = ctx
+            .sql("SELECT * FROM lenient WHERE name IS NULL AND id > 1")
+            .await
+            .unwrap();
+        assert_eq!(df.count().await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_insert_into_write_branch() {
+        let (catalog, namespace, table_name, _temp_dir) = get_test_catalog_and_table().await;
+        let table_ident = TableIdent::new(namespace.clone(), table_name.clone());
+        let provider =
+            IcebergTableProvider::try_new(catalog.clone(), namespace.clone(), table_name.clone())
+                .await
+                .unwrap();
+        let ctx = SessionContext::new();
+        ctx.register_table("main", Arc::new(provider.clone()))
+            .unwrap();
+        ctx.register_table("dev", Arc::new(provider.with_write_branch("dev")))
+            .unwrap();
+
+        let insert = |sql: &'static str| {
+            let ctx = ctx.clone();
+            async move { ctx.sql(sql).await.unwrap().collect().await.unwrap() }
+        };
+        insert("INSERT INTO main VALUES (1, 'a')").await;
+        let main_snapshot_id = catalog
+            .load_table(&table_ident)
+            .await
+            .unwrap()
+            .metadata()
+            .current_snapshot_id()
+            .unwrap();
+        insert("INSERT INTO dev VALUES (2, 'b'), (3, 'c')").await;
+        insert("INSERT INTO dev VALUES (4, 'd')").await;
+
+        // Main is unchanged.
+        let table = catalog.load_table(&table_ident).await.unwrap();
+        assert_eq!(
+            table.metadata().current_snapshot_id(),
+            Some(main_snapshot_id)
+        );
+        assert_eq!(
+            ctx.sql("SELECT * FROM main")
+                .await
+                .unwrap()
+                .count()
+                .await
+                .unwrap(),
+            1
+        );
+
+        // The dev branch was created off main and has the rows of both inserts.
+        let dev_head = table.metadata().snapshot_by_ref("dev").unwrap().clone();
+        let dev_ancestors: Vec<i64> = table
+            .metadata()
+            .ancestors_of(dev_head.snapshot_id())
+            .map(|snapshot| snapshot.snapshot_id())
+            .collect();
+        assert_eq!(dev_ancestors.len(), 3);
+        assert_eq!(dev_ancestors[2], main_snapshot_id);
+        let dev_provider =
+            IcebergStaticTableProvider::try_new_from_table_snapshot(table, dev_head.snapshot_id())
+                .await
+                .unwrap();
+        ctx.register_table("dev_read", Arc::new(dev_provider))
+            .unwrap();
+        let batches = ctx
+            .sql("SELECT id FROM dev_read ORDER BY id")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        let ids: Vec<i32> = batches
+            .iter()
+            .flat_map(|batch| {
+                batch
+                    .column(0)
+                    .as_any()
+                    .downcast_ref::<datafusion::arrow::array::Int32Array>()
+                    .unwrap()
+                    .values()
+                    .to_vec()
+            })
+            .collect();
+        assert_eq!(ids, vec![1, 2, 3, 4]);
+
+        // Overwrites are only supported on main.
+        let Err(err) = ctx
+            .sql("INSERT OVERWRITE dev VALUES (5, 'e')")
+            .await
+            .unwrap()
+            .collect()
+            .await
+        else {
+            panic!("Overwriting a write branch should fail");
+        };
+        let err = crate::find_iceberg_error(&err).unwrap();
+        assert_eq!(err.kind(), ErrorKind::FeatureUnsupported);
+    }
+
+    #[tokio::test]
+    async fn test_catalog_backed_provider_prewarm() {
+        use datafusion::prelude::{col, lit};
+
+        let (catalog, namespace, table_name, _temp_dir) = get_test_catalog_and_table().await;
+        let provider = IcebergTableProvider::try_new(catalog, namespace, table_name)
+            .await
+            .unwrap();
+        let ctx = SessionContext::new();
+        ctx.register_table("t", Arc::new(provider.clone())).unwrap();
+        let insert = async |sql: &str| {
+            ctx.sql(sql).await.unwrap().collect().await.unwrap();
+        };
+        let count = async |sql: &str| -> DFResult<usize> {
+            let batches = ctx.sql(sql).await?.collect().await?;
+            Ok(batches.iter().map(|batch| batch.num_rows()).sum())
+        };
+
+        insert("INSERT INTO t VALUES (1, 'a'), (2, 'b')").await;
+        provider.prewarm(&[col("id").gt(lit(1))]).await.unwrap();
+
+        // A new snapshot drops the cached plan
+        insert("INSERT INTO t VALUES (3, 'c')").await;
+        assert_eq!(count("SELECT * FROM t WHERE id > 1").await.unwrap(), 2);
+
+        provider.prewarm(&[col("id").gt(lit(1))]).await.unwrap();
+        provider
+            .prewarm_projection(Some(&vec![1]), &[])
+            .await
+            .unwrap();
+        remove_manifests(&provider);
+
+        // Without manifests, only the prewarmed scans can be planned
+        assert_eq!(count("SELECT * FROM t WHERE id > 1").await.unwrap(), 2);
+        let batches = ctx
+            .sql("SELECT name FROM t ORDER BY name")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        let batch =
+            datafusion::arrow::compute::concat_batches(&batches[0].schema(), &batches).unwrap();
+        assert_eq!(
+            batch.column(0).as_ref(),
+            &datafusion::arrow::array::StringArray::from(vec!["a", "b", "c"])
+                as &dyn datafusion::arrow::array::Array
+        );
+        assert!(count("SELECT * FROM t WHERE id > 2").await.is_err());
+        assert!(count("SELECT id FROM t").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_prewarmed_plans_are_dropped_on_schema_change() {
+        use iceberg::transaction::{ApplyTransactionAction, Transaction};
+
+        let (catalog, namespace, table_name, _temp_dir) = get_test_catalog_and_table().await;
+        let provider =
+            IcebergTableProvider::try_new(catalog.clone(), namespace.clone(), table_name.clone())
+                .await
+                .unwrap();
+        let ctx = SessionContext::new();
+        ctx.register_table("t", Arc::new(provider.clone())).unwrap();
+        ctx.sql("INSERT INTO t VALUES (1, 'a'), (2, 'b')")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        provider.prewarm(&[]).await.unwrap();
+
+        // Evolve the schema without a new snapshot
+        let table = catalog
+            .load_table(&TableIdent::new(namespace, table_name))
+            .await
+            .unwrap();
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .update_schema()
+            .add_column("extra", Type::Primitive(PrimitiveType::Long))
+            .apply(tx)
+            .unwrap();
+        tx.commit(catalog.as_ref()).await.unwrap();
+        remove_manifests(&provider);
+
+        // The plan of the previous schema is not reused, so the scan reads the manifests
+        let err = ctx
+            .sql("SELECT * FROM t")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Failed to load manifest list"));
+    }
+
+    #[tokio::test]
+    async fn test_prewarm_plans_the_pinned_snapshot() {
+        let (catalog, namespace, table_name, _temp_dir) = get_test_catalog_and_table().await;
+        let provider = IcebergTableProvider::try_new(catalog, namespace, table_name)
+            .await
+            .unwrap();
+        let ctx = SessionContext::new();
+        ctx.register_table("t", Arc::new(provider.clone())).unwrap();
+        let insert = async |sql: &str| {
+            ctx.sql(sql).await.unwrap().collect().await.unwrap();
+        };
+
+        insert("INSERT INTO t VALUES (1, 'a')").await;
+        let pinned = provider
+            .clone()
+            .with_consistent_read_token(provider.pin_snapshot().await.unwrap());
+        ctx.register_table("pinned", Arc::new(pinned.clone()))
+            .unwrap();
+        insert("INSERT INTO t VALUES (2, 'b')").await;
+
+        pinned.prewarm(&[]).await.unwrap();
+        remove_manifests(&provider);
+
+        // The pinned scan reads the cached plan of the pinned snapshot
+        let batches = ctx
+            .sql("SELECT * FROM pinned")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(
+            batches.iter().map(|batch| batch.num_rows()).sum::<usize>(),
+            1
+        );
+    }
+
+    /// Removes the manifest and manifest list files of the table of `provider`.
+    fn remove_manifests(provider: &IcebergTableProvider) {
+        let metadata_dir = format!("{}/metadata", provider.table().metadata().location());
+        for entry in std::fs::read_dir(metadata_dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path
+                .extension()
+                .is_some_and(|extension| extension == "avro")
+            {
+                std::fs::remove_file(path).unwrap();
+            }
+        }
+    }
 }
@@ -0,0 +1,93 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Lazy reads of Iceberg tables as DataFusion [`StreamingTable`]s.
+
+use std::sync::Arc;
+
+use datafusion::arrow::datatypes::SchemaRef as ArrowSchemaRef;
+use datafusion::catalog::streaming::StreamingTable;
+use datafusion::execution::{SendableRecordBatchStream, TaskContext};
+use datafusion::physical_plan::stream::RecordBatchStreamAdapter;
+use datafusion::physical_plan::streaming::PartitionStream;
+use futures::{StreamExt, TryStreamExt};
+use iceberg::Result;
+use iceberg::arrow::schema_to_arrow_schema;
+use iceberg::scan::{FileScanTask, TableScan};
+use iceberg::table::Table;
+
+use crate::error::{from_datafusion_error, to_datafusion_error};
+
+/// A DataFusion [`PartitionStream`] reading a single file scan task of an Iceberg table scan.
+///
+/// The file is only opened when the partition is executed, so a plan over many partitions
+/// doesn't hold a reader open for each of them.
+#[derive(Debug)]
+pub struct IcebergPartitionStream {
+    scan: Arc<TableScan>,
+    task: FileScanTask,
+    schema: ArrowSchemaRef,
+}
+
+impl IcebergPartitionStream {
+    /// Returns the file scan task read by this partition.
+    pub fn task(&self) -> &FileScanTask {
+        &self.task
+    }
+}
+
+impl PartitionStream for IcebergPartitionStream {
+    fn schema(&self) -> &ArrowSchemaRef {
+        &self.schema
+    }
+
+    fn execute(&self, _ctx: Arc<TaskContext>) -> SendableRecordBatchStream {
+        let stream = match self.scan.to_arrow_from_tasks([self.task.clone()]) {
+            Ok(stream) => stream.map_err(to_datafusion_error).boxed(),
+            Err(err) => {
+                futures::stream::once(futures::future::ready(Err(to_datafusion_error(err)))).boxed()
+            }
+        };
+        Box::pin(RecordBatchStreamAdapter::new(self.schema.clone(), stream))
+    }
+}
+
+/// Creates a [`StreamingTable`] reading the current snapshot of `table`, with one
+/// [`IcebergPartitionStream`] per file scan task.
+///
+/// The files of the snapshot are planned once, when the streaming table is created, and each
+/// file is read lazily by its partition. Unlike [`IcebergStaticTableProvider`], filters are
+/// not pushed down to the scan, so every file of the snapshot is read.
+///
+/// [`IcebergStaticTableProvider`]: crate::IcebergStaticTableProvider
+pub async fn to_streaming_table(table: &Table) -> Result<StreamingTable> {
+    let schema = Arc::new(schema_to_arrow_schema(table.metadata().current_schema())?);
+    let scan = Arc::new(table.scan().select_all().build()?);
+    let tasks: Vec<FileScanTask> = scan.plan_files().await?.try_collect().await?;
+
+    let partitions: Vec<Arc<dyn PartitionStream>> = tasks
+        .into_iter()
+        .map(|task| {
+            Arc::new(IcebergPartitionStream {
+                scan: scan.clone(),
+                task,
+                schema: schema.clone(),
+            }) as Arc<dyn PartitionStream>
+        })
+        .collect();
+    StreamingTable::try_new(schema, partitions).map_err(from_datafusion_error)
+}
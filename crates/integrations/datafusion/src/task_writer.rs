@@ -68,6 +68,8 @@ pub(crate) struct TaskWriter<B: IcebergWriterBuilder> {
     writer: SupportedWriter<B>,
     /// Partition splitter for partitioned tables (initialized in constructor)
     partition_splitter: Option<RecordBatchPartitionSplitter>,
+    /// Whether the partitions of a batch are written in partition key order
+    local_partition_sort: bool,
 }
 
 /// Internal enum to hold the different writer types.
@@ -147,9 +149,27 @@ impl<B: IcebergWriterBuilder> TaskWriter<B> {
         Ok(Self {
             writer,
             partition_splitter,
+            local_partition_sort: false,
         })
     }
 
+    /// Set whether each batch is sorted by partition key before it is written to a
+    /// FanoutWriter. Defaults to `false`.
+    ///
+    /// With local sort, the FanoutWriter only keeps the writer of the partition being written
+    /// open, instead of the writers of all partitions written so far. This trades the CPU
+    /// spent on sorting for fewer open files, at the cost of a new file each time a partition
+    /// reappears in a later batch. It has no effect on unpartitioned tables and on the
+    /// ClusteredWriter.
+    pub fn with_local_partition_sort(mut self, local_partition_sort: bool) -> Self {
+        if let SupportedWriter::Fanout(writer) = self.writer {
+            self.writer =
+                SupportedWriter::Fanout(writer.with_local_partition_sort(local_partition_sort));
+            self.local_partition_sort = local_partition_sort;
+        }
+        self
+    }
+
     /// Write a RecordBatch to the TaskWriter.
     ///
     /// For partitioned tables, uses the partition splitter to split
@@ -186,10 +206,17 @@ impl<B: IcebergWriterBuilder> TaskWriter<B> {
                 writer.write(batch).await
             }
             SupportedWriter::Fanout(writer) => {
-                Self::write_partitioned_batches(writer, &self.partition_splitter, &batch).await
+                Self::write_partitioned_batches(
+                    writer,
+                    &self.partition_splitter,
+                    &batch,
+                    self.local_partition_sort,
+                )
+                .await
             }
             SupportedWriter::Clustered(writer) => {
-                Self::write_partitioned_batches(writer, &self.partition_splitter, &batch).await
+                Self::write_partitioned_batches(writer, &self.partition_splitter, &batch, false)
+                    .await
             }
         }
     }
@@ -205,6 +232,7 @@ impl<B: IcebergWriterBuilder> TaskWriter<B> {
     /// * `writer` - The underlying PartitioningWriter (FanoutWriter or ClusteredWriter)
     /// * `partition_splitter` - The partition splitter
     /// * `batch` - The RecordBatch to write
+    /// * `sort_partitions` - If true, the partitions are written in partition key order
     ///
     /// # Returns
     ///
@@ -213,12 +241,16 @@ impl<B: IcebergWriterBuilder> TaskWriter<B> {
         writer: &mut W,
         partition_splitter: &Option<RecordBatchPartitionSplitter>,
         batch: &RecordBatch,
+        sort_partitions: bool,
     ) -> Result<()> {
         // Split batch by partition
         let splitter = partition_splitter
             .as_ref()
             .expect("Partition splitter should be initialized");
-        let partitioned_batches = splitter.split(batch)?;
+        let mut partitioned_batches = splitter.split(batch)?;
+        if sort_partitions {
+            partitioned_batches.sort_by_cached_key(|(partition_key, _)| partition_key.to_path());
+        }
 
         // Write each partition
         for (partition_key, partition_batch) in partitioned_batches {
@@ -263,12 +295,16 @@ impl<B: IcebergWriterBuilder> TaskWriter<B> {
 mod tests {
     use std::collections::HashMap;
     use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
 
     use datafusion::arrow::array::{ArrayRef, Int32Array, RecordBatch, StringArray, StructArray};
     use datafusion::arrow::datatypes::{DataType, Field, Schema};
     use iceberg::arrow::PROJECTED_PARTITION_VALUE_COLUMN;
     use iceberg::io::FileIOBuilder;
-    use iceberg::spec::{DataFileFormat, NestedField, PartitionSpec, PrimitiveType, Type};
+    use iceberg::spec::{
+        DataFileFormat, NestedField, PartitionKey, PartitionSpec, PrimitiveType, Type,
+    };
+    use iceberg::writer::IcebergWriter;
     use iceberg::writer::base_writer::data_file_writer::DataFileWriterBuilder;
     use iceberg::writer::file_writer::ParquetWriterBuilder;
     use iceberg::writer::file_writer::location_generator::{
@@ -363,7 +399,10 @@ mod tests {
             location_gen,
             file_name_gen,
         );
-        Ok(DataFileWriterBuilder::new(rolling_writer_builder))
+        Ok(DataFileWriterBuilder::new(
+            rolling_writer_builder,
+            PartitionSpec::unpartition_spec().spec_id(),
+        ))
     }
 
     #[tokio::test]
@@ -514,4 +553,112 @@ mod tests {
 
         Ok(())
     }
+
+    /// Tracks the number of writers open at the same time.
+    #[derive(Clone, Default)]
+    struct OpenWriters {
+        open: Arc<AtomicUsize>,
+        max_open: Arc<AtomicUsize>,
+    }
+
+    struct CountingWriterBuilder<B> {
+        inner: B,
+        open_writers: OpenWriters,
+    }
+
+    struct CountingWriter<W> {
+        inner: W,
+        open_writers: OpenWriters,
+    }
+
+    #[async_trait::async_trait]
+    impl<B: IcebergWriterBuilder> IcebergWriterBuilder for CountingWriterBuilder<B> {
+        type R = CountingWriter<B::R>;
+
+        async fn build(&self, partition_key: Option<PartitionKey>) -> Result<Self::R> {
+            let inner = self.inner.build(partition_key).await?;
+            let open = self.open_writers.open.fetch_add(1, Ordering::SeqCst) + 1;
+            self.open_writers.max_open.fetch_max(open, Ordering::SeqCst);
+            Ok(CountingWriter {
+                inner,
+                open_writers: self.open_writers.clone(),
+            })
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl<W: IcebergWriter> IcebergWriter for CountingWriter<W> {
+        async fn write(&mut self, input: RecordBatch) -> Result<()> {
+            self.inner.write(input).await
+        }
+
+        async fn close(&mut self) -> Result<Vec<iceberg::spec::DataFile>> {
+            self.open_writers.open.fetch_sub(1, Ordering::SeqCst);
+            self.inner.close().await
+        }
+    }
+
+    fn create_partitioned_batch(regions: &[&str]) -> Result<RecordBatch> {
+        let partition_field = Field::new("region", DataType::Utf8, false).with_metadata(
+            HashMap::from([(PARQUET_FIELD_ID_META_KEY.to_string(), "1000".to_string())]),
+        );
+        let partition_struct = StructArray::from(vec![(
+            Arc::new(partition_field),
+            Arc::new(StringArray::from(regions.to_vec())) as ArrayRef,
+        )]);
+        Ok(RecordBatch::try_new(
+            create_arrow_schema_with_partition(),
+            vec![
+                Arc::new(Int32Array::from_iter_values(0..regions.len() as i32)),
+                Arc::new(StringArray::from(vec!["name"; regions.len()])),
+                Arc::new(StringArray::from(regions.to_vec())),
+                Arc::new(partition_struct),
+            ],
+        )?)
+    }
+
+    #[tokio::test]
+    async fn test_task_writer_local_partition_sort_reduces_open_writers() -> Result<()> {
+        let schema = create_test_schema()?;
+        let partition_spec = Arc::new(
+            PartitionSpec::builder(schema.clone())
+                .with_spec_id(1)
+                .add_partition_field("region", "region", iceberg::spec::Transform::Identity)?
+                .build()?,
+        );
+        let batches = [
+            create_partitioned_batch(&["US", "EU", "ASIA", "EU", "US", "ASIA"])?,
+            create_partitioned_batch(&["ASIA", "US", "EU", "US", "EU", "ASIA"])?,
+        ];
+
+        let mut max_open_writers = vec![];
+        for local_partition_sort in [false, true] {
+            let temp_dir = TempDir::new()?;
+            let open_writers = OpenWriters::default();
+            let writer_builder = CountingWriterBuilder {
+                inner: create_writer_builder(&temp_dir, schema.clone())?,
+                open_writers: open_writers.clone(),
+            };
+            let mut task_writer =
+                TaskWriter::try_new(writer_builder, true, schema.clone(), partition_spec.clone())?
+                    .with_local_partition_sort(local_partition_sort);
+            for batch in &batches {
+                task_writer.write(batch.clone()).await?;
+            }
+            let data_files = task_writer.close().await?;
+
+            let partition_counts = verify_partition_files(&data_files, 12);
+            assert_eq!(partition_counts.get("US"), Some(&4));
+            assert_eq!(partition_counts.get("EU"), Some(&4));
+            assert_eq!(partition_counts.get("ASIA"), Some(&4));
+            assert_eq!(open_writers.open.load(Ordering::SeqCst), 0);
+            max_open_writers.push(open_writers.max_open.load(Ordering::SeqCst));
+        }
+
+        // Without local sort, the writers of all partitions stay open until the writer is
+        // closed. With local sort, only the writer of the current partition is open.
+        assert_eq!(max_open_writers, vec![3, 1]);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,471 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Time travel in SQL queries.
+//!
+//! DataFusion neither parses `FOR SYSTEM_VERSION AS OF` nor resolves the version of a table
+//! in a query, so [`sql_with_time_travel`] takes the time travel clauses out of the query,
+//! resolves the version of each of their tables, and plans the query with the table
+//! factors of these tables reading the resolved snapshots.
+
+use std::ops::ControlFlow;
+use std::sync::Arc;
+
+use datafusion::arrow::compute::kernels::cast_utils::string_to_timestamp_nanos;
+use datafusion::catalog::TableFunctionImpl;
+use datafusion::common::{DataFusionError, ScalarValue, plan_err};
+use datafusion::dataframe::DataFrame;
+use datafusion::datasource::TableProvider;
+use datafusion::error::Result as DFResult;
+use datafusion::execution::context::SessionContext;
+use datafusion::logical_expr::Expr;
+use datafusion::sql::parser::Statement as DFStatement;
+use datafusion::sql::sqlparser::ast::{
+    self, FunctionArg, FunctionArgExpr, Ident, ObjectName, TableAlias, TableFactor,
+    TableFunctionArgs, Value, VisitMut, VisitorMut,
+};
+use datafusion::sql::sqlparser::dialect::{Dialect, dialect_from_str};
+use datafusion::sql::sqlparser::parser::Parser;
+use datafusion::sql::sqlparser::tokenizer::{Token, TokenWithSpan, Tokenizer, Word};
+use iceberg::table::Table;
+use iceberg::{Error, ErrorKind};
+
+use crate::error::to_datafusion_error;
+use crate::table::{IcebergStaticTableProvider, IcebergTableProvider};
+
+/// Name of the table function the time traveled tables of a query are planned as. It is
+/// only registered in the state planning the query.
+const TIME_TRAVEL_FUNCTION: &str = "__iceberg_time_travel";
+
+/// The version of a table selected by a time travel clause.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TableVersion {
+    /// The snapshot that was current at a timestamp in milliseconds since the epoch.
+    Timestamp(i64),
+    /// The snapshot with an id.
+    Snapshot(i64),
+}
+
+/// A time travel clause following a table name in a query.
+struct TimeTravelClause {
+    /// The table name, as written in the query.
+    table_name: String,
+    /// The last part of the table name, aliasing the table unless the query gives it an alias.
+    table_alias: Ident,
+    version: TableVersion,
+    /// Index of the first token of the table name.
+    start: usize,
+    /// Index of the token following the clause.
+    end: usize,
+}
+
+/// Creates a [`DataFrame`] from a SQL query that may read past snapshots of Iceberg tables.
+///
+/// A table name in the query may be followed by one of the clauses:
+///
+/// - `FOR SYSTEM_TIME AS OF [TIMESTAMP] '<timestamp>'`, reading the snapshot that was current
+///   at the timestamp. A timestamp without time zone is in UTC.
+/// - `FOR SYSTEM_VERSION AS OF <snapshot id>`, reading the snapshot with the id.
+///
+/// The tables must be served by an [`IcebergTableProvider`] or an
+/// [`IcebergStaticTableProvider`]. The table of an [`IcebergTableProvider`] is reloaded from
+/// its catalog, so that the snapshots committed since the provider last loaded it are found.
+/// Each time traveled table is read as an [`IcebergStaticTableProvider`] of the selected
+/// snapshot, with the schema of the snapshot, and is aliased to the last part of its name
+/// unless the query gives it an alias. A query without time travel clauses is planned as
+/// with [`SessionContext::sql`].
+pub async fn sql_with_time_travel(ctx: &SessionContext, sql: &str) -> DFResult<DataFrame> {
+    let mut state = ctx.state();
+    let dialect_name = &state.config_options().sql_parser.dialect;
+    let Some(dialect) = dialect_from_str(dialect_name) else {
+        return plan_err!("Unsupported SQL dialect: {dialect_name}");
+    };
+    let tokens = Tokenizer::new(dialect.as_ref(), sql)
+        .tokenize_with_location()
+        .map_err(|err| DataFusionError::External(Box::new(err)))?;
+    let clauses = time_travel_clauses(&tokens)?;
+    if clauses.is_empty() {
+        return ctx.sql(sql).await;
+    }
+
+    let mut providers = Vec::with_capacity(clauses.len());
+    for clause in &clauses {
+        providers.push(time_travel_provider(ctx, &clause.table_name, clause.version).await?);
+    }
+
+    let mut statement = parse_without_clauses(dialect.as_ref(), tokens, &clauses)?;
+    plan_time_travel_tables(&mut statement, &clauses)?;
+
+    // The time traveled tables are resolved in a copy of the state of the context, so that
+    // concurrent queries of the context do not see them.
+    state.register_udtf(
+        TIME_TRAVEL_FUNCTION,
+        Arc::new(ResolvedTimeTravelTables { providers }),
+    );
+    let plan = state
+        .statement_to_plan(DFStatement::Statement(Box::new(statement)))
+        .await?;
+    ctx.execute_logical_plan(plan).await
+}
+
+/// Finds the time travel clauses of the tokens of a query, in order.
+fn time_travel_clauses(tokens: &[TokenWithSpan]) -> DFResult<Vec<TimeTravelClause>> {
+    let significant: Vec<usize> = tokens
+        .iter()
+        .enumerate()
+        .filter(|(_, token)| !matches!(token.token, Token::Whitespace(_)))
+        .map(|(index, _)| index)
+        .collect();
+    let token = |position: usize| significant.get(position).map(|index| &tokens[*index].token);
+
+    let mut clauses = vec![];
+    let mut position = 0;
+    while position < significant.len() {
+        let is_time = is_keyword(token(position + 1), "SYSTEM_TIME");
+        let is_version = is_keyword(token(position + 1), "SYSTEM_VERSION");
+        if !is_keyword(token(position), "FOR")
+            || !(is_time || is_version)
+            || !is_keyword(token(position + 2), "AS")
+            || !is_keyword(token(position + 3), "OF")
+        {
+            position += 1;
+            continue;
+        }
+
+        // The table name is the dot-separated identifiers preceding the clause.
+        let mut name_start = position;
+        while name_start > 0 && matches!(token(name_start - 1), Some(Token::Word(_))) {
+            name_start -= 1;
+            if name_start > 1 && matches!(token(name_start - 1), Some(Token::Period)) {
+                name_start -= 1;
+            } else {
+                break;
+            }
+        }
+        let Some(Token::Word(last_word)) = position.checked_sub(1).and_then(token) else {
+            return plan_err!("Time travel clause must follow a table name");
+        };
+        let table_name: String = (name_start..position)
+            .filter_map(token)
+            .map(ToString::to_string)
+            .collect();
+        let table_alias = word_to_ident(last_word);
+
+        let mut value_position = position + 4;
+        let version = if is_time {
+            if is_keyword(token(value_position), "TIMESTAMP") {
+                value_position += 1;
+            }
+            let Some(Token::SingleQuotedString(timestamp)) = token(value_position) else {
+                return plan_err!(
+                    "Expected a timestamp literal in FOR SYSTEM_TIME AS OF of table {table_name}"
+                );
+            };
+            let timestamp_nanos = string_to_timestamp_nanos(timestamp)?;
+            TableVersion::Timestamp(timestamp_nanos.div_euclid(1_000_000))
+        } else {
+            let snapshot_id = match token(value_position) {
+                Some(Token::Number(number, _)) => number.parse::<i64>().ok(),
+                _ => None,
+            };
+            let Some(snapshot_id) = snapshot_id else {
+                return plan_err!(
+                    "Expected a snapshot id in FOR SYSTEM_VERSION AS OF of table {table_name}"
+                );
+            };
+            TableVersion::Snapshot(snapshot_id)
+        };
+
+        clauses.push(TimeTravelClause {
+            table_name,
+            table_alias,
+            version,
+            start: significant[name_start],
+            end: significant[value_position] + 1,
+        });
+        position = value_position + 1;
+    }
+    Ok(clauses)
+}
+
+/// Returns whether `token` is the unquoted keyword `keyword`.
+fn is_keyword(token: Option<&Token>, keyword: &str) -> bool {
+    matches!(token, Some(Token::Word(word)) if word.quote_style.is_none() && word.value.eq_ignore_ascii_case(keyword))
+}
+
+fn word_to_ident(word: &Word) -> Ident {
+    match word.quote_style {
+        Some(quote) => Ident::with_quote(quote, &word.value),
+        None => Ident::new(&word.value),
+    }
+}
+
+/// Returns the name standing for the time traveled table of the clause `index` in the query
+/// parsed by [`parse_without_clauses`].
+fn time_travel_table_name(index: usize) -> String {
+    format!("{TIME_TRAVEL_FUNCTION}_{index}")
+}
+
+/// Parses the query of `tokens`, with the time travel clauses taken out and the names of
+/// their tables replaced by a name of [`time_travel_table_name`].
+fn parse_without_clauses(
+    dialect: &dyn Dialect,
+    tokens: Vec<TokenWithSpan>,
+    clauses: &[TimeTravelClause],
+) -> DFResult<ast::Statement> {
+    let mut query_tokens = Vec::with_capacity(tokens.len());
+    let mut clauses = clauses.iter().enumerate().peekable();
+    for (index, token) in tokens.into_iter().enumerate() {
+        match clauses.peek() {
+            Some((_, clause)) if index < clause.start => query_tokens.push(token),
+            Some((clause_index, clause)) => {
+                if index == clause.start {
+                    query_tokens.push(TokenWithSpan::new(
+                        Token::make_word(&time_travel_table_name(*clause_index), Some('"')),
+                        token.span,
+                    ));
+                }
+                if index + 1 == clause.end {
+                    clauses.next();
+                }
+            }
+            None => query_tokens.push(token),
+        }
+    }
+
+    let mut parser = Parser::new(dialect).with_tokens_with_locations(query_tokens);
+    let statements = parser
+        .parse_statements()
+        .map_err(|err| DataFusionError::SQL(Box::new(err), None))?;
+    let [statement] = <[_; 1]>::try_from(statements).map_err(|_| {
+        DataFusionError::NotImplemented(
+            "The context currently only supports a single SQL statement".to_string(),
+        )
+    })?;
+    Ok(statement)
+}
+
+/// Plans the tables of the time travel `clauses` of `statement` as calls of
+/// [`TIME_TRAVEL_FUNCTION`], aliased to the last part of the table name unless the query
+/// gives them an alias.
+fn plan_time_travel_tables(
+    statement: &mut ast::Statement,
+    clauses: &[TimeTravelClause],
+) -> DFResult<()> {
+    let mut visitor = TimeTravelTables {
+        clauses,
+        planned: vec![false; clauses.len()],
+    };
+    let _ = statement.visit(&mut visitor);
+    match visitor.planned.iter().position(|planned| !planned) {
+        Some(index) => plan_err!(
+            "Time travel clause of {} must follow a table name",
+            clauses[index].table_name
+        ),
+        None => Ok(()),
+    }
+}
+
+/// Replaces the names of the time traveled tables by calls of [`TIME_TRAVEL_FUNCTION`].
+struct TimeTravelTables<'a> {
+    clauses: &'a [TimeTravelClause],
+    /// Whether the table of each clause was found in the query.
+    planned: Vec<bool>,
+}
+
+impl VisitorMut for TimeTravelTables<'_> {
+    type Break = ();
+
+    fn pre_visit_table_factor(
+        &mut self,
+        table_factor: &mut TableFactor,
+    ) -> ControlFlow<Self::Break> {
+        let TableFactor::Table {
+            name, alias, args, ..
+        } = table_factor
+        else {
+            return ControlFlow::Continue(());
+        };
+        let Some(index) = (0..self.clauses.len()).find(|index| {
+            matches!(name.0.as_slice(), [part] if part.as_ident().is_some_and(|ident| ident.value == time_travel_table_name(*index)))
+        }) else {
+            return ControlFlow::Continue(());
+        };
+
+        *name = ObjectName::from(vec![Ident::new(TIME_TRAVEL_FUNCTION)]);
+        *args = Some(TableFunctionArgs {
+            args: vec![FunctionArg::Unnamed(FunctionArgExpr::Expr(
+                ast::Expr::value(Value::SingleQuotedString(index.to_string())),
+            ))],
+            settings: None,
+        });
+        alias.get_or_insert_with(|| TableAlias {
+            name: self.clauses[index].table_alias.clone(),
+            columns: vec![],
+        });
+        self.planned[index] = true;
+        ControlFlow::Continue(())
+    }
+}
+
+/// The table function reading the resolved versions of the time traveled tables of a query,
+/// by the index of their clause.
+#[derive(Debug)]
+struct ResolvedTimeTravelTables {
+    providers: Vec<Arc<dyn TableProvider>>,
+}
+
+impl TableFunctionImpl for ResolvedTimeTravelTables {
+    fn call(&self, args: &[Expr]) -> DFResult<Arc<dyn TableProvider>> {
+        let provider = match args {
+            [Expr::Literal(ScalarValue::Utf8(Some(index)), _)] => index
+                .parse::<usize>()
+                .ok()
+                .and_then(|index| self.providers.get(index)),
+            _ => None,
+        };
+        match provider {
+            Some(provider) => Ok(provider.clone()),
+            None => {
+                plan_err!("{TIME_TRAVEL_FUNCTION} only reads the time traveled tables of a query")
+            }
+        }
+    }
+}
+
+/// Returns a static provider of the version `version` of the Iceberg table `table_name`.
+async fn time_travel_provider(
+    ctx: &SessionContext,
+    table_name: &str,
+    version: TableVersion,
+) -> DFResult<Arc<dyn TableProvider>> {
+    let provider = ctx.table_provider(table_name).await?;
+    let table = if let Some(provider) = provider.as_any().downcast_ref::<IcebergTableProvider>() {
+        provider.refresh().await.map_err(to_datafusion_error)?
+    } else if let Some(provider) = provider
+        .as_any()
+        .downcast_ref::<IcebergStaticTableProvider>()
+    {
+        provider.table().clone()
+    } else {
+        return plan_err!(
+            "Table {table_name} is not an Iceberg table and does not support time travel"
+        );
+    };
+
+    let snapshot_id = match version {
+        TableVersion::Snapshot(snapshot_id) => snapshot_id,
+        TableVersion::Timestamp(timestamp_ms) => {
+            snapshot_id_as_of(&table, timestamp_ms).map_err(to_datafusion_error)?
+        }
+    };
+    let provider = IcebergStaticTableProvider::try_new_from_table_snapshot(table, snapshot_id)
+        .await
+        .map_err(to_datafusion_error)?;
+    Ok(Arc::new(provider))
+}
+
+/// Returns the id of the snapshot of `table` that was current at `timestamp_ms`.
+fn snapshot_id_as_of(table: &Table, timestamp_ms: i64) -> iceberg::Result<i64> {
+    table
+        .metadata()
+        .history()
+        .iter()
+        .rev()
+        .find(|log| log.timestamp_ms <= timestamp_ms)
+        .map(|log| log.snapshot_id)
+        .ok_or_else(|| {
+            Error::new(
+                ErrorKind::DataInvalid,
+                format!(
+                    "Table {} has no snapshot as of timestamp {timestamp_ms}",
+                    table.identifier()
+                ),
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use datafusion::sql::sqlparser::dialect::GenericDialect;
+
+    use super::*;
+
+    fn tokenize(sql: &str) -> Vec<TokenWithSpan> {
+        Tokenizer::new(&GenericDialect {}, sql)
+            .tokenize_with_location()
+            .unwrap()
+    }
+
+    fn clauses(sql: &str) -> Vec<(String, String, TableVersion)> {
+        time_travel_clauses(&tokenize(sql))
+            .unwrap()
+            .into_iter()
+            .map(|clause| {
+                (
+                    clause.table_name,
+                    clause.table_alias.to_string(),
+                    clause.version,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_time_travel_clauses() {
+        assert_eq!(
+            clauses(
+                "SELECT * FROM c.ns.\"T\" FOR SYSTEM_TIME AS OF TIMESTAMP '1970-01-01T00:00:01Z' \
+                 JOIN u for system_version as of 42 ON true"
+            ),
+            vec![
+                (
+                    "c.ns.\"T\"".to_string(),
+                    "\"T\"".to_string(),
+                    TableVersion::Timestamp(1000)
+                ),
+                ("u".to_string(), "u".to_string(), TableVersion::Snapshot(42)),
+            ]
+        );
+        assert!(clauses("SELECT 'FOR SYSTEM_TIME AS OF' FROM t").is_empty());
+        assert!(
+            time_travel_clauses(&tokenize("SELECT * FROM t FOR SYSTEM_VERSION AS OF x")).is_err()
+        );
+    }
+
+    #[test]
+    fn test_plan_time_travel_tables() {
+        let sql = "SELECT * FROM c.ns.t FOR SYSTEM_VERSION AS OF 1 \
+                   JOIN u FOR SYSTEM_TIME AS OF '1970-01-01T00:00:01Z' AS v ON true";
+        let tokens = tokenize(sql);
+        let clauses = time_travel_clauses(&tokens).unwrap();
+        let mut statement = parse_without_clauses(&GenericDialect {}, tokens, &clauses).unwrap();
+        plan_time_travel_tables(&mut statement, &clauses).unwrap();
+        assert_eq!(
+            statement.to_string(),
+            "SELECT * FROM __iceberg_time_travel('0') AS t \
+             JOIN __iceberg_time_travel('1') AS v ON true"
+        );
+
+        // A clause that does not follow a table of the query is rejected.
+        let sql = "SELECT x FOR SYSTEM_VERSION AS OF 1 FROM t";
+        let tokens = tokenize(sql);
+        let clauses = time_travel_clauses(&tokens).unwrap();
+        let mut statement = parse_without_clauses(&GenericDialect {}, tokens, &clauses).unwrap();
+        assert!(plan_time_travel_tables(&mut statement, &clauses).is_err());
+    }
+}
@@ -0,0 +1,43 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Conversion of Arrow types that Iceberg doesn't support natively.
+
+use std::fmt::Debug;
+
+use datafusion::arrow::array::ArrayRef;
+use datafusion::arrow::datatypes::DataType;
+use iceberg::Result;
+use iceberg::spec::Type;
+
+/// Converts the values of Arrow types that have no Iceberg counterpart, such as `Decimal256`
+/// or interval types, so that they can be inserted into Iceberg tables.
+///
+/// A converter is registered with
+/// [`IcebergTableProvider::with_arrow_type_converter`](crate::IcebergTableProvider::with_arrow_type_converter).
+/// When an inserted column has a converted Arrow type, its values are encoded as the Arrow
+/// type of the Iceberg type of the table column before they are written.
+pub trait ArrowTypeConverter: Debug + Send + Sync {
+    /// Returns the Iceberg type that the values of `data_type` are stored as, or `None` if
+    /// this converter doesn't handle `data_type`.
+    fn iceberg_type(&self, data_type: &DataType) -> Option<Type>;
+
+    /// Encodes the values of `array`, whose type is handled by this converter, as an array
+    /// of `target_type`, the Arrow type of the Iceberg type returned by
+    /// [`iceberg_type`](Self::iceberg_type).
+    fn convert(&self, array: &ArrayRef, target_type: &DataType) -> Result<ArrayRef>;
+}
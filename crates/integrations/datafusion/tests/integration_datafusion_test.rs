@@ -23,18 +23,25 @@ use std::vec;
 
 use datafusion::arrow::array::{Array, StringArray, UInt64Array};
 use datafusion::arrow::datatypes::{DataType, Field, Schema as ArrowSchema};
+use datafusion::arrow::util::pretty::pretty_format_batches;
 use datafusion::execution::context::SessionContext;
 use datafusion::parquet::arrow::PARQUET_FIELD_ID_META_KEY;
+use datafusion::physical_plan::ExecutionPlanProperties;
 use expect_test::expect;
+use futures::TryStreamExt;
 use iceberg::memory::{MEMORY_CATALOG_WAREHOUSE, MemoryCatalogBuilder};
 use iceberg::spec::{
-    NestedField, PrimitiveType, Schema, StructType, Transform, Type, UnboundPartitionSpec,
+    Literal, NestedField, PrimitiveType, Schema, Struct, StructType, Transform, Type,
+    UnboundPartitionSpec,
 };
 use iceberg::test_utils::check_record_batches;
 use iceberg::{
-    Catalog, CatalogBuilder, MemoryCatalog, NamespaceIdent, Result, TableCreation, TableIdent,
+    Catalog, CatalogBuilder, ErrorKind, MemoryCatalog, NamespaceIdent, Result, TableCreation,
+    TableIdent,
+};
+use iceberg_datafusion::{
+    IcebergCatalogProvider, find_iceberg_error, sql_with_time_travel, to_streaming_table,
 };
-use iceberg_datafusion::IcebergCatalogProvider;
 use tempfile::TempDir;
 
 fn temp_path() -> String {
@@ -943,3 +950,680 @@ async fn test_insert_into_partitioned() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_insert_into_partitioned_with_null_partition_values() -> Result<()> {
+    let iceberg_catalog = get_iceberg_catalog().await;
+    let namespace = NamespaceIdent::new("test_null_partition_write".to_string());
+    set_test_namespace(&iceberg_catalog, &namespace).await?;
+
+    let schema = Schema::builder()
+        .with_schema_id(0)
+        .with_fields(vec![
+            NestedField::required(1, "id", Type::Primitive(PrimitiveType::Int)).into(),
+            NestedField::optional(2, "category", Type::Primitive(PrimitiveType::String))
+                .with_write_default(Literal::string("misc"))
+                .into(),
+            NestedField::optional(3, "code", Type::Primitive(PrimitiveType::Int)).into(),
+            NestedField::optional(4, "name", Type::Primitive(PrimitiveType::String)).into(),
+        ])
+        .build()?;
+    let partition_spec = UnboundPartitionSpec::builder()
+        .with_spec_id(0)
+        .add_partition_field(2, "category", Transform::Identity)?
+        .add_partition_field(3, "code_bucket", Transform::Bucket(4))?
+        .add_partition_field(4, "name_trunc", Transform::Truncate(2))?
+        .build();
+    let creation = TableCreation::builder()
+        .name("null_partitioned_table".to_string())
+        .location(temp_path())
+        .schema(schema)
+        .partition_spec(partition_spec)
+        .properties(HashMap::new())
+        .build();
+    iceberg_catalog.create_table(&namespace, creation).await?;
+
+    let client = Arc::new(iceberg_catalog);
+    let catalog = Arc::new(IcebergCatalogProvider::try_new(client.clone()).await?);
+    let ctx = SessionContext::new();
+    ctx.register_catalog("catalog", catalog);
+
+    ctx.sql(
+        "INSERT INTO catalog.test_null_partition_write.null_partitioned_table \
+         VALUES (1, 'books', 10, 'novel'), (2, NULL, NULL, NULL), (3, NULL, NULL, NULL)",
+    )
+    .await
+    .unwrap()
+    .collect()
+    .await
+    .unwrap();
+    // A column missing from the insert is filled with its write-default value, not null.
+    ctx.sql(
+        "INSERT INTO catalog.test_null_partition_write.null_partitioned_table (id, code, name) \
+         VALUES (4, 20, 'atlas')",
+    )
+    .await
+    .unwrap()
+    .collect()
+    .await
+    .unwrap();
+
+    let batches = ctx
+        .sql(
+            "SELECT id FROM catalog.test_null_partition_write.null_partitioned_table \
+             WHERE category IS NULL ORDER BY id",
+        )
+        .await
+        .unwrap()
+        .collect()
+        .await
+        .unwrap();
+    check_record_batches(
+        batches,
+        expect![[r#"
+            Field { "id": Int32, metadata: {"PARQUET:field_id": "1"} }"#]],
+        expect![[r#"
+            id: PrimitiveArray<Int32>
+            [
+              2,
+              3,
+            ]"#]],
+        &[],
+        Some("id"),
+    );
+
+    let batches = ctx
+        .sql(
+            "SELECT id FROM catalog.test_null_partition_write.null_partitioned_table \
+             WHERE category = 'misc'",
+        )
+        .await
+        .unwrap()
+        .collect()
+        .await
+        .unwrap();
+    check_record_batches(
+        batches,
+        expect![[r#"
+            Field { "id": Int32, metadata: {"PARQUET:field_id": "1"} }"#]],
+        expect![[r#"
+            id: PrimitiveArray<Int32>
+            [
+              4,
+            ]"#]],
+        &[],
+        None,
+    );
+
+    // The rows with null source values are written to a single data file of the null
+    // partition.
+    let table_ident = TableIdent::new(namespace.clone(), "null_partitioned_table".to_string());
+    let table = client.load_table(&table_ident).await?;
+    let table_location = table.metadata().location();
+    for partition_path in [
+        format!("{table_location}/data/category=null/code_bucket=null/name_trunc=null"),
+        format!("{table_location}/data/category=misc"),
+    ] {
+        let partition_dir = partition_path.split("/code_bucket").next().unwrap();
+        assert!(
+            table.file_io().exists(partition_dir).await?,
+            "Expected partition directory: {partition_path}"
+        );
+    }
+
+    let tasks: Vec<_> = table
+        .scan()
+        .build()?
+        .plan_files()
+        .await?
+        .try_collect()
+        .await?;
+    let mut partitions: Vec<_> = tasks
+        .iter()
+        .map(|task| (task.record_count, task.partition.clone().unwrap()))
+        .collect();
+    partitions.sort_by_key(|(record_count, _)| *record_count);
+    assert_eq!(partitions.len(), 3);
+    assert_eq!(
+        partitions[2],
+        (Some(2), Struct::from_iter([None, None, None]))
+    );
+    assert!(
+        partitions[..2]
+            .iter()
+            .all(|(_, partition)| partition.iter().all(|value| value.is_some()))
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_insert_overwrite_replaces_touched_partitions() -> Result<()> {
+    let iceberg_catalog = get_iceberg_catalog().await;
+    let namespace = NamespaceIdent::new("test_insert_overwrite".to_string());
+    set_test_namespace(&iceberg_catalog, &namespace).await?;
+
+    let schema = Schema::builder()
+        .with_schema_id(0)
+        .with_fields(vec![
+            NestedField::required(1, "id", Type::Primitive(PrimitiveType::Int)).into(),
+            NestedField::required(2, "category", Type::Primitive(PrimitiveType::String)).into(),
+            NestedField::required(3, "value", Type::Primitive(PrimitiveType::String)).into(),
+        ])
+        .build()?;
+    let partition_spec = UnboundPartitionSpec::builder()
+        .with_spec_id(0)
+        .add_partition_field(2, "category", Transform::Identity)?
+        .build();
+    let creation = TableCreation::builder()
+        .name("overwritten_table".to_string())
+        .location(temp_path())
+        .schema(schema)
+        .partition_spec(partition_spec)
+        .properties(HashMap::new())
+        .build();
+    iceberg_catalog.create_table(&namespace, creation).await?;
+
+    let client = Arc::new(iceberg_catalog);
+    let catalog = Arc::new(IcebergCatalogProvider::try_new(client.clone()).await?);
+    let ctx = SessionContext::new();
+    ctx.register_catalog("catalog", catalog);
+
+    ctx.sql(
+        "INSERT INTO catalog.test_insert_overwrite.overwritten_table \
+         VALUES (1, 'electronics', 'laptop'), (2, 'books', 'novel'), (3, 'books', 'textbook')",
+    )
+    .await
+    .unwrap()
+    .collect()
+    .await
+    .unwrap();
+    ctx.sql(
+        "INSERT OVERWRITE catalog.test_insert_overwrite.overwritten_table \
+         VALUES (4, 'books', 'atlas')",
+    )
+    .await
+    .unwrap()
+    .collect()
+    .await
+    .unwrap();
+
+    let batches = ctx
+        .sql("SELECT value FROM catalog.test_insert_overwrite.overwritten_table ORDER BY id")
+        .await
+        .unwrap()
+        .collect()
+        .await
+        .unwrap();
+    let values: Vec<String> = batches
+        .iter()
+        .flat_map(|batch| {
+            let values = batch
+                .column(0)
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .unwrap();
+            values
+                .iter()
+                .map(|v| v.unwrap().to_string())
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    assert_eq!(values, vec!["laptop", "atlas"]);
+
+    let table_ident = TableIdent::new(namespace.clone(), "overwritten_table".to_string());
+    let table = client.load_table(&table_ident).await?;
+    let summary = table.metadata().current_snapshot().unwrap().summary();
+    assert_eq!(
+        summary.additional_properties.get("deleted-data-files"),
+        Some(&"1".to_string())
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_explain_analyze_reports_pruning() -> Result<()> {
+    let iceberg_catalog = get_iceberg_catalog().await;
+    let namespace = NamespaceIdent::new("test_explain_analyze".to_string());
+    set_test_namespace(&iceberg_catalog, &namespace).await?;
+
+    let schema = Schema::builder()
+        .with_schema_id(0)
+        .with_fields(vec![
+            NestedField::required(1, "id", Type::Primitive(PrimitiveType::Int)).into(),
+            NestedField::required(2, "category", Type::Primitive(PrimitiveType::String)).into(),
+        ])
+        .build()?;
+
+    let partition_spec = UnboundPartitionSpec::builder()
+        .with_spec_id(0)
+        .add_partition_field(2, "category", Transform::Identity)?
+        .build();
+
+    let creation = TableCreation::builder()
+        .name("t1".to_string())
+        .location(temp_path())
+        .schema(schema)
+        .partition_spec(partition_spec)
+        .properties(HashMap::new())
+        .build();
+    iceberg_catalog.create_table(&namespace, creation).await?;
+
+    let client = Arc::new(iceberg_catalog);
+    let catalog = Arc::new(IcebergCatalogProvider::try_new(client).await?);
+
+    let ctx = SessionContext::new();
+    ctx.register_catalog("catalog", catalog);
+
+    ctx.sql(
+        "INSERT INTO catalog.test_explain_analyze.t1 VALUES (1, 'books'), (2, 'clothing'), (3, 'electronics')",
+    )
+    .await
+    .unwrap()
+    .collect()
+    .await
+    .unwrap();
+
+    let records = ctx
+        .sql("EXPLAIN ANALYZE SELECT * FROM catalog.test_explain_analyze.t1 WHERE category = 'books'")
+        .await
+        .unwrap()
+        .collect()
+        .await
+        .unwrap();
+    assert_eq!(1, records.len());
+    // the first column is plan_type, the second column plan string.
+    let plan = records[0]
+        .column(1)
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .unwrap()
+        .value(0)
+        .to_string();
+
+    assert!(plan.contains("predicate:[category = "), "{plan}");
+    assert!(plan.contains("files_planned=1"), "{plan}");
+    assert!(plan.contains("files_pruned_by_partition=2"), "{plan}");
+    assert!(plan.contains("files_pruned_by_metrics=0"), "{plan}");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_explain_analyze_reports_bucket_pruning() -> Result<()> {
+    let iceberg_catalog = get_iceberg_catalog().await;
+    let namespace = NamespaceIdent::new("test_bucket_pruning".to_string());
+    set_test_namespace(&iceberg_catalog, &namespace).await?;
+
+    let schema = Schema::builder()
+        .with_schema_id(0)
+        .with_fields(vec![
+            NestedField::required(1, "user_id", Type::Primitive(PrimitiveType::Int)).into(),
+            NestedField::required(2, "name", Type::Primitive(PrimitiveType::String)).into(),
+        ])
+        .build()?;
+
+    let partition_spec = UnboundPartitionSpec::builder()
+        .with_spec_id(0)
+        .add_partition_field(1, "user_id_bucket", Transform::Bucket(16))?
+        .build();
+
+    let creation = TableCreation::builder()
+        .name("t1".to_string())
+        .location(temp_path())
+        .schema(schema)
+        .partition_spec(partition_spec)
+        .properties(HashMap::new())
+        .build();
+    iceberg_catalog.create_table(&namespace, creation).await?;
+
+    let client = Arc::new(iceberg_catalog);
+    let catalog = Arc::new(IcebergCatalogProvider::try_new(client.clone()).await?);
+
+    let ctx = SessionContext::new();
+    ctx.register_catalog("catalog", catalog);
+
+    let values = (0..64)
+        .map(|user_id| format!("({user_id}, 'user {user_id}')"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    ctx.sql(&format!(
+        "INSERT INTO catalog.test_bucket_pruning.t1 VALUES {values}"
+    ))
+    .await
+    .unwrap()
+    .collect()
+    .await
+    .unwrap();
+
+    let table_ident = TableIdent::new(namespace.clone(), "t1".to_string());
+    let table = client.load_table(&table_ident).await?;
+    let data_files: usize = table
+        .metadata()
+        .current_snapshot()
+        .unwrap()
+        .summary()
+        .additional_properties
+        .get("added-data-files")
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert!(data_files > 1);
+
+    let explain_analyze = |sql: String| {
+        let ctx = &ctx;
+        async move {
+            let records = ctx
+                .sql(&format!("EXPLAIN ANALYZE {sql}"))
+                .await
+                .unwrap()
+                .collect()
+                .await
+                .unwrap();
+            records[0]
+                .column(1)
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .unwrap()
+                .value(0)
+                .to_string()
+        }
+    };
+
+    // An equality predicate only reads the files of the bucket of the value.
+    let plan = explain_analyze(
+        "SELECT * FROM catalog.test_bucket_pruning.t1 WHERE user_id = 42".to_string(),
+    )
+    .await;
+    assert!(plan.contains("files_planned=1"), "{plan}");
+    assert!(
+        plan.contains(&format!("files_pruned_by_partition={}", data_files - 1)),
+        "{plan}"
+    );
+
+    let plan = explain_analyze(
+        "SELECT * FROM catalog.test_bucket_pruning.t1 WHERE 42 = user_id".to_string(),
+    )
+    .await;
+    assert!(plan.contains("files_planned=1"), "{plan}");
+    // A range predicate can't prune buckets.
+    let plan = explain_analyze(
+        "SELECT * FROM catalog.test_bucket_pruning.t1 WHERE user_id > 42".to_string(),
+    )
+    .await;
+    assert!(plan.contains("files_pruned_by_partition=0"), "{plan}");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_scan_metrics_recorded_when_stream_dropped_early() -> Result<()> {
+    let iceberg_catalog = get_iceberg_catalog().await;
+    let namespace = NamespaceIdent::new("test_scan_metrics_dropped".to_string());
+    set_test_namespace(&iceberg_catalog, &namespace).await?;
+
+    let schema = Schema::builder()
+        .with_schema_id(0)
+        .with_fields(vec![
+            NestedField::required(1, "id", Type::Primitive(PrimitiveType::Int)).into(),
+        ])
+        .build()?;
+    let creation = get_table_creation(temp_path(), "t1", Some(schema))?;
+    iceberg_catalog.create_table(&namespace, creation).await?;
+
+    let client = Arc::new(iceberg_catalog);
+    let catalog = Arc::new(IcebergCatalogProvider::try_new(client).await?);
+
+    let ctx = SessionContext::new();
+    ctx.register_catalog("catalog", catalog);
+
+    ctx.sql("INSERT INTO catalog.test_scan_metrics_dropped.t1 VALUES (1), (2), (3)")
+        .await
+        .unwrap()
+        .collect()
+        .await
+        .unwrap();
+
+    let provider = ctx
+        .table_provider("catalog.test_scan_metrics_dropped.t1")
+        .await
+        .unwrap();
+    let plan = provider.scan(&ctx.state(), None, &[], None).await.unwrap();
+
+    // Take a single batch and drop the stream before it is exhausted, as a
+    // LIMIT above the scan would.
+    let mut stream = plan.execute(0, ctx.task_ctx()).unwrap();
+    let batch = stream.try_next().await.unwrap().unwrap();
+    assert_eq!(batch.num_rows(), 3);
+    drop(stream);
+
+    let metrics = plan.metrics().unwrap();
+    assert_eq!(metrics.sum_by_name("files_planned").unwrap().as_usize(), 1);
+    assert!(metrics.sum_by_name("bytes_planned").unwrap().as_usize() > 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_select_for_system_time_and_version() -> Result<()> {
+    let iceberg_catalog = get_iceberg_catalog().await;
+    let namespace = NamespaceIdent::new("test_time_travel".to_string());
+    set_test_namespace(&iceberg_catalog, &namespace).await?;
+
+    let creation = get_table_creation(temp_path(), "my_table", None)?;
+    iceberg_catalog.create_table(&namespace, creation).await?;
+
+    let client = Arc::new(iceberg_catalog);
+    let catalog = Arc::new(IcebergCatalogProvider::try_new(client.clone()).await?);
+
+    let ctx = SessionContext::new();
+    ctx.register_catalog("catalog", catalog);
+
+    for sql in [
+        "INSERT INTO catalog.test_time_travel.my_table VALUES (1, 'alan')",
+        "INSERT INTO catalog.test_time_travel.my_table VALUES (2, 'turing')",
+    ] {
+        ctx.sql(sql).await.unwrap().collect().await.unwrap();
+        // Keep the snapshots apart in time.
+        tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+    }
+
+    let table = client
+        .load_table(&TableIdent::new(namespace, "my_table".to_string()))
+        .await?;
+    let history = table.metadata().history();
+    assert_eq!(history.len(), 2);
+    let first = history[0].clone();
+
+    for sql in [
+        format!(
+            "SELECT * FROM catalog.test_time_travel.my_table FOR SYSTEM_TIME AS OF TIMESTAMP '{}' WHERE my_table.foo1 > 0",
+            first.clone().timestamp()?.to_rfc3339()
+        ),
+        format!(
+            "SELECT t.* FROM catalog.test_time_travel.my_table FOR SYSTEM_VERSION AS OF {} AS t",
+            first.snapshot_id
+        ),
+    ] {
+        let batches = sql_with_time_travel(&ctx, &sql)
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        check_record_batches(
+            batches,
+            expect![[r#"
+                Field { "foo1": Int32, metadata: {"PARQUET:field_id": "1"} },
+                Field { "foo2": Utf8, metadata: {"PARQUET:field_id": "2"} }"#]],
+            expect![[r#"
+                foo1: PrimitiveArray<Int32>
+                [
+                  1,
+                ],
+                foo2: StringArray
+                [
+                  "alan",
+                ]"#]],
+            &[],
+            Some("foo1"),
+        );
+    }
+
+    // Without a time travel clause, the current snapshot is read.
+    let batches = sql_with_time_travel(&ctx, "SELECT * FROM catalog.test_time_travel.my_table")
+        .await
+        .unwrap()
+        .collect()
+        .await
+        .unwrap();
+    assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 2);
+
+    // A snapshot committed through another provider of the table is found.
+    let other_ctx = SessionContext::new();
+    other_ctx.register_catalog(
+        "catalog",
+        Arc::new(IcebergCatalogProvider::try_new(client.clone()).await?),
+    );
+    other_ctx
+        .sql("INSERT INTO catalog.test_time_travel.my_table VALUES (3, 'hopper')")
+        .await
+        .unwrap()
+        .collect()
+        .await
+        .unwrap();
+    let table = client.load_table(table.identifier()).await?;
+    let latest = table.metadata().current_snapshot().unwrap();
+    let batches = sql_with_time_travel(
+        &ctx,
+        &format!(
+            "SELECT * FROM catalog.test_time_travel.my_table FOR SYSTEM_VERSION AS OF {}",
+            latest.snapshot_id()
+        ),
+    )
+    .await
+    .unwrap()
+    .collect()
+    .await
+    .unwrap();
+    assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 3);
+
+    let Err(err) = sql_with_time_travel(
+        &ctx,
+        "SELECT * FROM catalog.test_time_travel.my_table FOR SYSTEM_TIME AS OF '1970-01-01T00:00:00Z'",
+    )
+    .await
+    else {
+        panic!("time traveling before the first snapshot should fail");
+    };
+    assert!(err.to_string().contains("has no snapshot as of"), "{err}");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_catalog_error_kind_is_kept_in_datafusion_error() -> Result<()> {
+    let iceberg_catalog = get_iceberg_catalog().await;
+    let namespace = NamespaceIdent::new("test_catalog_error".to_string());
+    set_test_namespace(&iceberg_catalog, &namespace).await?;
+
+    let creation = get_table_creation(temp_path(), "my_table", None)?;
+    iceberg_catalog.create_table(&namespace, creation).await?;
+
+    let client = Arc::new(iceberg_catalog);
+    let catalog = Arc::new(IcebergCatalogProvider::try_new(client.clone()).await?);
+
+    let ctx = SessionContext::new();
+    ctx.register_catalog("catalog", catalog);
+
+    // The table provider is still registered, but loading the table from the catalog fails.
+    client
+        .drop_table(&TableIdent::new(namespace, "my_table".to_string()))
+        .await?;
+    let df = ctx
+        .sql("SELECT * FROM catalog.test_catalog_error.my_table")
+        .await
+        .unwrap();
+    let Err(err) = df.collect().await else {
+        panic!("scanning a dropped table should fail");
+    };
+
+    let iceberg_error = find_iceberg_error(&err).expect("the iceberg error should be kept");
+    assert_eq!(iceberg_error.kind(), ErrorKind::TableNotFound);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_streaming_table_reads_same_rows_as_provider() -> Result<()> {
+    let iceberg_catalog = get_iceberg_catalog().await;
+    let namespace = NamespaceIdent::new("test_streaming_table".to_string());
+    set_test_namespace(&iceberg_catalog, &namespace).await?;
+
+    let creation = get_table_creation(temp_path(), "my_table", None)?;
+    iceberg_catalog.create_table(&namespace, creation).await?;
+
+    let client = Arc::new(iceberg_catalog);
+    let catalog = Arc::new(IcebergCatalogProvider::try_new(client.clone()).await?);
+
+    let ctx = SessionContext::new();
+    ctx.register_catalog("catalog", catalog);
+
+    // Each insert adds a file to the table.
+    for values in [
+        "(1, 'a'), (2, 'b')",
+        "(3, 'c')",
+        "(4, 'd'), (5, 'e'), (6, 'f')",
+    ] {
+        ctx.sql(&format!(
+            "INSERT INTO catalog.test_streaming_table.my_table VALUES {values}"
+        ))
+        .await
+        .unwrap()
+        .collect()
+        .await
+        .unwrap();
+    }
+
+    let table = client
+        .load_table(&TableIdent::new(namespace, "my_table".to_string()))
+        .await?;
+    let streaming_table = to_streaming_table(&table).await?;
+    ctx.register_table("streamed", Arc::new(streaming_table))
+        .unwrap();
+
+    let plan = ctx
+        .sql("SELECT * FROM streamed")
+        .await
+        .unwrap()
+        .create_physical_plan()
+        .await
+        .unwrap();
+    assert_eq!(plan.output_partitioning().partition_count(), 3);
+
+    let read = |sql: &'static str| {
+        let ctx = ctx.clone();
+        async move {
+            let batches = ctx.sql(sql).await.unwrap().collect().await.unwrap();
+            pretty_format_batches(&batches).unwrap().to_string()
+        }
+    };
+    let streamed = read("SELECT * FROM streamed ORDER BY foo1").await;
+    let provided = read("SELECT * FROM catalog.test_streaming_table.my_table ORDER BY foo1").await;
+    assert_eq!(streamed, provided);
+    expect![[r#"
+        +------+------+
+        | foo1 | foo2 |
+        +------+------+
+        | 1    | a    |
+        | 2    | b    |
+        | 3    | c    |
+        | 4    | d    |
+        | 5    | e    |
+        | 6    | f    |
+        +------+------+"#]]
+    .assert_eq(&streamed);
+
+    Ok(())
+}